@@ -7,7 +7,11 @@ use bevy::prelude::*;
 //
 // Listens for DayEndEvent and processes every animal entity:
 //
-//   1. Track consecutive unfed days (UnfedDays component).
+//   0. In winter, unfed barn/coop animals eat one hay each from the silo
+//      (SiloState). Once the silo runs dry the rest go unfed.
+//   1. Track consecutive unfed days (UnfedDays component). After 5 in a row
+//      the animal falls sick; a sick animal that goes 3 day-ends without
+//      medicine dies.
 //   2. Adjust happiness:
 //        - Fed today:   +8 (capped at u8::MAX = 255)
 //        - Not fed:     -18 (floors at 0)
@@ -16,7 +20,7 @@ use bevy::prelude::*;
 //   3. Reset daily flags (fed_today, petted_today).
 //   4. Age babies → adults after 7 days.
 //   5. Generate product_ready (+ PendingProductQuality) for adult animals
-//      that were fed and are not blocked by a starvation streak.
+//      that were fed, are not sick and are not blocked by a starvation streak.
 //
 // Happiness quality thresholds (deterministic — no RNG):
 //   happiness 220-255 → Iridium (2.0x)
@@ -48,6 +52,11 @@ const HAPPINESS_PETTED_BONUS: u8 = 7;
 const HAPPINESS_UNFED_PENALTY: u8 = 18;
 const HAPPINESS_OUTDOOR_SUNNY: u8 = 6;
 
+/// Consecutive unfed days after which an animal falls sick.
+pub const SICK_AFTER_UNFED_DAYS: u8 = 5;
+/// Day-ends a sick animal survives without medicine.
+pub const SICK_DAYS_BEFORE_DEATH: u8 = 3;
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Helper: derive quality tier from happiness (deterministic, no RNG)
 // ─────────────────────────────────────────────────────────────────────────────
@@ -71,6 +80,11 @@ pub fn quality_from_happiness(happiness: u8) -> ItemQuality {
     }
 }
 
+/// Barn and coop animals eat hay; companions (horse, cat, dog) don't.
+fn eats_hay(kind: AnimalKind) -> bool {
+    !matches!(kind, AnimalKind::Horse | AnimalKind::Cat | AnimalKind::Dog)
+}
+
 fn is_outside_on_farm_tile(logical_pos: Option<&LogicalPosition>) -> bool {
     let Some(lp) = logical_pos else {
         return false;
@@ -95,9 +109,26 @@ pub fn handle_day_end_for_animals(
         Option<&LogicalPosition>,
     )>,
     mut toast_writer: EventWriter<ToastEvent>,
+    mut silo: Option<ResMut<SiloState>>,
 ) {
-    for _event in day_end_events.read() {
+    for event in day_end_events.read() {
+        let mut silo_ran_out = false;
+
         for (entity, mut animal, wool_cd, unfed_days_opt, logical_pos) in animal_query.iter_mut() {
+            // ── 0. Winter silo feeding ───────────────────────────────────────
+            //
+            // There is no grass in winter, so stored hay feeds any animal the
+            // player didn't feed by hand. Companions don't eat hay.
+            if event.season == Season::Winter && !animal.fed_today && eats_hay(animal.kind) {
+                if let Some(silo) = silo.as_mut() {
+                    if silo.take_one() {
+                        animal.fed_today = true;
+                    } else if silo.capacity > 0 {
+                        silo_ran_out = true;
+                    }
+                }
+            }
+
             // ── 1. Track consecutive unfed days ──────────────────────────────
             //
             // Snapshot the previous count before any resets so we can
@@ -121,6 +152,34 @@ pub fn handle_day_end_for_animals(
                 });
             }
 
+            // ── 1b. Illness from neglect ─────────────────────────────────────
+            if animal.sick {
+                animal.days_sick = animal.days_sick.saturating_add(1);
+                if animal.days_sick >= SICK_DAYS_BEFORE_DEATH {
+                    toast_writer.send(ToastEvent {
+                        message: format!(
+                            "{} never recovered from its illness and passed away.",
+                            animal.name
+                        ),
+                        duration_secs: 5.0,
+                    });
+                    commands.entity(entity).despawn_recursive();
+                    continue;
+                }
+            } else if eats_hay(animal.kind) && new_unfed_count >= SICK_AFTER_UNFED_DAYS {
+                // Companions (horse, cat, dog) are never fed at a trough,
+                // so only livestock can fall ill from neglect.
+                animal.sick = true;
+                animal.days_sick = 0;
+                toast_writer.send(ToastEvent {
+                    message: format!(
+                        "{} has fallen ill from neglect! Treat it with Animal Medicine.",
+                        animal.name
+                    ),
+                    duration_secs: 5.0,
+                });
+            }
+
             // ── 2. Happiness adjustments ─────────────────────────────────────
             //
             // All adjustments use saturating arithmetic so happiness stays
@@ -194,6 +253,7 @@ pub fn handle_day_end_for_animals(
             //      the animal is finally fed.
             //   d) Happiness > 0 (a completely miserable animal refuses to
             //      produce even if technically fed).
+            //   e) Not sick.
 
             let fed_today_this_cycle = new_unfed_count == 0;
             // Block fires when the animal was not fed today AND had already been
@@ -204,6 +264,7 @@ pub fn handle_day_end_for_animals(
                 && fed_today_this_cycle
                 && !production_blocked
                 && animal.happiness > 0
+                && !animal.sick
             {
                 // Quality is based on post-adjustment happiness: the animal's
                 // happiness after today's feeding/petting bonuses are applied.
@@ -312,6 +373,13 @@ pub fn handle_day_end_for_animals(
             }
         }
 
+        if silo_ran_out {
+            toast_writer.send(ToastEvent {
+                message: "The silo is out of hay! Some animals went unfed.".to_string(),
+                duration_secs: 4.0,
+            });
+        }
+
         // ── 6. AnimalState resource sync ─────────────────────────────────────
         // sync_animal_state_resource (rendering.rs) rebuilds the Vec<Animal>
         // from ECS every frame, so no manual sync is needed here.
@@ -333,6 +401,8 @@ mod tests {
             fed_today: false,
             petted_today: false,
             product_ready: false,
+            sick: false,
+            days_sick: 0,
         }
     }
 
@@ -365,6 +435,56 @@ mod tests {
         assert_eq!(at.days_old, 7);
     }
 
+    #[test]
+    fn winter_day_end_feeds_animals_from_silo_hay() {
+        let mut app = App::new();
+        app.add_event::<DayEndEvent>();
+        app.add_event::<ToastEvent>();
+        app.add_systems(Update, handle_day_end_for_animals);
+
+        let mut silo = SiloState {
+            hay: 0,
+            capacity: 2,
+        };
+        assert_eq!(silo.store(5), 2, "silo only fills up to capacity");
+        app.insert_resource(silo);
+
+        let mut cow = test_animal(10, 100);
+        cow.kind = AnimalKind::Cow;
+        cow.age = AnimalAge::Adult;
+        let fed = [
+            app.world_mut().spawn(cow.clone()).id(),
+            app.world_mut().spawn(cow.clone()).id(),
+        ];
+
+        app.world_mut().send_event(DayEndEvent {
+            day: 1,
+            season: Season::Winter,
+            year: 1,
+        });
+        app.update();
+
+        assert_eq!(app.world().resource::<SiloState>().hay, 0);
+        for id in fed {
+            let animal = app.world().entity(id).get::<Animal>().unwrap();
+            assert_eq!(animal.happiness, 108, "hay counts as a feeding");
+            assert!(animal.product_ready);
+        }
+
+        // Silo is now empty: the next winter day the cows go unfed.
+        let hungry = app.world_mut().spawn(cow).id();
+        app.world_mut().send_event(DayEndEvent {
+            day: 2,
+            season: Season::Winter,
+            year: 1,
+        });
+        app.update();
+
+        let animal = app.world().entity(hungry).get::<Animal>().unwrap();
+        assert_eq!(animal.happiness, 82);
+        assert!(!animal.product_ready);
+    }
+
     #[test]
     fn outside_animals_get_bounded_happiness_bonus() {
         let mut app = App::new();
@@ -412,4 +532,39 @@ mod tests {
         assert_eq!(outside_happiness, 88);
         assert_eq!(not_outside_happiness, 82);
     }
+
+    #[test]
+    fn unfed_companions_never_fall_ill() {
+        let mut app = App::new();
+        app.add_event::<DayEndEvent>();
+        app.add_event::<ToastEvent>();
+        app.add_systems(Update, handle_day_end_for_animals);
+
+        let companions: Vec<Entity> = [AnimalKind::Dog, AnimalKind::Horse]
+            .into_iter()
+            .map(|kind| {
+                let mut animal = test_animal(10, 200);
+                animal.kind = kind;
+                animal.age = AnimalAge::Adult;
+                app.world_mut().spawn(animal).id()
+            })
+            .collect();
+
+        for day in 1..=10 {
+            app.world_mut().send_event(DayEndEvent {
+                day,
+                season: Season::Spring,
+                year: 1,
+            });
+            app.update();
+        }
+
+        for id in companions {
+            let animal = app
+                .world()
+                .get::<Animal>(id)
+                .expect("an unfed companion should survive ten days");
+            assert!(!animal.sick);
+        }
+    }
 }
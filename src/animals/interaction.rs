@@ -18,8 +18,39 @@ use bevy::prelude::*;
 // accidentally petting while collecting.
 // ─────────────────────────────────────────────────────────────────────────────
 
-const INTERACT_RANGE: f32 = 32.0;
+pub(super) const INTERACT_RANGE: f32 = 32.0;
 
+/// Bought from the animal shop; cures a sick animal when used next to it.
+pub const ANIMAL_MEDICINE_ITEM_ID: &str = "animal_medicine";
+
+/// Luck granted by the daily pet of a cat or dog.
+const PET_LUCK_MAGNITUDE: f32 = 1.1;
+const PET_LUCK_MINUTES: u32 = 120;
+
+/// Petting a cat or dog brings a little luck, unless a luck buff (e.g. from
+/// food) is already active.
+fn grant_pet_luck(active_buffs: &mut ActiveBuffs) {
+    if active_buffs
+        .buffs
+        .iter()
+        .any(|b| b.buff_type == BuffType::Luck)
+    {
+        return;
+    }
+    active_buffs.buffs.push(FoodBuff {
+        buff_type: BuffType::Luck,
+        magnitude: PET_LUCK_MAGNITUDE,
+        minutes_remaining: PET_LUCK_MINUTES,
+    });
+}
+
+/// Nurse a sick animal back to health.
+pub fn treat_animal(animal: &mut Animal) {
+    animal.sick = false;
+    animal.days_sick = 0;
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn handle_animal_interact(
     mut commands: Commands,
     player_input: Res<PlayerInput>,
@@ -27,6 +58,9 @@ pub fn handle_animal_interact(
     player_query: Query<&LogicalPosition, With<Player>>,
     mut animal_query: Query<(Entity, &mut Animal, &LogicalPosition)>,
     mut sfx_writer: EventWriter<PlaySfxEvent>,
+    mut active_buffs: ResMut<ActiveBuffs>,
+    mut inventory: ResMut<Inventory>,
+    mut item_removed_writer: EventWriter<ItemRemovedEvent>,
 ) {
     if input_blocks.is_blocked() {
         return;
@@ -61,18 +95,36 @@ pub fn handle_animal_interact(
             continue;
         }
 
+        // Holding medicine next to a sick animal treats it instead of petting.
+        let holding_medicine = inventory
+            .slots
+            .get(inventory.selected_slot)
+            .and_then(|s| s.as_ref())
+            .is_some_and(|s| s.item_id == ANIMAL_MEDICINE_ITEM_ID);
+        if animal.sick && holding_medicine {
+            if inventory.try_remove(ANIMAL_MEDICINE_ITEM_ID, 1) > 0 {
+                item_removed_writer.send(ItemRemovedEvent {
+                    item_id: ANIMAL_MEDICINE_ITEM_ID.to_string(),
+                    quantity: 1,
+                });
+                treat_animal(&mut animal);
+                spawn_floating_text(
+                    &mut commands,
+                    animal_pos.extend(Z_EFFECTS) + Vec3::new(0.0, 14.0, 0.0),
+                    "Feeling better!",
+                    Color::srgb(0.55, 0.95, 0.6),
+                );
+            }
+            break;
+        }
+
         // Pet the animal.
         if !animal.petted_today {
             let prior_happiness = animal.happiness;
+            // Like feeding, petting only marks the animal as cared for; the
+            // happiness bonus is applied once at day end, before product
+            // quality is decided.
             animal.petted_today = true;
-            // Lower-mood animals get a larger first pet boost.
-            let happiness_bump = match prior_happiness {
-                0..=79 => 12,
-                80..=159 => 8,
-                160..=219 => 4,
-                220..=u8::MAX => 1,
-            };
-            animal.happiness = animal.happiness.saturating_add(happiness_bump);
 
             let pet_text = match (animal.kind, prior_happiness) {
                 (AnimalKind::Chicken, 0..=79) => "Bawk?",
@@ -118,6 +170,10 @@ pub fn handle_animal_interact(
             sfx_writer.send(PlaySfxEvent {
                 sfx_id: "animal_pet".to_string(),
             });
+
+            if matches!(animal.kind, AnimalKind::Cat | AnimalKind::Dog) {
+                grant_pet_luck(&mut active_buffs);
+            }
         } else {
             // Already petted today — give small feedback so player knows.
             let repeat_text = match (animal.kind, animal.happiness) {
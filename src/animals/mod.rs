@@ -9,6 +9,7 @@ mod feeding;
 mod interaction;
 mod movement;
 mod products;
+mod rename;
 mod rendering;
 mod spawning;
 
@@ -17,6 +18,7 @@ pub use feeding::*;
 pub use interaction::*;
 pub use movement::*;
 pub use products::*;
+pub use rename::*;
 pub use rendering::*;
 pub use spawning::*;
 
@@ -42,6 +44,9 @@ pub struct WanderAi {
     pub pen_max: Vec2,
     /// Movement speed in pixels/sec.
     pub speed: f32,
+    /// Tiles to pass through before heading straight for `target`, nearest
+    /// first. Keeps animals from walking into pen walls and fences.
+    pub path: std::collections::VecDeque<(i32, i32)>,
 }
 
 /// Floating text or heart effect spawned on petting / product collection.
@@ -305,6 +310,7 @@ pub struct AnimalPlugin;
 impl Plugin for AnimalPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<AnimalSpriteData>()
+            .init_resource::<AnimalRenamePrompt>()
             // ── startup / loading ────────────────────────────────────────────
             .add_systems(
                 OnEnter(GameState::Playing),
@@ -316,8 +322,14 @@ impl Plugin for AnimalPlugin {
                 (
                     spawn_animals_from_state,
                     handle_animal_purchase,
+                    sync_pen_bounds.before(handle_animal_wander),
                     handle_animal_wander,
+                    handle_pet_follow,
                     handle_animal_interact,
+                    animal_rename_input.before(start_animal_rename),
+                    start_animal_rename,
+                    update_animal_rename_prompt_ui,
+                    sync_feed_trough,
                     handle_feed_trough_interact,
                     handle_product_collection,
                     update_floating_feedback,
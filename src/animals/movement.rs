@@ -1,14 +1,22 @@
 use super::WanderAi;
 use crate::shared::*;
+use crate::world::pathfind::{astar, bfs_nearest_walkable};
+use crate::world::WorldMap;
 use bevy::prelude::*;
 use rand::Rng;
+use std::collections::VecDeque;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Wander AI system
 // Animals pick a random point inside their pen every 2-4 seconds, walk toward
-// it, then idle briefly before picking the next point.
+// it, then idle briefly before picking the next point. The walk follows an A*
+// path so animals go around fences and buildings instead of into them.
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// How close (in pixels) an animal must get to an intermediate path tile
+/// before moving on to the next one.
+const WAYPOINT_RADIUS: f32 = 1.0;
+
 fn stop_radius_for(kind: AnimalKind) -> f32 {
     match kind {
         AnimalKind::Chicken => 0.75,
@@ -39,34 +47,47 @@ fn retarget_secs_for(kind: AnimalKind, rng: &mut impl Rng) -> f32 {
     }
 }
 
+#[allow(clippy::type_complexity)]
 pub fn handle_animal_wander(
     time: Res<Time>,
-    mut query: Query<(
-        &mut LogicalPosition,
-        &mut Transform,
-        &mut WanderAi,
-        &Animal,
-        Option<&mut Facing>,
-    )>,
+    world_map: Res<WorldMap>,
+    player_state: Res<PlayerState>,
+    mut query: Query<
+        (
+            &mut LogicalPosition,
+            &mut Transform,
+            &mut WanderAi,
+            &Animal,
+            Option<&mut Facing>,
+        ),
+        Without<PetFollow>,
+    >,
 ) {
     let mut rng = rand::thread_rng();
+    // WorldMap only describes the farm while the player is on it.
+    let farm_loaded = player_state.current_map == MapId::Farm;
 
     for (mut logical_pos, mut transform, mut wander, animal, facing_opt) in query.iter_mut() {
         // Advance the timer.
         wander.timer.tick(time.delta());
 
         if let Some(target) = wander.target {
-            // Move toward target.
+            // Move toward the next path tile, then the target itself.
             let current = logical_pos.0;
-            let delta = target - current;
+            let (waypoint, stop_radius) = match wander.path.front() {
+                Some(&(x, y)) => (grid_to_world_center(x, y), WAYPOINT_RADIUS),
+                None => (target, stop_radius_for(animal.kind)),
+            };
+            let delta = waypoint - current;
             let dist = delta.length();
-            let stop_radius = stop_radius_for(animal.kind);
 
             if dist < stop_radius {
-                // Arrived — clear target, start idle timer.
-                wander.target = None;
-                let idle_secs = idle_secs_for(animal.kind, &mut rng);
-                wander.timer = Timer::from_seconds(idle_secs, TimerMode::Once);
+                if wander.path.pop_front().is_none() {
+                    // Arrived — clear target, start idle timer.
+                    wander.target = None;
+                    let idle_secs = idle_secs_for(animal.kind, &mut rng);
+                    wander.timer = Timer::from_seconds(idle_secs, TimerMode::Once);
+                }
             } else {
                 // Step toward the target, capped by speed × dt.
                 let step = wander.speed * time.delta_secs();
@@ -104,10 +125,270 @@ pub fn handle_animal_wander(
             // Pick a new wander target inside the pen.
             let tx = rng.gen_range(wander.pen_min.x..=wander.pen_max.x);
             let ty = rng.gen_range(wander.pen_min.y..=wander.pen_max.y);
-            wander.target = Some(Vec2::new(tx, ty));
+            let (pen_min, pen_max) = (wander.pen_min, wander.pen_max);
+            let walkable = |x: i32, y: i32| {
+                let center = grid_to_world_center(x, y);
+                let in_pen = center.x >= pen_min.x
+                    && center.x <= pen_max.x
+                    && center.y >= pen_min.y
+                    && center.y <= pen_max.y;
+                in_pen && (!farm_loaded || world_map.is_walkable(x, y))
+            };
+
+            let start = world_to_grid(logical_pos.0.x, logical_pos.0.y);
+            let picked = world_to_grid(tx, ty);
+            let route = bfs_nearest_walkable((picked.x, picked.y), walkable).and_then(|goal| {
+                let path = astar((start.x, start.y), goal, walkable)?;
+                Some((goal, path))
+            });
+            if let Some((goal, path)) = route {
+                // Aim for the sampled point when it is open ground, else the
+                // centre of the nearest open tile.
+                wander.target = Some(if goal == (picked.x, picked.y) {
+                    Vec2::new(tx, ty)
+                } else {
+                    grid_to_world_center(goal.0, goal.1)
+                });
+                // The final leg goes straight to the target, not the tile centre.
+                wander.path = path.into();
+                wander.path.pop_back();
+            }
 
             let next_secs = retarget_secs_for(animal.kind, &mut rng);
             wander.timer = Timer::from_seconds(next_secs, TimerMode::Once);
         }
     }
 }
+
+/// Re-fence coop and barn animals when their building is moved so they wander
+/// the pen beside its new spot. Any walk in progress is dropped so the next
+/// target is picked inside the new pen.
+pub fn sync_pen_bounds(
+    placements: Res<BuildingPlacements>,
+    mut query: Query<(&Animal, &mut WanderAi)>,
+) {
+    if !placements.is_changed() {
+        return;
+    }
+    for (animal, mut wander) in &mut query {
+        let (pen_min, pen_max) = super::pen_bounds_with(animal.kind, &placements);
+        if wander.pen_min == pen_min && wander.pen_max == pen_max {
+            continue;
+        }
+        wander.pen_min = pen_min;
+        wander.pen_max = pen_max;
+        wander.target = None;
+        wander.path.clear();
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Pet follow AI
+// Cats and dogs trail the player around the farm and farmhouse instead of
+// wandering a pen. They re-path whenever the player changes tile, stop a short
+// distance away, and idle for a moment before following again.
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Pets start following once the player is farther than this (pixels).
+const PET_FOLLOW_START_DIST: f32 = TILE_SIZE * 2.5;
+/// Pets stop this close to the player (pixels).
+const PET_FOLLOW_STOP_DIST: f32 = TILE_SIZE * 1.25;
+
+/// Follow state for a companion pet.
+#[derive(Component, Debug, Clone)]
+pub struct PetFollow {
+    /// Player position the pet is heading for (None = idle).
+    pub target: Option<Vec2>,
+    /// Player tile the current `path` leads to.
+    pub goal_tile: Option<(i32, i32)>,
+    /// Tiles to pass through before heading straight for `target`.
+    pub path: VecDeque<(i32, i32)>,
+    /// Pause after catching up before following again.
+    pub idle_timer: Timer,
+}
+
+impl Default for PetFollow {
+    fn default() -> Self {
+        let mut idle_timer = Timer::from_seconds(1.0, TimerMode::Once);
+        idle_timer.tick(idle_timer.duration());
+        Self {
+            target: None,
+            goal_tile: None,
+            path: VecDeque::new(),
+            idle_timer,
+        }
+    }
+}
+
+/// Maps where pets follow the player.
+fn pet_follows_on(map: MapId) -> bool {
+    matches!(map, MapId::Farm | MapId::PlayerHouse)
+}
+
+pub fn handle_pet_follow(
+    time: Res<Time>,
+    world_map: Res<WorldMap>,
+    player_state: Res<PlayerState>,
+    player_query: Query<&LogicalPosition, (With<Player>, Without<PetFollow>)>,
+    mut pet_query: Query<(
+        &mut LogicalPosition,
+        &mut PetFollow,
+        &WanderAi,
+        &Animal,
+        Option<&mut Facing>,
+    )>,
+) {
+    if !pet_follows_on(player_state.current_map) {
+        return;
+    }
+    let Ok(player_pos) = player_query.get_single() else {
+        return;
+    };
+    let player_pos = player_pos.0;
+    let mut rng = rand::thread_rng();
+    // Without a loaded map (e.g. mid-transition) pets walk straight at the player.
+    let walkable = |x: i32, y: i32| world_map.map_def.is_none() || world_map.is_walkable(x, y);
+
+    for (mut logical_pos, mut follow, wander, animal, facing_opt) in pet_query.iter_mut() {
+        follow.idle_timer.tick(time.delta());
+        let dist_to_player = logical_pos.0.distance(player_pos);
+
+        if follow.target.is_none() {
+            if !follow.idle_timer.finished() || dist_to_player <= PET_FOLLOW_START_DIST {
+                continue;
+            }
+        } else if dist_to_player <= PET_FOLLOW_STOP_DIST {
+            // Caught up — sit for a moment.
+            follow.target = None;
+            follow.goal_tile = None;
+            follow.path.clear();
+            let idle_secs = idle_secs_for(animal.kind, &mut rng);
+            follow.idle_timer = Timer::from_seconds(idle_secs, TimerMode::Once);
+            continue;
+        }
+
+        // Track the player every tick; re-path only when they change tile.
+        follow.target = Some(player_pos);
+        let goal = world_to_grid(player_pos.x, player_pos.y);
+        if follow.goal_tile != Some((goal.x, goal.y)) {
+            let start = world_to_grid(logical_pos.0.x, logical_pos.0.y);
+            follow.path = astar((start.x, start.y), (goal.x, goal.y), walkable)
+                .map(VecDeque::from)
+                .unwrap_or_default();
+            // The final leg goes straight to the player, not the tile centre.
+            follow.path.pop_back();
+            follow.goal_tile = Some((goal.x, goal.y));
+        }
+
+        let waypoint = match follow.path.front() {
+            Some(&(x, y)) => grid_to_world_center(x, y),
+            None => player_pos,
+        };
+        let delta = waypoint - logical_pos.0;
+        let dist = delta.length();
+        if follow.path.front().is_some() && dist < WAYPOINT_RADIUS {
+            follow.path.pop_front();
+            continue;
+        }
+        if dist <= f32::EPSILON {
+            continue;
+        }
+
+        let step = wander.speed * time.delta_secs();
+        let movement = delta / dist * step.min(dist);
+        logical_pos.0 += movement;
+
+        if let Some(mut facing) = facing_opt {
+            if movement.x.abs() > movement.y.abs() {
+                *facing = if movement.x > 0.0 {
+                    Facing::Right
+                } else {
+                    Facing::Left
+                };
+            } else if movement.y.abs() > 0.1 {
+                *facing = if movement.y > 0.0 {
+                    Facing::Up
+                } else {
+                    Facing::Down
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn follow_app(player_at: Vec2) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<WorldMap>()
+            .insert_resource(PlayerState {
+                current_map: MapId::Farm,
+                ..default()
+            })
+            .add_systems(Update, handle_pet_follow);
+        app.world_mut().spawn((Player, LogicalPosition(player_at)));
+        app.world_mut().spawn((
+            Animal {
+                kind: AnimalKind::Dog,
+                name: "Biscuit".into(),
+                age: AnimalAge::Adult,
+                days_old: 10,
+                happiness: 200,
+                fed_today: false,
+                petted_today: false,
+                product_ready: false,
+                sick: false,
+                days_sick: 0,
+            },
+            LogicalPosition(Vec2::ZERO),
+            WanderAi {
+                timer: Timer::from_seconds(1.0, TimerMode::Once),
+                target: None,
+                pen_min: Vec2::ZERO,
+                pen_max: Vec2::ZERO,
+                speed: 40.0,
+                path: VecDeque::new(),
+            },
+            PetFollow::default(),
+        ));
+        app
+    }
+
+    fn pet_target(app: &mut App) -> Option<Vec2> {
+        app.world_mut()
+            .query::<&PetFollow>()
+            .single(app.world())
+            .target
+    }
+
+    #[test]
+    fn pet_target_tracks_player_each_tick() {
+        let start = Vec2::new(TILE_SIZE * 8.0, 0.0);
+        let mut app = follow_app(start);
+        app.update();
+        assert_eq!(pet_target(&mut app), Some(start));
+
+        let moved = Vec2::new(TILE_SIZE * 8.0, TILE_SIZE * 5.0);
+        app.world_mut()
+            .query_filtered::<&mut LogicalPosition, With<Player>>()
+            .single_mut(app.world_mut())
+            .0 = moved;
+        app.update();
+        assert_eq!(pet_target(&mut app), Some(moved));
+    }
+
+    #[test]
+    fn pet_stays_idle_near_player_and_off_pet_maps() {
+        let mut app = follow_app(Vec2::new(TILE_SIZE, 0.0));
+        app.update();
+        assert_eq!(pet_target(&mut app), None);
+
+        let mut app = follow_app(Vec2::new(TILE_SIZE * 8.0, 0.0));
+        app.world_mut().resource_mut::<PlayerState>().current_map = MapId::Town;
+        app.update();
+        assert_eq!(pet_target(&mut app), None);
+    }
+}
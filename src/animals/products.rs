@@ -1,4 +1,4 @@
-use super::day_end::PendingProductQuality;
+use super::day_end::{quality_from_happiness, PendingProductQuality};
 use super::{spawn_floating_text, ProductReadyIndicator};
 use crate::shared::*;
 use bevy::prelude::*;
@@ -8,9 +8,11 @@ use bevy::prelude::*;
 //
 // When the player presses Space near an animal that has product_ready == true:
 //   1. Collect the product — set product_ready = false.
-//   2. Determine quality from PendingProductQuality (written at day-end).
+//   2. Determine quality from PendingProductQuality (written at day-end),
+//      falling back to the animal's current happiness.
 //   3. Send AnimalProductEvent (cross-domain signal for stats/achievements).
-//   4. Send ItemPickupEvent so the inventory domain adds the item.
+//   4. Send ItemPickupEvent so the inventory domain adds the item at that
+//      quality (Inventory::try_add_quality).
 //   5. Send ToastEvent showing the quality label (for non-Normal quality).
 //   6. Send PlaySfxEvent for audio feedback.
 //   7. Spawn floating text above the animal.
@@ -77,11 +79,12 @@ pub fn handle_product_collection(
             AnimalKind::Horse | AnimalKind::Cat | AnimalKind::Dog => continue,
         };
 
-        // Read the quality that was decided at day-end; fall back to Normal if
-        // the component is somehow missing (e.g. save-load edge case).
+        // Read the quality that was decided at day-end; fall back to the
+        // animal's current happiness if the component is missing (e.g. the
+        // product_ready flag was restored from a save).
         let quality = pending_quality
             .map(|pq| pq.quality)
-            .unwrap_or(ItemQuality::Normal);
+            .unwrap_or_else(|| quality_from_happiness(animal.happiness));
 
         // ── Emit cross-domain events ──────────────────────────────────────────
 
@@ -92,10 +95,12 @@ pub fn handle_product_collection(
             product_id: product_id.to_string(),
         });
 
-        // Add the item to the player's inventory.
+        // Add the item to the player's inventory, keeping its quality so
+        // well-cared-for animals yield premium goods.
         pickup_writer.send(ItemPickupEvent {
             item_id: product_id.to_string(),
             quantity: 1,
+            quality,
         });
 
         // Audio cue.
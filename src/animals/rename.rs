@@ -0,0 +1,210 @@
+use super::interaction::INTERACT_RANGE;
+use crate::shared::*;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Renaming
+//
+// Using a name tag (R / RMB) next to an animal opens a one-line prompt seeded
+// with the animal's current name. Enter applies it and spends the tag, Esc
+// cancels. The new name lands on the `Animal` component, which
+// sync_animal_state_resource copies into AnimalState, so saves pick it up.
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub const NAME_TAG_ITEM_ID: &str = "name_tag";
+
+/// The animal being renamed and the text typed so far.
+#[derive(Resource, Debug, Default)]
+pub struct AnimalRenamePrompt {
+    pub target: Option<Entity>,
+    pub input: String,
+}
+
+/// Root node of the on-screen rename prompt.
+#[derive(Component)]
+pub struct AnimalRenamePromptUi;
+
+/// Give an animal a new name, validated like the player's own name.
+pub fn rename_animal(animal: &mut Animal, raw: &str) -> Result<(), String> {
+    animal.name = validate_name(raw)?;
+    Ok(())
+}
+
+pub fn start_animal_rename(
+    player_input: Res<PlayerInput>,
+    mut input_blocks: ResMut<InputBlocks>,
+    mut interaction_claimed: ResMut<InteractionClaimed>,
+    inventory: Res<Inventory>,
+    player_query: Query<&LogicalPosition, With<Player>>,
+    animal_query: Query<(Entity, &Animal, &LogicalPosition)>,
+    mut prompt: ResMut<AnimalRenamePrompt>,
+) {
+    if input_blocks.is_blocked() || !player_input.tool_secondary || interaction_claimed.0 {
+        return;
+    }
+    let holding_tag = inventory
+        .slots
+        .get(inventory.selected_slot)
+        .and_then(|s| s.as_ref())
+        .is_some_and(|s| s.item_id == NAME_TAG_ITEM_ID);
+    if !holding_tag {
+        return;
+    }
+    let Ok(player_lp) = player_query.get_single() else {
+        return;
+    };
+
+    let nearest = animal_query
+        .iter()
+        .map(|(entity, animal, lp)| (entity, animal, player_lp.0.distance(lp.0)))
+        .filter(|(_, _, dist)| *dist <= INTERACT_RANGE)
+        .min_by(|a, b| a.2.total_cmp(&b.2));
+    let Some((entity, animal, _)) = nearest else {
+        return;
+    };
+
+    prompt.target = Some(entity);
+    prompt.input = animal.name.clone();
+    input_blocks.block::<AnimalRenamePrompt>();
+    interaction_claimed.0 = true;
+}
+
+/// While the prompt is open, typed characters edit the name; Enter renames
+/// the animal and spends one name tag.
+pub fn animal_rename_input(
+    mut key_events: EventReader<KeyboardInput>,
+    mut prompt: ResMut<AnimalRenamePrompt>,
+    mut input_blocks: ResMut<InputBlocks>,
+    mut animal_query: Query<&mut Animal>,
+    mut inventory: ResMut<Inventory>,
+    mut item_removed_writer: EventWriter<ItemRemovedEvent>,
+    mut toast_writer: EventWriter<ToastEvent>,
+) {
+    let Some(target) = prompt.target else {
+        key_events.clear();
+        return;
+    };
+
+    let mut close = false;
+    for event in key_events.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter => {
+                let Ok(mut animal) = animal_query.get_mut(target) else {
+                    close = true;
+                    break;
+                };
+                let old_name = animal.name.clone();
+                if let Err(e) = rename_animal(&mut animal, &prompt.input) {
+                    toast_writer.send(ToastEvent {
+                        message: e,
+                        duration_secs: 2.0,
+                    });
+                    continue;
+                }
+                if inventory.try_remove(NAME_TAG_ITEM_ID, 1) > 0 {
+                    item_removed_writer.send(ItemRemovedEvent {
+                        item_id: NAME_TAG_ITEM_ID.to_string(),
+                        quantity: 1,
+                    });
+                }
+                toast_writer.send(ToastEvent {
+                    message: format!("{} is now called {}.", old_name, animal.name),
+                    duration_secs: 2.5,
+                });
+                close = true;
+                break;
+            }
+            Key::Escape => {
+                close = true;
+                break;
+            }
+            Key::Backspace => {
+                prompt.input.pop();
+            }
+            Key::Space => push_name_text(&mut prompt.input, " "),
+            Key::Character(text) => push_name_text(&mut prompt.input, text),
+            _ => {}
+        }
+    }
+
+    if close {
+        prompt.target = None;
+        prompt.input.clear();
+        input_blocks.unblock::<AnimalRenamePrompt>();
+    }
+}
+
+/// Show the prompt while a rename is in progress.
+pub fn update_animal_rename_prompt_ui(
+    mut commands: Commands,
+    prompt: Res<AnimalRenamePrompt>,
+    ui_query: Query<Entity, With<AnimalRenamePromptUi>>,
+    mut text_query: Query<&mut Text, With<AnimalRenamePromptUi>>,
+) {
+    if !prompt.is_changed() {
+        return;
+    }
+    if prompt.target.is_none() {
+        for entity in &ui_query {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    let line = format!("Name: {}_  (Enter to confirm, Esc to cancel)", prompt.input);
+    if let Ok(mut text) = text_query.get_single_mut() {
+        **text = line;
+        return;
+    }
+    commands.spawn((
+        AnimalRenamePromptUi,
+        Text::new(line),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::srgb(1.0, 0.95, 0.8)),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(8.0),
+            bottom: Val::Px(48.0),
+            padding: UiRect::all(Val::Px(6.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+        GlobalZIndex(80),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cow(name: &str) -> Animal {
+        Animal {
+            kind: AnimalKind::Cow,
+            name: name.to_string(),
+            age: AnimalAge::Adult,
+            days_old: 10,
+            happiness: 120,
+            fed_today: false,
+            petted_today: false,
+            product_ready: false,
+            sick: false,
+            days_sick: 0,
+        }
+    }
+
+    #[test]
+    fn rename_trims_and_rejects_blank_names() {
+        let mut animal = cow("Cow");
+        assert!(rename_animal(&mut animal, "  Clover ").is_ok());
+        assert_eq!(animal.name, "Clover");
+        assert!(rename_animal(&mut animal, "   ").is_err());
+        assert_eq!(animal.name, "Clover");
+    }
+}
@@ -1,4 +1,4 @@
-use super::{AnimalAnimTimer, AnimalSpriteData, WanderAi};
+use super::{AnimalAnimTimer, AnimalSpriteData, PetFollow, WanderAi};
 use crate::shared::*;
 use bevy::prelude::*;
 use rand::Rng;
@@ -91,6 +91,28 @@ pub fn pen_bounds_for(kind: AnimalKind) -> (Vec2, Vec2) {
     }
 }
 
+/// The building an animal lives in, or `None` for pets that roam freely.
+pub fn housing_building(kind: AnimalKind) -> Option<BuildingKind> {
+    match kind {
+        AnimalKind::Chicken | AnimalKind::Duck | AnimalKind::Rabbit => Some(BuildingKind::Coop),
+        AnimalKind::Cow | AnimalKind::Sheep | AnimalKind::Goat | AnimalKind::Pig => {
+            Some(BuildingKind::Barn)
+        }
+        AnimalKind::Horse | AnimalKind::Cat | AnimalKind::Dog => None,
+    }
+}
+
+/// Pen boundaries once the coop or barn may have been moved: the pen keeps
+/// its position relative to the building that houses the animal.
+pub fn pen_bounds_with(kind: AnimalKind, placements: &BuildingPlacements) -> (Vec2, Vec2) {
+    let (pen_min, pen_max) = pen_bounds_for(kind);
+    let (dx, dy) = housing_building(kind)
+        .map(|building| placements.offset(building))
+        .unwrap_or((0, 0));
+    let shift = Vec2::new(dx as f32 * TILE_SIZE, dy as f32 * TILE_SIZE);
+    (pen_min + shift, pen_max + shift)
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Item IDs that trigger animal purchase
 // ─────────────────────────────────────────────────────────────────────────────
@@ -150,7 +172,7 @@ fn animation_profile(kind: AnimalKind) -> (f32, usize) {
     }
 }
 
-fn pen_spawn_position(kind: AnimalKind, slot: usize) -> Vec2 {
+fn pen_spawn_position(kind: AnimalKind, slot: usize, placements: &BuildingPlacements) -> Vec2 {
     let (gx, gy) = match kind {
         AnimalKind::Chicken | AnimalKind::Duck | AnimalKind::Rabbit => {
             // Spawn in coop yard (south of coop building)
@@ -168,7 +190,10 @@ fn pen_spawn_position(kind: AnimalKind, slot: usize) -> Vec2 {
         }
     };
 
-    let world = grid_to_world_center(gx, gy);
+    let (dx, dy) = housing_building(kind)
+        .map(|building| placements.offset(building))
+        .unwrap_or((0, 0));
+    let world = grid_to_world_center(gx + dx, gy + dy);
     Vec2::new(world.x, world.y)
 }
 
@@ -183,6 +208,8 @@ fn starter_herd() -> Vec<Animal> {
             fed_today: true,
             petted_today: false,
             product_ready: false,
+            sick: false,
+            days_sick: 0,
         },
         Animal {
             kind: AnimalKind::Duck,
@@ -193,6 +220,8 @@ fn starter_herd() -> Vec<Animal> {
             fed_today: true,
             petted_today: false,
             product_ready: false,
+            sick: false,
+            days_sick: 0,
         },
         Animal {
             kind: AnimalKind::Cow,
@@ -203,6 +232,8 @@ fn starter_herd() -> Vec<Animal> {
             fed_today: true,
             petted_today: false,
             product_ready: false,
+            sick: false,
+            days_sick: 0,
         },
         Animal {
             kind: AnimalKind::Sheep,
@@ -213,6 +244,8 @@ fn starter_herd() -> Vec<Animal> {
             fed_today: true,
             petted_today: false,
             product_ready: false,
+            sick: false,
+            days_sick: 0,
         },
     ]
 }
@@ -222,10 +255,11 @@ fn spawn_animal_entity(
     sprite_data: &AnimalSpriteData,
     animal_data: Animal,
     spawn_pos: Vec2,
+    placements: &BuildingPlacements,
 ) {
     let kind = animal_data.kind;
     let vis = animal_visual(kind);
-    let (pen_min, pen_max) = pen_bounds_for(kind);
+    let (pen_min, pen_max) = pen_bounds_with(kind, placements);
 
     // Stagger initial wander timing slightly so the herd doesn't move in lockstep.
     let wander_duration = 2.0 + ((animal_data.days_old as f32) % 3.0);
@@ -372,11 +406,17 @@ fn spawn_animal_entity(
                 pen_min,
                 pen_max,
                 speed: animal_speed(kind),
+                path: Default::default(),
             },
             Facing::Down,
         ))
         .id();
 
+    // Cats and dogs are companions that trail the player instead of wandering.
+    if matches!(kind, AnimalKind::Cat | AnimalKind::Dog) {
+        commands.entity(entity).insert(PetFollow::default());
+    }
+
     let (anim_period, anim_frames) = animation_profile(kind);
     commands.entity(entity).insert(AnimalAnimTimer {
         timer: Timer::from_seconds(anim_period, TimerMode::Repeating),
@@ -401,6 +441,7 @@ pub fn spawn_animals_from_state(
     mut commands: Commands,
     mut animal_state: ResMut<AnimalState>,
     sprite_data: Res<AnimalSpriteData>,
+    placements: Res<BuildingPlacements>,
     animal_query: Query<&Animal>,
 ) {
     if !animal_query.is_empty() {
@@ -419,8 +460,8 @@ pub fn spawn_animals_from_state(
         animal_state.barn_level = 1;
 
         for (slot, animal) in starter_herd().into_iter().enumerate() {
-            let spawn_pos = pen_spawn_position(animal.kind, slot);
-            spawn_animal_entity(&mut commands, &sprite_data, animal, spawn_pos);
+            let spawn_pos = pen_spawn_position(animal.kind, slot, &placements);
+            spawn_animal_entity(&mut commands, &sprite_data, animal, spawn_pos, &placements);
         }
         return;
     }
@@ -448,8 +489,8 @@ pub fn spawn_animals_from_state(
             }
         };
 
-        let spawn_pos = pen_spawn_position(animal.kind, slot);
-        spawn_animal_entity(&mut commands, &sprite_data, animal, spawn_pos);
+        let spawn_pos = pen_spawn_position(animal.kind, slot, &placements);
+        spawn_animal_entity(&mut commands, &sprite_data, animal, spawn_pos, &placements);
     }
 }
 
@@ -484,12 +525,14 @@ fn generate_animal_name(kind: AnimalKind, rng: &mut impl Rng) -> String {
 // System: listen for ShopTransactionEvent and spawn animals on purchase
 // ─────────────────────────────────────────────────────────────────────────────
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_animal_purchase(
     mut commands: Commands,
     mut shop_events: EventReader<ShopTransactionEvent>,
     animal_state: Res<AnimalState>,
     mut sfx_writer: EventWriter<PlaySfxEvent>,
     sprite_data: Res<AnimalSpriteData>,
+    placements: Res<BuildingPlacements>,
     animal_query: Query<&Animal>,
     mut toast_writer: EventWriter<ToastEvent>,
 ) {
@@ -587,7 +630,7 @@ pub fn handle_animal_purchase(
 
         let name = generate_animal_name(kind, &mut rng);
 
-        let (pen_min, pen_max) = pen_bounds_for(kind);
+        let (pen_min, pen_max) = pen_bounds_with(kind, &placements);
         let spawn_pos = Vec2::new(
             rng.gen_range(pen_min.x..=pen_max.x),
             rng.gen_range(pen_min.y..=pen_max.y),
@@ -602,9 +645,17 @@ pub fn handle_animal_purchase(
             fed_today: false,
             petted_today: false,
             product_ready: false,
+            sick: false,
+            days_sick: 0,
         };
 
-        spawn_animal_entity(&mut commands, &sprite_data, animal_data, spawn_pos);
+        spawn_animal_entity(
+            &mut commands,
+            &sprite_data,
+            animal_data,
+            spawn_pos,
+            &placements,
+        );
         spawned_this_frame += 1;
 
         sfx_writer.send(PlaySfxEvent {
@@ -619,7 +670,7 @@ pub fn handle_animal_purchase(
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
-// System: spawn the feed trough at a fixed position on the farm.
+// System: spawn the feed trough beside the barn on the farm.
 // ─────────────────────────────────────────────────────────────────────────────
 
 pub fn setup_feed_trough(
@@ -627,6 +678,7 @@ pub fn setup_feed_trough(
     asset_server: Res<AssetServer>,
     mut layouts: ResMut<Assets<TextureAtlasLayout>>,
     mut furniture: ResMut<crate::world::objects::FurnitureAtlases>,
+    placements: Res<BuildingPlacements>,
     existing: Query<Entity, With<super::FeedTrough>>,
 ) {
     // Guard against re-entry (e.g. Playing → Cutscene → Playing).
@@ -660,20 +712,45 @@ pub fn setup_feed_trough(
     };
 
     // Trough sits at the entrance of the barn area. Grid position (5, 19) — south of barn entrance
-    // in a 16-px grid → world (80.0, 304.0).
+    // in a 16-px grid → world (80.0, 304.0) — shifted by however far the barn has moved.
+    let (grid_x, grid_y) = placements.feed_trough();
+    let pos = feed_trough_world_pos(grid_x, grid_y);
     commands.spawn((
-        super::FeedTrough {
-            grid_x: 5,
-            grid_y: 19,
-        },
+        super::FeedTrough { grid_x, grid_y },
         sprite,
-        Transform::from_xyz(5.0 * TILE_SIZE, 19.0 * TILE_SIZE, Z_ENTITY_BASE),
-        LogicalPosition(Vec2::new(5.0 * TILE_SIZE, 19.0 * TILE_SIZE)),
+        Transform::from_xyz(pos.x, pos.y, Z_ENTITY_BASE),
+        LogicalPosition(pos),
         YSorted,
         Visibility::default(),
     ));
 }
 
+fn feed_trough_world_pos(grid_x: i32, grid_y: i32) -> Vec2 {
+    Vec2::new(grid_x as f32 * TILE_SIZE, grid_y as f32 * TILE_SIZE)
+}
+
+/// Keep the feed trough beside the barn after the barn is moved.
+pub fn sync_feed_trough(
+    placements: Res<BuildingPlacements>,
+    mut query: Query<(&mut super::FeedTrough, &mut Transform, &mut LogicalPosition)>,
+) {
+    if !placements.is_changed() {
+        return;
+    }
+    let (grid_x, grid_y) = placements.feed_trough();
+    let pos = feed_trough_world_pos(grid_x, grid_y);
+    for (mut trough, mut transform, mut logical) in &mut query {
+        if trough.grid_x == grid_x && trough.grid_y == grid_y {
+            continue;
+        }
+        trough.grid_x = grid_x;
+        trough.grid_y = grid_y;
+        transform.translation.x = pos.x;
+        transform.translation.y = pos.y;
+        logical.0 = pos;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -683,6 +760,7 @@ mod tests {
         let mut app = App::new();
         app.init_resource::<AnimalState>();
         app.init_resource::<AnimalSpriteData>();
+        app.init_resource::<BuildingPlacements>();
         app.add_systems(Update, spawn_animals_from_state);
 
         app.update();
@@ -720,6 +798,8 @@ mod tests {
                 fed_today: true,
                 petted_today: false,
                 product_ready: false,
+                sick: false,
+                days_sick: 0,
             }],
             has_coop: false,
             has_barn: true,
@@ -727,6 +807,7 @@ mod tests {
             barn_level: 1,
         });
         app.init_resource::<AnimalSpriteData>();
+        app.init_resource::<BuildingPlacements>();
         app.add_systems(Update, spawn_animals_from_state);
 
         app.update();
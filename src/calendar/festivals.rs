@@ -41,6 +41,9 @@ pub struct FestivalState {
     /// Tracks whether the festival announcement toast was already sent
     /// for the current day so we don't spam.
     pub announced_day: Option<(Season, u8, u32)>,
+    /// Whether the player has been counted as attending today's festival.
+    #[serde(default)]
+    pub attended: bool,
 }
 
 impl FestivalState {
@@ -63,6 +66,22 @@ impl FestivalState {
     }
 }
 
+/// Eggs hidden at the start of the Egg Hunt.
+pub const EGG_HUNT_EGG_COUNT: u32 = 20;
+/// Eggs needed to win the Egg Hunt prize.
+pub const EGG_HUNT_PRIZE_THRESHOLD: u32 = 15;
+/// Prize awarded for winning the Egg Hunt.
+pub const EGG_HUNT_PRIZE_ITEM: &str = "ancient_seeds";
+/// The Egg Festival is hosted by the mayor, who warms to a winning hunter.
+pub const EGG_HUNT_HOST: &str = "mayor_rex";
+
+/// Festival grounds are open from this hour...
+pub const FESTIVAL_OPEN_HOUR: u8 = 9;
+/// ...until this hour.
+pub const FESTIVAL_CLOSE_HOUR: u8 = 22;
+/// Friendship each villager at the festival gains when the player shows up.
+pub const FESTIVAL_ATTENDANCE_FRIENDSHIP: i32 = 30;
+
 /// Marker component for egg entities spawned during the Egg Festival.
 #[derive(Component, Debug, Clone)]
 pub struct FestivalEgg;
@@ -90,6 +109,15 @@ fn festival_display_name(kind: FestivalKind) -> &'static str {
     }
 }
 
+/// Where each festival is held.
+pub fn festival_map(kind: FestivalKind) -> MapId {
+    match kind {
+        FestivalKind::EggFestival => MapId::Farm,
+        FestivalKind::Luau => MapId::Beach,
+        FestivalKind::HarvestFestival | FestivalKind::WinterStar => MapId::Town,
+    }
+}
+
 fn festival_preview_for_date(season: Season, day: u8) -> Option<(FestivalKind, u8)> {
     if let Some(kind) = festival_for_date(season, day) {
         return Some((kind, 0));
@@ -146,6 +174,7 @@ pub fn check_festival_day(
             festival.score = 0;
             festival.items_collected = 0;
             festival.timer = None;
+            festival.attended = false;
         }
 
         // Send announcement toast once per day.
@@ -171,6 +200,7 @@ pub fn check_festival_day(
             festival.timer = None;
             festival.winter_star_recipient = None;
             festival.winter_star_giver = None;
+            festival.attended = false;
         }
 
         if festival.announced_day != Some(today) {
@@ -200,13 +230,59 @@ pub fn check_festival_day(
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════
+// SYSTEM: mark_festival_attendance
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Counts the player as attending once they are on the festival map while
+/// the grounds are open. Each festival is counted once, and every villager
+/// present gains a little friendship.
+pub fn mark_festival_attendance(
+    calendar: Res<Calendar>,
+    player_state: Res<PlayerState>,
+    mut festival: ResMut<FestivalState>,
+    mut play_stats: ResMut<PlayStats>,
+    mut relationships: ResMut<Relationships>,
+    npcs: Query<&Npc>,
+    mut toast_writer: EventWriter<ToastEvent>,
+) {
+    let Some(kind) = festival.active else {
+        return;
+    };
+    if festival.attended
+        || player_state.current_map != festival_map(kind)
+        || !(FESTIVAL_OPEN_HOUR..FESTIVAL_CLOSE_HOUR).contains(&calendar.hour)
+    {
+        return;
+    }
+
+    festival.attended = true;
+    play_stats.festivals_attended += 1;
+    for npc in &npcs {
+        relationships.add_friendship(&npc.id, FESTIVAL_ATTENDANCE_FRIENDSHIP);
+    }
+
+    toast_writer.send(ToastEvent {
+        message: format!(
+            "Welcome to the {}! The villagers are glad you came.",
+            festival_display_name(kind)
+        ),
+        duration_secs: 4.0,
+    });
+    info!(
+        "[Festivals] Attended {} ({} total)",
+        festival_display_name(kind),
+        play_stats.festivals_attended
+    );
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // SYSTEM: start_egg_hunt  (Spring 13, Farm map, press E)
 // ═══════════════════════════════════════════════════════════════════════
 
 /// When the player presses E on the Farm during the Egg Festival and
-/// the hunt hasn't started yet, spawn 20 collectible eggs and start a
-/// 30-second timer.
+/// the hunt hasn't started yet, spawn `EGG_HUNT_EGG_COUNT` collectible eggs
+/// and start a 30-second timer.
 pub fn start_egg_hunt(
     player_input: Res<PlayerInput>,
     mut festival: ResMut<FestivalState>,
@@ -237,7 +313,7 @@ pub fn start_egg_hunt(
     // Spawn 20 egg entities at random positions within the farm area.
     let egg_image: Handle<Image> = asset_server.load("sprites/egg_item.png");
     let mut rng = rand::thread_rng();
-    for _ in 0..20 {
+    for _ in 0..EGG_HUNT_EGG_COUNT {
         // Range clamped to -6..6 tiles to keep eggs within the festival egg hunt area.
         let x = rng.gen_range(-6..6) as f32 * TILE_SIZE;
         let y = rng.gen_range(-6..6) as f32 * TILE_SIZE;
@@ -257,7 +333,10 @@ pub fn start_egg_hunt(
         duration_secs: 4.0,
     });
 
-    info!("[Festivals] Egg Hunt started — 20 eggs spawned.");
+    info!(
+        "[Festivals] Egg Hunt started — {} eggs spawned.",
+        EGG_HUNT_EGG_COUNT
+    );
 }
 
 // ═══════════════════════════════════════════════════════════════════════
@@ -265,7 +344,9 @@ pub fn start_egg_hunt(
 // ═══════════════════════════════════════════════════════════════════════
 
 /// Checks player proximity to each egg entity; despawns collected eggs
-/// and awards the player when the timer expires.
+/// and scores the hunt when the timer expires.  Reaching
+/// `EGG_HUNT_PRIZE_THRESHOLD` eggs wins the prize and friendship with the
+/// festival host.
 #[allow(clippy::too_many_arguments)]
 pub fn collect_eggs(
     time: Res<Time>,
@@ -273,6 +354,8 @@ pub fn collect_eggs(
     player_query: Query<&Transform, With<Player>>,
     egg_query: Query<(Entity, &Transform), With<FestivalEgg>>,
     mut commands: Commands,
+    mut relationships: ResMut<Relationships>,
+    item_registry: Res<ItemRegistry>,
     mut toast_writer: EventWriter<ToastEvent>,
     mut sfx_writer: EventWriter<PlaySfxEvent>,
     mut pickup_writer: EventWriter<ItemPickupEvent>,
@@ -317,20 +400,41 @@ pub fn collect_eggs(
     // Check if timer expired.
     if timer_finished {
         let collected = festival.items_collected;
+        festival.score = collected;
 
         toast_writer.send(ToastEvent {
-            message: format!("You found {} eggs!", collected),
+            message: format!(
+                "Time's up! You found {} of {} eggs.",
+                collected, EGG_HUNT_EGG_COUNT
+            ),
             duration_secs: 4.0,
         });
 
-        // Prize: finding 15 or more eggs awards a rare seed.
-        if collected >= 15 {
+        if collected >= EGG_HUNT_PRIZE_THRESHOLD {
             pickup_writer.send(ItemPickupEvent {
-                item_id: "rare_seed".into(),
+                item_id: EGG_HUNT_PRIZE_ITEM.into(),
                 quantity: 1,
+                quality: ItemQuality::Normal,
+            });
+            relationships.add_friendship(EGG_HUNT_HOST, FRIENDSHIP_PER_HEART as i32);
+
+            let prize_name = item_registry
+                .get(EGG_HUNT_PRIZE_ITEM)
+                .map(|def| def.name.as_str())
+                .unwrap_or("a prize");
+            toast_writer.send(ToastEvent {
+                message: format!("You won the Egg Hunt! Mayor Rex awards you {}.", prize_name),
+                duration_secs: 4.0,
+            });
+            sfx_writer.send(PlaySfxEvent {
+                sfx_id: "festival_complete".into(),
             });
+        } else {
             toast_writer.send(ToastEvent {
-                message: "Amazing! You won a Rare Seed as a prize!".into(),
+                message: format!(
+                    "Find {} eggs next year to win the prize!",
+                    EGG_HUNT_PRIZE_THRESHOLD
+                ),
                 duration_secs: 4.0,
             });
         }
@@ -718,6 +822,7 @@ pub fn winter_star_give_gift(
     pickup_writer.send(ItemPickupEvent {
         item_id: gift_item.into(),
         quantity: 1,
+        quality: ItemQuality::Normal,
     });
 
     toast_writer.send(ToastEvent {
@@ -758,6 +863,7 @@ pub fn cleanup_festival_on_day_end(
             festival.items_collected = 0;
             festival.winter_star_recipient = None;
             festival.winter_star_giver = None;
+            festival.attended = false;
 
             // Despawn any leftover eggs.
             for entity in egg_query.iter() {
@@ -14,6 +14,8 @@
 //! - Added `PreviousDayWeather` resource: stores the weather of the day that just
 //!   ended so that farming (and other domains) can check if it rained on the ENDED
 //!   day rather than reading calendar.weather (which is already rolled for the new day).
+//!   The resource lives in `crate::shared` so farming can read it without importing
+//!   this domain.
 //! - Fixed `process_day_end` to advance the calendar when DayEndEvent arrives from
 //!   an external source (e.g. the new sleep trigger). Previously only the 2 AM
 //!   auto-trigger in `tick_time` advanced the calendar; external DayEndEvents left
@@ -27,22 +29,6 @@ use rand::Rng;
 use crate::shared::*;
 use festivals::FestivalState;
 
-/// Stores the weather of the most recently ended day so other domains can
-/// check whether it rained *today* (the ended day) rather than tomorrow.
-/// Updated every time a DayEndEvent is processed.
-#[derive(Resource, Debug, Clone)]
-pub struct PreviousDayWeather {
-    pub weather: Weather,
-}
-
-impl Default for PreviousDayWeather {
-    fn default() -> Self {
-        Self {
-            weather: Weather::Sunny,
-        }
-    }
-}
-
 pub struct CalendarPlugin;
 
 impl Plugin for CalendarPlugin {
@@ -85,6 +71,7 @@ impl Plugin for CalendarPlugin {
                 Update,
                 (
                     festivals::check_festival_day,
+                    festivals::mark_festival_attendance,
                     festivals::start_egg_hunt,
                     festivals::collect_eggs,
                     festivals::start_luau,
@@ -126,11 +113,13 @@ fn pause_time(mut calendar: ResMut<Calendar>) {
 /// 2 AM rollover.  Sends a DayEndEvent which process_day_end will pick up to
 /// advance the calendar, and all other domains (farming, economy, etc.) will
 /// process their end-of-day logic.
+#[allow(clippy::too_many_arguments)]
 pub fn trigger_sleep(
     player_input: Res<PlayerInput>,
     calendar: Res<Calendar>,
     player_state: Res<PlayerState>,
     mut day_end_events: EventWriter<DayEndEvent>,
+    mut well_rested: ResMut<WellRestedPending>,
     interaction_claimed: Res<InteractionClaimed>,
     mut cutscene_queue: ResMut<CutsceneQueue>,
     player_query: Query<&GridPosition, With<Player>>,
@@ -190,6 +179,9 @@ pub fn trigger_sleep(
         calendar.year
     };
 
+    // Going to bed before midnight earns tomorrow's well-rested bonus.
+    well_rested.0 = calendar.hour < 24;
+
     // Send DayEndEvent — all backend systems (farming, economy, etc.) process
     // their end-of-day logic this frame, while we're still in Playing state.
     day_end_events.send(DayEndEvent {
@@ -255,6 +247,7 @@ fn tick_time(
     mut day_end_writer: EventWriter<DayEndEvent>,
     mut prev_weather: ResMut<PreviousDayWeather>,
     mut cutscene_queue: ResMut<CutsceneQueue>,
+    mut collapse: ResMut<CollapsePending>,
 ) {
     let delta = time.delta_secs();
     calendar.elapsed_real_seconds += delta;
@@ -276,6 +269,11 @@ fn tick_time(
         advance_one_minute(&mut calendar, &mut day_end_writer, &mut prev_weather);
     }
 
+    // Reaching 2 AM still awake counts as collapsing from exhaustion.
+    if calendar.day != day_before {
+        collapse.0 = true;
+    }
+
     // If the day changed during this tick (auto 2AM rollover), build a
     // cutscene transition so the player sees a day card instead of the
     // calendar silently advancing.
@@ -408,7 +406,7 @@ fn trigger_day_end(
 ///
 /// In both cases we store the ended day's weather in PreviousDayWeather so farming
 /// can check whether it rained on the day that just ended (not the new day).
-fn process_day_end(
+pub fn process_day_end(
     mut day_end_reader: EventReader<DayEndEvent>,
     mut season_writer: EventWriter<SeasonChangeEvent>,
     mut calendar: ResMut<Calendar>,
@@ -569,37 +567,53 @@ fn detect_festival_day(
 
 // ─── Weather rolling ──────────────────────────────────────────────────────────
 
+/// Late-night warnings as (time of day, message). 25.0 = 1:00 AM.
+const LATE_NIGHT_WARNINGS: [(f32, &str); 4] = [
+    (
+        22.0,
+        "It's getting late. Head home. The farmhouse is waiting for you.",
+    ),
+    (24.0, "You're exhausted! Get to bed before you collapse!"),
+    (
+        25.0,
+        "It's 1 AM. You'll collapse at 2 AM and lose some gold!",
+    ),
+    (25.5, "It's 1:30 AM! Sleep now or collapse in half an hour!"),
+];
+
 /// Warn the player when it gets late. Uses Local flags to fire each warning only once per day.
+/// Only the latest passed threshold is announced, so loading a save at 1:45 AM
+/// doesn't replay the whole evening's worth of warnings.
 fn time_warnings(
     calendar: Res<Calendar>,
     mut toast_events: EventWriter<ToastEvent>,
-    mut warned_10pm: Local<bool>,
-    mut warned_midnight: Local<bool>,
+    mut warned: Local<[bool; LATE_NIGHT_WARNINGS.len()]>,
     mut last_day: Local<u8>,
 ) {
     // Reset warnings on new day
     if calendar.day != *last_day {
-        *warned_10pm = false;
-        *warned_midnight = false;
+        *warned = [false; LATE_NIGHT_WARNINGS.len()];
         *last_day = calendar.day;
     }
 
-    if calendar.hour >= 22 && !*warned_10pm {
-        *warned_10pm = true;
-        toast_events.send(ToastEvent {
-            message: "It's getting late. Head home. The farmhouse is waiting for you."
-                .into(),
-            duration_secs: 4.0,
-        });
+    let now = calendar.time_float();
+    let Some(latest) = LATE_NIGHT_WARNINGS
+        .iter()
+        .rposition(|(threshold, _)| now >= *threshold)
+    else {
+        return;
+    };
+    if warned[latest] {
+        return;
     }
-
-    if calendar.hour >= 24 && !*warned_midnight {
-        *warned_midnight = true;
-        toast_events.send(ToastEvent {
-            message: "You're exhausted! Get to bed before you collapse!".into(),
-            duration_secs: 4.0,
-        });
+    for flag in warned.iter_mut().take(latest + 1) {
+        *flag = true;
     }
+
+    toast_events.send(ToastEvent {
+        message: LATE_NIGHT_WARNINGS[latest].1.into(),
+        duration_secs: 4.0,
+    });
 }
 
 /// Rolls a weather result for the given season using weighted probabilities.
@@ -858,4 +872,41 @@ mod tests {
             assert_ne!(w, Weather::Snowy, "Summer should never produce snow");
         }
     }
+
+    fn warnings_at(app: &mut App, hour: u8, minute: u8) -> Vec<String> {
+        {
+            let mut calendar = app.world_mut().resource_mut::<Calendar>();
+            calendar.hour = hour;
+            calendar.minute = minute;
+        }
+        app.update();
+        let events = app.world().resource::<Events<ToastEvent>>();
+        events
+            .iter_current_update_events()
+            .map(|t| t.message.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_late_night_warnings_fire_once_per_threshold() {
+        let mut app = App::new();
+        app.init_resource::<Calendar>()
+            .add_event::<ToastEvent>()
+            .add_systems(Update, time_warnings);
+
+        assert!(warnings_at(&mut app, 20, 0).is_empty());
+        assert_eq!(warnings_at(&mut app, 22, 0).len(), 1);
+        assert_eq!(warnings_at(&mut app, 24, 10).len(), 1);
+        assert!(warnings_at(&mut app, 24, 50).is_empty());
+
+        let one_am = warnings_at(&mut app, 25, 0);
+        assert_eq!(one_am.len(), 1);
+        assert!(one_am[0].contains("1 AM"));
+        assert!(warnings_at(&mut app, 25, 10).is_empty());
+
+        let half_past = warnings_at(&mut app, 25, 30);
+        assert_eq!(half_past.len(), 1);
+        assert!(half_past[0].contains("1:30 AM"));
+        assert!(warnings_at(&mut app, 25, 45).is_empty());
+    }
 }
@@ -70,18 +70,66 @@ pub struct CraftItemEvent {
 // SYSTEMS
 // ──────────────────────────────────────────────────────────────────────────────
 
+/// How close the player has to be to a bench or stove to use it.
+const STATION_REACH: f32 = TILE_SIZE * 1.5;
+
+/// Whether a player at `pos` facing `facing` can reach a station for the given
+/// mode: a crafting bench for crafting, a kitchen stove for cooking.
+pub fn station_in_reach(
+    pos: Vec2,
+    facing: Facing,
+    cooking_mode: bool,
+    stations: impl IntoIterator<Item = (InteractionKind, Vec2)>,
+) -> bool {
+    let wanted = if cooking_mode {
+        InteractionKind::KitchenStove
+    } else {
+        InteractionKind::CraftingBench
+    };
+    stations.into_iter().any(|(kind, station_pos)| {
+        kind == wanted && can_interact_facing(pos, facing, station_pos, STATION_REACH)
+    })
+}
+
 /// Runs in Playing — listens for OpenCraftingEvent and transitions to Crafting state.
 /// Populates the CraftingUiState with the list of unlocked recipes.
+/// The player must be at a crafting bench (or a stove, for cooking).
+#[allow(clippy::too_many_arguments)]
 pub fn handle_open_crafting(
     mut events: EventReader<OpenCraftingEvent>,
     mut next_state: ResMut<NextState<GameState>>,
     mut ui_state: ResMut<CraftingUiState>,
     unlocked: Res<UnlockedRecipes>,
     recipe_registry: Res<RecipeRegistry>,
+    player_query: Query<(&LogicalPosition, &PlayerMovement), With<Player>>,
+    station_query: Query<(&Interactable, &Transform)>,
+    mut toast_events: EventWriter<ToastEvent>,
 ) {
     for event in events.read() {
         let cooking_mode = event.cooking_mode;
 
+        let at_station = player_query.get_single().is_ok_and(|(pos, movement)| {
+            station_in_reach(
+                pos.0,
+                movement.facing,
+                cooking_mode,
+                station_query
+                    .iter()
+                    .map(|(i, tf)| (i.kind, tf.translation.truncate())),
+            )
+        });
+        if !at_station {
+            toast_events.send(ToastEvent {
+                message: if cooking_mode {
+                    "You need to be at a stove to cook.".into()
+                } else {
+                    "You need to be at a crafting bench to craft.".into()
+                },
+                duration_secs: 2.0,
+            });
+            continue;
+        }
+
         // Build the list of available recipes filtered by:
         //   1. Unlocked
         //   2. Cooking/crafting mode match
@@ -196,6 +244,7 @@ pub fn handle_craft_item(
         pickup_events.send(ItemPickupEvent {
             item_id: recipe.result.clone(),
             quantity: recipe.result_quantity,
+            quality: ItemQuality::Normal,
         });
         *achievements
             .progress
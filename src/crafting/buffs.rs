@@ -256,9 +256,18 @@ pub fn tick_buff_durations(
 
     let current_minute = calendar_absolute_minute(&calendar);
 
+    // Sleeping skips the night: on a new day, count from 6 AM rather than
+    // from bedtime, so buffs granted on waking keep their full duration.
+    let day_start = current_minute - (calendar.hour as u32 * 60 + calendar.minute as u32);
+    let baseline = if *last_minute < day_start {
+        day_start + 6 * 60
+    } else {
+        *last_minute
+    };
+
     // How many game-minutes have elapsed since the last frame we processed?
-    let elapsed = if current_minute >= *last_minute {
-        current_minute - *last_minute
+    let elapsed = if current_minute >= baseline {
+        current_minute - baseline
     } else {
         // Handle wrap-around (new day / year rollover).
         1
@@ -411,3 +420,61 @@ fn item_display_name<'a>(registry: &'a ItemRegistry, item_id: &'a str) -> String
                 .join(" ")
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_time(app: &mut App, day: u8, hour: u8) {
+        let mut calendar = app.world_mut().resource_mut::<Calendar>();
+        calendar.day = day;
+        calendar.hour = hour;
+        calendar.minute = 0;
+    }
+
+    #[test]
+    fn buffs_granted_on_waking_do_not_lose_the_night() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<Calendar>()
+            .init_resource::<ActiveBuffs>()
+            .add_event::<ToastEvent>()
+            .add_systems(Update, tick_buff_durations);
+
+        // A food buff running at bedtime sets the ticker's baseline.
+        app.world_mut()
+            .resource_mut::<ActiveBuffs>()
+            .buffs
+            .push(FoodBuff {
+                buff_type: BuffType::Speed,
+                magnitude: 1.2,
+                minutes_remaining: 600,
+            });
+        set_time(&mut app, 1, 22);
+        app.update();
+
+        // Wake on the next day with a fresh 12-hour buff.
+        set_time(&mut app, 2, 6);
+        app.world_mut().resource_mut::<ActiveBuffs>().buffs = vec![FoodBuff {
+            buff_type: BuffType::MaxStamina,
+            magnitude: 30.0,
+            minutes_remaining: 12 * 60,
+        }];
+        app.update();
+        assert_eq!(
+            app.world().resource::<ActiveBuffs>().buffs[0].minutes_remaining,
+            12 * 60
+        );
+
+        set_time(&mut app, 2, 10);
+        app.update();
+        assert_eq!(
+            app.world().resource::<ActiveBuffs>().buffs[0].minutes_remaining,
+            8 * 60
+        );
+
+        set_time(&mut app, 2, 18);
+        app.update();
+        assert!(app.world().resource::<ActiveBuffs>().buffs.is_empty());
+    }
+}
@@ -62,6 +62,10 @@ pub fn handle_cook_item(
         // Kitchen upgrade required
         if !house_state.has_kitchen {
             ui_state.set_feedback("You need a kitchen upgrade first!".to_string());
+            toast_events.send(ToastEvent {
+                message: "You need a kitchen to cook. Upgrade your house first.".into(),
+                duration_secs: 3.0,
+            });
             continue;
         }
 
@@ -176,6 +180,7 @@ pub fn handle_cook_item(
         pickup_events.send(ItemPickupEvent {
             item_id: recipe.result.clone(),
             quantity: recipe.result_quantity,
+            quality: ItemQuality::Normal,
         });
         *achievements
             .progress
@@ -227,30 +232,17 @@ fn find_any_fish_in_inventory(inventory: &Inventory) -> Option<ItemId> {
 
 /// Check all non-wildcard ingredients.
 fn has_all_non_wildcard_ingredients(inventory: &Inventory, recipe: &Recipe) -> bool {
-    for (item_id, qty) in &recipe.ingredients {
-        if item_id == "any_fish" {
-            continue;
-        }
-        if !inventory.has(item_id, *qty) {
-            return false;
-        }
-    }
-    true
+    recipe.missing_ingredients(inventory).is_empty()
 }
 
 /// Human-readable list of missing non-wildcard ingredients.
 fn missing_non_wildcard_description(inventory: &Inventory, recipe: &Recipe) -> String {
-    let mut parts = Vec::new();
-    for (item_id, qty) in &recipe.ingredients {
-        if item_id == "any_fish" {
-            continue;
-        }
-        let have = inventory.count(item_id) as u8;
-        if have < *qty {
-            parts.push(format!("{} (have {}/{})", item_id, have, qty));
-        }
-    }
-    parts.join(", ")
+    recipe
+        .missing_ingredients(inventory)
+        .iter()
+        .map(|(item_id, short)| format!("{} x{}", item_id, short))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 /// Consume all non-wildcard ingredients.
@@ -283,3 +275,70 @@ fn refund_non_wildcard_ingredients(
         inventory.try_add(item_id, *qty, max_stack);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cooking_without_kitchen_toasts_and_keeps_ingredients() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<Inventory>()
+            .init_resource::<RecipeRegistry>()
+            .init_resource::<ItemRegistry>()
+            .init_resource::<UnlockedRecipes>()
+            .init_resource::<HouseState>()
+            .init_resource::<Achievements>()
+            .insert_resource(CraftingUiState {
+                is_cooking_mode: true,
+                ..default()
+            })
+            .add_event::<CraftItemEvent>()
+            .add_event::<ItemPickupEvent>()
+            .add_event::<StaminaDrainEvent>()
+            .add_event::<PlaySfxEvent>()
+            .add_event::<ToastEvent>()
+            .add_systems(Update, handle_cook_item);
+
+        app.world_mut()
+            .resource_mut::<RecipeRegistry>()
+            .recipes
+            .insert(
+                "fried_egg".into(),
+                Recipe {
+                    id: "fried_egg".into(),
+                    name: "Fried Egg".into(),
+                    ingredients: vec![("egg".into(), 1)],
+                    result: "fried_egg".into(),
+                    result_quantity: 1,
+                    is_cooking: true,
+                    unlocked_by_default: true,
+                },
+            );
+        app.world_mut()
+            .resource_mut::<UnlockedRecipes>()
+            .ids
+            .push("fried_egg".into());
+        app.world_mut()
+            .resource_mut::<Inventory>()
+            .try_add("egg", 1, 99);
+
+        app.world_mut().send_event(CraftItemEvent {
+            recipe_id: "fried_egg".into(),
+        });
+        app.update();
+
+        let inventory = app.world().resource::<Inventory>();
+        assert_eq!(inventory.count("egg"), 1);
+        assert_eq!(inventory.count("fried_egg"), 0);
+
+        let toasts = app.world().resource::<Events<ToastEvent>>();
+        let messages: Vec<_> = toasts
+            .get_cursor()
+            .read(toasts)
+            .map(|t| t.message.clone())
+            .collect();
+        assert!(messages.iter().any(|m| m.contains("kitchen")));
+    }
+}
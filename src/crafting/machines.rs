@@ -2,6 +2,7 @@ use crate::shared::*;
 use bevy::image::{Image, ImageSampler};
 use bevy::prelude::*;
 use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 // ──────────────────────────────────────────────────────────────────────────────
@@ -21,8 +22,13 @@ pub enum MachineType {
     BeeHouse,
     RecyclingMachine,
     CrabPot,
+    SeedMaker,
+    Cask,
 }
 
+/// Days a cask needs to raise its contents by one quality step.
+pub const CASK_DAYS_PER_QUALITY: u8 = 7;
+
 impl MachineType {
     /// Processing time in game-hours.
     pub fn processing_hours(&self) -> f32 {
@@ -38,6 +44,8 @@ impl MachineType {
             MachineType::BeeHouse => 96.0,          // 4 days × 24h
             MachineType::RecyclingMachine => 24.0,  // 1 day
             MachineType::CrabPot => 24.0,           // 1 day
+            MachineType::SeedMaker => 2.0,          // 120 game-minutes
+            MachineType::Cask => 0.0,               // ages per day, see `age_cask`
         }
     }
 
@@ -54,6 +62,8 @@ impl MachineType {
             MachineType::BeeHouse => "Bee House",
             MachineType::RecyclingMachine => "Recycling Machine",
             MachineType::CrabPot => "Crab Pot",
+            MachineType::SeedMaker => "Seed Maker",
+            MachineType::Cask => "Cask",
         }
     }
 }
@@ -72,6 +82,8 @@ pub fn machine_atlas_index(machine_type: MachineType) -> usize {
         MachineType::BeeHouse => 27,
         MachineType::RecyclingMachine => 28,
         MachineType::CrabPot => 29,
+        MachineType::SeedMaker => 28, // shares the recycling machine frame
+        MachineType::Cask => 24,      // shares the keg frame
     }
 }
 
@@ -225,7 +237,7 @@ fn generate_machine_image(machine_type: MachineType) -> Image {
                 set(&mut data, x, 9, 220, 220, 200, 180);
             }
         }
-        MachineType::Keg => {
+        MachineType::Keg | MachineType::Cask => {
             // Barrel shape (brown rectangle with hoops)
             fill_rect(&mut data, 4, 3, 11, 13, 130, 85, 40, 255); // barrel body
             fill_rect(&mut data, 5, 4, 10, 12, 150, 100, 50, 255); // lighter center
@@ -294,9 +306,15 @@ pub struct ProcessingMachine {
     pub machine_type: MachineType,
     pub input_item: Option<ItemId>,
     pub output_item: Option<ItemId>,
+    /// How many of `output_item` collecting yields.
+    pub output_quantity: u8,
     /// Remaining processing time in game hours.
     pub processing_time_remaining: f32,
     pub is_ready: bool,
+    /// Quality the output comes out at. Only casks raise it above Normal.
+    pub output_quality: ItemQuality,
+    /// Day-ends a cask's contents have aged since their last quality step.
+    pub aging_days: u8,
 }
 
 impl ProcessingMachine {
@@ -305,8 +323,11 @@ impl ProcessingMachine {
             machine_type,
             input_item: None,
             output_item: None,
+            output_quantity: 1,
             processing_time_remaining: 0.0,
             is_ready: false,
+            output_quality: ItemQuality::Normal,
+            aging_days: 0,
         }
     }
 
@@ -412,7 +433,54 @@ pub fn resolve_machine_output(machine: MachineType, input: &str) -> Option<(Item
             "bait" => Some(("crab".to_string(), 1)),
             _ => None,
         },
+        // Seeds depend on the crop data; see `resolve_seed_maker_output`.
+        MachineType::SeedMaker => None,
+        // Casks age wine and cheese in place; the item keeps its id and gains quality.
+        MachineType::Cask => match input {
+            "blueberry_wine" | "strawberry_wine" | "melon_wine" | "ancient_fruit_wine" | "mead"
+            | "cheese" | "large_cheese" => Some((input.to_string(), 1)),
+            _ => None,
+        },
+    }
+}
+
+/// One day of cask aging. Every `CASK_DAYS_PER_QUALITY` days the contents go
+/// up a quality step and become collectable; aging stops at Iridium.
+/// Returns true when the quality improved.
+pub fn age_cask(machine: &mut ProcessingMachine) -> bool {
+    if machine.machine_type != MachineType::Cask || machine.input_item.is_none() {
+        return false;
+    }
+    let Some(next) = machine.output_quality.next() else {
+        return false;
+    };
+    machine.aging_days = machine.aging_days.saturating_add(1);
+    if machine.aging_days < CASK_DAYS_PER_QUALITY {
+        return false;
     }
+    machine.aging_days = 0;
+    machine.output_quality = next;
+    machine.is_ready = true;
+    true
+}
+
+/// Seed item for a harvested crop, looked up through `CropRegistry`.
+pub fn crop_to_seed(registry: &CropRegistry, harvest_id: &str) -> Option<ItemId> {
+    registry
+        .crops
+        .values()
+        .find(|def| def.harvest_id == harvest_id)
+        .map(|def| def.seed_id.clone())
+}
+
+/// Seed maker output: 1-3 seeds of the inserted crop.
+pub fn resolve_seed_maker_output(
+    registry: &CropRegistry,
+    input: &str,
+    rng: &mut impl Rng,
+) -> Option<(ItemId, u8)> {
+    let seed_id = crop_to_seed(registry, input)?;
+    Some((seed_id, rng.gen_range(1..=3)))
 }
 
 // ──────────────────────────────────────────────────────────────────────────────
@@ -433,8 +501,18 @@ pub struct SavedMachine {
     pub machine_type: MachineType,
     pub input_item: Option<ItemId>,
     pub output_item: Option<ItemId>,
+    #[serde(default = "default_output_quantity")]
+    pub output_quantity: u8,
     pub processing_time_remaining: f32,
     pub is_ready: bool,
+    #[serde(default)]
+    pub output_quality: ItemQuality,
+    #[serde(default)]
+    pub aging_days: u8,
+}
+
+fn default_output_quantity() -> u8 {
+    1
 }
 
 // ──────────────────────────────────────────────────────────────────────────────
@@ -446,6 +524,8 @@ pub struct InsertMachineInputEvent {
     pub machine_entity: Entity,
     pub item_id: ItemId,
     pub quantity: u8,
+    /// Quality of the inserted stack. Casks age up from it.
+    pub quality: ItemQuality,
 }
 
 #[derive(Event, Debug, Clone)]
@@ -472,6 +552,8 @@ pub fn item_to_machine_type(item_id: &str) -> Option<MachineType> {
         "bee_house" => Some(MachineType::BeeHouse),
         "recycling_machine" => Some(MachineType::RecyclingMachine),
         "crab_pot" => Some(MachineType::CrabPot),
+        "seed_maker" => Some(MachineType::SeedMaker),
+        "cask" => Some(MachineType::Cask),
         _ => None,
     }
 }
@@ -542,6 +624,7 @@ pub fn tick_processing_machines(
 /// (mainly for machines measured in days, like Preserves Jar / Cheese Press / Loom).
 /// The `tick_processing_machines` system already handles this via real time accumulation,
 /// but this system re-checks and finalizes any machines that should have completed.
+/// Casks age their contents here instead, one day per day-end.
 pub fn handle_day_end_processing(
     mut day_end_events: EventReader<DayEndEvent>,
     mut machines: Query<&mut ProcessingMachine>,
@@ -549,6 +632,14 @@ pub fn handle_day_end_processing(
 ) {
     for _event in day_end_events.read() {
         for mut machine in machines.iter_mut() {
+            if machine.machine_type == MachineType::Cask {
+                if age_cask(&mut machine) {
+                    sfx_events.send(PlaySfxEvent {
+                        sfx_id: "machine_ready".to_string(),
+                    });
+                }
+                continue;
+            }
             // Force-complete any machine that has been processing for >= full processing time
             // (safety net in case real-time ticking missed the boundary)
             if machine.is_processing() && machine.processing_time_remaining <= 0.0 {
@@ -562,11 +653,13 @@ pub fn handle_day_end_processing(
 }
 
 /// When the player inserts an item into a machine, start processing.
+#[allow(clippy::too_many_arguments)]
 pub fn handle_insert_machine_input(
     mut events: EventReader<InsertMachineInputEvent>,
     mut machines: Query<&mut ProcessingMachine>,
     mut inventory: ResMut<Inventory>,
     item_registry: Res<ItemRegistry>,
+    crop_registry: Res<CropRegistry>,
     mut sfx_events: EventWriter<PlaySfxEvent>,
     mut toast_events: EventWriter<ToastEvent>,
 ) {
@@ -598,9 +691,13 @@ pub fn handle_insert_machine_input(
         }
 
         // Resolve what the machine will produce
-        let Some((output_id, _output_qty)) =
-            resolve_machine_output(machine.machine_type, &event.item_id)
-        else {
+        let output = match machine.machine_type {
+            MachineType::SeedMaker => {
+                resolve_seed_maker_output(&crop_registry, &event.item_id, &mut rand::thread_rng())
+            }
+            other => resolve_machine_output(other, &event.item_id),
+        };
+        let Some((output_id, output_qty)) = output else {
             warn!(
                 "{} cannot process item '{}'",
                 machine.machine_type.display_name(),
@@ -616,8 +713,17 @@ pub fn handle_insert_machine_input(
             continue;
         };
 
+        // Iridium is as good as it gets; a cask has nothing left to do.
+        if machine.machine_type == MachineType::Cask && event.quality == ItemQuality::Iridium {
+            toast_events.send(ToastEvent {
+                message: "That's already as fine as it will ever get.".to_string(),
+                duration_secs: 2.5,
+            });
+            continue;
+        }
+
         // Remove input from inventory
-        let removed = inventory.try_remove(&event.item_id, event.quantity);
+        let removed = inventory.try_remove_quality(&event.item_id, event.quantity, event.quality);
         if removed < event.quantity {
             warn!(
                 "Not enough '{}' in inventory (needed {}, removed {})",
@@ -629,7 +735,7 @@ pub fn handle_insert_machine_input(
                     .get(&event.item_id)
                     .map(|d| d.stack_size)
                     .unwrap_or(99);
-                inventory.try_add(&event.item_id, removed, max_stack);
+                inventory.try_add_quality(&event.item_id, removed, max_stack, event.quality);
             }
             toast_events.send(ToastEvent {
                 message: format!("Not enough {} in inventory.", event.item_id),
@@ -651,8 +757,15 @@ pub fn handle_insert_machine_input(
         let processing_hours = machine.machine_type.processing_hours();
         machine.input_item = Some(event.item_id.clone());
         machine.output_item = Some(output_id);
+        machine.output_quantity = output_qty;
         machine.processing_time_remaining = processing_hours;
         machine.is_ready = false;
+        machine.output_quality = if machine.machine_type == MachineType::Cask {
+            event.quality
+        } else {
+            ItemQuality::Normal
+        };
+        machine.aging_days = 0;
 
         info!(
             "Started processing '{}' in {} ({}h remaining)",
@@ -720,14 +833,17 @@ pub fn handle_collect_machine_output(
             .map(|d| d.stack_size)
             .unwrap_or(99);
 
-        let leftover = inventory.try_add(output_id, 1, max_stack);
-        if leftover == 0 {
-            // Successfully added to inventory
+        let quantity = machine.output_quantity.max(1);
+        let quality = machine.output_quality;
+        let leftover = inventory.try_add_quality(output_id, quantity, max_stack, quality);
+        if leftover < quantity {
             pickup_events.send(ItemPickupEvent {
                 item_id: output_id.clone(),
-                quantity: 1,
+                quantity: quantity - leftover,
+                quality,
             });
-
+        }
+        if leftover == 0 {
             info!("Collected '{}' from {}", output_id, machine_name);
 
             toast_events.send(ToastEvent {
@@ -738,13 +854,18 @@ pub fn handle_collect_machine_output(
             // Reset machine state
             machine.input_item = None;
             machine.output_item = None;
+            machine.output_quantity = 1;
             machine.processing_time_remaining = 0.0;
             machine.is_ready = false;
+            machine.output_quality = ItemQuality::Normal;
+            machine.aging_days = 0;
 
             sfx_events.send(PlaySfxEvent {
                 sfx_id: "item_pickup".to_string(),
             });
         } else {
+            // Keep whatever didn't fit in the machine for the next collect.
+            machine.output_quantity = leftover;
             warn!("Inventory full — cannot collect output from machine");
             toast_events.send(ToastEvent {
                 message: "Inventory full! Can't collect output.".to_string(),
@@ -63,6 +63,17 @@ pub fn make_crafting_recipe(id: &str) -> Option<Recipe> {
             is_cooking: false,
             unlocked_by_default: true,
         },
+        // ── Expedition Gear ─────────────────────────────────────────────────
+        "bedroll" => Recipe {
+            id: "bedroll".into(),
+            name: "Bedroll".into(),
+            // Single-use: ends the day wherever you are, no pass-out penalty.
+            ingredients: vec![("fiber".into(), 30), ("cloth".into(), 1)],
+            result: "bedroll".into(),
+            result_quantity: 1,
+            is_cooking: false,
+            unlocked_by_default: true,
+        },
         // ── Storage ─────────────────────────────────────────────────────────
         "chest" => Recipe {
             id: "chest".into(),
@@ -156,6 +167,34 @@ pub fn make_crafting_recipe(id: &str) -> Option<Recipe> {
             is_cooking: false,
             unlocked_by_default: false,
         },
+        "staircase" => Recipe {
+            id: "staircase".into(),
+            name: "Staircase".into(),
+            ingredients: vec![("stone".into(), 99)],
+            result: "staircase".into(),
+            result_quantity: 1,
+            is_cooking: false,
+            unlocked_by_default: true,
+        },
+        // ── Gifts & Animals ─────────────────────────────────────────────────
+        "gift_wrap" => Recipe {
+            id: "gift_wrap".into(),
+            name: "Gift Wrap".into(),
+            ingredients: vec![("fiber".into(), 10), ("sap".into(), 2)],
+            result: "gift_wrap".into(),
+            result_quantity: 1,
+            is_cooking: false,
+            unlocked_by_default: true,
+        },
+        "name_tag" => Recipe {
+            id: "name_tag".into(),
+            name: "Name Tag".into(),
+            ingredients: vec![("fiber".into(), 3), ("sap".into(), 1)],
+            result: "name_tag".into(),
+            result_quantity: 1,
+            is_cooking: false,
+            unlocked_by_default: true,
+        },
         // ── Lighting ────────────────────────────────────────────────────────
         "torch" => Recipe {
             id: "torch".into(),
@@ -219,6 +258,16 @@ pub fn make_crafting_recipe(id: &str) -> Option<Recipe> {
             is_cooking: false,
             unlocked_by_default: false,
         },
+        "cask" => Recipe {
+            id: "cask".into(),
+            name: "Cask".into(),
+            // Ages wine and cheese up through the quality tiers.
+            ingredients: vec![("wood".into(), 20), ("hardwood".into(), 1)],
+            result: "cask".into(),
+            result_quantity: 1,
+            is_cooking: false,
+            unlocked_by_default: false,
+        },
         "oil_maker" => Recipe {
             id: "oil_maker".into(),
             name: "Oil Maker".into(),
@@ -489,6 +538,7 @@ pub const ALL_CRAFTING_RECIPE_IDS: &[&str] = &[
     "path",
     "gate",
     "chest",
+    "bedroll",
     "furnace",
     "preserves_jar",
     "cheese_press",
@@ -496,11 +546,15 @@ pub const ALL_CRAFTING_RECIPE_IDS: &[&str] = &[
     "scarecrow",
     "bomb",
     "mega_bomb",
+    "staircase",
+    "gift_wrap",
+    "name_tag",
     "torch",
     "campfire",
     "bee_house",
     "lightning_rod",
     "keg",
+    "cask",
     "oil_maker",
     "seed_maker",
     "recycler",
@@ -186,6 +186,10 @@ pub const MILESTONE_RECIPE_UNLOCKS: &[MilestoneRecipeUnlock] = &[
         trigger_item: "hardwood",
         recipe_id: "oil_maker",
     },
+    MilestoneRecipeUnlock {
+        trigger_item: "cheese",
+        recipe_id: "cask",
+    },
     MilestoneRecipeUnlock {
         trigger_item: "maple_syrup",
         recipe_id: "bee_house",
@@ -327,11 +331,14 @@ pub fn check_milestone_recipe_unlocks(
 }
 
 /// Checks friendship levels and sends UnlockRecipeEvents when thresholds are crossed.
-/// Runs in Playing state — checks after any relationship change.
+/// Runs in Playing state — checks after any relationship change. The NPC also
+/// mails the player a note about the recipe, which arrives the next morning.
 pub fn check_friendship_recipe_unlocks(
     relationships: Res<Relationships>,
     unlocked: Res<UnlockedRecipes>,
     recipe_registry: Res<RecipeRegistry>,
+    npc_registry: Res<NpcRegistry>,
+    mut mailbox: ResMut<Mailbox>,
     mut unlock_events: EventWriter<UnlockRecipeEvent>,
 ) {
     if !relationships.is_changed() {
@@ -349,6 +356,20 @@ pub fn check_friendship_recipe_unlocks(
                     "Friendship unlock: {} at {} hearts unlocks recipe '{}'",
                     entry.npc_id, entry.hearts, recipe_id
                 );
+                let npc_name = npc_registry
+                    .npcs
+                    .get(entry.npc_id)
+                    .map(|npc| npc.name.as_str())
+                    .unwrap_or(entry.npc_id);
+                let recipe_name = &recipe_registry.recipes[&recipe_id].name;
+                mailbox.send(MailItem {
+                    subject: format!("A recipe from {}", npc_name),
+                    body: format!(
+                        "I wanted you to have my recipe for {}. It's in your recipe book now. - {}",
+                        recipe_name, npc_name
+                    ),
+                    attachment: None,
+                });
                 unlock_events.send(UnlockRecipeEvent { recipe_id });
             }
         }
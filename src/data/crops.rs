@@ -27,6 +27,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             sell_price: 60,
             // Per-crop atlas: sequential column indices (crop_turnip.png, 7×3)
             sprite_stages: vec![0, 1, 2, 3],
+            needs_trellis: false,
+            off_season_tolerant: true,
         },
         CropDef {
             id: "potato".into(),
@@ -40,6 +42,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             regrow_days: 0,
             sell_price: 80,
             sprite_stages: vec![10, 11, 12, 13],
+            needs_trellis: false,
+            off_season_tolerant: true,
         },
         CropDef {
             id: "cauliflower".into(),
@@ -54,6 +58,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             sell_price: 175,
             // Per-crop atlas: sequential column indices (crop_cauliflower.png, 7×2)
             sprite_stages: vec![0, 1, 2, 3, 4],
+            needs_trellis: false,
+            off_season_tolerant: false,
         },
         CropDef {
             id: "strawberry".into(),
@@ -68,6 +74,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             sell_price: 120,
             // Per-crop atlas: sequential column indices (crop_strawberry.png, 7×2)
             sprite_stages: vec![0, 1, 2, 3],
+            needs_trellis: false,
+            off_season_tolerant: false,
         },
         // ── Summer Crops ────────────────────────────────────────────────────────
         CropDef {
@@ -82,6 +90,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             regrow_days: 0,
             sell_price: 250,
             sprite_stages: vec![40, 41, 42, 43, 44],
+            needs_trellis: false,
+            off_season_tolerant: false,
         },
         CropDef {
             id: "tomato".into(),
@@ -96,6 +106,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             sell_price: 60,
             // Per-crop atlas: sequential column indices (crop_tomato.png, 7×4)
             sprite_stages: vec![0, 1, 2, 3, 4],
+            needs_trellis: false,
+            off_season_tolerant: false,
         },
         CropDef {
             id: "blueberry".into(),
@@ -109,6 +121,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             regrow_days: 4,
             sell_price: 50,
             sprite_stages: vec![60, 61, 62, 63, 64],
+            needs_trellis: false,
+            off_season_tolerant: false,
         },
         CropDef {
             id: "corn".into(),
@@ -124,6 +138,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             sell_price: 50,
             // Per-crop atlas: sequential column indices (crop_corn.png, 7×4)
             sprite_stages: vec![0, 1, 2, 3, 4, 5],
+            needs_trellis: false,
+            off_season_tolerant: false,
         },
         // ── Fall Crops ───────────────────────────────────────────────────────────
         CropDef {
@@ -138,6 +154,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             regrow_days: 5,
             sell_price: 60,
             sprite_stages: vec![80, 81, 82, 83, 84],
+            needs_trellis: false,
+            off_season_tolerant: false,
         },
         CropDef {
             id: "pumpkin".into(),
@@ -152,6 +170,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             sell_price: 320,
             // Per-crop atlas: sequential column indices (crop_pumpkin.png, 7×4)
             sprite_stages: vec![0, 1, 2, 3, 4],
+            needs_trellis: false,
+            off_season_tolerant: true,
         },
         CropDef {
             id: "cranberry".into(),
@@ -165,6 +185,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             regrow_days: 5,
             sell_price: 75,
             sprite_stages: vec![100, 101, 102, 103],
+            needs_trellis: false,
+            off_season_tolerant: true,
         },
         CropDef {
             id: "yam".into(),
@@ -178,6 +200,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             regrow_days: 0,
             sell_price: 160,
             sprite_stages: vec![110, 111, 112, 113, 114],
+            needs_trellis: false,
+            off_season_tolerant: true,
         },
         // ── Hops (Summer) ──────────────────────────────────────────────────────
         CropDef {
@@ -192,6 +216,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             regrow_days: 0,
             sell_price: 25,
             sprite_stages: vec![120, 121, 122, 123, 124],
+            needs_trellis: true,
+            off_season_tolerant: false,
         },
         // ── Any-Season Crops ─────────────────────────────────────────────────────
         CropDef {
@@ -208,6 +234,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             sell_price: 25,
             // Per-crop atlas: sequential column indices (crop_wheat.png, 7×2)
             sprite_stages: vec![0, 1, 2, 3],
+            needs_trellis: false,
+            off_season_tolerant: true,
         },
         CropDef {
             id: "coffee".into(),
@@ -223,6 +251,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             sell_price: 150,
             // Per-crop atlas: sequential column indices (crop_coffee.png, 7×4)
             sprite_stages: vec![0, 1, 2, 3, 4],
+            needs_trellis: false,
+            off_season_tolerant: false,
         },
         CropDef {
             id: "ancient_fruit".into(),
@@ -237,6 +267,8 @@ pub fn populate_crops(registry: &mut CropRegistry) {
             regrow_days: 7,
             sell_price: 750,
             sprite_stages: vec![140, 141, 142, 143, 144, 145],
+            needs_trellis: false,
+            off_season_tolerant: false,
         },
     ];
 
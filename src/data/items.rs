@@ -1313,7 +1313,7 @@ pub fn populate_items(registry: &mut ItemRegistry) {
             description: "Spiced pumpkin loaf.".into(),
             category: ItemCategory::Food,
             sell_price: 80,
-            buy_price: None,
+            buy_price: Some(180),
             stack_size: 99,
             edible: true,
             energy_restore: 35.0,
@@ -1563,6 +1563,30 @@ pub fn populate_items(registry: &mut ItemRegistry) {
             energy_restore: 0.0,
             sprite_index: 216,
         },
+        ItemDef {
+            id: "fertilizer".into(),
+            name: "Fertilizer".into(),
+            description: "Work it into tilled soil for better odds of a quality harvest.".into(),
+            category: ItemCategory::Special,
+            sell_price: 2,
+            buy_price: Some(100),
+            stack_size: 99,
+            edible: false,
+            energy_restore: 0.0,
+            sprite_index: 19,
+        },
+        ItemDef {
+            id: "animal_medicine".into(),
+            name: "Animal Medicine".into(),
+            description: "Cures a sick farm animal. Use it next to the animal.".into(),
+            category: ItemCategory::Special,
+            sell_price: 50,
+            buy_price: Some(150),
+            stack_size: 99,
+            edible: false,
+            energy_restore: 0.0,
+            sprite_index: 217,
+        },
         ItemDef {
             id: "bait".into(),
             name: "Bait".into(),
@@ -1629,6 +1653,43 @@ pub fn populate_items(registry: &mut ItemRegistry) {
             energy_restore: 0.0,
             sprite_index: 221,
         },
+        ItemDef {
+            id: "staircase".into(),
+            name: "Staircase".into(),
+            description: "Use in the mine to go straight down to the next floor.".into(),
+            category: ItemCategory::CraftingMaterial,
+            sell_price: 1,
+            buy_price: None,
+            stack_size: 99,
+            edible: false,
+            energy_restore: 0.0,
+            sprite_index: 70,
+        },
+        ItemDef {
+            id: "gift_wrap".into(),
+            name: "Gift Wrap".into(),
+            description: "Hold Ctrl while giving a gift to wrap it for extra friendship."
+                .into(),
+            category: ItemCategory::Special,
+            sell_price: 5,
+            buy_price: None,
+            stack_size: 99,
+            edible: false,
+            energy_restore: 0.0,
+            sprite_index: 46,
+        },
+        ItemDef {
+            id: "name_tag".into(),
+            name: "Name Tag".into(),
+            description: "Use next to a farm animal to give it a new name.".into(),
+            category: ItemCategory::Special,
+            sell_price: 5,
+            buy_price: None,
+            stack_size: 99,
+            edible: false,
+            energy_restore: 0.0,
+            sprite_index: 112,
+        },
 
         // ── Animals (purchased at Animal Shop) ──────────────────────
         ItemDef {
@@ -1879,6 +1940,7 @@ pub fn populate_items(registry: &mut ItemRegistry) {
         ItemDef { id: "cheese_press".into(), name: "Cheese Press".into(), description: "Turns milk into cheese.".into(), category: ItemCategory::Furniture, sell_price: 200, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 38 },
         ItemDef { id: "loom".into(), name: "Loom".into(), description: "Turns wool into cloth.".into(), category: ItemCategory::Furniture, sell_price: 200, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 39 },
         ItemDef { id: "keg".into(), name: "Keg".into(), description: "A patient barrel for slow rewards: bright cider, deep wine, and honest ale.".into(), category: ItemCategory::Furniture, sell_price: 200, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 186 },
+        ItemDef { id: "cask".into(), name: "Cask".into(), description: "Ages wine and cheese into higher quality, one week per step.".into(), category: ItemCategory::Furniture, sell_price: 150, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 186 },
         ItemDef { id: "lightning_rod".into(), name: "Lightning Rod".into(), description: "Captures lightning energy during storms.".into(), category: ItemCategory::Furniture, sell_price: 100, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 187 },
         ItemDef { id: "mayonnaise_machine".into(), name: "Mayonnaise Machine".into(), description: "Turns eggs into mayonnaise.".into(), category: ItemCategory::Furniture, sell_price: 150, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 188 },
         ItemDef { id: "crab_pot".into(), name: "Crab Pot".into(), description: "Place in water to catch shellfish.".into(), category: ItemCategory::Furniture, sell_price: 50, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 189 },
@@ -1887,6 +1949,12 @@ pub fn populate_items(registry: &mut ItemRegistry) {
         ItemDef { id: "tapper".into(), name: "Tapper".into(), description: "Attach to trees to collect sap and syrup.".into(), category: ItemCategory::Furniture, sell_price: 100, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 192 },
         ItemDef { id: "bee_house".into(), name: "Bee House".into(), description: "Produces honey. Nearby flowers affect flavor.".into(), category: ItemCategory::Furniture, sell_price: 200, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 48 },
         ItemDef { id: "recycling_machine".into(), name: "Recycling Machine".into(), description: "Recycles trash into useful materials.".into(), category: ItemCategory::Furniture, sell_price: 100, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 49 },
+        ItemDef { id: "seed_maker".into(), name: "Seed Maker".into(), description: "Turns harvested crops back into a few seeds.".into(), category: ItemCategory::Furniture, sell_price: 150, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 49 },
+        ItemDef { id: "fish_tank".into(), name: "Fish Tank".into(), description: "A glass tank for showing off your best catches. Holds up to 6 fish.".into(), category: ItemCategory::Furniture, sell_price: 150, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 245 },
+        ItemDef { id: "bedroll".into(), name: "Bedroll".into(), description: "Roll it out anywhere to sleep until morning. Wake at home without the pass-out penalty.".into(), category: ItemCategory::Special, sell_price: 40, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 246 },
+        ItemDef { id: "backpack".into(), name: "Backpack".into(), description: "Unlocks a second row of inventory slots.".into(), category: ItemCategory::Special, sell_price: 0, buy_price: None, stack_size: 1, edible: false, energy_restore: 0.0, sprite_index: 16 },
+        ItemDef { id: "deluxe_backpack".into(), name: "Deluxe Backpack".into(), description: "Unlocks the last row of inventory slots.".into(), category: ItemCategory::Special, sell_price: 0, buy_price: None, stack_size: 1, edible: false, energy_restore: 0.0, sprite_index: 17 },
+        ItemDef { id: "starter_bundle".into(), name: "Starter Bundle".into(), description: "15 turnip seeds, 5 potato seeds and 2 basic sprinklers. One per farm.".into(), category: ItemCategory::Special, sell_price: 0, buy_price: None, stack_size: 1, edible: false, energy_restore: 0.0, sprite_index: 18 },
 
         // ═══════════════════════════════════════════════════════════════
         // ANIMAL PRODUCTS (new)
@@ -1902,6 +1970,14 @@ pub fn populate_items(registry: &mut ItemRegistry) {
         ItemDef { id: "slime_jelly".into(), name: "Slime Jelly".into(), description: "Gelatinous residue from slimes.".into(), category: ItemCategory::CraftingMaterial, sell_price: 25, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 104 },
         ItemDef { id: "crab_shell".into(), name: "Crab Shell".into(), description: "A hard shell from a rock crab.".into(), category: ItemCategory::CraftingMaterial, sell_price: 75, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 105 },
 
+        // ═══════════════════════════════════════════════════════════════
+        // JUNK — Recycling Machine inputs (icons borrowed via JUNK_ICONS)
+        // ═══════════════════════════════════════════════════════════════
+        ItemDef { id: "trash".into(), name: "Trash".into(), description: "Someone else's mess. A Recycling Machine can salvage it.".into(), category: ItemCategory::CraftingMaterial, sell_price: 0, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 0 },
+        ItemDef { id: "driftwood".into(), name: "Driftwood".into(), description: "A waterlogged branch, smoothed by the current.".into(), category: ItemCategory::CraftingMaterial, sell_price: 0, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 0 },
+        ItemDef { id: "old_glasses".into(), name: "Broken Glasses".into(), description: "Cracked lenses in a bent frame.".into(), category: ItemCategory::CraftingMaterial, sell_price: 0, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 0 },
+        ItemDef { id: "newspaper".into(), name: "Soggy Newspaper".into(), description: "Yesterday's news, soaked through.".into(), category: ItemCategory::CraftingMaterial, sell_price: 0, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 0 },
+
         // ═══════════════════════════════════════════════════════════════
         // MINERALS & PROCESSED (new)
         // ═══════════════════════════════════════════════════════════════
@@ -1914,13 +1990,13 @@ pub fn populate_items(registry: &mut ItemRegistry) {
         ItemDef { id: "tree_seed".into(), name: "Tree Seed".into(), description: "A seed that grows into a tree over time.".into(), category: ItemCategory::Seed, sell_price: 5, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 108 },
         ItemDef { id: "wild_berry".into(), name: "Wild Berry".into(), description: "A tart berry foraged from bushes.".into(), category: ItemCategory::Crop, sell_price: 20, buy_price: None, stack_size: 99, edible: true, energy_restore: 15.0, sprite_index: 109 },
         ItemDef { id: "wild_horseradish".into(), name: "Wild Horseradish".into(), description: "A pungent spring root dug up from the forest floor.".into(), category: ItemCategory::Crop, sell_price: 50, buy_price: None, stack_size: 99, edible: true, energy_restore: 13.0, sprite_index: 223 },
-        ItemDef { id: "daffodil".into(), name: "Daffodil".into(), description: "A cheerful spring flower often given as a gift.".into(), category: ItemCategory::Gift, sell_price: 30, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 224 },
+        ItemDef { id: "daffodil".into(), name: "Daffodil".into(), description: "A cheerful spring flower often given as a gift.".into(), category: ItemCategory::Gift, sell_price: 30, buy_price: Some(80), stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 224 },
         ItemDef { id: "leek".into(), name: "Leek".into(), description: "A sturdy wild allium with a mild, savory flavor.".into(), category: ItemCategory::Crop, sell_price: 60, buy_price: None, stack_size: 99, edible: true, energy_restore: 20.0, sprite_index: 225 },
         ItemDef { id: "dandelion".into(), name: "Dandelion".into(), description: "A common spring flower with edible leaves and roots.".into(), category: ItemCategory::Crop, sell_price: 40, buy_price: None, stack_size: 99, edible: true, energy_restore: 25.0, sprite_index: 226 },
         ItemDef { id: "spring_onion".into(), name: "Spring Onion".into(), description: "A tender wild onion that grows in damp spring soil.".into(), category: ItemCategory::Crop, sell_price: 8, buy_price: None, stack_size: 99, edible: true, energy_restore: 8.0, sprite_index: 227 },
         ItemDef { id: "grape".into(), name: "Grape".into(), description: "A juicy cluster of wild grapes picked in late summer.".into(), category: ItemCategory::Crop, sell_price: 80, buy_price: None, stack_size: 99, edible: true, energy_restore: 18.0, sprite_index: 228 },
         ItemDef { id: "spice_berry".into(), name: "Spice Berry".into(), description: "A fragrant summer berry with a warm, spicy aroma.".into(), category: ItemCategory::Crop, sell_price: 80, buy_price: None, stack_size: 99, edible: true, energy_restore: 25.0, sprite_index: 229 },
-        ItemDef { id: "sweet_pea".into(), name: "Sweet Pea".into(), description: "A delicate summer blossom that makes a lovely gift.".into(), category: ItemCategory::Gift, sell_price: 50, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 230 },
+        ItemDef { id: "sweet_pea".into(), name: "Sweet Pea".into(), description: "A delicate summer blossom that makes a lovely gift.".into(), category: ItemCategory::Gift, sell_price: 50, buy_price: Some(120), stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 230 },
         ItemDef { id: "red_mushroom".into(), name: "Red Mushroom".into(), description: "A vivid wild mushroom gathered in warm summer shade.".into(), category: ItemCategory::Crop, sell_price: 75, buy_price: None, stack_size: 99, edible: true, energy_restore: 30.0, sprite_index: 231 },
         ItemDef { id: "common_mushroom".into(), name: "Common Mushroom".into(), description: "A familiar edible mushroom found in cool forest patches.".into(), category: ItemCategory::Crop, sell_price: 40, buy_price: None, stack_size: 99, edible: true, energy_restore: 20.0, sprite_index: 232 },
         ItemDef { id: "wild_plum".into(), name: "Wild Plum".into(), description: "A tart purple fruit that ripens during autumn.".into(), category: ItemCategory::Crop, sell_price: 80, buy_price: None, stack_size: 99, edible: true, energy_restore: 25.0, sprite_index: 233 },
@@ -1930,7 +2006,14 @@ pub fn populate_items(registry: &mut ItemRegistry) {
         ItemDef { id: "winter_root".into(), name: "Winter Root".into(), description: "A hardy pale root dug from frozen winter soil.".into(), category: ItemCategory::Crop, sell_price: 70, buy_price: None, stack_size: 99, edible: true, energy_restore: 18.0, sprite_index: 237 },
         ItemDef { id: "crystal_fruit".into(), name: "Crystal Fruit".into(), description: "A bright winter fruit with a cool, crisp bite.".into(), category: ItemCategory::Crop, sell_price: 150, buy_price: None, stack_size: 99, edible: true, energy_restore: 38.0, sprite_index: 238 },
         ItemDef { id: "snow_yam".into(), name: "Snow Yam".into(), description: "A starchy tuber found under powdery winter snow.".into(), category: ItemCategory::Crop, sell_price: 100, buy_price: None, stack_size: 99, edible: true, energy_restore: 30.0, sprite_index: 239 },
-        ItemDef { id: "crocus".into(), name: "Crocus".into(), description: "A small winter flower prized more for beauty than nutrition.".into(), category: ItemCategory::Gift, sell_price: 60, buy_price: None, stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 240 },
+        ItemDef { id: "crocus".into(), name: "Crocus".into(), description: "A small winter flower prized more for beauty than nutrition.".into(), category: ItemCategory::Gift, sell_price: 60, buy_price: Some(150), stack_size: 99, edible: false, energy_restore: 0.0, sprite_index: 240 },
+        ItemDef { id: "wild_strawberry".into(), name: "Wild Strawberry".into(), description: "A tiny, intensely sweet strawberry found under forest ferns.".into(), category: ItemCategory::Crop, sell_price: 70, buy_price: None, stack_size: 99, edible: true, energy_restore: 15.0, sprite_index: 23 },
+        ItemDef { id: "wild_potato".into(), name: "Wild Potato".into(), description: "A knobbly little potato dug from the forest floor.".into(), category: ItemCategory::Crop, sell_price: 45, buy_price: None, stack_size: 99, edible: true, energy_restore: 18.0, sprite_index: 21 },
+        ItemDef { id: "wild_blueberry".into(), name: "Wild Blueberry".into(), description: "Small, tart blueberries from a forest thicket.".into(), category: ItemCategory::Crop, sell_price: 30, buy_price: None, stack_size: 99, edible: true, energy_restore: 10.0, sprite_index: 26 },
+        ItemDef { id: "wild_tomato".into(), name: "Wild Tomato".into(), description: "A cherry-sized tomato growing wild in a sunny clearing.".into(), category: ItemCategory::Crop, sell_price: 35, buy_price: None, stack_size: 99, edible: true, energy_restore: 12.0, sprite_index: 25 },
+        ItemDef { id: "wild_cranberry".into(), name: "Wild Cranberry".into(), description: "Sour red berries picked from a boggy forest hollow.".into(), category: ItemCategory::Crop, sell_price: 45, buy_price: None, stack_size: 99, edible: true, energy_restore: 12.0, sprite_index: 30 },
+        ItemDef { id: "wild_yam".into(), name: "Wild Yam".into(), description: "A stringy autumn yam that grows on its own among the roots.".into(), category: ItemCategory::Crop, sell_price: 90, buy_price: None, stack_size: 99, edible: true, energy_restore: 25.0, sprite_index: 31 },
+        ItemDef { id: "wild_turnip".into(), name: "Wild Turnip".into(), description: "A hardy turnip that survives the frost in sheltered woods.".into(), category: ItemCategory::Crop, sell_price: 40, buy_price: None, stack_size: 99, edible: true, energy_restore: 15.0, sprite_index: 20 },
 
         // ═══════════════════════════════════════════════════════════════
         // ARTISAN GOODS — Machine outputs (preserves, wines, jellies)
@@ -2002,4 +2085,20 @@ pub fn populate_items(registry: &mut ItemRegistry) {
     for item in items {
         registry.items.insert(item.id.clone(), item);
     }
+
+    for (junk_id, icon_id) in JUNK_ICONS {
+        let icon = registry.items.get(*icon_id).map(|def| def.sprite_index);
+        if let (Some(icon), Some(junk)) = (icon, registry.items.get_mut(*junk_id)) {
+            junk.sprite_index = icon;
+        }
+    }
 }
+
+/// Junk has no art of its own in the items atlas; each piece shows the icon
+/// of the material the Recycling Machine turns it into.
+const JUNK_ICONS: &[(&str, &str)] = &[
+    ("trash", "stone"),
+    ("driftwood", "wood"),
+    ("old_glasses", "refined_quartz"),
+    ("newspaper", "cloth"),
+];
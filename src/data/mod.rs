@@ -3,7 +3,8 @@
 //! This plugin runs in OnEnter(GameState::Loading), fills every registry
 //! (ItemRegistry, CropRegistry, FishRegistry, RecipeRegistry, NpcRegistry,
 //! ShopData) from the hard-coded game-design data defined in submodules,
-//! then transitions the game into GameState::MainMenu.
+//! then transitions the game into GameState::MainMenu. JSON files in
+//! `assets/data/` may override individual definitions (see `overrides`).
 //!
 //! No other domain needs to seed these resources. All domain plugins can
 //! safely read them once GameState has advanced past Loading.
@@ -12,6 +13,7 @@ mod crops;
 pub mod fish;
 mod items;
 pub mod npcs;
+pub mod overrides;
 mod recipes;
 mod shops;
 
@@ -54,6 +56,16 @@ fn load_all_data(
     recipes::populate_recipes(&mut recipe_registry);
     info!("  Recipes loaded: {}", recipe_registry.recipes.len());
 
+    overrides::apply_data_overrides(
+        std::path::Path::new(overrides::DATA_OVERRIDE_DIR),
+        overrides::OverrideTargets {
+            items: &mut item_registry,
+            crops: &mut crop_registry,
+            fish: &mut fish_registry,
+            recipes: &mut recipe_registry,
+        },
+    );
+
     npcs::populate_npcs(&mut npc_registry);
     info!(
         "  NPCs loaded: {}, Schedules loaded: {}",
@@ -237,6 +249,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn junk_items_borrow_their_recycled_materials_icons() {
+        let mut item_registry = ItemRegistry::default();
+        items::populate_items(&mut item_registry);
+
+        let sprite = |id: &str| item_registry.get(id).unwrap().sprite_index;
+        assert_eq!(sprite("trash"), sprite("stone"));
+        assert_eq!(sprite("driftwood"), sprite("wood"));
+        assert_eq!(sprite("old_glasses"), sprite("refined_quartz"));
+        assert_eq!(sprite("newspaper"), sprite("cloth"));
+    }
+
     #[test]
     fn crop_and_fish_ids_have_no_duplicates() {
         let mut crop_registry = CropRegistry::default();
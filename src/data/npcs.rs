@@ -100,6 +100,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 "Mayor Rex says the town notices every new furrow on your farm. I say that's because people are nosy.".into(),
             ],
             heart_dialogue,
+            context_dialogue: ContextDialogue::default(),
             is_marriageable: false,
             sprite_index: 0,
             portrait_index: 0,
@@ -179,6 +180,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 },
             ]),
             festival_override: None,
+            bedtime: Some(22.0),
         };
 
         registry.npcs.insert("margaret".into(), npc);
@@ -264,6 +266,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 "I am experimenting with a new sauce. Very exciting!".into(),
             ],
             heart_dialogue,
+            context_dialogue: ContextDialogue::default(),
             is_marriageable: false,
             sprite_index: 1,
             portrait_index: 1,
@@ -343,6 +346,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 },
             ]),
             festival_override: None,
+            bedtime: Some(23.0),
         };
 
         registry.npcs.insert("marco".into(), npc);
@@ -428,6 +432,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 "If you ever want a bouquet for your farmhouse table, I'll tuck in something that matches the season.".into(),
             ],
             heart_dialogue,
+            context_dialogue: ContextDialogue::default(),
             is_marriageable: true,
             sprite_index: 2,
             portrait_index: 2,
@@ -513,6 +518,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 },
             ]),
             festival_override: None,
+            bedtime: Some(21.0),
         };
 
         registry.npcs.insert("lily".into(), npc);
@@ -583,6 +589,23 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
             "This dock has been lonelier than I'd admit. Not anymore though. Not anymore.".into(),
         ]);
 
+        let mut context_dialogue = ContextDialogue::default();
+        context_dialogue.time_of_day.insert(
+            DayPeriod::Evening,
+            vec![
+                "Evening rise. The trout come up for the last of the light, if you're patient."
+                    .into(),
+                "Sun's going down. Best hour on the dock, and nobody else seems to know it.".into(),
+            ],
+        );
+        context_dialogue.time_of_day.insert(
+            DayPeriod::Night,
+            vec![
+                "Shouldn't you be asleep? Hmph. Neither should I, I suppose.".into(),
+                "Catfish bite after dark. That's the only reason I'm still out here.".into(),
+            ],
+        );
+
         let npc = NpcDef {
             id: "old_tom".into(),
             name: "Old Tom".into(),
@@ -598,6 +621,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 "Leave me be, I'm thinking.".into(),
             ],
             heart_dialogue,
+            context_dialogue,
             is_marriageable: false,
             sprite_index: 3,
             portrait_index: 3,
@@ -665,6 +689,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 },
             ]),
             festival_override: None,
+            bedtime: Some(21.0),
         };
 
         registry.npcs.insert("old_tom".into(), npc);
@@ -749,6 +774,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 "Father's working on something big in the back.".into(),
             ],
             heart_dialogue,
+            context_dialogue: ContextDialogue::default(),
             is_marriageable: true,
             sprite_index: 4,
             portrait_index: 4,
@@ -828,6 +854,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 },
             ]),
             festival_override: None,
+            bedtime: Some(22.0),
         };
 
         registry.npcs.insert("elena".into(), npc);
@@ -910,6 +937,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 "Tell me what you seek and I'll see if I have it.".into(),
             ],
             heart_dialogue,
+            context_dialogue: ContextDialogue::default(),
             is_marriageable: false,
             sprite_index: 5,
             portrait_index: 5,
@@ -977,6 +1005,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 },
             ]),
             festival_override: None,
+            bedtime: Some(22.0),
         };
 
         registry.npcs.insert("mira".into(), npc);
@@ -1064,6 +1093,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 "Eat your vegetables. That's not just advice, it's medicine.".into(),
             ],
             heart_dialogue,
+            context_dialogue: ContextDialogue::default(),
             is_marriageable: false,
             sprite_index: 6,
             portrait_index: 6,
@@ -1137,6 +1167,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 },
             ]),
             festival_override: None,
+            bedtime: Some(22.0),
         };
 
         registry.npcs.insert("doc".into(), npc);
@@ -1219,6 +1250,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 "A healthy town requires trade, cheer, and at least one topic everyone politely pretends not to discuss. At present, that topic is your farm.".into(),
             ],
             heart_dialogue,
+            context_dialogue: ContextDialogue::default(),
             is_marriageable: false,
             sprite_index: 7,
             portrait_index: 7,
@@ -1311,6 +1343,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                     y: 5,
                 },
             ]),
+            bedtime: Some(22.0),
         };
 
         registry.npcs.insert("mayor_rex".into(), npc);
@@ -1405,6 +1438,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 "This town needs more music and fewer people whispering outside the general store. If they're going to stare at your farm, they should at least clap on beat.".into(),
             ],
             heart_dialogue,
+            context_dialogue: ContextDialogue::default(),
             is_marriageable: false,
             sprite_index: 8,
             portrait_index: 8,
@@ -1484,6 +1518,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 },
             ]),
             festival_override: None,
+            bedtime: Some(21.0),
         };
 
         registry.npcs.insert("sam".into(), npc);
@@ -1573,6 +1608,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 "Best time to walk a field is just after sunrise. You hear the birds, smell the weather, and catch problems before breakfast.".into(),
             ],
             heart_dialogue,
+            context_dialogue: ContextDialogue::default(),
             is_marriageable: false,
             sprite_index: 9,
             portrait_index: 9,
@@ -1658,6 +1694,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 },
             ]),
             festival_override: None,
+            bedtime: Some(21.0),
         };
 
         registry.npcs.insert("nora".into(), npc);
@@ -1752,6 +1789,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 "I've lived up here longer than most people remember.".into(),
             ],
             heart_dialogue,
+            context_dialogue: ContextDialogue::default(),
             is_marriageable: false,
             sprite_index: 10,
             portrait_index: 10,
@@ -1831,6 +1869,7 @@ pub fn populate_npcs(registry: &mut NpcRegistry) {
                 },
             ]),
             festival_override: None,
+            bedtime: Some(21.0),
         };
 
         registry.npcs.insert("bjorn".into(), npc);
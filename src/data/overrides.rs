@@ -0,0 +1,283 @@
+//! Optional JSON overrides for the hard-coded game data.
+//!
+//! If `assets/data/` contains any of `items.json`, `crops.json`, `fish.json`
+//! or `recipes.json`, each file is read as a JSON array of the matching
+//! definition type (`ItemDef`, `CropDef`, `FishDef`, `Recipe`). Entries
+//! replace the built-in definition with the same id, or add a new one.
+//! Files that are missing are skipped; entries that fail validation are
+//! logged and skipped, so a bad edit never removes the built-in data.
+
+use std::fs;
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+
+use crate::shared::*;
+
+/// Directory the override files are read from, relative to the working dir.
+pub const DATA_OVERRIDE_DIR: &str = "assets/data";
+
+/// What a pass over the override directory did.
+#[derive(Debug, Default)]
+pub struct OverrideReport {
+    /// Definitions that were inserted or replaced.
+    pub applied: usize,
+    /// One line per file or entry that was rejected.
+    pub errors: Vec<String>,
+}
+
+/// Mutable access to the registries that overrides can touch.
+pub struct OverrideTargets<'a> {
+    pub items: &'a mut ItemRegistry,
+    pub crops: &'a mut CropRegistry,
+    pub fish: &'a mut FishRegistry,
+    pub recipes: &'a mut RecipeRegistry,
+}
+
+/// Read every override file in `dir` into the registries and log the result.
+pub fn apply_data_overrides(dir: &Path, targets: OverrideTargets) -> OverrideReport {
+    let mut report = OverrideReport::default();
+
+    apply_file(
+        dir,
+        "items.json",
+        &mut report,
+        validate_item,
+        |def: ItemDef| {
+            targets.items.items.insert(def.id.clone(), def);
+        },
+    );
+    apply_file(
+        dir,
+        "crops.json",
+        &mut report,
+        validate_crop,
+        |def: CropDef| {
+            targets.crops.crops.insert(def.id.clone(), def);
+        },
+    );
+    apply_file(
+        dir,
+        "fish.json",
+        &mut report,
+        validate_fish,
+        |def: FishDef| {
+            targets.fish.fish.insert(def.id.clone(), def);
+        },
+    );
+    apply_file(
+        dir,
+        "recipes.json",
+        &mut report,
+        validate_recipe,
+        |def: Recipe| {
+            targets.recipes.recipes.insert(def.id.clone(), def);
+        },
+    );
+
+    for error in &report.errors {
+        warn!("Data override rejected: {}", error);
+    }
+    if report.applied > 0 {
+        info!(
+            "  Data overrides applied from {}: {}",
+            dir.display(),
+            report.applied
+        );
+    }
+    report
+}
+
+fn apply_file<T: DeserializeOwned>(
+    dir: &Path,
+    file_name: &str,
+    report: &mut OverrideReport,
+    validate: fn(&T) -> Result<(), String>,
+    mut insert: impl FnMut(T),
+) {
+    let path = dir.join(file_name);
+    if !path.exists() {
+        return;
+    }
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) => {
+            report.errors.push(format!("{}: {}", path.display(), e));
+            return;
+        }
+    };
+    let defs: Vec<T> = match serde_json::from_str(&text) {
+        Ok(defs) => defs,
+        Err(e) => {
+            report.errors.push(format!("{}: {}", path.display(), e));
+            return;
+        }
+    };
+    for (index, def) in defs.into_iter().enumerate() {
+        match validate(&def) {
+            Ok(()) => {
+                insert(def);
+                report.applied += 1;
+            }
+            Err(e) => report
+                .errors
+                .push(format!("{} entry {}: {}", file_name, index, e)),
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// VALIDATION
+// ═══════════════════════════════════════════════════════════════════════
+
+fn require_id(field: &str, value: &str) -> Result<(), String> {
+    if value.trim().is_empty() {
+        Err(format!("{} must not be empty", field))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_item(def: &ItemDef) -> Result<(), String> {
+    require_id("id", &def.id)?;
+    require_id("name", &def.name)?;
+    if def.buy_price == Some(0) {
+        return Err(format!("'{}' has a buy price of 0", def.id));
+    }
+    if def.stack_size == 0 {
+        return Err(format!("'{}' has a stack size of 0", def.id));
+    }
+    Ok(())
+}
+
+fn validate_crop(def: &CropDef) -> Result<(), String> {
+    require_id("id", &def.id)?;
+    require_id("seed_id", &def.seed_id)?;
+    require_id("harvest_id", &def.harvest_id)?;
+    if def.sell_price == 0 {
+        return Err(format!("'{}' must have a positive sell price", def.id));
+    }
+    if def.growth_days.is_empty() || def.growth_days.contains(&0) {
+        return Err(format!(
+            "'{}' needs growth stages of at least 1 day",
+            def.id
+        ));
+    }
+    Ok(())
+}
+
+fn validate_fish(def: &FishDef) -> Result<(), String> {
+    require_id("id", &def.id)?;
+    if def.sell_price == 0 {
+        return Err(format!("'{}' must have a positive sell price", def.id));
+    }
+    Ok(())
+}
+
+fn validate_recipe(def: &Recipe) -> Result<(), String> {
+    require_id("id", &def.id)?;
+    require_id("result", &def.result)?;
+    if def.result_quantity == 0 {
+        return Err(format!("'{}' must produce at least 1 item", def.id));
+    }
+    if def
+        .ingredients
+        .iter()
+        .any(|(id, qty)| id.is_empty() || *qty == 0)
+    {
+        return Err(format!("'{}' has an empty ingredient", def.id));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "hearthfield_overrides_{}_{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn json_override_replaces_builtin_definition() {
+        let dir = scratch_dir("items");
+        fs::write(
+            dir.join("items.json"),
+            r#"[
+                {"id": "parsnip", "name": "Golden Parsnip", "description": "Rebalanced.",
+                 "category": "Crop", "sell_price": 99, "buy_price": null, "stack_size": 99,
+                 "edible": true, "energy_restore": 10.0, "sprite_index": 0},
+                {"id": "", "name": "Nameless", "description": "",
+                 "category": "Crop", "sell_price": 1, "buy_price": null, "stack_size": 99,
+                 "edible": false, "energy_restore": 0.0, "sprite_index": 0}
+            ]"#,
+        )
+        .unwrap();
+
+        let mut items = ItemRegistry::default();
+        items.items.insert(
+            "parsnip".into(),
+            ItemDef {
+                id: "parsnip".into(),
+                name: "Parsnip".into(),
+                description: String::new(),
+                category: ItemCategory::Crop,
+                sell_price: 35,
+                buy_price: None,
+                stack_size: 99,
+                edible: true,
+                energy_restore: 10.0,
+                sprite_index: 0,
+            },
+        );
+        let mut crops = CropRegistry::default();
+        let mut fish = FishRegistry::default();
+        let mut recipes = RecipeRegistry::default();
+
+        let report = apply_data_overrides(
+            &dir,
+            OverrideTargets {
+                items: &mut items,
+                crops: &mut crops,
+                fish: &mut fish,
+                recipes: &mut recipes,
+            },
+        );
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(report.applied, 1);
+        assert_eq!(report.errors.len(), 1, "empty id should be rejected");
+        let parsnip = items.get("parsnip").unwrap();
+        assert_eq!(parsnip.name, "Golden Parsnip");
+        assert_eq!(parsnip.sell_price, 99);
+        assert_eq!(items.items.len(), 1);
+    }
+
+    #[test]
+    fn missing_directory_changes_nothing() {
+        let mut items = ItemRegistry::default();
+        let mut crops = CropRegistry::default();
+        let mut fish = FishRegistry::default();
+        let mut recipes = RecipeRegistry::default();
+        let report = apply_data_overrides(
+            Path::new("does/not/exist"),
+            OverrideTargets {
+                items: &mut items,
+                crops: &mut crops,
+                fish: &mut fish,
+                recipes: &mut recipes,
+            },
+        );
+        assert_eq!(report.applied, 0);
+        assert!(report.errors.is_empty());
+    }
+}
@@ -191,6 +191,34 @@ pub fn populate_recipes(registry: &mut RecipeRegistry) {
             is_cooking: false,
             unlocked_by_default: false, // Learned from Blacksmith at friendship 4
         },
+        Recipe {
+            id: "recipe_staircase".into(),
+            name: "Staircase".into(),
+            ingredients: vec![("stone".into(), 99)],
+            result: "staircase".into(),
+            result_quantity: 1,
+            is_cooking: false,
+            unlocked_by_default: true,
+        },
+        // ── Gifts & Animals ────────────────────────────────────────────
+        Recipe {
+            id: "recipe_gift_wrap".into(),
+            name: "Gift Wrap".into(),
+            ingredients: vec![("fiber".into(), 10), ("sap".into(), 2)],
+            result: "gift_wrap".into(),
+            result_quantity: 1,
+            is_cooking: false,
+            unlocked_by_default: true,
+        },
+        Recipe {
+            id: "recipe_name_tag".into(),
+            name: "Name Tag".into(),
+            ingredients: vec![("fiber".into(), 3), ("sap".into(), 1)],
+            result: "name_tag".into(),
+            result_quantity: 1,
+            is_cooking: false,
+            unlocked_by_default: true,
+        },
         // ── Fishing Accessories ────────────────────────────────────────
         Recipe {
             id: "recipe_crab_pot".into(),
@@ -201,6 +229,24 @@ pub fn populate_recipes(registry: &mut RecipeRegistry) {
             is_cooking: false,
             unlocked_by_default: false, // Learned from Fishing level 3
         },
+        Recipe {
+            id: "recipe_fish_tank".into(),
+            name: "Fish Tank".into(),
+            ingredients: vec![("wood".into(), 30), ("quartz".into(), 2)],
+            result: "fish_tank".into(),
+            result_quantity: 1,
+            is_cooking: false,
+            unlocked_by_default: true,
+        },
+        Recipe {
+            id: "recipe_bedroll".into(),
+            name: "Bedroll".into(),
+            ingredients: vec![("fiber".into(), 30), ("cloth".into(), 1)],
+            result: "bedroll".into(),
+            result_quantity: 1,
+            is_cooking: false,
+            unlocked_by_default: true,
+        },
         // ── Miscellaneous Crafting ─────────────────────────────────────
         Recipe {
             id: "recipe_torch".into(),
@@ -110,6 +110,11 @@ pub fn populate_shops(shop_data: &mut ShopData) {
             price: 50,
             season_available: None,
         },
+        ShopListing {
+            item_id: "fertilizer".into(),
+            price: 100,
+            season_available: None,
+        },
         ShopListing {
             item_id: "bait".into(),
             price: 5,
@@ -137,6 +142,27 @@ pub fn populate_shops(shop_data: &mut ShopData) {
             price: 5_000,
             season_available: None,
         },
+        // ── Seasonal Goods (this season only) ────────────────────
+        ShopListing {
+            item_id: "daffodil".into(),
+            price: 80,
+            season_available: Some(Season::Spring),
+        },
+        ShopListing {
+            item_id: "sweet_pea".into(),
+            price: 120,
+            season_available: Some(Season::Summer),
+        },
+        ShopListing {
+            item_id: "pumpkin_bread".into(),
+            price: 180,
+            season_available: Some(Season::Fall),
+        },
+        ShopListing {
+            item_id: "crocus".into(),
+            price: 150,
+            season_available: Some(Season::Winter),
+        },
         // ── Recipes for Sale (represented as special items) ───────
         // Note: actual recipe unlock is handled by the economy domain;
         // here we list them at their purchasing prices. The economy
@@ -152,6 +178,27 @@ pub fn populate_shops(shop_data: &mut ShopData) {
             price: 300,
             season_available: None,
         },
+        // ── Starter bundle ────────────────────────────────────────
+        // One-time: grants `STARTER_BUNDLE_ITEMS` for a single price and
+        // disappears once bought.
+        ShopListing {
+            item_id: "starter_bundle".into(),
+            price: 450,
+            season_available: Some(Season::Spring),
+        },
+        // ── Backpack upgrades ─────────────────────────────────────
+        // Only the next upgrade in `BACKPACK_UPGRADES` is shown; buying
+        // one unlocks slots instead of adding an item.
+        ShopListing {
+            item_id: "backpack".into(),
+            price: 2_000,
+            season_available: None,
+        },
+        ShopListing {
+            item_id: "deluxe_backpack".into(),
+            price: 10_000,
+            season_available: None,
+        },
     ];
 
     shop_data
@@ -258,6 +305,11 @@ pub fn populate_shops(shop_data: &mut ShopData) {
             price: 50,
             season_available: None,
         },
+        ShopListing {
+            item_id: "animal_medicine".into(),
+            price: 150,
+            season_available: None,
+        },
     ];
 
     shop_data
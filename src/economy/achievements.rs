@@ -1,6 +1,6 @@
 //! Achievement system for Hearthfield.
 //!
-//! Defines all 30 achievements, checks conditions every frame during Playing state,
+//! Defines all 31 achievements, checks conditions every frame during Playing state,
 //! and fires `AchievementUnlockedEvent` when a new achievement is earned.
 //! Also tracks manually-counted progress counters via `Achievements.progress`.
 
@@ -16,159 +16,199 @@ pub struct AchievementDef {
     pub id: &'static str,
     pub name: &'static str,
     pub description: &'static str,
+    /// Counter in `Achievements.progress` that drives this achievement, and
+    /// the value it must reach. `None` for achievements checked against
+    /// other game state.
+    pub counter: Option<(&'static str, u32)>,
 }
 
-/// All 30 achievements defined statically.
+/// All 31 achievements defined statically.
 pub const ACHIEVEMENTS: &[AchievementDef] = &[
     AchievementDef {
         id: "first_harvest",
         name: "First Harvest",
         description: "Harvest your first crop",
+        counter: None,
     },
     AchievementDef {
         id: "green_thumb",
         name: "Green Thumb",
         description: "Harvest 100 crops",
+        counter: None,
     },
     AchievementDef {
         id: "master_farmer",
         name: "Master Farmer",
         description: "Harvest 1000 crops",
+        counter: None,
     },
     AchievementDef {
         id: "gone_fishin",
         name: "Gone Fishin'",
         description: "Catch your first fish",
+        counter: None,
     },
     AchievementDef {
         id: "angler",
         name: "Angler",
         description: "Catch 50 fish",
+        counter: None,
     },
     AchievementDef {
         id: "fisherman",
         name: "Fisherman",
         description: "Catch 100 fish",
+        counter: None,
+    },
+    AchievementDef {
+        id: "ichthyologist",
+        name: "Ichthyologist",
+        description: "Catch 20 different species of fish",
+        counter: Some(("fish_species", 20)),
     },
     AchievementDef {
         id: "social_butterfly",
         name: "Social Butterfly",
         description: "Reach 5 hearts with 5 NPCs",
+        counter: None,
     },
     AchievementDef {
         id: "best_friends",
         name: "Best Friends",
         description: "Reach 10 hearts with any NPC",
+        counter: None,
     },
     AchievementDef {
         id: "community_pillar",
         name: "Community Pillar",
         description: "Reach 5+ hearts with all 11 NPCs",
+        counter: None,
     },
     AchievementDef {
         id: "newlywed",
         name: "Newlywed",
         description: "Get married",
+        counter: None,
     },
     AchievementDef {
         id: "deep_pockets",
         name: "Deep Pockets",
         description: "Earn 100,000 gold total",
+        counter: None,
     },
     AchievementDef {
         id: "steady_income",
         name: "Steady Income",
         description: "Earn 10,000 gold",
+        counter: None,
     },
     AchievementDef {
         id: "millionaire",
         name: "Millionaire",
         description: "Have 1,000,000 gold",
+        counter: None,
     },
     AchievementDef {
         id: "shipping_mogul",
         name: "Shipping Mogul",
         description: "Ship 500 items",
+        counter: None,
     },
     AchievementDef {
         id: "spelunker",
         name: "Spelunker",
         description: "Reach mine floor 10",
+        counter: None,
     },
     AchievementDef {
         id: "mine_crawler",
         name: "Mine Crawler",
         description: "Reach mine floor 20",
+        counter: None,
     },
     AchievementDef {
         id: "chef",
         name: "Chef",
         description: "Cook 20 recipes",
+        counter: Some(("recipes_cooked", 20)),
     },
     AchievementDef {
         id: "all_seasons",
         name: "All Seasons",
         description: "Play through all 4 seasons",
+        counter: None,
     },
     AchievementDef {
         id: "second_year",
         name: "Second Year",
         description: "Reach Year 2",
+        counter: None,
     },
     AchievementDef {
         id: "pet_lover",
         name: "Pet Lover",
         description: "Max happiness on a pet",
+        counter: None,
     },
     AchievementDef {
         id: "rancher",
         name: "Rancher",
         description: "Own 12 animals",
+        counter: None,
     },
     AchievementDef {
         id: "early_riser",
         name: "Early Riser",
         description: "Water all crops before 8 AM",
+        counter: None,
     },
     AchievementDef {
         id: "night_owl",
         name: "Night Owl",
         description: "Still awake at 1 AM",
+        counter: None,
     },
     AchievementDef {
         id: "artisan",
         name: "Artisan",
         description: "Craft 20 items",
+        counter: Some(("crafts", 20)),
     },
     AchievementDef {
         id: "generous",
         name: "Generous",
         description: "Give 50 gifts",
+        counter: None,
     },
     AchievementDef {
         id: "gold_star",
         name: "Gold Star",
         description: "Harvest a gold-quality crop",
+        counter: Some(("gold_crops", 1)),
     },
     AchievementDef {
         id: "home_sweet_home",
         name: "Home Sweet Home",
         description: "Upgrade house to Deluxe",
+        counter: None,
     },
     AchievementDef {
         id: "green_acres",
         name: "Green Acres",
         description: "Plant 50 crops",
+        counter: Some(("crops_planted", 50)),
     },
     AchievementDef {
         id: "rock_breaker",
         name: "Rock Breaker",
         description: "Break 100 rocks",
+        counter: Some(("rocks_broken", 100)),
     },
     AchievementDef {
         id: "completionist",
         name: "Completionist",
         description: "Unlock 25 other achievements",
+        counter: None,
     },
 ];
 
@@ -202,6 +242,14 @@ fn evaluate_condition(
         "gone_fishin" => stats.fish_caught >= 1,
         "angler" => stats.fish_caught >= 50,
         "fisherman" => stats.fish_caught >= 100,
+        "ichthyologist" => {
+            achievements
+                .progress
+                .get("fish_species")
+                .copied()
+                .unwrap_or(0)
+                >= 20
+        }
 
         // ── Social ───────────────────────────────────────────────────────
         "social_butterfly" => {
@@ -424,11 +472,23 @@ pub fn notify_achievement_unlocked(
 /// - `crops_planted`  — incremented on `ToolUseEvent` with Hoe (soil tilling
 ///   is a reasonable proxy for planting intent); also
 ///   incremented on CropHarvestedEvent as a post-hoc count
+/// - `fish_species`   — mirrors `FishEncyclopedia::unique_species`
 pub fn track_achievement_progress(
     mut tool_events: EventReader<ToolUseEvent>,
     mut harvest_events: EventReader<CropHarvestedEvent>,
+    encyclopedia: Res<crate::fishing::FishEncyclopedia>,
     mut achievements: ResMut<Achievements>,
 ) {
+    // ── Encyclopedia → fish_species ──────────────────────────────────
+    if encyclopedia.is_changed() {
+        let species = encyclopedia.unique_species() as u32;
+        let counter = achievements
+            .progress
+            .entry("fish_species".to_string())
+            .or_insert(0);
+        *counter = (*counter).max(species);
+    }
+
     // ── Pickaxe swings → rocks_broken ────────────────────────────────
     for ev in tool_events.read() {
         match ev.tool {
@@ -480,8 +540,8 @@ mod tests {
     }
 
     #[test]
-    fn test_achievement_count_exactly_30() {
-        assert_eq!(ACHIEVEMENTS.len(), 30);
+    fn test_achievement_count_exactly_31() {
+        assert_eq!(ACHIEVEMENTS.len(), 31);
     }
 
     #[test]
@@ -509,6 +569,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_counter_targets_match_conditions() {
+        for def in ACHIEVEMENTS {
+            let Some((counter, target)) = def.counter else {
+                continue;
+            };
+            let mut achievements = Achievements::default();
+            let check = |achievements: &Achievements| {
+                evaluate_condition(
+                    def.id,
+                    &PlayStats::default(),
+                    &Relationships::default(),
+                    &PlayerState::default(),
+                    &Calendar::default(),
+                    &AnimalState::default(),
+                    &MarriageState::default(),
+                    &MineState::default(),
+                    achievements,
+                    &HouseState::default(),
+                    &FarmState::default(),
+                )
+            };
+            achievements
+                .progress
+                .insert(counter.to_string(), target - 1);
+            assert!(!check(&achievements), "'{}' unlocked early", def.id);
+            achievements.progress.insert(counter.to_string(), target);
+            assert!(check(&achievements), "'{}' not unlocked at target", def.id);
+        }
+    }
+
     #[test]
     fn test_evaluate_condition_first_harvest() {
         let mut stats = PlayStats::default();
@@ -51,6 +51,15 @@ pub struct BuildingLevels {
     pub upgrade_in_progress: Option<(BuildingKind, BuildingTier, u8)>,
 }
 
+/// Hay capacity provided by the current buildings. Only a built silo stores hay.
+pub fn silo_capacity(levels: &BuildingLevels) -> u32 {
+    if levels.silo_built {
+        SILO_HAY_CAPACITY
+    } else {
+        0
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Systems
 // ─────────────────────────────────────────────────────────────────────────────
@@ -236,6 +245,19 @@ pub fn tick_building_upgrade(
     }
 }
 
+/// Keeps [`SiloState::capacity`] in step with [`BuildingLevels`], e.g. when
+/// the silo finishes construction or a save is loaded.
+pub fn sync_silo_capacity(building_levels: Res<BuildingLevels>, mut silo: ResMut<SiloState>) {
+    if !building_levels.is_changed() {
+        return;
+    }
+    let capacity = silo_capacity(&building_levels);
+    if silo.capacity != capacity {
+        silo.capacity = capacity;
+        silo.hay = silo.hay.min(capacity);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -316,6 +338,14 @@ mod tests {
         assert_eq!(BuildingTier::Deluxe.capacity(), 12);
     }
 
+    #[test]
+    fn test_silo_capacity_requires_built_silo() {
+        let mut levels = BuildingLevels::default();
+        assert_eq!(silo_capacity(&levels), 0);
+        levels.silo_built = true;
+        assert_eq!(silo_capacity(&levels), SILO_HAY_CAPACITY);
+    }
+
     #[test]
     fn test_building_levels_default() {
         let levels = BuildingLevels::default();
@@ -10,6 +10,54 @@ pub struct EconomyStats {
     pub total_transactions: u64,
 }
 
+/// Today's gold movements grouped by reason, e.g. "Shipping bin sold +420g,
+/// Tool upgrade -2000g". Shown on the end-of-day card, then cleared.
+/// Saved so a mid-day save/load keeps the running totals.
+#[derive(Resource, Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct DailyLedger {
+    /// (category, net gold) pairs in the order each category first appeared.
+    pub entries: Vec<(String, i64)>,
+}
+
+impl DailyLedger {
+    /// Add `amount` to the running total for the reason's category.
+    pub fn record(&mut self, reason: &str, amount: i64) {
+        let category = ledger_category(reason);
+        if let Some(entry) = self.entries.iter_mut().find(|(c, _)| c == category) {
+            entry.1 += amount;
+        } else {
+            self.entries.push((category.to_string(), amount));
+        }
+    }
+
+    /// Multi-line summary for the end-of-day card, or `None` if no gold moved.
+    pub fn summary_text(&self) -> Option<String> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let mut lines = vec!["Today's ledger".to_string()];
+        for (category, amount) in &self.entries {
+            lines.push(format!("{} {:+}g", category, amount));
+        }
+        let net: i64 = self.entries.iter().map(|(_, a)| a).sum();
+        lines.push(format!("Net {:+}g", net));
+        Some(lines.join("\n"))
+    }
+}
+
+/// Collapse a free-form gold reason into a ledger category by dropping
+/// per-event detail: "Shipping bin sold (3 items)" → "Shipping bin sold",
+/// "Quest completed: Lost Ring" → "Quest completed".
+pub fn ledger_category(reason: &str) -> &str {
+    let end = reason.find([':', '(']).unwrap_or(reason.len());
+    let category = reason[..end].trim();
+    if category.is_empty() {
+        "Other"
+    } else {
+        category
+    }
+}
+
 /// Applies GoldChangeEvents to PlayerState.gold.
 /// Validates that spending does not put gold below 0 (clamped to 0).
 /// Tracks total_gold_earned for EconomyStats.
@@ -17,12 +65,14 @@ pub fn apply_gold_changes(
     mut gold_events: EventReader<GoldChangeEvent>,
     mut player_state: ResMut<PlayerState>,
     mut stats: ResMut<EconomyStats>,
+    mut ledger: ResMut<DailyLedger>,
 ) {
     for ev in gold_events.read() {
         if ev.amount >= 0 {
             let gain = ev.amount as u32;
             player_state.gold = player_state.gold.saturating_add(gain);
             stats.total_gold_earned = stats.total_gold_earned.saturating_add(gain as u64);
+            ledger.record(&ev.reason, gain as i64);
             info!(
                 "[Economy] Gold +{}: {}. New balance: {}g",
                 gain, ev.reason, player_state.gold
@@ -32,6 +82,7 @@ pub fn apply_gold_changes(
             if player_state.gold >= cost {
                 player_state.gold -= cost;
                 stats.total_gold_spent = stats.total_gold_spent.saturating_add(cost as u64);
+                ledger.record(&ev.reason, -(cost as i64));
                 info!(
                     "[Economy] Gold -{}: {}. New balance: {}g",
                     cost, ev.reason, player_state.gold
@@ -46,6 +97,7 @@ pub fn apply_gold_changes(
                 stats.total_gold_spent = stats
                     .total_gold_spent
                     .saturating_add(player_state.gold as u64);
+                ledger.record(&ev.reason, -(player_state.gold as i64));
                 player_state.gold = 0;
             }
         }
@@ -53,6 +105,34 @@ pub fn apply_gold_changes(
     }
 }
 
+/// On day end, show the day's ledger on the sleep card (just before the
+/// "Day N" text) and reset it for tomorrow. Runs in PostUpdate so the
+/// shipping payout sent on the same DayEndEvent has already been applied.
+pub fn close_daily_ledger(
+    mut day_end_events: EventReader<DayEndEvent>,
+    mut ledger: ResMut<DailyLedger>,
+    mut cutscene_queue: ResMut<CutsceneQueue>,
+) {
+    if day_end_events.read().last().is_none() {
+        return;
+    }
+    if let Some(text) = ledger.summary_text() {
+        info!("[Economy] {}", text.replace('\n', " | "));
+        // Only extend a day-transition cutscene that is already queued;
+        // a lone text card would play without its fade.
+        if let Some(at) = cutscene_queue
+            .steps
+            .iter()
+            .rposition(|step| matches!(step, CutsceneStep::ShowText(..)))
+        {
+            cutscene_queue
+                .steps
+                .insert(at, CutsceneStep::ShowText(text, 3.0));
+        }
+    }
+    ledger.entries.clear();
+}
+
 /// Format a gold amount as a display string (e.g. "1,234g").
 #[allow(dead_code)]
 pub fn format_gold(amount: u32) -> String {
@@ -94,6 +174,83 @@ mod tests {
         assert_eq!(format_gold(100000), "100,000g");
     }
 
+    #[test]
+    fn test_daily_ledger_totals_by_reason() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<GoldChangeEvent>()
+            .insert_resource(PlayerState {
+                gold: 5000,
+                ..default()
+            })
+            .init_resource::<EconomyStats>()
+            .init_resource::<DailyLedger>()
+            .add_systems(Update, apply_gold_changes);
+
+        for (amount, reason) in [
+            (300, "Shipping bin sold (3 items)"),
+            (120, "Shipping bin sold (1 items)"),
+            (-150, "Seeds"),
+            (-2000, "Tool upgrade: Hoe → Copper"),
+            (-50, "Seeds"),
+        ] {
+            app.world_mut().send_event(GoldChangeEvent {
+                amount,
+                reason: reason.to_string(),
+            });
+        }
+        app.update();
+
+        let ledger = app.world().resource::<DailyLedger>();
+        assert_eq!(
+            ledger.entries,
+            vec![
+                ("Shipping bin sold".to_string(), 420),
+                ("Seeds".to_string(), -200),
+                ("Tool upgrade".to_string(), -2000),
+            ]
+        );
+        assert_eq!(app.world().resource::<PlayerState>().gold, 3220);
+    }
+
+    #[test]
+    fn test_daily_ledger_shown_before_day_card_and_reset() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<DayEndEvent>()
+            .init_resource::<DailyLedger>()
+            .init_resource::<CutsceneQueue>()
+            .add_systems(Update, close_daily_ledger);
+
+        app.world_mut()
+            .resource_mut::<DailyLedger>()
+            .record("Shipping bin sold (2 items)", 420);
+        {
+            let mut queue = app.world_mut().resource_mut::<CutsceneQueue>();
+            queue.steps.push_back(CutsceneStep::FadeOut(1.0));
+            queue
+                .steps
+                .push_back(CutsceneStep::ShowText("Day 2".into(), 2.0));
+            queue.steps.push_back(CutsceneStep::FadeIn(1.0));
+        }
+        app.world_mut().send_event(DayEndEvent {
+            day: 1,
+            season: Season::Spring,
+            year: 1,
+        });
+        app.update();
+
+        let queue = app.world().resource::<CutsceneQueue>();
+        match &queue.steps[1] {
+            CutsceneStep::ShowText(text, _) => {
+                assert!(text.contains("Shipping bin sold +420g"), "{}", text)
+            }
+            other => panic!("expected ledger text, got {:?}", other),
+        }
+        assert!(matches!(&queue.steps[2], CutsceneStep::ShowText(t, _) if t == "Day 2"));
+        assert!(app.world().resource::<DailyLedger>().entries.is_empty());
+    }
+
     #[test]
     fn test_economy_stats_default() {
         let stats = EconomyStats::default();
@@ -0,0 +1,151 @@
+//! Mail — letters and deliveries that reach the farm mailbox overnight.
+//!
+//! Any domain can queue a letter with `Mailbox::send`; it shows up in the
+//! mailbox the next morning. Attachments are moved into the inventory when the
+//! player collects the letter from the mailbox screen.
+
+use crate::shared::*;
+use bevy::prelude::*;
+
+/// Fires on DayEndEvent: delivers today's outgoing mail and lets the player
+/// know there is something waiting in the mailbox.
+pub fn deliver_mail_on_day_end(
+    mut day_end_events: EventReader<DayEndEvent>,
+    mut mailbox: ResMut<Mailbox>,
+    mut toast_writer: EventWriter<ToastEvent>,
+) {
+    for _ev in day_end_events.read() {
+        let arriving = mailbox.pending.len();
+        mailbox.deliver();
+        if arriving == 0 {
+            continue;
+        }
+
+        info!(
+            "[Economy] Delivered {} letter(s); {} waiting in the mailbox.",
+            arriving,
+            mailbox.letters.len()
+        );
+        toast_writer.send(ToastEvent {
+            message: format!(
+                "You've got mail! {} letter(s) in your mailbox.",
+                mailbox.letters.len()
+            ),
+            duration_secs: 4.0,
+        });
+    }
+}
+
+/// Removes letter `index` from the mailbox and moves its attachment into the
+/// inventory. Returns the collected letter.
+///
+/// If the attachment only partly fits, the remainder stays on the letter, the
+/// letter stays in the mailbox, and `None` is returned.
+pub fn collect_letter(
+    mailbox: &mut Mailbox,
+    index: usize,
+    inventory: &mut Inventory,
+    item_registry: &ItemRegistry,
+) -> Option<MailItem> {
+    let letter = mailbox.letters.get_mut(index)?;
+
+    if let Some((item_id, quantity)) = letter.attachment.as_mut() {
+        let max_stack = item_registry
+            .get(item_id)
+            .map(|def| def.stack_size)
+            .unwrap_or(99);
+        let leftover = inventory.try_add(item_id, *quantity, max_stack);
+        if leftover > 0 {
+            *quantity = leftover;
+            return None;
+        }
+    }
+
+    Some(mailbox.letters.remove(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn turnip_registry() -> ItemRegistry {
+        let mut registry = ItemRegistry::default();
+        registry.items.insert(
+            "turnip".to_string(),
+            ItemDef {
+                id: "turnip".to_string(),
+                name: "Turnip".to_string(),
+                description: String::new(),
+                category: ItemCategory::Crop,
+                sell_price: 60,
+                buy_price: None,
+                stack_size: 99,
+                edible: false,
+                energy_restore: 0.0,
+                sprite_index: 0,
+            },
+        );
+        registry
+    }
+
+    fn letter_with_turnips(quantity: u8) -> MailItem {
+        MailItem {
+            subject: "From Nora".to_string(),
+            body: "A few turnips from my field.".to_string(),
+            attachment: Some(("turnip".to_string(), quantity)),
+        }
+    }
+
+    #[test]
+    fn queued_mail_arrives_next_morning_and_attachment_is_collected() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<DayEndEvent>()
+            .add_event::<ToastEvent>()
+            .init_resource::<Mailbox>()
+            .add_systems(Update, deliver_mail_on_day_end);
+
+        app.world_mut()
+            .resource_mut::<Mailbox>()
+            .send(letter_with_turnips(5));
+        app.update();
+        assert!(app.world().resource::<Mailbox>().letters.is_empty());
+
+        app.world_mut().send_event(DayEndEvent {
+            day: 1,
+            season: Season::Spring,
+            year: 1,
+        });
+        app.update();
+        let mut mailbox = app.world().resource::<Mailbox>().clone();
+        assert_eq!(mailbox.letters.len(), 1);
+        assert!(mailbox.pending.is_empty());
+
+        let mut inventory = Inventory::default();
+        let letter = collect_letter(&mut mailbox, 0, &mut inventory, &turnip_registry());
+        assert_eq!(letter.map(|l| l.subject), Some("From Nora".to_string()));
+        assert_eq!(inventory.count("turnip"), 5);
+        assert!(mailbox.letters.is_empty());
+    }
+
+    #[test]
+    fn attachment_that_does_not_fit_stays_in_mailbox() {
+        let registry = turnip_registry();
+        let mut inventory = Inventory::default();
+        for slot in inventory.slots.iter_mut() {
+            *slot = Some(InventorySlot {
+                item_id: "stone".to_string(),
+                quantity: 1,
+                quality: ItemQuality::Normal,
+            });
+        }
+        let mut mailbox = Mailbox {
+            letters: vec![letter_with_turnips(3)],
+            ..default()
+        };
+
+        assert!(collect_letter(&mut mailbox, 0, &mut inventory, &registry).is_none());
+        assert_eq!(mailbox.letters.len(), 1);
+        assert_eq!(inventory.count("turnip"), 0);
+    }
+}
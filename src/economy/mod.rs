@@ -11,6 +11,7 @@ pub mod blacksmith;
 pub mod buildings;
 pub mod evaluation;
 pub mod gold;
+pub mod mail;
 pub mod play_stats;
 pub mod shipping;
 pub mod shop;
@@ -22,9 +23,12 @@ use blacksmith::{
     drain_upgrade_complete, handle_upgrade_request, tick_upgrade_queue, ToolUpgradeCompleteEvent,
     ToolUpgradeQueue, ToolUpgradeRequestEvent,
 };
-use buildings::{handle_building_upgrade_request, tick_building_upgrade, BuildingLevels};
+use buildings::{
+    handle_building_upgrade_request, sync_silo_capacity, tick_building_upgrade, BuildingLevels,
+};
 use evaluation::{check_evaluation_trigger, handle_evaluation};
-use gold::{apply_gold_changes, EconomyStats};
+use gold::{apply_gold_changes, close_daily_ledger, DailyLedger, EconomyStats};
+use mail::deliver_mail_on_day_end;
 use play_stats::{
     track_animal_products_collected, track_crops_harvested, track_day_end, track_fish_caught,
     track_food_eaten, track_gifts_given, track_gold_earned,
@@ -34,8 +38,8 @@ use shipping::{
     ShipItemEvent, ShippingBinPreview, ShippingBinQuality,
 };
 use shop::{
-    handle_shop_transaction_gold, on_enter_shop, on_exit_shop, refresh_shop_affordability,
-    ActiveShop,
+    clear_buyback_on_day_end, on_enter_shop, on_exit_shop, record_shop_sales_for_buyback,
+    refresh_shop_affordability, ActiveShop, ShopBuyback,
 };
 use stats::{track_animal_products, track_crop_harvests, AnimalProductStats, HarvestStats};
 
@@ -49,7 +53,9 @@ impl Plugin for EconomyPlugin {
     fn build(&self, app: &mut App) {
         // ── Resources ──────────────────────────────────────────────────────
         app.init_resource::<EconomyStats>()
+            .init_resource::<DailyLedger>()
             .init_resource::<ActiveShop>()
+            .init_resource::<ShopBuyback>()
             .init_resource::<ShippingBinPreview>()
             .init_resource::<ToolUpgradeQueue>()
             .init_resource::<HarvestStats>()
@@ -73,7 +79,9 @@ impl Plugin for EconomyPlugin {
                 // Update the pending-value preview for the HUD.
                 update_shipping_bin_preview,
                 // Gold change events can arrive from any domain at any time.
-                apply_gold_changes,
+                // Ordered after shipping so the day-end payout lands in the
+                // same frame's ledger.
+                apply_gold_changes.after(process_shipping_bin_on_day_end),
                 // Day-end: process the shipping bin sell-through.
                 process_shipping_bin_on_day_end,
                 // Day-end: tick tool upgrade timers.
@@ -112,10 +120,32 @@ impl Plugin for EconomyPlugin {
                 .run_if(in_state(GameState::Playing)),
         );
 
+        // ── Mail: today's letters arrive in the farm mailbox overnight ─────
+        app.add_systems(
+            Update,
+            deliver_mail_on_day_end.run_if(in_state(GameState::Playing)),
+        );
+
+        // ── Shop buyback lists are forgotten overnight ─────────────────────
+        app.add_systems(
+            Update,
+            clear_buyback_on_day_end.run_if(in_state(GameState::Playing)),
+        );
+
+        // ── Day-end ledger: after every Update system has applied gold ─────
+        app.add_systems(
+            PostUpdate,
+            close_daily_ledger.run_if(in_state(GameState::Playing)),
+        );
+
         // ── Systems: Building upgrades (Playing state) ─────────────────────
         app.add_systems(
             Update,
-            (handle_building_upgrade_request, tick_building_upgrade)
+            (
+                handle_building_upgrade_request,
+                tick_building_upgrade,
+                sync_silo_capacity,
+            )
                 .run_if(in_state(GameState::Playing)),
         );
 
@@ -129,11 +159,12 @@ impl Plugin for EconomyPlugin {
                 handle_upgrade_request,
                 // Allow exiting the shop with Escape.
                 on_exit_shop,
-                // Gold changes can also arrive while in the shop
-                // (e.g., from another concurrent event — keep it consistent).
-                apply_gold_changes,
-                // Track buy/sell transactions in EconomyStats and PlayStats via GoldChangeEvent.
-                handle_shop_transaction_gold,
+                // Shop purchases and sales arrive as GoldChangeEvents; applying
+                // them after the shop UI's intent phase keeps gold current
+                // before the next transaction is validated.
+                apply_gold_changes.in_set(UpdatePhase::Reactions),
+                // Remember sales so the player can buy them back today.
+                record_shop_sales_for_buyback,
             )
                 .run_if(in_state(GameState::Shop)),
         );
@@ -1,4 +1,5 @@
 use crate::economy::gold::EconomyStats;
+use crate::economy::shop::is_sell_locked;
 use crate::shared::*;
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -64,8 +65,8 @@ pub fn place_in_shipping_bin(
             continue;
         }
 
-        // Remove from inventory.
-        let removed = inventory.try_remove(&ev.item_id, quantity);
+        // Remove from inventory, taking the stacks of the shipped quality.
+        let removed = inventory.try_remove_quality(&ev.item_id, quantity, ev.quality);
         if removed == 0 {
             continue;
         }
@@ -82,6 +83,7 @@ pub fn place_in_shipping_bin(
             shipping_bin.items.push(InventorySlot {
                 item_id: ev.item_id.clone(),
                 quantity: removed,
+                quality: ItemQuality::Normal,
             });
         }
 
@@ -116,10 +118,95 @@ pub fn place_in_shipping_bin(
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Shipping collection
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Categories the shipping collection tracks, in display order. Tools, key
+/// items and placeable furniture are left out.
+pub const COLLECTION_CATEGORIES: &[ItemCategory] = &[
+    ItemCategory::Crop,
+    ItemCategory::Seed,
+    ItemCategory::AnimalProduct,
+    ItemCategory::ArtisanGood,
+    ItemCategory::Fish,
+    ItemCategory::Mineral,
+    ItemCategory::Gem,
+    ItemCategory::CraftingMaterial,
+    ItemCategory::Food,
+    ItemCategory::Gift,
+];
+
+/// Gold mailed to the player the first time every item in a collection
+/// category has been shipped.
+pub const COLLECTION_CATEGORY_REWARD: u32 = 1_000;
+
+/// Display name for a collection category.
+pub fn collection_category_label(category: ItemCategory) -> &'static str {
+    match category {
+        ItemCategory::Seed => "Seeds",
+        ItemCategory::Crop => "Crops",
+        ItemCategory::AnimalProduct => "Animal Products",
+        ItemCategory::ArtisanGood => "Artisan Goods",
+        ItemCategory::Fish => "Fish",
+        ItemCategory::Mineral => "Minerals",
+        ItemCategory::Gem => "Gems",
+        ItemCategory::CraftingMaterial => "Materials",
+        ItemCategory::Food => "Cooking",
+        ItemCategory::Tool => "Tools",
+        ItemCategory::Furniture => "Furniture",
+        ItemCategory::Gift => "Gifts",
+        ItemCategory::Special => "Special Items",
+    }
+}
+
+/// Whether an item counts toward the shipping collection.
+pub fn is_collectable(def: &ItemDef) -> bool {
+    def.sell_price > 0 && !is_sell_locked(def) && def.category != ItemCategory::Furniture
+}
+
+/// Collectable items of `category`, sorted by name for display.
+pub fn collection_items(category: ItemCategory, registry: &ItemRegistry) -> Vec<&ItemDef> {
+    let mut items: Vec<&ItemDef> = registry
+        .items
+        .values()
+        .filter(|def| def.category == category && is_collectable(def))
+        .collect();
+    items.sort_by(|a, b| a.name.cmp(&b.name));
+    items
+}
+
+/// (shipped at least once, total) for the collectable items of `category`.
+pub fn category_completion(
+    category: ItemCategory,
+    log: &ShippingLog,
+    registry: &ItemRegistry,
+) -> (u32, u32) {
+    let items = collection_items(category, registry);
+    let done = items
+        .iter()
+        .filter(|def| log.shipped_items.get(&def.id).is_some_and(|&n| n > 0))
+        .count();
+    (done as u32, items.len() as u32)
+}
+
+fn completed_categories(log: &ShippingLog, registry: &ItemRegistry) -> Vec<ItemCategory> {
+    COLLECTION_CATEGORIES
+        .iter()
+        .copied()
+        .filter(|&category| {
+            let (done, total) = category_completion(category, log, registry);
+            total > 0 && done == total
+        })
+        .collect()
+}
+
 /// Fires on DayEndEvent: sells everything in the shipping bin, adds gold, clears bin.
-/// This is the primary income source for the player.
+/// This is the primary income source for the player. The earnings are toasted
+/// and a receipt is left in the mailbox for the morning.
 /// Quality multipliers (Silver 1.25x, Gold 1.5x, Iridium 2.0x) are applied via
-/// the parallel `ShippingBinQuality` resource.
+/// the parallel `ShippingBinQuality` resource. Shipping the last missing item
+/// of a collection category mails a one-time `COLLECTION_CATEGORY_REWARD`.
 #[allow(clippy::too_many_arguments)]
 pub fn process_shipping_bin_on_day_end(
     mut day_end_events: EventReader<DayEndEvent>,
@@ -131,6 +218,7 @@ pub fn process_shipping_bin_on_day_end(
     mut shipping_log: ResMut<ShippingLog>,
     mut sfx_writer: EventWriter<PlaySfxEvent>,
     mut toast_writer: EventWriter<ToastEvent>,
+    mut mailbox: ResMut<Mailbox>,
 ) {
     for _ev in day_end_events.read() {
         if shipping_bin.items.is_empty() {
@@ -140,6 +228,7 @@ pub fn process_shipping_bin_on_day_end(
         let mut total_value: u32 = 0;
         let mut items_shipped: u64 = 0;
         let mut sale_details: Vec<String> = Vec::new();
+        let completed_before = completed_categories(&shipping_log, &item_registry);
 
         // Use the quality-aware entries for pricing when available.
         // Each entry is (item_id, quantity, quality).
@@ -214,6 +303,39 @@ pub fn process_shipping_bin_on_day_end(
             duration_secs: 4.0,
         });
 
+        // The receipt goes straight into the mailbox: the sale happened
+        // overnight, so it is already waiting when the player wakes up.
+        mailbox.letters.push(MailItem {
+            subject: format!("Shipping receipt: {}g", total_value),
+            body: format!(
+                "Your shipment of {} items sold overnight for {}g. The gold has been added to your purse.",
+                items_shipped, total_value
+            ),
+            attachment: None,
+        });
+
+        for category in completed_categories(&shipping_log, &item_registry) {
+            if completed_before.contains(&category) {
+                continue;
+            }
+            gold_writer.send(GoldChangeEvent {
+                amount: COLLECTION_CATEGORY_REWARD as i32,
+                reason: format!(
+                    "Shipping collection complete: {}",
+                    collection_category_label(category)
+                ),
+            });
+            mailbox.letters.push(MailItem {
+                subject: format!("Collection complete: {}", collection_category_label(category)),
+                body: format!(
+                    "You've now shipped one of everything in {}. The shipping company sends {}g with its thanks.",
+                    collection_category_label(category),
+                    COLLECTION_CATEGORY_REWARD
+                ),
+                attachment: None,
+            });
+        }
+
         sfx_writer.send(PlaySfxEvent {
             sfx_id: "sell".to_string(),
         });
@@ -321,6 +443,7 @@ mod tests {
             items: vec![InventorySlot {
                 item_id: "turnip".to_string(),
                 quantity: 5,
+                quality: ItemQuality::Normal,
             }],
         };
         let quality = ShippingBinQuality {
@@ -337,10 +460,12 @@ mod tests {
                 InventorySlot {
                     item_id: "turnip".to_string(),
                     quantity: 3,
+                    quality: ItemQuality::Normal,
                 },
                 InventorySlot {
                     item_id: "potato".to_string(),
                     quantity: 2,
+                    quality: ItemQuality::Normal,
                 },
             ],
         };
@@ -361,6 +486,7 @@ mod tests {
             items: vec![InventorySlot {
                 item_id: "mystery_item".to_string(),
                 quantity: 10,
+                quality: ItemQuality::Normal,
             }],
         };
         // No quality entries — falls back to bin items with Normal quality
@@ -375,6 +501,7 @@ mod tests {
             items: vec![InventorySlot {
                 item_id: "turnip".to_string(),
                 quantity: 4,
+                quality: ItemQuality::Normal,
             }],
         };
         // 1 Normal (100), 1 Silver (125), 1 Gold (150), 1 Iridium (200)
@@ -390,6 +517,51 @@ mod tests {
         assert_eq!(calculate_bin_value(&quality, &bin, &registry), 575);
     }
 
+    #[test]
+    fn test_category_completion_counts_shipped_collectables() {
+        let mut registry = make_registry_with(vec![("turnip", 60), ("potato", 80), ("weed", 0)]);
+        registry.items.insert(
+            "sardine".to_string(),
+            ItemDef {
+                id: "sardine".to_string(),
+                name: "sardine".to_string(),
+                category: ItemCategory::Fish,
+                ..registry.items["turnip"].clone()
+            },
+        );
+        let mut log = ShippingLog::default();
+        assert_eq!(
+            category_completion(ItemCategory::Crop, &log, &registry),
+            (0, 2),
+            "worthless items are not part of the collection"
+        );
+
+        log.shipped_items.insert("turnip".to_string(), 12);
+        log.shipped_items.insert("sardine".to_string(), 1);
+        assert_eq!(
+            category_completion(ItemCategory::Crop, &log, &registry),
+            (1, 2)
+        );
+        assert_eq!(
+            category_completion(ItemCategory::Fish, &log, &registry),
+            (1, 1)
+        );
+        assert_eq!(
+            completed_categories(&log, &registry),
+            vec![ItemCategory::Fish]
+        );
+
+        log.shipped_items.insert("potato".to_string(), 1);
+        assert_eq!(
+            category_completion(ItemCategory::Crop, &log, &registry),
+            (2, 2)
+        );
+        assert_eq!(
+            category_completion(ItemCategory::Gem, &log, &registry),
+            (0, 0)
+        );
+    }
+
     #[test]
     fn test_shipping_bin_preview_default() {
         let preview = ShippingBinPreview::default();
@@ -1,6 +1,14 @@
 use crate::npcs::spawning::SpawnedNpcs;
 use crate::shared::*;
 use bevy::prelude::*;
+use std::collections::{HashMap, VecDeque};
+
+/// How many sold stacks each shop remembers for buyback.
+pub const BUYBACK_CAPACITY: usize = 10;
+
+/// Most a shop will pay for a buyable item, as a fraction of what it charges.
+/// Stops buy-then-sell loops from turning a profit.
+pub const SHOP_SELL_FRACTION: f32 = 0.5;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Resources
@@ -26,16 +34,59 @@ pub struct ActiveListing {
     pub can_afford: bool, // cached against current gold — UI re-reads per frame
 }
 
+/// Items the player sold today, per shop, so an accidental sale can be undone.
+/// Each entry is `(item_id, quantity, price_per_unit, quality)` at the price and
+/// quality it sold for; the newest sale is at the back.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ShopBuyback {
+    pub entries: HashMap<ShopId, VecDeque<(ItemId, u8, u32, ItemQuality)>>,
+}
+
+impl ShopBuyback {
+    /// Buyback entries for one shop, oldest first.
+    pub fn for_shop(&self, shop_id: ShopId) -> Option<&VecDeque<(ItemId, u8, u32, ItemQuality)>> {
+        self.entries.get(&shop_id)
+    }
+
+    /// Remember a sale. Repeated sales of the same item at the same price and
+    /// quality stack onto the newest entry; the oldest entry drops off past
+    /// `BUYBACK_CAPACITY`.
+    pub fn record_sale(
+        &mut self,
+        shop_id: ShopId,
+        item_id: &str,
+        quantity: u8,
+        price: u32,
+        quality: ItemQuality,
+    ) {
+        let queue = self.entries.entry(shop_id).or_default();
+        if let Some((last_id, last_qty, last_price, last_quality)) = queue.back_mut() {
+            if last_id == item_id
+                && *last_price == price
+                && *last_quality == quality
+                && last_qty.checked_add(quantity).is_some()
+            {
+                *last_qty += quantity;
+                return;
+            }
+        }
+        queue.push_back((item_id.to_string(), quantity, price, quality));
+        while queue.len() > BUYBACK_CAPACITY {
+            queue.pop_front();
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Systems
 // ─────────────────────────────────────────────────────────────────────────────
 
-/// Detects MapTransitionEvents for shop maps and:
+/// Detects HonoredMapTransitionEvents for shop maps and:
 ///   1. Transitions GameState to GameState::Shop
 ///   2. Populates ActiveShop with season-filtered listings
 #[allow(clippy::too_many_arguments)]
 pub fn on_enter_shop(
-    mut map_events: EventReader<MapTransitionEvent>,
+    mut map_events: EventReader<HonoredMapTransitionEvent>,
     shop_data: Res<ShopData>,
     item_registry: Res<ItemRegistry>,
     player_state: Res<PlayerState>,
@@ -129,29 +180,27 @@ pub fn on_exit_shop(
     }
 }
 
-/// Updates EconomyStats directly for shop transactions without sending
-/// GoldChangeEvent. The shop UI already mutates player.gold directly for
-/// immediate feedback; sending GoldChangeEvent would cause apply_gold_changes
-/// to modify gold a second time (double deduction bug).
-pub fn handle_shop_transaction_gold(
+/// Adds every shop sale to that shop's buyback list.
+pub fn record_shop_sales_for_buyback(
     mut tx_events: EventReader<ShopTransactionEvent>,
-    mut stats: ResMut<super::gold::EconomyStats>,
+    mut buyback: ResMut<ShopBuyback>,
 ) {
     for ev in tx_events.read() {
-        if ev.is_purchase {
-            stats.total_gold_spent = stats.total_gold_spent.saturating_add(ev.total_cost as u64);
-            info!(
-                "[Economy] Shop buy stats: {} for {}g. Total spent: {}g",
-                ev.item_id, ev.total_cost, stats.total_gold_spent
-            );
-        } else {
-            stats.total_gold_earned = stats.total_gold_earned.saturating_add(ev.total_cost as u64);
-            info!(
-                "[Economy] Shop sell stats: {} for {}g. Total earned: {}g",
-                ev.item_id, ev.total_cost, stats.total_gold_earned
-            );
+        if ev.is_purchase || ev.quantity == 0 {
+            continue;
         }
-        stats.total_transactions += 1;
+        let price = ev.total_cost / ev.quantity as u32;
+        buyback.record_sale(ev.shop_id, &ev.item_id, ev.quantity, price, ev.quality);
+    }
+}
+
+/// Shopkeepers restock overnight; anything not bought back is gone.
+pub fn clear_buyback_on_day_end(
+    mut day_end_events: EventReader<DayEndEvent>,
+    mut buyback: ResMut<ShopBuyback>,
+) {
+    if day_end_events.read().count() > 0 {
+        buyback.entries.clear();
     }
 }
 
@@ -172,27 +221,40 @@ pub enum TransactionResult {
     InsufficientItems { need: u8, have: u8 },
     /// Item not found in the item registry.
     UnknownItem,
+    /// Tools and special items can't be sold.
+    Locked,
+    /// A one-time purchase (the starter bundle) has already been made.
+    AlreadyOwned,
+}
+
+/// Tools and special (quest/key) items never appear in a shop's sell list.
+pub fn is_sell_locked(def: &ItemDef) -> bool {
+    matches!(def.category, ItemCategory::Tool | ItemCategory::Special)
+}
+
+/// What a shop pays for one unit at `quality`: the item's `sell_price`,
+/// capped at `SHOP_SELL_FRACTION` of its buy price when the shop stocks it,
+/// then scaled by the quality multiplier.
+pub fn shop_sell_price(def: &ItemDef, quality: ItemQuality) -> u32 {
+    let base = match def.buy_price {
+        Some(buy) => def.sell_price.min((buy as f32 * SHOP_SELL_FRACTION) as u32),
+        None => def.sell_price,
+    };
+    (base as f32 * quality.sell_multiplier()) as u32
 }
 
 /// Attempts to buy `quantity` of `item_id` at `price_per_unit` from a shop.
 ///
-/// On success:
-///   - Deducts gold from `PlayerState`
-///   - Adds the item to `Inventory`
-///   - Fires a `ShopTransactionEvent` (is_purchase = true)
-///   - Fires a `GoldChangeEvent` (negative)
-///
-/// Returns a `TransactionResult` describing success or failure reason.
-///
-/// Note: This function mutates state directly for immediate UI feedback.
-/// The `GoldChangeEvent` is NOT sent (to avoid double-deduction — see
-/// `handle_shop_transaction_gold` which tracks stats separately).
-#[allow(dead_code)]
+/// Gold and inventory space are both validated before anything changes. On
+/// success the item is added to `Inventory` and the cost is returned in
+/// `Success { total }`; the caller charges it with a single
+/// `shop_gold_change` event, since `apply_gold_changes` is the only system
+/// that touches `PlayerState.gold`. On failure nothing is modified.
 pub fn try_buy(
     item_id: &str,
     quantity: u8,
     price_per_unit: u32,
-    player_state: &mut PlayerState,
+    player_state: &PlayerState,
     inventory: &mut Inventory,
     item_registry: &ItemRegistry,
 ) -> TransactionResult {
@@ -222,28 +284,56 @@ pub fn try_buy(
     let actually_added = quantity - remaining;
     let total_cost = price_per_unit.saturating_mul(actually_added as u32);
 
-    // Deduct gold.
-    player_state.gold = player_state.gold.saturating_sub(total_cost);
-
     TransactionResult::Success { total: total_cost }
 }
 
-/// Attempts to sell `quantity` of `item_id` from the player's inventory.
-///
-/// On success:
-///   - Removes the item from `Inventory`
-///   - Adds gold to `PlayerState`
+/// Attempts to buy the general store's one-time starter bundle: every stack
+/// in `STARTER_BUNDLE_ITEMS` for a single `price`. Gold and room for the whole
+/// set are checked up front, so the items arrive all together or not at all.
+/// On success the caller charges `total` and sets
+/// `PlayerState::starter_bundle_bought`.
+pub fn try_buy_starter_bundle(
+    price: u32,
+    player_state: &PlayerState,
+    inventory: &mut Inventory,
+    item_registry: &ItemRegistry,
+) -> TransactionResult {
+    if player_state.starter_bundle_bought {
+        return TransactionResult::AlreadyOwned;
+    }
+    if player_state.gold < price {
+        return TransactionResult::InsufficientGold {
+            need: price,
+            have: player_state.gold,
+        };
+    }
+
+    let mut filled = inventory.clone();
+    for (item_id, quantity) in STARTER_BUNDLE_ITEMS {
+        let Some(def) = item_registry.get(item_id) else {
+            return TransactionResult::UnknownItem;
+        };
+        if filled.try_add(item_id, quantity, def.stack_size) > 0 {
+            return TransactionResult::InventoryFull;
+        }
+    }
+    *inventory = filled;
+
+    TransactionResult::Success { total: price }
+}
+
+/// Attempts to sell `quantity` of `item_id` at `quality` from the player's
+/// inventory.
 ///
-/// The sell price comes from the item's `sell_price` in the registry, modified
-/// by the optional quality multiplier.
+/// On success that many items of that quality are removed from `Inventory`
+/// and the revenue is returned in `Success { total }` for the caller to pay
+/// out via `shop_gold_change`. The per-unit price comes from `shop_sell_price`.
 ///
 /// Returns a `TransactionResult` describing success or failure reason.
-#[allow(dead_code)]
 pub fn try_sell(
     item_id: &str,
     quantity: u8,
     quality: crate::shared::ItemQuality,
-    player_state: &mut PlayerState,
     inventory: &mut Inventory,
     item_registry: &ItemRegistry,
 ) -> TransactionResult {
@@ -253,8 +343,12 @@ pub fn try_sell(
         None => return TransactionResult::UnknownItem,
     };
 
+    if is_sell_locked(item_def) {
+        return TransactionResult::Locked;
+    }
+
     // Check inventory.
-    let held = inventory.count(item_id);
+    let held = inventory.count_quality(item_id, quality);
     if held < quantity as u32 {
         return TransactionResult::InsufficientItems {
             need: quantity,
@@ -262,24 +356,86 @@ pub fn try_sell(
         };
     }
 
-    let quality_adjusted = (item_def.sell_price as f32 * quality.sell_multiplier()) as u32;
-    let total_revenue = quality_adjusted.saturating_mul(quantity as u32);
+    let total_revenue = shop_sell_price(item_def, quality).saturating_mul(quantity as u32);
 
     // Remove from inventory.
-    inventory.try_remove(item_id, quantity);
-
-    // Add gold.
-    player_state.gold = player_state.gold.saturating_add(total_revenue);
+    inventory.try_remove_quality(item_id, quantity, quality);
 
     TransactionResult::Success {
         total: total_revenue,
     }
 }
 
+/// Repurchases one unit of buyback entry `index` at `shop_id` for the price and
+/// quality it was sold at. The entry is removed once its last unit is bought back. Like
+/// `try_buy`, the price is returned for the caller to charge.
+pub fn try_buyback(
+    buyback: &mut ShopBuyback,
+    shop_id: ShopId,
+    index: usize,
+    player_state: &PlayerState,
+    inventory: &mut Inventory,
+    item_registry: &ItemRegistry,
+) -> TransactionResult {
+    let Some(queue) = buyback.entries.get_mut(&shop_id) else {
+        return TransactionResult::UnknownItem;
+    };
+    let Some((item_id, quantity, price, quality)) = queue.get_mut(index) else {
+        return TransactionResult::UnknownItem;
+    };
+
+    if player_state.gold < *price {
+        return TransactionResult::InsufficientGold {
+            need: *price,
+            have: player_state.gold,
+        };
+    }
+
+    let max_stack = item_registry
+        .get(item_id)
+        .map(|def| def.stack_size)
+        .unwrap_or(99);
+    if inventory.try_add_quality(item_id, 1, max_stack, *quality) > 0 {
+        return TransactionResult::InventoryFull;
+    }
+
+    let total = *price;
+    *quantity -= 1;
+    if *quantity == 0 {
+        queue.remove(index);
+    }
+
+    TransactionResult::Success { total }
+}
+
+/// The single gold movement for a successful shop transaction: a charge for
+/// purchases, a payout for sales. `item_name` ends up in the daily ledger.
+pub fn shop_gold_change(is_purchase: bool, item_name: &str, total: u32) -> GoldChangeEvent {
+    if is_purchase {
+        GoldChangeEvent {
+            amount: -(total as i32),
+            reason: format!("Shop purchase: {}", item_name),
+        }
+    } else {
+        GoldChangeEvent {
+            amount: total as i32,
+            reason: format!("Shop sale: {}", item_name),
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Listing Helpers
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// The listings a shop offers in `season`: year-round stock plus anything
+/// tied to that season. Out-of-season listings are hidden.
+pub fn listings_for_season(shop: &[ShopListing], season: Season) -> Vec<&ShopListing> {
+    shop.iter()
+        .filter(|listing| listing.season_available.is_none_or(|s| s == season))
+        .collect()
+}
+
 fn build_listings(
     shop_id: ShopId,
     shop_data: &ShopData,
@@ -292,12 +448,8 @@ fn build_listings(
         None => return Vec::new(),
     };
 
-    raw_listings
-        .iter()
-        .filter(|listing| {
-            // Keep items that are always available OR available this season.
-            listing.season_available.is_none_or(|s| s == current_season)
-        })
+    listings_for_season(raw_listings, current_season)
+        .into_iter()
         .filter_map(|listing| {
             let def = item_registry.get(&listing.item_id)?;
             Some(ActiveListing {
@@ -347,22 +499,21 @@ mod tests {
     #[test]
     fn test_try_buy_success() {
         let registry = make_registry(&[("seeds", 20, 99)]);
-        let mut player = default_player(500);
+        let player = default_player(500);
         let mut inv = Inventory::default();
 
-        let result = try_buy("seeds", 5, 20, &mut player, &mut inv, &registry);
+        let result = try_buy("seeds", 5, 20, &player, &mut inv, &registry);
         assert_eq!(result, TransactionResult::Success { total: 100 });
-        assert_eq!(player.gold, 400);
         assert_eq!(inv.count("seeds"), 5);
     }
 
     #[test]
     fn test_try_buy_insufficient_gold() {
         let registry = make_registry(&[("seeds", 20, 99)]);
-        let mut player = default_player(50);
+        let player = default_player(50);
         let mut inv = Inventory::default();
 
-        let result = try_buy("seeds", 5, 20, &mut player, &mut inv, &registry);
+        let result = try_buy("seeds", 5, 20, &player, &mut inv, &registry);
         assert_eq!(
             result,
             TransactionResult::InsufficientGold {
@@ -370,114 +521,124 @@ mod tests {
                 have: 50
             }
         );
-        assert_eq!(player.gold, 50); // unchanged
+        assert_eq!(inv.count("seeds"), 0);
     }
 
     #[test]
     fn test_try_buy_unknown_item() {
         let registry = ItemRegistry::default();
-        let mut player = default_player(500);
+        let player = default_player(500);
         let mut inv = Inventory::default();
 
-        let result = try_buy("nonexistent", 1, 10, &mut player, &mut inv, &registry);
+        let result = try_buy("nonexistent", 1, 10, &player, &mut inv, &registry);
         assert_eq!(result, TransactionResult::UnknownItem);
     }
 
     #[test]
     fn test_try_sell_success() {
         let registry = make_registry(&[("turnip", 60, 99)]);
-        let mut player = default_player(100);
         let mut inv = Inventory::default();
         inv.try_add("turnip", 10, 99);
 
-        let result = try_sell(
-            "turnip",
-            3,
-            ItemQuality::Normal,
-            &mut player,
-            &mut inv,
-            &registry,
-        );
+        let result = try_sell("turnip", 3, ItemQuality::Normal, &mut inv, &registry);
         assert_eq!(result, TransactionResult::Success { total: 180 });
-        assert_eq!(player.gold, 280);
         assert_eq!(inv.count("turnip"), 7);
     }
 
     #[test]
     fn test_try_sell_quality_multiplier() {
         let registry = make_registry(&[("turnip", 100, 99)]);
-        let mut player = default_player(0);
         let mut inv = Inventory::default();
-        inv.try_add("turnip", 2, 99);
+        inv.try_add_quality("turnip", 2, 99, ItemQuality::Gold);
 
         // Gold quality = 1.5x multiplier
-        let result = try_sell(
-            "turnip",
-            1,
-            ItemQuality::Gold,
-            &mut player,
-            &mut inv,
-            &registry,
-        );
+        let result = try_sell("turnip", 1, ItemQuality::Gold, &mut inv, &registry);
         assert_eq!(result, TransactionResult::Success { total: 150 });
-        assert_eq!(player.gold, 150);
     }
 
     #[test]
     fn test_try_sell_iridium_quality() {
         let registry = make_registry(&[("turnip", 100, 99)]);
-        let mut player = default_player(0);
         let mut inv = Inventory::default();
-        inv.try_add("turnip", 1, 99);
+        inv.try_add_quality("turnip", 1, 99, ItemQuality::Iridium);
 
         // Iridium quality = 2.0x
-        let result = try_sell(
-            "turnip",
-            1,
-            ItemQuality::Iridium,
-            &mut player,
-            &mut inv,
-            &registry,
-        );
+        let result = try_sell("turnip", 1, ItemQuality::Iridium, &mut inv, &registry);
         assert_eq!(result, TransactionResult::Success { total: 200 });
     }
 
     #[test]
     fn test_try_sell_insufficient_items() {
         let registry = make_registry(&[("turnip", 60, 99)]);
-        let mut player = default_player(100);
         let mut inv = Inventory::default();
         inv.try_add("turnip", 2, 99);
 
-        let result = try_sell(
-            "turnip",
-            5,
-            ItemQuality::Normal,
-            &mut player,
-            &mut inv,
-            &registry,
-        );
+        let result = try_sell("turnip", 5, ItemQuality::Normal, &mut inv, &registry);
         assert_eq!(
             result,
             TransactionResult::InsufficientItems { need: 5, have: 2 }
         );
-        assert_eq!(player.gold, 100); // unchanged
+    }
+
+    #[test]
+    fn test_try_sell_gold_stack_pays_quality_price_per_unit() {
+        let registry = make_registry(&[("melon", 250, 99)]);
+        let mut inv = Inventory::default();
+        inv.try_add_quality("melon", 4, 99, ItemQuality::Gold);
+        inv.try_add("melon", 3, 99);
+
+        let result = try_sell("melon", 4, ItemQuality::Gold, &mut inv, &registry);
+        let expected = (250.0 * 1.5) as u32 * 4;
+        assert_eq!(result, TransactionResult::Success { total: expected });
+        // Only the gold stack was sold; the normal melons are untouched.
+        assert_eq!(inv.count_quality("melon", ItemQuality::Gold), 0);
+        assert_eq!(inv.count_quality("melon", ItemQuality::Normal), 3);
+    }
+
+    #[test]
+    fn test_try_sell_does_not_take_a_different_quality() {
+        let registry = make_registry(&[("turnip", 60, 99)]);
+        let mut inv = Inventory::default();
+        inv.try_add("turnip", 5, 99);
+
+        let result = try_sell("turnip", 1, ItemQuality::Gold, &mut inv, &registry);
+        assert_eq!(
+            result,
+            TransactionResult::InsufficientItems { need: 1, have: 0 }
+        );
+        assert_eq!(inv.count("turnip"), 5);
+    }
+
+    #[test]
+    fn test_try_sell_refuses_tools() {
+        let mut registry = make_registry(&[("hoe", 100, 1)]);
+        registry.items.get_mut("hoe").unwrap().category = ItemCategory::Tool;
+        let mut inv = Inventory::default();
+        inv.try_add("hoe", 1, 1);
+
+        let result = try_sell("hoe", 1, ItemQuality::Normal, &mut inv, &registry);
+        assert_eq!(result, TransactionResult::Locked);
+        assert_eq!(inv.count("hoe"), 1);
+    }
+
+    #[test]
+    fn test_shop_sell_price_is_capped_by_buy_price() {
+        let mut registry = make_registry(&[("seeds", 30, 99)]);
+        let def = registry.items.get_mut("seeds").unwrap();
+        def.buy_price = Some(40);
+
+        assert_eq!(shop_sell_price(def, ItemQuality::Normal), 20);
+        assert_eq!(shop_sell_price(def, ItemQuality::Gold), 30);
+        def.buy_price = None;
+        assert_eq!(shop_sell_price(def, ItemQuality::Normal), 30);
     }
 
     #[test]
     fn test_try_sell_unknown_item() {
         let registry = ItemRegistry::default();
-        let mut player = default_player(100);
         let mut inv = Inventory::default();
 
-        let result = try_sell(
-            "ghost_item",
-            1,
-            ItemQuality::Normal,
-            &mut player,
-            &mut inv,
-            &registry,
-        );
+        let result = try_sell("ghost_item", 1, ItemQuality::Normal, &mut inv, &registry);
         assert_eq!(result, TransactionResult::UnknownItem);
     }
 
@@ -500,4 +661,100 @@ mod tests {
         assert!(shop.shop_id.is_none());
         assert!(shop.listings.is_empty());
     }
+
+    #[test]
+    fn test_sold_item_can_be_bought_back_at_sold_price() {
+        let registry = make_registry(&[("ancient_fruit", 550, 99)]);
+        let player = default_player(1_000);
+        let mut inv = Inventory::default();
+        inv.try_add_quality("ancient_fruit", 1, 99, ItemQuality::Gold);
+        let mut buyback = ShopBuyback::default();
+
+        let result = try_sell("ancient_fruit", 1, ItemQuality::Gold, &mut inv, &registry);
+        let TransactionResult::Success { total } = result else {
+            panic!("sale failed: {:?}", result);
+        };
+        buyback.record_sale(
+            ShopId::GeneralStore,
+            "ancient_fruit",
+            1,
+            total,
+            ItemQuality::Gold,
+        );
+        assert_eq!(inv.count("ancient_fruit"), 0);
+
+        // Other shops don't carry it.
+        assert_eq!(
+            try_buyback(
+                &mut buyback,
+                ShopId::Blacksmith,
+                0,
+                &player,
+                &mut inv,
+                &registry
+            ),
+            TransactionResult::UnknownItem
+        );
+
+        let result = try_buyback(
+            &mut buyback,
+            ShopId::GeneralStore,
+            0,
+            &player,
+            &mut inv,
+            &registry,
+        );
+        assert_eq!(result, TransactionResult::Success { total });
+        assert_eq!(inv.count_quality("ancient_fruit", ItemQuality::Gold), 1);
+        assert!(buyback.for_shop(ShopId::GeneralStore).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_buyback_is_capped_and_stacks_repeat_sales() {
+        let mut buyback = ShopBuyback::default();
+        buyback.record_sale(ShopId::GeneralStore, "turnip", 1, 60, ItemQuality::Normal);
+        buyback.record_sale(ShopId::GeneralStore, "turnip", 1, 60, ItemQuality::Normal);
+        assert_eq!(
+            buyback.for_shop(ShopId::GeneralStore).unwrap()[0],
+            ("turnip".to_string(), 2, 60, ItemQuality::Normal)
+        );
+
+        for i in 0..BUYBACK_CAPACITY {
+            buyback.record_sale(
+                ShopId::GeneralStore,
+                &format!("item_{}", i),
+                1,
+                10,
+                ItemQuality::Normal,
+            );
+        }
+        let queue = buyback.for_shop(ShopId::GeneralStore).unwrap();
+        assert_eq!(queue.len(), BUYBACK_CAPACITY);
+        assert_eq!(queue[0].0, "item_0");
+    }
+
+    #[test]
+    fn test_spring_only_listing_is_hidden_in_summer() {
+        let listings = vec![
+            ShopListing {
+                item_id: "turnip_seeds".into(),
+                price: 20,
+                season_available: Some(Season::Spring),
+            },
+            ShopListing {
+                item_id: "fertilizer".into(),
+                price: 50,
+                season_available: None,
+            },
+        ];
+        let ids = |season| -> Vec<String> {
+            listings_for_season(&listings, season)
+                .into_iter()
+                .map(|l| l.item_id.clone())
+                .collect()
+        };
+
+        assert_eq!(ids(Season::Spring), vec!["turnip_seeds", "fertilizer"]);
+        assert_eq!(ids(Season::Summer), vec!["fertilizer"]);
+    }
 }
@@ -1,10 +1,13 @@
 //! Crop planting and growth-stage management.
 
 use super::{
-    crop_can_grow_in_season, crop_stage_color, CropTileEntity, FarmEntities, PlantSeedEvent,
+    crop_can_grow_in_season, crop_stage_color, sprinklers::line_tiles, CropTileEntity,
+    FarmEntities, PlantSeedEvent,
 };
 use crate::shared::*;
+use crate::world::WorldMap;
 use bevy::prelude::*;
+use std::collections::HashSet;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Detect seed use — player presses interact while holding a seed over tilled soil
@@ -105,6 +108,104 @@ pub fn detect_seed_use(
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Drag planting — hold the plant action and walk across tilled ground
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// An in-progress drag plant. The first seed goes in with the regular press
+/// in `detect_seed_use`; this tracks the seed and the last tile planted after.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SeedDrag {
+    pub seed_id: Option<ItemId>,
+    pub last_tile: Option<(i32, i32)>,
+}
+
+/// A tile takes a seed when it is tilled (or watered) and nothing grows there.
+fn tile_ready_for_seed(farm_state: &FarmState, pos: (i32, i32)) -> bool {
+    matches!(
+        farm_state.soil.get(&pos),
+        Some(SoilState::Tilled | SoilState::Watered)
+    ) && !farm_state.crops.contains_key(&pos)
+        && !farm_state
+            .giant_crops
+            .iter()
+            .any(|g| g.covers(pos.0, pos.1))
+}
+
+/// While the plant action stays held with a seed selected, plant one seed on
+/// each new tile the player faces. Tiles that aren't tilled and empty are
+/// skipped, and planting stops once the seed stack runs out.
+#[allow(clippy::too_many_arguments)]
+pub fn drag_plant_seeds(
+    player_input: Res<PlayerInput>,
+    input_blocks: Res<InputBlocks>,
+    player_state: Res<PlayerState>,
+    inventory: Res<Inventory>,
+    farm_state: Res<FarmState>,
+    calendar: Res<Calendar>,
+    crop_registry: Res<CropRegistry>,
+    player_query: Query<(&LogicalPosition, &PlayerMovement), With<Player>>,
+    mut drag: ResMut<SeedDrag>,
+    mut plant_events: EventWriter<PlantSeedEvent>,
+) {
+    let selected = inventory
+        .slots
+        .get(inventory.selected_slot)
+        .and_then(|s| s.as_ref())
+        .map(|s| s.item_id.clone())
+        .filter(|id| {
+            crop_registry
+                .crops
+                .values()
+                .any(|c| c.seed_id == *id && crop_can_grow_in_season(c, calendar.season))
+        });
+
+    let active = player_input.tool_use_held
+        && !input_blocks.is_blocked()
+        && player_state.current_map == MapId::Farm;
+    let Some(seed_id) = selected.filter(|_| active) else {
+        *drag = SeedDrag::default();
+        return;
+    };
+    let Ok((pos, movement)) = player_query.get_single() else {
+        return;
+    };
+    let target = player_target_tile(pos.0, movement.facing);
+
+    // The press itself plants the first seed; start tracking from there.
+    if player_input.tool_use {
+        drag.seed_id = Some(seed_id);
+        drag.last_tile = Some(target);
+        return;
+    }
+    if drag.seed_id.as_ref() != Some(&seed_id) {
+        return;
+    }
+    let Some(last) = drag.last_tile else {
+        return;
+    };
+    if last == target {
+        return;
+    }
+    drag.last_tile = Some(target);
+
+    let mut remaining = inventory.count(&seed_id);
+    for tile in line_tiles(last, target) {
+        if remaining == 0 {
+            break;
+        }
+        if !tile_ready_for_seed(&farm_state, tile) {
+            continue;
+        }
+        plant_events.send(PlantSeedEvent {
+            grid_x: tile.0,
+            grid_y: tile.1,
+            seed_item_id: seed_id.clone(),
+        });
+        remaining -= 1;
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Handle plant seed event
 // ─────────────────────────────────────────────────────────────────────────────
@@ -123,6 +224,8 @@ pub fn handle_plant_seed(
     crop_registry: Res<CropRegistry>,
     calendar: Res<Calendar>,
     player_state: Res<PlayerState>,
+    mut world_map: ResMut<WorldMap>,
+    player_query: Query<&LogicalPosition, With<Player>>,
 ) {
     // Farming tools only work on the farm map.
     if player_state.current_map != MapId::Farm {
@@ -138,8 +241,13 @@ pub fn handle_plant_seed(
             continue;
         }
 
-        // Tile must not already have a crop.
-        if farm_state.crops.contains_key(&pos) {
+        // Tile must not already have a crop (or sit under a giant crop).
+        if farm_state.crops.contains_key(&pos)
+            || farm_state
+                .giant_crops
+                .iter()
+                .any(|g| g.covers(pos.0, pos.1))
+        {
             continue;
         }
 
@@ -162,6 +270,24 @@ pub fn handle_plant_seed(
             continue;
         }
 
+        // A trellis blocks walking, so never put one where it boxes the player in.
+        if crop_def.needs_trellis {
+            let player_tile = player_query.get_single().ok().map(|lp| {
+                let g = world_to_grid(lp.0.x, lp.0.y);
+                (g.x, g.y)
+            });
+            if player_tile.is_some_and(|tile| trellis_would_trap(&world_map, tile, pos)) {
+                toast_writer.send(ToastEvent {
+                    message: format!(
+                        "{} needs a trellis. Plant it where you can still walk around.",
+                        crop_def.name
+                    ),
+                    duration_secs: 3.0,
+                });
+                continue;
+            }
+        }
+
         // Remove one seed from inventory.
         if inventory.try_remove(&event.seed_item_id, 1) == 0 {
             // Player doesn't have the seed any more.
@@ -183,6 +309,9 @@ pub fn handle_plant_seed(
             dead: false,
         };
         farm_state.crops.insert(pos, crop.clone());
+        if crop_def.needs_trellis {
+            world_map.set_solid(pos.0, pos.1, true);
+        }
 
         sfx_events.send(PlaySfxEvent {
             sfx_id: "plant".to_string(),
@@ -242,11 +371,13 @@ pub fn spawn_crop_entity(
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Advance all crops by one day.  Called from the DayEnd handler.
+/// With `peaceful` set, unwatered crops pause instead of dying.
 pub fn advance_crop_growth(
     farm_state: &mut FarmState,
     crop_registry: &CropRegistry,
     current_season: Season,
     is_rainy: bool,
+    peaceful: bool,
 ) -> Vec<(i32, i32)> // returns positions of crops that need entity updates
 {
     let positions: Vec<(i32, i32)> = farm_state.crops.keys().cloned().collect();
@@ -295,10 +426,10 @@ pub fn advance_crop_growth(
             }
         } else {
             // Not watered today.
-            crop.days_without_water += 1;
+            crop.days_without_water = crop.days_without_water.saturating_add(1);
 
-            if crop.days_without_water >= 3 {
-                // 3 days without water → dead.
+            if crop.days_without_water >= 3 && !peaceful {
+                // 3 days without water → dead. In peaceful mode it just waits.
                 crop.dead = true;
             }
             // 2 days → wilting (visual handled by sync_crop_sprites via days_without_water).
@@ -313,6 +444,81 @@ pub fn advance_crop_growth(
     updated
 }
 
+/// Watered days left before a crop can be harvested, mirroring the stage
+/// rules in `advance_crop_growth` (each stage takes at least one day).
+/// Returns `Some(0)` for a mature crop and `None` for a dead one.
+pub fn days_until_harvest(tile: &CropTile, def: &CropDef) -> Option<u8> {
+    if tile.dead {
+        return None;
+    }
+    let stage = tile.current_stage as usize;
+    let Some(&current) = def.growth_days.get(stage) else {
+        return Some(0);
+    };
+    let left_in_stage = current.saturating_sub(tile.days_in_stage).max(1) as u32;
+    let later_stages: u32 = def.growth_days[stage + 1..]
+        .iter()
+        .map(|&days| days.max(1) as u32)
+        .sum();
+    Some((left_in_stage + later_stages).min(u8::MAX as u32) as u8)
+}
+
+/// A trellis on `target` traps the player if it covers their own tile or
+/// leaves them without a walkable neighbour.
+pub fn trellis_would_trap(
+    world_map: &WorldMap,
+    player_tile: (i32, i32),
+    target: (i32, i32),
+) -> bool {
+    if target == player_tile {
+        return true;
+    }
+    let (px, py) = player_tile;
+    ![(px + 1, py), (px - 1, py), (px, py + 1), (px, py - 1)]
+        .into_iter()
+        .any(|(x, y)| (x, y) != target && world_map.is_walkable(x, y))
+}
+
+/// Keep trellis crops solid in `WorldMap`: re-mark them after the farm map
+/// reloads, and clear tiles whose crop was harvested or otherwise removed.
+pub fn sync_trellis_collision(
+    farm_state: Res<FarmState>,
+    crop_registry: Res<CropRegistry>,
+    player_state: Res<PlayerState>,
+    mut world_map: ResMut<WorldMap>,
+    mut marked: Local<HashSet<(i32, i32)>>,
+) {
+    if player_state.current_map != MapId::Farm {
+        marked.clear();
+        return;
+    }
+    if !farm_state.is_changed() && !world_map.is_changed() {
+        return;
+    }
+
+    let trellis_tiles: HashSet<(i32, i32)> = farm_state
+        .crops
+        .iter()
+        .filter(|(_, crop)| {
+            crop_registry
+                .crops
+                .get(&crop.crop_id)
+                .is_some_and(|def| def.needs_trellis)
+        })
+        .map(|(&pos, _)| pos)
+        .collect();
+
+    for &(x, y) in marked.difference(&trellis_tiles) {
+        world_map.set_solid(x, y, false);
+    }
+    for &(x, y) in &trellis_tiles {
+        if !world_map.solid_tiles.contains(&(x, y)) {
+            world_map.set_solid(x, y, true);
+        }
+    }
+    *marked = trellis_tiles;
+}
+
 /// Reset all soil from Watered back to Tilled at day start
 /// (rain may re-water later; sprinklers run first).
 pub fn reset_soil_watered_state(farm_state: &mut FarmState) {
@@ -322,3 +528,161 @@ pub fn reset_soil_watered_state(farm_state: &mut FarmState) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crop_def(growth_days: Vec<u8>, regrows: bool) -> CropDef {
+        CropDef {
+            id: "tomato".into(),
+            name: "Tomato".into(),
+            seed_id: "tomato_seeds".into(),
+            harvest_id: "tomato".into(),
+            seasons: vec![Season::Summer],
+            growth_days,
+            regrows,
+            regrow_days: if regrows { 4 } else { 0 },
+            sell_price: 60,
+            sprite_stages: vec![0, 1, 2, 3],
+            needs_trellis: false,
+            off_season_tolerant: false,
+        }
+    }
+
+    fn crop_tile(current_stage: u8, days_in_stage: u8) -> CropTile {
+        CropTile {
+            crop_id: "tomato".into(),
+            current_stage,
+            days_in_stage,
+            watered_today: false,
+            days_without_water: 0,
+            dead: false,
+        }
+    }
+
+    #[test]
+    fn days_until_harvest_counts_down_through_stages() {
+        let def = crop_def(vec![2, 3, 3, 4], false);
+        assert_eq!(days_until_harvest(&crop_tile(0, 0), &def), Some(12));
+        assert_eq!(days_until_harvest(&crop_tile(0, 1), &def), Some(11));
+        assert_eq!(days_until_harvest(&crop_tile(2, 2), &def), Some(5));
+        assert_eq!(days_until_harvest(&crop_tile(3, 3), &def), Some(1));
+        assert_eq!(days_until_harvest(&crop_tile(4, 0), &def), Some(0));
+    }
+
+    #[test]
+    fn days_until_harvest_matches_simulated_growth() {
+        let def = crop_def(vec![1, 2, 0, 3], false);
+        let mut registry = CropRegistry::default();
+        registry.crops.insert("tomato".into(), def.clone());
+        let mut farm = FarmState::default();
+        farm.crops.insert((0, 0), crop_tile(0, 0));
+
+        let expected = days_until_harvest(&farm.crops[&(0, 0)], &def).unwrap();
+        for day in 0..expected {
+            assert_eq!(
+                days_until_harvest(&farm.crops[&(0, 0)], &def),
+                Some(expected - day)
+            );
+            advance_crop_growth(&mut farm, &registry, Season::Summer, true, false);
+        }
+        assert_eq!(days_until_harvest(&farm.crops[&(0, 0)], &def), Some(0));
+    }
+
+    #[test]
+    fn days_until_harvest_after_regrow_reset() {
+        let def = crop_def(vec![1, 2, 2, 3], true);
+        // Harvesting a regrowing crop sends it back to the last stage.
+        assert_eq!(days_until_harvest(&crop_tile(3, 0), &def), Some(3));
+        assert_eq!(days_until_harvest(&crop_tile(3, 2), &def), Some(1));
+    }
+
+    #[test]
+    fn days_until_harvest_is_none_for_dead_crops() {
+        let def = crop_def(vec![1, 1, 1, 1], false);
+        let mut tile = crop_tile(4, 0);
+        tile.dead = true;
+        assert_eq!(days_until_harvest(&tile, &def), None);
+    }
+
+    #[test]
+    fn drag_planting_three_tilled_tiles_uses_three_seeds() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PlayerInput>()
+            .init_resource::<InputBlocks>()
+            .init_resource::<FarmEntities>()
+            .init_resource::<WorldMap>()
+            .init_resource::<SeedDrag>()
+            .add_event::<PlantSeedEvent>()
+            .add_event::<ItemRemovedEvent>()
+            .add_event::<PlaySfxEvent>()
+            .add_event::<ToastEvent>()
+            .add_systems(Update, (drag_plant_seeds, handle_plant_seed).chain());
+
+        app.insert_resource(PlayerState {
+            current_map: MapId::Farm,
+            ..default()
+        });
+        app.insert_resource(Calendar {
+            season: Season::Summer,
+            ..default()
+        });
+        let mut registry = CropRegistry::default();
+        registry
+            .crops
+            .insert("tomato".into(), crop_def(vec![2, 3, 3, 4], false));
+        app.insert_resource(registry);
+        let mut farm = FarmState::default();
+        for x in 1..=3 {
+            farm.soil.insert((x, 0), SoilState::Tilled);
+        }
+        app.insert_resource(farm);
+        let mut inventory = Inventory::default();
+        inventory.try_add("tomato_seeds", 3, 99);
+        app.insert_resource(inventory);
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                LogicalPosition(grid_to_world_center(0, 0)),
+                PlayerMovement {
+                    facing: Facing::Right,
+                    ..default()
+                },
+            ))
+            .id();
+
+        // Initial press: the regular seed use plants the faced tile.
+        {
+            let mut input = app.world_mut().resource_mut::<PlayerInput>();
+            input.tool_use = true;
+            input.tool_use_held = true;
+        }
+        app.world_mut().send_event(PlantSeedEvent {
+            grid_x: 1,
+            grid_y: 0,
+            seed_item_id: "tomato_seeds".into(),
+        });
+        app.update();
+        app.world_mut().resource_mut::<PlayerInput>().tool_use = false;
+
+        // Keep holding while walking right across the tilled row.
+        for x in 1..=2 {
+            app.world_mut()
+                .get_mut::<LogicalPosition>(player)
+                .unwrap()
+                .0 = grid_to_world_center(x, 0);
+            app.update();
+        }
+
+        let farm = app.world().resource::<FarmState>();
+        assert_eq!(farm.crops.len(), 3);
+        for x in 1..=3 {
+            assert!(farm.crops.contains_key(&(x, 0)), "tile ({x}, 0) planted");
+        }
+        assert_eq!(app.world().resource::<Inventory>().count("tomato_seeds"), 0);
+    }
+}
@@ -9,9 +9,14 @@
 //! - Added `track_day_weather` system that runs each frame during Playing state to
 //!   keep `TrackedDayWeather` in sync with `Calendar.weather` BEFORE day-end
 //!   processing can overwrite it.
+//! - `on_day_end` now reads the calendar's `PreviousDayWeather`, which both day-end
+//!   paths (2 AM rollover and sleeping) set before rolling the new day's weather,
+//!   instead of its own frame snapshot.  It is ordered after `process_day_end` so
+//!   the sleep path has stored the ended day's weather by the time it runs.
 
 use super::{
     crops::{advance_crop_growth, reset_soil_watered_state},
+    giant_crops::form_giant_crops,
     soil::spawn_or_update_soil_entity,
     sprinkler::apply_rain_watering,
     FarmEntities, MorningSprinklerEvent, TrackedDayWeather,
@@ -81,37 +86,38 @@ pub fn send_morning_sprinkler_event(
 /// 3. Advance crop growth for all crops.
 /// 4. Reset soil state (Watered -> Tilled) for the next day.
 /// 5. Kill crops that can't survive in the current season.
-/// 6. Handle crow events (scare away crows via scarecrows).
+/// 6. Roll for a crow eating an unprotected mature crop (scarecrows protect).
+/// 7. After a storm, roll for lightning hitting a crop or object (rods protect).
+/// 8. Roll for 3×3 blocks of mature crops merging into giant crops.
+///
+/// In peaceful mode (`GameSettings::peaceful`) thirsty crops stop growing
+/// but never die.
+#[allow(clippy::too_many_arguments)]
 pub fn on_day_end(
     mut day_end_events: EventReader<DayEndEvent>,
     mut farm_state: ResMut<FarmState>,
     mut farm_entities: ResMut<FarmEntities>,
     mut commands: Commands,
     crop_registry: Res<CropRegistry>,
-    tracked_weather: Res<TrackedDayWeather>,
+    previous_weather: Res<PreviousDayWeather>,
+    settings: Res<GameSettings>,
+    mut toast_events: EventWriter<ToastEvent>,
 ) {
     for event in day_end_events.read() {
-        // VERIFIED: The rain auto-watering chain is correct end-to-end.
-        //
-        // Chain verification:
-        //   1. `track_day_weather` runs in the `First` schedule each frame (registered
-        //      in FarmingPlugin), which executes before `Update` where `on_day_end` lives.
-        //   2. `tick_time` (in the calendar domain) fires `DayEndEvent` AND rolls new
-        //      weather in the same Update frame.  By the time `on_day_end` processes the
-        //      event, `calendar.weather` is already the new day's weather.
-        //   3. `track_day_weather` ran earlier (First schedule) and captured the OLD
-        //      day's weather into `TrackedDayWeather`.  Weather never changes mid-day,
-        //      so the snapshot is always the ENDED day's weather.
-        //   4. `apply_rain_watering` (sprinkler.rs) sets all `SoilState::Tilled` tiles to
+        // Rain auto-watering chain:
+        //   1. `tick_time` (2 AM rollover) or `process_day_end` (sleeping) stores the
+        //      ended day's weather in `PreviousDayWeather`, then rolls new weather.
+        //      By the time `on_day_end` processes the event, `calendar.weather` is
+        //      already the new day's weather, so it must not be read here.
+        //   2. FarmingPlugin orders this system after `process_day_end`, so both
+        //      paths have stored the ended day's weather before we read it.
+        //   3. `apply_rain_watering` (sprinkler.rs) sets all `SoilState::Tilled` tiles to
         //      `SoilState::Watered` AND marks `watered_today = true` / resets
         //      `days_without_water = 0` for ALL living crops — ensuring both soil state
         //      and crop state are consistent before `advance_crop_growth` evaluates them.
-        //   5. `reset_soil_watered_state` then runs after crop growth to flip Watered
+        //   4. `reset_soil_watered_state` then runs after crop growth to flip Watered
         //      back to Tilled for the next morning, so sprinklers/rain can re-water.
-        //
-        // No bug found. Reading TrackedDayWeather (not calendar.weather) is the correct
-        // approach and is already implemented.
-        let is_rainy = matches!(tracked_weather.weather, Weather::Rainy | Weather::Stormy);
+        let is_rainy = matches!(previous_weather.weather, Weather::Rainy | Weather::Stormy);
 
         // Rain waters all tilled/watered tiles.
         if is_rainy {
@@ -119,18 +125,64 @@ pub fn on_day_end(
         }
 
         // Advance crop growth (mutates FarmState).
-        let updated_positions =
-            advance_crop_growth(&mut farm_state, &crop_registry, event.season, is_rainy);
+        let updated_positions = advance_crop_growth(
+            &mut farm_state,
+            &crop_registry,
+            event.season,
+            is_rainy,
+            settings.peaceful,
+        );
+
+        // Process crow events — a crow may eat a mature crop that no
+        // scarecrow protects.  Crows only appear in non-winter seasons.
+        if event.season != Season::Winter
+            && maybe_crow_event(&mut farm_state, &crop_registry, &mut rand::thread_rng()).is_some()
+        {
+            toast_events.send(ToastEvent {
+                message: "A crow ate one of your crops! A scarecrow would keep them away.".into(),
+                duration_secs: 3.0,
+            });
+        }
 
-        // Process crow events — kill a random unprotected crop.
-        // Crows only appear in non-winter seasons.
-        if event.season != Season::Winter {
-            maybe_crow_event(&mut farm_state, &farm_entities);
+        // A stormy day may end with lightning hitting the farm, unless a
+        // lightning rod nearby takes the strike.
+        if previous_weather.weather == Weather::Stormy {
+            match maybe_lightning_strike(&mut farm_state, &mut rand::thread_rng()) {
+                Some(LightningStrike::Absorbed) => {
+                    toast_events.send(ToastEvent {
+                        message: "Lightning struck overnight, but your lightning rod caught it."
+                            .into(),
+                        duration_secs: 3.0,
+                    });
+                }
+                Some(LightningStrike::Destroyed) => {
+                    toast_events.send(ToastEvent {
+                        message: "Lightning struck the farm overnight! A lightning rod would help."
+                            .into(),
+                        duration_secs: 3.0,
+                    });
+                }
+                None => {}
+            }
         }
 
         // Reset soil watered state for the next day.
         reset_soil_watered_state(&mut farm_state);
 
+        // Rarely, a full 3×3 block of mature crops merges into a giant crop.
+        let formed = form_giant_crops(
+            &mut farm_state,
+            &mut farm_entities,
+            &mut commands,
+            &crop_registry,
+        );
+        if formed > 0 {
+            toast_events.send(ToastEvent {
+                message: "Something huge is growing in the field...".into(),
+                duration_secs: 3.0,
+            });
+        }
+
         // Despawn dead crop entities.
         let dead_positions: Vec<(i32, i32)> = updated_positions
             .iter()
@@ -155,33 +207,53 @@ pub fn on_day_end(
 // Season Change
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Crops that wither when the season turns to `new_season`: every crop that
+/// can't grow in it (or has no definition), except hardy ones
+/// (`CropDef::off_season_tolerant`) while `grace` is on. Those are left for
+/// the next day end, where `advance_crop_growth` kills them.
+fn off_season_casualties(
+    farm_state: &FarmState,
+    crop_registry: &CropRegistry,
+    new_season: Season,
+    grace: bool,
+) -> Vec<(i32, i32)> {
+    farm_state
+        .crops
+        .iter()
+        .filter(|(_, crop)| !crop.dead)
+        .filter(|(_, crop)| match crop_registry.crops.get(&crop.crop_id) {
+            // Unknown crop — remove
+            None => true,
+            Some(def) => {
+                !def.seasons.is_empty()
+                    && !def.seasons.contains(&new_season)
+                    && (!grace || !def.off_season_tolerant)
+            }
+        })
+        .map(|(&pos, _)| pos)
+        .collect()
+}
+
 /// When the season changes, kill all crops that can't grow in the new season.
+/// With `GameSettings::off_season_grace` on, hardy crops get one more day.
 pub fn on_season_change(
     mut season_events: EventReader<SeasonChangeEvent>,
     mut farm_state: ResMut<FarmState>,
     mut farm_entities: ResMut<FarmEntities>,
     mut commands: Commands,
     crop_registry: Res<CropRegistry>,
+    settings: Res<GameSettings>,
     mut toast_events: EventWriter<ToastEvent>,
 ) {
     for event in season_events.read() {
         let new_season = event.new_season;
 
-        let positions: Vec<(i32, i32)> = farm_state.crops.keys().cloned().collect();
-        let mut to_kill = Vec::new();
-
-        for pos in positions {
-            let Some(crop) = farm_state.crops.get(&pos) else {
-                continue;
-            };
-            let Some(def) = crop_registry.crops.get(&crop.crop_id) else {
-                to_kill.push(pos); // unknown crop — remove
-                continue;
-            };
-            if !def.seasons.is_empty() && !def.seasons.contains(&new_season) {
-                to_kill.push(pos);
-            }
-        }
+        let to_kill = off_season_casualties(
+            &farm_state,
+            &crop_registry,
+            new_season,
+            settings.off_season_grace,
+        );
 
         // Kill out-of-season crops immediately (mark dead, visual handled by render).
         let had_deaths = !to_kill.is_empty();
@@ -218,55 +290,143 @@ pub fn on_season_change(
 // Crow event
 // ─────────────────────────────────────────────────────────────────────────────
 
-/// Small chance each day for a crow to destroy a random unprotected crop.
-fn maybe_crow_event(farm_state: &mut FarmState, farm_entities: &FarmEntities) {
-    use rand::Rng;
+/// Daily chance that a crow visits the farm.
+const CROW_ATTACK_CHANCE: f64 = 0.08;
 
-    let mut rng = rand::thread_rng();
+/// Tiles within this many tiles of a scarecrow (on both axes) are safe from crows.
+pub const SCARECROW_RADIUS: i32 = 8;
 
-    // 8% daily chance of a crow attack.
-    if rng.gen_bool(0.92) {
-        return;
-    }
+/// True if a scarecrow on the farm covers tile `(x, y)`.
+pub fn tile_protected_by_scarecrow(farm: &FarmState, x: i32, y: i32) -> bool {
+    farm.objects.iter().any(|(&(sx, sy), obj)| {
+        matches!(obj, FarmObject::Scarecrow)
+            && (x - sx).abs() <= SCARECROW_RADIUS
+            && (y - sy).abs() <= SCARECROW_RADIUS
+    })
+}
 
-    // Find all scarecrow positions.
-    let scarecrow_positions: Vec<(i32, i32)> = farm_state
-        .objects
+/// Pick a random mature, living crop outside every scarecrow's radius.
+fn pick_crow_target(
+    farm_state: &FarmState,
+    crop_registry: &CropRegistry,
+    rng: &mut impl rand::Rng,
+) -> Option<(i32, i32)> {
+    let mut eligible: Vec<(i32, i32)> = farm_state
+        .crops
         .iter()
-        .filter(|(_, obj)| matches!(obj, FarmObject::Scarecrow))
+        .filter(|(_, crop)| !crop.dead)
+        .filter(|(_, crop)| {
+            crop_registry
+                .crops
+                .get(&crop.crop_id)
+                .is_some_and(|def| crop.current_stage as usize >= def.growth_days.len())
+        })
         .map(|(&pos, _)| pos)
+        .filter(|&(x, y)| !tile_protected_by_scarecrow(farm_state, x, y))
         .collect();
+    if eligible.is_empty() {
+        return None;
+    }
+    // HashMap order is arbitrary; sort so a seeded rng picks reproducibly.
+    eligible.sort_unstable();
+    Some(eligible[rng.gen_range(0..eligible.len())])
+}
+
+/// Small chance each day for a crow to eat a random unprotected mature crop.
+/// Returns the position of the eaten crop, if any.  The crop is removed from
+/// `FarmState`; `sync_crop_sprites` despawns its entity.
+fn maybe_crow_event(
+    farm_state: &mut FarmState,
+    crop_registry: &CropRegistry,
+    rng: &mut impl rand::Rng,
+) -> Option<(i32, i32)> {
+    if !rng.gen_bool(CROW_ATTACK_CHANCE) {
+        return None;
+    }
+    let target = pick_crow_target(farm_state, crop_registry, rng)?;
+    farm_state.crops.remove(&target);
+    Some(target)
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Lightning strikes
+// ─────────────────────────────────────────────────────────────────────────────
 
-    const SCARECROW_RADIUS: i32 = 8;
+/// Chance that a stormy day ends with lightning hitting the farm.
+const LIGHTNING_STRIKE_CHANCE: f64 = 0.25;
 
-    // Gather unprotected, living crops.
-    let unprotected: Vec<(i32, i32)> = farm_state
+/// Strikes within this many tiles of a lightning rod (on both axes) hit the rod.
+pub const LIGHTNING_ROD_RADIUS: i32 = 5;
+
+/// What became of a lightning strike on the farm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LightningStrike {
+    /// A lightning rod caught it; nothing was harmed.
+    Absorbed,
+    /// The crop or object on the struck tile was destroyed.
+    Destroyed,
+}
+
+/// True if a lightning rod on the farm covers tile `(x, y)`.
+pub fn tile_protected_by_lightning_rod(farm: &FarmState, x: i32, y: i32) -> bool {
+    farm.objects.iter().any(|(&(rx, ry), obj)| {
+        matches!(obj, FarmObject::LightningRod)
+            && (x - rx).abs() <= LIGHTNING_ROD_RADIUS
+            && (y - ry).abs() <= LIGHTNING_ROD_RADIUS
+    })
+}
+
+/// Pick a random living crop, fence or scarecrow for lightning to hit.
+fn pick_lightning_target(farm_state: &FarmState, rng: &mut impl rand::Rng) -> Option<(i32, i32)> {
+    let mut eligible: Vec<(i32, i32)> = farm_state
         .crops
         .iter()
-        .filter(|(_, c)| !c.dead)
+        .filter(|(_, crop)| !crop.dead)
         .map(|(&pos, _)| pos)
-        .filter(|&pos| {
-            !scarecrow_positions.iter().any(|&sc| {
-                let dx = (pos.0 - sc.0).abs();
-                let dy = (pos.1 - sc.1).abs();
-                dx <= SCARECROW_RADIUS && dy <= SCARECROW_RADIUS
-            })
-        })
+        .chain(
+            farm_state
+                .objects
+                .iter()
+                .filter(|(_, obj)| matches!(obj, FarmObject::Fence | FarmObject::Scarecrow))
+                .map(|(&pos, _)| pos),
+        )
         .collect();
-
-    if unprotected.is_empty() {
-        return;
+    if eligible.is_empty() {
+        return None;
     }
+    // HashMap order is arbitrary; sort so a seeded rng picks reproducibly.
+    eligible.sort_unstable();
+    eligible.dedup();
+    Some(eligible[rng.gen_range(0..eligible.len())])
+}
 
-    // Pick a random unprotected crop and kill it.
-    let idx = rng.gen_range(0..unprotected.len());
-    let target = unprotected[idx];
-
-    if let Some(crop) = farm_state.crops.get_mut(&target) {
-        crop.dead = true;
+/// Bring lightning down on tile `pos`. A lightning rod in range takes the
+/// hit; otherwise the crop, fence or scarecrow there is destroyed.
+pub fn strike_tile(farm_state: &mut FarmState, pos: (i32, i32)) -> LightningStrike {
+    if tile_protected_by_lightning_rod(farm_state, pos.0, pos.1) {
+        return LightningStrike::Absorbed;
     }
+    farm_state.crops.remove(&pos);
+    if matches!(
+        farm_state.objects.get(&pos),
+        Some(FarmObject::Fence | FarmObject::Scarecrow)
+    ) {
+        farm_state.objects.remove(&pos);
+    }
+    LightningStrike::Destroyed
+}
 
-    let _ = farm_entities; // would use to update sprite in a more complete system
+/// Chance after a storm for lightning to hit a random crop or object.
+/// Removed crops and objects are despawned by the sprite sync systems.
+fn maybe_lightning_strike(
+    farm_state: &mut FarmState,
+    rng: &mut impl rand::Rng,
+) -> Option<LightningStrike> {
+    if !rng.gen_bool(LIGHTNING_STRIKE_CHANCE) {
+        return None;
+    }
+    let target = pick_lightning_target(farm_state, rng)?;
+    Some(strike_tile(farm_state, target))
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -283,3 +443,152 @@ fn ensure_all_soil_entities(
         spawn_or_update_soil_entity(commands, farm_entities, pos, state);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn registry() -> CropRegistry {
+        let mut registry = CropRegistry::default();
+        registry.crops.insert(
+            "turnip".into(),
+            CropDef {
+                id: "turnip".into(),
+                name: "Turnip".into(),
+                seed_id: "turnip_seeds".into(),
+                harvest_id: "turnip".into(),
+                seasons: vec![Season::Spring],
+                growth_days: vec![1, 1, 1, 1],
+                regrows: false,
+                regrow_days: 0,
+                sell_price: 60,
+                sprite_stages: vec![0, 1, 2, 3],
+                needs_trellis: false,
+                off_season_tolerant: false,
+            },
+        );
+        registry
+    }
+
+    fn plant(farm: &mut FarmState, pos: (i32, i32), stage: u8) {
+        farm.crops.insert(
+            pos,
+            CropTile {
+                crop_id: "turnip".into(),
+                current_stage: stage,
+                days_in_stage: 0,
+                watered_today: false,
+                days_without_water: 0,
+                dead: false,
+            },
+        );
+    }
+
+    #[test]
+    fn scarecrow_radius_boundary() {
+        let mut farm = FarmState::default();
+        farm.objects.insert((10, 10), FarmObject::Scarecrow);
+
+        assert!(tile_protected_by_scarecrow(&farm, 10, 10));
+        assert!(tile_protected_by_scarecrow(&farm, 18, 10));
+        assert!(tile_protected_by_scarecrow(&farm, 2, 18));
+        assert!(!tile_protected_by_scarecrow(&farm, 19, 10));
+        assert!(!tile_protected_by_scarecrow(&farm, 10, 1));
+    }
+
+    #[test]
+    fn other_objects_do_not_protect() {
+        let mut farm = FarmState::default();
+        farm.objects.insert((10, 10), FarmObject::Sprinkler);
+        assert!(!tile_protected_by_scarecrow(&farm, 10, 11));
+    }
+
+    #[test]
+    fn crows_only_target_unprotected_mature_crops() {
+        let registry = registry();
+        let mut farm = FarmState::default();
+        farm.objects.insert((0, 0), FarmObject::Scarecrow);
+        plant(&mut farm, (3, 3), 4); // mature but protected
+        plant(&mut farm, (20, 0), 2); // unprotected but still growing
+        plant(&mut farm, (20, 20), 4); // unprotected and mature
+
+        for seed in 0..20 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            assert_eq!(pick_crow_target(&farm, &registry, &mut rng), Some((20, 20)));
+        }
+    }
+
+    #[test]
+    fn fully_protected_farm_has_no_crow_target() {
+        let registry = registry();
+        let mut farm = FarmState::default();
+        farm.objects.insert((0, 0), FarmObject::Scarecrow);
+        plant(&mut farm, (8, 8), 4);
+        plant(&mut farm, (-8, 5), 4);
+
+        let mut rng = StdRng::seed_from_u64(1);
+        assert_eq!(pick_crow_target(&farm, &registry, &mut rng), None);
+    }
+
+    #[test]
+    fn lightning_rod_in_range_absorbs_strike() {
+        let mut farm = FarmState::default();
+        farm.objects.insert((10, 10), FarmObject::LightningRod);
+        plant(&mut farm, (14, 12), 2);
+
+        assert_eq!(strike_tile(&mut farm, (14, 12)), LightningStrike::Absorbed);
+        assert!(
+            farm.crops.contains_key(&(14, 12)),
+            "a crop covered by a lightning rod should survive the strike"
+        );
+    }
+
+    #[test]
+    fn lightning_destroys_unprotected_crop() {
+        let mut farm = FarmState::default();
+        farm.objects.insert((0, 0), FarmObject::LightningRod);
+        plant(&mut farm, (20, 20), 2);
+
+        assert_eq!(strike_tile(&mut farm, (20, 20)), LightningStrike::Destroyed);
+        assert!(!farm.crops.contains_key(&(20, 20)));
+        // The rod itself is never a target.
+        let mut rng = StdRng::seed_from_u64(3);
+        assert_eq!(pick_lightning_target(&farm, &mut rng), None);
+    }
+
+    #[test]
+    fn hardy_crop_survives_one_off_season_day_with_grace() {
+        let mut registry = registry();
+        registry
+            .crops
+            .get_mut("turnip")
+            .unwrap()
+            .off_season_tolerant = true;
+        let mut farm = FarmState::default();
+        plant(&mut farm, (1, 1), 2);
+
+        assert_eq!(
+            off_season_casualties(&farm, &registry, Season::Summer, false),
+            vec![(1, 1)],
+            "without grace the crop withers at the season change"
+        );
+        assert!(off_season_casualties(&farm, &registry, Season::Summer, true).is_empty());
+
+        // First summer night: the grace is over.
+        advance_crop_growth(&mut farm, &registry, Season::Summer, true, false);
+        assert!(farm.crops[&(1, 1)].dead);
+    }
+
+    #[test]
+    fn grace_does_not_cover_tender_crops() {
+        let registry = registry();
+        let mut farm = FarmState::default();
+        plant(&mut farm, (1, 1), 2);
+        assert_eq!(
+            off_season_casualties(&farm, &registry, Season::Summer, true),
+            vec![(1, 1)]
+        );
+    }
+}
@@ -0,0 +1,259 @@
+//! Giant crops — a 3×3 block of the same mature crop can merge overnight into
+//! one giant crop, which the player breaks with an axe for a bigger harvest.
+//!
+//! Detection and merging run from `events_handler::on_day_end` after crops
+//! grow. Breaking is handled by the world domain's `handle_tool_use_on_objects`
+//! alongside trees and rocks.
+
+use super::{FarmEntities, FarmingAtlases};
+use crate::shared::*;
+use bevy::prelude::*;
+use rand::Rng;
+
+/// Chance per eligible 3×3 block, per night, that it merges into a giant crop.
+pub const GIANT_CROP_CHANCE: f64 = 0.01;
+
+/// Items dropped when a giant crop is broken (vs. 9 from harvesting the block).
+pub const GIANT_CROP_YIELD: u8 = 18;
+
+/// Marker for the sprite of a giant crop. `FarmEntities::giant_crop_entities`
+/// maps each origin tile to its sprite.
+#[derive(Component, Debug, Clone)]
+pub struct GiantCropEntity;
+
+/// Whether the crop at `pos` is alive, fully grown, and a crop that is
+/// harvested once (regrowing crops never merge).
+fn is_eligible(
+    farm: &FarmState,
+    crop_registry: &CropRegistry,
+    pos: (i32, i32),
+    crop_id: &str,
+) -> bool {
+    let Some(crop) = farm.crops.get(&pos) else {
+        return false;
+    };
+    let Some(def) = crop_registry.crops.get(crop_id) else {
+        return false;
+    };
+    crop.crop_id == crop_id
+        && !crop.dead
+        && !def.regrows
+        && crop.current_stage >= def.growth_days.len() as u8
+}
+
+/// Find non-overlapping 3×3 blocks of the same mature crop that could merge
+/// into a giant crop. Blocks are scanned bottom-left first, so the result is
+/// deterministic for a given farm.
+pub fn detect_giant_crop_blocks(farm: &FarmState, crop_registry: &CropRegistry) -> Vec<GiantCrop> {
+    let mut origins: Vec<(i32, i32)> = farm.crops.keys().copied().collect();
+    origins.sort_by_key(|&(x, y)| (y, x));
+
+    let mut claimed = std::collections::HashSet::new();
+    let mut blocks = Vec::new();
+    for origin in origins {
+        let crop_id = &farm.crops[&origin].crop_id;
+        let tiles: Vec<(i32, i32)> = (0..3)
+            .flat_map(|dy| (0..3).map(move |dx| (origin.0 + dx, origin.1 + dy)))
+            .collect();
+        let fits = tiles
+            .iter()
+            .all(|&pos| !claimed.contains(&pos) && is_eligible(farm, crop_registry, pos, crop_id));
+        if fits {
+            claimed.extend(tiles);
+            blocks.push(GiantCrop {
+                origin,
+                crop_id: crop_id.clone(),
+            });
+        }
+    }
+    blocks
+}
+
+/// Roll each eligible block and merge the winners: the nine crops are removed
+/// and a `GiantCrop` is recorded in `FarmState`. Returns how many formed.
+pub fn form_giant_crops(
+    farm_state: &mut FarmState,
+    farm_entities: &mut FarmEntities,
+    commands: &mut Commands,
+    crop_registry: &CropRegistry,
+) -> usize {
+    let mut rng = rand::thread_rng();
+    let mut formed = 0;
+    for giant in detect_giant_crop_blocks(farm_state, crop_registry) {
+        if !rng.gen_bool(GIANT_CROP_CHANCE) {
+            continue;
+        }
+        for dy in 0..3 {
+            for dx in 0..3 {
+                let pos = (giant.origin.0 + dx, giant.origin.1 + dy);
+                farm_state.crops.remove(&pos);
+                if let Some(entity) = farm_entities.crop_entities.remove(&pos) {
+                    commands.entity(entity).despawn();
+                }
+            }
+        }
+        info!(
+            "[Farming] Giant {} formed at {:?}",
+            giant.crop_id, giant.origin
+        );
+        farm_state.giant_crops.push(giant);
+        formed += 1;
+    }
+    formed
+}
+
+/// Spawn a 3×3 sprite for each giant crop and despawn sprites for giant crops
+/// that have been broken.
+pub fn sync_giant_crop_sprites(
+    mut commands: Commands,
+    mut farm_entities: ResMut<FarmEntities>,
+    farm_state: Res<FarmState>,
+    crop_registry: Res<CropRegistry>,
+    atlases: Res<FarmingAtlases>,
+) {
+    if !farm_state.is_changed() && !atlases.is_changed() {
+        return;
+    }
+
+    for giant in &farm_state.giant_crops {
+        if farm_entities
+            .giant_crop_entities
+            .contains_key(&giant.origin)
+        {
+            continue;
+        }
+        // Centre of the middle tile of the block.
+        let wc = grid_to_world_center(giant.origin.0 + 1, giant.origin.1 + 1);
+        let size = Vec2::splat(TILE_SIZE * 3.0);
+        let mature_index = crop_registry
+            .crops
+            .get(&giant.crop_id)
+            .map(|def| def.growth_days.len())
+            .unwrap_or(0);
+        let sprite = match atlases.crop_atlases.get(&giant.crop_id) {
+            Some((image, layout)) if atlases.loaded => {
+                let mut s = Sprite::from_atlas_image(
+                    image.clone(),
+                    TextureAtlas {
+                        layout: layout.clone(),
+                        index: mature_index,
+                    },
+                );
+                s.custom_size = Some(size);
+                s
+            }
+            _ => Sprite {
+                color: Color::srgb(0.45, 0.7, 0.3),
+                custom_size: Some(size),
+                ..default()
+            },
+        };
+        let entity = commands
+            .spawn((
+                sprite,
+                Transform::from_translation(wc.extend(Z_ENTITY_BASE)),
+                LogicalPosition(wc),
+                YSorted,
+                GiantCropEntity,
+            ))
+            .id();
+        farm_entities
+            .giant_crop_entities
+            .insert(giant.origin, entity);
+    }
+
+    let stale: Vec<(i32, i32)> = farm_entities
+        .giant_crop_entities
+        .keys()
+        .filter(|origin| !farm_state.giant_crops.iter().any(|g| g.origin == **origin))
+        .copied()
+        .collect();
+    for origin in stale {
+        if let Some(entity) = farm_entities.giant_crop_entities.remove(&origin) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> CropRegistry {
+        let mut registry = CropRegistry::default();
+        for (id, regrows) in [("cauliflower", false), ("strawberry", true)] {
+            registry.crops.insert(
+                id.to_string(),
+                CropDef {
+                    id: id.to_string(),
+                    name: id.to_string(),
+                    seed_id: format!("{}_seeds", id),
+                    harvest_id: id.to_string(),
+                    seasons: vec![Season::Spring],
+                    growth_days: vec![3, 3, 3, 3],
+                    regrows,
+                    regrow_days: if regrows { 4 } else { 0 },
+                    sell_price: 100,
+                    sprite_stages: vec![0, 1, 2, 3, 4],
+                    needs_trellis: false,
+                    off_season_tolerant: false,
+                },
+            );
+        }
+        registry
+    }
+
+    fn plant_block(farm: &mut FarmState, origin: (i32, i32), crop_id: &str, stage: u8) {
+        for dy in 0..3 {
+            for dx in 0..3 {
+                farm.crops.insert(
+                    (origin.0 + dx, origin.1 + dy),
+                    CropTile {
+                        crop_id: crop_id.to_string(),
+                        current_stage: stage,
+                        days_in_stage: 0,
+                        watered_today: false,
+                        days_without_water: 0,
+                        dead: false,
+                    },
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn full_mature_block_is_one_giant_crop_candidate() {
+        let registry = registry();
+        let mut farm = FarmState::default();
+        plant_block(&mut farm, (5, 5), "cauliflower", 4);
+
+        let blocks = detect_giant_crop_blocks(&farm, &registry);
+        assert_eq!(
+            blocks,
+            vec![GiantCrop {
+                origin: (5, 5),
+                crop_id: "cauliflower".to_string(),
+            }]
+        );
+        assert!(blocks[0].covers(7, 7));
+        assert!(!blocks[0].covers(8, 7));
+    }
+
+    #[test]
+    fn immature_mixed_or_regrowing_blocks_are_not_candidates() {
+        let registry = registry();
+
+        let mut farm = FarmState::default();
+        plant_block(&mut farm, (0, 0), "cauliflower", 3);
+        assert!(detect_giant_crop_blocks(&farm, &registry).is_empty());
+
+        let mut farm = FarmState::default();
+        plant_block(&mut farm, (0, 0), "cauliflower", 4);
+        farm.crops.get_mut(&(1, 1)).unwrap().crop_id = "strawberry".to_string();
+        assert!(detect_giant_crop_blocks(&farm, &registry).is_empty());
+
+        let mut farm = FarmState::default();
+        plant_block(&mut farm, (0, 0), "strawberry", 4);
+        assert!(detect_giant_crop_blocks(&farm, &registry).is_empty());
+    }
+}
@@ -131,6 +131,7 @@ pub fn handle_harvest_attempt(
     mut toast_events: EventWriter<ToastEvent>,
     crop_registry: Res<CropRegistry>,
     player_state: Res<PlayerState>,
+    play_stats: Res<PlayStats>,
 ) {
     // Farming tools only work on the farm map.
     if player_state.current_map != MapId::Farm {
@@ -150,24 +151,42 @@ pub fn handle_harvest_attempt(
         ];
 
         for target_pos in candidates {
-            if let Some(crop_name) = try_harvest_at(
+            if let Some(outcome) = try_harvest_at(
                 target_pos,
                 &mut farm_state,
                 &mut farm_entities,
                 &mut commands,
-                &mut item_pickup_events,
-                &mut crop_harvested_events,
                 &crop_registry,
             ) {
-                sfx_events.send(PlaySfxEvent {
-                    sfx_id: "harvest".to_string(),
-                });
-                if !crop_name.is_empty() {
+                if let HarvestOutcome::Harvested { def, fertilizer } = outcome {
+                    let quality = roll_crop_quality(
+                        farming_level(play_stats.crops_harvested),
+                        fertilizer,
+                        &mut rand::thread_rng(),
+                    );
+                    let quantity: u8 = 1; // base quantity always 1
+
+                    item_pickup_events.send(ItemPickupEvent {
+                        item_id: def.harvest_id.clone(),
+                        quantity,
+                        quality,
+                    });
+                    crop_harvested_events.send(CropHarvestedEvent {
+                        crop_id: def.id.clone(),
+                        harvest_id: def.harvest_id.clone(),
+                        quantity,
+                        x: target_pos.0,
+                        y: target_pos.1,
+                        quality: Some(quality),
+                    });
                     toast_events.send(ToastEvent {
-                        message: format!("Harvested {}!", crop_name),
+                        message: format!("Harvested {}!", def.name),
                         duration_secs: 2.0,
                     });
                 }
+                sfx_events.send(PlaySfxEvent {
+                    sfx_id: "harvest".to_string(),
+                });
 
                 // Spawn harvest particle burst at the tile position
                 let world_pos = grid_to_world_center(target_pos.0, target_pos.1);
@@ -179,23 +198,32 @@ pub fn handle_harvest_attempt(
     }
 }
 
-/// Try to harvest the crop at `pos`. Returns `Some(crop_name)` if a harvest
-/// occurred (empty string for dead crop removal), or `None` if nothing happened.
+/// What happened when the player reaped a tile.
+enum HarvestOutcome {
+    /// A dead crop was cleared away; nothing is gained.
+    DeadCropCleared,
+    /// A mature crop was harvested with the fertilizer it was grown in.
+    Harvested {
+        def: CropDef,
+        fertilizer: Option<Fertilizer>,
+    },
+}
+
+/// Try to harvest the crop at `pos`, updating the farm state and sprites.
+/// Returns `None` if nothing happened; the caller hands out the produce.
 fn try_harvest_at(
     pos: (i32, i32),
     farm_state: &mut FarmState,
     farm_entities: &mut FarmEntities,
     commands: &mut Commands,
-    item_pickup_events: &mut EventWriter<ItemPickupEvent>,
-    crop_harvested_events: &mut EventWriter<CropHarvestedEvent>,
     crop_registry: &CropRegistry,
-) -> Option<String> {
+) -> Option<HarvestOutcome> {
     let crop = farm_state.crops.get(&pos)?;
 
     if crop.dead {
         // Remove dead crop.
         despawn_crop(pos, farm_state, farm_entities, commands);
-        return Some(String::new());
+        return Some(HarvestOutcome::DeadCropCleared);
     }
 
     let def = crop_registry.crops.get(&crop.crop_id).cloned()?;
@@ -209,24 +237,7 @@ fn try_harvest_at(
     }
 
     // Harvest!
-    let quality = roll_harvest_quality();
-    let quantity: u8 = 1; // base quantity always 1
-
-    item_pickup_events.send(ItemPickupEvent {
-        item_id: def.harvest_id.clone(),
-        quantity,
-    });
-
-    crop_harvested_events.send(CropHarvestedEvent {
-        crop_id: def.id.clone(),
-        harvest_id: def.harvest_id.clone(),
-        quantity,
-        x: pos.0,
-        y: pos.1,
-        quality: Some(quality),
-    });
-
-    let crop_name = def.name.clone();
+    let fertilizer = farm_state.fertilizer.get(&pos).copied();
 
     if def.regrows {
         // Reset to regrow stage.  The crop goes back to the last stage and
@@ -243,8 +254,9 @@ fn try_harvest_at(
             refresh_crop_entity(pos, crop_ref, &def, farm_entities, commands);
         }
     } else {
-        // Remove the crop entirely.
+        // Remove the crop entirely. Fertilizer is used up with it.
         despawn_crop(pos, farm_state, farm_entities, commands);
+        farm_state.fertilizer.remove(&pos);
 
         // Also reset soil to tilled (harvest doesn't remove the tilled state).
         if let Some(state) = farm_state.soil.get_mut(&pos) {
@@ -254,7 +266,7 @@ fn try_harvest_at(
         }
     }
 
-    Some(crop_name)
+    Some(HarvestOutcome::Harvested { def, fertilizer })
 }
 
 /// Remove a crop from FarmState and despawn its entity.
@@ -271,15 +283,46 @@ pub fn despawn_crop(
     }
 }
 
+/// Cumulative harvest counts needed for farming levels 1–10.
+pub const FARMING_LEVEL_THRESHOLDS: [u64; 10] = [10, 25, 50, 100, 200, 350, 550, 800, 1100, 1500];
+
+/// Farming level (0–10) earned from lifetime harvests.
+pub fn farming_level(crops_harvested: u64) -> u32 {
+    FARMING_LEVEL_THRESHOLDS
+        .iter()
+        .take_while(|&&threshold| crops_harvested >= threshold)
+        .count() as u32
+}
+
 /// Roll the quality of a harvested crop.
-/// Distribution: 74% Normal, 20% Silver, 5% Gold, 1% Iridium.
-fn roll_harvest_quality() -> ItemQuality {
-    let roll: f32 = rand::thread_rng().gen_range(0.0..1.0);
-    if roll < 0.01 {
+///
+/// Each farming level adds 3% and each fertilizer tier 8% to a shared bonus
+/// `b`. Qualities are then rolled best-first:
+///   - Iridium: 1% + b / 10
+///   - Gold:    5% + b / 2
+///   - Silver: 20% + b
+///   - Normal:  whatever is left
+///
+/// Level 0 without fertilizer gives 74% Normal, 20% Silver, 5% Gold,
+/// 1% Iridium. Level 10 with fertilizer (b = 0.38) rolls Normal about 14% of the time.
+pub fn roll_crop_quality(
+    farming_level: u32,
+    fertilizer: Option<Fertilizer>,
+    rng: &mut impl Rng,
+) -> ItemQuality {
+    let level = farming_level.min(FARMING_LEVEL_THRESHOLDS.len() as u32);
+    let bonus = level as f32 * 0.03 + fertilizer.map_or(0, Fertilizer::tier) as f32 * 0.08;
+
+    let iridium = 0.01 + bonus * 0.1;
+    let gold = iridium + 0.05 + bonus * 0.5;
+    let silver = gold + 0.20 + bonus;
+
+    let roll: f32 = rng.gen_range(0.0..1.0);
+    if roll < iridium {
         ItemQuality::Iridium
-    } else if roll < 0.06 {
+    } else if roll < gold {
         ItemQuality::Gold
-    } else if roll < 0.26 {
+    } else if roll < silver {
         ItemQuality::Silver
     } else {
         ItemQuality::Normal
@@ -326,3 +369,52 @@ fn refresh_crop_entity(
         farm_entities.crop_entities.insert(pos, entity);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    /// Counts of [Normal, Silver, Gold, Iridium] over `n` seeded rolls.
+    fn distribution(level: u32, fertilizer: Option<Fertilizer>, n: usize) -> [usize; 4] {
+        let mut rng = StdRng::seed_from_u64(2121);
+        let mut counts = [0; 4];
+        for _ in 0..n {
+            let idx = match roll_crop_quality(level, fertilizer, &mut rng) {
+                ItemQuality::Normal => 0,
+                ItemQuality::Silver => 1,
+                ItemQuality::Gold => 2,
+                ItemQuality::Iridium => 3,
+            };
+            counts[idx] += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn farming_level_follows_thresholds() {
+        assert_eq!(farming_level(0), 0);
+        assert_eq!(farming_level(9), 0);
+        assert_eq!(farming_level(10), 1);
+        assert_eq!(farming_level(1_499), 9);
+        assert_eq!(farming_level(100_000), 10);
+    }
+
+    #[test]
+    fn higher_farming_level_shifts_quality_upward() {
+        let novice = distribution(0, None, 5_000);
+        let expert = distribution(10, None, 5_000);
+
+        assert!(expert[0] < novice[0], "{expert:?} vs {novice:?}");
+        assert!(expert[2] + expert[3] > novice[2] + novice[3]);
+    }
+
+    #[test]
+    fn fertilizer_shifts_quality_upward() {
+        let plain = distribution(3, None, 5_000);
+        let fertilized = distribution(3, Some(Fertilizer::Basic), 5_000);
+
+        assert!(fertilized[0] < plain[0], "{fertilized:?} vs {plain:?}");
+    }
+}
@@ -8,19 +8,27 @@
 //!   ENDED day (not the new day whose weather was already rolled).
 //! - Added `track_day_weather` system that runs BEFORE `on_day_end` each frame to
 //!   keep the snapshot current.  This avoids a cross-domain import.
+//! - `on_day_end` now reads the shared `PreviousDayWeather`, which the calendar sets
+//!   on both day-end paths, and is ordered after `process_day_end`.
+//!   `TrackedDayWeather` is still kept current for anything that needs the
+//!   in-progress day's weather.
 
 use crate::shared::*;
 use bevy::prelude::*;
 
 pub mod crops;
 pub mod events_handler;
+pub mod giant_crops;
 mod harvest;
 mod render;
 mod soil;
 mod sprinkler;
 pub mod sprinklers;
 mod tool_fx;
-use sprinklers::{auto_water_sprinklers, handle_place_sprinkler, remove_sprinkler};
+pub use soil::{hoe_stamina_cost, WATERING_CAN_STAMINA_COST};
+use sprinklers::{
+    auto_water_sprinklers, handle_place_sprinkler, line_place_drag, remove_sprinkler, LinePlacement,
+};
 
 /// Event to place a farm object (fence, scarecrow, etc.) at a grid position.
 #[derive(Event, Debug, Clone)]
@@ -65,6 +73,55 @@ pub struct FarmEntities {
     pub crop_entities: std::collections::HashMap<(i32, i32), Entity>,
     /// (x, y) -> farm object entity (sprinklers, scarecrows)
     pub object_entities: std::collections::HashMap<(i32, i32), Entity>,
+    /// Origin (x, y) -> giant crop sprite entity
+    pub giant_crop_entities: std::collections::HashMap<(i32, i32), Entity>,
+}
+
+impl FarmEntities {
+    /// Despawn every tracked soil, crop, farm-object, and giant crop entity and forget
+    /// the mapping. Called when the farm map is unloaded.
+    pub fn despawn_all(&mut self, commands: &mut Commands) {
+        for (_, entity) in self
+            .soil_entities
+            .drain()
+            .chain(self.crop_entities.drain())
+            .chain(self.object_entities.drain())
+            .chain(self.giant_crop_entities.drain())
+        {
+            if let Some(e) = commands.get_entity(entity) {
+                e.despawn_recursive();
+            }
+        }
+    }
+}
+
+/// Rebuild farm visuals from the persistent `FarmState` when the farm map is
+/// loaded. Any leftover entities are despawned first so the mapping in
+/// `FarmEntities` always points at the freshly spawned set.
+///
+/// Soil and crop entities are spawned here directly. Farm objects (including
+/// sprinklers, which `SprinklerState` mirrors into `FarmState.objects`) are
+/// rebuilt by `sync_farm_objects_sprites`, which runs because `FarmState` is
+/// marked changed.
+pub fn respawn_farm_entities(
+    commands: &mut Commands,
+    farm_entities: &mut FarmEntities,
+    farm_state: &mut ResMut<FarmState>,
+    crop_registry: &CropRegistry,
+) {
+    farm_entities.despawn_all(commands);
+
+    for (&pos, &state) in farm_state.soil.iter() {
+        soil::spawn_or_update_soil_entity(commands, farm_entities, pos, state);
+    }
+    for (&pos, crop) in farm_state.crops.iter() {
+        if let Some(def) = crop_registry.crops.get(&crop.crop_id) {
+            crops::spawn_crop_entity(commands, farm_entities, pos, crop, def);
+        }
+    }
+
+    // Let the sprite sync systems apply atlases and spawn object sprites.
+    farm_state.set_changed();
 }
 
 /// Holds the texture atlas handles for farming sprites (soil tiles and plant stages).
@@ -120,7 +177,7 @@ pub struct MorningSprinklerEvent;
 /// that just ended.
 ///
 /// This resource snapshots the weather each frame BEFORE the day-end trigger can
-/// overwrite it, ensuring `on_day_end` always reads the correct (ended) day's weather.
+/// overwrite it.  Day-end rain checks read `PreviousDayWeather` instead.
 #[derive(Resource, Debug, Clone)]
 pub struct TrackedDayWeather {
     pub weather: Weather,
@@ -149,6 +206,8 @@ impl Plugin for FarmingPlugin {
             .init_resource::<FarmEntities>()
             .init_resource::<FarmingAtlases>()
             .init_resource::<TrackedDayWeather>()
+            .init_resource::<LinePlacement>()
+            .init_resource::<crops::SeedDrag>()
             // Internal events
             .add_event::<HarvestAttemptEvent>()
             .add_event::<PlantSeedEvent>()
@@ -174,19 +233,32 @@ impl Plugin for FarmingPlugin {
                     // Tool use responses — soil interaction
                     soil::handle_hoe_tool_use,
                     soil::handle_watering_can_tool_use,
+                    soil::handle_quick_water,
+                    // Fertilizer (player works it into a tilled tile)
+                    soil::apply_fertilizer,
                     // Planting (player presses interact with seed in hand over tilled tile)
                     crops::handle_plant_seed,
                     // Harvest (player presses Space near mature crop)
                     harvest::handle_harvest_attempt,
+                    // Trellis crops block walking until they are removed
+                    crops::sync_trellis_collision
+                        .after(crops::handle_plant_seed)
+                        .after(harvest::handle_harvest_attempt),
                     // Keyboard shortcut: Space bar -> try harvest at player position
                     harvest::detect_harvest_input,
                     // Seed placement detection (player uses seed item)
                     crops::detect_seed_use,
+                    // Drag planting (hold the plant action to sow across tilled rows)
+                    crops::drag_plant_seeds.before(crops::handle_plant_seed),
+                    // Drag placement (hold secondary to lay sprinklers/paths in a line)
+                    line_place_drag
+                        .before(handle_place_sprinkler)
+                        .before(handle_place_farm_object),
                     // Sprinkler placement (player places sprinkler from inventory)
                     handle_place_sprinkler,
                     // Sprinkler removal (pickaxe on a sprinkler tile)
                     remove_sprinkler,
-                    // Farm object placement (fence, scarecrow)
+                    // Farm object placement (fence, scarecrow, paths)
                     handle_place_farm_object,
                     // Harvest particle animation
                     harvest::update_harvest_particles,
@@ -228,6 +300,7 @@ impl Plugin for FarmingPlugin {
                     events_handler::on_season_change,
                 )
                     .chain()
+                    .after(crate::calendar::process_day_end)
                     .run_if(in_state(GameState::Playing)),
             )
             // ------------------------------------------------------------------
@@ -239,6 +312,7 @@ impl Plugin for FarmingPlugin {
                     render::sync_soil_sprites,
                     render::sync_crop_sprites,
                     render::sync_farm_objects_sprites,
+                    giant_crops::sync_giant_crop_sprites,
                 )
                     .run_if(in_farm_map)
                     .run_if(in_state(GameState::Playing)),
@@ -260,6 +334,20 @@ impl Plugin for FarmingPlugin {
                 render::animate_sprinklers
                     .after(render::sync_farm_objects_sprites)
                     .run_if(in_state(GameState::Playing)),
+            )
+            // ------------------------------------------------------------------
+            // Sprinkler coverage preview — follows the held sprinkler
+            // ------------------------------------------------------------------
+            .add_systems(
+                PostUpdate,
+                render::update_sprinkler_preview.run_if(in_state(GameState::Playing)),
+            )
+            // ------------------------------------------------------------------
+            // Harvest countdown overlay — optional, toggled in settings
+            // ------------------------------------------------------------------
+            .add_systems(
+                PostUpdate,
+                render::update_harvest_countdown.run_if(in_state(GameState::Playing)),
             );
     }
 }
@@ -416,6 +504,8 @@ pub fn handle_place_farm_object(
         let farm_obj = match ev.item_id.as_str() {
             "fence" => FarmObject::Fence,
             "scarecrow" => FarmObject::Scarecrow,
+            "lightning_rod" => FarmObject::LightningRod,
+            "wood_path" | "stone_path" => FarmObject::Path,
             _ => {
                 warn!("PlaceFarmObjectEvent: unknown item '{}'", ev.item_id);
                 continue;
@@ -428,6 +518,9 @@ pub fn handle_place_farm_object(
         let label = match ev.item_id.as_str() {
             "fence" => "Fence",
             "scarecrow" => "Scarecrow",
+            "lightning_rod" => "Lightning Rod",
+            "wood_path" => "Wood Path",
+            "stone_path" => "Stone Path",
             _ => "Object",
         };
         toast_events.send(ToastEvent {
@@ -439,3 +532,213 @@ pub fn handle_place_farm_object(
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn enter_farm(
+        mut commands: Commands,
+        mut farm_entities: ResMut<FarmEntities>,
+        mut farm_state: ResMut<FarmState>,
+        crop_registry: Res<CropRegistry>,
+    ) {
+        respawn_farm_entities(
+            &mut commands,
+            &mut farm_entities,
+            &mut farm_state,
+            &crop_registry,
+        );
+    }
+
+    fn leave_farm(mut commands: Commands, mut farm_entities: ResMut<FarmEntities>) {
+        farm_entities.despawn_all(&mut commands);
+    }
+
+    #[test]
+    fn crops_survive_leaving_and_returning_to_farm() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<FarmEntities>()
+            .init_resource::<FarmState>()
+            .init_resource::<CropRegistry>();
+        app.world_mut().resource_mut::<CropRegistry>().crops.insert(
+            "turnip".into(),
+            CropDef {
+                id: "turnip".into(),
+                name: "Turnip".into(),
+                seed_id: "turnip_seeds".into(),
+                harvest_id: "turnip".into(),
+                seasons: vec![Season::Spring],
+                growth_days: vec![1, 1, 1, 1],
+                regrows: false,
+                regrow_days: 0,
+                sell_price: 60,
+                sprite_stages: vec![0, 1, 2, 3],
+                needs_trellis: false,
+                off_season_tolerant: false,
+            },
+        );
+
+        // Plant a turnip on watered soil.
+        let pos = (3, 4);
+        {
+            let mut farm = app.world_mut().resource_mut::<FarmState>();
+            farm.soil.insert(pos, SoilState::Watered);
+            farm.crops.insert(
+                pos,
+                CropTile {
+                    crop_id: "turnip".into(),
+                    current_stage: 1,
+                    days_in_stage: 0,
+                    watered_today: true,
+                    days_without_water: 0,
+                    dead: false,
+                },
+            );
+        }
+        app.world_mut().run_system_once(enter_farm).unwrap();
+        let planted = app.world().resource::<FarmEntities>().crop_entities[&pos];
+
+        // Leaving despawns the visuals and forgets the mapping...
+        app.world_mut().run_system_once(leave_farm).unwrap();
+        assert!(app.world().get_entity(planted).is_err());
+        assert!(app
+            .world()
+            .resource::<FarmEntities>()
+            .crop_entities
+            .is_empty());
+
+        // ...and returning rebuilds them from FarmState.
+        app.world_mut().run_system_once(enter_farm).unwrap();
+        let farm_entities = app.world().resource::<FarmEntities>();
+        let crop_entity = farm_entities.crop_entities[&pos];
+        assert!(farm_entities.soil_entities.contains_key(&pos));
+        let tile = app
+            .world()
+            .get::<CropTileEntity>(crop_entity)
+            .expect("crop entity should be respawned");
+        assert_eq!((tile.grid_x, tile.grid_y), pos);
+        let crop = app.world().get::<CropTile>(crop_entity).unwrap();
+        assert_eq!(crop.current_stage, 1);
+    }
+
+    #[test]
+    fn trellis_crop_blocks_its_tile_until_harvested() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<FarmEntities>()
+            .init_resource::<FarmState>()
+            .init_resource::<CropRegistry>()
+            .init_resource::<Inventory>()
+            .init_resource::<Calendar>()
+            .init_resource::<PlayStats>()
+            .insert_resource(PlayerState {
+                current_map: MapId::Farm,
+                ..default()
+            })
+            .insert_resource(crate::world::WorldMap {
+                width: 16,
+                height: 16,
+                ..default()
+            })
+            .add_event::<PlantSeedEvent>()
+            .add_event::<HarvestAttemptEvent>()
+            .add_event::<ItemRemovedEvent>()
+            .add_event::<ItemPickupEvent>()
+            .add_event::<CropHarvestedEvent>()
+            .add_event::<PlaySfxEvent>()
+            .add_event::<ToastEvent>()
+            .add_systems(
+                Update,
+                (
+                    crops::handle_plant_seed,
+                    harvest::handle_harvest_attempt,
+                    crops::sync_trellis_collision,
+                )
+                    .chain(),
+            );
+        app.world_mut().resource_mut::<CropRegistry>().crops.insert(
+            "hops".into(),
+            CropDef {
+                id: "hops".into(),
+                name: "Hops".into(),
+                seed_id: "hops_seeds".into(),
+                harvest_id: "hops".into(),
+                seasons: vec![Season::Spring],
+                growth_days: vec![1, 1],
+                regrows: false,
+                regrow_days: 0,
+                sell_price: 25,
+                sprite_stages: vec![0, 1],
+                needs_trellis: true,
+                off_season_tolerant: false,
+            },
+        );
+        let pos = (5, 5);
+        app.world_mut()
+            .resource_mut::<FarmState>()
+            .soil
+            .insert(pos, SoilState::Tilled);
+        app.world_mut()
+            .resource_mut::<Inventory>()
+            .try_add("hops_seeds", 1, 99);
+
+        app.world_mut().send_event(PlantSeedEvent {
+            grid_x: pos.0,
+            grid_y: pos.1,
+            seed_item_id: "hops_seeds".into(),
+        });
+        app.update();
+        assert!(app.world().resource::<FarmState>().crops.contains_key(&pos));
+        assert!(app
+            .world()
+            .resource::<crate::world::WorldMap>()
+            .solid_tiles
+            .contains(&pos));
+
+        app.world_mut()
+            .resource_mut::<FarmState>()
+            .crops
+            .get_mut(&pos)
+            .unwrap()
+            .current_stage = 2;
+        app.world_mut().send_event(HarvestAttemptEvent {
+            grid_x: pos.0,
+            grid_y: pos.1,
+        });
+        app.update();
+        assert!(!app.world().resource::<FarmState>().crops.contains_key(&pos));
+        assert!(!app
+            .world()
+            .resource::<crate::world::WorldMap>()
+            .solid_tiles
+            .contains(&pos));
+    }
+
+    #[test]
+    fn trellis_may_not_box_in_the_player() {
+        let mut world_map = crate::world::WorldMap {
+            map_def: Some(crate::world::maps::MapDef {
+                id: MapId::Farm,
+                width: 16,
+                height: 16,
+                tiles: vec![TileKind::Grass; 16 * 16],
+                transitions: vec![],
+                objects: vec![],
+                forage_points: vec![],
+            }),
+            width: 16,
+            height: 16,
+            ..default()
+        };
+        assert!(crops::trellis_would_trap(&world_map, (5, 5), (5, 5)));
+        assert!(!crops::trellis_would_trap(&world_map, (5, 5), (6, 5)));
+
+        world_map.set_solid(4, 5, true);
+        world_map.set_solid(5, 4, true);
+        world_map.set_solid(5, 6, true);
+        assert!(crops::trellis_would_trap(&world_map, (5, 5), (6, 5)));
+    }
+}
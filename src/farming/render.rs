@@ -6,8 +6,11 @@
 //! coloured placeholder sprites are used as a fallback.
 
 use super::{
-    crop_stage_color, soil::soil_color, CropTileEntity, FarmEntities, FarmObjectEntity,
-    FarmingAtlases, SoilTileEntity,
+    crop_stage_color,
+    crops::days_until_harvest,
+    soil::soil_color,
+    sprinklers::{sprinkler_affected_tiles, sprinkler_kind_for_item},
+    CropTileEntity, FarmEntities, FarmObjectEntity, FarmingAtlases, SoilTileEntity,
 };
 use crate::shared::*;
 use crate::world::objects::WindSway;
@@ -89,6 +92,35 @@ fn crop_atlas_index(stage: u8, total_stages: u8) -> usize {
     ((stage as usize * 5) / total).min(5)
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Crop tint
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Tint applied to a crop sprite. Healthy crops are untinted (WHITE); crops
+/// that missed a watering turn yellow — deeper after two dry days — as a
+/// warning before they die; dead crops are a flat dark brown.
+pub fn crop_tint(tile: &CropTile) -> Color {
+    if tile.dead {
+        Color::srgb(0.4, 0.3, 0.2) // dark brown — withered/dead
+    } else if tile.days_without_water >= 2 {
+        Color::srgb(0.85, 0.70, 0.30) // severely dehydrated — deep yellow
+    } else if tile.days_without_water >= 1 {
+        Color::srgb(0.90, 0.85, 0.50) // thirsty — light yellow
+    } else {
+        Color::WHITE // healthy / watered today
+    }
+}
+
+/// Colour for a crop drawn as a plain rectangle (no atlas): the growth-stage
+/// colour while healthy, otherwise the thirsty/dead tint.
+fn crop_placeholder_color(tile: &CropTile, total_stages: u8) -> Color {
+    if tile.dead || tile.days_without_water >= 1 {
+        crop_tint(tile)
+    } else {
+        crop_stage_color(tile.current_stage, total_stages, tile.dead)
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Soil sprite sync
 // ─────────────────────────────────────────────────────────────────────────────
@@ -305,7 +337,7 @@ pub fn sync_crop_sprites(
                 // Dead crops: dark brown tint regardless of atlas availability.
                 // Remove any atlas reference so the colour shows through.
                 sprite.texture_atlas = None;
-                sprite.color = Color::srgb(0.4, 0.3, 0.2);
+                sprite.color = crop_tint(crop);
                 sprite.custom_size = Some(Vec2::splat(TILE_SIZE * 0.8));
             } else if let Some(atlas) = &mut sprite.texture_atlas {
                 // Atlas sprite: update slice index for current stage.
@@ -317,14 +349,7 @@ pub fn sync_crop_sprites(
                     atlas.index = crop_atlas_index(crop.current_stage, total_stages);
                 }
                 // Apply dehydration tint on top of the atlas image.
-                // Freshly watered or healthy crops get no tint (WHITE).
-                sprite.color = if crop.days_without_water >= 2 {
-                    Color::srgb(0.85, 0.70, 0.30) // severely dehydrated — deep yellow
-                } else if crop.days_without_water >= 1 {
-                    Color::srgb(0.90, 0.85, 0.50) // mildly dehydrated — light yellow
-                } else {
-                    Color::WHITE // healthy / watered today
-                };
+                sprite.color = crop_tint(crop);
             } else if atlases.loaded && !crop.dead {
                 // Upgrade: sprite was spawned as color-only before atlases loaded.
                 // Prefer per-crop atlas if available, otherwise fall back to plants.png.
@@ -347,23 +372,10 @@ pub fn sync_crop_sprites(
                         },
                     );
                 }
-                sprite.color = if crop.days_without_water >= 2 {
-                    Color::srgb(0.85, 0.70, 0.30)
-                } else if crop.days_without_water >= 1 {
-                    Color::srgb(0.90, 0.85, 0.50)
-                } else {
-                    Color::WHITE
-                };
+                sprite.color = crop_tint(crop);
             } else {
                 // Fallback: colour placeholder (atlases not ready, or dead crop).
-                let color = if crop.days_without_water >= 2 {
-                    Color::srgb(0.85, 0.70, 0.30) // severely dehydrated
-                } else if crop.days_without_water >= 1 {
-                    Color::srgb(0.90, 0.85, 0.50) // mildly dehydrated
-                } else {
-                    crop_stage_color(crop.current_stage, total_stages, crop.dead)
-                };
-                sprite.color = color;
+                sprite.color = crop_placeholder_color(crop, total_stages);
             }
 
             // Scale: slightly larger when mature.
@@ -446,15 +458,7 @@ pub fn sync_crop_sprites(
                 .id()
         } else {
             // Fallback: coloured rectangle (also used for dead crops).
-            let color = if crop.dead {
-                Color::srgb(0.4, 0.3, 0.2) // dark brown — withered/dead
-            } else if crop.days_without_water >= 2 {
-                Color::srgb(0.85, 0.70, 0.30) // severely dehydrated
-            } else if crop.days_without_water >= 1 {
-                Color::srgb(0.90, 0.85, 0.50) // mildly dehydrated
-            } else {
-                crop_stage_color(crop.current_stage, total_stages, crop.dead)
-            };
+            let color = crop_placeholder_color(&crop, total_stages);
             commands
                 .spawn((
                     Sprite {
@@ -553,6 +557,8 @@ fn farm_object_color(obj: &FarmObject) -> Color {
         FarmObject::Sprinkler => Color::srgb(0.5, 0.5, 0.7),
         FarmObject::Scarecrow => Color::srgb(0.6, 0.4, 0.2),
         FarmObject::Fence => Color::srgb(0.6, 0.4, 0.2),
+        FarmObject::Path => Color::srgb(0.7, 0.65, 0.51),
+        FarmObject::LightningRod => Color::srgb(0.55, 0.57, 0.62),
         _ => Color::srgb(0.5, 0.5, 0.5),
     }
 }
@@ -575,7 +581,8 @@ fn fence_autotile_index(farm_state: &FarmState, x: i32, y: i32) -> usize {
     mask as usize
 }
 
-/// Synchronise visual entities for sprinklers, scarecrows, and fences in `FarmState.objects`.
+/// Synchronise visual entities for sprinklers, scarecrows, lightning rods, fences, and paths in
+/// `FarmState.objects`.
 ///
 /// Follows the same overall pattern as `sync_soil_sprites` / `sync_crop_sprites`:
 ///   - Spawn missing entities.
@@ -604,7 +611,11 @@ pub fn sync_farm_objects_sprites(
         .filter(|(&pos, obj)| {
             matches!(
                 obj,
-                FarmObject::Sprinkler | FarmObject::Scarecrow | FarmObject::Fence
+                FarmObject::Sprinkler
+                    | FarmObject::Scarecrow
+                    | FarmObject::Fence
+                    | FarmObject::Path
+                    | FarmObject::LightningRod
             ) && !farm_entities.object_entities.contains_key(&pos)
         })
         .map(|(&pos, obj)| (pos, obj.clone()))
@@ -615,7 +626,23 @@ pub fn sync_farm_objects_sprites(
         let translation = Vec3::new(wc.x, wc.y, Z_ENTITY_BASE);
         let logical = LogicalPosition(wc);
 
-        let entity = if matches!(obj, FarmObject::Fence) {
+        let entity = if matches!(obj, FarmObject::Path) {
+            // Paths lie flat on the ground layer (just over soil), so they skip Y-sorting.
+            commands
+                .spawn((
+                    Sprite {
+                        color: farm_object_color(&obj),
+                        custom_size: Some(Vec2::splat(TILE_SIZE)),
+                        ..default()
+                    },
+                    Transform::from_translation(wc.extend(Z_FARM_OVERLAY + 0.5)),
+                    FarmObjectEntity {
+                        grid_x: pos.0,
+                        grid_y: pos.1,
+                    },
+                ))
+                .id()
+        } else if matches!(obj, FarmObject::Fence) {
             // Fences use the fences atlas with autotiling.
             if obj_atlases.loaded {
                 let idx = fence_autotile_index(&farm_state, pos.0, pos.1);
@@ -683,7 +710,7 @@ pub fn sync_farm_objects_sprites(
                     },
                 ))
                 .id()
-        } else if farming_atlases.loaded {
+        } else if farming_atlases.loaded && !matches!(obj, FarmObject::LightningRod) {
             // Fallback: standalone sprite images for sprinkler/scarecrow.
             let image = match obj {
                 FarmObject::Sprinkler => farming_atlases.sprinkler_image.clone(),
@@ -741,7 +768,11 @@ pub fn sync_farm_objects_sprites(
                 .map(|o| {
                     matches!(
                         o,
-                        FarmObject::Sprinkler | FarmObject::Scarecrow | FarmObject::Fence
+                        FarmObject::Sprinkler
+                            | FarmObject::Scarecrow
+                            | FarmObject::Fence
+                            | FarmObject::Path
+                            | FarmObject::LightningRod
                     )
                 })
                 .unwrap_or(false)
@@ -813,3 +844,323 @@ pub fn animate_sprinklers(
         }
     }
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Sprinkler coverage preview
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Translucent highlight over one tile the held sprinkler would water.
+#[derive(Component, Debug, Clone)]
+pub struct SprinklerPreviewTile {
+    pub grid_x: i32,
+    pub grid_y: i32,
+}
+
+const SPRINKLER_PREVIEW_COLOR: Color = Color::srgba(0.35, 0.65, 1.0, 0.35);
+
+/// While a sprinkler is selected on the farm, highlight the tiles it would
+/// water if placed on the faced tile.  Coverage comes straight from
+/// `sprinkler_affected_tiles`, so the preview always matches real watering.
+/// Only tiles that enter or leave the coverage are respawned or despawned,
+/// and the highlight clears once the sprinkler is deselected or the player
+/// leaves the farm.
+pub fn update_sprinkler_preview(
+    mut commands: Commands,
+    inventory: Res<Inventory>,
+    player_state: Res<PlayerState>,
+    player_query: Query<(&LogicalPosition, &PlayerMovement), With<Player>>,
+    previews: Query<(Entity, &SprinklerPreviewTile)>,
+) {
+    let kind = inventory
+        .slots
+        .get(inventory.selected_slot)
+        .and_then(|s| s.as_ref())
+        .and_then(|s| sprinkler_kind_for_item(&s.item_id));
+
+    let mut wanted: std::collections::HashSet<(i32, i32)> = match (kind, player_query.get_single())
+    {
+        (Some(kind), Ok((pos, movement))) if player_state.current_map == MapId::Farm => {
+            let (tx, ty) = player_target_tile(pos.0, movement.facing);
+            sprinkler_affected_tiles(kind, tx, ty).into_iter().collect()
+        }
+        _ => Default::default(),
+    };
+
+    // Keep highlights still under the coverage; whatever is left is new.
+    for (entity, tile) in previews.iter() {
+        if !wanted.remove(&(tile.grid_x, tile.grid_y)) {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    for (x, y) in wanted {
+        commands.spawn((
+            Sprite {
+                color: SPRINKLER_PREVIEW_COLOR,
+                custom_size: Some(Vec2::splat(TILE_SIZE)),
+                ..default()
+            },
+            Transform::from_translation(grid_to_world_center(x, y).extend(Z_FARM_OVERLAY + 1.25)),
+            SprinklerPreviewTile {
+                grid_x: x,
+                grid_y: y,
+            },
+        ));
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Harvest countdown overlay
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Label above a crop showing days left until harvest, or a harvest icon once
+/// the crop is ready.
+#[derive(Component, Debug, Clone)]
+pub struct HarvestCountdownLabel {
+    pub grid_x: i32,
+    pub grid_y: i32,
+    pub days: u8,
+}
+
+const HARVEST_READY_COLOR: Color = Color::srgb(1.0, 0.82, 0.2);
+
+/// Vertical offset of the countdown above the crop's tile centre.
+const HARVEST_COUNTDOWN_OFFSET_Y: f32 = TILE_SIZE * 0.6;
+
+/// When `GameSettings::show_harvest_countdown` is on and the player is on the
+/// farm, label every living crop with its remaining growth days; mature crops
+/// get a small harvest icon instead.  Dead crops are left unlabelled.  Only
+/// labels whose countdown changed are respawned.
+pub fn update_harvest_countdown(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    farm_state: Res<FarmState>,
+    crop_registry: Res<CropRegistry>,
+    player_state: Res<PlayerState>,
+    labels: Query<(Entity, &HarvestCountdownLabel)>,
+) {
+    if !settings.is_changed()
+        && !farm_state.is_changed()
+        && !crop_registry.is_changed()
+        && !player_state.is_changed()
+    {
+        return;
+    }
+
+    let mut wanted = std::collections::HashSet::new();
+    if settings.show_harvest_countdown && player_state.current_map == MapId::Farm {
+        for (&pos, crop) in &farm_state.crops {
+            let days = crop_registry
+                .crops
+                .get(&crop.crop_id)
+                .and_then(|def| days_until_harvest(crop, def));
+            if let Some(days) = days {
+                wanted.insert((pos, days));
+            }
+        }
+    }
+
+    // Keep labels that are still right; whatever is left in `wanted` is new.
+    for (entity, label) in labels.iter() {
+        if !wanted.remove(&((label.grid_x, label.grid_y), label.days)) {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    for ((x, y), days) in wanted {
+        let position = (grid_to_world_center(x, y) + Vec2::new(0.0, HARVEST_COUNTDOWN_OFFSET_Y))
+            .extend(Z_EFFECTS);
+        let label = HarvestCountdownLabel {
+            grid_x: x,
+            grid_y: y,
+            days,
+        };
+        if days == 0 {
+            // Harvest icon: a small gold diamond.
+            commands.spawn((
+                Sprite {
+                    color: HARVEST_READY_COLOR,
+                    custom_size: Some(Vec2::splat(4.0)),
+                    ..default()
+                },
+                Transform::from_translation(position)
+                    .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+                label,
+            ));
+        } else {
+            commands.spawn((
+                Text2d::new(days.to_string()),
+                TextFont {
+                    font_size: 8.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Transform::from_translation(position),
+                label,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn sprinkler_preview_matches_coverage_for_each_kind() {
+        for (item_id, kind) in [
+            ("sprinkler", SprinklerKind::Basic),
+            ("quality_sprinkler", SprinklerKind::Quality),
+            ("iridium_sprinkler", SprinklerKind::Iridium),
+        ] {
+            let mut app = App::new();
+            app.add_plugins(MinimalPlugins)
+                .insert_resource(PlayerState {
+                    current_map: MapId::Farm,
+                    ..default()
+                })
+                .add_systems(Update, update_sprinkler_preview);
+            let mut inventory = Inventory::default();
+            inventory.try_add(item_id, 1, 1);
+            app.insert_resource(inventory);
+            app.world_mut().spawn((
+                Player,
+                LogicalPosition(grid_to_world_center(4, 4)),
+                PlayerMovement {
+                    facing: Facing::Up,
+                    ..default()
+                },
+            ));
+
+            app.update();
+
+            let mut query = app.world_mut().query::<&SprinklerPreviewTile>();
+            let previewed: HashSet<(i32, i32)> = query
+                .iter(app.world())
+                .map(|t| (t.grid_x, t.grid_y))
+                .collect();
+            let expected: HashSet<(i32, i32)> =
+                sprinkler_affected_tiles(kind, 4, 5).into_iter().collect();
+            assert_eq!(previewed, expected, "{:?} preview mismatch", kind);
+        }
+    }
+
+    #[test]
+    fn sprinkler_preview_clears_when_deselected() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(PlayerState {
+                current_map: MapId::Farm,
+                ..default()
+            })
+            .add_systems(Update, update_sprinkler_preview);
+        let mut inventory = Inventory::default();
+        inventory.try_add("sprinkler", 1, 1);
+        app.insert_resource(inventory);
+        app.world_mut().spawn((
+            Player,
+            LogicalPosition(grid_to_world_center(0, 0)),
+            PlayerMovement::default(),
+        ));
+        app.update();
+
+        app.world_mut().resource_mut::<Inventory>().selected_slot = 1;
+        app.update();
+
+        let mut query = app.world_mut().query::<&SprinklerPreviewTile>();
+        assert_eq!(query.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn harvest_countdown_labels_living_crops_only() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(GameSettings {
+                show_harvest_countdown: true,
+                ..default()
+            })
+            .insert_resource(PlayerState {
+                current_map: MapId::Farm,
+                ..default()
+            })
+            .init_resource::<FarmState>()
+            .init_resource::<CropRegistry>()
+            .add_systems(Update, update_harvest_countdown);
+        app.world_mut().resource_mut::<CropRegistry>().crops.insert(
+            "turnip".into(),
+            CropDef {
+                id: "turnip".into(),
+                name: "Turnip".into(),
+                seed_id: "turnip_seeds".into(),
+                harvest_id: "turnip".into(),
+                seasons: vec![Season::Spring],
+                growth_days: vec![1, 1, 1, 1],
+                regrows: false,
+                regrow_days: 0,
+                sell_price: 60,
+                sprite_stages: vec![0, 1, 2, 3],
+                needs_trellis: false,
+                off_season_tolerant: false,
+            },
+        );
+        {
+            let mut farm = app.world_mut().resource_mut::<FarmState>();
+            for (pos, stage, dead) in [((0, 0), 1, false), ((1, 0), 4, false), ((2, 0), 2, true)] {
+                farm.crops.insert(
+                    pos,
+                    CropTile {
+                        crop_id: "turnip".into(),
+                        current_stage: stage,
+                        days_in_stage: 0,
+                        watered_today: false,
+                        days_without_water: 0,
+                        dead,
+                    },
+                );
+            }
+        }
+        app.update();
+
+        let mut text_query = app.world_mut().query::<(&HarvestCountdownLabel, &Text2d)>();
+        let texts: Vec<((i32, i32), String)> = text_query
+            .iter(app.world())
+            .map(|(l, t)| ((l.grid_x, l.grid_y), t.0.clone()))
+            .collect();
+        assert_eq!(texts, vec![((0, 0), "3".to_string())]);
+        let mut label_query = app.world_mut().query::<&HarvestCountdownLabel>();
+        let labelled: HashSet<(i32, i32)> = label_query
+            .iter(app.world())
+            .map(|l| (l.grid_x, l.grid_y))
+            .collect();
+        assert_eq!(labelled, HashSet::from([(0, 0), (1, 0)]));
+
+        app.world_mut()
+            .resource_mut::<GameSettings>()
+            .show_harvest_countdown = false;
+        app.update();
+        assert_eq!(label_query.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn crop_tint_warns_when_thirsty_before_death() {
+        let crop = |days_without_water, dead| CropTile {
+            crop_id: "turnip".into(),
+            current_stage: 1,
+            days_in_stage: 0,
+            watered_today: false,
+            days_without_water,
+            dead,
+        };
+
+        let healthy = crop_tint(&crop(0, false));
+        let thirsty = crop_tint(&crop(1, false));
+        let dead = crop_tint(&crop(3, true));
+
+        assert_eq!(healthy, Color::WHITE);
+        assert_ne!(thirsty, healthy);
+        assert_ne!(thirsty, dead);
+        assert_eq!(dead, Color::srgb(0.4, 0.3, 0.2));
+        assert_eq!(crop_tint(&crop(0, true)), dead, "dead wins over watered");
+    }
+}
@@ -6,10 +6,26 @@ use bevy::prelude::*;
 
 const FIRST_HOE_USE_HINT_ID: &str = "first_hoe_use";
 
+/// Stamina tilling a tile costs on top of the swing, by hoe tier.
+pub fn hoe_stamina_cost(tier: ToolTier) -> f32 {
+    match tier {
+        ToolTier::Basic => 2.0,
+        ToolTier::Copper => 1.8,
+        ToolTier::Iron => 1.5,
+        ToolTier::Gold => 1.2,
+        ToolTier::Iridium => 1.0,
+    }
+}
+
+/// Stamina a watering-can pour costs on top of the swing at basic tier;
+/// upgrades scale it down through `tool_stamina_cost`.
+pub const WATERING_CAN_STAMINA_COST: f32 = 2.0;
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Hoe — till a dirt tile
 // ─────────────────────────────────────────────────────────────────────────────
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_hoe_tool_use(
     mut tool_events: EventReader<ToolUseEvent>,
     mut farm_state: ResMut<FarmState>,
@@ -20,6 +36,7 @@ pub fn handle_hoe_tool_use(
     mut sfx_events: EventWriter<PlaySfxEvent>,
     mut toast_events: EventWriter<ToastEvent>,
     player_state: Res<PlayerState>,
+    settings: Res<GameSettings>,
 ) {
     for event in tool_events.read() {
         if event.tool != ToolKind::Hoe {
@@ -47,12 +64,10 @@ pub fn handle_hoe_tool_use(
         farm_state.soil.insert(pos, SoilState::Tilled);
 
         // Drain stamina (2 per tile, basic tier; higher tiers could expand range).
-        let stamina_cost = match event.tier {
-            ToolTier::Basic => 2.0,
-            ToolTier::Copper => 1.8,
-            ToolTier::Iron => 1.5,
-            ToolTier::Gold => 1.2,
-            ToolTier::Iridium => 1.0,
+        let stamina_cost = if settings.creative_tools {
+            0.0
+        } else {
+            hoe_stamina_cost(event.tier)
         };
         stamina_events.send(StaminaDrainEvent {
             amount: stamina_cost,
@@ -94,6 +109,7 @@ pub fn handle_watering_can_tool_use(
     mut toast_events: EventWriter<ToastEvent>,
     player_query: Query<&PlayerMovement, With<Player>>,
     player_state: Res<PlayerState>,
+    settings: Res<GameSettings>,
 ) {
     // Determine the direction the player is currently facing.
     // Fall back to Down if the query returns nothing (shouldn't happen in normal play).
@@ -156,7 +172,11 @@ pub fn handle_watering_can_tool_use(
         }
 
         // Single stamina drain for the whole action (not per tile).
-        let stamina_cost = tool_stamina_cost(2.0, event.tier);
+        let stamina_cost = tool_stamina_cost(
+            WATERING_CAN_STAMINA_COST,
+            event.tier,
+            settings.creative_tools,
+        );
         stamina_events.send(StaminaDrainEvent {
             amount: stamina_cost,
         });
@@ -166,6 +186,173 @@ pub fn handle_watering_can_tool_use(
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Quick water — sweep the can's whole area in one pour
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Water every Tilled tile among `tiles`. Returns the tiles watered.
+pub fn quick_water_sweep(farm_state: &mut FarmState, tiles: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    let mut watered = Vec::new();
+    for &pos in tiles {
+        if farm_state.soil.get(&pos).copied() != Some(SoilState::Tilled) {
+            continue;
+        }
+        farm_state.soil.insert(pos, SoilState::Watered);
+        if let Some(crop) = farm_state.crops.get_mut(&pos) {
+            crop.watered_today = true;
+            crop.days_without_water = 0;
+        }
+        watered.push(pos);
+    }
+    watered
+}
+
+/// Quick-water key: water every dry tilled tile in the watering can's area in
+/// front of the player for the price of one tier-scaled watering-can swing.
+/// Refused when stamina can't cover it.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_quick_water(
+    player_input: Res<PlayerInput>,
+    input_blocks: Res<InputBlocks>,
+    player_state: Res<PlayerState>,
+    mut farm_state: ResMut<FarmState>,
+    mut farm_entities: ResMut<FarmEntities>,
+    mut commands: Commands,
+    player_query: Query<(&LogicalPosition, &PlayerMovement), With<Player>>,
+    mut stamina_events: EventWriter<StaminaDrainEvent>,
+    mut sfx_events: EventWriter<PlaySfxEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
+    settings: Res<GameSettings>,
+) {
+    if !player_input.quick_water || input_blocks.is_blocked() {
+        return;
+    }
+    if player_state.current_map != MapId::Farm {
+        return;
+    }
+    let Some(tier) = player_state.tools.get(&ToolKind::WateringCan).copied() else {
+        return;
+    };
+    let Ok((logical_pos, movement)) = player_query.get_single() else {
+        return;
+    };
+
+    let grid = world_to_grid(logical_pos.0.x, logical_pos.0.y);
+    let (dx, dy) = crate::player::facing_offset(&movement.facing);
+    let tiles = watering_can_area(tier, grid.x + dx, grid.y + dy, movement.facing);
+    let cost = tool_stamina_cost(
+        crate::player::stamina_cost(&ToolKind::WateringCan),
+        tier,
+        settings.creative_tools,
+    );
+
+    let dry = tiles
+        .iter()
+        .any(|pos| farm_state.soil.get(pos).copied() == Some(SoilState::Tilled));
+    if !dry || player_state.stamina < cost {
+        let message = if dry {
+            "Too tired to water. Rest or eat something!"
+        } else {
+            "Nothing here needs water."
+        };
+        toast_events.send(ToastEvent {
+            message: message.into(),
+            duration_secs: 1.5,
+        });
+        return;
+    }
+
+    let watered = quick_water_sweep(&mut farm_state, &tiles);
+    for &pos in &watered {
+        spawn_or_update_soil_entity(&mut commands, &mut farm_entities, pos, SoilState::Watered);
+    }
+    stamina_events.send(StaminaDrainEvent { amount: cost });
+    sfx_events.send(PlaySfxEvent {
+        sfx_id: "water".to_string(),
+    });
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Fertilizer — work it into a tilled tile
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Fertilizer worked into the soil by an item, if `item_id` is one.
+pub fn fertilizer_for_item(item_id: &str) -> Option<Fertilizer> {
+    match item_id {
+        "fertilizer" => Some(Fertilizer::Basic),
+        _ => None,
+    }
+}
+
+/// Pressing interact or tool-use with fertilizer selected works one into the
+/// tilled tile the player faces. It stays there until the crop on it is
+/// harvested, raising that harvest's quality odds.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_fertilizer(
+    player_input: Res<PlayerInput>,
+    input_blocks: Res<InputBlocks>,
+    interaction_claimed: Res<InteractionClaimed>,
+    player_state: Res<PlayerState>,
+    mut inventory: ResMut<Inventory>,
+    mut farm_state: ResMut<FarmState>,
+    player_query: Query<(&LogicalPosition, &PlayerMovement), With<Player>>,
+    mut item_removed_events: EventWriter<ItemRemovedEvent>,
+    mut sfx_events: EventWriter<PlaySfxEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
+) {
+    if input_blocks.is_blocked() || interaction_claimed.0 {
+        return;
+    }
+    if !player_input.interact && !player_input.tool_use {
+        return;
+    }
+    if player_state.current_map != MapId::Farm {
+        return;
+    }
+    let Some((item_id, fertilizer)) = inventory
+        .slots
+        .get(inventory.selected_slot)
+        .and_then(|s| s.as_ref())
+        .and_then(|s| fertilizer_for_item(&s.item_id).map(|f| (s.item_id.clone(), f)))
+    else {
+        return;
+    };
+    let Ok((logical_pos, movement)) = player_query.get_single() else {
+        return;
+    };
+    let pos = player_target_tile(logical_pos.0, movement.facing);
+
+    if !matches!(
+        farm_state.soil.get(&pos),
+        Some(SoilState::Tilled | SoilState::Watered)
+    ) {
+        toast_events.send(ToastEvent {
+            message: "Fertilizer goes on tilled soil.".into(),
+            duration_secs: 1.5,
+        });
+        return;
+    }
+    if farm_state.fertilizer.contains_key(&pos) {
+        toast_events.send(ToastEvent {
+            message: "This soil is already fertilized.".into(),
+            duration_secs: 1.5,
+        });
+        return;
+    }
+    if inventory.try_remove(&item_id, 1) == 0 {
+        return;
+    }
+
+    farm_state.fertilizer.insert(pos, fertilizer);
+    item_removed_events.send(ItemRemovedEvent {
+        item_id,
+        quantity: 1,
+    });
+    sfx_events.send(PlaySfxEvent {
+        sfx_id: "hoe".to_string(),
+    });
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Entity helpers
 // ─────────────────────────────────────────────────────────────────────────────
@@ -255,6 +442,7 @@ mod tests {
         app.init_resource::<FarmState>();
         app.init_resource::<FarmEntities>();
         app.init_resource::<TutorialState>();
+        app.init_resource::<GameSettings>();
         app.insert_resource(farm_player_state());
         app.add_event::<ToolUseEvent>();
         app.add_event::<StaminaDrainEvent>();
@@ -282,12 +470,149 @@ mod tests {
         assert!(toasts.is_empty());
     }
 
+    #[test]
+    fn fertilizer_goes_into_tilled_soil_once() {
+        let mut app = App::new();
+        app.init_resource::<PlayerInput>();
+        app.init_resource::<InputBlocks>();
+        app.init_resource::<InteractionClaimed>();
+        app.insert_resource(farm_player_state());
+        let mut farm = FarmState::default();
+        farm.soil.insert((1, 0), SoilState::Tilled);
+        app.insert_resource(farm);
+        let mut inventory = Inventory::default();
+        inventory.try_add("fertilizer", 2, 99);
+        app.insert_resource(inventory);
+        app.add_event::<ItemRemovedEvent>();
+        app.add_event::<PlaySfxEvent>();
+        app.add_event::<ToastEvent>();
+        app.add_systems(Update, apply_fertilizer);
+        app.world_mut().spawn((
+            Player,
+            LogicalPosition(grid_to_world_center(0, 0)),
+            PlayerMovement {
+                facing: Facing::Right,
+                ..default()
+            },
+        ));
+
+        app.world_mut().resource_mut::<PlayerInput>().interact = true;
+        app.update();
+        assert_eq!(
+            app.world().resource::<FarmState>().fertilizer.get(&(1, 0)),
+            Some(&Fertilizer::Basic)
+        );
+        assert_eq!(app.world().resource::<Inventory>().count("fertilizer"), 1);
+
+        // A second press on the same tile keeps the remaining bag.
+        app.update();
+        assert_eq!(app.world().resource::<Inventory>().count("fertilizer"), 1);
+        let toasts = drain_toasts(&mut app);
+        assert_eq!(
+            toasts.last().map(|t| t.message.as_str()),
+            Some("This soil is already fertilized.")
+        );
+    }
+
+    fn quick_water_app(stamina: f32) -> App {
+        let mut app = App::new();
+        app.init_resource::<PlayerInput>();
+        app.init_resource::<InputBlocks>();
+        app.init_resource::<FarmState>();
+        app.init_resource::<FarmEntities>();
+        app.init_resource::<GameSettings>();
+        let mut player = farm_player_state();
+        player.tools.insert(ToolKind::WateringCan, ToolTier::Iron);
+        player.stamina = stamina;
+        app.insert_resource(player);
+        app.add_event::<StaminaDrainEvent>();
+        app.add_event::<PlaySfxEvent>();
+        app.add_event::<ToastEvent>();
+        app.add_systems(Update, handle_quick_water);
+        app.world_mut().spawn((
+            Player,
+            LogicalPosition(grid_to_world_center(-1, 0)),
+            PlayerMovement {
+                facing: Facing::Right,
+                ..default()
+            },
+        ));
+        app
+    }
+
+    #[test]
+    fn quick_water_sweeps_patch_for_one_swing() {
+        let tiles = watering_can_area(ToolTier::Iron, 0, 0, Facing::Right);
+        let cost = tool_stamina_cost(
+            crate::player::stamina_cost(&ToolKind::WateringCan),
+            ToolTier::Iron,
+            false,
+        );
+        let mut app = quick_water_app(cost);
+        {
+            let mut farm = app.world_mut().resource_mut::<FarmState>();
+            for &pos in &tiles {
+                farm.soil.insert(pos, SoilState::Tilled);
+            }
+            // One tile in the area is already wet.
+            farm.soil.insert((1, 0), SoilState::Watered);
+        }
+
+        app.world_mut().resource_mut::<PlayerInput>().quick_water = true;
+        app.update();
+
+        let farm = app.world().resource::<FarmState>();
+        for pos in &tiles {
+            assert_eq!(farm.soil[pos], SoilState::Watered, "{pos:?} stays dry");
+        }
+        let drains: Vec<f32> = app
+            .world_mut()
+            .resource_mut::<Events<StaminaDrainEvent>>()
+            .drain()
+            .map(|ev| ev.amount)
+            .collect();
+        assert_eq!(drains.len(), 1);
+        assert!((drains[0] - cost).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn quick_water_is_refused_without_stamina_for_the_swing() {
+        let cost = tool_stamina_cost(
+            crate::player::stamina_cost(&ToolKind::WateringCan),
+            ToolTier::Iron,
+            false,
+        );
+        let mut app = quick_water_app(cost - 0.1);
+        app.world_mut()
+            .resource_mut::<FarmState>()
+            .soil
+            .insert((0, 0), SoilState::Tilled);
+
+        app.world_mut().resource_mut::<PlayerInput>().quick_water = true;
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<FarmState>().soil[&(0, 0)],
+            SoilState::Tilled
+        );
+        assert!(app
+            .world()
+            .resource::<Events<StaminaDrainEvent>>()
+            .is_empty());
+        let toasts = drain_toasts(&mut app);
+        assert_eq!(
+            toasts.last().map(|t| t.message.as_str()),
+            Some("Too tired to water. Rest or eat something!")
+        );
+    }
+
     #[test]
     fn already_tilled_tile_keeps_existing_feedback() {
         let mut app = App::new();
         app.init_resource::<FarmState>();
         app.init_resource::<FarmEntities>();
         app.init_resource::<TutorialState>();
+        app.init_resource::<GameSettings>();
         app.insert_resource(farm_player_state());
         app.add_event::<ToolUseEvent>();
         app.add_event::<StaminaDrainEvent>();
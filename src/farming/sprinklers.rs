@@ -8,11 +8,13 @@
 //!   range/diagonal logic (replaces legacy 3×3 for Phase 4)
 //! * `remove_sprinkler`       — listens for pickaxe/interact tool use on a sprinkler
 //!   tile and returns it to inventory
+//! * `line_place_drag`        — while `tool_secondary` is held with a sprinkler or
+//!   path selected, queues a placement on every tile the player walks past
 //!
 //! All shared types are imported via `crate::shared::*`.  No imports from any other
 //! domain are permitted.
 
-use super::{soil::spawn_or_update_soil_entity, FarmEntities};
+use super::{soil::spawn_or_update_soil_entity, FarmEntities, PlaceFarmObjectEvent};
 use crate::shared::*;
 use bevy::prelude::*;
 
@@ -273,6 +275,142 @@ pub fn remove_sprinkler(
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// System 4 — line_place_drag
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// An in-progress drag placement. The first tile is placed by the regular
+/// item-use press; this tracks the item and the last tile queued after that.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct LinePlacement {
+    pub item_id: Option<ItemId>,
+    pub last_tile: Option<(i32, i32)>,
+}
+
+/// Sprinkler kind placed by a sprinkler item, if `item_id` is one.
+pub fn sprinkler_kind_for_item(item_id: &str) -> Option<SprinklerKind> {
+    match item_id {
+        ITEM_BASIC_SPRINKLER => Some(SprinklerKind::Basic),
+        ITEM_QUALITY_SPRINKLER => Some(SprinklerKind::Quality),
+        ITEM_IRIDIUM_SPRINKLER => Some(SprinklerKind::Iridium),
+        _ => None,
+    }
+}
+
+/// Items that can be laid down in a line by holding `tool_secondary`.
+pub fn is_line_placeable(item_id: &str) -> bool {
+    sprinkler_kind_for_item(item_id).is_some() || matches!(item_id, "wood_path" | "stone_path")
+}
+
+/// Tiles stepped through going from `from` (exclusive) to `to` (inclusive),
+/// one tile at a time, so fast movement never leaves gaps in the line.
+pub fn line_tiles(from: (i32, i32), to: (i32, i32)) -> Vec<(i32, i32)> {
+    let mut tiles = Vec::new();
+    let (mut x, mut y) = from;
+    while (x, y) != to {
+        x += (to.0 - x).signum();
+        y += (to.1 - y).signum();
+        tiles.push((x, y));
+    }
+    tiles
+}
+
+/// A tile can take a line placement only when it is open ground that nothing
+/// is built or growing on.
+fn line_place_tile_is_free(
+    farm_state: &FarmState,
+    world_map: &crate::world::WorldMap,
+    pos: (i32, i32),
+) -> bool {
+    let open_ground = world_map.map_def.as_ref().is_some_and(|md| {
+        matches!(
+            md.get_tile(pos.0, pos.1),
+            TileKind::Grass | TileKind::Dirt | TileKind::TilledSoil | TileKind::WateredSoil
+        )
+    });
+    open_ground
+        && !world_map.is_solid(pos.0, pos.1)
+        && !farm_state.objects.contains_key(&pos)
+        && !farm_state.crops.contains_key(&pos)
+}
+
+/// While `tool_secondary` stays held with a sprinkler or path selected, queue a
+/// placement for each new tile the player faces. Occupied tiles are skipped
+/// and the line stops once the stack in the inventory runs out.
+#[allow(clippy::too_many_arguments)]
+pub fn line_place_drag(
+    player_input: Res<PlayerInput>,
+    input_blocks: Res<InputBlocks>,
+    player_state: Res<PlayerState>,
+    inventory: Res<Inventory>,
+    farm_state: Res<FarmState>,
+    world_map: Res<crate::world::WorldMap>,
+    player_query: Query<(&LogicalPosition, &PlayerMovement), With<Player>>,
+    mut drag: ResMut<LinePlacement>,
+    mut sprinkler_events: EventWriter<PlaceSprinklerEvent>,
+    mut object_events: EventWriter<PlaceFarmObjectEvent>,
+) {
+    let selected = inventory
+        .slots
+        .get(inventory.selected_slot)
+        .and_then(|s| s.as_ref())
+        .map(|s| s.item_id.clone())
+        .filter(|id| is_line_placeable(id));
+
+    let active = player_input.tool_secondary_held
+        && !input_blocks.is_blocked()
+        && player_state.current_map == MapId::Farm;
+    let Some(item_id) = selected.filter(|_| active) else {
+        *drag = LinePlacement::default();
+        return;
+    };
+    let Ok((pos, movement)) = player_query.get_single() else {
+        return;
+    };
+    let target = player_target_tile(pos.0, movement.facing);
+
+    // The press itself places the first tile via item use; start tracking.
+    if player_input.tool_secondary {
+        drag.item_id = Some(item_id);
+        drag.last_tile = Some(target);
+        return;
+    }
+    if drag.item_id.as_ref() != Some(&item_id) {
+        return;
+    }
+    let Some(last) = drag.last_tile else {
+        return;
+    };
+    if last == target {
+        return;
+    }
+    drag.last_tile = Some(target);
+
+    let mut remaining = inventory.count(&item_id);
+    for tile in line_tiles(last, target) {
+        if remaining == 0 {
+            break;
+        }
+        if !line_place_tile_is_free(&farm_state, &world_map, tile) {
+            continue;
+        }
+        if let Some(kind) = sprinkler_kind_for_item(&item_id) {
+            sprinkler_events.send(PlaceSprinklerEvent {
+                kind,
+                tile_x: tile.0,
+                tile_y: tile.1,
+            });
+        } else {
+            object_events.send(PlaceFarmObjectEvent {
+                item_id: item_id.clone(),
+                grid_x: tile.0,
+                grid_y: tile.1,
+            });
+        }
+        remaining -= 1;
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Tests
 // ─────────────────────────────────────────────────────────────────────────────
@@ -341,6 +479,131 @@ mod tests {
         }
     }
 
+    #[test]
+    fn line_tiles_steps_without_gaps() {
+        assert_eq!(line_tiles((0, 0), (3, 0)), vec![(1, 0), (2, 0), (3, 0)]);
+        assert_eq!(line_tiles((2, 2), (2, 2)), Vec::<(i32, i32)>::new());
+    }
+
+    fn grass_farm_map() -> crate::world::WorldMap {
+        crate::world::WorldMap {
+            map_def: Some(crate::world::maps::MapDef {
+                id: MapId::Farm,
+                width: 8,
+                height: 8,
+                tiles: vec![TileKind::Grass; 8 * 8],
+                transitions: vec![],
+                objects: vec![],
+                forage_points: vec![],
+            }),
+            width: 8,
+            height: 8,
+            ..default()
+        }
+    }
+
+    /// Press on (1, 0) with three basic sprinklers, then keep holding while
+    /// walking right so the faced tile steps through (2, 0), (3, 0) and (4, 0).
+    fn drag_three_sprinklers_right(world_map: crate::world::WorldMap) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PlayerInput>()
+            .init_resource::<InputBlocks>()
+            .init_resource::<FarmState>()
+            .init_resource::<SprinklerState>()
+            .init_resource::<LinePlacement>()
+            .insert_resource(world_map)
+            .add_event::<PlaceSprinklerEvent>()
+            .add_event::<PlaceFarmObjectEvent>()
+            .add_event::<ToastEvent>()
+            .add_event::<PlaySfxEvent>()
+            .add_systems(Update, (line_place_drag, handle_place_sprinkler).chain());
+
+        app.insert_resource(PlayerState {
+            current_map: MapId::Farm,
+            ..default()
+        });
+        let mut inventory = Inventory::default();
+        inventory.try_add(ITEM_BASIC_SPRINKLER, 3, 99);
+        app.insert_resource(inventory);
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                LogicalPosition(grid_to_world_center(0, 0)),
+                PlayerMovement {
+                    facing: Facing::Right,
+                    ..default()
+                },
+            ))
+            .id();
+
+        // Initial press: item use places the first sprinkler on the faced tile.
+        {
+            let mut input = app.world_mut().resource_mut::<PlayerInput>();
+            input.tool_secondary = true;
+            input.tool_secondary_held = true;
+        }
+        app.world_mut().send_event(PlaceSprinklerEvent {
+            kind: SprinklerKind::Basic,
+            tile_x: 1,
+            tile_y: 0,
+        });
+        app.update();
+        app.world_mut().resource_mut::<PlayerInput>().tool_secondary = false;
+
+        // Keep holding while walking right; each step faces a new tile.
+        for x in 1..=3 {
+            app.world_mut()
+                .get_mut::<LogicalPosition>(player)
+                .unwrap()
+                .0 = grid_to_world_center(x, 0);
+            app.update();
+        }
+        app
+    }
+
+    fn placed_sprinklers(app: &App) -> Vec<(i32, i32)> {
+        app.world()
+            .resource::<SprinklerState>()
+            .sprinklers
+            .iter()
+            .map(|s| (s.tile_x, s.tile_y))
+            .collect()
+    }
+
+    #[test]
+    fn dragging_a_line_of_three_sprinklers_consumes_three_items() {
+        let app = drag_three_sprinklers_right(grass_farm_map());
+
+        assert_eq!(placed_sprinklers(&app), vec![(1, 0), (2, 0), (3, 0)]);
+        assert_eq!(
+            app.world()
+                .resource::<Inventory>()
+                .count(ITEM_BASIC_SPRINKLER),
+            0
+        );
+    }
+
+    #[test]
+    fn dragging_skips_solid_tiles_and_water() {
+        let mut world_map = grass_farm_map();
+        world_map.set_solid(2, 0, true);
+        if let Some(md) = world_map.map_def.as_mut() {
+            md.tiles[3] = TileKind::Water;
+        }
+        let app = drag_three_sprinklers_right(world_map);
+
+        assert_eq!(placed_sprinklers(&app), vec![(1, 0), (4, 0)]);
+        assert_eq!(
+            app.world()
+                .resource::<Inventory>()
+                .count(ITEM_BASIC_SPRINKLER),
+            1
+        );
+    }
+
     #[test]
     fn sprinkler_item_ids_are_correct() {
         assert_eq!(sprinkler_item_id(SprinklerKind::Basic), "sprinkler");
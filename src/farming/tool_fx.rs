@@ -26,8 +26,9 @@ pub fn spawn_water_splash(mut commands: Commands, mut impact_events: EventReader
         if event.tool != ToolKind::WateringCan {
             continue;
         }
-        let wx = event.grid_x as f32 * TILE_SIZE + TILE_SIZE * 0.5;
-        let wy = event.grid_y as f32 * TILE_SIZE + TILE_SIZE * 0.5;
+        let wc = grid_to_world_center(event.grid_x, event.grid_y);
+        let wx = wc.x;
+        let wy = wc.y;
 
         // Main splash poof: light blue semi-transparent, expands from 0.3 to 1.2 scale
         commands.spawn((
@@ -166,8 +167,9 @@ pub fn spawn_scythe_arc(
             continue;
         }
 
-        let wx = event.grid_x as f32 * TILE_SIZE + TILE_SIZE * 0.5;
-        let wy = event.grid_y as f32 * TILE_SIZE + TILE_SIZE * 0.5;
+        let wc = grid_to_world_center(event.grid_x, event.grid_y);
+        let wx = wc.x;
+        let wy = wc.y;
 
         // Slight directional offset toward facing direction
         let (offset_x, offset_y) = match facing {
@@ -264,8 +266,9 @@ pub fn spawn_cut_debris(
             continue;
         }
 
-        let wx = event.grid_x as f32 * TILE_SIZE + TILE_SIZE * 0.5;
-        let wy = event.grid_y as f32 * TILE_SIZE + TILE_SIZE * 0.5;
+        let wc = grid_to_world_center(event.grid_x, event.grid_y);
+        let wx = wc.x;
+        let wy = wc.y;
 
         let debris_count = rng.gen_range(2..=3usize);
         for _ in 0..debris_count {
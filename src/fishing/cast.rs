@@ -17,6 +17,8 @@ const BITE_TIMER_BASE: f32 = 4.0;
 /// Random range added to base bite wait.
 const BITE_TIMER_RANDOM_MAX: f32 = 6.0;
 const REACTION_WINDOW: f32 = 1.5; // seconds to press Space after bite
+/// Bait units taken from the inventory for each cast.
+const BAIT_PER_CAST: u8 = 1;
 
 // ─── Bait helpers ─────────────────────────────────────────────────────────────
 
@@ -41,6 +43,37 @@ pub fn bait_bite_multiplier(bait_id: &str) -> f32 {
     }
 }
 
+/// Returns a multiplier for the bite-wait timer based on the weather.
+///
+/// Fish feed more in the rain and hang back on bright days.
+///
+/// | Weather | Multiplier |
+/// |---------|------------|
+/// | Stormy  | 0.70       |
+/// | Rainy   | 0.80       |
+/// | Snowy   | 1.00       |
+/// | Sunny   | 1.10       |
+pub fn weather_bite_multiplier(weather: Weather) -> f32 {
+    match weather {
+        Weather::Stormy => 0.70,
+        Weather::Rainy => 0.80,
+        Weather::Snowy => 1.00,
+        Weather::Sunny => 1.10,
+    }
+}
+
+/// Roll the seconds until a fish bites.
+///
+/// `base` is the fixed part of the wait with any skill reduction already
+/// subtracted; a random `0..BITE_TIMER_RANDOM_MAX` is added, then the
+/// weather and bait multipliers apply. Never drops below one second, so max
+/// bait and skill can't produce an instant bite.
+pub fn bite_delay(base: f32, weather: Weather, bait: Option<&str>, rng: &mut impl Rng) -> f32 {
+    let random_component: f32 = rng.gen_range(0.0..BITE_TIMER_RANDOM_MAX);
+    let bait_mult = bait.map_or(1.0, bait_bite_multiplier);
+    ((base + random_component) * weather_bite_multiplier(weather) * bait_mult).max(1.0)
+}
+
 /// Check the player's inventory for a known bait item. Returns the item ID
 /// of the first matching bait found, or `None` if no bait is equipped.
 ///
@@ -55,6 +88,47 @@ fn detect_bait(inventory: &Inventory) -> Option<String> {
     None
 }
 
+/// Result of baiting the rod for a new cast.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BaitLoad {
+    /// One unit of this bait was taken from the inventory for the cast.
+    Baited(ItemId),
+    /// The rod had bait but none is left; this cast goes without.
+    RanOut,
+    /// No bait on the rod and none carried.
+    Bare,
+}
+
+/// Bait the rod for a cast, consuming `BAIT_PER_CAST` from the inventory.
+///
+/// Keeps using the bait type already on the rod while any is left, otherwise
+/// auto-rebaits with the best bait carried (see `detect_bait`). When nothing
+/// is left, clears the rod bait and `bait_equipped`.
+pub fn bait_rod_for_cast(fishing_state: &mut FishingState, inventory: &mut Inventory) -> BaitLoad {
+    let bait_id = fishing_state
+        .rod_bait
+        .clone()
+        .filter(|id| inventory.has(id, BAIT_PER_CAST))
+        .or_else(|| detect_bait(inventory));
+
+    let Some(bait_id) = bait_id else {
+        let ran_out = fishing_state.rod_bait.take().is_some();
+        fishing_state.bait_id = None;
+        fishing_state.bait_equipped = false;
+        return if ran_out {
+            BaitLoad::RanOut
+        } else {
+            BaitLoad::Bare
+        };
+    };
+
+    inventory.try_remove(&bait_id, BAIT_PER_CAST);
+    fishing_state.rod_bait = Some(bait_id.clone());
+    fishing_state.bait_id = Some(bait_id.clone());
+    fishing_state.bait_equipped = true;
+    BaitLoad::Baited(bait_id)
+}
+
 // ─── Systems ─────────────────────────────────────────────────────────────────
 
 /// Listen for ToolUseEvent with FishingRod.
@@ -72,6 +146,7 @@ pub fn handle_tool_use_for_fishing(
     mut toast_events: EventWriter<ToastEvent>,
     fishing_atlas: Res<super::FishingAtlas>,
     world_map: Res<crate::world::WorldMap>,
+    calendar: Res<Calendar>,
 ) {
     for event in tool_events.read() {
         if event.tool != ToolKind::FishingRod {
@@ -100,9 +175,24 @@ pub fn handle_tool_use_for_fishing(
             continue;
         }
 
-        // Detect bait type — priority ordering is handled inside detect_bait
-        let bait_id = detect_bait(&inventory);
-        let bait_equipped = bait_id.is_some();
+        // Bait the rod, consuming one unit; re-baits automatically while any is left.
+        let bait_id = match bait_rod_for_cast(&mut fishing_state, &mut inventory) {
+            BaitLoad::Baited(id) => {
+                item_removed_events.send(ItemRemovedEvent {
+                    item_id: id.clone(),
+                    quantity: BAIT_PER_CAST,
+                });
+                Some(id)
+            }
+            BaitLoad::RanOut => {
+                toast_events.send(ToastEvent {
+                    message: "Out of bait! Casting without it.".into(),
+                    duration_secs: 2.0,
+                });
+                None
+            }
+            BaitLoad::Bare => None,
+        };
 
         // Detect specific tackle type. Priority order: spinner > trap_bobber > lead_bobber > generic.
         let tackle_kind = if inventory.has("spinner", 1) {
@@ -127,18 +217,13 @@ pub fn handle_tool_use_for_fishing(
 
         // Compute bite timer:
         //  Spec formula: 4.0 + random(0.0, 6.0) - 0.5 per level
-        //  Then apply bait multiplier.
-        let mut rng = rand::thread_rng();
-        let random_component: f32 = rng.gen_range(0.0..BITE_TIMER_RANDOM_MAX);
-        let level_reduction = skill.bite_wait_reduction();
-        let base_wait = BITE_TIMER_BASE + random_component - level_reduction;
-
-        let bait_mult = match &bait_id {
-            Some(id) => bait_bite_multiplier(id),
-            None => 1.0,
-        };
-        // Clamp to a minimum of 1.0s so max bait+skill never yields an instant bite.
-        let wait = (base_wait * bait_mult).max(1.0);
+        //  Then apply weather and bait multipliers.
+        let wait = bite_delay(
+            BITE_TIMER_BASE - skill.bite_wait_reduction(),
+            calendar.weather,
+            bait_id.as_deref(),
+            &mut rand::thread_rng(),
+        );
 
         // Update fishing state
         fishing_state.phase = FishingPhase::WaitingForBite;
@@ -146,8 +231,6 @@ pub fn handle_tool_use_for_fishing(
         fishing_state.bobber_pos =
             Vec2::new(target_x as f32 * TILE_SIZE, target_y as f32 * TILE_SIZE);
         fishing_state.bite_timer = Some(Timer::from_seconds(wait, TimerMode::Once));
-        fishing_state.bait_id = bait_id.clone();
-        fishing_state.bait_equipped = bait_equipped;
         fishing_state.tackle_equipped = tackle_equipped;
         fishing_state.tackle_kind = tackle_kind;
         fishing_state.rod_tier = rod_tier;
@@ -174,17 +257,6 @@ pub fn handle_tool_use_for_fishing(
         sfx_events.send(PlaySfxEvent {
             sfx_id: "fishing_cast".to_string(),
         });
-
-        // Consume one unit of the equipped bait
-        if let Some(ref used_bait_id) = bait_id {
-            let removed = inventory.try_remove(used_bait_id, 1);
-            if removed > 0 {
-                item_removed_events.send(ItemRemovedEvent {
-                    item_id: used_bait_id.clone(),
-                    quantity: removed,
-                });
-            }
-        }
     }
 }
 
@@ -253,6 +325,7 @@ pub fn handle_bite_reaction_window(
     mut commands: Commands,
     skill: Res<FishingSkill>,
     mut toast_events: EventWriter<ToastEvent>,
+    settings: Res<GameSettings>,
 ) {
     if fishing_state.phase != FishingPhase::BitePending {
         return;
@@ -305,6 +378,11 @@ pub fn handle_bite_reaction_window(
             fishing_state.rod_tier,
             fishing_state.tackle_kind,
             &skill,
+            settings.fishing_assist,
+        );
+        minigame_state.place_treasure(
+            super::treasure::treasure_chance(fishing_state.bait_id.as_deref()),
+            &mut rand::thread_rng(),
         );
 
         // Transition to Fishing game state; OnEnter will spawn the minigame UI
@@ -370,6 +448,35 @@ pub fn wild_bait_double_catch_roll() -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn average_bite_delay(weather: Weather) -> f32 {
+        let mut rng = StdRng::seed_from_u64(2125);
+        let rolls = 2_000;
+        let total: f32 = (0..rolls)
+            .map(|_| bite_delay(BITE_TIMER_BASE, weather, None, &mut rng))
+            .sum();
+        total / rolls as f32
+    }
+
+    #[test]
+    fn test_rain_shortens_average_bite_delay() {
+        let sunny = average_bite_delay(Weather::Sunny);
+        let rainy = average_bite_delay(Weather::Rainy);
+        let stormy = average_bite_delay(Weather::Stormy);
+        assert!(rainy < sunny, "rainy {rainy} vs sunny {sunny}");
+        assert!(stormy < rainy, "stormy {stormy} vs rainy {rainy}");
+    }
+
+    #[test]
+    fn test_bite_delay_never_below_one_second() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..500 {
+            let wait = bite_delay(0.0, Weather::Stormy, Some("wild_bait"), &mut rng);
+            assert!(wait >= 1.0);
+        }
+    }
 
     #[test]
     fn test_bait_bite_multiplier_worm() {
@@ -394,6 +501,60 @@ mod tests {
         assert!((bait_bite_multiplier("some_other_bait") - 1.00).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn test_bait_consumed_per_cast_until_exhausted() {
+        let mut state = FishingState::default();
+        let mut inventory = Inventory::default();
+        inventory.try_add("worm_bait", 3, 99);
+
+        for left in (0..3).rev() {
+            assert_eq!(
+                bait_rod_for_cast(&mut state, &mut inventory),
+                BaitLoad::Baited("worm_bait".into())
+            );
+            assert_eq!(inventory.count("worm_bait"), left);
+            assert!(state.bait_equipped);
+            // Each cast ends with a reset; the rod keeps its bait type.
+            state.reset();
+        }
+
+        assert_eq!(
+            bait_rod_for_cast(&mut state, &mut inventory),
+            BaitLoad::RanOut
+        );
+        assert!(!state.bait_equipped);
+        assert_eq!(state.bait_id, None);
+        // Only the cast that runs out reports it.
+        assert_eq!(
+            bait_rod_for_cast(&mut state, &mut inventory),
+            BaitLoad::Bare
+        );
+    }
+
+    #[test]
+    fn test_rod_keeps_bait_type_then_rebaits_from_inventory() {
+        let mut state = FishingState::default();
+        let mut inventory = Inventory::default();
+        inventory.try_add("worm_bait", 1, 99);
+        state.rod_bait = Some("worm_bait".into());
+        inventory.try_add("wild_bait", 1, 99);
+
+        // The loaded worm bait is used before the higher-priority wild bait.
+        assert_eq!(
+            bait_rod_for_cast(&mut state, &mut inventory),
+            BaitLoad::Baited("worm_bait".into())
+        );
+        assert_eq!(
+            bait_rod_for_cast(&mut state, &mut inventory),
+            BaitLoad::Baited("wild_bait".into())
+        );
+        assert_eq!(state.rod_bait.as_deref(), Some("wild_bait"));
+        assert_eq!(
+            bait_rod_for_cast(&mut state, &mut inventory),
+            BaitLoad::RanOut
+        );
+    }
+
     #[test]
     fn test_wild_bait_double_catch_roll_returns_bool() {
         // Just verify it returns a bool and doesn't panic
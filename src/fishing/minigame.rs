@@ -15,6 +15,11 @@
 //! # Perfect Catch
 //! If the catch bar was inside the fish zone for 90%+ of the minigame duration,
 //! the player gets a "Perfect catch!" toast and a quality upgrade notification.
+//!
+//! # Treasure
+//! Some games place a treasure chest in the bar. Keeping the catch bar over it
+//! fills a treasure meter beside the bar; if the meter completes and the fish
+//! is landed, the chest's contents are granted along with the fish.
 
 use bevy::prelude::*;
 use rand::Rng;
@@ -43,6 +48,8 @@ const FISH_MIN_SPEED: f32 = 20.0;
 const MINIGAME_DURATION: f32 = 12.0;
 /// Overlap ratio required to catch the fish (spec: 68%).
 const CATCH_OVERLAP_THRESHOLD: f32 = 0.68;
+/// Seconds of holding the reel that land a fish with the Auto assist.
+const AUTO_CATCH_HOLD_SECS: f32 = 1.5;
 
 // ─── Systems ─────────────────────────────────────────────────────────────────
 
@@ -119,6 +126,9 @@ pub fn update_catch_bar(
     let dt = time.delta_secs();
     let space_held = player_input.fishing_reel;
     minigame_state.space_held = space_held;
+    if space_held {
+        minigame_state.reel_hold_time += dt;
+    }
 
     let catch_half = minigame_state.catch_bar_half;
 
@@ -191,6 +201,19 @@ pub fn update_progress(
     }
 }
 
+/// Fill or drain the treasure meter and chime when it completes.
+pub fn update_treasure(
+    mut minigame_state: ResMut<FishingMinigameState>,
+    time: Res<Time>,
+    mut sfx_events: EventWriter<PlaySfxEvent>,
+) {
+    if minigame_state.advance_treasure(time.delta_secs()) {
+        sfx_events.send(PlaySfxEvent {
+            sfx_id: "treasure_found".to_string(),
+        });
+    }
+}
+
 /// Check whether the minigame timer has expired, determine catch/fail, or cancel.
 #[allow(clippy::too_many_arguments)]
 pub fn check_minigame_result(
@@ -210,6 +233,9 @@ pub fn check_minigame_result(
     mut commands: Commands,
 ) {
     let timer_expired = minigame_state.minigame_total_time >= MINIGAME_DURATION;
+    // Auto assist: a short hold on the reel lands the fish outright.
+    let auto_caught =
+        minigame_state.auto_catch && minigame_state.reel_hold_time >= AUTO_CATCH_HOLD_SECS;
 
     // Check overlap ratio
     let overlap_ratio = if minigame_state.minigame_total_time > 0.1 {
@@ -218,10 +244,11 @@ pub fn check_minigame_result(
         0.0
     };
 
-    if timer_expired {
-        if overlap_ratio >= CATCH_OVERLAP_THRESHOLD {
-            // Win: overlap >= 68% — caught the fish!
-            let is_perfect = minigame_state.is_perfect_catch();
+    if timer_expired || auto_caught {
+        if auto_caught || overlap_ratio >= CATCH_OVERLAP_THRESHOLD {
+            // Win: overlap >= 68% (or assisted hold) — caught the fish!
+            let is_perfect = !auto_caught && minigame_state.is_perfect_catch();
+            let treasure_collected = minigame_state.treasure_collected;
             let bait_id = fishing_state.bait_id.clone();
             let selected_fish = fishing_state.selected_fish_id.clone();
 
@@ -239,7 +266,7 @@ pub fn check_minigame_result(
                 &calendar,
                 &mut toast_events,
                 &mut gold_events,
-                bait_id.as_deref(),
+                treasure_collected,
             );
 
             // Perfect catch notification (after the normal catch is processed)
@@ -260,6 +287,7 @@ pub fn check_minigame_result(
                     item_pickup_events.send(ItemPickupEvent {
                         item_id: fid.clone(),
                         quantity: 1,
+                        quality: ItemQuality::Normal,
                     });
                     toast_events.send(ToastEvent {
                         message: "Wild Bait bonus: extra fish!".to_string(),
@@ -320,3 +348,74 @@ pub(super) const MINIGAME_BAR_WIDTH: f32 = 40.0;
 pub(super) const PROGRESS_BAR_Y: f32 = -130.0;
 pub(super) const PROGRESS_BAR_WIDTH: f32 = 120.0;
 pub(super) const PROGRESS_BAR_HEIGHT: f32 = 12.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fishing::treasure::treasure_reward;
+    use bevy::state::app::StatesPlugin;
+
+    fn result_app(minigame_state: FishingMinigameState) -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.init_state::<GameState>();
+        app.insert_resource(minigame_state)
+            .init_resource::<FishingState>()
+            .init_resource::<PlayerInput>()
+            .init_resource::<FishRegistry>()
+            .init_resource::<Calendar>()
+            .init_resource::<FishEncyclopedia>();
+        app.add_event::<StaminaDrainEvent>()
+            .add_event::<ItemPickupEvent>()
+            .add_event::<PlaySfxEvent>()
+            .add_event::<ToastEvent>()
+            .add_event::<GoldChangeEvent>();
+        app.add_systems(Update, check_minigame_result);
+        app
+    }
+
+    fn landed_game(treasure_collected: bool) -> FishingMinigameState {
+        FishingMinigameState {
+            minigame_total_time: MINIGAME_DURATION,
+            overlap_time_total: MINIGAME_DURATION * 0.8,
+            treasure_center: Some(50.0),
+            treasure_meter: if treasure_collected { 1.0 } else { 0.4 },
+            treasure_collected,
+            ..default()
+        }
+    }
+
+    fn picked_up_items(app: &App) -> Vec<ItemId> {
+        let events = app.world().resource::<Events<ItemPickupEvent>>();
+        events
+            .get_cursor()
+            .read(events)
+            .map(|e| e.item_id.clone())
+            .collect()
+    }
+
+    #[test]
+    fn full_treasure_meter_grants_bonus_item_with_the_fish() {
+        let mut app = result_app(landed_game(true));
+        app.update();
+
+        let items = picked_up_items(&app);
+        assert_eq!(items.len(), 2, "expected fish plus treasure, got {items:?}");
+        assert_eq!(items[0], "carp");
+
+        let mut rng = rand::thread_rng();
+        let table: Vec<ItemId> = (0..200).map(|_| treasure_reward(&mut rng).0).collect();
+        assert!(table.contains(&items[1]), "{} is not treasure", items[1]);
+
+        let gold = app.world().resource::<Events<GoldChangeEvent>>();
+        assert_eq!(gold.get_cursor().read(gold).count(), 1);
+    }
+
+    #[test]
+    fn unfinished_treasure_meter_grants_only_the_fish() {
+        let mut app = result_app(landed_game(false));
+        app.update();
+
+        assert_eq!(picked_up_items(&app), vec!["carp".to_string()]);
+    }
+}
@@ -85,6 +85,7 @@ impl Plugin for FishingPlugin {
                     minigame::update_fish_zone,
                     minigame::update_catch_bar,
                     minigame::update_progress,
+                    minigame::update_treasure,
                     minigame::check_minigame_result,
                 )
                     .chain()
@@ -104,7 +105,11 @@ impl Plugin for FishingPlugin {
             // Minigame visual updates (color feedback on progress bar)
             .add_systems(
                 Update,
-                render::update_progress_fill_color.run_if(in_state(GameState::Fishing)),
+                (
+                    render::update_progress_fill_color,
+                    render::update_treasure_display,
+                )
+                    .run_if(in_state(GameState::Fishing)),
             )
             // Bobber animation runs in both Playing (while waiting for bite) and Fishing
             .add_systems(
@@ -170,6 +175,11 @@ impl FishEncyclopedia {
             true
         }
     }
+
+    /// Number of distinct species caught at least once.
+    pub fn unique_species(&self) -> usize {
+        self.entries.len()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -231,6 +241,9 @@ pub struct FishingState {
     pub bait_id: Option<String>,
     /// Whether bait is currently equipped (derived from bait_id for convenience).
     pub bait_equipped: bool,
+    /// Bait type loaded on the rod. Unlike `bait_id` this survives `reset()`,
+    /// so the next cast re-baits with the same type while any is left.
+    pub rod_bait: Option<ItemId>,
     /// Whether tackle is currently equipped (any kind).
     pub tackle_equipped: bool,
     /// Specific tackle type that is equipped (None if no tackle).
@@ -250,6 +263,7 @@ impl Default for FishingState {
             selected_fish_id: None,
             bait_id: None,
             bait_equipped: false,
+            rod_bait: None,
             tackle_equipped: false,
             tackle_kind: TackleKind::None,
             rod_tier: ToolTier::Basic,
@@ -306,6 +320,16 @@ pub struct FishingMinigameState {
     pub overlap_time_total: f32,
     /// Total time (seconds) the minigame has been running (excluding the ramp-up grace period).
     pub minigame_total_time: f32,
+    /// Auto assist: the catch lands once the reel has been held long enough.
+    pub auto_catch: bool,
+    /// Seconds the reel button has been held this game (used by auto assist).
+    pub reel_hold_time: f32,
+    /// Position (0-100) of the treasure chest in the bar, if one appeared.
+    pub treasure_center: Option<f32>,
+    /// Treasure meter: 0.0 → 1.0. Fills while the catch bar covers the chest.
+    pub treasure_meter: f32,
+    /// Set once the treasure meter has filled; the chest is granted on catch.
+    pub treasure_collected: bool,
 }
 
 impl Default for FishingMinigameState {
@@ -326,6 +350,11 @@ impl Default for FishingMinigameState {
             catch_fall_multiplier: 1.0,
             overlap_time_total: 0.0,
             minigame_total_time: 0.0,
+            auto_catch: false,
+            reel_hold_time: 0.0,
+            treasure_center: None,
+            treasure_meter: 0.0,
+            treasure_collected: false,
         }
     }
 }
@@ -334,13 +363,15 @@ impl FishingMinigameState {
     /// Set up the minigame incorporating the player's fishing skill bonuses.
     ///
     /// `FishingSkill::catch_zone_bonus` expands the catch bar so experienced
-    /// anglers have an easier time.
+    /// anglers have an easier time. The player's `FishingAssist` setting is
+    /// blended in on top of the rod and tackle modifiers.
     pub fn setup_with_skill(
         &mut self,
         difficulty: f32,
         rod_tier: ToolTier,
         tackle_kind: TackleKind,
         fishing_skill: &skill::FishingSkill,
+        assist: FishingAssist,
     ) {
         let mut rng = rand::thread_rng();
         self.fish_zone_center = rng.gen_range(20.0..80.0);
@@ -353,6 +384,11 @@ impl FishingMinigameState {
         self.space_held = false;
         self.overlap_time_total = 0.0;
         self.minigame_total_time = 0.0;
+        self.auto_catch = assist == FishingAssist::Auto;
+        self.reel_hold_time = 0.0;
+        self.treasure_center = None;
+        self.treasure_meter = 0.0;
+        self.treasure_collected = false;
 
         // Fish zone size: easier fish have bigger zones (more forgiving).
         // Difficulty 0.0 → fish_zone_half = 22.0
@@ -387,7 +423,8 @@ impl FishingMinigameState {
             TackleKind::TrapBobber => 1.0, // TrapBobber helps via drain rate instead
             TackleKind::LeadBobber => 1.25, // LeadBobber gets +25% catch bar
         };
-        self.catch_bar_half = base_half * catch_bar_tackle_bonus * tier_bonus;
+        self.catch_bar_half =
+            base_half * catch_bar_tackle_bonus * tier_bonus * assist.catch_bar_multiplier();
 
         // Trap Bobber: slow progress drain rate (stored on minigame state for
         // update_progress to read at runtime).
@@ -396,11 +433,11 @@ impl FishingMinigameState {
         self.progress_drain_multiplier = match tackle_kind {
             TackleKind::TrapBobber => 0.5,
             _ => 1.0,
-        };
+        } * assist.drain_multiplier();
         self.catch_fall_multiplier = match tackle_kind {
             TackleKind::LeadBobber => 0.7,
             _ => 1.0,
-        };
+        } * assist.drain_multiplier();
 
         // Reset timer with randomized first direction change
         let first_change = rng.gen_range(0.8..2.5);
@@ -416,6 +453,39 @@ impl FishingMinigameState {
         catch_hi > fish_lo && catch_lo < fish_hi
     }
 
+    /// Maybe place a treasure chest in the bar for this game, with the given
+    /// probability. Chests sit away from the very edges so they stay reachable.
+    pub fn place_treasure(&mut self, chance: f64, rng: &mut impl Rng) {
+        self.treasure_center = rng
+            .gen_bool(chance.clamp(0.0, 1.0))
+            .then(|| rng.gen_range(TREASURE_MIN_POS..TREASURE_MAX_POS));
+    }
+
+    /// Returns true if the catch bar currently covers the treasure chest.
+    pub fn is_over_treasure(&self) -> bool {
+        self.treasure_center
+            .is_some_and(|center| (center - self.catch_bar_center).abs() <= self.catch_bar_half)
+    }
+
+    /// Fill the treasure meter while the catch bar covers the chest and let it
+    /// drain otherwise. Returns `true` on the frame the meter completes.
+    pub fn advance_treasure(&mut self, dt: f32) -> bool {
+        if self.treasure_collected || self.treasure_center.is_none() {
+            return false;
+        }
+        if self.is_over_treasure() {
+            self.treasure_meter += dt / TREASURE_FILL_SECS;
+        } else {
+            self.treasure_meter -= dt / TREASURE_DRAIN_SECS;
+        }
+        self.treasure_meter = self.treasure_meter.clamp(0.0, 1.0);
+        if self.treasure_meter >= 1.0 {
+            self.treasure_collected = true;
+            return true;
+        }
+        false
+    }
+
     /// Returns `true` if the player achieved a "perfect catch" — they kept the
     /// catch bar in the fish zone for at least 90% of the total minigame duration.
     ///
@@ -439,6 +509,14 @@ impl FishingMinigameState {
 /// Overlap ratio required for a "perfect catch" bonus (quality upgrade).
 const PERFECT_CATCH_THRESHOLD: f32 = 0.90;
 
+/// Seconds the catch bar must cover the chest to fill the treasure meter.
+const TREASURE_FILL_SECS: f32 = 2.0;
+/// Seconds a full treasure meter takes to drain when the bar leaves the chest.
+const TREASURE_DRAIN_SECS: f32 = 4.0;
+/// Range (0-100) a treasure chest may appear in.
+const TREASURE_MIN_POS: f32 = 10.0;
+const TREASURE_MAX_POS: f32 = 90.0;
+
 // ─── Marker Components ───────────────────────────────────────────────────────
 
 /// Marks the bobber entity.
@@ -514,6 +592,14 @@ pub struct MinigameProgressFill;
 #[derive(Component)]
 pub struct MinigameProgressBg;
 
+/// The treasure chest sitting in the minigame bar.
+#[derive(Component)]
+pub struct MinigameTreasureChest;
+
+/// The treasure meter fill beside the minigame bar.
+#[derive(Component)]
+pub struct MinigameTreasureFill;
+
 fn update_fishing_proximity_hint(
     mut commands: Commands,
     time: Res<Time>,
@@ -613,3 +699,63 @@ fn nearest_fishing_hint_tile(
         (map_def.get_tile(tile_x, tile_y) == TileKind::Water).then_some((tile_x, tile_y))
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup(assist: FishingAssist) -> FishingMinigameState {
+        let mut state = FishingMinigameState::default();
+        state.setup_with_skill(
+            0.6,
+            ToolTier::Basic,
+            TackleKind::None,
+            &skill::FishingSkill::default(),
+            assist,
+        );
+        state
+    }
+
+    #[test]
+    fn easy_assist_enlarges_catch_bar_and_slows_drain() {
+        let off = setup(FishingAssist::Off);
+        let easy = setup(FishingAssist::Easy);
+        assert!(
+            easy.catch_bar_half > off.catch_bar_half,
+            "Easy ({}) should have a larger catch bar than Off ({})",
+            easy.catch_bar_half,
+            off.catch_bar_half
+        );
+        assert!(easy.catch_fall_multiplier < off.catch_fall_multiplier);
+        assert!(easy.progress_drain_multiplier < off.progress_drain_multiplier);
+        assert!(!easy.auto_catch);
+    }
+
+    #[test]
+    fn treasure_meter_fills_over_the_chest_and_drains_away_from_it() {
+        let mut state = setup(FishingAssist::Off);
+        state.treasure_center = Some(state.catch_bar_center);
+
+        assert!(!state.advance_treasure(TREASURE_FILL_SECS / 2.0));
+        assert!((state.treasure_meter - 0.5).abs() < 1e-4);
+
+        state.catch_bar_center = state.catch_bar_half;
+        state.treasure_center = Some(95.0);
+        state.advance_treasure(TREASURE_DRAIN_SECS / 4.0);
+        assert!((state.treasure_meter - 0.25).abs() < 1e-4);
+
+        state.treasure_center = Some(state.catch_bar_center);
+        assert!(state.advance_treasure(TREASURE_FILL_SECS));
+        assert!(state.treasure_collected);
+        assert!(!state.advance_treasure(1.0), "completes only once");
+    }
+
+    #[test]
+    fn auto_assist_enables_auto_catch_only() {
+        let off = setup(FishingAssist::Off);
+        let auto = setup(FishingAssist::Auto);
+        assert!(auto.auto_catch);
+        assert!(!off.auto_catch);
+        assert_eq!(auto.catch_bar_half, off.catch_bar_half);
+    }
+}
@@ -10,7 +10,8 @@ use super::minigame::{
 use super::{
     Bobber, BobberRippleTimer, BobberSplashSpawned, FishingMinigameState, FishingPhase,
     FishingState, MinigameBgBar, MinigameCatchBar, MinigameFishZone, MinigameProgressBg,
-    MinigameProgressFill, MinigameRoot, WaterDroplet, WaterRipple,
+    MinigameProgressFill, MinigameRoot, MinigameTreasureChest, MinigameTreasureFill, WaterDroplet,
+    WaterRipple,
 };
 use crate::shared::*;
 
@@ -79,6 +80,14 @@ fn color_progress_fill_near() -> Color {
     Color::srgb(0.73, 0.70, 0.42)
 }
 
+fn color_treasure_chest() -> Color {
+    Color::srgb(0.85, 0.65, 0.25)
+}
+
+fn color_treasure_fill() -> Color {
+    Color::srgb(0.95, 0.82, 0.35)
+}
+
 // ─── Z-layers ─────────────────────────────────────────────────────────────────
 
 const Z_UI_BG: f32 = 50.0;
@@ -195,6 +204,49 @@ pub fn spawn_minigame_ui(mut commands: Commands, minigame_state: Res<FishingMini
                 .with_scale(Vec3::new(0.001, 1.0, 1.0)),
                 MinigameProgressFill,
             ));
+
+            // Treasure chest and its meter, only when a chest appeared this game
+            if let Some(treasure_center) = minigame_state.treasure_center {
+                let chest_size = bar_w_world * 0.5;
+                let meter_x = bar_w_world / 2.0 + 6.0 * screen_to_world;
+                let meter_w = 4.0 * screen_to_world;
+
+                parent.spawn((
+                    Sprite {
+                        color: color_treasure_chest(),
+                        custom_size: Some(Vec2::splat(chest_size)),
+                        ..default()
+                    },
+                    Transform::from_translation(Vec3::new(
+                        0.0,
+                        zone_to_screen_y(treasure_center) * y_scale,
+                        1.2,
+                    )),
+                    MinigameTreasureChest,
+                ));
+
+                parent.spawn((
+                    Sprite {
+                        color: color_progress_bg(),
+                        custom_size: Some(Vec2::new(meter_w, bar_h_world)),
+                        ..default()
+                    },
+                    Transform::from_translation(Vec3::new(meter_x, 0.0, 1.0)),
+                ));
+
+                // Anchored to the bottom edge; y-scale = 0.001 to 1.0 representing the meter
+                parent.spawn((
+                    Sprite {
+                        color: color_treasure_fill(),
+                        custom_size: Some(Vec2::new(meter_w, bar_h_world)),
+                        anchor: bevy::sprite::Anchor::BottomCenter,
+                        ..default()
+                    },
+                    Transform::from_translation(Vec3::new(meter_x, -bar_h_world / 2.0, 2.0))
+                        .with_scale(Vec3::new(1.0, 0.001, 1.0)),
+                    MinigameTreasureFill,
+                ));
+            }
         });
 }
 
@@ -254,6 +306,27 @@ pub fn update_progress_fill_color(
     }
 }
 
+// ─── Treasure meter ───────────────────────────────────────────────────────────
+
+/// Grow the treasure meter with its fill level and dim the chest once it has
+/// been collected.
+pub fn update_treasure_display(
+    minigame_state: Res<FishingMinigameState>,
+    mut fill_query: Query<&mut Transform, With<MinigameTreasureFill>>,
+    mut chest_query: Query<&mut Sprite, With<MinigameTreasureChest>>,
+) {
+    for mut transform in fill_query.iter_mut() {
+        transform.scale.y = minigame_state.treasure_meter.max(0.001);
+    }
+    for mut sprite in chest_query.iter_mut() {
+        sprite.color = if minigame_state.treasure_collected {
+            color_treasure_chest().with_alpha(0.35)
+        } else {
+            color_treasure_chest()
+        };
+    }
+}
+
 // ─── Fish Display Swimming Animation ─────────────────────────────────────────
 
 /// Animate fish display sprites with a gentle swimming motion.
@@ -3,22 +3,13 @@
 //! These are helper functions called from within systems, not systems themselves.
 //!
 //! # Treasure integration
-//! The new `check_and_grant_treasure` function from `treasure.rs` is used here
-//! instead of the old inline loot logic. Bait type affects the treasure chance:
-//!
-//! | Bait / Condition | Treasure chance |
-//! |------------------|-----------------|
-//! | No bait          | 10%             |
-//! | Generic bait     | 10%             |
-//! | wild_bait        | 15%             |
-//! | magnet_bait      | 25%             |
+//! A treasure chest is granted alongside the fish when the player filled the
+//! minigame's treasure meter (see `treasure.rs` for the loot table and the
+//! bait-dependent chance of a chest appearing).
 
 use bevy::prelude::*;
 
-use super::treasure::{
-    check_and_grant_treasure, BASE_TREASURE_CHANCE, MAGNET_BAIT_EXTRA_CHANCE,
-    WILD_BAIT_EXTRA_CHANCE,
-};
+use super::treasure::grant_treasure;
 use super::{FishEncyclopedia, FishingPhase, FishingState};
 use crate::shared::*;
 
@@ -26,8 +17,8 @@ use crate::shared::*;
 
 /// Called when the player successfully catches a fish.
 ///
-/// `bait_id` is the specific bait item ID used for this cast (e.g. "wild_bait"),
-/// or `None` if no bait was equipped. This determines the treasure bonus.
+/// `treasure_collected` is true when the player filled the treasure meter
+/// during the minigame; the chest is then opened alongside the fish.
 #[allow(clippy::too_many_arguments)]
 pub fn catch_fish(
     fishing_state: &mut FishingState,
@@ -42,7 +33,7 @@ pub fn catch_fish(
     calendar: &Calendar,
     toast_events: &mut EventWriter<ToastEvent>,
     gold_events: &mut EventWriter<GoldChangeEvent>,
-    bait_id: Option<&str>,
+    treasure_collected: bool,
 ) {
     // Determine what was caught
     let fish_id = fishing_state
@@ -66,6 +57,7 @@ pub fn catch_fish(
     item_pickup_events.send(ItemPickupEvent {
         item_id: valid_id.clone(),
         quantity: 1,
+        quality: ItemQuality::Normal,
     });
 
     // Toast for the catch
@@ -125,23 +117,9 @@ pub fn catch_fish(
     }
 
     // ── Treasure Chest ────────────────────────────────────────────────────
-    // Bait affects treasure probability:
-    //   - magnet_bait adds +15% (MAGNET_BAIT_EXTRA_CHANCE)  → 25% total
-    //   - wild_bait adds +5% (WILD_BAIT_EXTRA_CHANCE)       → 15% total
-    //   - any other bait / no bait: base rate only           → 10% total
-    let treasure_chance = match bait_id {
-        Some("magnet_bait") => BASE_TREASURE_CHANCE + MAGNET_BAIT_EXTRA_CHANCE,
-        Some("wild_bait") => BASE_TREASURE_CHANCE + WILD_BAIT_EXTRA_CHANCE,
-        _ => BASE_TREASURE_CHANCE,
-    };
-
-    check_and_grant_treasure(
-        treasure_chance,
-        item_pickup_events,
-        gold_events,
-        toast_events,
-        sfx_events,
-    );
+    if treasure_collected {
+        grant_treasure(item_pickup_events, gold_events, toast_events);
+    }
 
     // Sound effect for the catch itself
     sfx_events.send(PlaySfxEvent {
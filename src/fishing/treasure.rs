@@ -1,8 +1,10 @@
 //! Treasure chest loot logic for the fishing system.
 //!
-//! A percentage of successful catches also yield a treasure chest containing
-//! a random selection of items and some gold. The base chance is 10% (spec), and
-//! certain bait types (magnet_bait, wild_bait) raise that probability further.
+//! Some minigames show a treasure chest in the bar. Holding the catch bar over
+//! it fills the treasure meter; landing the fish with a full meter also yields
+//! the chest, containing a random item and some gold. The chance of a chest
+//! appearing is 10% (spec), and certain bait types (magnet_bait, wild_bait)
+//! raise that probability further.
 //!
 //! # Loot table
 //! | Tier     | Weight | Example items                          |
@@ -36,20 +38,33 @@ pub struct TreasureContents {
     pub gold: u32,
 }
 
+/// Chance that a treasure chest appears in the minigame for this cast.
+///
+/// | Bait / Condition | Treasure chance |
+/// |------------------|-----------------|
+/// | No bait          | 10%             |
+/// | Generic bait     | 10%             |
+/// | wild_bait        | 15%             |
+/// | magnet_bait      | 25%             |
+pub fn treasure_chance(bait_id: Option<&str>) -> f64 {
+    match bait_id {
+        Some("magnet_bait") => BASE_TREASURE_CHANCE + MAGNET_BAIT_EXTRA_CHANCE,
+        Some("wild_bait") => BASE_TREASURE_CHANCE + WILD_BAIT_EXTRA_CHANCE,
+        _ => BASE_TREASURE_CHANCE,
+    }
+}
+
 // ─── Loot rolling ─────────────────────────────────────────────────────────────
 
-/// Roll for treasure chest contents using weighted loot tables.
+/// Roll the bonus item from the weighted treasure table.
 ///
 /// ```
 /// // 60% → ore tier  (copper_ore ×3 or iron_ore ×2)
 /// // 20% → gem tier  (amethyst ×1 or topaz ×1)
 /// // 15% → artifact  (ancient_sword ×1 or dinosaur_egg ×1)
 /// //  5% → rare      (iridium_ore ×1 or prismatic_shard ×1)
-/// // Always: 50–200 gold
 /// ```
-pub fn roll_treasure() -> TreasureContents {
-    let mut rng = rand::thread_rng();
-
+pub fn treasure_reward(rng: &mut impl Rng) -> (ItemId, u8) {
     let tier: f64 = rng.gen();
     let (item_id, qty): (&str, u8) = if tier < 0.60 {
         // Ore tier
@@ -81,32 +96,30 @@ pub fn roll_treasure() -> TreasureContents {
         }
     };
 
+    (item_id.to_string(), qty)
+}
+
+/// Roll full treasure chest contents: one item from the treasure table plus
+/// 50–200 gold.
+pub fn roll_treasure() -> TreasureContents {
+    let mut rng = rand::thread_rng();
+    let item = treasure_reward(&mut rng);
     let gold = rng.gen_range(50u32..=200u32);
 
     TreasureContents {
-        items: vec![(item_id.to_string(), qty)],
+        items: vec![item],
         gold,
     }
 }
 
-/// Determine whether a treasure chest should spawn this catch and, if so,
-/// generate its contents and send the appropriate events.
-///
-/// `treasure_chance` is the probability in [0.0, 1.0]. Pass
-/// `BASE_TREASURE_CHANCE` for a bare-rod cast; bait types may add their own
-/// bonus before calling this function.
-pub fn check_and_grant_treasure(
-    treasure_chance: f64,
+/// Open a treasure chest the player earned in the minigame: roll its
+/// contents and send the pickup, gold and toast events. The pickup plays its
+/// own sound.
+pub fn grant_treasure(
     item_pickup_events: &mut bevy::prelude::EventWriter<ItemPickupEvent>,
     gold_change_events: &mut bevy::prelude::EventWriter<GoldChangeEvent>,
     toast_events: &mut bevy::prelude::EventWriter<ToastEvent>,
-    sfx_events: &mut bevy::prelude::EventWriter<PlaySfxEvent>,
 ) {
-    let mut rng = rand::thread_rng();
-    if !rng.gen_bool(treasure_chance) {
-        return;
-    }
-
     let contents = roll_treasure();
 
     // Grant items
@@ -114,6 +127,7 @@ pub fn check_and_grant_treasure(
         item_pickup_events.send(ItemPickupEvent {
             item_id: item_id.clone(),
             quantity: *qty,
+            quality: ItemQuality::Normal,
         });
     }
 
@@ -129,10 +143,6 @@ pub fn check_and_grant_treasure(
         message: format!("You found a treasure chest! (+{} gold)", contents.gold),
         duration_secs: 3.5,
     });
-
-    sfx_events.send(PlaySfxEvent {
-        sfx_id: "treasure_found".to_string(),
-    });
 }
 
 #[cfg(test)]
@@ -193,7 +203,7 @@ mod tests {
 
     #[test]
     fn test_treasure_chance_with_magnet_bait() {
-        let effective_chance = BASE_TREASURE_CHANCE + MAGNET_BAIT_EXTRA_CHANCE;
+        let effective_chance = treasure_chance(Some("magnet_bait"));
         assert!(
             (effective_chance - 0.25).abs() < f64::EPSILON,
             "Magnet bait should give 25% treasure chance"
@@ -202,7 +212,7 @@ mod tests {
 
     #[test]
     fn test_treasure_chance_with_wild_bait() {
-        let effective_chance = BASE_TREASURE_CHANCE + WILD_BAIT_EXTRA_CHANCE;
+        let effective_chance = treasure_chance(Some("wild_bait"));
         assert!(
             (effective_chance - 0.15).abs() < f64::EPSILON,
             "Wild bait should give 15% treasure chance"
@@ -434,6 +434,13 @@ fn reset_and_read_input(
                 keys.just_pressed(bindings.tool_use) || mouse.just_pressed(MouseButton::Left);
             input.tool_secondary = keys.just_pressed(bindings.tool_secondary)
                 || mouse.just_pressed(MouseButton::Right);
+            input.tool_secondary_held =
+                keys.pressed(bindings.tool_secondary) || mouse.pressed(MouseButton::Right);
+            input.tool_use_held =
+                keys.pressed(bindings.tool_use) || mouse.pressed(MouseButton::Left);
+            input.quick_water = keys.just_pressed(bindings.quick_water);
+            input.run_held = keys.pressed(bindings.run);
+            input.wrap_gift_held = keys.pressed(bindings.wrap_gift);
             input.attack = input.tool_use;
 
             input.open_inventory = keys.just_pressed(bindings.open_inventory);
@@ -468,6 +475,7 @@ fn reset_and_read_input(
             // Quicksave / quickload
             input.quicksave = keys.just_pressed(KeyCode::F5);
             input.quickload = keys.just_pressed(KeyCode::F9);
+            input.zoom_cycle = keys.just_pressed(KeyCode::KeyZ);
 
             // UI navigation for in-game overlays (chest panel, elevator, etc.)
             input.ui_up =
@@ -488,10 +496,13 @@ fn reset_and_read_input(
                 input.interact = input.interact || gp.just_pressed(GamepadButton::South);
                 // X (West) → tool_use
                 input.tool_use = input.tool_use || gp.just_pressed(GamepadButton::West);
+                input.tool_use_held = input.tool_use_held || gp.pressed(GamepadButton::West);
                 input.attack = input.tool_use;
                 // Y (North) → tool_secondary
                 input.tool_secondary =
                     input.tool_secondary || gp.just_pressed(GamepadButton::North);
+                input.tool_secondary_held =
+                    input.tool_secondary_held || gp.pressed(GamepadButton::North);
                 // B (East) → pause
                 input.pause = input.pause || gp.just_pressed(GamepadButton::East);
                 // Start → pause
@@ -528,6 +539,8 @@ fn reset_and_read_input(
             input.ui_confirm =
                 keys.just_pressed(bindings.ui_confirm) || keys.just_pressed(bindings.interact);
             input.ui_cancel = keys.just_pressed(bindings.ui_cancel);
+            input.ui_secondary = keys.just_pressed(bindings.ui_secondary);
+            input.ui_discard = keys.just_pressed(bindings.ui_discard);
             input.pause = keys.just_pressed(bindings.pause);
             input.tab_pressed = keys.just_pressed(KeyCode::Tab);
 
@@ -551,6 +564,10 @@ fn reset_and_read_input(
                 // Select → toggle-close inventory (same button that opened it)
                 input.open_inventory =
                     input.open_inventory || gp.just_pressed(GamepadButton::Select);
+                // Y → secondary action on the selected entry
+                input.ui_secondary = input.ui_secondary || gp.just_pressed(GamepadButton::North);
+                // X → throw away the selected entry
+                input.ui_discard = input.ui_discard || gp.just_pressed(GamepadButton::West);
                 // RB → tab
                 input.tab_pressed =
                     input.tab_pressed || gp.just_pressed(GamepadButton::RightTrigger);
@@ -631,8 +648,11 @@ fn reset_and_read_input(
 /// Derives InputContext from GameState. ONE system, replaces all per-domain guards.
 /// When the context changes, blanks all input for one frame to prevent carryover
 /// (e.g., swinging a hoe near an NPC → Space carried into dialogue as "advance").
+/// While an overlay holds an `InputBlocks` entry during gameplay, the gameplay
+/// actions are dropped too, so a key the overlay uses can't also swing a tool.
 fn manage_input_context(
     game_state: Res<State<GameState>>,
+    input_blocks: Res<InputBlocks>,
     mut context: ResMut<InputContext>,
     mut input: ResMut<PlayerInput>,
 ) {
@@ -647,6 +667,8 @@ fn manage_input_context(
         GameState::Journal => InputContext::Menu,
         GameState::RelationshipsView => InputContext::Menu,
         GameState::MapView => InputContext::Menu,
+        GameState::Mailbox => InputContext::Menu,
+        GameState::QuestBoard => InputContext::Menu,
         GameState::Dialogue => InputContext::Dialogue,
         GameState::Fishing => InputContext::Fishing,
         GameState::Cutscene => InputContext::Cutscene,
@@ -661,4 +683,14 @@ fn manage_input_context(
     }
 
     *context = new_context;
+
+    if new_context == InputContext::Gameplay && input_blocks.is_blocked() {
+        input.interact = false;
+        input.tool_use = false;
+        input.tool_use_held = false;
+        input.tool_secondary = false;
+        input.tool_secondary_held = false;
+        input.quick_water = false;
+        input.attack = false;
+    }
 }
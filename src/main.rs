@@ -49,6 +49,9 @@ fn main() {
                 fit_canvas_to_parent: true,
                 ..default()
             }),
+            // Closing is handled by the pause menu so unsaved progress can
+            // be saved first.
+            close_when_requested: false,
             ..default()
         })
         .set(ImagePlugin::default_nearest());
@@ -80,8 +83,15 @@ fn main() {
         .init_resource::<FarmState>()
         .init_resource::<AnimalState>()
         .init_resource::<Relationships>()
+        .init_resource::<GiftHistory>()
+        .init_resource::<BuildingPlacements>()
+        .init_resource::<OverflowStore>()
         .init_resource::<MineState>()
         .init_resource::<UnlockedRecipes>()
+        .init_resource::<PinnedRecipes>()
+        .init_resource::<PlayerProfile>()
+        .init_resource::<CollapsePending>()
+        .init_resource::<WellRestedPending>()
         .init_resource::<ShippingBin>()
         .init_resource::<ItemRegistry>()
         .init_resource::<CropRegistry>()
@@ -94,21 +104,26 @@ fn main() {
         .init_resource::<MarriageState>()
         .init_resource::<QuestLog>()
         .init_resource::<SprinklerState>()
+        .init_resource::<SiloState>()
         .init_resource::<ActiveBuffs>()
         .init_resource::<EvaluationScore>()
         .init_resource::<RelationshipStages>()
         // Phase 3 resources
         .init_resource::<Achievements>()
         .init_resource::<ShippingLog>()
+        .init_resource::<Mailbox>()
         .init_resource::<TutorialState>()
         .init_resource::<PlayStats>()
         .init_resource::<InputBlocks>()
         .init_resource::<InteractionClaimed>()
+        .init_resource::<InteractionTarget>()
+        .init_resource::<BedrollRest>()
         .init_resource::<CutsceneQueue>()
         // Input & menu abstraction
         .init_resource::<PlayerInput>()
         .init_resource::<InputContext>()
         .init_resource::<KeyBindings>()
+        .init_resource::<GameSettings>()
         .init_resource::<MenuTheme>()
         .init_resource::<MenuAction>()
         // Events
@@ -121,6 +136,7 @@ fn main() {
         .add_event::<ShopTransactionEvent>()
         .add_event::<ToolUseEvent>()
         .add_event::<MapTransitionEvent>()
+        .add_event::<HonoredMapTransitionEvent>()
         .add_event::<StaminaDrainEvent>()
         .add_event::<GoldChangeEvent>()
         .add_event::<GiftGivenEvent>()
@@ -141,8 +157,10 @@ fn main() {
         .add_event::<EvaluationTriggerEvent>()
         // Phase 3 events
         .add_event::<HintEvent>()
+        .add_event::<ReturnToTitleEvent>()
         .add_event::<AchievementUnlockedEvent>()
         .add_event::<BuildingUpgradeEvent>()
+        .add_event::<BuildingMoveEvent>()
         .add_event::<ToolImpactEvent>()
         .add_event::<ToastEvent>()
         // Input plugin (before all domain plugins)
@@ -259,10 +259,15 @@ fn draw_rock_crab(data: &mut [u8], w: usize) {
 // ANIMATION SYSTEMS
 // ═══════════════════════════════════════════════════════════════════════
 
+/// Size multiplier applied on top of the idle animation for bosses.
+const BOSS_SCALE: f32 = 1.5;
+
 /// System: animate enemy idle behaviors based on their type.
 /// - Slimes: vertical squash-stretch with a larger silhouette and faster pulse
 /// - Bats: stronger wing flap via scale X oscillation + more pronounced vertical bob
 /// - Rock crabs: quicker horizontal wobble with a wider idle offset
+///
+/// Boss-floor bosses get the same motion at a larger size.
 pub fn animate_enemy_idle(
     time: Res<Time>,
     mut query: Query<(
@@ -270,6 +275,7 @@ pub fn animate_enemy_idle(
         &mut EnemyIdleAnim,
         &mut Transform,
         &mut Sprite,
+        Option<&MineBoss>,
     )>,
     in_mine: Res<InMine>,
 ) {
@@ -279,7 +285,7 @@ pub fn animate_enemy_idle(
 
     let t = time.elapsed_secs();
 
-    for (monster, mut anim, mut transform, mut sprite) in query.iter_mut() {
+    for (monster, mut anim, mut transform, mut sprite, boss) in query.iter_mut() {
         let phase = anim.phase;
         // Advance phase (used for continuity)
         anim.phase += time.delta_secs();
@@ -317,6 +323,9 @@ pub fn animate_enemy_idle(
                 transform.scale = Vec3::splat(1.28);
             }
         }
+        if boss.is_some() {
+            transform.scale *= BOSS_SCALE;
+        }
 
         // Shadows/ghost-like alpha pulse for all enemies (very subtle)
         // Only the rock crab gets a subtle color shift to simulate shadow effect
@@ -11,7 +11,8 @@ use bevy::prelude::*;
 use rand::prelude::*;
 
 use super::components::*;
-use super::floor_gen::{MINE_HEIGHT, MINE_WIDTH};
+use super::floor_gen::{boss_reward, MINE_HEIGHT, MINE_WIDTH};
+use super::transitions::KnockoutPenalty;
 use crate::shared::*;
 
 /// Player combat damage based on pickaxe tier (doubles as weapon).
@@ -30,12 +31,16 @@ fn player_attack_damage(tier: ToolTier) -> f32 {
 pub fn handle_player_attack(
     mut commands: Commands,
     mut tool_events: EventReader<ToolUseEvent>,
-    mut enemies: Query<(Entity, &MineGridPos, &mut MineMonster)>,
+    mut enemies: Query<(Entity, &MineGridPos, &mut MineMonster, Option<&MineBoss>)>,
     mut pickup_events: EventWriter<ItemPickupEvent>,
     mut sfx_events: EventWriter<PlaySfxEvent>,
     mut monster_slain_events: EventWriter<MonsterSlainEvent>,
     mut stamina_events: EventWriter<StaminaDrainEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
     in_mine: Res<InMine>,
+    active_buffs: Res<ActiveBuffs>,
+    mut session_loot: ResMut<MineSessionLoot>,
+    mut mine_state: ResMut<MineState>,
 ) {
     if !in_mine.0 {
         return;
@@ -49,7 +54,7 @@ pub fn handle_player_attack(
         let damage = player_attack_damage(event.tier);
         let mut killed = None;
 
-        for (entity, grid_pos, mut monster) in enemies.iter_mut() {
+        for (entity, grid_pos, mut monster, boss) in enemies.iter_mut() {
             if grid_pos.x == event.target_x && grid_pos.y == event.target_y {
                 monster.health -= damage;
 
@@ -58,25 +63,46 @@ pub fn handle_player_attack(
                 });
 
                 if monster.health <= 0.0 {
-                    killed = Some((entity, monster.kind));
+                    killed = Some((entity, monster.kind, boss.map(|b| b.floor)));
                 }
                 break;
             }
         }
 
-        if let Some((entity, kind)) = killed {
+        if let Some((entity, kind, boss_floor)) = killed {
             commands.entity(entity).despawn_recursive();
 
             sfx_events.send(PlaySfxEvent {
                 sfx_id: "mine_enemy_die".to_string(),
             });
 
-            // Drop loot based on enemy type
-            let (item_id, qty) = enemy_loot(kind);
-            pickup_events.send(ItemPickupEvent {
-                item_id,
-                quantity: qty,
-            });
+            // Drop loot based on enemy type, with a bonus roll for Luck
+            let luck = luck_multiplier(&active_buffs);
+            for (item_id, quantity) in roll_monster_drops(kind, luck, &mut rand::thread_rng()) {
+                session_loot.record(&item_id, quantity);
+                pickup_events.send(ItemPickupEvent {
+                    item_id,
+                    quantity,
+                    quality: ItemQuality::Normal,
+                });
+            }
+
+            // Boss floors guarantee a reward on top of the normal loot roll,
+            // paid out only the first time each floor's boss falls
+            if let Some(floor) = boss_floor {
+                for (item_id, quantity) in claim_boss_reward(&mut mine_state, floor) {
+                    session_loot.record(&item_id, quantity);
+                    pickup_events.send(ItemPickupEvent {
+                        item_id,
+                        quantity,
+                        quality: ItemQuality::Normal,
+                    });
+                }
+                toast_events.send(ToastEvent {
+                    message: format!("The guardian of floor {} is defeated!", floor),
+                    duration_secs: 3.0,
+                });
+            }
 
             // Notify quest system
             let kind_str = match kind {
@@ -94,49 +120,84 @@ pub fn handle_player_attack(
     }
 }
 
-/// Determine loot dropped by a killed enemy.
-fn enemy_loot(kind: MineEnemy) -> (String, u8) {
-    let mut rng = rand::thread_rng();
+/// The boss reward for `floor`, recorded as claimed in `mine_state`. Empty
+/// once the floor's reward has been paid out before.
+fn claim_boss_reward(mine_state: &mut MineState, floor: u8) -> Vec<(ItemId, u8)> {
+    if mine_state.boss_rewards_claimed.contains(&floor) {
+        return Vec::new();
+    }
+    mine_state.boss_rewards_claimed.push(floor);
+    boss_reward(floor)
+}
+
+/// Loot table entry: (item_id, drop chance, min quantity, max quantity).
+/// Every entry is rolled independently, so one kill can drop several items.
+type LootEntry = (&'static str, f64, u8, u8);
+
+const GREEN_SLIME_LOOT: &[LootEntry] = &[
+    ("slime", 0.8, 1, 3),
+    ("slime_jelly", 0.25, 1, 1),
+    ("sap", 0.2, 1, 2),
+    ("copper_ore", 0.1, 1, 1),
+];
+
+const BAT_LOOT: &[LootEntry] = &[
+    ("bat_wing", 0.6, 1, 2),
+    ("stone", 0.3, 1, 2),
+    ("copper_ore", 0.2, 1, 2),
+    ("iron_ore", 0.15, 1, 1),
+];
+
+const ROCK_CRAB_LOOT: &[LootEntry] = &[
+    ("crab_shell", 0.5, 1, 1),
+    ("stone", 0.6, 2, 4),
+    ("iron_ore", 0.25, 1, 2),
+    ("gold_ore", 0.1, 1, 1),
+];
+
+fn loot_table(kind: MineEnemy) -> &'static [LootEntry] {
     match kind {
-        MineEnemy::GreenSlime => {
-            let roll: f64 = rng.gen();
-            if roll < 0.25 {
-                ("slime".to_string(), rng.gen_range(1..=3))
-            } else if roll < 0.45 {
-                ("slime_jelly".to_string(), rng.gen_range(1..=2))
-            } else if roll < 0.6 {
-                ("copper_ore".to_string(), 1)
-            } else if roll < 0.75 {
-                ("sap".to_string(), rng.gen_range(1..=2))
-            } else {
-                ("stone".to_string(), rng.gen_range(1..=3))
-            }
-        }
-        MineEnemy::Bat => {
-            let roll: f64 = rng.gen();
-            if roll < 0.25 {
-                ("bat_wing".to_string(), 1)
-            } else if roll < 0.5 {
-                ("iron_ore".to_string(), 1)
-            } else if roll < 0.7 {
-                ("copper_ore".to_string(), rng.gen_range(1..=2))
-            } else {
-                ("stone".to_string(), rng.gen_range(1..=2))
-            }
+        MineEnemy::GreenSlime => GREEN_SLIME_LOOT,
+        MineEnemy::Bat => BAT_LOOT,
+        MineEnemy::RockCrab => ROCK_CRAB_LOOT,
+    }
+}
+
+/// Rolls the loot table for a killed enemy. May be empty on an unlucky roll.
+pub fn monster_loot(kind: MineEnemy, rng: &mut impl Rng) -> Vec<(ItemId, u8)> {
+    let mut drops = Vec::new();
+    for (item_id, chance, min, max) in loot_table(kind) {
+        if rng.gen_bool(*chance) {
+            drops.push((item_id.to_string(), rng.gen_range(*min..=*max)));
         }
-        MineEnemy::RockCrab => {
-            let roll: f64 = rng.gen();
-            if roll < 0.2 {
-                ("crab_shell".to_string(), 1)
-            } else if roll < 0.45 {
-                ("gold_ore".to_string(), 1)
-            } else if roll < 0.65 {
-                ("iron_ore".to_string(), rng.gen_range(1..=2))
-            } else {
-                ("stone".to_string(), rng.gen_range(2..=4))
+    }
+    drops
+}
+
+/// Rolls loot with the player's Luck buff applied. A Luck magnitude of 1.5
+/// gives a 50% chance of a second roll on the table; stacks are merged.
+fn roll_monster_drops(kind: MineEnemy, luck: f32, rng: &mut impl Rng) -> Vec<(ItemId, u8)> {
+    let mut drops = monster_loot(kind, rng);
+    let bonus_chance = (luck as f64 - 1.0).clamp(0.0, 1.0);
+    if bonus_chance > 0.0 && rng.gen_bool(bonus_chance) {
+        for (item_id, qty) in monster_loot(kind, rng) {
+            match drops.iter_mut().find(|(id, _)| *id == item_id) {
+                Some((_, existing)) => *existing = existing.saturating_add(qty),
+                None => drops.push((item_id, qty)),
             }
         }
     }
+    drops
+}
+
+/// Magnitude of the active Luck buff, or 1.0 (no effect) when none is active.
+fn luck_multiplier(buffs: &ActiveBuffs) -> f32 {
+    buffs
+        .buffs
+        .iter()
+        .find(|b| b.buff_type == BuffType::Luck)
+        .map(|b| b.magnitude)
+        .unwrap_or(1.0)
 }
 
 /// System: enemy AI movement toward the player.
@@ -269,7 +330,8 @@ pub fn enemy_attack_player(
 }
 
 /// System: check if the player's health has reached zero (knockout).
-/// On knockout, exit the mine, set health to a fraction, and lose some gold.
+/// On knockout, exit the mine, set health to a fraction, and lose some gold
+/// (peaceful mode skips the gold and item penalty).
 #[allow(clippy::too_many_arguments)]
 pub fn check_player_knockout(
     mut player_state: ResMut<PlayerState>,
@@ -278,8 +340,8 @@ pub fn check_player_knockout(
     mut in_mine: ResMut<InMine>,
     mut map_events: EventWriter<MapTransitionEvent>,
     mut sfx_events: EventWriter<PlaySfxEvent>,
-    mut gold_events: EventWriter<GoldChangeEvent>,
     mut iframes: ResMut<PlayerIFrames>,
+    mut penalty: KnockoutPenalty,
 ) {
     if !in_mine.0 {
         return;
@@ -291,14 +353,8 @@ pub fn check_player_knockout(
             sfx_id: "player_knockout".to_string(),
         });
 
-        // Lose 10% of gold (min 0)
-        let gold_loss = (player_state.gold as f32 * 0.10) as i32;
-        if gold_loss > 0 {
-            gold_events.send(GoldChangeEvent {
-                amount: -gold_loss,
-                reason: "Knocked out in the mine".to_string(),
-            });
-        }
+        // Lose a capped share of gold and a few stacks, kept for recovery.
+        penalty.apply(&player_state, &mut mine_state, "Knocked out in the mine");
 
         // Restore partial health
         player_state.health = player_state.max_health * 0.5;
@@ -317,3 +373,73 @@ pub fn check_player_knockout(
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const ROLLS: usize = 5000;
+
+    fn average_drop_count(kind: MineEnemy, luck: f32, seed: u64) -> f64 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let total: u32 = (0..ROLLS)
+            .map(|_| {
+                roll_monster_drops(kind, luck, &mut rng)
+                    .iter()
+                    .map(|(_, qty)| *qty as u32)
+                    .sum::<u32>()
+            })
+            .sum();
+        total as f64 / ROLLS as f64
+    }
+
+    #[test]
+    fn boss_reward_is_paid_once_per_floor() {
+        let mut mine_state = MineState::default();
+        assert_eq!(claim_boss_reward(&mut mine_state, 10), boss_reward(10));
+        assert!(claim_boss_reward(&mut mine_state, 10).is_empty());
+        assert!(!claim_boss_reward(&mut mine_state, 20).is_empty());
+        assert_eq!(mine_state.boss_rewards_claimed, vec![10, 20]);
+    }
+
+    #[test]
+    fn monster_loot_matches_table_distribution() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for kind in [MineEnemy::GreenSlime, MineEnemy::Bat, MineEnemy::RockCrab] {
+            let table = loot_table(kind);
+            let mut hits = vec![0usize; table.len()];
+            for _ in 0..ROLLS {
+                for (item_id, qty) in monster_loot(kind, &mut rng) {
+                    let index = table
+                        .iter()
+                        .position(|(id, _, _, _)| *id == item_id)
+                        .unwrap_or_else(|| panic!("{item_id} is not in the {kind:?} table"));
+                    let (_, _, min, max) = table[index];
+                    assert!((min..=max).contains(&qty), "{item_id} x{qty} out of range");
+                    hits[index] += 1;
+                }
+            }
+            for ((item_id, chance, _, _), count) in table.iter().zip(hits) {
+                let rate = count as f64 / ROLLS as f64;
+                assert!(
+                    (rate - chance).abs() < 0.05,
+                    "{kind:?} dropped {item_id} at {rate:.3}, expected about {chance}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn luck_raises_average_drop_count() {
+        for kind in [MineEnemy::GreenSlime, MineEnemy::Bat, MineEnemy::RockCrab] {
+            let base = average_drop_count(kind, 1.0, 11);
+            let lucky = average_drop_count(kind, 1.5, 11);
+            assert!(
+                lucky > base * 1.3,
+                "{kind:?}: lucky average {lucky:.2} should beat base {base:.2}"
+            );
+        }
+    }
+}
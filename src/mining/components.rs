@@ -5,6 +5,8 @@
 
 use bevy::prelude::*;
 
+use crate::shared::{ItemId, ItemRegistry};
+
 /// Marker for all entities belonging to the current mine floor.
 /// Used for bulk despawning when changing floors or leaving the mine.
 #[derive(Component, Debug)]
@@ -33,6 +35,13 @@ pub struct MineGridPos {
     pub y: i32,
 }
 
+/// Marks the mini-boss of a boss floor. Defeating it grants the floor's
+/// guaranteed reward.
+#[derive(Component, Debug)]
+pub struct MineBoss {
+    pub floor: u8,
+}
+
 /// Tracks enemy movement cooldown so they don't move every frame.
 #[derive(Component, Debug)]
 pub struct EnemyMoveTick {
@@ -76,6 +85,47 @@ impl Default for ActiveFloor {
     }
 }
 
+/// Items gathered from rocks and monsters since the player last entered the
+/// mine, listed in the order they were first collected.
+#[derive(Resource, Debug, Default)]
+pub struct MineSessionLoot {
+    pub items: Vec<(ItemId, u32)>,
+}
+
+impl MineSessionLoot {
+    pub fn record(&mut self, item_id: &str, quantity: u8) {
+        match self.items.iter_mut().find(|(id, _)| id == item_id) {
+            Some((_, total)) => *total += quantity as u32,
+            None => self.items.push((item_id.to_string(), quantity as u32)),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn total(&self) -> u32 {
+        self.items.iter().map(|(_, qty)| qty).sum()
+    }
+
+    /// One-line recap for the exit toast, e.g. "Mine haul: 12 Stone, 3 Copper
+    /// Ore". `None` when nothing was collected.
+    pub fn summary(&self, registry: &ItemRegistry) -> Option<String> {
+        if self.items.is_empty() {
+            return None;
+        }
+        let parts: Vec<String> = self
+            .items
+            .iter()
+            .map(|(id, qty)| {
+                let name = registry
+                    .get(id)
+                    .map(|d| d.name.as_str())
+                    .unwrap_or(id.as_str());
+                format!("{} {}", qty, name)
+            })
+            .collect();
+        Some(format!("Mine haul: {}", parts.join(", ")))
+    }
+}
+
 /// Resource: when set to true, the mine systems should generate and spawn a new floor.
 #[derive(Resource, Debug, Default)]
 pub struct FloorSpawnRequest {
@@ -104,3 +154,22 @@ impl Default for PlayerIFrames {
 /// The elevator UI state (when player is choosing a floor at the elevator).
 #[derive(Resource, Debug, Default)]
 pub struct ElevatorUiOpen(pub bool);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_loot_merges_repeat_pickups() {
+        let mut loot = MineSessionLoot::default();
+        loot.record("stone", 2);
+        loot.record("copper_ore", 1);
+        loot.record("stone", 3);
+
+        assert_eq!(
+            loot.items,
+            vec![("stone".to_string(), 5), ("copper_ore".to_string(), 1)]
+        );
+        assert_eq!(loot.total(), 6);
+    }
+}
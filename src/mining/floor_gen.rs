@@ -12,10 +12,14 @@ use rand::rngs::StdRng;
 pub const MINE_WIDTH: i32 = 24;
 pub const MINE_HEIGHT: i32 = 24;
 
+/// Every 10th floor is a mini-boss floor: one tough enemy instead of a pack.
+pub const BOSS_FLOOR_INTERVAL: u8 = 10;
+const BOSS_HEALTH_MULTIPLIER: f32 = 5.0;
+const BOSS_DAMAGE_MULTIPLIER: f32 = 1.5;
+
 /// Describes a single generated floor before it is spawned into the ECS.
 #[derive(Debug, Clone)]
 pub struct FloorBlueprint {
-    #[allow(dead_code)]
     pub floor: u8,
     pub rocks: Vec<RockBlueprint>,
     pub enemies: Vec<EnemyBlueprint>,
@@ -50,6 +54,12 @@ pub struct EnemyBlueprint {
     pub max_health: f32,
     pub damage: f32,
     pub speed: f32,
+    /// The mini-boss of a boss floor; drops `boss_reward` when defeated.
+    pub is_boss: bool,
+}
+
+pub fn is_boss_floor(floor: u8) -> bool {
+    floor > 0 && floor.is_multiple_of(BOSS_FLOOR_INTERVAL)
 }
 
 /// Generate a complete floor blueprint for the given floor number.
@@ -160,8 +170,13 @@ pub fn generate_floor(floor: u8) -> FloorBlueprint {
         ((lx, ly), false, None)
     };
 
-    // --- Place enemies ---
-    let enemy_count = enemy_count_for_floor(floor, &mut rng);
+    // --- Place enemies (a lone mini-boss on boss floors) ---
+    let boss_floor = is_boss_floor(floor);
+    let enemy_count = if boss_floor {
+        1
+    } else {
+        enemy_count_for_floor(floor, &mut rng)
+    };
     let mut enemies = Vec::new();
     let mut enemy_attempts = 0;
     while enemies.len() < enemy_count && enemy_attempts < enemy_count * 10 {
@@ -169,8 +184,12 @@ pub fn generate_floor(floor: u8) -> FloorBlueprint {
         let y = rng.gen_range(3..MINE_HEIGHT - 2);
         if !occupied.contains(&(x, y)) {
             occupied.insert((x, y));
-            let kind = pick_enemy_kind(floor, &mut rng);
-            let bp = make_enemy_blueprint(kind, floor, x, y);
+            let bp = if boss_floor {
+                make_boss_blueprint(floor, x, y)
+            } else {
+                let kind = pick_enemy_kind(floor, &mut rng);
+                make_enemy_blueprint(kind, floor, x, y)
+            };
             enemies.push(bp);
         }
         enemy_attempts += 1;
@@ -187,76 +206,83 @@ pub fn generate_floor(floor: u8) -> FloorBlueprint {
     }
 }
 
-/// Choose a rock drop based on floor depth.
+/// Weighted rock drops for a floor as `(item, chance)` pairs summing to 1.0.
 ///
 /// Spec drop rates:
 /// - Floors 1-5:  Stone (70%), Copper ore (30%)
 /// - Floors 6-10: Stone (40%), Copper (40%), Iron ore (20%)
 /// - Floors 11-15: Stone (35%), Iron (40%), Gold ore (20%), gems (5%)
 /// - Floors 16-20: Stone (20%), Gold (30%), Iridium ore (10%), gems (10%), Iron (30%)
+/// - Floors 21+: the 16-20 table drifts over the next 40 floors toward
+///   Stone (10%), Iron (15%), Gold (30%), Iridium (25%), gems (20%)
 ///
-/// Rock health: 3 (stone) to 6 (ore/gem).
-fn rock_drop(floor: u8, rng: &mut StdRng) -> (String, u8, u8) {
-    let roll: f64 = rng.gen();
-
-    if floor <= 5 {
-        // Floors 1-5: Stone (70%), Copper ore (30%)
-        if roll < 0.30 {
-            ("copper_ore".to_string(), rng.gen_range(1..=2), 4)
-        } else {
-            ("stone".to_string(), rng.gen_range(1..=3), 3)
-        }
+/// Gems are split quartz 40%, amethyst 25%, emerald 15%, ruby 12%, diamond 8%.
+pub fn floor_ore_table(floor: u8) -> Vec<(ItemId, f32)> {
+    let (stone, copper, iron, gold, iridium, gems) = if floor <= 5 {
+        (0.70, 0.30, 0.0, 0.0, 0.0, 0.0)
     } else if floor <= 10 {
-        // Floors 6-10: Stone (40%), Copper (40%), Iron (20%)
-        if roll < 0.20 {
-            ("iron_ore".to_string(), rng.gen_range(1..=2), 5)
-        } else if roll < 0.60 {
-            ("copper_ore".to_string(), rng.gen_range(1..=2), 4)
-        } else {
-            ("stone".to_string(), rng.gen_range(1..=3), 3)
-        }
+        (0.40, 0.40, 0.20, 0.0, 0.0, 0.0)
     } else if floor <= 15 {
-        // Floors 11-15: Stone (35%), Iron (40%), Gold (20%), gems (5%)
-        if roll < 0.05 {
-            (pick_gem(rng), 1, 5)
-        } else if roll < 0.25 {
-            ("gold_ore".to_string(), rng.gen_range(1..=2), 5)
-        } else if roll < 0.65 {
-            ("iron_ore".to_string(), rng.gen_range(1..=2), 5)
-        } else {
-            ("stone".to_string(), rng.gen_range(1..=3), 3)
-        }
+        (0.35, 0.0, 0.40, 0.20, 0.0, 0.05)
     } else {
-        // Floors 16-20: Stone (20%), Gold (30%), Iridium (10%), gems (10%), Iron (30%)
-        if roll < 0.10 {
-            (pick_gem(rng), 1, 6)
-        } else if roll < 0.20 {
-            ("iridium_ore".to_string(), rng.gen_range(1..=2), 6)
-        } else if roll < 0.50 {
-            ("gold_ore".to_string(), rng.gen_range(1..=2), 5)
-        } else if roll < 0.80 {
-            ("iron_ore".to_string(), rng.gen_range(1..=2), 5)
-        } else {
-            ("stone".to_string(), rng.gen_range(1..=3), 3)
-        }
-    }
+        let depth = ((floor as f32 - 20.0) / 40.0).clamp(0.0, 1.0);
+        (
+            0.20 - 0.10 * depth,
+            0.0,
+            0.30 - 0.15 * depth,
+            0.30,
+            0.10 + 0.15 * depth,
+            0.10 + 0.10 * depth,
+        )
+    };
+
+    let mut table: Vec<(ItemId, f32)> = [
+        ("stone", stone),
+        ("copper_ore", copper),
+        ("iron_ore", iron),
+        ("gold_ore", gold),
+        ("iridium_ore", iridium),
+        ("quartz", gems * 0.40),
+        ("amethyst", gems * 0.25),
+        ("emerald", gems * 0.15),
+        ("ruby", gems * 0.12),
+        ("diamond", gems * 0.08),
+    ]
+    .into_iter()
+    .filter(|(_, chance)| *chance > 0.0)
+    .map(|(id, chance)| (id.to_string(), chance))
+    .collect();
+    // Rarest first so a roll near 0 lands on the best drop.
+    table.reverse();
+    table
 }
 
-/// Pick a gem type according to spec distribution:
-/// quartz 40%, amethyst 25%, emerald 15%, ruby 12%, diamond 8%
-fn pick_gem(rng: &mut StdRng) -> String {
-    let roll: f64 = rng.gen();
-    if roll < 0.40 {
-        "quartz".to_string()
-    } else if roll < 0.65 {
-        "amethyst".to_string()
-    } else if roll < 0.80 {
-        "emerald".to_string()
-    } else if roll < 0.92 {
-        "ruby".to_string()
-    } else {
-        "diamond".to_string()
-    }
+/// Choose a rock drop from `floor_ore_table`.
+///
+/// Rock health: 3 (stone) to 6 (ore/gem).
+fn rock_drop(floor: u8, rng: &mut StdRng) -> (String, u8, u8) {
+    let table = floor_ore_table(floor);
+    let roll: f32 = rng.gen();
+    let mut cumulative = 0.0_f32;
+    let item = table
+        .iter()
+        .find(|(_, chance)| {
+            cumulative += *chance;
+            roll < cumulative
+        })
+        .or(table.last())
+        .map(|(id, _)| id.clone())
+        .unwrap_or_else(|| "stone".to_string());
+
+    let (quantity, health) = match item.as_str() {
+        "stone" => (rng.gen_range(1..=3), 3),
+        "copper_ore" => (rng.gen_range(1..=2), 4),
+        "iron_ore" | "gold_ore" => (rng.gen_range(1..=2), 5),
+        "iridium_ore" => (rng.gen_range(1..=2), 6),
+        // Gems: a single stone, harder to crack below floor 15.
+        _ => (1, if floor <= 15 { 5 } else { 6 }),
+    };
+    (item, quantity, health)
 }
 
 /// How many enemies spawn on this floor.
@@ -266,6 +292,7 @@ fn pick_gem(rng: &mut StdRng) -> String {
 /// - Floors 6-10: 3-5 mixed toward GreenSlime
 /// - Floors 11-15: 4-6 mixed toward RockCrab
 /// - Floors 16-20: 5-7 sharp mixed pressure
+/// - Floors 21+: one more enemy per 10 floors past 20, up to +3
 fn enemy_count_for_floor(floor: u8, rng: &mut StdRng) -> usize {
     if floor <= 5 {
         rng.gen_range(1..=2)
@@ -274,7 +301,8 @@ fn enemy_count_for_floor(floor: u8, rng: &mut StdRng) -> usize {
     } else if floor <= 15 {
         rng.gen_range(4..=6)
     } else {
-        rng.gen_range(5..=7)
+        let extra = (floor.saturating_sub(20) as usize / 10).min(3);
+        rng.gen_range(5..=7) + extra
     }
 }
 
@@ -312,47 +340,80 @@ fn pick_enemy_kind(floor: u8, rng: &mut StdRng) -> MineEnemy {
     }
 }
 
-/// Build an EnemyBlueprint with stats scaled to floor depth.
+/// Combat stats for one enemy on a given floor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnemyStats {
+    pub health: f32,
+    pub damage: f32,
+    pub speed: f32,
+}
+
+/// Enemy stats scaled to floor depth.
 ///
 /// Base stats per spec:
 /// - GreenSlime: HP 20, DMG 5, Speed 34
 /// - Bat: HP 15, DMG 8, Speed 56
 /// - RockCrab: HP 40, DMG 12, Speed 18
 ///
-/// Floor scaling: +1 HP per floor, +0.7 DMG per floor.
-fn make_enemy_blueprint(kind: MineEnemy, floor: u8, x: i32, y: i32) -> EnemyBlueprint {
+/// Floor scaling: +1 HP and +0.7 DMG per floor. Past floor 20 each floor
+/// adds another +1 HP and +0.3 DMG, and speed climbs by up to 20%.
+pub fn enemy_stats_for_floor(kind: MineEnemy, floor: u8) -> EnemyStats {
+    let (base_health, base_damage, base_speed) = match kind {
+        MineEnemy::GreenSlime => (20.0, 5.0, 34.0),
+        MineEnemy::Bat => (15.0, 8.0, 56.0),
+        MineEnemy::RockCrab => (40.0, 12.0, 18.0),
+    };
     let f = floor as f32;
-    match kind {
-        MineEnemy::GreenSlime => EnemyBlueprint {
-            x,
-            y,
-            kind,
-            health: 20.0 + f,
-            max_health: 20.0 + f,
-            damage: 5.0 + f * 0.7,
-            speed: 34.0,
-        },
-        MineEnemy::Bat => EnemyBlueprint {
-            x,
-            y,
-            kind,
-            health: 15.0 + f,
-            max_health: 15.0 + f,
-            damage: 8.0 + f * 0.7,
-            speed: 56.0,
-        },
-        MineEnemy::RockCrab => EnemyBlueprint {
-            x,
-            y,
-            kind,
-            health: 40.0 + f,
-            max_health: 40.0 + f,
-            damage: 12.0 + f * 0.7,
-            speed: 18.0,
-        },
+    let deep = floor.saturating_sub(20) as f32;
+    EnemyStats {
+        health: base_health + f + deep,
+        damage: base_damage + f * 0.7 + deep * 0.3,
+        speed: base_speed * (1.0 + (deep / 100.0).min(0.2)),
+    }
+}
+
+/// Build an EnemyBlueprint with stats from `enemy_stats_for_floor`.
+fn make_enemy_blueprint(kind: MineEnemy, floor: u8, x: i32, y: i32) -> EnemyBlueprint {
+    let stats = enemy_stats_for_floor(kind, floor);
+    EnemyBlueprint {
+        x,
+        y,
+        kind,
+        health: stats.health,
+        max_health: stats.health,
+        damage: stats.damage,
+        speed: stats.speed,
+        is_boss: false,
     }
 }
 
+/// The mini-boss for a boss floor: a floor-scaled enemy with 5x health and
+/// 1.5x damage. The kind rotates slime → crab → bat every 10 floors.
+fn make_boss_blueprint(floor: u8, x: i32, y: i32) -> EnemyBlueprint {
+    let kind = match (floor / BOSS_FLOOR_INTERVAL) % 3 {
+        1 => MineEnemy::GreenSlime,
+        2 => MineEnemy::RockCrab,
+        _ => MineEnemy::Bat,
+    };
+    let mut bp = make_enemy_blueprint(kind, floor, x, y);
+    bp.health *= BOSS_HEALTH_MULTIPLIER;
+    bp.max_health = bp.health;
+    bp.damage *= BOSS_DAMAGE_MULTIPLIER;
+    bp.is_boss = true;
+    bp
+}
+
+/// Guaranteed drop for defeating a boss floor's mini-boss.
+pub fn boss_reward(floor: u8) -> Vec<(ItemId, u8)> {
+    let (bar, gem) = match floor {
+        0..=10 => ("copper_bar", "quartz"),
+        11..=20 => ("iron_bar", "amethyst"),
+        21..=30 => ("gold_bar", "ruby"),
+        _ => ("iridium_bar", "diamond"),
+    };
+    vec![(bar.to_string(), 5), (gem.to_string(), 1)]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -437,6 +498,57 @@ mod tests {
         }
     }
 
+    fn chance_of(table: &[(ItemId, f32)], ids: &[&str]) -> f32 {
+        table
+            .iter()
+            .filter(|(id, _)| ids.contains(&id.as_str()))
+            .map(|(_, chance)| chance)
+            .sum()
+    }
+
+    #[test]
+    fn ore_tables_sum_to_one() {
+        for floor in [1_u8, 5, 6, 12, 18, 20, 40, 60, 120] {
+            let total: f32 = floor_ore_table(floor).iter().map(|(_, c)| c).sum();
+            assert!((total - 1.0).abs() < 1e-4, "floor {floor} sums to {total}");
+        }
+    }
+
+    #[test]
+    fn deeper_floors_have_better_ore_and_tougher_enemies() {
+        let shallow = floor_ore_table(5);
+        let deep = floor_ore_table(40);
+        let precious = ["gold_ore", "iridium_ore"];
+        let gems = ["quartz", "amethyst", "emerald", "ruby", "diamond"];
+
+        assert!(chance_of(&deep, &precious) > chance_of(&shallow, &precious));
+        assert!(chance_of(&deep, &gems) > chance_of(&shallow, &gems));
+        assert!(chance_of(&deep, &["stone"]) < chance_of(&shallow, &["stone"]));
+        assert!(chance_of(&deep, &gems) > chance_of(&floor_ore_table(20), &gems));
+
+        for kind in [MineEnemy::GreenSlime, MineEnemy::Bat, MineEnemy::RockCrab] {
+            let weak = enemy_stats_for_floor(kind, 5);
+            let strong = enemy_stats_for_floor(kind, 40);
+            assert!(strong.health > weak.health, "{kind:?} health");
+            assert!(strong.damage > weak.damage, "{kind:?} damage");
+            assert!(strong.speed >= weak.speed, "{kind:?} speed");
+        }
+    }
+
+    #[test]
+    fn boss_floor_spawns_a_single_tougher_boss() {
+        let bp = generate_floor(20);
+        assert_eq!(bp.enemies.len(), 1);
+        let boss = &bp.enemies[0];
+        assert!(boss.is_boss);
+        let normal = enemy_stats_for_floor(boss.kind, 20);
+        assert!(boss.max_health >= normal.health * BOSS_HEALTH_MULTIPLIER);
+        assert_eq!(boss.health, boss.max_health);
+        assert!(!boss_reward(20).is_empty());
+
+        assert!(generate_floor(19).enemies.iter().all(|e| !e.is_boss));
+    }
+
     #[test]
     fn ladder_position_safety_bound_holds_across_many_floors() {
         for floor in 1_u8..=100 {
@@ -2,7 +2,8 @@
 //!
 //! The main HUD is managed by the UI domain. This module only spawns
 //! a small "Floor N" text label that exists while in the mine, plus
-//! the elevator selection prompt when ElevatorUiOpen is true.
+//! the elevator selection prompt when ElevatorUiOpen is true, and small
+//! world-space health bars over wounded monsters.
 
 use bevy::prelude::*;
 
@@ -21,6 +22,23 @@ pub struct FloorLabel;
 #[derive(Component, Debug)]
 pub struct ElevatorPrompt;
 
+/// Health bar background that follows a wounded monster.
+#[derive(Component, Debug)]
+pub struct MonsterHealthBar {
+    pub owner: Entity,
+}
+
+/// The coloured fill drawn over a `MonsterHealthBar`.
+#[derive(Component, Debug)]
+pub struct MonsterHealthBarFill {
+    pub owner: Entity,
+}
+
+const HEALTH_BAR_WIDTH: f32 = 14.0;
+const HEALTH_BAR_HEIGHT: f32 = 2.0;
+const HEALTH_BAR_OFFSET_Y: f32 = 12.0;
+const HEALTH_BAR_Z: f32 = 5.0;
+
 /// System: spawn mine HUD when entering the mine.
 pub fn spawn_mine_hud(
     mut commands: Commands,
@@ -126,3 +144,106 @@ pub fn despawn_mine_hud(
         }
     }
 }
+
+/// System: keep a health bar over every damaged monster. Bars appear on the
+/// first hit and are removed once the monster is back to full or gone.
+#[allow(clippy::type_complexity)]
+pub fn update_monster_health_bars(
+    mut commands: Commands,
+    monsters: Query<(Entity, &MineMonster, &Transform, Option<&MineBoss>)>,
+    mut bars: Query<
+        (Entity, &MonsterHealthBar, &mut Transform),
+        (Without<MineMonster>, Without<MonsterHealthBarFill>),
+    >,
+    mut fills: Query<
+        (Entity, &MonsterHealthBarFill, &mut Transform, &mut Sprite),
+        (Without<MineMonster>, Without<MonsterHealthBar>),
+    >,
+    in_mine: Res<InMine>,
+) {
+    if !in_mine.0 {
+        return;
+    }
+
+    let wounded = |owner: Entity| {
+        monsters
+            .get(owner)
+            .ok()
+            .filter(|(_, monster, _, _)| monster.health < monster.max_health)
+    };
+
+    // Move or remove existing bars.
+    let mut has_bar = std::collections::HashSet::new();
+    for (entity, bar, mut transform) in bars.iter_mut() {
+        match wounded(bar.owner) {
+            Some((_, _, owner_tf, boss)) => {
+                transform.translation = bar_position(owner_tf, boss.is_some());
+                has_bar.insert(bar.owner);
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+    for (entity, fill, mut transform, mut sprite) in fills.iter_mut() {
+        match wounded(fill.owner) {
+            Some((_, monster, owner_tf, boss)) => {
+                let ratio = (monster.health / monster.max_health).clamp(0.0, 1.0);
+                let width = bar_width(boss.is_some());
+                sprite.custom_size = Some(Vec2::new(width * ratio, HEALTH_BAR_HEIGHT));
+                transform.translation = bar_position(owner_tf, boss.is_some());
+                transform.translation.x -= width * (1.0 - ratio) / 2.0;
+                transform.translation.z += 0.1;
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+
+    // Spawn bars for newly wounded monsters; the fill is sized next frame.
+    for (owner, monster, owner_tf, boss) in monsters.iter() {
+        if monster.health >= monster.max_health || has_bar.contains(&owner) {
+            continue;
+        }
+        let width = bar_width(boss.is_some());
+        let position = bar_position(owner_tf, boss.is_some());
+        commands.spawn((
+            Sprite {
+                color: Color::srgba(0.1, 0.05, 0.05, 0.85),
+                custom_size: Some(Vec2::new(width, HEALTH_BAR_HEIGHT)),
+                ..default()
+            },
+            Transform::from_translation(position),
+            MineFloorEntity,
+            MonsterHealthBar { owner },
+        ));
+        commands.spawn((
+            Sprite {
+                color: Color::srgb(0.85, 0.2, 0.2),
+                custom_size: Some(Vec2::new(width, HEALTH_BAR_HEIGHT)),
+                ..default()
+            },
+            Transform::from_translation(position + Vec3::Z * 0.1),
+            MineFloorEntity,
+            MonsterHealthBarFill { owner },
+        ));
+    }
+}
+
+fn bar_width(is_boss: bool) -> f32 {
+    if is_boss {
+        HEALTH_BAR_WIDTH * 2.0
+    } else {
+        HEALTH_BAR_WIDTH
+    }
+}
+
+fn bar_position(owner: &Transform, is_boss: bool) -> Vec3 {
+    let lift = if is_boss {
+        HEALTH_BAR_OFFSET_Y * 1.5
+    } else {
+        HEALTH_BAR_OFFSET_Y
+    };
+    Vec3::new(
+        owner.translation.x,
+        owner.translation.y + lift,
+        HEALTH_BAR_Z,
+    )
+}
@@ -1,6 +1,7 @@
 //! Ladder interaction and elevator system.
 //!
 //! - When the player steps on a revealed ladder, descend to the next floor.
+//! - Using a staircase item anywhere on a floor descends the same way.
 //! - Every 5 floors, an elevator stop is unlocked.
 //! - The elevator allows choosing any unlocked floor when entering the mine.
 
@@ -9,6 +10,45 @@ use bevy::prelude::*;
 use super::components::*;
 use crate::shared::*;
 
+/// The deepest floor of the mine.
+const DEEPEST_FLOOR: u8 = 20;
+
+/// Item that acts as a portable ladder when used in the mine.
+pub const STAIRCASE_ITEM_ID: &str = "staircase";
+
+/// Advance to the next floor: track the deepest floor, unlock elevator stops
+/// and request the new floor. Returns `None` at the bottom of the mine.
+fn descend_one_floor(
+    mine_state: &mut MineState,
+    active_floor: &mut ActiveFloor,
+    floor_req: &mut FloorSpawnRequest,
+) -> Option<u8> {
+    let next_floor = mine_state.current_floor + 1;
+    if next_floor > DEEPEST_FLOOR {
+        return None;
+    }
+
+    mine_state.current_floor = next_floor;
+
+    // Track deepest floor
+    if next_floor > mine_state.deepest_floor_reached {
+        mine_state.deepest_floor_reached = next_floor;
+    }
+
+    // Unlock elevator every 5 floors
+    if next_floor.is_multiple_of(5) && !mine_state.elevator_floors.contains(&next_floor) {
+        mine_state.elevator_floors.push(next_floor);
+        mine_state.elevator_floors.sort();
+    }
+
+    // Request new floor spawn
+    floor_req.pending = true;
+    floor_req.floor = next_floor;
+    active_floor.spawned = false;
+
+    Some(next_floor)
+}
+
 /// System: detect when the player stands on the revealed ladder and descend.
 #[allow(clippy::too_many_arguments)]
 pub fn handle_ladder_interaction(
@@ -48,50 +88,92 @@ pub fn handle_ladder_interaction(
     for (grid_pos, ladder) in ladders.iter() {
         if grid_pos.x == px && grid_pos.y == py && ladder.revealed {
             // Descend!
-            let next_floor = mine_state.current_floor + 1;
-
-            // Cap at floor 20
-            if next_floor > 20 {
-                sfx_events.send(PlaySfxEvent {
-                    sfx_id: "ui_deny".to_string(),
-                });
-                toast_events.send(ToastEvent {
-                    message: "You've reached the deepest floor.".to_string(),
-                    duration_secs: 2.0,
-                });
+            let Some(next_floor) =
+                descend_one_floor(&mut mine_state, &mut active_floor, &mut floor_req)
+            else {
+                send_deepest_floor_notice(&mut sfx_events, &mut toast_events);
                 return;
-            }
+            };
 
             sfx_events.send(PlaySfxEvent {
                 sfx_id: "mine_descend".to_string(),
             });
-
-            mine_state.current_floor = next_floor;
-
             toast_events.send(ToastEvent {
                 message: format!("Floor {}", next_floor),
                 duration_secs: 1.5,
             });
 
-            // Track deepest floor
-            if next_floor > mine_state.deepest_floor_reached {
-                mine_state.deepest_floor_reached = next_floor;
-            }
+            return;
+        }
+    }
+}
 
-            // Unlock elevator every 5 floors
-            if next_floor.is_multiple_of(5) && !mine_state.elevator_floors.contains(&next_floor) {
-                mine_state.elevator_floors.push(next_floor);
-                mine_state.elevator_floors.sort();
-            }
+fn send_deepest_floor_notice(
+    sfx_events: &mut EventWriter<PlaySfxEvent>,
+    toast_events: &mut EventWriter<ToastEvent>,
+) {
+    sfx_events.send(PlaySfxEvent {
+        sfx_id: "ui_deny".to_string(),
+    });
+    toast_events.send(ToastEvent {
+        message: "You've reached the deepest floor.".to_string(),
+        duration_secs: 2.0,
+    });
+}
 
-            // Request new floor spawn
-            floor_req.pending = true;
-            floor_req.floor = next_floor;
-            active_floor.spawned = false;
+/// System: using a staircase (R with it selected) descends immediately,
+/// consuming one staircase. Does nothing outside the mine.
+#[allow(clippy::too_many_arguments)]
+pub fn use_staircase(
+    mut mine_state: ResMut<MineState>,
+    mut active_floor: ResMut<ActiveFloor>,
+    mut floor_req: ResMut<FloorSpawnRequest>,
+    mut inventory: ResMut<Inventory>,
+    in_mine: Res<InMine>,
+    elevator_ui: Res<ElevatorUiOpen>,
+    player_input: Res<PlayerInput>,
+    input_blocks: Res<InputBlocks>,
+    mut removed_events: EventWriter<ItemRemovedEvent>,
+    mut sfx_events: EventWriter<PlaySfxEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
+) {
+    if !in_mine.0 || !active_floor.spawned || elevator_ui.0 {
+        return;
+    }
 
-            return;
-        }
+    if input_blocks.is_blocked() || !player_input.tool_secondary {
+        return;
     }
+
+    let selected = inventory
+        .slots
+        .get(inventory.selected_slot)
+        .and_then(|s| s.as_ref());
+    if selected.is_none_or(|slot| slot.item_id != STAIRCASE_ITEM_ID) {
+        return;
+    }
+
+    let Some(next_floor) = descend_one_floor(&mut mine_state, &mut active_floor, &mut floor_req)
+    else {
+        // Keep the staircase — there is nowhere further down to go.
+        send_deepest_floor_notice(&mut sfx_events, &mut toast_events);
+        return;
+    };
+
+    if inventory.try_remove(STAIRCASE_ITEM_ID, 1) > 0 {
+        removed_events.send(ItemRemovedEvent {
+            item_id: STAIRCASE_ITEM_ID.into(),
+            quantity: 1,
+        });
+    }
+
+    sfx_events.send(PlaySfxEvent {
+        sfx_id: "mine_descend".to_string(),
+    });
+    toast_events.send(ToastEvent {
+        message: format!("Floor {}", next_floor),
+        duration_secs: 1.5,
+    });
 }
 
 /// System: detect when the player steps on the exit tile to leave the mine.
@@ -105,6 +187,9 @@ pub fn handle_mine_exit(
     input_blocks: Res<InputBlocks>,
     mut map_events: EventWriter<MapTransitionEvent>,
     mut sfx_events: EventWriter<PlaySfxEvent>,
+    mut session_loot: ResMut<MineSessionLoot>,
+    item_registry: Res<ItemRegistry>,
+    mut toast_events: EventWriter<ToastEvent>,
 ) {
     if !in_mine.0 || !active_floor.spawned {
         return;
@@ -134,6 +219,14 @@ pub fn handle_mine_exit(
             in_mine.0 = false;
             active_floor.spawned = false;
 
+            // Recap what this trip into the mine yielded
+            if let Some(message) = std::mem::take(&mut *session_loot).summary(&item_registry) {
+                toast_events.send(ToastEvent {
+                    message,
+                    duration_secs: 4.0,
+                });
+            }
+
             // Transition back to mine entrance
             map_events.send(MapTransitionEvent {
                 to_map: MapId::MineEntrance,
@@ -4,10 +4,11 @@
 //! - Procedural mine floor generation (20 floors, rocks, enemies, ladder)
 //! - Rock breaking with pickaxe (scaled by tool tier)
 //! - Combat with mine monsters (slimes, bats, rock crabs)
+//! - Mini-boss every 10th floor with a guaranteed reward
 //! - Enemy AI movement and attack
-//! - Ladder discovery and floor descent
+//! - Ladder discovery and floor descent (or a crafted staircase)
 //! - Elevator system (every 5 floors)
-//! - Mine HUD (floor indicator, elevator selection)
+//! - Mine HUD (floor indicator, elevator selection, monster health bars)
 //! - Mine entry/exit via MapTransitionEvent
 //! - Player knockout on death (gold penalty, return to surface)
 //! - Day-end handling (pass out penalty)
@@ -27,6 +28,8 @@ mod transitions;
 use crate::shared::*;
 use bevy::prelude::*;
 use components::*;
+#[allow(unused_imports)]
+pub use ladder::{use_staircase, STAIRCASE_ITEM_ID};
 use movement::MineMoveCooldown;
 #[allow(unused_imports)]
 pub use rock_breaking::handle_rock_breaking;
@@ -43,6 +46,7 @@ impl Plugin for MiningPlugin {
         app.init_resource::<ActiveFloor>();
         app.init_resource::<FloorSpawnRequest>();
         app.init_resource::<InMine>();
+        app.init_resource::<MineSessionLoot>();
         app.init_resource::<PlayerIFrames>();
         app.init_resource::<MineMoveCooldown>();
         app.init_resource::<ElevatorUiOpen>();
@@ -67,6 +71,7 @@ impl Plugin for MiningPlugin {
                 // Map transition handling (entry/exit detection)
                 transitions::handle_mine_entry,
                 transitions::handle_day_end_in_mine,
+                transitions::recover_lost_items,
                 transitions::cleanup_mine_on_exit,
             )
                 .run_if(in_state(GameState::Playing)),
@@ -90,6 +95,7 @@ impl Plugin for MiningPlugin {
                 combat::check_player_knockout,
                 // Ladder and elevator
                 ladder::handle_ladder_interaction,
+                ladder::use_staircase,
                 ladder::handle_mine_exit,
                 ladder::handle_elevator_selection,
                 // HUD
@@ -97,6 +103,7 @@ impl Plugin for MiningPlugin {
                 hud::update_floor_label,
                 hud::show_elevator_prompt,
                 hud::despawn_mine_hud,
+                hud::update_monster_health_bars,
             )
                 .run_if(in_state(GameState::Playing)),
         );
@@ -95,8 +95,10 @@ pub fn mine_player_movement(
     }
 }
 
-/// System: when the player presses the action key (Space or E), generate a ToolUseEvent
+/// System: when the player presses interact (E), generate a ToolUseEvent
 /// targeting the tile the player is facing. Uses the currently equipped tool.
+/// The tool-use key goes through `player::tools::tool_use`, whose swing lands
+/// on the animation's impact frame.
 #[allow(clippy::too_many_arguments)]
 pub fn mine_player_action(
     player_input: Res<PlayerInput>,
@@ -117,7 +119,7 @@ pub fn mine_player_action(
     }
 
     // Only fire on press, not hold
-    if !player_input.tool_use && !player_input.interact {
+    if !player_input.interact {
         return;
     }
 
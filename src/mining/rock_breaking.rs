@@ -20,7 +20,7 @@ fn pickaxe_damage(tier: ToolTier) -> u8 {
 }
 
 /// Stamina cost for a pickaxe swing.
-fn pickaxe_stamina_cost(tier: ToolTier) -> f32 {
+pub fn pickaxe_stamina_cost(tier: ToolTier) -> f32 {
     match tier {
         ToolTier::Basic => 3.5,
         ToolTier::Copper => 3.0,
@@ -45,6 +45,8 @@ pub fn handle_rock_breaking(
     mut rock_destroyed_events: EventWriter<RockDestroyedEvent>,
     in_mine: Res<InMine>,
     atlases: Res<MiningAtlases>,
+    settings: Res<GameSettings>,
+    mut session_loot: ResMut<MineSessionLoot>,
 ) {
     if !in_mine.0 {
         return;
@@ -56,7 +58,11 @@ pub fn handle_rock_breaking(
         }
 
         let damage = pickaxe_damage(event.tier);
-        let stamina_cost = pickaxe_stamina_cost(event.tier);
+        let stamina_cost = if settings.creative_tools {
+            0.0
+        } else {
+            pickaxe_stamina_cost(event.tier)
+        };
 
         // Find a rock at the target position
         // hit_rock = Some((entity, drop_item, drop_qty, grid_x, grid_y, world_x, world_y))
@@ -114,9 +120,11 @@ pub fn handle_rock_breaking(
             commands.entity(entity).despawn();
 
             // Drop loot
+            session_loot.record(&drop_item, drop_qty);
             pickup_events.send(ItemPickupEvent {
                 item_id: drop_item,
                 quantity: drop_qty,
+                quality: ItemQuality::Normal,
             });
 
             sfx_events.send(PlaySfxEvent {
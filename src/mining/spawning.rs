@@ -341,7 +341,7 @@ fn spawn_enemies(commands: &mut Commands, blueprint: &FloorBlueprint, enemy_atla
         // Random initial phase so enemies don't animate in lockstep
         let initial_phase: f32 = rng.gen_range(0.0..std::f32::consts::TAU);
 
-        commands.spawn((
+        let mut enemy = commands.spawn((
             Sprite::from_atlas_image(
                 enemy_atlas.image.clone(),
                 TextureAtlas {
@@ -372,6 +372,11 @@ fn spawn_enemies(commands: &mut Commands, blueprint: &FloorBlueprint, enemy_atla
                 phase: initial_phase,
             },
         ));
+        if enemy_bp.is_boss {
+            enemy.insert(MineBoss {
+                floor: blueprint.floor,
+            });
+        }
     }
 }
 
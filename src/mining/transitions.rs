@@ -3,18 +3,125 @@
 //! Listens for MapTransitionEvent to MapId::Mine and sets up mine state.
 //! Also handles the DayEndEvent to reset mine progress if the player
 //! passed out or the day ended while in the mine.
+//!
+//! Passing out costs a capped share of gold and a few inventory stacks. The
+//! stacks wait in `MineState::lost_items` and are handed back the next time
+//! the player reaches the mine entrance on a later day.
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use rand::prelude::*;
 
 use super::components::*;
 use crate::shared::*;
 
-/// System: listen for MapTransitionEvent targeting the Mine.
+/// Share of carried gold lost when passing out in the mine.
+pub const KNOCKOUT_GOLD_FRACTION: f32 = 0.10;
+/// Most gold a single pass-out can cost.
+pub const KNOCKOUT_GOLD_CAP: u32 = 1000;
+/// Most inventory stacks a single pass-out can drop.
+pub const KNOCKOUT_MAX_DROPS: usize = 3;
+
+/// Tools and special (quest/key) items are never dropped.
+fn is_drop_locked(registry: &ItemRegistry, item_id: &str) -> bool {
+    registry
+        .get(item_id)
+        .is_some_and(|def| matches!(def.category, ItemCategory::Tool | ItemCategory::Special))
+}
+
+/// What passing out costs: a capped share of `gold`, and the indices of up
+/// to `KNOCKOUT_MAX_DROPS` random inventory slots holding droppable items.
+pub fn knockout_penalty(
+    gold: u32,
+    inventory: &Inventory,
+    registry: &ItemRegistry,
+    rng: &mut impl Rng,
+) -> (u32, Vec<usize>) {
+    let gold_loss = ((gold as f32 * KNOCKOUT_GOLD_FRACTION) as u32).min(KNOCKOUT_GOLD_CAP);
+
+    let candidates: Vec<usize> = inventory
+        .slots
+        .iter()
+        .enumerate()
+        .filter(|(_, slot)| {
+            slot.as_ref()
+                .is_some_and(|s| !is_drop_locked(registry, &s.item_id))
+        })
+        .map(|(index, _)| index)
+        .collect();
+    let count = rng.gen_range(1..=KNOCKOUT_MAX_DROPS).min(candidates.len());
+    let mut dropped: Vec<usize> = candidates.choose_multiple(rng, count).copied().collect();
+    dropped.sort_unstable();
+
+    (gold_loss, dropped)
+}
+
+/// What the pass-out penalty touches, shared by the knockout in combat and
+/// the end-of-day pass-out so both charge it the same way.
+#[derive(SystemParam)]
+pub struct KnockoutPenalty<'w> {
+    inventory: ResMut<'w, Inventory>,
+    item_registry: Res<'w, ItemRegistry>,
+    calendar: Res<'w, Calendar>,
+    settings: Res<'w, GameSettings>,
+    gold_events: EventWriter<'w, GoldChangeEvent>,
+    toast_events: EventWriter<'w, ToastEvent>,
+}
+
+impl KnockoutPenalty<'_> {
+    /// Roll and apply the pass-out penalty: charge the gold, move the dropped
+    /// stacks into the lost-items cache and tell the player where to find
+    /// them. Peaceful mode skips it; returns whether the penalty was applied.
+    pub fn apply(
+        &mut self,
+        player_state: &PlayerState,
+        mine_state: &mut MineState,
+        reason: &str,
+    ) -> bool {
+        if self.settings.peaceful {
+            return false;
+        }
+
+        let (gold_loss, dropped) = knockout_penalty(
+            player_state.gold,
+            &self.inventory,
+            &self.item_registry,
+            &mut rand::thread_rng(),
+        );
+        if gold_loss > 0 {
+            self.gold_events.send(GoldChangeEvent {
+                amount: -(gold_loss as i32),
+                reason: reason.to_string(),
+            });
+        }
+
+        let mut count = 0;
+        for index in dropped {
+            if let Some(slot) = self.inventory.slots[index].take() {
+                count += slot.quantity as u32;
+                mine_state.lost_items.push(slot);
+            }
+        }
+        if count > 0 {
+            mine_state.lost_items_day = self.calendar.total_days_elapsed();
+            self.toast_events.send(ToastEvent {
+                message: format!(
+                    "You dropped {} item(s). Check the mine entrance tomorrow.",
+                    count
+                ),
+                duration_secs: 3.0,
+            });
+        }
+        true
+    }
+}
+
+/// System: listen for HonoredMapTransitionEvent targeting the Mine.
 /// When the player enters the mine, set InMine, configure floor, and either
 /// show elevator UI (if unlocked floors exist) or spawn floor 1.
 #[allow(clippy::too_many_arguments)]
 pub fn handle_mine_entry(
-    mut map_events: EventReader<MapTransitionEvent>,
+    mut map_events: EventReader<HonoredMapTransitionEvent>,
     mut mine_state: ResMut<MineState>,
     mut in_mine: ResMut<InMine>,
     mut floor_req: ResMut<FloorSpawnRequest>,
@@ -22,11 +129,13 @@ pub fn handle_mine_entry(
     mut elevator_ui: ResMut<ElevatorUiOpen>,
     mut sfx_events: EventWriter<PlaySfxEvent>,
     mut music_events: EventWriter<PlayMusicEvent>,
+    mut session_loot: ResMut<MineSessionLoot>,
 ) {
     for event in map_events.read() {
         if event.to_map == MapId::Mine {
             // Entering the mine!
             in_mine.0 = true;
+            *session_loot = MineSessionLoot::default();
 
             sfx_events.send(PlaySfxEvent {
                 sfx_id: "mine_enter".to_string(),
@@ -76,22 +185,21 @@ pub fn handle_day_end_in_mine(
     mut active_floor: ResMut<ActiveFloor>,
     mut player_state: ResMut<PlayerState>,
     mut map_events: EventWriter<MapTransitionEvent>,
-    mut gold_events: EventWriter<GoldChangeEvent>,
     mut query: Query<(&mut LogicalPosition, &mut GridPosition), With<Player>>,
+    bedroll: Res<BedrollRest>,
+    mut penalty: KnockoutPenalty,
 ) {
-    for _event in day_events.read() {
+    for event in day_events.read() {
         if in_mine.0 {
-            // Player passed out in the mine — penalty
-            let gold_loss = (player_state.gold as f32 * 0.10) as i32;
-            if gold_loss > 0 {
-                gold_events.send(GoldChangeEvent {
-                    amount: -gold_loss,
-                    reason: "Passed out in the mine".to_string(),
-                });
-            }
-
-            // Restore health partially, stamina fully
-            player_state.health = player_state.max_health * 0.5;
+            // Slept in a bedroll (a planned rest) or playing peacefully:
+            // no pass-out penalty. Otherwise wake with half health.
+            let passed_out = !bedroll.covers(event)
+                && penalty.apply(&player_state, &mut mine_state, "Passed out in the mine");
+            player_state.health = if passed_out {
+                player_state.max_health * 0.5
+            } else {
+                player_state.max_health
+            };
             player_state.stamina = player_state.max_stamina;
 
             // Exit mine
@@ -122,6 +230,45 @@ pub fn handle_day_end_in_mine(
     }
 }
 
+/// System: hand back items dropped on a pass-out once the player reaches the
+/// mine entrance on a later day. Anything that doesn't fit stays cached.
+pub fn recover_lost_items(
+    player_state: Res<PlayerState>,
+    calendar: Res<Calendar>,
+    item_registry: Res<ItemRegistry>,
+    mut mine_state: ResMut<MineState>,
+    mut inventory: ResMut<Inventory>,
+    mut toast_events: EventWriter<ToastEvent>,
+) {
+    if player_state.current_map != MapId::MineEntrance
+        || mine_state.lost_items.is_empty()
+        || calendar.total_days_elapsed() <= mine_state.lost_items_day
+    {
+        return;
+    }
+
+    let mut recovered = 0u32;
+    for mut slot in std::mem::take(&mut mine_state.lost_items) {
+        let max_stack = item_registry
+            .get(&slot.item_id)
+            .map(|def| def.stack_size)
+            .unwrap_or(99);
+        let left = inventory.try_add_quality(&slot.item_id, slot.quantity, max_stack, slot.quality);
+        recovered += (slot.quantity - left) as u32;
+        if left > 0 {
+            slot.quantity = left;
+            mine_state.lost_items.push(slot);
+        }
+    }
+
+    if recovered > 0 {
+        toast_events.send(ToastEvent {
+            message: format!("Recovered {} lost item(s) by the mine entrance.", recovered),
+            duration_secs: 3.0,
+        });
+    }
+}
+
 /// System: clean up all mine floor entities when leaving the mine.
 pub fn cleanup_mine_on_exit(
     mut commands: Commands,
@@ -139,3 +286,66 @@ pub fn cleanup_mine_on_exit(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn item(id: &str, category: ItemCategory) -> ItemDef {
+        ItemDef {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: String::new(),
+            category,
+            sell_price: 10,
+            buy_price: None,
+            stack_size: 99,
+            edible: false,
+            energy_restore: 0.0,
+            sprite_index: 0,
+        }
+    }
+
+    #[test]
+    fn knockout_never_drops_locked_items() {
+        let mut registry = ItemRegistry::default();
+        for def in [
+            item("copper_ore", ItemCategory::Mineral),
+            item("rusty_key", ItemCategory::Special),
+            item("trash_can", ItemCategory::Tool),
+            item("turnip", ItemCategory::Crop),
+        ] {
+            registry.items.insert(def.id.clone(), def);
+        }
+        let mut inventory = Inventory::default();
+        for id in ["rusty_key", "copper_ore", "trash_can", "turnip"] {
+            inventory.try_add(id, 5, 99);
+        }
+
+        for seed in 0..200 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let (_, dropped) = knockout_penalty(500, &inventory, &registry, &mut rng);
+            assert!(!dropped.is_empty() && dropped.len() <= KNOCKOUT_MAX_DROPS);
+            for index in dropped {
+                let id = &inventory.slots[index].as_ref().unwrap().item_id;
+                assert!(id == "copper_ore" || id == "turnip", "dropped locked {id}");
+            }
+        }
+    }
+
+    #[test]
+    fn knockout_gold_loss_is_capped() {
+        let registry = ItemRegistry::default();
+        let inventory = Inventory::default();
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let (small, dropped) = knockout_penalty(500, &inventory, &registry, &mut rng);
+        assert_eq!(small, 50);
+        assert!(dropped.is_empty(), "nothing to drop");
+
+        let (large, _) = knockout_penalty(1_000_000, &inventory, &registry, &mut rng);
+        assert_eq!(large, KNOCKOUT_GOLD_CAP);
+    }
+}
@@ -39,10 +39,10 @@ pub fn animate_npc_sprites(
     >,
 ) {
     for (movement, logical_pos, mut sprite, mut anim) in query.iter_mut() {
-        // Determine facing from movement vector (current pos → target).
+        // Determine facing from movement vector (current pos → next waypoint).
         // Only update facing when actually moving; idle NPCs keep last direction.
-        let dx = movement.target_x - logical_pos.0.x;
-        let dy = movement.target_y - logical_pos.0.y;
+        let heading = movement.next_waypoint() - logical_pos.0;
+        let (dx, dy) = (heading.x, heading.y);
 
         let base: usize = if dx.abs() > 0.5 || dy.abs() > 0.5 {
             // Meaningful movement delta — update facing
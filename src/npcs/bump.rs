@@ -0,0 +1,198 @@
+//! Walking into an NPC: they turn toward the player and, now and then, say a
+//! short greeting.
+//!
+//! Player collision only checks tiles, so the player can walk through NPCs.
+//! A "bump" is the player moving toward an NPC while overlapping it. Greetings
+//! are rate-limited per NPC so brushing past someone doesn't spam toasts.
+
+use super::animation::NpcAnimationTimer;
+use super::emotes::{EmoteKind, NpcEmoteEvent};
+use super::spawning::NpcMovement;
+use crate::shared::*;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// How close (in world units) the player must be to count as bumping an NPC.
+const BUMP_RADIUS: f32 = TILE_SIZE * 0.8;
+
+/// Seconds before the same NPC will greet the player again after a bump.
+pub const BUMP_GREETING_COOLDOWN_SECS: f32 = 30.0;
+
+/// Short lines said when bumped into; picked per NPC so each has a habit.
+const BUMP_GREETINGS: &[&str] = &[
+    "Oh! Hello there.",
+    "Whoa, careful!",
+    "Oof! Good to see you too.",
+    "Excuse me!",
+    "Hey, watch your step!",
+    "Hi! Busy day?",
+];
+
+/// Per-NPC time (in `Time::elapsed_secs`) of the last bump greeting.
+#[derive(Resource, Debug, Default)]
+pub struct NpcBumpTracker {
+    last_greeting: HashMap<NpcId, f32>,
+}
+
+impl NpcBumpTracker {
+    /// Records a greeting from `npc_id` at `now` and returns true, unless that
+    /// NPC already greeted within the cooldown.
+    pub fn try_greet(&mut self, npc_id: &str, now: f32) -> bool {
+        if let Some(&last) = self.last_greeting.get(npc_id) {
+            if now - last < BUMP_GREETING_COOLDOWN_SECS {
+                return false;
+            }
+        }
+        self.last_greeting.insert(npc_id.to_string(), now);
+        true
+    }
+}
+
+fn bump_greeting(npc_id: &str) -> &'static str {
+    let hash = npc_id
+        .bytes()
+        .fold(5381u32, |h, b| h.wrapping_mul(33).wrapping_add(b as u32));
+    BUMP_GREETINGS[hash as usize % BUMP_GREETINGS.len()]
+}
+
+/// Atlas row base (0/4/8/12) for an NPC at `npc_pos` looking at `target`.
+pub(super) fn facing_base_toward(npc_pos: Vec2, target: Vec2) -> usize {
+    let delta = target - npc_pos;
+    if delta.x.abs() > delta.y.abs() {
+        if delta.x > 0.0 {
+            8
+        } else {
+            4
+        }
+    } else if delta.y > 0.0 {
+        12
+    } else {
+        0
+    }
+}
+
+/// System: detect the player walking into an NPC. Idle NPCs turn to face the
+/// player; the NPC greets them with a toast and emote if off cooldown.
+pub fn handle_npc_bumps(
+    time: Res<Time>,
+    input_blocks: Res<InputBlocks>,
+    player_query: Query<(&LogicalPosition, &PlayerMovement), With<Player>>,
+    mut npc_query: Query<(&Npc, &LogicalPosition, &NpcMovement, &mut NpcAnimationTimer)>,
+    mut tracker: ResMut<NpcBumpTracker>,
+    mut toast_events: EventWriter<ToastEvent>,
+    mut emote_events: EventWriter<NpcEmoteEvent>,
+) {
+    if input_blocks.is_blocked() {
+        return;
+    }
+    let Ok((player_pos, player_movement)) = player_query.get_single() else {
+        return;
+    };
+    if !player_movement.is_moving {
+        return;
+    }
+
+    for (npc, npc_pos, movement, mut anim) in &mut npc_query {
+        if player_pos.0.distance(npc_pos.0) > BUMP_RADIUS
+            || !can_interact_facing(player_pos.0, player_movement.facing, npc_pos.0, BUMP_RADIUS)
+        {
+            continue;
+        }
+
+        // Walking NPCs keep facing their path; idle ones turn to look.
+        if !movement.is_moving {
+            anim.last_base = facing_base_toward(npc_pos.0, player_pos.0);
+        }
+
+        if tracker.try_greet(&npc.id, time.elapsed_secs()) {
+            toast_events.send(ToastEvent {
+                message: format!("{}: {}", npc.name, bump_greeting(&npc.id)),
+                duration_secs: 2.0,
+            });
+            emote_events.send(NpcEmoteEvent {
+                npc_id: npc.id.clone(),
+                emote: EmoteKind::Exclamation,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bump_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<InputBlocks>()
+            .init_resource::<NpcBumpTracker>()
+            .add_event::<ToastEvent>()
+            .add_event::<NpcEmoteEvent>()
+            .add_systems(Update, handle_npc_bumps);
+
+        app.world_mut().spawn((
+            Player,
+            PlayerMovement {
+                facing: Facing::Right,
+                is_moving: true,
+                ..default()
+            },
+            LogicalPosition(Vec2::ZERO),
+        ));
+        app.world_mut().spawn((
+            Npc {
+                id: "elena".to_string(),
+                name: "Elena".to_string(),
+            },
+            LogicalPosition(Vec2::new(TILE_SIZE * 0.5, 0.0)),
+            NpcMovement::default(),
+            NpcAnimationTimer {
+                timer: Timer::from_seconds(0.2, TimerMode::Repeating),
+                frame_count: 4,
+                current_frame: 0,
+                last_base: 0,
+            },
+        ));
+        app
+    }
+
+    fn greeting_count(app: &App) -> usize {
+        let events = app.world().resource::<Events<ToastEvent>>();
+        events.get_cursor().read(events).count()
+    }
+
+    #[test]
+    fn bumped_npc_turns_toward_player_and_greets() {
+        let mut app = bump_app();
+        app.update();
+
+        assert_eq!(greeting_count(&app), 1);
+        let base = app
+            .world_mut()
+            .query::<&NpcAnimationTimer>()
+            .single(app.world())
+            .last_base;
+        assert_eq!(base, 4, "NPC east of the player should face left");
+    }
+
+    #[test]
+    fn repeated_bumps_within_cooldown_do_not_greet_again() {
+        let mut app = bump_app();
+        let mut total = 0;
+        for _ in 0..10 {
+            app.update();
+            total += greeting_count(&app);
+            app.world_mut().resource_mut::<Events<ToastEvent>>().clear();
+        }
+        assert_eq!(total, 1, "only the first bump should greet");
+    }
+
+    #[test]
+    fn tracker_greets_again_after_cooldown() {
+        let mut tracker = NpcBumpTracker::default();
+        assert!(tracker.try_greet("elena", 0.0));
+        assert!(!tracker.try_greet("elena", BUMP_GREETING_COOLDOWN_SECS - 1.0));
+        assert!(tracker.try_greet("mira", 1.0));
+        assert!(tracker.try_greet("elena", BUMP_GREETING_COOLDOWN_SECS));
+    }
+}
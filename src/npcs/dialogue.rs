@@ -1,8 +1,10 @@
 //! Dialogue system: handle player interaction with NPCs, build dialogue lines
 //! based on friendship level, and emit DialogueStartEvent.
 
+use super::quests::turn_in_deliveries;
 use super::spawning::NpcMovement;
 use crate::shared::*;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use std::collections::HashSet;
 use std::sync::atomic::{AtomicU8, Ordering};
@@ -22,7 +24,20 @@ pub struct DailyTalkTracker {
     pub talked: HashSet<String>,
 }
 
-/// System: detect player pressing F (interact) near an NPC and start dialogue.
+/// Bundles what handing over Deliver quest items needs, to stay within Bevy's
+/// 16-parameter system limit.
+#[derive(SystemParam)]
+pub struct QuestTurnInParams<'w> {
+    quest_log: ResMut<'w, QuestLog>,
+    inventory: ResMut<'w, Inventory>,
+    item_registry: Res<'w, ItemRegistry>,
+    removed: EventWriter<'w, ItemRemovedEvent>,
+    completed: EventWriter<'w, QuestCompletedEvent>,
+}
+
+/// System: detect player pressing F (interact) while facing a nearby NPC and
+/// start dialogue. Items the NPC asked for in a Deliver quest are handed over
+/// first, completing the quest once everything has been delivered.
 #[allow(clippy::too_many_arguments)]
 pub fn handle_npc_interaction(
     player_input: Res<PlayerInput>,
@@ -38,6 +53,8 @@ pub fn handle_npc_interaction(
     mut next_state: ResMut<NextState<GameState>>,
     mut interaction_claimed: ResMut<InteractionClaimed>,
     mut daily_talks: ResMut<DailyTalkTracker>,
+    target: Res<InteractionTarget>,
+    mut quests: QuestTurnInParams,
 ) {
     // Only check interaction during Playing state
     if *current_state.get() != GameState::Playing {
@@ -61,23 +78,9 @@ pub fn handle_npc_interaction(
     };
 
     let player_pos = player_transform.translation.truncate();
-    let interaction_range = TILE_SIZE * 1.5; // 1.5 tiles in world space
-
-    // Find the closest adjacent NPC within range
-    let mut closest: Option<(Entity, f32)> = None;
-    for (entity, _npc, npc_transform, _) in npc_query.iter() {
-        let npc_pos = npc_transform.translation.truncate();
-        let dist = player_pos.distance(npc_pos);
-        if dist <= interaction_range {
-            match closest {
-                None => closest = Some((entity, dist)),
-                Some((_, best_dist)) if dist < best_dist => closest = Some((entity, dist)),
-                _ => {}
-            }
-        }
-    }
 
-    let Some((closest_entity, _)) = closest else {
+    // Only respond when the press resolved to an NPC.
+    let Some(closest_entity) = target.entity_of(InteractTargetKind::Npc) else {
         return;
     };
 
@@ -92,7 +95,38 @@ pub fn handle_npc_interaction(
         movement.target_y = player_pos.y;
     }
     let hearts = relationships.hearts(&npc_id);
-    let lines = build_dialogue_lines(&npc_id, hearts, &npc_registry, &relationships, &calendar);
+    let mut lines = build_dialogue_lines(&npc_id, hearts, &npc_registry, &relationships, &calendar);
+
+    // Hand over anything this NPC is waiting on; their thanks come first.
+    let turn_ins = turn_in_deliveries(&npc_id, &mut quests.quest_log, &mut quests.inventory);
+    let mut thanks = Vec::with_capacity(turn_ins.len());
+    for turn_in in turn_ins {
+        quests.removed.send(ItemRemovedEvent {
+            item_id: turn_in.item_id.clone(),
+            quantity: turn_in.handed_over,
+        });
+        let item_name = quests
+            .item_registry
+            .get(&turn_in.item_id)
+            .map(|d| d.name.as_str())
+            .unwrap_or(turn_in.item_id.as_str());
+        if turn_in.completes_quest() {
+            thanks.push(format!(
+                "{} x{}! That's everything I needed. Thank you!",
+                item_name, turn_in.handed_over
+            ));
+            quests.completed.send(QuestCompletedEvent {
+                quest_id: turn_in.quest_id,
+                reward_gold: turn_in.reward_gold,
+            });
+        } else {
+            thanks.push(format!(
+                "Thanks for the {} x{}. I still need {} more.",
+                item_name, turn_in.handed_over, turn_in.still_needed
+            ));
+        }
+    }
+    lines.splice(0..0, thanks);
 
     let portrait_index = npc_registry.npcs.get(&npc_id).map(|def| def.portrait_index);
 
@@ -131,12 +165,7 @@ pub fn build_dialogue_lines(
     let mut lines = Vec::new();
 
     // --- Contextual: "already gifted today" notice ---
-    if relationships
-        .gifted_today
-        .get(npc_id)
-        .copied()
-        .unwrap_or(false)
-    {
+    if relationships.has_gifted_today(npc_id) {
         lines.push(
             "I really appreciate everything you've given me today. Come back tomorrow!".to_string(),
         );
@@ -150,17 +179,25 @@ pub fn build_dialogue_lines(
         lines.push("Oh! Today is my birthday! I can't believe you remembered!".to_string());
     }
 
-    // --- Contextual: weather comment (prepend one line) ---
-    let weather_line = npc_weather_comment(npc_id, calendar.weather);
-    if let Some(wl) = weather_line {
-        lines.push(wl);
-    }
+    // --- Contextual: tagged lines from the NPC's data win over the built-in
+    // weather and season comments ---
+    let total_pts = relationships.friendship.get(npc_id).copied().unwrap_or(0);
+    let pick = calendar.day as usize + total_pts as usize;
+    if let Some(line) = context_dialogue_line(npc_def, calendar, pick) {
+        lines.push(line);
+    } else {
+        // --- Contextual: weather comment (prepend one line) ---
+        let weather_line = npc_weather_comment(npc_id, calendar.weather);
+        if let Some(wl) = weather_line {
+            lines.push(wl);
+        }
 
-    // --- Contextual: seasonal comment ---
-    SEASON_COMMENT_DAY.store(calendar.day, Ordering::Relaxed);
-    let season_line = npc_season_comment(npc_id, calendar.season);
-    if let Some(sl) = season_line {
-        lines.push(sl);
+        // --- Contextual: seasonal comment ---
+        SEASON_COMMENT_DAY.store(calendar.day, Ordering::Relaxed);
+        let season_line = npc_season_comment(npc_id, calendar.season);
+        if let Some(sl) = season_line {
+            lines.push(sl);
+        }
     }
 
     let tier = friendship_tier(hearts);
@@ -209,6 +246,24 @@ pub fn build_dialogue_lines(
     lines
 }
 
+/// Pick one of the NPC's tagged lines for the current moment. Weather pools
+/// are checked first (rain is more remarkable than the season), then season,
+/// then time of day. Returns None when no matching pool has lines, so the
+/// caller falls back to the built-in comments and `default_dialogue`.
+pub fn context_dialogue_line(npc_def: &NpcDef, calendar: &Calendar, pick: usize) -> Option<String> {
+    let context = &npc_def.context_dialogue;
+    let pools = [
+        context.weather.get(&calendar.weather),
+        context.season.get(&calendar.season),
+        context.time_of_day.get(&calendar.day_period()),
+    ];
+    pools
+        .into_iter()
+        .flatten()
+        .find(|pool| !pool.is_empty())
+        .map(|pool| pool[pick % pool.len()].clone())
+}
+
 /// Return a weather-aware comment for the given NPC, or None if no comment is warranted.
 fn npc_weather_comment(npc_id: &str, weather: Weather) -> Option<String> {
     let comment = match (npc_id, weather) {
@@ -970,6 +1025,94 @@ pub fn reset_daily_talks(
 mod tests {
     use super::*;
 
+    fn npc_with_context(context_dialogue: ContextDialogue) -> NpcRegistry {
+        let mut registry = NpcRegistry::default();
+        registry.npcs.insert(
+            "wren".into(),
+            NpcDef {
+                id: "wren".into(),
+                name: "Wren".into(),
+                birthday_season: Season::Fall,
+                birthday_day: 3,
+                gift_preferences: Default::default(),
+                default_dialogue: vec!["Nice day for it.".into()],
+                heart_dialogue: Default::default(),
+                context_dialogue,
+                is_marriageable: false,
+                sprite_index: 0,
+                portrait_index: 0,
+            },
+        );
+        registry
+    }
+
+    #[test]
+    fn rainy_weather_selects_tagged_rain_lines() {
+        let mut context = ContextDialogue::default();
+        context.weather.insert(
+            Weather::Rainy,
+            vec!["Listen to that rain on the roof!".into()],
+        );
+        context
+            .season
+            .insert(Season::Spring, vec!["Spring already?".into()]);
+        let registry = npc_with_context(context);
+        let relationships = Relationships::default();
+
+        let rainy = Calendar {
+            weather: Weather::Rainy,
+            ..Default::default()
+        };
+        let lines = build_dialogue_lines("wren", 0, &registry, &relationships, &rainy);
+        assert!(lines.contains(&"Listen to that rain on the roof!".to_string()));
+        assert!(!lines.contains(&"Spring already?".to_string()));
+
+        let sunny = Calendar::default();
+        let lines = build_dialogue_lines("wren", 0, &registry, &relationships, &sunny);
+        assert!(lines.contains(&"Spring already?".to_string()));
+        assert_eq!(lines.last().unwrap(), "Nice day for it.");
+    }
+
+    #[test]
+    fn untagged_npc_falls_back_to_default_dialogue() {
+        let registry = npc_with_context(ContextDialogue::default());
+        let calendar = Calendar {
+            weather: Weather::Rainy,
+            ..Default::default()
+        };
+        let lines =
+            build_dialogue_lines("wren", 0, &registry, &Relationships::default(), &calendar);
+        assert_eq!(lines, vec!["Nice day for it.".to_string()]);
+    }
+
+    #[test]
+    fn old_tom_has_night_lines_after_midnight_too() {
+        let mut registry = NpcRegistry::default();
+        crate::data::npcs::populate_npcs(&mut registry);
+        let night =
+            registry.npcs["old_tom"].context_dialogue.time_of_day[&DayPeriod::Night].clone();
+        let says_night_line = |hour: u8| {
+            let calendar = Calendar {
+                hour,
+                ..Default::default()
+            };
+            build_dialogue_lines(
+                "old_tom",
+                0,
+                &registry,
+                &Relationships::default(),
+                &calendar,
+            )
+            .iter()
+            .any(|line| night.contains(line))
+        };
+
+        assert!(says_night_line(22));
+        assert!(says_night_line(1));
+        assert!(says_night_line(25));
+        assert!(!says_night_line(8));
+    }
+
     #[test]
     fn test_friendship_tier_low_hearts() {
         assert_eq!(friendship_tier(0), 0);
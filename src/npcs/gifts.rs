@@ -1,18 +1,30 @@
 //! Gift system: handle GiftGivenEvent, apply friendship points, send response dialogue.
 
+use super::animation::NpcAnimationTimer;
+use super::bump::facing_base_toward;
 use super::dialogue::build_gift_response_lines;
 use super::emotes::{EmoteKind, NpcEmoteEvent};
+use super::spawning::NpcMovement;
+use crate::player::interact_dispatch::pick_interaction_target;
 use crate::shared::*;
 use bevy::prelude::*;
 
+/// Crafted wrapping consumed alongside a gift when the player holds the wrap key.
+pub const GIFT_WRAP_ITEM_ID: &str = "gift_wrap";
+
+/// Extra friendship a wrapped gift earns, once per wrap spent.
+pub const WRAPPED_GIFT_BONUS: i32 = 30;
+
 /// System: process gift-given events, apply friendship changes, send response dialogue.
 #[allow(clippy::too_many_arguments)]
 pub fn handle_gifts(
     mut gift_reader: EventReader<GiftGivenEvent>,
     mut relationships: ResMut<Relationships>,
+    mut gift_history: ResMut<GiftHistory>,
     npc_registry: Res<NpcRegistry>,
     item_registry: Res<ItemRegistry>,
     calendar: Res<Calendar>,
+    settings: Res<GameSettings>,
     mut dialogue_writer: EventWriter<DialogueStartEvent>,
     mut emote_writer: EventWriter<NpcEmoteEvent>,
     mut toast_writer: EventWriter<ToastEvent>,
@@ -22,22 +34,16 @@ pub fn handle_gifts(
         let npc_id = &gift_event.npc_id;
         let item_id = &gift_event.item_id;
 
-        // Check if NPC has already received a gift today
-        if relationships
-            .gifted_today
-            .get(npc_id)
-            .copied()
-            .unwrap_or(false)
-        {
+        // Check if NPC has already received today's gifts
+        let limit = settings.gifts_per_day.max(1);
+        if relationships.gifts_today(npc_id) >= limit {
             // Send polite decline dialogue
-            let _npc_name = npc_registry
-                .npcs
-                .get(npc_id)
-                .map(|d| d.name.as_str())
-                .unwrap_or("them");
-            let decline_lines = vec![
-                format!("You've already given me a gift today. That's very generous, but once a day is enough!"),
-            ];
+            let decline_lines = vec![if limit == 1 {
+                "You've already given me a gift today. That's very generous, but once a day is enough!"
+                    .to_string()
+            } else {
+                "You've been so generous today! Save the rest for tomorrow.".to_string()
+            }];
             let portrait_index = npc_registry.npcs.get(npc_id).map(|d| d.portrait_index);
             dialogue_writer.send(DialogueStartEvent {
                 npc_id: npc_id.clone(),
@@ -61,9 +67,12 @@ pub fn handle_gifts(
             calendar.season == npc_def.birthday_season && calendar.day == npc_def.birthday_day;
 
         // Calculate friendship points
-        let base_points = preference_to_points(preference);
-        let multiplier = if is_birthday { 8 } else { 1 };
-        let total_points = base_points * multiplier;
+        let repeated =
+            settings.repeat_gift_fatigue && gift_history.given_this_week(npc_id, item_id);
+        let total_points = repeat_gift_points(
+            gift_points(preference, is_birthday, gift_event.wrapped),
+            repeated,
+        );
 
         // Apply friendship change
         relationships.add_friendship(npc_id, total_points);
@@ -81,8 +90,9 @@ pub fn handle_gifts(
             emote: EmoteKind::from(preference),
         });
 
-        // Mark as gifted today
-        relationships.gifted_today.insert(npc_id.clone(), true);
+        // Count today's gift and remember it for the week
+        relationships.record_gift(npc_id);
+        gift_history.record(npc_id, item_id);
 
         // Look up item name for dialogue
         let item_name = item_registry
@@ -116,6 +126,24 @@ fn preference_to_points(preference: GiftPreference) -> i32 {
     }
 }
 
+/// Friendship for one gift: birthdays multiply the preference points by 8,
+/// and wrapping adds a flat bonus afterwards.
+fn gift_points(preference: GiftPreference, is_birthday: bool, wrapped: bool) -> i32 {
+    let multiplier = if is_birthday { 8 } else { 1 };
+    let bonus = if wrapped { WRAPPED_GIFT_BONUS } else { 0 };
+    preference_to_points(preference) * multiplier + bonus
+}
+
+/// A villager who already got the same item this week is only half as
+/// pleased. Losses from disliked gifts are not softened.
+fn repeat_gift_points(points: i32, repeated: bool) -> i32 {
+    if repeated && points > 0 {
+        points / 2
+    } else {
+        points
+    }
+}
+
 /// Build the toast message shown to the player after giving a gift.
 fn preference_toast_message(npc_name: &str, preference: GiftPreference, points: i32) -> String {
     match preference {
@@ -138,18 +166,26 @@ fn preference_toast_message(npc_name: &str, preference: GiftPreference, points:
 /// System: handle player pressing G (or the configured gift key) while in dialogue
 /// with an NPC, using the selected hotbar item as the gift.
 ///
-/// This system checks: player is adjacent to an NPC, presses G, has selected item.
-/// If so, it emits a GiftGivenEvent and removes one of the item from inventory.
-#[allow(clippy::too_many_arguments)]
+/// This system checks: player is adjacent to and facing an NPC, presses G, has
+/// selected item. If so, it emits a GiftGivenEvent, removes one of the item from
+/// inventory, and an idle NPC turns to take the gift from the player.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn handle_gift_input(
     player_input: Res<PlayerInput>,
     input_blocks: Res<InputBlocks>,
-    player_query: Query<&Transform, With<Player>>,
-    npc_query: Query<(&Npc, &Transform)>,
+    player_query: Query<(&LogicalPosition, &PlayerMovement), With<Player>>,
+    mut npc_query: Query<(
+        Entity,
+        &Npc,
+        &Transform,
+        Option<&NpcMovement>,
+        Option<&mut NpcAnimationTimer>,
+    )>,
     mut inventory: ResMut<Inventory>,
     item_registry: Res<ItemRegistry>,
     npc_registry: Res<NpcRegistry>,
     relationships: Res<Relationships>,
+    settings: Res<GameSettings>,
     mut gift_writer: EventWriter<GiftGivenEvent>,
     mut item_removed_writer: EventWriter<ItemRemovedEvent>,
     mut toast_writer: EventWriter<ToastEvent>,
@@ -173,36 +209,27 @@ pub fn handle_gift_input(
         return;
     }
 
-    let Ok(player_transform) = player_query.get_single() else {
+    let Ok((player_pos, player_movement)) = player_query.get_single() else {
         return;
     };
 
-    let player_pos = player_transform.translation.truncate();
-    let interaction_range = TILE_SIZE * 1.5;
-
-    // Find closest NPC
-    let mut closest_npc_id: Option<String> = None;
-    let mut closest_dist = f32::MAX;
-    for (npc, npc_transform) in npc_query.iter() {
-        let npc_pos = npc_transform.translation.truncate();
-        let dist = player_pos.distance(npc_pos);
-        if dist <= interaction_range && dist < closest_dist {
-            closest_dist = dist;
-            closest_npc_id = Some(npc.id.clone());
-        }
-    }
-
-    let Some(npc_id) = closest_npc_id else {
+    // Same reach and facing rules as talking: only the NPC the player faces.
+    let candidates = npc_query
+        .iter()
+        .map(|(entity, _, tf, _, _)| (entity, InteractTargetKind::Npc, tf.translation.truncate()));
+    let Some((npc_entity, _)) =
+        pick_interaction_target(player_pos.0, player_movement.facing, candidates)
+    else {
+        return;
+    };
+    let Ok((_, npc, npc_transform, npc_movement, npc_anim)) = npc_query.get_mut(npc_entity) else {
         return;
     };
+    let npc_id = npc.id.clone();
+    let npc_pos = npc_transform.translation.truncate();
 
-    // Already gifted today?
-    if relationships
-        .gifted_today
-        .get(&npc_id)
-        .copied()
-        .unwrap_or(false)
-    {
+    // Already given today's gifts?
+    if relationships.gifts_today(&npc_id) >= settings.gifts_per_day.max(1) {
         return;
     }
 
@@ -244,6 +271,22 @@ pub fn handle_gift_input(
         quantity: 1,
     });
 
+    // Holding the wrap key spends a carried gift wrap for a one-off bonus.
+    let wrapped = player_input.wrap_gift_held && inventory.try_remove(GIFT_WRAP_ITEM_ID, 1) == 1;
+    if wrapped {
+        item_removed_writer.send(ItemRemovedEvent {
+            item_id: GIFT_WRAP_ITEM_ID.to_string(),
+            quantity: 1,
+        });
+    }
+
+    // Hand-over: an idle NPC turns to take the gift from the player.
+    if let Some(mut anim) = npc_anim {
+        if !npc_movement.is_some_and(|m| m.is_moving) {
+            anim.last_base = facing_base_toward(npc_pos, player_pos.0);
+        }
+    }
+
     // Emit gift event with the resolved preference
     let preference = npc_registry
         .npcs
@@ -254,6 +297,7 @@ pub fn handle_gift_input(
         npc_id,
         item_id,
         preference,
+        wrapped,
     });
     interaction_claimed.0 = true;
 }
@@ -293,6 +337,7 @@ mod tests {
         let base = preference_to_points(GiftPreference::Loved);
         let birthday_total = base * 8;
         assert_eq!(birthday_total, 640);
+        assert_eq!(gift_points(GiftPreference::Loved, true, false), 640);
     }
 
     #[test]
@@ -300,5 +345,271 @@ mod tests {
         let base = preference_to_points(GiftPreference::Liked);
         let normal_total = base;
         assert_eq!(normal_total, 45);
+        assert_eq!(gift_points(GiftPreference::Liked, false, false), 45);
+    }
+
+    #[test]
+    fn test_wrapped_gift_adds_bonus_after_multiplier() {
+        assert_eq!(
+            gift_points(GiftPreference::Liked, false, true),
+            45 + WRAPPED_GIFT_BONUS
+        );
+        assert_eq!(
+            gift_points(GiftPreference::Loved, true, true),
+            640 + WRAPPED_GIFT_BONUS
+        );
+    }
+
+    use bevy::state::app::StatesPlugin;
+    use std::collections::HashMap;
+
+    fn gift_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.init_state::<GameState>();
+        app.init_resource::<Relationships>()
+            .init_resource::<GiftHistory>()
+            .init_resource::<ItemRegistry>()
+            .init_resource::<Calendar>()
+            .init_resource::<GameSettings>();
+        app.add_event::<GiftGivenEvent>()
+            .add_event::<DialogueStartEvent>()
+            .add_event::<NpcEmoteEvent>()
+            .add_event::<ToastEvent>();
+
+        let mut registry = NpcRegistry::default();
+        registry.npcs.insert(
+            "nora".to_string(),
+            NpcDef {
+                id: "nora".to_string(),
+                name: "Nora".to_string(),
+                birthday_season: Season::Fall,
+                birthday_day: 20,
+                gift_preferences: HashMap::new(),
+                default_dialogue: Vec::new(),
+                heart_dialogue: HashMap::new(),
+                context_dialogue: ContextDialogue::default(),
+                is_marriageable: false,
+                sprite_index: 0,
+                portrait_index: 3,
+            },
+        );
+        app.insert_resource(registry);
+        app.add_systems(Update, handle_gifts);
+        app
+    }
+
+    #[test]
+    fn hated_gift_queues_negative_reaction_and_costs_friendship() {
+        let mut app = gift_test_app();
+        app.world_mut()
+            .resource_mut::<Relationships>()
+            .add_friendship("nora", 200);
+
+        app.world_mut().send_event(GiftGivenEvent {
+            npc_id: "nora".to_string(),
+            item_id: "trash".to_string(),
+            preference: GiftPreference::Hated,
+            wrapped: false,
+        });
+        app.update();
+
+        let relationships = app.world().resource::<Relationships>();
+        assert_eq!(relationships.friendship.get("nora").copied(), Some(160));
+
+        let events = app.world().resource::<Events<DialogueStartEvent>>();
+        let dialogue: Vec<_> = events.get_cursor().read(events).cloned().collect();
+        assert_eq!(dialogue.len(), 1);
+        assert_eq!(dialogue[0].npc_id, "nora");
+        assert_eq!(
+            dialogue[0].lines,
+            build_gift_response_lines("nora", "Nora", GiftPreference::Hated, "trash", false)
+        );
+
+        let emotes = app.world().resource::<Events<NpcEmoteEvent>>();
+        let emote = emotes.get_cursor().read(emotes).next().map(|e| e.emote);
+        assert_eq!(emote, Some(EmoteKind::from(GiftPreference::Hated)));
+    }
+
+    #[test]
+    fn gift_limit_allows_configured_number_of_gifts() {
+        let mut app = gift_test_app();
+        app.world_mut().resource_mut::<GameSettings>().gifts_per_day = 2;
+
+        let give = |app: &mut App| {
+            app.world_mut().send_event(GiftGivenEvent {
+                npc_id: "nora".to_string(),
+                item_id: "flower".to_string(),
+                preference: GiftPreference::Liked,
+                wrapped: false,
+            });
+            app.update();
+            app.world().resource::<Relationships>().friendship["nora"]
+        };
+
+        assert_eq!(give(&mut app), 45);
+        assert_eq!(give(&mut app), 90, "second gift should still count");
+        assert_eq!(give(&mut app), 90, "third gift should be declined");
+        assert_eq!(
+            app.world().resource::<Relationships>().gifts_today("nora"),
+            2
+        );
+    }
+
+    #[test]
+    fn repeat_loved_gift_earns_less_when_fatigue_is_on() {
+        let mut app = gift_test_app();
+        app.world_mut()
+            .resource_mut::<GameSettings>()
+            .repeat_gift_fatigue = true;
+
+        let give = |app: &mut App, day_over: bool| {
+            if day_over {
+                app.world_mut()
+                    .resource_mut::<Relationships>()
+                    .gifted_today
+                    .clear();
+            }
+            app.world_mut().send_event(GiftGivenEvent {
+                npc_id: "nora".to_string(),
+                item_id: "amethyst".to_string(),
+                preference: GiftPreference::Loved,
+                wrapped: false,
+            });
+            app.update();
+            app.world().resource::<Relationships>().friendship["nora"]
+        };
+
+        assert_eq!(give(&mut app, false), 80);
+        assert_eq!(give(&mut app, true), 120, "repeat gift earns half");
+        assert_eq!(
+            app.world().resource::<GiftHistory>().items_for("nora"),
+            vec!["amethyst".to_string()]
+        );
+
+        app.world_mut()
+            .resource_mut::<GiftHistory>()
+            .this_week
+            .clear();
+        assert_eq!(give(&mut app, true), 200, "a new week starts fresh");
+    }
+
+    /// Player at the origin facing right, holding one amethyst, with Nora
+    /// standing at `npc_pos`.
+    fn gift_input_app(npc_pos: Vec2) -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.insert_state(GameState::Playing);
+        app.init_resource::<InputBlocks>()
+            .init_resource::<InteractionClaimed>()
+            .init_resource::<Inventory>()
+            .init_resource::<ItemRegistry>()
+            .init_resource::<NpcRegistry>()
+            .init_resource::<Relationships>()
+            .init_resource::<GameSettings>();
+        app.add_event::<GiftGivenEvent>()
+            .add_event::<ItemRemovedEvent>()
+            .add_event::<ToastEvent>();
+        app.insert_resource(PlayerInput {
+            tool_secondary: true,
+            ..default()
+        });
+        app.world_mut()
+            .resource_mut::<Inventory>()
+            .try_add("amethyst", 1, 99);
+
+        app.world_mut().spawn((
+            Player,
+            PlayerMovement {
+                facing: Facing::Right,
+                ..default()
+            },
+            LogicalPosition(Vec2::ZERO),
+        ));
+        app.world_mut().spawn((
+            Npc {
+                id: "nora".to_string(),
+                name: "Nora".to_string(),
+            },
+            Transform::from_translation(npc_pos.extend(0.0)),
+            NpcAnimationTimer {
+                timer: Timer::from_seconds(0.2, TimerMode::Repeating),
+                frame_count: 4,
+                current_frame: 0,
+                last_base: 0,
+            },
+        ));
+        app.add_systems(Update, handle_gift_input);
+        app
+    }
+
+    fn gifts_sent(app: &App) -> usize {
+        let events = app.world().resource::<Events<GiftGivenEvent>>();
+        events.get_cursor().read(events).count()
+    }
+
+    #[test]
+    fn gift_to_npc_out_of_range_is_rejected() {
+        let mut app = gift_input_app(Vec2::new(TILE_SIZE * 6.0, 0.0));
+        app.update();
+
+        assert_eq!(gifts_sent(&app), 0);
+        assert_eq!(app.world().resource::<Inventory>().count("amethyst"), 1);
+    }
+
+    #[test]
+    fn gift_to_npc_behind_the_player_is_rejected() {
+        let mut app = gift_input_app(Vec2::new(-TILE_SIZE, 0.0));
+        app.update();
+
+        assert_eq!(gifts_sent(&app), 0);
+    }
+
+    #[test]
+    fn adjacent_facing_gift_is_given_and_npc_turns_to_player() {
+        let mut app = gift_input_app(Vec2::new(TILE_SIZE, 0.0));
+        app.update();
+
+        assert_eq!(gifts_sent(&app), 1);
+        assert_eq!(app.world().resource::<Inventory>().count("amethyst"), 0);
+        let base = app
+            .world_mut()
+            .query::<&NpcAnimationTimer>()
+            .single(app.world())
+            .last_base;
+        assert_eq!(base, 4, "NPC east of the player should face left");
+    }
+
+    fn gift_was_wrapped(app: &App) -> bool {
+        let events = app.world().resource::<Events<GiftGivenEvent>>();
+        events.get_cursor().read(events).any(|ev| ev.wrapped)
+    }
+
+    #[test]
+    fn gift_wrap_is_only_spent_when_the_wrap_key_is_held() {
+        let mut app = gift_input_app(Vec2::new(TILE_SIZE, 0.0));
+        app.world_mut()
+            .resource_mut::<Inventory>()
+            .try_add(GIFT_WRAP_ITEM_ID, 2, 99);
+        app.update();
+
+        assert!(!gift_was_wrapped(&app));
+        assert_eq!(
+            app.world().resource::<Inventory>().count(GIFT_WRAP_ITEM_ID),
+            2
+        );
+
+        let mut app = gift_input_app(Vec2::new(TILE_SIZE, 0.0));
+        app.world_mut()
+            .resource_mut::<Inventory>()
+            .try_add(GIFT_WRAP_ITEM_ID, 2, 99);
+        app.world_mut().resource_mut::<PlayerInput>().wrap_gift_held = true;
+        app.update();
+
+        assert!(gift_was_wrapped(&app));
+        assert_eq!(
+            app.world().resource::<Inventory>().count(GIFT_WRAP_ITEM_ID),
+            1
+        );
     }
 }
@@ -0,0 +1,325 @@
+//! Heart events — short scripted scenes that play once when the player
+//! reaches a friendship milestone with an NPC.
+//!
+//! Each entry in the table names an NPC, the heart level (2/4/6/8/10) that
+//! unlocks it, and the map the player has to walk into for it to play. When
+//! both conditions hold the scene's steps are pushed into `CutsceneQueue`
+//! and recorded in `HeartEventLog` so they never repeat.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::shared::*;
+
+/// A scripted scene tied to a friendship milestone.
+#[derive(Debug, Clone)]
+pub struct HeartEvent {
+    pub npc_id: &'static str,
+    pub hearts: u8,
+    /// The scene plays when the player enters this map.
+    pub map: MapId,
+    pub script: Vec<CutsceneStep>,
+}
+
+impl HeartEvent {
+    /// Stable key used to remember that this event has played.
+    pub fn key(&self) -> String {
+        format!("{}:{}", self.npc_id, self.hearts)
+    }
+}
+
+/// Heart events that have already played, keyed by `HeartEvent::key`.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HeartEventLog {
+    pub fired: HashSet<String>,
+}
+
+/// Dialogue scene with a short lead-in, used by most table entries.
+fn scene(npc_id: &str, portrait_index: u32, intro: &str, lines: &[&str]) -> Vec<CutsceneStep> {
+    vec![
+        CutsceneStep::Wait(0.6),
+        CutsceneStep::ShowText(intro.into(), 1.5),
+        CutsceneStep::StartDialogueCustom {
+            npc_id: npc_id.into(),
+            lines: lines.iter().map(|l| (*l).to_string()).collect(),
+            portrait_index: Some(portrait_index),
+        },
+        CutsceneStep::WaitForDialogueEnd,
+    ]
+}
+
+/// All heart events in the game.
+pub fn heart_event_table() -> Vec<HeartEvent> {
+    vec![
+        // ── Lily ────────────────────────────────────────────────────────────
+        HeartEvent {
+            npc_id: "lily",
+            hearts: 2,
+            map: MapId::Town,
+            script: scene(
+                "lily",
+                2,
+                "Lily is kneeling by the flower beds in the square.",
+                &[
+                    "Oh! You caught me talking to the tulips. They grow better when someone listens.",
+                    "You can help, if you like. Just don't step on the seedlings.",
+                ],
+            ),
+        },
+        HeartEvent {
+            npc_id: "lily",
+            hearts: 4,
+            map: MapId::Town,
+            script: scene(
+                "lily",
+                2,
+                "Lily waves you over, holding a tiny paper packet.",
+                &[
+                    "I saved these seeds from last year's best bloom. I want you to have some.",
+                    "Plant them somewhere you'll see every morning. That's the rule.",
+                ],
+            ),
+        },
+        HeartEvent {
+            npc_id: "lily",
+            hearts: 6,
+            map: MapId::Town,
+            script: scene(
+                "lily",
+                2,
+                "A storm has flattened half of Lily's garden.",
+                &[
+                    "It's silly to cry over flowers, isn't it?",
+                    "...Thank you for staying. Helping me replant makes it feel less like losing.",
+                ],
+            ),
+        },
+        HeartEvent {
+            npc_id: "lily",
+            hearts: 8,
+            map: MapId::Town,
+            script: scene(
+                "lily",
+                2,
+                "Lily has been waiting for you by the fountain.",
+                &[
+                    "I pressed a flower from the day we first met. I keep it in my favourite book.",
+                    "I don't know why I'm telling you this. Maybe I just wanted you to know.",
+                ],
+            ),
+        },
+        HeartEvent {
+            npc_id: "lily",
+            hearts: 10,
+            map: MapId::Town,
+            script: scene(
+                "lily",
+                2,
+                "The square is full of flowers you've never seen before.",
+                &[
+                    "I grew them all this season. Every one of them reminds me of you.",
+                    "Whatever happens next, I'm glad you came to this town.",
+                ],
+            ),
+        },
+        // ── Elena ───────────────────────────────────────────────────────────
+        HeartEvent {
+            npc_id: "elena",
+            hearts: 2,
+            map: MapId::Blacksmith,
+            script: scene(
+                "elena",
+                4,
+                "Elena is hammering a glowing bar at the anvil.",
+                &[
+                    "Stand back from the sparks. ...You can watch, though.",
+                    "Most people leave as soon as it gets loud. You didn't.",
+                ],
+            ),
+        },
+        HeartEvent {
+            npc_id: "elena",
+            hearts: 4,
+            map: MapId::Blacksmith,
+            script: scene(
+                "elena",
+                4,
+                "Elena is frowning at a cracked ingot.",
+                &[
+                    "My new alloy keeps splitting when it cools. Father says I'm rushing it.",
+                    "Maybe he's right. Don't tell him I said that.",
+                ],
+            ),
+        },
+        HeartEvent {
+            npc_id: "elena",
+            hearts: 6,
+            map: MapId::Blacksmith,
+            script: scene(
+                "elena",
+                4,
+                "Elena holds up a flawless blade, grinning.",
+                &[
+                    "It held! The alloy finally held!",
+                    "You're the first person I wanted to show. I'm not sure what that means yet.",
+                ],
+            ),
+        },
+        HeartEvent {
+            npc_id: "elena",
+            hearts: 8,
+            map: MapId::Blacksmith,
+            script: scene(
+                "elena",
+                4,
+                "The forge is quiet. Elena sits alone by the cooling coals.",
+                &[
+                    "Sometimes I wonder if I'd ever leave this town. Then I think about who I'd leave behind.",
+                    "Stay a while? The forge is warmest right before it goes out.",
+                ],
+            ),
+        },
+        HeartEvent {
+            npc_id: "elena",
+            hearts: 10,
+            map: MapId::Blacksmith,
+            script: scene(
+                "elena",
+                4,
+                "Elena presses a small iron charm into your hand.",
+                &[
+                    "It's stamped with my maker's mark. I've never put it on anything I didn't keep.",
+                    "So now you know. I'm keeping you.",
+                ],
+            ),
+        },
+    ]
+}
+
+/// When the player enters a map, queue the first unplayed heart event on
+/// that map whose NPC has reached the required hearts. At most one scene is
+/// queued per transition, and only when no other cutscene is pending;
+/// `activate_pending_cutscene` then starts it like any other queued scene.
+pub fn trigger_heart_events(
+    mut transition_reader: EventReader<HonoredMapTransitionEvent>,
+    relationships: Res<Relationships>,
+    mut log: ResMut<HeartEventLog>,
+    mut cutscene_queue: ResMut<CutsceneQueue>,
+) {
+    let Some(event) = transition_reader.read().last() else {
+        return;
+    };
+    if cutscene_queue.active || !cutscene_queue.steps.is_empty() {
+        return;
+    }
+
+    let Some(heart_event) = heart_event_table().into_iter().find(|he| {
+        he.map == event.to_map
+            && relationships.hearts(he.npc_id) >= he.hearts
+            && !log.fired.contains(&he.key())
+    }) else {
+        return;
+    };
+
+    info!(
+        "[HeartEvents] Playing {}'s {}-heart event",
+        heart_event.npc_id, heart_event.hearts
+    );
+    log.fired.insert(heart_event.key());
+    cutscene_queue.steps.extend(heart_event.script);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::cutscene_runner::activate_pending_cutscene;
+    use crate::ui::transitions::ScreenFade;
+    use bevy::state::app::StatesPlugin;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Playing)
+            .add_event::<HonoredMapTransitionEvent>()
+            .init_resource::<Relationships>()
+            .init_resource::<HeartEventLog>()
+            .init_resource::<CutsceneQueue>()
+            .init_resource::<ScreenFade>()
+            .add_systems(Update, trigger_heart_events)
+            .add_systems(
+                PostUpdate,
+                activate_pending_cutscene.run_if(in_state(GameState::Playing)),
+            );
+        app
+    }
+
+    fn enter_map(app: &mut App, map: MapId) {
+        app.world_mut().send_event(HonoredMapTransitionEvent {
+            to_map: map,
+            to_x: 0,
+            to_y: 0,
+        });
+        app.update();
+    }
+
+    #[test]
+    fn heart_event_table_uses_milestone_thresholds() {
+        let table = heart_event_table();
+        assert!(!table.is_empty());
+        let mut keys = HashSet::new();
+        for he in &table {
+            assert!(
+                [2, 4, 6, 8, 10].contains(&he.hearts),
+                "{} has an off-milestone event at {} hearts",
+                he.npc_id,
+                he.hearts
+            );
+            assert!(!he.script.is_empty());
+            assert!(keys.insert(he.key()), "duplicate heart event {}", he.key());
+        }
+    }
+
+    #[test]
+    fn crossing_threshold_queues_heart_event_once() {
+        let mut app = setup_app();
+
+        // Below the 2-heart threshold nothing plays.
+        app.world_mut()
+            .resource_mut::<Relationships>()
+            .add_friendship("lily", 150);
+        enter_map(&mut app, MapId::Town);
+        assert!(app.world().resource::<CutsceneQueue>().steps.is_empty());
+
+        // Crossing it queues the scene on the next visit.
+        app.world_mut()
+            .resource_mut::<Relationships>()
+            .add_friendship("lily", 60);
+        enter_map(&mut app, MapId::Town);
+        let queued = app.world().resource::<CutsceneQueue>().steps.len();
+        assert_eq!(queued, heart_event_table()[0].script.len());
+        assert!(app
+            .world()
+            .resource::<HeartEventLog>()
+            .fired
+            .contains("lily:2"));
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Cutscene
+        );
+
+        // Once played, re-entering the map does not queue it again.
+        {
+            let mut queue = app.world_mut().resource_mut::<CutsceneQueue>();
+            queue.steps.clear();
+            queue.active = false;
+        }
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        enter_map(&mut app, MapId::Town);
+        assert!(app.world().resource::<CutsceneQueue>().steps.is_empty());
+    }
+}
@@ -13,11 +13,11 @@ pub struct GiftDecayTracker {
     pub days_since_gift: HashMap<NpcId, u32>,
 }
 
-/// System: handle MapTransitionEvent — despawn old map NPCs, spawn new map NPCs.
+/// System: handle HonoredMapTransitionEvent — despawn old map NPCs, spawn new map NPCs.
 #[allow(clippy::too_many_arguments)]
 pub fn handle_map_transition(
     mut commands: Commands,
-    mut transition_reader: EventReader<MapTransitionEvent>,
+    mut transition_reader: EventReader<HonoredMapTransitionEvent>,
     mut spawned: ResMut<SpawnedNpcs>,
     npc_map_tags: Query<(Entity, &NpcMapTag)>,
     calendar: Res<Calendar>,
@@ -62,19 +62,16 @@ pub fn handle_day_end(
     mut day_end_reader: EventReader<DayEndEvent>,
     mut relationships: ResMut<Relationships>,
     mut decay_tracker: ResMut<GiftDecayTracker>,
+    mut gift_history: ResMut<GiftHistory>,
     npc_registry: Res<NpcRegistry>,
     mut toast_writer: EventWriter<ToastEvent>,
 ) {
-    for _event in day_end_reader.read() {
+    for event in day_end_reader.read() {
         // Process each known NPC for decay
         let npc_ids: Vec<NpcId> = npc_registry.npcs.keys().cloned().collect();
 
         for npc_id in &npc_ids {
-            let gifted_today = relationships
-                .gifted_today
-                .get(npc_id.as_str())
-                .copied()
-                .unwrap_or(false);
+            let gifted_today = relationships.has_gifted_today(npc_id);
 
             if gifted_today {
                 // Player gifted this NPC today — reset the drought counter
@@ -130,5 +127,16 @@ pub fn handle_day_end(
 
         // Clear gifted_today after processing decay (so the state is fresh for the next day)
         relationships.gifted_today.clear();
+
+        // The weekly gift log rolls over once Sunday is done.
+        let ended = Calendar {
+            day: event.day,
+            season: event.season,
+            year: event.year,
+            ..default()
+        };
+        if ended.day_of_week() == DayOfWeek::Sunday {
+            gift_history.this_week.clear();
+        }
     }
 }
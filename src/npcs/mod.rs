@@ -7,10 +7,12 @@ use crate::shared::*;
 use bevy::prelude::*;
 
 mod animation;
+mod bump;
 pub mod definitions;
 pub mod dialogue;
 pub mod emotes;
 mod gifts;
+pub mod heart_events;
 pub mod idle_behavior;
 pub mod map_events;
 pub mod quests;
@@ -20,9 +22,11 @@ pub mod schedules;
 pub mod spawning;
 
 use animation::animate_npc_sprites;
+use bump::{handle_npc_bumps, NpcBumpTracker};
 use dialogue::{handle_npc_interaction, reset_daily_talks, ActiveNpcInteraction, DailyTalkTracker};
 use emotes::{animate_emote_bubbles, spawn_emote_bubbles, EmoteSprites, NpcEmoteEvent};
 use gifts::{handle_gift_input, handle_gifts};
+use heart_events::{trigger_heart_events, HeartEventLog};
 use idle_behavior::{attach_npc_shadows, npc_idle_behavior_system, ShadowSpriteCache};
 use map_events::{handle_day_end, handle_map_transition, GiftDecayTracker};
 use quests::{
@@ -31,8 +35,9 @@ use quests::{
     track_quest_progress,
 };
 use romance::{
-    handle_bouquet, handle_proposal, handle_spouse_action, handle_wedding, spouse_daily_action,
-    tick_wedding_timer, update_relationship_stages, update_spouse_happiness, WeddingTimer,
+    handle_bouquet, handle_proposal, handle_spouse_action, handle_spouse_gift, handle_wedding,
+    spouse_daily_action, tick_wedding_timer, update_relationship_stages, update_spouse_happiness,
+    WeddingTimer,
 };
 use schedule::{move_npcs_toward_targets, update_npc_schedules, ScheduleUpdateTimer};
 use schedules::{
@@ -40,7 +45,8 @@ use schedules::{
     FarmVisitTracker,
 };
 use spawning::{
-    preload_npc_sprites, spawn_initial_npcs, spawn_mayor_for_intro, NpcSpriteData, SpawnedNpcs,
+    preload_npc_sprites, spawn_initial_npcs, spawn_mayor_for_intro, update_npc_sleep,
+    NpcSpriteData, SpawnedNpcs,
 };
 
 pub struct NpcPlugin;
@@ -55,9 +61,11 @@ impl Plugin for NpcPlugin {
             .init_resource::<ScheduleUpdateTimer>()
             .init_resource::<GiftDecayTracker>()
             .init_resource::<WeddingTimer>()
+            .init_resource::<HeartEventLog>()
             .init_resource::<FarmVisitTracker>()
             .init_resource::<EmoteSprites>()
             .init_resource::<ShadowSpriteCache>()
+            .init_resource::<NpcBumpTracker>()
             .add_event::<NpcEmoteEvent>();
 
         // NPC data is populated by DataPlugin during OnEnter(Loading).
@@ -81,13 +89,14 @@ impl Plugin for NpcPlugin {
             spawn_mayor_for_intro.run_if(in_state(GameState::Cutscene)),
         );
 
-        // NPC interaction runs before the world interaction dispatcher so NPCs
-        // take priority over world objects when both are within range.
+        // NPC interaction only answers presses resolved to an NPC, so it
+        // runs after target resolution and before the world dispatcher.
         app.add_systems(
             Update,
             handle_npc_interaction
                 .in_set(UpdatePhase::Intent)
                 .run_if(in_state(GameState::Playing))
+                .after(crate::player::interact_dispatch::resolve_interaction_target)
                 .before(crate::player::interact_dispatch::dispatch_world_interaction),
         );
 
@@ -106,14 +115,17 @@ impl Plugin for NpcPlugin {
                 move_npcs_toward_targets,
                 animate_npc_sprites,
                 handle_gift_input,
+                handle_spouse_gift.before(handle_gifts),
                 handle_gifts,
                 handle_map_transition,
+                update_npc_sleep.after(handle_map_transition),
                 handle_day_end,
                 refresh_schedules_on_season_change,
                 check_farm_visits,
                 spawn_emote_bubbles,
                 animate_emote_bubbles,
                 npc_idle_behavior_system,
+                handle_npc_bumps.after(npc_idle_behavior_system),
                 attach_npc_shadows,
                 reset_daily_talks,
             )
@@ -126,6 +138,7 @@ impl Plugin for NpcPlugin {
             Update,
             (
                 update_relationship_stages,
+                trigger_heart_events,
                 handle_bouquet,
                 handle_proposal,
                 tick_wedding_timer,
@@ -137,7 +150,6 @@ impl Plugin for NpcPlugin {
                 post_seasonal_quests,
                 check_story_quests,
                 log_quest_posted,
-                handle_quest_accepted,
                 track_quest_progress,
                 track_monster_slain,
                 handle_quest_completed,
@@ -146,5 +158,13 @@ impl Plugin for NpcPlugin {
                 .in_set(UpdatePhase::Reactions)
                 .run_if(in_state(GameState::Playing)),
         );
+
+        // Quests are accepted from the board screen as well as by story triggers.
+        app.add_systems(
+            Update,
+            handle_quest_accepted
+                .in_set(UpdatePhase::Reactions)
+                .run_if(in_state(GameState::Playing).or(in_state(GameState::QuestBoard))),
+        );
     }
 }
@@ -383,17 +383,18 @@ pub fn log_quest_posted(mut events: EventReader<QuestPostedEvent>) {
 }
 
 /// Listens to `DayEndEvent` and generates 2-3 random quests for the next day.
-/// Each quest is posted via `QuestPostedEvent` and added to `QuestLog.active`.
+/// Each quest is posted via `QuestPostedEvent` and pinned to the bulletin
+/// board (`QuestLog.posted`), replacing yesterday's unaccepted quests.
 pub fn post_daily_quests(
     mut day_end_events: EventReader<DayEndEvent>,
     mut quest_posted: EventWriter<QuestPostedEvent>,
-    mut accepted_events: EventWriter<QuestAcceptedEvent>,
     mut quest_log: ResMut<QuestLog>,
     calendar: Res<Calendar>,
     npc_registry: Res<NpcRegistry>,
 ) {
     for _event in day_end_events.read() {
         let mut rng = rand::thread_rng();
+        quest_log.posted.clear();
 
         // Generate 2-3 quests
         let quest_count = rng.gen_range(2u8..=3);
@@ -436,7 +437,6 @@ pub fn post_daily_quests(
                 };
 
             let quest_id = make_quest_id(next_day, &next_season, next_year, i);
-            let quest_id_clone = quest_id.clone();
             let deadline = rng.gen_range(3u8..=7);
 
             // Pick a random NPC as the quest giver
@@ -612,14 +612,11 @@ pub fn post_daily_quests(
                 }
             };
 
-            // Fire event and add to quest log
+            // Fire event and pin to the board
             quest_posted.send(QuestPostedEvent {
                 quest: quest.clone(),
             });
-            quest_log.active.push(quest);
-            accepted_events.send(QuestAcceptedEvent {
-                quest_id: quest_id_clone,
-            });
+            quest_log.posted.push(quest);
         }
     }
 }
@@ -629,16 +626,34 @@ pub fn post_daily_quests(
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Reads `QuestAcceptedEvent` and marks a quest as accepted.
-/// In our model, quests posted via `post_daily_quests` are already in
-/// `QuestLog.active`, so this is a confirmation/no-op if already active.
-/// If a UI later separates "posted" from "accepted", this system would
-/// move the quest between lists.
+/// Quests taken from the bulletin board move from `QuestLog.posted` into
+/// `QuestLog.active`, unless `MAX_ACTIVE_QUESTS` are already in progress.
+/// Seasonal and story quests are pushed straight into `active` before the
+/// event is sent, so for them this only confirms the acceptance.
 pub fn handle_quest_accepted(
     mut accepted_events: EventReader<QuestAcceptedEvent>,
-    quest_log: Res<QuestLog>,
+    mut quest_log: ResMut<QuestLog>,
+    calendar: Res<Calendar>,
     mut toast_writer: EventWriter<ToastEvent>,
 ) {
     for event in accepted_events.read() {
+        if let Some(index) = quest_log.posted.iter().position(|q| q.id == event.quest_id) {
+            if quest_log.active.len() >= MAX_ACTIVE_QUESTS {
+                toast_writer.send(ToastEvent {
+                    message: format!("You already have {} quests in progress.", MAX_ACTIVE_QUESTS),
+                    duration_secs: 3.0,
+                });
+                continue;
+            }
+            let mut quest = quest_log.posted.remove(index);
+            quest.accepted_day = (
+                calendar.day,
+                season_to_idx(&calendar.season),
+                calendar.year as u16,
+            );
+            quest_log.active.push(quest);
+        }
+
         // Check if quest is already in active list
         if let Some(quest) = quest_log.active.iter().find(|q| q.id == event.quest_id) {
             toast_writer.send(ToastEvent {
@@ -658,8 +673,11 @@ pub fn handle_quest_accepted(
 ///
 /// Tracked events:
 /// - `CropHarvestedEvent` -> `QuestObjective::Harvest`
-/// - `ItemPickupEvent` -> `QuestObjective::Deliver`, `QuestObjective::Mine`, `QuestObjective::Catch`
+/// - `ItemPickupEvent` -> `QuestObjective::Mine`, `QuestObjective::Catch`
 /// - `DialogueStartEvent` -> `QuestObjective::Talk` (talking to the NPC)
+///
+/// `QuestObjective::Deliver` only advances when the items are handed over in
+/// person; see `turn_in_deliveries`.
 pub fn track_quest_progress(
     mut crop_events: EventReader<CropHarvestedEvent>,
     mut item_events: EventReader<ItemPickupEvent>,
@@ -690,22 +708,10 @@ pub fn track_quest_progress(
         }
     }
 
-    // --- ItemPickupEvent -> Deliver, Mine, and Catch objectives ---
+    // --- ItemPickupEvent -> Mine and Catch objectives ---
     for event in item_events.read() {
         for quest in quest_log.active.iter_mut() {
             match &mut quest.objective {
-                QuestObjective::Deliver {
-                    ref item_id,
-                    quantity,
-                    ref mut delivered,
-                } => {
-                    if *item_id == event.item_id {
-                        *delivered = (*delivered + event.quantity).min(*quantity);
-                        if *delivered >= *quantity {
-                            newly_completed.push((quest.id.clone(), quest.reward_gold));
-                        }
-                    }
-                }
                 QuestObjective::Mine {
                     ref item_id,
                     quantity,
@@ -764,6 +770,62 @@ pub fn track_quest_progress(
     }
 }
 
+/// Items handed to an NPC for one of their Deliver quests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryTurnIn {
+    pub quest_id: String,
+    pub item_id: ItemId,
+    /// How many were taken from the inventory this time.
+    pub handed_over: u8,
+    /// How many the quest still needs after this hand-over.
+    pub still_needed: u8,
+    pub reward_gold: u32,
+}
+
+impl DeliveryTurnIn {
+    pub fn completes_quest(&self) -> bool {
+        self.still_needed == 0
+    }
+}
+
+/// Hand over items for every active Deliver quest that `npc_id` gave out.
+/// Each quest takes as many as the player carries, up to what it still
+/// needs, so deliveries can be split across visits.
+pub fn turn_in_deliveries(
+    npc_id: &str,
+    quest_log: &mut QuestLog,
+    inventory: &mut Inventory,
+) -> Vec<DeliveryTurnIn> {
+    let mut turn_ins = Vec::new();
+    for quest in quest_log.active.iter_mut().filter(|q| q.giver == npc_id) {
+        let QuestObjective::Deliver {
+            ref item_id,
+            quantity,
+            ref mut delivered,
+        } = quest.objective
+        else {
+            continue;
+        };
+        let wanted = quantity.saturating_sub(*delivered);
+        if wanted == 0 {
+            continue;
+        }
+        let handed_over = inventory.try_remove(item_id, wanted);
+        if handed_over == 0 {
+            continue;
+        }
+        *delivered += handed_over;
+        turn_ins.push(DeliveryTurnIn {
+            quest_id: quest.id.clone(),
+            item_id: item_id.clone(),
+            handed_over,
+            still_needed: quantity - *delivered,
+            reward_gold: quest.reward_gold,
+        });
+    }
+    turn_ins
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // System 4: handle_quest_completed
 // ─────────────────────────────────────────────────────────────────────────────
@@ -534,8 +534,7 @@ pub fn update_spouse_happiness(
         // Check if the spouse was interacted with today (gift or conversation)
         let spouse_id = relationships.spouse.as_deref();
         let talked_today = spouse_id.is_some_and(|id| {
-            relationships.gifted_today.get(id).copied().unwrap_or(false)
-                || daily_talks.talked.contains(id)
+            relationships.has_gifted_today(id) || daily_talks.talked.contains(id)
         });
 
         let old_happiness = marriage_state.spouse_happiness;
@@ -569,6 +568,45 @@ pub fn update_spouse_happiness(
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════
+// SYSTEM 9: handle_spouse_gift
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Spouse happiness change for a gift of the given preference.
+pub fn spouse_gift_happiness(preference: GiftPreference) -> i16 {
+    match preference {
+        GiftPreference::Loved => 10,
+        GiftPreference::Liked => 5,
+        GiftPreference::Neutral => 2,
+        GiftPreference::Disliked => -5,
+        GiftPreference::Hated => -10,
+    }
+}
+
+/// GiftGivenEvent listener. The spouse's daily gift also moves
+/// `MarriageState::spouse_happiness`. Runs before `handle_gifts` so that
+/// `gifted_today` still reflects whether this is the first gift of the day.
+pub fn handle_spouse_gift(
+    mut gift_reader: EventReader<GiftGivenEvent>,
+    relationships: Res<Relationships>,
+    mut marriage_state: ResMut<MarriageState>,
+) {
+    for ev in gift_reader.read() {
+        if marriage_state.spouse.is_none() || relationships.spouse.as_ref() != Some(&ev.npc_id) {
+            continue;
+        }
+        // Only the first gift of the day moves happiness, even when the
+        // gift limit allows more.
+        if relationships.has_gifted_today(&ev.npc_id) {
+            continue;
+        }
+
+        marriage_state.spouse_happiness = (marriage_state.spouse_happiness
+            + spouse_gift_happiness(ev.preference))
+        .clamp(-100, 100);
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // HELPERS
 // ═══════════════════════════════════════════════════════════════════════
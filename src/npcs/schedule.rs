@@ -2,11 +2,12 @@
 
 use super::spawning::NpcMovement;
 use crate::shared::*;
+use crate::world::pathfind::astar;
+use crate::world::WorldMap;
 use bevy::prelude::*;
 
-/// Given the current calendar state, return the active schedule entry for an NPC.
-pub fn current_schedule_entry(calendar: &Calendar, schedule: &NpcSchedule) -> ScheduleEntry {
-    let time = calendar.time_float();
+/// The entry list that applies today: festival > rain > weekend > weekday.
+fn todays_entries<'a>(calendar: &Calendar, schedule: &'a NpcSchedule) -> &'a [ScheduleEntry] {
     let is_weekend = matches!(
         calendar.day_of_week(),
         DayOfWeek::Saturday | DayOfWeek::Sunday
@@ -18,7 +19,7 @@ pub fn current_schedule_entry(calendar: &Calendar, schedule: &NpcSchedule) -> Sc
     let is_festival = calendar.is_festival_day();
 
     // Priority: festival > rain > weekend > weekday
-    let entries = if is_festival {
+    if is_festival {
         if let Some(ref fest) = schedule.festival_override {
             fest
         } else if is_weekend {
@@ -38,7 +39,13 @@ pub fn current_schedule_entry(calendar: &Calendar, schedule: &NpcSchedule) -> Sc
         &schedule.weekend
     } else {
         &schedule.weekday
-    };
+    }
+}
+
+/// Given the current calendar state, return the active schedule entry for an NPC.
+pub fn current_schedule_entry(calendar: &Calendar, schedule: &NpcSchedule) -> ScheduleEntry {
+    let time = calendar.time_float();
+    let entries = todays_entries(calendar, schedule);
 
     // Find the latest entry whose time <= current time
     let mut active: Option<&ScheduleEntry> = None;
@@ -63,11 +70,34 @@ pub fn current_schedule_entry(calendar: &Calendar, schedule: &NpcSchedule) -> Sc
         })
 }
 
+/// Whether the NPC is home asleep: past their bedtime, or it is still before
+/// the first entry of their day.
+pub fn is_asleep(calendar: &Calendar, schedule: &NpcSchedule) -> bool {
+    let Some(bedtime) = schedule.bedtime else {
+        return false;
+    };
+    let time = calendar.time_float();
+    time >= bedtime
+        || todays_entries(calendar, schedule)
+            .first()
+            .is_some_and(|first| time < first.time)
+}
+
+/// The entry an NPC should be walking toward right now, or `None` while they
+/// are home asleep and absent from every map.
+pub fn active_schedule_target(
+    calendar: &Calendar,
+    schedule: &NpcSchedule,
+) -> Option<ScheduleEntry> {
+    (!is_asleep(calendar, schedule)).then(|| current_schedule_entry(calendar, schedule))
+}
+
 /// System: update NPC target positions based on current schedule, then move them.
 pub fn update_npc_schedules(
     calendar: Res<Calendar>,
     npc_registry: Res<NpcRegistry>,
     player_state: Res<PlayerState>,
+    world_map: Res<WorldMap>,
     mut query: Query<(&Npc, &mut NpcMovement, &LogicalPosition)>,
 ) {
     let current_map = player_state.current_map;
@@ -79,7 +109,9 @@ pub fn update_npc_schedules(
             continue;
         };
 
-        let entry = current_schedule_entry(&calendar, schedule);
+        let Some(entry) = active_schedule_target(&calendar, schedule) else {
+            continue;
+        };
 
         // Only update target if on the right map
         if entry.map == current_map {
@@ -92,9 +124,24 @@ pub fn update_npc_schedules(
             let dy = target_y - logical_pos.0.y;
             let dist_sq = dx * dx + dy * dy;
 
+            // Re-plan only when the destination changes, so the A* search
+            // runs once per schedule step rather than every tick.
+            let target_changed = movement.target_x != target_x || movement.target_y != target_y;
             movement.target_x = target_x;
             movement.target_y = target_y;
             movement.is_moving = dist_sq > 4.0; // threshold: 2 pixels
+
+            if target_changed && movement.is_moving {
+                let start = world_to_grid(logical_pos.0.x, logical_pos.0.y);
+                // No route (e.g. map not loaded yet): walk straight as before.
+                movement.path = astar((start.x, start.y), (entry.x, entry.y), |x, y| {
+                    world_map.is_walkable(x, y)
+                })
+                .unwrap_or_default()
+                .into();
+            } else if !movement.is_moving {
+                movement.path.clear();
+            }
         }
     }
 }
@@ -114,15 +161,17 @@ pub fn move_npcs_toward_targets(
         let current_x = logical_pos.0.x;
         let current_y = logical_pos.0.y;
 
-        let dx = movement.target_x - current_x;
-        let dy = movement.target_y - current_y;
+        let waypoint = movement.next_waypoint();
+        let dx = waypoint.x - current_x;
+        let dy = waypoint.y - current_y;
         let dist = (dx * dx + dy * dy).sqrt();
 
         if dist < 2.0 {
-            // Snap to target
-            logical_pos.0.x = movement.target_x;
-            logical_pos.0.y = movement.target_y;
-            movement.is_moving = false;
+            // Snap to the waypoint; stop once the final target is reached.
+            logical_pos.0 = waypoint;
+            if movement.path.pop_front().is_none() {
+                movement.is_moving = false;
+            }
         } else {
             // Move at NPC speed
             let step = (movement.speed * dt).min(dist);
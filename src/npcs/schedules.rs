@@ -381,6 +381,7 @@ fn mayor_rex_schedule(season: Season) -> NpcSchedule {
             24,
             18,
         )),
+        bedtime: Some(22.0),
     }
 }
 
@@ -526,6 +527,7 @@ fn margaret_schedule(season: Season) -> NpcSchedule {
             22,
             18,
         )),
+        bedtime: Some(22.0),
     }
 }
 
@@ -684,6 +686,7 @@ fn elena_schedule(season: Season) -> NpcSchedule {
             22,
             18,
         )),
+        bedtime: Some(22.0),
     }
 }
 
@@ -820,6 +823,7 @@ fn doc_schedule(season: Season) -> NpcSchedule {
         festival_override: Some(festival_override_for_season(
             season, home_map, home_x, home_y, 22, 18,
         )),
+        bedtime: Some(22.0),
     }
 }
 
@@ -982,6 +986,7 @@ fn old_tom_schedule(season: Season) -> NpcSchedule {
         festival_override: Some(festival_override_for_season(
             season, home_map, home_x, home_y, 18, 18,
         )),
+        bedtime: Some(21.0),
     }
 }
 
@@ -1127,6 +1132,7 @@ fn marco_schedule(season: Season) -> NpcSchedule {
             22,
             18,
         )),
+        bedtime: Some(23.0),
     }
 }
 
@@ -1269,6 +1275,7 @@ fn sam_schedule(season: Season) -> NpcSchedule {
         festival_override: Some(festival_override_for_season(
             season, home_map, home_x, home_y, 20, 18,
         )),
+        bedtime: Some(21.0),
     }
 }
 
@@ -1407,6 +1414,7 @@ fn mira_schedule(season: Season) -> NpcSchedule {
         festival_override: Some(festival_override_for_season(
             season, home_map, home_x, home_y, 22, 18,
         )),
+        bedtime: Some(22.0),
     }
 }
 
@@ -1564,6 +1572,7 @@ fn nora_schedule(season: Season) -> NpcSchedule {
             22,
             18,
         )),
+        bedtime: Some(21.0),
     }
 }
 
@@ -1740,6 +1749,7 @@ fn lily_schedule(season: Season) -> NpcSchedule {
             22,
             18,
         )),
+        bedtime: Some(21.0),
     }
 }
 
@@ -1858,4 +1868,24 @@ mod tests {
             "Margaret should visit Beach in summer evenings"
         );
     }
+
+    #[test]
+    fn test_npcs_sleep_from_bedtime_until_morning() {
+        use crate::npcs::schedule::is_asleep;
+        let at = |hour: u8, minute: u8| Calendar {
+            hour,
+            minute,
+            ..Default::default()
+        };
+        let mut sched = enhanced_schedule("margaret", Season::Spring).unwrap();
+        assert_eq!(sched.bedtime, Some(22.0));
+
+        assert!(!is_asleep(&at(6, 0), &sched), "up with the first entry");
+        assert!(!is_asleep(&at(21, 50), &sched));
+        assert!(is_asleep(&at(22, 0), &sched));
+        assert!(is_asleep(&at(25, 0), &sched), "still asleep past midnight");
+
+        sched.bedtime = None;
+        assert!(!is_asleep(&at(25, 0), &sched));
+    }
 }
@@ -4,7 +4,7 @@
 use super::animation::NpcAnimationTimer;
 use super::definitions::{npc_color, npc_sprite_file, ALL_NPC_IDS};
 use super::idle_behavior::NpcIdleBehavior;
-use super::schedule::current_schedule_entry;
+use super::schedule::{active_schedule_target, is_asleep};
 use crate::shared::*;
 use crate::ui::cutscene_runner::CutsceneFlags;
 use bevy::prelude::*;
@@ -16,6 +16,9 @@ pub struct NpcMovement {
     pub target_y: f32,
     pub speed: f32,
     pub is_moving: bool,
+    /// Tiles still to walk through on the way to the target, nearest first.
+    /// Empty when the NPC walks straight at the target.
+    pub path: std::collections::VecDeque<(i32, i32)>,
 }
 
 impl Default for NpcMovement {
@@ -25,6 +28,18 @@ impl Default for NpcMovement {
             target_y: 0.0,
             speed: 40.0,
             is_moving: false,
+            path: std::collections::VecDeque::new(),
+        }
+    }
+}
+
+impl NpcMovement {
+    /// The point the NPC is currently walking toward: the next path tile, or
+    /// the target itself once the path is used up.
+    pub fn next_waypoint(&self) -> Vec2 {
+        match self.path.front() {
+            Some(&(x, y)) => grid_to_world_center(x, y),
+            None => Vec2::new(self.target_x, self.target_y),
         }
     }
 }
@@ -151,7 +166,10 @@ pub fn spawn_npcs_for_map(
             continue;
         };
 
-        let entry = current_schedule_entry(calendar, schedule);
+        // NPCs asleep at home aren't on any map.
+        let Some(entry) = active_schedule_target(calendar, schedule) else {
+            continue;
+        };
 
         // Only spawn on the correct map
         if entry.map != map {
@@ -200,8 +218,7 @@ pub fn spawn_npcs_for_map(
                 NpcMovement {
                     target_x: world_x,
                     target_y: world_y,
-                    speed: 40.0,
-                    is_moving: false,
+                    ..default()
                 },
                 NpcAnimationTimer {
                     timer: Timer::from_seconds(0.15, TimerMode::Repeating),
@@ -240,6 +257,57 @@ pub fn spawn_npcs_for_map(
     }
 }
 
+/// System: put NPCs to bed and wake them as the clock passes their schedule's
+/// sleep entries. Sleeping NPCs are despawned so they can't be talked to;
+/// anyone who wakes up is spawned back in if their morning spot is on the
+/// player's current map.
+#[allow(clippy::too_many_arguments)]
+pub fn update_npc_sleep(
+    mut commands: Commands,
+    calendar: Res<Calendar>,
+    player_state: Res<PlayerState>,
+    npc_registry: Res<NpcRegistry>,
+    mut spawned: ResMut<SpawnedNpcs>,
+    asset_server: Res<AssetServer>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+    mut npc_sprites: ResMut<NpcSpriteData>,
+    mut asleep: Local<std::collections::HashSet<String>>,
+) {
+    if !calendar.is_changed() {
+        return;
+    }
+
+    let mut woke_up = false;
+    for &npc_id in ALL_NPC_IDS {
+        let Some(schedule) = npc_registry.schedules.get(npc_id) else {
+            continue;
+        };
+        let sleeping = is_asleep(&calendar, schedule);
+        if sleeping {
+            if asleep.insert(npc_id.to_string()) {
+                if let Some(entity) = spawned.entities.remove(npc_id) {
+                    commands.entity(entity).despawn_recursive();
+                }
+            }
+        } else if asleep.remove(npc_id) {
+            woke_up = true;
+        }
+    }
+
+    if woke_up {
+        spawn_npcs_for_map(
+            &mut commands,
+            &calendar,
+            player_state.current_map,
+            &npc_registry,
+            &mut spawned,
+            &asset_server,
+            &mut layouts,
+            &mut npc_sprites,
+        );
+    }
+}
+
 /// System: force-spawn Mayor Rex on the Farm during the intro cutscene.
 ///
 /// Runs during `Cutscene` state. When the `mayor_intro_visit` flag is set
@@ -321,8 +389,7 @@ pub fn spawn_mayor_for_intro(
                 NpcMovement {
                     target_x: world_x,
                     target_y: world_y,
-                    speed: 40.0,
-                    is_moving: false,
+                    ..default()
                 },
                 NpcAnimationTimer {
                     timer: Timer::from_seconds(0.15, TimerMode::Repeating),
@@ -3,6 +3,37 @@ use crate::shared::*;
 use crate::world::WorldMap;
 use bevy::prelude::*;
 
+/// Cycle the camera zoom with Z. The new level is stored in `GameSettings`,
+/// so it persists with the other settings.
+pub fn cycle_camera_zoom(
+    player_input: Res<PlayerInput>,
+    input_blocks: Res<InputBlocks>,
+    mut settings: ResMut<GameSettings>,
+) {
+    if input_blocks.is_blocked() || !player_input.zoom_cycle {
+        return;
+    }
+    settings.cycle_camera_zoom();
+}
+
+/// Apply the zoom setting to the camera scale whenever settings change.
+/// Zoom levels are whole numbers, so the rounded camera translation below
+/// always falls on a whole screen pixel.
+pub fn apply_camera_zoom(
+    settings: Res<GameSettings>,
+    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    let scale = camera_scale(settings.camera_zoom);
+    for mut cam_tf in &mut camera_query {
+        if cam_tf.scale.x != scale {
+            cam_tf.scale = Vec3::splat(scale);
+        }
+    }
+}
+
 /// Smoothly follow the player with the camera using a lerp, clamped to map bounds.
 /// On map transitions, snaps instantly for 3 frames (ensures WorldMap bounds are
 /// updated before the final clamp).
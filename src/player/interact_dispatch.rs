@@ -1,10 +1,14 @@
 //! World Interaction Dispatcher (F key)
 //!
-//! Single system that finds the nearest `Interactable` entity within range,
-//! dispatches the appropriate event, and sets `InteractionClaimed` so that
-//! legacy F-key systems skip.
+//! `resolve_interaction_target` picks the one entity an interact press is
+//! aimed at — the nearest NPC, `Interactable`, chest or fish tank the player
+//! is facing — and records it in `InteractionTarget`. The dispatcher below
+//! handles `Interactable` targets, dispatches the appropriate event, and sets
+//! `InteractionClaimed` so that legacy F-key systems skip.
 
 use crate::shared::*;
+use crate::world::chests::ChestMarker;
+use crate::world::fish_tanks::FishTank;
 use bevy::prelude::*;
 
 // Domain event imports — use pub re-exports from domain mod.rs.
@@ -13,13 +17,103 @@ use crate::crafting::{
 };
 use crate::economy::shipping::ShipItemEvent;
 
+// ═══════════════════════════════════════════════════════════════════════
+// TARGET RESOLUTION
+// ═══════════════════════════════════════════════════════════════════════
+
+/// How far away each kind of target can be interacted with.
+fn target_range(kind: InteractTargetKind) -> f32 {
+    match kind {
+        InteractTargetKind::Chest => TILE_SIZE * 2.0,
+        InteractTargetKind::Npc | InteractTargetKind::Object | InteractTargetKind::FishTank => {
+            TILE_SIZE * 1.5
+        }
+    }
+}
+
+/// Tie-break between equally near targets; lower wins. NPCs come first so
+/// talking to someone standing on a chest or bin still works.
+fn target_priority(kind: InteractTargetKind) -> u8 {
+    match kind {
+        InteractTargetKind::Npc => 0,
+        InteractTargetKind::Object => 1,
+        InteractTargetKind::Chest => 2,
+        InteractTargetKind::FishTank => 3,
+    }
+}
+
+/// Pick the single nearest candidate in range that the player is facing,
+/// breaking distance ties by `target_priority`.
+pub fn pick_interaction_target(
+    player_pos: Vec2,
+    facing: Facing,
+    candidates: impl IntoIterator<Item = (Entity, InteractTargetKind, Vec2)>,
+) -> Option<(Entity, InteractTargetKind)> {
+    candidates
+        .into_iter()
+        .filter(|(_, kind, pos)| can_interact_facing(player_pos, facing, *pos, target_range(*kind)))
+        .min_by(|(_, kind_a, pos_a), (_, kind_b, pos_b)| {
+            player_pos
+                .distance(*pos_a)
+                .total_cmp(&player_pos.distance(*pos_b))
+                .then(target_priority(*kind_a).cmp(&target_priority(*kind_b)))
+        })
+        .map(|(entity, kind, _)| (entity, kind))
+}
+
+/// On an interact press, decide which single entity the press is for.
+/// Cleared every frame so a stale target never leaks into the next press.
+#[allow(clippy::too_many_arguments)]
+pub fn resolve_interaction_target(
+    player_input: Res<PlayerInput>,
+    input_blocks: Res<InputBlocks>,
+    player_query: Query<(&LogicalPosition, &PlayerMovement), With<Player>>,
+    npc_query: Query<(Entity, &Transform), With<Npc>>,
+    interactable_query: Query<(Entity, &Transform), With<Interactable>>,
+    chest_query: Query<(Entity, &Transform), With<ChestMarker>>,
+    tank_query: Query<(Entity, &Transform), With<FishTank>>,
+    mut target: ResMut<InteractionTarget>,
+) {
+    target.0 = None;
+    if input_blocks.is_blocked() || !player_input.interact {
+        return;
+    }
+    let Ok((player_pos, movement)) = player_query.get_single() else {
+        return;
+    };
+
+    let candidates = npc_query
+        .iter()
+        .map(|(e, tf)| (e, InteractTargetKind::Npc, tf.translation.truncate()))
+        .chain(
+            interactable_query
+                .iter()
+                .map(|(e, tf)| (e, InteractTargetKind::Object, tf.translation.truncate())),
+        )
+        .chain(
+            chest_query
+                .iter()
+                .map(|(e, tf)| (e, InteractTargetKind::Chest, tf.translation.truncate())),
+        )
+        .chain(
+            tank_query
+                .iter()
+                .map(|(e, tf)| (e, InteractTargetKind::FishTank, tf.translation.truncate())),
+        );
+    target.0 = pick_interaction_target(player_pos.0, movement.facing, candidates);
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// DISPATCH
+// ═══════════════════════════════════════════════════════════════════════
+
 #[allow(clippy::too_many_arguments)]
 pub fn dispatch_world_interaction(
     player_input: Res<PlayerInput>,
     input_blocks: Res<InputBlocks>,
     inventory: Res<Inventory>,
-    player_query: Query<&LogicalPosition, With<Player>>,
-    interactable_query: Query<(&Transform, &Interactable, Entity)>,
+    target: Res<InteractionTarget>,
+    interactable_query: Query<&Interactable>,
     mut interaction_claimed: ResMut<InteractionClaimed>,
     // Event writers
     mut ship_events: EventWriter<ShipItemEvent>,
@@ -33,26 +127,18 @@ pub fn dispatch_world_interaction(
     mut toast_events: EventWriter<ToastEvent>,
     // For bed "too early" guard
     calendar: Res<Calendar>,
+    mailbox: Res<Mailbox>,
+    quest_log: Res<QuestLog>,
 ) {
     if input_blocks.is_blocked() || !player_input.interact {
         return;
     }
 
-    let Ok(player_pos) = player_query.get_single() else {
+    // Only respond when the resolved target is a world object.
+    let Some(entity) = target.entity_of(InteractTargetKind::Object) else {
         return;
     };
-    let range = TILE_SIZE * 1.5;
-
-    // Find nearest interactable within range.
-    let mut best: Option<(f32, &Interactable, Entity)> = None;
-    for (tf, inter, entity) in &interactable_query {
-        let d = player_pos.0.distance(tf.translation.truncate());
-        if d <= range && best.as_ref().is_none_or(|b| d < b.0) {
-            best = Some((d, inter, entity));
-        }
-    }
-
-    let Some((_dist, interactable, entity)) = best else {
+    let Ok(interactable) = interactable_query.get(entity) else {
         return;
     };
 
@@ -71,7 +157,7 @@ pub fn dispatch_world_interaction(
             ship_events.send(ShipItemEvent {
                 item_id: slot.item_id.clone(),
                 quantity: 1,
-                quality: ItemQuality::Normal,
+                quality: slot.quality,
             });
         }
 
@@ -96,6 +182,7 @@ pub fn dispatch_world_interaction(
                             machine_entity: entity,
                             item_id: slot.item_id.clone(),
                             quantity: 1,
+                            quality: slot.quality,
                         });
                     }
                 }
@@ -112,6 +199,30 @@ pub fn dispatch_world_interaction(
             craft_events.send(OpenCraftingEvent { cooking_mode: true });
         }
 
+        InteractionKind::Mailbox => {
+            interaction_claimed.0 = true;
+            if mailbox.letters.is_empty() {
+                toast_events.send(ToastEvent {
+                    message: "The mailbox is empty.".into(),
+                    duration_secs: 2.0,
+                });
+            } else {
+                next_state.set(GameState::Mailbox);
+            }
+        }
+
+        InteractionKind::QuestBoard => {
+            interaction_claimed.0 = true;
+            if quest_log.posted.is_empty() {
+                toast_events.send(ToastEvent {
+                    message: "No new quests on the board today.".into(),
+                    duration_secs: 2.0,
+                });
+            } else {
+                next_state.set(GameState::QuestBoard);
+            }
+        }
+
         InteractionKind::Bed => {
             if calendar.hour < 18 {
                 interaction_claimed.0 = true;
@@ -126,3 +237,76 @@ pub fn dispatch_world_interaction(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolver_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<InputBlocks>()
+            .init_resource::<InteractionTarget>()
+            .insert_resource(PlayerInput {
+                interact: true,
+                ..Default::default()
+            })
+            .add_systems(Update, resolve_interaction_target);
+        app.world_mut().spawn((
+            Player,
+            LogicalPosition(Vec2::ZERO),
+            PlayerMovement {
+                facing: Facing::Right,
+                ..Default::default()
+            },
+        ));
+        app
+    }
+
+    fn at(x: f32) -> Transform {
+        Transform::from_xyz(x, 0.0, 0.0)
+    }
+
+    fn shipping_bin() -> Interactable {
+        Interactable {
+            kind: InteractionKind::ShippingBin,
+            label: "Shipping Bin".into(),
+        }
+    }
+
+    fn npc() -> Npc {
+        Npc {
+            id: "elena".into(),
+            name: "Elena".into(),
+        }
+    }
+
+    #[test]
+    fn overlapping_targets_resolve_to_the_npc() {
+        let mut app = resolver_app();
+        let npc = app.world_mut().spawn((npc(), at(TILE_SIZE))).id();
+        app.world_mut().spawn((ChestMarker, at(TILE_SIZE)));
+        app.world_mut().spawn((shipping_bin(), at(TILE_SIZE)));
+
+        app.update();
+
+        let target = *app.world().resource::<InteractionTarget>();
+        assert_eq!(target.0, Some((npc, InteractTargetKind::Npc)));
+        assert_eq!(target.entity_of(InteractTargetKind::Chest), None);
+        assert_eq!(target.entity_of(InteractTargetKind::Object), None);
+    }
+
+    #[test]
+    fn nearest_faced_target_wins_over_priority() {
+        let mut app = resolver_app();
+        let bin = app.world_mut().spawn((shipping_bin(), at(TILE_SIZE))).id();
+        app.world_mut().spawn((npc(), at(TILE_SIZE * 1.4)));
+        app.world_mut().spawn((ChestMarker, at(TILE_SIZE * 1.8)));
+        // Right next to the player, but behind them.
+        app.world_mut().spawn((npc(), at(-TILE_SIZE * 0.6)));
+
+        app.update();
+
+        let target = *app.world().resource::<InteractionTarget>();
+        assert_eq!(target.0, Some((bin, InteractTargetKind::Object)));
+    }
+}
@@ -375,18 +375,17 @@ pub fn map_transition_check(
     }
 }
 
-/// Handle incoming `MapTransitionEvent` — reposition player and update
+/// Handle incoming `HonoredMapTransitionEvent` — reposition player and update
 /// `PlayerState.current_map`. The world domain handles loading/despawning
 /// tiles; we only move the player.
 pub fn handle_map_transition(
-    mut events: EventReader<MapTransitionEvent>,
+    mut events: EventReader<HonoredMapTransitionEvent>,
     mut player_state: ResMut<PlayerState>,
     mut collision_map: ResMut<CollisionMap>,
     mut camera_snap: ResMut<super::CameraSnap>,
     mut query: Query<(&mut LogicalPosition, &mut GridPosition), With<Player>>,
     registry: Res<MapRegistry>,
 ) {
-    // Process only the most recent transition (in case multiple fire).
     let Some(ev) = events.read().last() else {
         return;
     };
@@ -467,6 +466,7 @@ pub fn item_pickup_check(
                 pickup_events.send(ItemPickupEvent {
                     item_id: crop.crop_id.clone(),
                     quantity: 1,
+                    quality: ItemQuality::Normal,
                 });
             }
         }
@@ -482,6 +482,7 @@ pub fn item_pickup_check(
 pub fn add_items_to_inventory(
     mut pickup_events: EventReader<ItemPickupEvent>,
     mut inventory: ResMut<Inventory>,
+    mut overflow: ResMut<OverflowStore>,
     item_registry: Res<ItemRegistry>,
     mut sfx_events: EventWriter<PlaySfxEvent>,
     mut toast_events: EventWriter<ToastEvent>,
@@ -491,7 +492,14 @@ pub fn add_items_to_inventory(
             .get(&ev.item_id)
             .map(|def| def.stack_size)
             .unwrap_or(99);
-        let remaining = inventory.try_add(&ev.item_id, ev.quantity, max_stack);
+        let remaining = grant_item(
+            &mut inventory,
+            &mut overflow,
+            &ev.item_id,
+            ev.quantity,
+            max_stack,
+            ev.quality,
+        );
         if remaining == 0 {
             sfx_events.send(PlaySfxEvent {
                 sfx_id: "item_pickup".to_string(),
@@ -503,33 +511,100 @@ pub fn add_items_to_inventory(
                 .map(|d| d.name.as_str())
                 .unwrap_or(&ev.item_id);
             toast_events.send(ToastEvent {
-                message: format!("Inventory full! Couldn't pick up {}.", name),
+                message: format!(
+                    "Inventory full! {} sent to the lost and found at the farmhouse.",
+                    name
+                ),
                 duration_secs: 3.0,
             });
             info!(
-                "[Player] Inventory full — could not pick up {} × '{}' ({} dropped)",
-                ev.quantity, ev.item_id, remaining
+                "[Player] Inventory full — {} × '{}' sent to the lost and found",
+                remaining, ev.item_id
             );
         }
     }
 }
 
+/// Hand lost-and-found items back when the player walks into the farmhouse,
+/// as many as the backpack has room for.
+pub fn collect_overflow_at_farmhouse(
+    player_state: Res<PlayerState>,
+    mut was_home: Local<bool>,
+    mut overflow: ResMut<OverflowStore>,
+    mut inventory: ResMut<Inventory>,
+    item_registry: Res<ItemRegistry>,
+    mut toast_events: EventWriter<ToastEvent>,
+) {
+    let home = player_state.current_map == MapId::PlayerHouse;
+    let arrived = home && !*was_home;
+    *was_home = home;
+    if !arrived || overflow.items.is_empty() {
+        return;
+    }
+
+    let returned = overflow.return_to(&mut inventory, |id| {
+        item_registry
+            .get(id)
+            .map(|def| def.stack_size)
+            .unwrap_or(99)
+    });
+    let message = match (returned, overflow.items.is_empty()) {
+        (0, _) => "Your lost and found is waiting, but your inventory is full.".to_string(),
+        (n, true) => format!("Collected {} items from the lost and found.", n),
+        (n, false) => format!(
+            "Collected {} items from the lost and found. The rest will wait until you have room.",
+            n
+        ),
+    };
+    toast_events.send(ToastEvent {
+        message,
+        duration_secs: 3.0,
+    });
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Day End Handling
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Share of gold lost when collapsing from exhaustion, and the cap on it.
+const COLLAPSE_GOLD_PENALTY_RATE: f32 = 0.10;
+const COLLAPSE_GOLD_PENALTY_MAX: u32 = 1_000;
+/// Fraction of max stamina the player wakes with after collapsing.
+const COLLAPSE_STAMINA_FRACTION: f32 = 0.5;
+/// Extra max stamina for the morning after going to bed before midnight.
+pub const WELL_RESTED_STAMINA_BONUS: f32 = 30.0;
+/// How long the well-rested bonus lasts, in game minutes (6 AM to 6 PM).
+pub const WELL_RESTED_MINUTES: u32 = 12 * 60;
+
+/// Gold lost for collapsing while carrying `gold`.
+pub fn collapse_gold_penalty(gold: u32) -> u32 {
+    ((gold as f32 * COLLAPSE_GOLD_PENALTY_RATE) as u32).min(COLLAPSE_GOLD_PENALTY_MAX)
+}
+
 /// When a day ends (player sleeps), restore stamina to maximum and
 /// reposition the player to their bed in the farmhouse.
 /// Sends a MapTransitionEvent so the world domain loads the PlayerHouse map.
+/// If the day ended in a collapse (2 AM or exhausted after midnight) the
+/// player wakes with half stamina and loses some gold, unless peaceful mode
+/// is on. Going to bed before midnight instead wakes the player well rested.
+#[allow(clippy::too_many_arguments)]
 pub fn handle_day_end(
     mut events: EventReader<DayEndEvent>,
     mut player_state: ResMut<PlayerState>,
     mut query: Query<(&mut LogicalPosition, &mut GridPosition), With<Player>>,
     mut map_events: EventWriter<MapTransitionEvent>,
+    mut collapse: ResMut<CollapsePending>,
+    mut well_rested: ResMut<WellRestedPending>,
+    mut active_buffs: ResMut<ActiveBuffs>,
+    mut gold_events: EventWriter<GoldChangeEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
+    settings: Res<GameSettings>,
 ) {
     for _ev in events.read() {
         // Restore stamina fully.
         player_state.stamina = player_state.max_stamina;
+        let collapsed = std::mem::take(&mut collapse.0) && !settings.peaceful;
+        let rested = std::mem::take(&mut well_rested.0) && !collapsed;
 
         // If the player is in the mine, the mining domain handles the transition
         // (with gold penalty and partial health restore). Skip here.
@@ -538,6 +613,35 @@ pub fn handle_day_end(
             continue;
         }
 
+        if collapsed {
+            player_state.stamina = player_state.max_stamina * COLLAPSE_STAMINA_FRACTION;
+            let gold_loss = collapse_gold_penalty(player_state.gold);
+            if gold_loss > 0 {
+                gold_events.send(GoldChangeEvent {
+                    amount: -(gold_loss as i32),
+                    reason: "Collapsed from exhaustion".to_string(),
+                });
+            }
+            toast_events.send(ToastEvent {
+                message: format!(
+                    "You collapsed from exhaustion and were carried home. Lost {}g.",
+                    gold_loss
+                ),
+                duration_secs: 4.0,
+            });
+        }
+
+        if rested {
+            grant_well_rested(&mut player_state, &mut active_buffs);
+            toast_events.send(ToastEvent {
+                message: format!(
+                    "You feel well rested! +{:.0} stamina today.",
+                    WELL_RESTED_STAMINA_BONUS
+                ),
+                duration_secs: 3.0,
+            });
+        }
+
         // Restore health fully.
         player_state.health = player_state.max_health;
 
@@ -566,18 +670,46 @@ pub fn handle_day_end(
     }
 }
 
+/// Apply the well-rested MaxStamina buff and wake the player with the extra
+/// stamina already filled. A leftover MaxStamina buff from yesterday's food
+/// is replaced, so its bonus is not counted twice.
+fn grant_well_rested(player_state: &mut PlayerState, active_buffs: &mut ActiveBuffs) {
+    let stale_bonus: f32 = active_buffs
+        .buffs
+        .iter()
+        .filter(|b| b.buff_type == BuffType::MaxStamina)
+        .map(|b| b.magnitude)
+        .sum();
+    active_buffs
+        .buffs
+        .retain(|b| b.buff_type != BuffType::MaxStamina);
+    active_buffs.buffs.push(FoodBuff {
+        buff_type: BuffType::MaxStamina,
+        magnitude: WELL_RESTED_STAMINA_BONUS,
+        minutes_remaining: WELL_RESTED_MINUTES,
+    });
+    player_state.stamina = player_state.max_stamina - stale_bonus + WELL_RESTED_STAMINA_BONUS;
+}
+
 /// Checks each frame whether stamina has reached zero at or past midnight
 /// (hour >= 24). If so, the player passes out and a `DayEndEvent` is sent.
+/// Nobody passes out in peaceful mode.
 pub fn check_stamina_consequences(
     player_state: Res<PlayerState>,
     calendar: Res<Calendar>,
+    settings: Res<GameSettings>,
     mut day_end_events: EventWriter<DayEndEvent>,
+    mut collapse: ResMut<CollapsePending>,
     mut has_passed_out: Local<bool>,
 ) {
+    if settings.peaceful {
+        return;
+    }
     if player_state.stamina <= 0.0 && calendar.hour >= 24 {
         // Only fire once per exhaustion episode; reset when stamina recovers.
         if !*has_passed_out {
             *has_passed_out = true;
+            collapse.0 = true;
             warn!(
                 "[Player] Passed out from exhaustion at hour {}! Ending the day.",
                 calendar.hour
@@ -594,6 +726,116 @@ pub fn check_stamina_consequences(
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// Bedroll
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Seconds the player has to press R again to confirm sleeping in a bedroll.
+const BEDROLL_CONFIRM_WINDOW: f32 = 3.0;
+/// Monsters within this many tiles count as combat and block the bedroll.
+const BEDROLL_COMBAT_RADIUS_TILES: f32 = 6.0;
+
+/// R with a bedroll selected ends the day on the spot. The first press asks
+/// for confirmation; a second press within a few seconds consumes the
+/// bedroll and sends `DayEndEvent`. The regular day-end handlers still wake
+/// the player in the farmhouse, and `BedrollRest` keeps the mine from
+/// treating the rest as passing out.
+#[allow(clippy::too_many_arguments)]
+pub fn use_bedroll(
+    player_input: Res<PlayerInput>,
+    input_blocks: Res<InputBlocks>,
+    time: Res<Time>,
+    calendar: Res<Calendar>,
+    mut inventory: ResMut<Inventory>,
+    mut bedroll_rest: ResMut<BedrollRest>,
+    mut cutscene_queue: ResMut<CutsceneQueue>,
+    player_query: Query<&LogicalPosition, With<Player>>,
+    monster_query: Query<&Transform, With<MineMonster>>,
+    mut day_end_events: EventWriter<DayEndEvent>,
+    mut removed_events: EventWriter<ItemRemovedEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
+    mut confirm_timer: Local<Option<f32>>,
+) {
+    if let Some(remaining) = confirm_timer.as_mut() {
+        *remaining -= time.delta_secs();
+        if *remaining <= 0.0 {
+            *confirm_timer = None;
+        }
+    }
+
+    if input_blocks.is_blocked() || cutscene_queue.active || !player_input.tool_secondary {
+        return;
+    }
+
+    let bedroll_selected = inventory
+        .slots
+        .get(inventory.selected_slot)
+        .and_then(|s| s.as_ref())
+        .is_some_and(|slot| slot.item_id == "bedroll");
+    if !bedroll_selected {
+        *confirm_timer = None;
+        return;
+    }
+
+    let Ok(player_pos) = player_query.get_single() else {
+        return;
+    };
+    let combat_range = TILE_SIZE * BEDROLL_COMBAT_RADIUS_TILES;
+    if monster_query
+        .iter()
+        .any(|tf| tf.translation.truncate().distance(player_pos.0) <= combat_range)
+    {
+        *confirm_timer = None;
+        toast_events.send(ToastEvent {
+            message: "You can't rest with monsters nearby!".into(),
+            duration_secs: 2.0,
+        });
+        return;
+    }
+
+    if confirm_timer.is_none() {
+        *confirm_timer = Some(BEDROLL_CONFIRM_WINDOW);
+        toast_events.send(ToastEvent {
+            message: "Sleep here until morning? Press again to confirm.".into(),
+            duration_secs: BEDROLL_CONFIRM_WINDOW,
+        });
+        return;
+    }
+    *confirm_timer = None;
+
+    if inventory.try_remove("bedroll", 1) == 0 {
+        return;
+    }
+    removed_events.send(ItemRemovedEvent {
+        item_id: "bedroll".into(),
+        quantity: 1,
+    });
+
+    info!(
+        "[Player] Slept in a bedroll at {}:{:02} on {:?}",
+        calendar.hour, calendar.minute, calendar.season
+    );
+    bedroll_rest.0 = Some((calendar.day, calendar.season, calendar.year));
+    day_end_events.send(DayEndEvent {
+        day: calendar.day,
+        season: calendar.season,
+        year: calendar.year,
+    });
+
+    // Short fade so the jump to the farmhouse reads as falling asleep.
+    // activate_pending_cutscene picks this up after DayEndEvent readers run.
+    if cutscene_queue.steps.is_empty() {
+        let mut steps = std::collections::VecDeque::new();
+        steps.push_back(CutsceneStep::FadeOut(1.2));
+        steps.push_back(CutsceneStep::ShowText(
+            "You roll out your bedroll and drift off...".into(),
+            2.0,
+        ));
+        steps.push_back(CutsceneStep::FadeIn(1.8));
+        cutscene_queue.steps = steps;
+    }
+}
+
 /// Grant starter items on first entering Playing state (inventory is empty).
 /// The intro dialogue mentions "seeds in your pack" so we deliver on that promise.
 pub fn grant_starter_items(mut inventory: ResMut<Inventory>, item_registry: Res<ItemRegistry>) {
@@ -10,6 +10,7 @@ use bevy::prelude::*;
 // Domain event imports — use pub re-exports from domain mod.rs.
 use crate::crafting::PlaceMachineEvent;
 use crate::farming::PlaceFarmObjectEvent;
+use crate::mining::STAIRCASE_ITEM_ID;
 
 /// Bundles all EventWriters used by `dispatch_item_use` to stay within Bevy's
 /// 16-parameter system limit.
@@ -67,6 +68,17 @@ pub fn dispatch_item_use(
         return;
     }
 
+    // ── STAIRCASE (handled by the mining domain while in the mine) ─
+    if item_id == STAIRCASE_ITEM_ID {
+        if player_state.current_map != MapId::Mine {
+            ev.toast.send(ToastEvent {
+                message: "A staircase can only be used in the mine.".into(),
+                duration_secs: 2.0,
+            });
+        }
+        return;
+    }
+
     // ── FOOD ──────────────────────────────────────────────────────
     if def.edible {
         // Send EatFoodEvent with buff: None — the handle_eat_food system
@@ -112,7 +124,14 @@ pub fn dispatch_item_use(
     // ── MACHINES ──────────────────────────────────────────────────
     if matches!(
         item_id.as_str(),
-        "furnace" | "preserves_jar" | "cheese_press" | "loom" | "keg" | "oil_maker"
+        "furnace"
+            | "preserves_jar"
+            | "cheese_press"
+            | "loom"
+            | "keg"
+            | "oil_maker"
+            | "seed_maker"
+            | "cask"
     ) {
         if player_state.current_map != MapId::Farm {
             ev.toast.send(ToastEvent {
@@ -129,8 +148,11 @@ pub fn dispatch_item_use(
         return;
     }
 
-    // ── FARM OBJECTS (fence, scarecrow) ───────────────────────────────────────
-    if matches!(item_id.as_str(), "fence" | "scarecrow") {
+    // ── FARM OBJECTS (fence, scarecrow, lightning rod, paths) ─────────────────
+    if matches!(
+        item_id.as_str(),
+        "fence" | "scarecrow" | "lightning_rod" | "wood_path" | "stone_path"
+    ) {
         ev.farm_object.send(PlaceFarmObjectEvent {
             item_id: item_id.clone(),
             grid_x: target_x,
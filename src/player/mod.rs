@@ -18,6 +18,7 @@ impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         // -- Local resources --
         app.init_resource::<ToolCooldown>();
+        app.init_resource::<PendingToolUse>();
         app.init_resource::<CollisionMap>();
         app.init_resource::<CameraSnap>();
         app.init_resource::<PlayerSpriteData>();
@@ -35,6 +36,13 @@ impl Plugin for PlayerPlugin {
         );
 
         // -- Interaction dispatchers: run BEFORE all legacy F-key systems --
+        app.add_systems(
+            Update,
+            interact_dispatch::resolve_interaction_target
+                .before(interact_dispatch::dispatch_world_interaction)
+                .in_set(UpdatePhase::Intent)
+                .run_if(in_state(GameState::Playing)),
+        );
         app.add_systems(
             Update,
             interact_dispatch::dispatch_world_interaction
@@ -68,9 +76,11 @@ impl Plugin for PlayerPlugin {
                 tools::stamina_low_warning,
                 interaction::item_pickup_check,
                 interaction::add_items_to_inventory,
+                interaction::collect_overflow_at_farmhouse,
                 interaction::map_transition_check,
                 interaction::handle_map_transition,
                 interaction::check_stamina_consequences,
+                interaction::use_bedroll,
             )
                 .in_set(UpdatePhase::Simulation)
                 .run_if(in_state(GameState::Playing)),
@@ -94,10 +104,19 @@ impl Plugin for PlayerPlugin {
         );
         app.add_systems(
             Update,
-            camera::camera_follow_player
-                .in_set(UpdatePhase::Presentation)
+            camera::cycle_camera_zoom
+                .in_set(UpdatePhase::Intent)
                 .run_if(in_state(GameState::Playing)),
         );
+        app.add_systems(
+            Update,
+            (
+                camera::apply_camera_zoom,
+                camera::camera_follow_player.run_if(in_state(GameState::Playing)),
+            )
+                .chain()
+                .in_set(UpdatePhase::Presentation),
+        );
 
         app.add_systems(
             Update,
@@ -107,7 +126,7 @@ impl Plugin for PlayerPlugin {
         );
 
         // -- DayEnd handling: only runs in Playing state to ensure world/NPC
-        // handlers (also gated on Playing) process the MapTransitionEvent --
+        // handlers (also gated on Playing) process the HonoredMapTransitionEvent --
         app.add_systems(
             Update,
             interaction::handle_day_end
@@ -174,6 +193,14 @@ impl Default for ToolCooldown {
     }
 }
 
+/// A swing that has been committed to but hasn't landed yet. `tool_use`
+/// fills it on the key press; `animate_tool_use` sends the `ToolUseEvent`
+/// and drains the stamina once the animation reaches its impact frame.
+#[derive(Resource, Default)]
+pub struct PendingToolUse {
+    pub swing: Option<(ToolUseEvent, f32)>,
+}
+
 /// Collision map for the current area.
 #[derive(Resource, Default)]
 pub struct CollisionMap {
@@ -197,7 +224,7 @@ pub struct CameraSnap {
 // Helpers shared across sub-modules
 // ═══════════════════════════════════════════════════════════════════════════
 
-/// Toggle boat mode when the player presses F near a Dock object.
+/// Toggle boat mode when the player presses the interact key near a Dock object.
 ///
 /// Boarding: player must be within 2 tiles of a Dock and have "boat" in inventory.
 /// Docking:  player must be on a water tile within 2 tiles of a Dock.
@@ -207,9 +234,10 @@ pub fn handle_boat_toggle(
     inventory: Res<Inventory>,
     mut boat_mode: ResMut<BoatMode>,
     keys: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
     mut toast: EventWriter<ToastEvent>,
 ) {
-    if !keys.just_pressed(KeyCode::KeyF) {
+    if !keys.just_pressed(bindings.interact) {
         return;
     }
 
@@ -267,6 +295,29 @@ pub fn stamina_cost(tool: &ToolKind) -> f32 {
     }
 }
 
+/// Stamina one use of the equipped tool drains where the player stands: the
+/// flat swing cost `tool_use` charges plus the tier-scaled cost the tool's own
+/// handler adds when it lands (tilling and watering on the farm, breaking rock
+/// in the mine). The HUD previews this.
+pub fn equipped_tool_stamina_cost(player: &PlayerState, creative: bool) -> f32 {
+    if creative {
+        return 0.0;
+    }
+    let tool = player.equipped_tool;
+    let tier = player.tools.get(&tool).copied().unwrap_or(ToolTier::Basic);
+    let handler_cost = match (tool, player.current_map) {
+        (ToolKind::Hoe, MapId::Farm) => crate::farming::hoe_stamina_cost(tier),
+        (ToolKind::WateringCan, MapId::Farm) => {
+            tool_stamina_cost(crate::farming::WATERING_CAN_STAMINA_COST, tier, false)
+        }
+        (ToolKind::Pickaxe, MapId::Mine) => {
+            crate::mining::rock_breaking::pickaxe_stamina_cost(tier)
+        }
+        _ => 0.0,
+    };
+    stamina_cost(&tool) + handler_cost
+}
+
 /// The ordered list of tools for cycling with Q/E.
 pub const TOOL_ORDER: [ToolKind; 6] = [
     ToolKind::Hoe,
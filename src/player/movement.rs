@@ -3,6 +3,21 @@ use crate::shared::*;
 use crate::world::WorldMap;
 use bevy::prelude::*;
 
+/// Speed multiplier applied while the run key is held.
+pub const RUN_SPEED_MULTIPLIER: f32 = 1.4;
+/// Stamina spent per second of running; drained in whole points.
+pub const RUN_STAMINA_PER_SECOND: f32 = 0.5;
+
+/// Effective walk speed for this frame. Applied on top of `PlayerMovement.speed`
+/// rather than written into it, so food speed buffs still stack with running.
+pub fn movement_speed(base: f32, running: bool) -> f32 {
+    if running {
+        base * RUN_SPEED_MULTIPLIER
+    } else {
+        base
+    }
+}
+
 /// Core movement system — reads input, applies velocity to LogicalPosition,
 /// updates facing direction, snaps grid position, and checks collisions.
 #[allow(clippy::too_many_arguments)]
@@ -15,6 +30,8 @@ pub fn player_movement(
     input_blocks: Res<InputBlocks>,
     boat_mode: Res<BoatMode>,
     world_map: Res<WorldMap>,
+    mut stamina_events: EventWriter<StaminaDrainEvent>,
+    mut run_stamina_acc: Local<f32>,
     mut query: Query<(&mut LogicalPosition, &mut PlayerMovement, &mut GridPosition), With<Player>>,
 ) {
     if input_blocks.is_blocked() {
@@ -48,8 +65,12 @@ pub fn player_movement(
             };
         }
 
+        // No running at sea or on an empty stamina bar. The walk cycle is
+        // distance-driven, so it speeds up along with the player.
+        let running = player_input.run_held && !boat_mode.active && player_state.stamina > 0.0;
+
         let normalized = dir.normalize();
-        let delta = normalized * movement.speed * time.delta_secs();
+        let delta = normalized * movement_speed(movement.speed, running) * time.delta_secs();
 
         let candidate_x = logical_pos.0.x + delta.x;
         let candidate_y = logical_pos.0.y + delta.y;
@@ -79,6 +100,7 @@ pub fn player_movement(
             )
         };
 
+        let start = logical_pos.0;
         if can_move_x {
             logical_pos.0.x = candidate_x;
         }
@@ -86,6 +108,15 @@ pub fn player_movement(
             logical_pos.0.y = candidate_y;
         }
 
+        // Running into a wall goes nowhere, so it costs nothing.
+        if running && logical_pos.0 != start {
+            *run_stamina_acc += RUN_STAMINA_PER_SECOND * time.delta_secs();
+            if *run_stamina_acc >= 1.0 {
+                stamina_events.send(StaminaDrainEvent { amount: 1.0 });
+                *run_stamina_acc -= 1.0;
+            }
+        }
+
         let g = world_to_grid(logical_pos.0.x, logical_pos.0.y);
         grid_pos.x = g.x;
         grid_pos.y = g.y;
@@ -224,3 +255,97 @@ fn is_blocked(
 
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    fn movement_app(run_held: bool) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .insert_resource(PlayerInput {
+                move_axis: Vec2::X,
+                run_held,
+                ..Default::default()
+            })
+            .init_resource::<CollisionMap>()
+            .init_resource::<FarmState>()
+            .init_resource::<PlayerState>()
+            .init_resource::<InputBlocks>()
+            .init_resource::<BoatMode>()
+            .init_resource::<WorldMap>()
+            .add_event::<StaminaDrainEvent>()
+            .add_systems(Update, player_movement);
+        app.world_mut().spawn((
+            Player,
+            LogicalPosition(Vec2::ZERO),
+            PlayerMovement::default(),
+            GridPosition::new(0, 0),
+        ));
+        app
+    }
+
+    /// Distance covered in one frame after the first (warm-up) update.
+    fn frame_distance(app: &mut App) -> f32 {
+        app.update();
+        let before = player_x(app);
+        app.update();
+        player_x(app) - before
+    }
+
+    fn player_x(app: &mut App) -> f32 {
+        app.world_mut()
+            .query_filtered::<&LogicalPosition, With<Player>>()
+            .single(app.world())
+            .0
+            .x
+    }
+
+    #[test]
+    fn holding_run_scales_per_frame_distance() {
+        let walk = frame_distance(&mut movement_app(false));
+        let run = frame_distance(&mut movement_app(true));
+
+        assert!(walk > 0.0);
+        assert!((run / walk - RUN_SPEED_MULTIPLIER).abs() < 1e-4);
+    }
+
+    /// Stamina drains sent over three seconds of holding run.
+    fn run_drains(app: &mut App) -> usize {
+        (0..30)
+            .map(|_| {
+                app.update();
+                app.world_mut()
+                    .resource_mut::<Events<StaminaDrainEvent>>()
+                    .drain()
+                    .count()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn running_into_a_wall_costs_no_stamina() {
+        let mut open = movement_app(true);
+        assert!(run_drains(&mut open) > 0);
+
+        let mut walled = movement_app(true);
+        walled.insert_resource(CollisionMap {
+            initialised: true,
+            bounds: (0, 0, 0, 0),
+            ..Default::default()
+        });
+        walled
+            .world_mut()
+            .query_filtered::<&mut LogicalPosition, With<Player>>()
+            .single_mut(walled.world_mut())
+            .0
+            .x = TILE_SIZE - 0.1;
+        assert_eq!(run_drains(&mut walled), 0);
+        assert!((player_x(&mut walled) - (TILE_SIZE - 0.1)).abs() < f32::EPSILON);
+    }
+}
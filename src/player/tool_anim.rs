@@ -1,4 +1,4 @@
-use super::facing_offset;
+use super::{facing_offset, PendingToolUse};
 use crate::shared::*;
 use bevy::image::ImageSampler;
 use bevy::prelude::*;
@@ -10,19 +10,41 @@ const TOOL_SWING_DURATION_MULTIPLIER: f32 = 1.08;
 const IMPACT_PARTICLE_COUNT_MULTIPLIER: f32 = 2.0;
 
 /// Per-tool frame duration in seconds. Heavy tools feel weighty,
-/// light tools feel snappy. Total animation = duration x 4 frames.
+/// light tools feel snappy. Total animation = duration x the tool's frames.
 fn tool_frame_duration(tool: ToolKind) -> f32 {
     TOOL_SWING_DURATION_MULTIPLIER
         * match tool {
-            ToolKind::Axe => 0.145,        // 0.58s total — heavy, impactful chop
-            ToolKind::Pickaxe => 0.135,    // 0.54s total — heavy swing
-            ToolKind::Hoe => 0.105,        // 0.42s total — deliberate tilling
-            ToolKind::FishingRod => 0.11,  // 0.44s total — quick cast flick
-            ToolKind::WateringCan => 0.09, // 0.36s total — smooth pour
-            ToolKind::Scythe => 0.075,     // 0.30s total — fast sweep
+            ToolKind::Axe => 0.116,        // 0.58s over 5 frames — heavy, impactful chop
+            ToolKind::Pickaxe => 0.108,    // 0.54s over 5 frames — heavy swing
+            ToolKind::Hoe => 0.105,        // 0.42s over 4 frames — deliberate tilling
+            ToolKind::FishingRod => 0.075, // 0.45s over 6 frames — wind-up and flick
+            ToolKind::WateringCan => 0.09, // 0.36s over 4 frames — smooth pour
+            ToolKind::Scythe => 0.1,       // 0.30s over 3 frames — fast sweep
         }
 }
 
+/// Per-tool animation profile: (total_frames, impact_frame).
+/// The impact frame is when the tool actually meets the target tile, so
+/// the `ToolUseEvent`, the stamina drain and `ToolImpactEvent` (with its
+/// sound, splash, and debris) line up with the visible contact in
+/// `tool_swing_params` instead of the wind-up.
+pub fn tool_anim_profile(tool: ToolKind) -> (u8, u8) {
+    match tool {
+        // The blade drops on the snap-forward frame.
+        ToolKind::Hoe => (4, 2),
+        // Water leaves the spout as soon as the can tilts.
+        ToolKind::WateringCan => (4, 1),
+        // A long overhead wind-up before the chop bites.
+        ToolKind::Axe => (5, 3),
+        // The point strikes early and stays in the rock for a beat.
+        ToolKind::Pickaxe => (5, 2),
+        // Two frames of wind-up before the line releases.
+        ToolKind::FishingRod => (6, 4),
+        // One quick sweep straight across the tile.
+        ToolKind::Scythe => (3, 1),
+    }
+}
+
 fn scaled_impact_particle_count(count: usize) -> usize {
     ((count as f32) * IMPACT_PARTICLE_COUNT_MULTIPLIER).round() as usize
 }
@@ -36,40 +58,59 @@ fn tool_bob_frame(frame: usize) -> usize {
 // Transform-based tool swing parameters per tool kind
 // ═══════════════════════════════════════════════════════════════════════════
 
-/// Returns (rotation_degrees, translation_offset) for each of the 4 frames.
-/// Frame 0 = wind-up, 1 = swing, 2 = impact, 3 = recovery.
+/// Returns (rotation_degrees, translation_offset) for each frame of the
+/// tool's `tool_anim_profile`; the impact frame is the forward snap.
 fn tool_swing_params(tool: ToolKind, frame: usize) -> (f32, Vec2) {
     match tool {
-        // Hoe / Pickaxe / Axe: overhead swing arc
-        ToolKind::Hoe | ToolKind::Pickaxe | ToolKind::Axe => match frame {
+        // Hoe: overhead swing arc
+        ToolKind::Hoe => match frame {
             0 => (-52.0, Vec2::new(0.0, 1.4)), // wind-up: tilt back
             1 => (-18.0, Vec2::new(0.0, 0.7)), // mid-swing
             2 => (40.0, Vec2::new(0.0, -1.8)), // impact: snap forward
             3 => (12.0, Vec2::ZERO),           // recovery
             _ => (0.0, Vec2::ZERO),
         },
+        // Axe: lift, full wind-up, drive down, chop
+        ToolKind::Axe => match frame {
+            0 => (-36.0, Vec2::new(0.0, 1.0)), // lift
+            1 => (-62.0, Vec2::new(0.0, 1.7)), // full wind-up
+            2 => (-12.0, Vec2::new(0.0, 0.6)), // drive down
+            3 => (46.0, Vec2::new(0.0, -2.0)), // impact: chop
+            4 => (14.0, Vec2::ZERO),           // recovery
+            _ => (0.0, Vec2::ZERO),
+        },
+        // Pickaxe: short wind-up, strike, then wrench free
+        ToolKind::Pickaxe => match frame {
+            0 => (-55.0, Vec2::new(0.0, 1.5)), // wind-up
+            1 => (-15.0, Vec2::new(0.0, 0.6)), // swing
+            2 => (42.0, Vec2::new(0.0, -1.9)), // impact: strike
+            3 => (32.0, Vec2::new(0.0, -1.2)), // stuck in the rock
+            4 => (10.0, Vec2::ZERO),           // wrench free
+            _ => (0.0, Vec2::ZERO),
+        },
         // Watering can: gentle forward tilt (pour)
         ToolKind::WateringCan => match frame {
             0 => (5.0, Vec2::ZERO),            // slight lift
-            1 => (15.0, Vec2::new(0.0, -0.5)), // tilting
-            2 => (20.0, Vec2::new(0.0, -1.0)), // full pour
+            1 => (20.0, Vec2::new(0.0, -1.0)), // tip: water flows
+            2 => (16.0, Vec2::new(0.0, -0.7)), // keep pouring
             3 => (8.0, Vec2::ZERO),            // recovery
             _ => (0.0, Vec2::ZERO),
         },
         // Fishing rod: cast arc (wind back then fling forward)
         ToolKind::FishingRod => match frame {
-            0 => (-60.0, Vec2::new(0.0, 1.5)), // wind-up far back
-            1 => (-20.0, Vec2::new(0.0, 0.5)), // mid-cast
-            2 => (45.0, Vec2::new(0.0, -1.5)), // cast forward
-            3 => (15.0, Vec2::ZERO),           // follow-through
+            0 => (-30.0, Vec2::new(0.0, 0.8)), // raise
+            1 => (-60.0, Vec2::new(0.0, 1.5)), // wind-up far back
+            2 => (-35.0, Vec2::new(0.0, 1.0)), // start forward
+            3 => (5.0, Vec2::ZERO),            // mid-cast
+            4 => (45.0, Vec2::new(0.0, -1.5)), // release: cast forward
+            5 => (15.0, Vec2::ZERO),           // follow-through
             _ => (0.0, Vec2::ZERO),
         },
         // Scythe: horizontal sweep with translation
         ToolKind::Scythe => match frame {
             0 => (-25.0, Vec2::new(-2.0, 0.0)), // wind-up to the side
-            1 => (-10.0, Vec2::new(-1.0, 0.0)), // mid-sweep
-            2 => (20.0, Vec2::new(2.0, 0.0)),   // impact sweep across
-            3 => (8.0, Vec2::new(1.0, 0.0)),    // recovery
+            1 => (20.0, Vec2::new(2.0, 0.0)),   // impact sweep across
+            2 => (8.0, Vec2::new(1.0, 0.0)),    // recovery
             _ => (0.0, Vec2::ZERO),
         },
     }
@@ -121,6 +162,9 @@ pub fn animate_tool_use(
         With<Player>,
     >,
     mut impact_events: EventWriter<ToolImpactEvent>,
+    mut tool_events: EventWriter<ToolUseEvent>,
+    mut stamina_events: EventWriter<StaminaDrainEvent>,
+    mut pending: ResMut<PendingToolUse>,
     mut frame_timer: Local<f32>,
     mut impact_fired: Local<bool>,
 ) {
@@ -167,9 +211,15 @@ pub fn animate_tool_use(
             // Accumulate time
             *frame_timer += time.delta_secs();
 
-            // Emit impact event on frame 2 (once)
-            if frame >= 2 && !*impact_fired {
+            // Emit impact event on the tool's impact frame (once)
+            let (_, impact_frame) = tool_anim_profile(tool);
+            if frame == impact_frame && !*impact_fired {
                 *impact_fired = true;
+                // The tool lands now: let other domains react and pay for it.
+                if let Some((event, cost)) = pending.swing.take() {
+                    tool_events.send(event);
+                    stamina_events.send(StaminaDrainEvent { amount: cost });
+                }
                 // Impact flash: bright white burst
                 sprite.color = Color::srgb(1.7, 1.68, 1.55);
                 // Impact squash: brief scale distortion
@@ -214,7 +264,7 @@ pub fn animate_tool_use(
                         atlas.index = facing_base + walk_frame;
                     }
                     // Recovery frame: scale back to normal, fade tint
-                    if new_frame == 3 {
+                    if new_frame == impact_frame + 1 {
                         sprite.color = Color::srgb(1.2, 1.16, 1.04);
                         transform.scale = Vec3::ONE;
                     }
@@ -226,9 +276,13 @@ pub fn animate_tool_use(
                 }
             }
         } else {
-            // Not in tool animation — reset timer and ensure transform is clean
+            // Not in tool animation — reset timer and ensure transform is clean.
+            // A swing cut short before its impact frame never lands.
             *frame_timer = 0.0;
             *impact_fired = false;
+            if pending.swing.is_some() {
+                pending.swing = None;
+            }
             if transform.rotation != Quat::IDENTITY {
                 transform.rotation = Quat::IDENTITY;
             }
@@ -316,7 +370,30 @@ mod tests {
     }
 
     #[test]
-    fn tool_swing_params_return_four_frames() {
+    fn tool_anim_profile_impact_frames() {
+        use crate::shared::ToolKind;
+        let expected = [
+            (ToolKind::Hoe, 4, 2),
+            (ToolKind::Pickaxe, 5, 2),
+            (ToolKind::Axe, 5, 3),
+            (ToolKind::WateringCan, 4, 1),
+            (ToolKind::FishingRod, 6, 4),
+            (ToolKind::Scythe, 3, 1),
+        ];
+        for (tool, frames, impact) in expected {
+            let (total, impact_frame) = super::tool_anim_profile(tool);
+            assert_eq!(total, frames, "{:?} frame count", tool);
+            assert_eq!(impact_frame, impact, "{:?} impact frame", tool);
+            assert!(
+                impact_frame < total,
+                "{:?} impact frame must fall inside the animation",
+                tool
+            );
+        }
+    }
+
+    #[test]
+    fn tool_swing_params_cover_each_profile() {
         use crate::shared::ToolKind;
         for tool in [
             ToolKind::Axe,
@@ -326,7 +403,8 @@ mod tests {
             ToolKind::FishingRod,
             ToolKind::Scythe,
         ] {
-            for frame in 0..4 {
+            let (total, impact_frame) = super::tool_anim_profile(tool);
+            for frame in 0..total as usize {
                 let (rot, _offset) = super::tool_swing_params(tool, frame);
                 assert!(
                     rot.is_finite(),
@@ -335,8 +413,83 @@ mod tests {
                     frame
                 );
             }
+            // The impact frame is the forward-most pose of the swing.
+            let (impact_rot, _) = super::tool_swing_params(tool, impact_frame as usize);
+            for frame in 0..total as usize {
+                let (rot, _) = super::tool_swing_params(tool, frame);
+                assert!(
+                    rot <= impact_rot,
+                    "{:?} frame {} swings past the impact pose",
+                    tool,
+                    frame
+                );
+            }
         }
     }
+
+    #[test]
+    fn tool_use_lands_on_the_impact_frame() {
+        use super::{animate_tool_use, PendingToolUse};
+        use crate::shared::*;
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<PendingToolUse>()
+            .add_event::<ToolImpactEvent>()
+            .add_event::<ToolUseEvent>()
+            .add_event::<StaminaDrainEvent>()
+            .add_systems(Update, animate_tool_use);
+
+        let (total_frames, impact_frame) = super::tool_anim_profile(ToolKind::Axe);
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                PlayerMovement {
+                    anim_state: PlayerAnimState::ToolUse {
+                        tool: ToolKind::Axe,
+                        frame: 0,
+                        total_frames,
+                    },
+                    ..default()
+                },
+                Sprite::default(),
+                Transform::default(),
+                LogicalPosition(Vec2::ZERO),
+            ))
+            .id();
+        app.world_mut().resource_mut::<PendingToolUse>().swing = Some((
+            ToolUseEvent {
+                tool: ToolKind::Axe,
+                tier: ToolTier::Basic,
+                target_x: 0,
+                target_y: -1,
+            },
+            6.0,
+        ));
+
+        // Wind-up: nothing has been hit or paid for yet.
+        app.update();
+        assert!(app.world().resource::<Events<ToolUseEvent>>().is_empty());
+        assert!(app
+            .world()
+            .resource::<Events<StaminaDrainEvent>>()
+            .is_empty());
+
+        app.world_mut()
+            .get_mut::<PlayerMovement>(player)
+            .unwrap()
+            .anim_state = PlayerAnimState::ToolUse {
+            tool: ToolKind::Axe,
+            frame: impact_frame,
+            total_frames,
+        };
+        app.update();
+        assert_eq!(app.world().resource::<Events<ToolUseEvent>>().len(), 1);
+        assert_eq!(app.world().resource::<Events<StaminaDrainEvent>>().len(), 1);
+        assert!(app.world().resource::<PendingToolUse>().swing.is_none());
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -735,8 +888,9 @@ pub fn spawn_impact_particles(
 
     for event in impact_events.read() {
         // Target tile centre in world space
-        let wx = event.grid_x as f32 * TILE_SIZE + TILE_SIZE * 0.5;
-        let wy = event.grid_y as f32 * TILE_SIZE + TILE_SIZE * 0.5;
+        let wc = grid_to_world_center(event.grid_x, event.grid_y);
+        let wx = wc.x;
+        let wy = wc.y;
 
         let (color, count, gravity) = match event.tool {
             ToolKind::Hoe => (
@@ -841,8 +995,9 @@ pub fn spawn_till_poof(mut commands: Commands, mut impact_events: EventReader<To
         if event.tool != ToolKind::Hoe {
             continue;
         }
-        let wx = event.grid_x as f32 * TILE_SIZE + TILE_SIZE * 0.5;
-        let wy = event.grid_y as f32 * TILE_SIZE + TILE_SIZE * 0.5;
+        let wc = grid_to_world_center(event.grid_x, event.grid_y);
+        let wx = wc.x;
+        let wy = wc.y;
         commands.spawn((
             TillPoof {
                 timer: Timer::from_seconds(0.2, TimerMode::Once),
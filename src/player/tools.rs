@@ -1,4 +1,4 @@
-use super::{facing_offset, stamina_cost, ToolCooldown, TOOL_ORDER};
+use super::{facing_offset, stamina_cost, PendingToolUse, ToolCooldown, TOOL_ORDER};
 use crate::shared::*;
 use bevy::prelude::*;
 
@@ -31,8 +31,9 @@ pub fn tool_cycle(
     }
 }
 
-/// Use the currently equipped tool on the tile the player is facing.
-/// Sends a `ToolUseEvent` and a `StaminaDrainEvent` when successful.
+/// Start a swing of the currently equipped tool at the tile the player is
+/// facing. The `ToolUseEvent` and `StaminaDrainEvent` are queued in
+/// `PendingToolUse` and sent by `animate_tool_use` on the impact frame.
 #[allow(clippy::too_many_arguments)]
 pub fn tool_use(
     time: Res<Time>,
@@ -41,11 +42,11 @@ pub fn tool_use(
     input_blocks: Res<InputBlocks>,
     mut cooldown: ResMut<ToolCooldown>,
     mut query: Query<(&LogicalPosition, &mut PlayerMovement), With<Player>>,
-    mut tool_events: EventWriter<ToolUseEvent>,
-    mut stamina_events: EventWriter<StaminaDrainEvent>,
+    mut pending: ResMut<PendingToolUse>,
     mut sfx_events: EventWriter<PlaySfxEvent>,
     mut toast_events: EventWriter<ToastEvent>,
     upgrade_queue: Res<crate::economy::blacksmith::ToolUpgradeQueue>,
+    settings: Res<GameSettings>,
 ) {
     if input_blocks.is_blocked() {
         return;
@@ -69,8 +70,22 @@ pub fn tool_use(
         return;
     };
 
+    // A swing already in flight has to land before the next one starts.
+    if matches!(movement.anim_state, PlayerAnimState::ToolUse { .. }) {
+        return;
+    }
+
     let tool = player_state.equipped_tool;
-    let cost = stamina_cost(&tool);
+    let tier = player_state
+        .tools
+        .get(&tool)
+        .copied()
+        .unwrap_or(ToolTier::Basic);
+    let cost = if settings.creative_tools {
+        0.0
+    } else {
+        stamina_cost(&tool)
+    };
 
     // Block usage if tool is being upgraded at the blacksmith
     if upgrade_queue.is_upgrading(tool) {
@@ -103,23 +118,16 @@ pub fn tool_use(
     let target_x = px + dx;
     let target_y = py + dy;
 
-    // Determine tool tier.
-    let tier = player_state
-        .tools
-        .get(&tool)
-        .copied()
-        .unwrap_or(ToolTier::Basic);
-
-    // Send the tool use event for other domains to react.
-    tool_events.send(ToolUseEvent {
-        tool,
-        tier,
-        target_x,
-        target_y,
-    });
-
-    // Drain stamina.
-    stamina_events.send(StaminaDrainEvent { amount: cost });
+    // Queue the tool use and its stamina cost for the impact frame.
+    pending.swing = Some((
+        ToolUseEvent {
+            tool,
+            tier,
+            target_x,
+            target_y,
+        },
+        cost,
+    ));
 
     // Play a sound effect for the tool.
     let sfx_id = match tool {
@@ -135,10 +143,11 @@ pub fn tool_use(
     });
 
     // Set tool-use animation state
+    let (total_frames, _) = super::tool_anim::tool_anim_profile(tool);
     movement.anim_state = PlayerAnimState::ToolUse {
         tool,
         frame: 0,
-        total_frames: 4,
+        total_frames,
     };
 
     // Reset cooldown.
@@ -18,6 +18,8 @@ use crate::npcs::schedules::FarmVisitTracker;
 use crate::shared::ShippingLog;
 use crate::shared::*;
 use crate::world::chests::ChestMarker;
+use crate::world::fish_tanks::{spawn_fish_tank, FishTank};
+use crate::world::maps::FarmLayout;
 use crate::world::CurrentMapId;
 
 // ═══════════════════════════════════════════════════════════════════════
@@ -93,7 +95,33 @@ pub struct LoadCompleteEvent {
 #[derive(Event, Debug, Clone)]
 pub struct NewGameEvent {
     pub farm_name: String,
+    pub player_name: String,
     pub active_slot: u8,
+    pub farm_layout: FarmLayout,
+    /// Season to start in instead of Spring (new-game+ and debug scenarios).
+    pub start_season: Option<Season>,
+    /// Year to start in instead of Year 1.
+    pub start_year: Option<u32>,
+}
+
+impl NewGameEvent {
+    /// The calendar a new game begins on: day 1 of the requested season and
+    /// year, falling back to `Calendar::default()` (Year 1 Spring).
+    pub fn starting_calendar(&self) -> Calendar {
+        let default = Calendar::default();
+        Calendar {
+            season: self.start_season.unwrap_or(default.season),
+            year: self.start_year.unwrap_or(default.year).max(1),
+            ..default
+        }
+    }
+
+    /// The player profile a new game begins with.
+    pub fn player_profile(&self) -> PlayerProfile {
+        PlayerProfile {
+            name: self.player_name.clone(),
+        }
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════
@@ -131,6 +159,12 @@ pub struct GameStatistics {
     pub total_items_shipped: u64,
     pub play_time_seconds: u64,
     pub farm_name: String,
+    /// Set once creative tools have been switched on during this farm.
+    #[serde(default)]
+    pub creative_mode: bool,
+    /// Set once peaceful mode has been switched on during this farm.
+    #[serde(default)]
+    pub peaceful_mode: bool,
 }
 
 impl GameStatistics {
@@ -140,6 +174,74 @@ impl GameStatistics {
             total_items_shipped: 0,
             play_time_seconds: 0,
             farm_name: farm_name.into(),
+            creative_mode: false,
+            peaceful_mode: false,
+        }
+    }
+}
+
+/// Whether progress has changed since the last save or load, so quitting
+/// can offer to save first. Gold, inventory contents and the calendar day
+/// are compared against a snapshot taken when the game was last saved.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct UnsavedChanges {
+    pub dirty: bool,
+    baseline: Option<ProgressFingerprint>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ProgressFingerprint {
+    gold: u32,
+    day: u32,
+    inventory: u64,
+}
+
+impl ProgressFingerprint {
+    fn capture(player_state: &PlayerState, inventory: &Inventory, calendar: &Calendar) -> Self {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        for slot in &inventory.slots {
+            slot.as_ref()
+                .map(|s| (s.item_id.as_str(), s.quantity))
+                .hash(&mut hasher);
+        }
+        Self {
+            gold: player_state.gold,
+            day: calendar.total_days_elapsed(),
+            inventory: hasher.finish(),
+        }
+    }
+}
+
+impl UnsavedChanges {
+    /// Treat the current state as saved.
+    pub fn mark_saved(
+        &mut self,
+        player_state: &PlayerState,
+        inventory: &Inventory,
+        calendar: &Calendar,
+    ) {
+        self.dirty = false;
+        self.baseline = Some(ProgressFingerprint::capture(
+            player_state,
+            inventory,
+            calendar,
+        ));
+    }
+
+    /// Compare the current state against the last save and flag any change.
+    pub fn refresh(
+        &mut self,
+        player_state: &PlayerState,
+        inventory: &Inventory,
+        calendar: &Calendar,
+    ) {
+        let current = ProgressFingerprint::capture(player_state, inventory, calendar);
+        match self.baseline {
+            // First look at a freshly started or loaded session.
+            None => self.baseline = Some(current),
+            Some(baseline) if baseline != current => self.dirty = true,
+            Some(_) => {}
         }
     }
 }
@@ -210,12 +312,22 @@ struct ExtendedResources<'w> {
     pub harvest_stats: Res<'w, crate::economy::stats::HarvestStats>,
     pub animal_product_stats: Res<'w, crate::economy::stats::AnimalProductStats>,
     pub economy_stats: Res<'w, crate::economy::gold::EconomyStats>,
+    pub daily_ledger: Res<'w, crate::economy::gold::DailyLedger>,
     pub daily_talk_tracker: Res<'w, crate::npcs::dialogue::DailyTalkTracker>,
     pub gift_decay_tracker: Res<'w, crate::npcs::map_events::GiftDecayTracker>,
+    pub gift_history: Res<'w, GiftHistory>,
+    pub building_placements: Res<'w, BuildingPlacements>,
+    pub overflow_store: Res<'w, OverflowStore>,
+    pub heart_event_log: Res<'w, crate::npcs::heart_events::HeartEventLog>,
+    pub mailbox: Res<'w, Mailbox>,
     pub tool_upgrade_queue: Res<'w, ToolUpgradeQueue>,
     pub shipping_bin_quality: Res<'w, ShippingBinQuality>,
     pub festival_state: Res<'w, FestivalState>,
     pub farm_visit_tracker: Res<'w, FarmVisitTracker>,
+    pub farm_layout: Res<'w, FarmLayout>,
+    pub silo_state: Res<'w, SiloState>,
+    pub pinned_recipes: Res<'w, PinnedRecipes>,
+    pub player_profile: Res<'w, PlayerProfile>,
 }
 
 /// Mutable bundle of the extended resources (for loading / new game).
@@ -238,19 +350,31 @@ struct ExtendedResourcesMut<'w> {
     pub harvest_stats: ResMut<'w, crate::economy::stats::HarvestStats>,
     pub animal_product_stats: ResMut<'w, crate::economy::stats::AnimalProductStats>,
     pub economy_stats: ResMut<'w, crate::economy::gold::EconomyStats>,
+    pub daily_ledger: ResMut<'w, crate::economy::gold::DailyLedger>,
     pub daily_talk_tracker: ResMut<'w, crate::npcs::dialogue::DailyTalkTracker>,
     pub gift_decay_tracker: ResMut<'w, crate::npcs::map_events::GiftDecayTracker>,
+    pub gift_history: ResMut<'w, GiftHistory>,
+    pub building_placements: ResMut<'w, BuildingPlacements>,
+    pub overflow_store: ResMut<'w, OverflowStore>,
+    pub heart_event_log: ResMut<'w, crate::npcs::heart_events::HeartEventLog>,
+    pub mailbox: ResMut<'w, Mailbox>,
     pub tool_upgrade_queue: ResMut<'w, ToolUpgradeQueue>,
     pub shipping_bin_quality: ResMut<'w, ShippingBinQuality>,
     pub festival_state: ResMut<'w, FestivalState>,
     pub farm_visit_tracker: ResMut<'w, FarmVisitTracker>,
+    pub farm_layout: ResMut<'w, FarmLayout>,
+    pub silo_state: ResMut<'w, SiloState>,
+    pub pinned_recipes: ResMut<'w, PinnedRecipes>,
+    pub player_profile: ResMut<'w, PlayerProfile>,
 }
 
-/// Chest-related resources needed during load (for restoring chest entities).
+/// Chest-related resources needed during load (for restoring chest and
+/// fish tank entities).
 #[derive(SystemParam)]
 struct ChestLoadResources<'w, 's> {
     pub current_map_id: ResMut<'w, CurrentMapId>,
     pub existing_chests: Query<'w, 's, Entity, With<ChestMarker>>,
+    pub existing_fish_tanks: Query<'w, 's, Entity, With<FishTank>>,
     pub chest_sprites: Res<'w, crate::world::chests::ChestSpriteData>,
 }
 
@@ -276,6 +400,7 @@ impl Plugin for SavePlugin {
             .init_resource::<SaveSlotInfoCache>()
             .init_resource::<GameStatistics>()
             .init_resource::<SessionTimer>()
+            .init_resource::<UnsavedChanges>()
             // Events emitted/received by this plugin
             .add_event::<SaveRequestEvent>()
             .add_event::<LoadRequestEvent>()
@@ -283,7 +408,9 @@ impl Plugin for SavePlugin {
             .add_event::<LoadCompleteEvent>()
             .add_event::<NewGameEvent>()
             // Startup: scan existing save files for the slot cache
-            .add_systems(Startup, scan_save_slots)
+            .add_systems(Startup, (scan_save_slots, load_game_settings))
+            // Settings are global, so persist them whenever they change.
+            .add_systems(Update, persist_game_settings)
             // Playing systems — registered individually to stay within Bevy's
             // system-tuple trait bounds (each system has many Res/ResMut params).
             .add_systems(
@@ -294,6 +421,10 @@ impl Plugin for SavePlugin {
                 Update,
                 track_gold_earned.run_if(in_state(GameState::Playing)),
             )
+            .add_systems(
+                Update,
+                (mark_creative_farm, mark_peaceful_farm).run_if(in_state(GameState::Playing)),
+            )
             .add_systems(
                 Update,
                 track_items_shipped.run_if(in_state(GameState::Playing)),
@@ -328,8 +459,18 @@ impl Plugin for SavePlugin {
                 Update,
                 handle_new_game.run_if(in_state(GameState::MainMenu)),
             )
+            // Dirty tracking for the quit-without-saving guard.
+            .add_systems(
+                Update,
+                track_unsaved_changes.run_if(
+                    not(in_state(GameState::MainMenu)).and(not(in_state(GameState::Loading))),
+                ),
+            )
             // Refresh slot metadata whenever menu is entered.
-            .add_systems(OnEnter(GameState::MainMenu), scan_save_slots)
+            .add_systems(
+                OnEnter(GameState::MainMenu),
+                (scan_save_slots, reset_unsaved_changes),
+            )
             // Quick-save keybind: F5 in Playing or Paused
             .add_systems(
                 Update,
@@ -379,6 +520,70 @@ fn current_timestamp() -> u64 {
     0
 }
 
+// ═══════════════════════════════════════════════════════════════════════
+// GAME SETTINGS PERSISTENCE
+// ═══════════════════════════════════════════════════════════════════════
+
+#[cfg(not(target_arch = "wasm32"))]
+fn settings_path() -> PathBuf {
+    saves_directory().join("settings.json")
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_settings() -> Option<String> {
+    fs::read_to_string(settings_path()).ok()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_settings() -> Option<String> {
+    web_sys::window()?
+        .local_storage()
+        .ok()??
+        .get_item("hearthfield_settings")
+        .ok()?
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_settings(json: &str) -> Result<(), String> {
+    ensure_saves_dir().map_err(|e| format!("Failed to create saves dir: {}", e))?;
+    fs::write(settings_path(), json).map_err(|e| format!("Write failed: {}", e))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write_settings(json: &str) -> Result<(), String> {
+    web_sys::window()
+        .ok_or_else(|| "No browser window".to_string())?
+        .local_storage()
+        .map_err(|_| "Failed to access localStorage".to_string())?
+        .ok_or_else(|| "localStorage not available".to_string())?
+        .set_item("hearthfield_settings", json)
+        .map_err(|_| "Failed to write to localStorage".to_string())
+}
+
+/// Restore `GameSettings` from the settings file, if one exists.
+fn load_game_settings(mut settings: ResMut<GameSettings>) {
+    let Some(json) = read_settings() else {
+        return;
+    };
+    match serde_json::from_str::<GameSettings>(&json) {
+        Ok(loaded) => *settings = loaded,
+        Err(e) => warn!("[Save] Ignoring unreadable settings file: {}", e),
+    }
+}
+
+/// Write `GameSettings` out whenever the player changes them.
+fn persist_game_settings(settings: Res<GameSettings>) {
+    if !settings.is_changed() || settings.is_added() {
+        return;
+    }
+    let result = serde_json::to_string_pretty(&*settings)
+        .map_err(|e| format!("Serialization failed: {}", e))
+        .and_then(|json| write_settings(&json));
+    if let Err(e) = result {
+        warn!("[Save] Failed to write settings: {}", e);
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // FULL SAVE DATA WITH EXTENDED FIELDS
 // ═══════════════════════════════════════════════════════════════════════
@@ -402,6 +607,12 @@ struct FullSaveFile {
     pub shipping_bin: ShippingBin,
     pub total_gold_earned: u64,
     pub total_items_shipped: u64,
+    /// True if creative tools were used at any point on this farm.
+    #[serde(default)]
+    pub creative_mode: bool,
+    /// True if peaceful mode was on at any point on this farm.
+    #[serde(default)]
+    pub peaceful_mode: bool,
     #[serde(default)]
     pub house_state: HouseState,
     #[serde(default)]
@@ -437,10 +648,22 @@ struct FullSaveFile {
     #[serde(default)]
     pub economy_stats: crate::economy::gold::EconomyStats,
     #[serde(default)]
+    pub daily_ledger: crate::economy::gold::DailyLedger,
+    #[serde(default)]
     pub daily_talk_tracker: crate::npcs::dialogue::DailyTalkTracker,
     #[serde(default)]
     pub gift_decay_tracker: crate::npcs::map_events::GiftDecayTracker,
     #[serde(default)]
+    pub gift_history: GiftHistory,
+    #[serde(default)]
+    pub building_placements: BuildingPlacements,
+    #[serde(default)]
+    pub overflow_store: OverflowStore,
+    #[serde(default)]
+    pub heart_event_log: crate::npcs::heart_events::HeartEventLog,
+    #[serde(default)]
+    pub mailbox: Mailbox,
+    #[serde(default)]
     pub tool_upgrade_queue: ToolUpgradeQueue,
     #[serde(default)]
     pub shipping_bin_quality: ShippingBinQuality,
@@ -448,9 +671,20 @@ struct FullSaveFile {
     pub festival_state: FestivalState,
     #[serde(default)]
     pub farm_visit_tracker: FarmVisitTracker,
+    #[serde(default)]
+    pub farm_layout: FarmLayout,
+    #[serde(default)]
+    pub silo_state: SiloState,
+    #[serde(default)]
+    pub pinned_recipes: PinnedRecipes,
+    #[serde(default)]
+    pub player_profile: PlayerProfile,
     /// Storage chest contents placed by the player.
     #[serde(default)]
     pub chests: Vec<StorageChest>,
+    /// Fish tanks placed by the player, with the fish on display.
+    #[serde(default)]
+    pub fish_tanks: Vec<FishTank>,
     /// Processing machines placed by the player.
     #[serde(default)]
     pub placed_machines: Vec<SavedMachine>,
@@ -507,13 +741,24 @@ fn write_save(
     harvest_stats: &crate::economy::stats::HarvestStats,
     animal_product_stats: &crate::economy::stats::AnimalProductStats,
     economy_stats: &crate::economy::gold::EconomyStats,
+    daily_ledger: &crate::economy::gold::DailyLedger,
     daily_talk_tracker: &crate::npcs::dialogue::DailyTalkTracker,
     gift_decay_tracker: &crate::npcs::map_events::GiftDecayTracker,
+    gift_history: &GiftHistory,
+    building_placements: &BuildingPlacements,
+    overflow_store: &OverflowStore,
+    heart_event_log: &crate::npcs::heart_events::HeartEventLog,
+    mailbox: &Mailbox,
     tool_upgrade_queue: &ToolUpgradeQueue,
     shipping_bin_quality: &ShippingBinQuality,
     festival_state: &FestivalState,
     farm_visit_tracker: &FarmVisitTracker,
+    farm_layout: FarmLayout,
+    silo_state: &SiloState,
+    pinned_recipes: &PinnedRecipes,
+    player_profile: &PlayerProfile,
     chests: &[StorageChest],
+    fish_tanks: &[FishTank],
     placed_machines: &[SavedMachine],
 ) -> Result<(), String> {
     ensure_saves_dir().map_err(|e| format!("Could not create saves directory: {}", e))?;
@@ -535,6 +780,8 @@ fn write_save(
         shipping_bin: shipping_bin.clone(),
         total_gold_earned: statistics.total_gold_earned,
         total_items_shipped: statistics.total_items_shipped,
+        creative_mode: statistics.creative_mode,
+        peaceful_mode: statistics.peaceful_mode,
         house_state: house_state.clone(),
         marriage_state: marriage_state.clone(),
         quest_log: quest_log.clone(),
@@ -552,13 +799,24 @@ fn write_save(
         harvest_stats: harvest_stats.clone(),
         animal_product_stats: animal_product_stats.clone(),
         economy_stats: economy_stats.clone(),
+        daily_ledger: daily_ledger.clone(),
         daily_talk_tracker: daily_talk_tracker.clone(),
         gift_decay_tracker: gift_decay_tracker.clone(),
+        gift_history: gift_history.clone(),
+        building_placements: *building_placements,
+        overflow_store: overflow_store.clone(),
+        heart_event_log: heart_event_log.clone(),
+        mailbox: mailbox.clone(),
         tool_upgrade_queue: tool_upgrade_queue.clone(),
         shipping_bin_quality: shipping_bin_quality.clone(),
         festival_state: festival_state.clone(),
         farm_visit_tracker: farm_visit_tracker.clone(),
+        farm_layout,
+        silo_state: silo_state.clone(),
+        pinned_recipes: pinned_recipes.clone(),
+        player_profile: player_profile.clone(),
         chests: chests.to_vec(),
+        fish_tanks: fish_tanks.to_vec(),
         placed_machines: placed_machines.to_vec(),
     };
 
@@ -612,13 +870,24 @@ fn write_save(
     harvest_stats: &crate::economy::stats::HarvestStats,
     animal_product_stats: &crate::economy::stats::AnimalProductStats,
     economy_stats: &crate::economy::gold::EconomyStats,
+    daily_ledger: &crate::economy::gold::DailyLedger,
     daily_talk_tracker: &crate::npcs::dialogue::DailyTalkTracker,
     gift_decay_tracker: &crate::npcs::map_events::GiftDecayTracker,
+    gift_history: &GiftHistory,
+    building_placements: &BuildingPlacements,
+    overflow_store: &OverflowStore,
+    heart_event_log: &crate::npcs::heart_events::HeartEventLog,
+    mailbox: &Mailbox,
     tool_upgrade_queue: &ToolUpgradeQueue,
     shipping_bin_quality: &ShippingBinQuality,
     festival_state: &FestivalState,
     farm_visit_tracker: &FarmVisitTracker,
+    farm_layout: FarmLayout,
+    silo_state: &SiloState,
+    pinned_recipes: &PinnedRecipes,
+    player_profile: &PlayerProfile,
     chests: &[StorageChest],
+    fish_tanks: &[FishTank],
     placed_machines: &[SavedMachine],
 ) -> Result<(), String> {
     let file = FullSaveFile {
@@ -638,6 +907,8 @@ fn write_save(
         shipping_bin: shipping_bin.clone(),
         total_gold_earned: statistics.total_gold_earned,
         total_items_shipped: statistics.total_items_shipped,
+        creative_mode: statistics.creative_mode,
+        peaceful_mode: statistics.peaceful_mode,
         house_state: house_state.clone(),
         marriage_state: marriage_state.clone(),
         quest_log: quest_log.clone(),
@@ -655,13 +926,24 @@ fn write_save(
         harvest_stats: harvest_stats.clone(),
         animal_product_stats: animal_product_stats.clone(),
         economy_stats: economy_stats.clone(),
+        daily_ledger: daily_ledger.clone(),
         daily_talk_tracker: daily_talk_tracker.clone(),
         gift_decay_tracker: gift_decay_tracker.clone(),
+        gift_history: gift_history.clone(),
+        building_placements: *building_placements,
+        overflow_store: overflow_store.clone(),
+        heart_event_log: heart_event_log.clone(),
+        mailbox: mailbox.clone(),
         tool_upgrade_queue: tool_upgrade_queue.clone(),
         shipping_bin_quality: shipping_bin_quality.clone(),
         festival_state: festival_state.clone(),
         farm_visit_tracker: farm_visit_tracker.clone(),
+        farm_layout,
+        silo_state: silo_state.clone(),
+        pinned_recipes: pinned_recipes.clone(),
+        player_profile: player_profile.clone(),
         chests: chests.to_vec(),
+        fish_tanks: fish_tanks.to_vec(),
         placed_machines: placed_machines.to_vec(),
     };
 
@@ -770,6 +1052,27 @@ fn scan_save_slots(mut cache: ResMut<SaveSlotInfoCache>) {
     info!("Save slot scan complete. Found {} slots.", NUM_SAVE_SLOTS);
 }
 
+fn track_unsaved_changes(
+    mut save_events: EventReader<SaveCompleteEvent>,
+    mut load_events: EventReader<LoadCompleteEvent>,
+    player_state: Res<PlayerState>,
+    inventory: Res<Inventory>,
+    calendar: Res<Calendar>,
+    mut unsaved: ResMut<UnsavedChanges>,
+) {
+    let saved = save_events.read().any(|ev| ev.success);
+    let loaded = load_events.read().any(|ev| ev.success);
+    if saved || loaded {
+        unsaved.mark_saved(&player_state, &inventory, &calendar);
+    } else {
+        unsaved.refresh(&player_state, &inventory, &calendar);
+    }
+}
+
+fn reset_unsaved_changes(mut unsaved: ResMut<UnsavedChanges>) {
+    *unsaved = UnsavedChanges::default();
+}
+
 fn tick_session_timer(
     time: Res<Time>,
     mut session: ResMut<SessionTimer>,
@@ -784,6 +1087,20 @@ fn tick_session_timer(
     }
 }
 
+/// Flag the farm as a creative one the moment creative tools are used on it.
+fn mark_creative_farm(settings: Res<GameSettings>, mut stats: ResMut<GameStatistics>) {
+    if settings.creative_tools && !stats.creative_mode {
+        stats.creative_mode = true;
+    }
+}
+
+/// Flag the farm as a peaceful one the moment peaceful mode is used on it.
+fn mark_peaceful_farm(settings: Res<GameSettings>, mut stats: ResMut<GameStatistics>) {
+    if settings.peaceful && !stats.peaceful_mode {
+        stats.peaceful_mode = true;
+    }
+}
+
 fn track_gold_earned(
     mut gold_events: EventReader<GoldChangeEvent>,
     mut stats: ResMut<GameStatistics>,
@@ -819,6 +1136,7 @@ fn handle_save_request(
     ext: ExtendedResources,
     player_grid_q: Query<&GridPosition, With<Player>>,
     chest_query: Query<&StorageChest, With<ChestMarker>>,
+    fish_tank_query: Query<&FishTank>,
     machine_query: Query<(&ProcessingMachine, &GridPosition)>,
 ) {
     for ev in save_events.read() {
@@ -833,6 +1151,7 @@ fn handle_save_request(
 
         // Collect all chest contents from ECS entities
         let chests: Vec<StorageChest> = chest_query.iter().cloned().collect();
+        let fish_tanks: Vec<FishTank> = fish_tank_query.iter().cloned().collect();
 
         // Collect all placed processing machines from ECS entities
         let placed_machines: Vec<SavedMachine> = machine_query
@@ -843,8 +1162,11 @@ fn handle_save_request(
                 machine_type: machine.machine_type,
                 input_item: machine.input_item.clone(),
                 output_item: machine.output_item.clone(),
+                output_quantity: machine.output_quantity,
                 processing_time_remaining: machine.processing_time_remaining,
                 is_ready: machine.is_ready,
+                output_quality: machine.output_quality,
+                aging_days: machine.aging_days,
             })
             .collect();
 
@@ -879,13 +1201,24 @@ fn handle_save_request(
             &ext.harvest_stats,
             &ext.animal_product_stats,
             &ext.economy_stats,
+            &ext.daily_ledger,
             &ext.daily_talk_tracker,
             &ext.gift_decay_tracker,
+            &ext.gift_history,
+            &ext.building_placements,
+            &ext.overflow_store,
+            &ext.heart_event_log,
+            &ext.mailbox,
             &ext.tool_upgrade_queue,
             &ext.shipping_bin_quality,
             &ext.festival_state,
             &ext.farm_visit_tracker,
+            *ext.farm_layout,
+            &ext.silo_state,
+            &ext.pinned_recipes,
+            &ext.player_profile,
             &chests,
+            &fish_tanks,
             &placed_machines,
         ) {
             Ok(()) => {
@@ -958,6 +1291,8 @@ fn handle_load_request(
                 core.statistics.total_items_shipped = file.total_items_shipped;
                 core.statistics.play_time_seconds = file.play_time_seconds;
                 core.statistics.farm_name = file.farm_name;
+                core.statistics.creative_mode = file.creative_mode;
+                core.statistics.peaceful_mode = file.peaceful_mode;
 
                 *ext.house_state = file.house_state;
                 *ext.marriage_state = file.marriage_state;
@@ -976,13 +1311,23 @@ fn handle_load_request(
                 *ext.harvest_stats = file.harvest_stats;
                 *ext.animal_product_stats = file.animal_product_stats;
                 *ext.economy_stats = file.economy_stats;
+                *ext.daily_ledger = file.daily_ledger;
                 *ext.daily_talk_tracker = file.daily_talk_tracker;
                 *ext.gift_decay_tracker = file.gift_decay_tracker;
+                *ext.gift_history = file.gift_history;
+                *ext.building_placements = file.building_placements;
+                *ext.overflow_store = file.overflow_store;
+                *ext.heart_event_log = file.heart_event_log;
+                *ext.mailbox = file.mailbox;
                 *ext.tool_upgrade_queue = file.tool_upgrade_queue;
                 *ext.shipping_bin_quality = file.shipping_bin_quality;
                 *ext.festival_state = file.festival_state;
                 ext.festival_state.restore_runtime_state();
                 *ext.farm_visit_tracker = file.farm_visit_tracker;
+                *ext.farm_layout = file.farm_layout;
+                *ext.silo_state = file.silo_state;
+                *ext.pinned_recipes = file.pinned_recipes;
+                *ext.player_profile = file.player_profile;
 
                 // Restore storage chests: despawn any existing chest entities
                 // and spawn saved ones.
@@ -991,8 +1336,9 @@ fn handle_load_request(
                 }
                 for chest in file.chests {
                     let (gx, gy) = chest.grid_pos;
-                    let world_x = gx as f32 * TILE_SIZE + TILE_SIZE * 0.5;
-                    let world_y = gy as f32 * TILE_SIZE + TILE_SIZE * 0.5;
+                    let wc = grid_to_world_center(gx, gy);
+                    let world_x = wc.x;
+                    let world_y = wc.y;
 
                     let chest_sprite = if chests.chest_sprites.loaded {
                         let mut s = Sprite::from_atlas_image(
@@ -1022,6 +1368,14 @@ fn handle_load_request(
                     ));
                 }
 
+                // Restore fish tanks; their fish icons are rebuilt by the world domain.
+                for entity in chests.existing_fish_tanks.iter() {
+                    commands.entity(entity).despawn_recursive();
+                }
+                for tank in file.fish_tanks {
+                    spawn_fish_tank(&mut commands, tank);
+                }
+
                 // Restore processing machines: despawn existing, spawn from save, rebuild registry.
                 for entity in machines.existing_machines.iter() {
                     commands.entity(entity).despawn_recursive();
@@ -1073,13 +1427,18 @@ fn handle_load_request(
                                 "recycling_machine"
                             }
                             crate::crafting::machines::MachineType::CrabPot => "crab_pot",
+                            crate::crafting::machines::MachineType::SeedMaker => "seed_maker",
+                            crate::crafting::machines::MachineType::Cask => "cask",
                         }
                     };
                     let mut restored = ProcessingMachine::new(saved.machine_type);
                     restored.input_item = saved.input_item;
                     restored.output_item = saved.output_item;
+                    restored.output_quantity = saved.output_quantity;
                     restored.processing_time_remaining = saved.processing_time_remaining;
                     restored.is_ready = saved.is_ready;
+                    restored.output_quality = saved.output_quality;
+                    restored.aging_days = saved.aging_days;
                     let entity = commands
                         .spawn((
                             restored,
@@ -1149,12 +1508,13 @@ fn handle_new_game(
     mut ext: ExtendedResourcesMut,
     mut machine_registry: ResMut<ProcessingMachineRegistry>,
     existing_chests: Query<Entity, With<ChestMarker>>,
+    existing_fish_tanks: Query<Entity, With<FishTank>>,
     existing_machines: Query<Entity, With<ProcessingMachine>>,
 ) {
     for ev in new_game_events.read() {
         info!(
-            "Starting new game in slot {} with farm name '{}'",
-            ev.active_slot, ev.farm_name
+            "Starting new game in slot {} for {} on '{}' ({:?} layout)",
+            ev.active_slot, ev.player_name, ev.farm_name, ev.farm_layout
         );
 
         active_slot.slot = ev.active_slot;
@@ -1164,6 +1524,11 @@ fn handle_new_game(
             commands.entity(entity).despawn_recursive();
         }
 
+        // Despawn any placed fish tanks from a previous session
+        for entity in existing_fish_tanks.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+
         // Despawn any placed machine entities from a previous session
         for entity in existing_machines.iter() {
             commands.entity(entity).despawn_recursive();
@@ -1171,9 +1536,12 @@ fn handle_new_game(
         machine_registry.machines.clear();
 
         // Reset all shared resources to default state
-        *core.calendar = Calendar::default();
+        *core.calendar = ev.starting_calendar();
         *player_state = PlayerState::default();
-        *core.inventory = Inventory::default();
+        *core.inventory = Inventory {
+            unlocked_slots: STARTING_UNLOCKED_SLOTS,
+            ..Inventory::default()
+        };
         *core.farm_state = FarmState::default();
         *core.animal_state = AnimalState::default();
         *core.relationships = Relationships::default();
@@ -1202,12 +1570,22 @@ fn handle_new_game(
         *ext.harvest_stats = crate::economy::stats::HarvestStats::default();
         *ext.animal_product_stats = crate::economy::stats::AnimalProductStats::default();
         *ext.economy_stats = crate::economy::gold::EconomyStats::default();
+        *ext.daily_ledger = crate::economy::gold::DailyLedger::default();
         *ext.daily_talk_tracker = crate::npcs::dialogue::DailyTalkTracker::default();
         *ext.gift_decay_tracker = crate::npcs::map_events::GiftDecayTracker::default();
+        *ext.gift_history = GiftHistory::default();
+        *ext.building_placements = BuildingPlacements::default();
+        *ext.overflow_store = OverflowStore::default();
+        *ext.heart_event_log = crate::npcs::heart_events::HeartEventLog::default();
+        *ext.mailbox = Mailbox::default();
         *ext.tool_upgrade_queue = ToolUpgradeQueue::default();
         *ext.shipping_bin_quality = ShippingBinQuality::default();
         *ext.festival_state = FestivalState::default();
         *ext.farm_visit_tracker = FarmVisitTracker::default();
+        *ext.farm_layout = ev.farm_layout;
+        *ext.silo_state = SiloState::default();
+        *ext.pinned_recipes = PinnedRecipes::default();
+        *ext.player_profile = ev.player_profile();
 
         // Starter items are granted by grant_starter_items in player/interaction.rs
         // (runs on first frame of Playing state when inventory is empty).
@@ -1263,3 +1641,129 @@ fn quicksave_keybind(
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gold_change_after_save_marks_progress_dirty_until_next_save() {
+        let mut player_state = PlayerState::default();
+        let inventory = Inventory::default();
+        let calendar = Calendar::default();
+        let mut unsaved = UnsavedChanges::default();
+
+        unsaved.mark_saved(&player_state, &inventory, &calendar);
+        unsaved.refresh(&player_state, &inventory, &calendar);
+        assert!(!unsaved.dirty);
+
+        player_state.gold += 250;
+        unsaved.refresh(&player_state, &inventory, &calendar);
+        assert!(unsaved.dirty);
+
+        unsaved.mark_saved(&player_state, &inventory, &calendar);
+        assert!(!unsaved.dirty);
+        unsaved.refresh(&player_state, &inventory, &calendar);
+        assert!(!unsaved.dirty);
+    }
+
+    fn new_game(start_season: Option<Season>, start_year: Option<u32>) -> NewGameEvent {
+        NewGameEvent {
+            farm_name: "Test Farm".to_string(),
+            player_name: "Robin".to_string(),
+            active_slot: 0,
+            farm_layout: FarmLayout::default(),
+            start_season,
+            start_year,
+        }
+    }
+
+    #[test]
+    fn new_game_can_start_in_fall_of_year_two() {
+        let calendar = new_game(Some(Season::Fall), Some(2)).starting_calendar();
+        assert_eq!(calendar.season, Season::Fall);
+        assert_eq!(calendar.year, 2);
+        assert_eq!(calendar.day, 1);
+        assert_eq!(calendar.hour, 6);
+    }
+
+    #[test]
+    fn new_game_defaults_to_spring_of_year_one() {
+        let calendar = new_game(None, None).starting_calendar();
+        let default = Calendar::default();
+        assert_eq!(calendar.season, default.season);
+        assert_eq!(calendar.year, default.year);
+        assert_eq!(calendar.day, default.day);
+    }
+
+    fn new_game_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<NewGameEvent>()
+            .init_resource::<ActiveSaveSlot>()
+            .init_resource::<PlayerState>()
+            .init_resource::<ProcessingMachineRegistry>()
+            .init_resource::<Calendar>()
+            .init_resource::<Inventory>()
+            .init_resource::<FarmState>()
+            .init_resource::<AnimalState>()
+            .init_resource::<Relationships>()
+            .init_resource::<MineState>()
+            .init_resource::<UnlockedRecipes>()
+            .init_resource::<ShippingBin>()
+            .init_resource::<GameStatistics>()
+            .init_resource::<HouseState>()
+            .init_resource::<MarriageState>()
+            .init_resource::<QuestLog>()
+            .init_resource::<SprinklerState>()
+            .init_resource::<ActiveBuffs>()
+            .init_resource::<EvaluationScore>()
+            .init_resource::<RelationshipStages>()
+            .init_resource::<Achievements>()
+            .init_resource::<TutorialState>()
+            .init_resource::<PlayStats>()
+            .init_resource::<BuildingLevels>()
+            .init_resource::<ShippingLog>()
+            .init_resource::<crate::fishing::FishEncyclopedia>()
+            .init_resource::<crate::fishing::skill::FishingSkill>()
+            .init_resource::<crate::economy::stats::HarvestStats>()
+            .init_resource::<crate::economy::stats::AnimalProductStats>()
+            .init_resource::<crate::economy::gold::EconomyStats>()
+            .init_resource::<crate::economy::gold::DailyLedger>()
+            .init_resource::<crate::npcs::dialogue::DailyTalkTracker>()
+            .init_resource::<crate::npcs::map_events::GiftDecayTracker>()
+            .init_resource::<GiftHistory>()
+            .init_resource::<BuildingPlacements>()
+            .init_resource::<OverflowStore>()
+            .init_resource::<crate::npcs::heart_events::HeartEventLog>()
+            .init_resource::<Mailbox>()
+            .init_resource::<ToolUpgradeQueue>()
+            .init_resource::<ShippingBinQuality>()
+            .init_resource::<FestivalState>()
+            .init_resource::<FarmVisitTracker>()
+            .init_resource::<FarmLayout>()
+            .init_resource::<SiloState>()
+            .init_resource::<PinnedRecipes>()
+            .init_resource::<PlayerProfile>()
+            .add_systems(Update, handle_new_game);
+        app
+    }
+
+    #[test]
+    fn new_game_applies_names_used_in_dialogue() {
+        let mut app = new_game_app();
+        app.world_mut().send_event(new_game(None, None));
+        app.update();
+
+        let world = app.world();
+        let profile = world.resource::<PlayerProfile>();
+        let farm_name = &world.resource::<GameStatistics>().farm_name;
+
+        assert_eq!(profile.name, "Robin");
+        assert_eq!(farm_name, "Test Farm");
+        assert_eq!(
+            profile.fill_tokens("Hi, {player}! How's {farm}?", farm_name),
+            "Hi, Robin! How's Test Farm?"
+        );
+    }
+}
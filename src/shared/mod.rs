@@ -9,7 +9,7 @@ pub use schedule::*;
 
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 // ═══════════════════════════════════════════════════════════════════════
 // GAME STATE — top-level state machine
@@ -35,6 +35,8 @@ pub enum GameState {
     RelationshipsView,
     MapView,
     FishEncyclopedia,
+    Mailbox,
+    QuestBoard,
 }
 
 // ═══════════════════════════════════════════════════════════════════════
@@ -149,6 +151,57 @@ impl Calendar {
     pub fn time_float(&self) -> f32 {
         self.hour as f32 + (self.minute as f32 / 60.0)
     }
+
+    pub fn day_period(&self) -> DayPeriod {
+        DayPeriod::from_hour(self.hour)
+    }
+}
+
+/// Coarse time-of-day bucket used for flavour text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DayPeriod {
+    Morning,   // 6:00 - 11:59
+    Afternoon, // 12:00 - 16:59
+    Evening,   // 17:00 - 20:59
+    Night,     // 21:00 - 5:59, including the hours past midnight
+}
+
+impl DayPeriod {
+    pub fn from_hour(hour: u8) -> Self {
+        match hour {
+            6..=11 => DayPeriod::Morning,
+            12..=16 => DayPeriod::Afternoon,
+            17..=20 => DayPeriod::Evening,
+            _ => DayPeriod::Night,
+        }
+    }
+}
+
+/// Set when the day ends because the player collapsed (the 2 AM rollover,
+/// or running out of stamina after midnight) instead of going to bed. The
+/// player domain applies the penalty while handling the DayEndEvent.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CollapsePending(pub bool);
+
+/// Set when the player goes to bed before midnight. The player domain turns
+/// it into the next morning's well-rested bonus while handling the DayEndEvent.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct WellRestedPending(pub bool);
+
+/// Stores the weather of the most recently ended day so other domains can
+/// check whether it rained *today* (the ended day) rather than tomorrow.
+/// Updated by the calendar every time a DayEndEvent is processed.
+#[derive(Resource, Debug, Clone)]
+pub struct PreviousDayWeather {
+    pub weather: Weather,
+}
+
+impl Default for PreviousDayWeather {
+    fn default() -> Self {
+        Self {
+            weather: Weather::Sunny,
+        }
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════
@@ -291,6 +344,9 @@ pub struct PlayerState {
     pub save_grid_x: i32,
     #[serde(default)]
     pub save_grid_y: i32,
+    /// Set once the general store's one-time starter bundle is bought.
+    #[serde(default)]
+    pub starter_bundle_bought: bool,
 }
 
 impl Default for PlayerState {
@@ -314,10 +370,59 @@ impl Default for PlayerState {
             current_map: MapId::PlayerHouse,
             save_grid_x: 8,
             save_grid_y: 8,
+            starter_bundle_bought: false,
         }
     }
 }
 
+/// Longest farm or player name accepted at new game, in characters.
+pub const MAX_NAME_LEN: usize = 16;
+
+/// Who the player is, chosen on the new-game setup screen.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerProfile {
+    pub name: String,
+}
+
+impl Default for PlayerProfile {
+    fn default() -> Self {
+        Self {
+            name: "Farmer".to_string(),
+        }
+    }
+}
+
+impl PlayerProfile {
+    /// Replace the `{player}` and `{farm}` tokens in a line of dialogue.
+    pub fn fill_tokens(&self, line: &str, farm_name: &str) -> String {
+        line.replace("{player}", &self.name)
+            .replace("{farm}", farm_name)
+    }
+}
+
+/// Trim a typed name and check it is non-empty and at most `MAX_NAME_LEN`
+/// characters.
+pub fn validate_name(raw: &str) -> Result<String, String> {
+    let name = raw.trim();
+    if name.is_empty() {
+        return Err("Names can't be empty.".to_string());
+    }
+    if name.chars().count() > MAX_NAME_LEN {
+        return Err(format!("Names can be at most {} characters.", MAX_NAME_LEN));
+    }
+    Ok(name.to_string())
+}
+
+/// Append typed text to a name field, stopping at `MAX_NAME_LEN` characters.
+pub fn push_name_text(field: &mut String, text: &str) {
+    for c in text.chars().filter(|c| !c.is_control()) {
+        if field.chars().count() >= MAX_NAME_LEN {
+            break;
+        }
+        field.push(c);
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // INVENTORY
 // ═══════════════════════════════════════════════════════════════════════
@@ -361,6 +466,9 @@ pub struct ItemDef {
 pub struct InventorySlot {
     pub item_id: ItemId,
     pub quantity: u8,
+    /// Quality of every item in this stack. Stacks only merge with equal quality.
+    #[serde(default)]
+    pub quality: ItemQuality,
 }
 
 #[derive(Resource, Debug, Clone, Serialize, Deserialize)]
@@ -368,6 +476,14 @@ pub struct Inventory {
     /// `TOTAL_INVENTORY_SLOTS` slots: 0-11 = hotbar, 12-35 = backpack
     pub slots: Vec<Option<InventorySlot>>,
     pub selected_slot: usize,
+    /// Number of usable slots, counted from the first hotbar slot. Grows with
+    /// backpack upgrades; saves from before upgrades existed get every slot.
+    #[serde(default = "default_unlocked_slots")]
+    pub unlocked_slots: usize,
+}
+
+fn default_unlocked_slots() -> usize {
+    TOTAL_INVENTORY_SLOTS
 }
 
 impl Default for Inventory {
@@ -375,22 +491,56 @@ impl Default for Inventory {
         Self {
             slots: vec![None; TOTAL_INVENTORY_SLOTS],
             selected_slot: 0,
+            unlocked_slots: TOTAL_INVENTORY_SLOTS,
         }
     }
 }
 
 impl Inventory {
+    /// The backpack upgrade the player can buy next, if any.
+    pub fn next_backpack_upgrade(&self) -> Option<&'static str> {
+        BACKPACK_UPGRADES
+            .iter()
+            .find(|(_, slots)| *slots > self.unlocked_slots)
+            .map(|(id, _)| *id)
+    }
+
+    /// Unlock the slots granted by `item_id` if it is the next backpack
+    /// upgrade. Returns false (and changes nothing) otherwise.
+    pub fn apply_backpack_upgrade(&mut self, item_id: &str) -> bool {
+        if self.next_backpack_upgrade() != Some(item_id) {
+            return false;
+        }
+        if let Some((_, slots)) = BACKPACK_UPGRADES.iter().find(|(id, _)| *id == item_id) {
+            self.unlocked_slots = *slots;
+        }
+        true
+    }
+
     /// Try to add an item. Returns the quantity that couldn't fit.
     pub fn try_add(&mut self, item_id: &str, quantity: u8, max_stack: u8) -> u8 {
+        self.try_add_quality(item_id, quantity, max_stack, ItemQuality::Normal)
+    }
+
+    /// Try to add an item of a given quality. Only stacks onto slots holding
+    /// the same item at the same quality. Returns the quantity that couldn't fit.
+    pub fn try_add_quality(
+        &mut self,
+        item_id: &str,
+        quantity: u8,
+        max_stack: u8,
+        quality: ItemQuality,
+    ) -> u8 {
         let mut remaining = quantity;
+        let unlocked = self.unlocked_slots;
 
-        // First pass: stack onto existing slots with same item
-        for slot in self.slots.iter_mut() {
+        // First pass: stack onto existing slots with same item and quality
+        for slot in self.slots.iter_mut().take(unlocked) {
             if remaining == 0 {
                 break;
             }
             if let Some(ref mut s) = slot {
-                if s.item_id == item_id && s.quantity < max_stack {
+                if s.item_id == item_id && s.quality == quality && s.quantity < max_stack {
                     let space = max_stack - s.quantity;
                     let add = remaining.min(space);
                     s.quantity += add;
@@ -400,7 +550,7 @@ impl Inventory {
         }
 
         // Second pass: fill empty slots
-        for slot in self.slots.iter_mut() {
+        for slot in self.slots.iter_mut().take(unlocked) {
             if remaining == 0 {
                 break;
             }
@@ -409,6 +559,7 @@ impl Inventory {
                 *slot = Some(InventorySlot {
                     item_id: item_id.to_string(),
                     quantity: add,
+                    quality,
                 });
                 remaining -= add;
             }
@@ -417,6 +568,59 @@ impl Inventory {
         remaining
     }
 
+    /// Move up to `quantity` items from slot `from` onto slot `to`.
+    ///
+    /// An empty target takes the items and a matching stack (same item and
+    /// quality) takes as many as fit under `max_stack`. A different item swaps
+    /// places, but only when the whole stack is moving. Locked slots can't be
+    /// targeted. Returns how many items left `from`.
+    pub fn move_stack(&mut self, from: usize, to: usize, quantity: u8, max_stack: u8) -> u8 {
+        if from == to || from >= self.slots.len() || to >= self.unlocked_slots.min(self.slots.len())
+        {
+            return 0;
+        }
+        let Some(source) = self.slots[from].clone() else {
+            return 0;
+        };
+        let quantity = quantity.min(source.quantity);
+        if quantity == 0 {
+            return 0;
+        }
+
+        // None: empty target. Some(Some(n)): matching stack of n. Some(None): another item.
+        let target = self.slots[to].as_ref().map(|t| {
+            (t.item_id == source.item_id && t.quality == source.quality).then_some(t.quantity)
+        });
+        let moved = match target {
+            None => quantity,
+            Some(Some(target_qty)) => quantity.min(max_stack.saturating_sub(target_qty)),
+            Some(None) if quantity == source.quantity => {
+                self.slots.swap(from, to);
+                return quantity;
+            }
+            Some(None) => 0,
+        };
+        if moved == 0 {
+            return 0;
+        }
+
+        if let Some(target) = self.slots[to].as_mut() {
+            target.quantity += moved;
+        } else {
+            self.slots[to] = Some(InventorySlot {
+                quantity: moved,
+                ..source
+            });
+        }
+        if let Some(remaining) = self.slots[from].as_mut() {
+            remaining.quantity -= moved;
+            if remaining.quantity == 0 {
+                self.slots[from] = None;
+            }
+        }
+        moved
+    }
+
     /// Remove quantity of an item. Returns how many were actually removed.
     pub fn try_remove(&mut self, item_id: &str, quantity: u8) -> u8 {
         let mut remaining = quantity;
@@ -438,6 +642,28 @@ impl Inventory {
         quantity - remaining
     }
 
+    /// Remove quantity of an item at a specific quality. Returns how many
+    /// were actually removed.
+    pub fn try_remove_quality(&mut self, item_id: &str, quantity: u8, quality: ItemQuality) -> u8 {
+        let mut remaining = quantity;
+        for slot in self.slots.iter_mut() {
+            if remaining == 0 {
+                break;
+            }
+            if let Some(ref mut s) = slot {
+                if s.item_id == item_id && s.quality == quality {
+                    let remove = remaining.min(s.quantity);
+                    s.quantity -= remove;
+                    remaining -= remove;
+                    if s.quantity == 0 {
+                        *slot = None;
+                    }
+                }
+            }
+        }
+        quantity - remaining
+    }
+
     pub fn count(&self, item_id: &str) -> u32 {
         self.slots
             .iter()
@@ -447,11 +673,86 @@ impl Inventory {
             .sum()
     }
 
+    /// Count of an item at one specific quality.
+    pub fn count_quality(&self, item_id: &str, quality: ItemQuality) -> u32 {
+        self.slots
+            .iter()
+            .filter_map(|s| s.as_ref())
+            .filter(|s| s.item_id == item_id && s.quality == quality)
+            .map(|s| s.quantity as u32)
+            .sum()
+    }
+
     pub fn has(&self, item_id: &str, quantity: u8) -> bool {
         self.count(item_id) >= quantity as u32
     }
 }
 
+/// Lost and found: items that didn't fit in the backpack, waiting at the
+/// farmhouse until the player has room for them.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OverflowStore {
+    pub items: Vec<InventorySlot>,
+}
+
+impl OverflowStore {
+    /// Store items, merging into an entry with the same item and quality.
+    pub fn add(&mut self, item_id: &str, quantity: u8, quality: ItemQuality) {
+        let mut remaining = quantity;
+        for entry in self
+            .items
+            .iter_mut()
+            .filter(|e| e.item_id == item_id && e.quality == quality)
+        {
+            let add = remaining.min(u8::MAX - entry.quantity);
+            entry.quantity += add;
+            remaining -= add;
+        }
+        if remaining > 0 {
+            self.items.push(InventorySlot {
+                item_id: item_id.to_string(),
+                quantity: remaining,
+                quality,
+            });
+        }
+    }
+
+    /// Move as much as fits into the inventory, keeping the rest. Returns how
+    /// many items were handed back.
+    pub fn return_to(&mut self, inventory: &mut Inventory, max_stack: impl Fn(&str) -> u8) -> u32 {
+        let mut returned = 0;
+        for entry in &mut self.items {
+            let left = inventory.try_add_quality(
+                &entry.item_id,
+                entry.quantity,
+                max_stack(&entry.item_id),
+                entry.quality,
+            );
+            returned += (entry.quantity - left) as u32;
+            entry.quantity = left;
+        }
+        self.items.retain(|e| e.quantity > 0);
+        returned
+    }
+}
+
+/// Add items to the inventory, sending whatever doesn't fit to the lost and
+/// found instead of dropping it. Returns the quantity that went to overflow.
+pub fn grant_item(
+    inventory: &mut Inventory,
+    overflow: &mut OverflowStore,
+    item_id: &str,
+    quantity: u8,
+    max_stack: u8,
+    quality: ItemQuality,
+) -> u8 {
+    let left = inventory.try_add_quality(item_id, quantity, max_stack, quality);
+    if left > 0 {
+        overflow.add(item_id, left, quality);
+    }
+    left
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // ITEM REGISTRY — loaded from data
 // ═══════════════════════════════════════════════════════════════════════
@@ -490,6 +791,13 @@ pub struct CropDef {
     pub regrow_days: u8, // days to regrow after harvest (if regrows)
     pub sell_price: u32,
     pub sprite_stages: Vec<u32>, // atlas indices per growth stage
+    /// Grows up a trellis that blocks walking while the crop is planted.
+    #[serde(default)]
+    pub needs_trellis: bool,
+    /// Hardy enough to get one extra day after its season ends when
+    /// `GameSettings::off_season_grace` is on.
+    #[serde(default)]
+    pub off_season_tolerant: bool,
 }
 
 #[derive(Component, Debug, Clone, Serialize, Deserialize)]
@@ -522,6 +830,43 @@ pub struct FarmState {
     pub crops: HashMap<(i32, i32), CropTile>,
     /// Objects on the farm (trees, rocks, stumps). Key = (x, y).
     pub objects: HashMap<(i32, i32), FarmObject>,
+    /// 3×3 giant crops that replaced a block of mature crops overnight.
+    #[serde(default)]
+    pub giant_crops: Vec<GiantCrop>,
+    /// Fertilizer worked into a tile. Key = (x, y).
+    #[serde(default)]
+    pub fertilizer: HashMap<(i32, i32), Fertilizer>,
+}
+
+/// Soil fertilizer worked into a tile to improve its next harvest's quality.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Fertilizer {
+    Basic,
+}
+
+impl Fertilizer {
+    /// Tier used by the harvest-quality roll.
+    pub fn tier(self) -> u32 {
+        match self {
+            Fertilizer::Basic => 1,
+        }
+    }
+}
+
+/// A giant crop covering the 3×3 block whose bottom-left tile is `origin`.
+/// Broken with an axe for a large harvest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GiantCrop {
+    pub origin: (i32, i32),
+    pub crop_id: ItemId,
+}
+
+impl GiantCrop {
+    /// Whether the grid tile (x, y) is part of this giant crop.
+    pub fn covers(&self, x: i32, y: i32) -> bool {
+        (self.origin.0..self.origin.0 + 3).contains(&x)
+            && (self.origin.1..self.origin.1 + 3).contains(&y)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -535,6 +880,7 @@ pub enum FarmObject {
     Fence,
     Path,
     ShippingBin,
+    LightningRod,
 }
 
 // ═══════════════════════════════════════════════════════════════════════
@@ -571,6 +917,12 @@ pub struct Animal {
     pub fed_today: bool,
     pub petted_today: bool,
     pub product_ready: bool,
+    /// Fallen ill from neglect; produces nothing until given medicine.
+    #[serde(default)]
+    pub sick: bool,
+    /// Day-ends spent sick. Untreated animals die after a few.
+    #[serde(default)]
+    pub days_sick: u8,
 }
 
 #[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
@@ -668,11 +1020,26 @@ pub struct NpcDef {
     pub gift_preferences: HashMap<ItemId, GiftPreference>,
     pub default_dialogue: Vec<String>,
     pub heart_dialogue: HashMap<u8, Vec<String>>, // hearts reached → new lines
+    /// Optional lines tagged by weather, season or time of day.
+    #[serde(default)]
+    pub context_dialogue: ContextDialogue,
     pub is_marriageable: bool,
     pub sprite_index: u32,
     pub portrait_index: u32,
 }
 
+/// Tagged line pools an NPC can draw on when the moment matches, so they
+/// remark on the rain or the first snow instead of their usual small talk.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContextDialogue {
+    #[serde(default)]
+    pub weather: HashMap<Weather, Vec<String>>,
+    #[serde(default)]
+    pub season: HashMap<Season, Vec<String>>,
+    #[serde(default)]
+    pub time_of_day: HashMap<DayPeriod, Vec<String>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScheduleEntry {
     pub time: f32, // e.g. 9.0 = 9:00 AM
@@ -689,17 +1056,58 @@ pub struct NpcSchedule {
     /// Season overrides
     pub rain_override: Option<Vec<ScheduleEntry>>,
     pub festival_override: Option<Vec<ScheduleEntry>>,
+    /// Hour the NPC turns in for the night. From then until the first entry
+    /// of the next day they are home asleep, off every map. `None` keeps
+    /// them out all night.
+    #[serde(default)]
+    pub bedtime: Option<f32>,
 }
 
 #[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Relationships {
     /// NPC id → friendship points (0-1000, 100 per heart)
     pub friendship: HashMap<NpcId, u32>,
-    pub gifted_today: HashMap<NpcId, bool>,
+    /// NPC id → gifts accepted today. Older saves stored a bool per NPC.
+    #[serde(default, deserialize_with = "deserialize_gift_counts")]
+    pub gifted_today: HashMap<NpcId, u8>,
     pub spouse: Option<NpcId>,
 }
 
+fn deserialize_gift_counts<'de, D>(deserializer: D) -> Result<HashMap<NpcId, u8>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum GiftCount {
+        Count(u8),
+        Flag(bool),
+    }
+
+    let raw = HashMap::<NpcId, GiftCount>::deserialize(deserializer)?;
+    Ok(raw
+        .into_iter()
+        .map(|(id, count)| match count {
+            GiftCount::Count(n) => (id, n),
+            GiftCount::Flag(gifted) => (id, gifted as u8),
+        })
+        .collect())
+}
+
 impl Relationships {
+    pub fn gifts_today(&self, npc_id: &str) -> u8 {
+        self.gifted_today.get(npc_id).copied().unwrap_or(0)
+    }
+
+    pub fn has_gifted_today(&self, npc_id: &str) -> bool {
+        self.gifts_today(npc_id) > 0
+    }
+
+    pub fn record_gift(&mut self, npc_id: &str) {
+        let count = self.gifted_today.entry(npc_id.to_string()).or_insert(0);
+        *count = count.saturating_add(1);
+    }
+
     pub fn hearts(&self, npc_id: &str) -> u8 {
         let points = self.friendship.get(npc_id).copied().unwrap_or(0);
         (points / 100).min(10) as u8
@@ -711,6 +1119,38 @@ impl Relationships {
     }
 }
 
+/// Items each villager has received this week. Cleared when Sunday ends.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GiftHistory {
+    pub this_week: HashMap<NpcId, HashSet<ItemId>>,
+}
+
+impl GiftHistory {
+    pub fn given_this_week(&self, npc_id: &str, item_id: &str) -> bool {
+        self.this_week
+            .get(npc_id)
+            .is_some_and(|items| items.contains(item_id))
+    }
+
+    pub fn record(&mut self, npc_id: &str, item_id: &str) {
+        self.this_week
+            .entry(npc_id.to_string())
+            .or_default()
+            .insert(item_id.to_string());
+    }
+
+    /// This week's gifts to one villager, sorted for display.
+    pub fn items_for(&self, npc_id: &str) -> Vec<ItemId> {
+        let mut items: Vec<ItemId> = self
+            .this_week
+            .get(npc_id)
+            .map(|items| items.iter().cloned().collect())
+            .unwrap_or_default();
+        items.sort();
+        items
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Component, Debug, Clone)]
 pub struct Npc {
@@ -767,6 +1207,21 @@ pub struct Recipe {
     pub unlocked_by_default: bool,
 }
 
+impl Recipe {
+    /// Ingredients the inventory is short of, paired with how many more are
+    /// needed. The `any_fish` wildcard is resolved by cooking and skipped here.
+    pub fn missing_ingredients(&self, inventory: &Inventory) -> Vec<(ItemId, u8)> {
+        self.ingredients
+            .iter()
+            .filter(|(item_id, _)| item_id != "any_fish")
+            .filter_map(|(item_id, qty)| {
+                let have = inventory.count(item_id).min(u8::MAX as u32) as u8;
+                (have < *qty).then(|| (item_id.clone(), qty - have))
+            })
+            .collect()
+    }
+}
+
 #[derive(Resource, Debug, Clone, Default)]
 pub struct RecipeRegistry {
     pub recipes: HashMap<String, Recipe>,
@@ -777,6 +1232,29 @@ pub struct UnlockedRecipes {
     pub ids: Vec<String>,
 }
 
+/// Recipes the player pinned so they sort to the top of the crafting list.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PinnedRecipes {
+    pub ids: Vec<String>,
+}
+
+impl PinnedRecipes {
+    pub fn is_pinned(&self, recipe_id: &str) -> bool {
+        self.ids.iter().any(|id| id == recipe_id)
+    }
+
+    /// Pins or unpins `recipe_id`. Returns true if it is now pinned.
+    pub fn toggle(&mut self, recipe_id: &str) -> bool {
+        if let Some(pos) = self.ids.iter().position(|id| id == recipe_id) {
+            self.ids.remove(pos);
+            false
+        } else {
+            self.ids.push(recipe_id.to_string());
+            true
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // FISHING
 // ═══════════════════════════════════════════════════════════════════════
@@ -833,6 +1311,15 @@ pub struct MineState {
     pub current_floor: u8,         // 0 = not in mine
     pub deepest_floor_reached: u8, // for elevator
     pub elevator_floors: Vec<u8>,  // unlocked elevator stops (every 5)
+    /// Stacks dropped when passing out, waiting at the mine entrance.
+    #[serde(default)]
+    pub lost_items: Vec<InventorySlot>,
+    /// Day (`Calendar::total_days_elapsed`) the lost items were dropped.
+    #[serde(default)]
+    pub lost_items_day: u32,
+    /// Boss floors whose guaranteed reward has already been paid out.
+    #[serde(default)]
+    pub boss_rewards_claimed: Vec<u8>,
 }
 
 #[derive(Component, Debug, Clone)]
@@ -873,6 +1360,8 @@ pub struct SeasonChangeEvent {
 pub struct ItemPickupEvent {
     pub item_id: ItemId,
     pub quantity: u8,
+    /// Quality the picked-up items are stored at in the inventory.
+    pub quality: ItemQuality,
 }
 
 #[allow(dead_code)]
@@ -900,6 +1389,7 @@ pub struct ShopTransactionEvent {
     pub quantity: u8,
     pub total_cost: u32,
     pub is_purchase: bool, // true = buy, false = sell
+    pub quality: ItemQuality,
 }
 
 #[derive(Event, Debug, Clone)]
@@ -917,6 +1407,40 @@ pub struct MapTransitionEvent {
     pub to_y: i32,
 }
 
+/// Frames after an honored map transition during which further transitions
+/// are dropped, so a zone the player is still touching can't reload the map.
+pub const MAP_TRANSITION_COOLDOWN_FRAMES: u32 = 10;
+
+/// Frame of the last honored map transition.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TransitionCooldown {
+    pub last_frame: Option<u32>,
+}
+
+impl TransitionCooldown {
+    /// Whether a transition arriving on `frame` may be honored; records it if so.
+    pub fn try_claim(&mut self, frame: u32) -> bool {
+        if self
+            .last_frame
+            .is_some_and(|last| frame.wrapping_sub(last) < MAP_TRANSITION_COOLDOWN_FRAMES)
+        {
+            return false;
+        }
+        self.last_frame = Some(frame);
+        true
+    }
+}
+
+/// A `MapTransitionEvent` that was honored: the first one sent in its frame,
+/// outside the cooldown after the previous load. Every system that reacts to
+/// a map change reads this instead of the raw request.
+#[derive(Event, Debug, Clone)]
+pub struct HonoredMapTransitionEvent {
+    pub to_map: MapId,
+    pub to_x: i32,
+    pub to_y: i32,
+}
+
 #[derive(Event, Debug, Clone)]
 pub struct StaminaDrainEvent {
     pub amount: f32,
@@ -933,6 +1457,9 @@ pub struct GiftGivenEvent {
     pub npc_id: NpcId,
     pub item_id: ItemId,
     pub preference: GiftPreference,
+    /// The gift was wrapped in gift wrap and earns a bonus on top of the
+    /// preference points.
+    pub wrapped: bool,
 }
 
 #[derive(Event, Debug, Clone)]
@@ -970,6 +1497,10 @@ pub struct PlayMusicEvent {
 
 pub const TILE_SIZE: f32 = 16.0;
 pub const PIXEL_SCALE: f32 = 3.0; // render scale (16px × 3 = 48px on screen)
+/// Camera zoom bounds, in screen pixels per world pixel. Only whole steps are
+/// offered so sprites always land on whole screen pixels.
+pub const MIN_CAMERA_ZOOM: u8 = 2;
+pub const MAX_CAMERA_ZOOM: u8 = 4;
 pub const SCREEN_WIDTH: f32 = 960.0;
 pub const SCREEN_HEIGHT: f32 = 540.0;
 
@@ -982,6 +1513,22 @@ pub const MAX_HEALTH: f32 = 100.0;
 pub const HOTBAR_SLOTS: usize = 12;
 pub const BACKPACK_SLOTS: usize = 24;
 pub const TOTAL_INVENTORY_SLOTS: usize = HOTBAR_SLOTS + BACKPACK_SLOTS;
+/// A new game starts with only the hotbar usable.
+pub const STARTING_UNLOCKED_SLOTS: usize = HOTBAR_SLOTS;
+/// Backpack upgrades sold at the general store, in purchase order, with the
+/// number of usable slots each one brings the inventory up to.
+pub const BACKPACK_UPGRADES: [(&str, usize); 2] = [
+    ("backpack", HOTBAR_SLOTS + 12),
+    ("deluxe_backpack", TOTAL_INVENTORY_SLOTS),
+];
+/// Shop item id of the general store's one-time spring starter bundle.
+pub const STARTER_BUNDLE: &str = "starter_bundle";
+/// What the starter bundle hands over, as (item id, quantity).
+pub const STARTER_BUNDLE_ITEMS: [(&str, u8); 3] = [
+    ("turnip_seeds", 15),
+    ("potato_seeds", 5),
+    ("basic_sprinkler", 2),
+];
 
 pub const FRIENDSHIP_PER_HEART: u32 = 100;
 pub const MAX_HEARTS: u32 = 10;
@@ -1079,7 +1626,6 @@ impl ItemQuality {
         }
     }
 
-    #[allow(dead_code)]
     pub fn next(&self) -> Option<ItemQuality> {
         match self {
             ItemQuality::Normal => Some(ItemQuality::Silver),
@@ -1121,6 +1667,96 @@ impl StorageChest {
             grid_pos: (x, y),
         }
     }
+
+    /// True if any slot already holds this item, at any quality.
+    pub fn contains_item(&self, item_id: &str) -> bool {
+        self.slots
+            .iter()
+            .take(self.capacity)
+            .flatten()
+            .any(|s| s.item_id == item_id)
+    }
+
+    /// Store items of a given quality, stacking only onto slots holding the
+    /// same item at the same quality. Returns the quantity that didn't fit.
+    pub fn add_quality(&mut self, item_id: &str, quantity: u8, quality: ItemQuality) -> u8 {
+        let mut remaining = quantity;
+        let capacity = self.capacity;
+
+        for slot in self.slots.iter_mut().take(capacity).flatten() {
+            if remaining == 0 {
+                break;
+            }
+            if slot.item_id == item_id && slot.quality == quality {
+                let add = remaining.min(99u8.saturating_sub(slot.quantity));
+                slot.quantity += add;
+                remaining -= add;
+            }
+        }
+
+        for slot in self.slots.iter_mut().take(capacity) {
+            if remaining == 0 {
+                break;
+            }
+            if slot.is_none() {
+                *slot = Some(QualityStack {
+                    item_id: item_id.to_string(),
+                    quantity: remaining,
+                    quality,
+                });
+                remaining = 0;
+            }
+        }
+
+        remaining
+    }
+
+    /// Move every inventory stack whose item is already stored in this chest.
+    /// Items the chest doesn't hold yet stay in the inventory. Returns the
+    /// number of items moved.
+    pub fn deposit_matching(&mut self, inventory: &mut Inventory) -> u32 {
+        let mut moved = 0u32;
+        for inv_slot in inventory.slots.iter_mut() {
+            let Some(stack) = inv_slot.as_mut() else {
+                continue;
+            };
+            if !self.contains_item(&stack.item_id) {
+                continue;
+            }
+            let remaining = self.add_quality(&stack.item_id, stack.quantity, stack.quality);
+            moved += u32::from(stack.quantity - remaining);
+            if remaining == 0 {
+                *inv_slot = None;
+            } else {
+                stack.quantity = remaining;
+            }
+        }
+        moved
+    }
+
+    /// Move as much of the chest into the inventory as will fit. Returns the
+    /// number of items moved.
+    pub fn take_all(&mut self, inventory: &mut Inventory, item_registry: &ItemRegistry) -> u32 {
+        let mut moved = 0u32;
+        for chest_slot in self.slots.iter_mut() {
+            let Some(stack) = chest_slot.as_mut() else {
+                continue;
+            };
+            let max_stack = item_registry
+                .get(&stack.item_id)
+                .map(|def| def.stack_size)
+                .unwrap_or(99);
+            let remaining =
+                inventory.try_add_quality(&stack.item_id, stack.quantity, max_stack, stack.quality);
+            moved += u32::from(stack.quantity - remaining);
+            if remaining == 0 {
+                *chest_slot = None;
+            } else {
+                stack.quantity = remaining;
+            }
+        }
+        moved
+    }
 }
 
 /// Day/night ambient light level (0.0 = midnight dark, 1.0 = noon bright).
@@ -1219,11 +1855,17 @@ pub enum SpouseAction {
     StandOnPorch,
 }
 
+/// Most quests the player can have in progress before the board refuses more.
+pub const MAX_ACTIVE_QUESTS: usize = 6;
+
 /// Quest/bulletin board system.
 #[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
 pub struct QuestLog {
     pub active: Vec<Quest>,
     pub completed: Vec<String>,
+    /// Today's quests on the town bulletin board, waiting to be accepted.
+    #[serde(default)]
+    pub posted: Vec<Quest>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1441,6 +2083,38 @@ pub struct ShippingLog {
     pub shipped_items: HashMap<ItemId, u32>,
 }
 
+/// A letter in the farm mailbox, optionally carrying an item stack.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MailItem {
+    pub subject: String,
+    pub body: String,
+    pub attachment: Option<(ItemId, u8)>,
+}
+
+/// Letters for the player. `letters` are waiting in the farm mailbox;
+/// `pending` holds mail sent today, which arrives the next morning.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Mailbox {
+    pub letters: Vec<MailItem>,
+    #[serde(default)]
+    pub pending: Vec<MailItem>,
+}
+
+impl Mailbox {
+    /// Queue a letter for delivery tomorrow morning. A letter identical to
+    /// one already queued today is dropped.
+    pub fn send(&mut self, mail: MailItem) {
+        if !self.pending.contains(&mail) {
+            self.pending.push(mail);
+        }
+    }
+
+    /// Move everything sent today into the mailbox.
+    pub fn deliver(&mut self) {
+        self.letters.append(&mut self.pending);
+    }
+}
+
 /// Tutorial/hint system state.
 #[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TutorialState {
@@ -1449,6 +2123,12 @@ pub struct TutorialState {
     pub current_objective: Option<String>,
 }
 
+/// Leave the running game for the title screen. The world domain despawns
+/// gameplay entities before switching to `GameState::MainMenu`; callers that
+/// want to save first wait for `SaveCompleteEvent` before sending this.
+#[derive(Event, Debug, Clone, Copy, Default)]
+pub struct ReturnToTitleEvent;
+
 /// Contextual hint event — shows a non-intrusive tip when the player does something new.
 #[allow(dead_code)]
 #[derive(Event, Debug, Clone)]
@@ -1485,6 +2165,106 @@ pub enum BuildingKind {
     Silo,
 }
 
+/// Request to move the coop or barn to a new spot on the farm. `to` is the
+/// new bottom-left tile of the building's footprint.
+#[derive(Event, Debug, Clone)]
+pub struct BuildingMoveEvent {
+    pub building: BuildingKind,
+    pub to: (i32, i32),
+}
+
+/// Farm tile where the chicken coop stands on a new farm.
+pub const DEFAULT_COOP_ORIGIN: (i32, i32) = (24, 1);
+/// Farm tile where the barn stands on a new farm.
+pub const DEFAULT_BARN_ORIGIN: (i32, i32) = (2, 1);
+/// Farm tile where the barn's feed trough stands on a new farm.
+pub const DEFAULT_FEED_TROUGH_TILE: (i32, i32) = (5, 19);
+
+/// Where the movable farm buildings stand. Each origin is the bottom-left
+/// tile of the building's footprint.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildingPlacements {
+    pub coop: (i32, i32),
+    pub barn: (i32, i32),
+}
+
+impl Default for BuildingPlacements {
+    fn default() -> Self {
+        Self {
+            coop: DEFAULT_COOP_ORIGIN,
+            barn: DEFAULT_BARN_ORIGIN,
+        }
+    }
+}
+
+impl BuildingPlacements {
+    /// Current origin of a movable building; `None` for the house and silo.
+    pub fn origin(&self, building: BuildingKind) -> Option<(i32, i32)> {
+        match building {
+            BuildingKind::Coop => Some(self.coop),
+            BuildingKind::Barn => Some(self.barn),
+            BuildingKind::House | BuildingKind::Silo => None,
+        }
+    }
+
+    /// Move a building's origin. Ignored for buildings that can't move.
+    pub fn set_origin(&mut self, building: BuildingKind, origin: (i32, i32)) {
+        match building {
+            BuildingKind::Coop => self.coop = origin,
+            BuildingKind::Barn => self.barn = origin,
+            BuildingKind::House | BuildingKind::Silo => {}
+        }
+    }
+
+    /// How far a building has moved from where it stands on a new farm.
+    pub fn offset(&self, building: BuildingKind) -> (i32, i32) {
+        let default = Self::default();
+        match (self.origin(building), default.origin(building)) {
+            (Some((x, y)), Some((dx, dy))) => (x - dx, y - dy),
+            _ => (0, 0),
+        }
+    }
+
+    /// Tile of the feed trough, which moves along with the barn.
+    pub fn feed_trough(&self) -> (i32, i32) {
+        let (dx, dy) = self.offset(BuildingKind::Barn);
+        (
+            DEFAULT_FEED_TROUGH_TILE.0 + dx,
+            DEFAULT_FEED_TROUGH_TILE.1 + dy,
+        )
+    }
+}
+
+/// Hay held by a built silo.
+pub const SILO_HAY_CAPACITY: u32 = 240;
+
+/// Hay stored in the farm silo. Scything grass fills it; in winter the
+/// animals are fed from it automatically at day end.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SiloState {
+    pub hay: u32,
+    /// Maximum hay the silo holds. 0 until a silo is built.
+    pub capacity: u32,
+}
+
+impl SiloState {
+    /// Store up to `amount` hay. Returns how much actually fit.
+    pub fn store(&mut self, amount: u32) -> u32 {
+        let added = amount.min(self.capacity.saturating_sub(self.hay));
+        self.hay += added;
+        added
+    }
+
+    /// Take one piece of hay out of the silo. Returns false when empty.
+    pub fn take_one(&mut self) -> bool {
+        if self.hay == 0 {
+            return false;
+        }
+        self.hay -= 1;
+        true
+    }
+}
+
 /// Tracks total play statistics for achievements and end-of-year summary.
 #[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PlayStats {
@@ -1536,6 +2316,43 @@ impl InputBlocks {
 #[derive(Resource, Default, Debug)]
 pub struct InteractionClaimed(pub bool);
 
+/// What an interact press is aimed at: the single nearest interactable the
+/// player faces, picked by `resolve_interaction_target` before any F-key
+/// handler runs. Handlers only respond when the target is one of theirs.
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct InteractionTarget(pub Option<(Entity, InteractTargetKind)>);
+
+impl InteractionTarget {
+    /// The target entity, if it is of the given kind.
+    pub fn entity_of(&self, kind: InteractTargetKind) -> Option<Entity> {
+        self.0
+            .filter(|(_, target_kind)| *target_kind == kind)
+            .map(|(entity, _)| entity)
+    }
+}
+
+/// The families of things an interact press can resolve to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InteractTargetKind {
+    Npc,
+    /// Any entity with an `Interactable` component.
+    Object,
+    Chest,
+    FishTank,
+}
+
+/// The (day, season, year) whose DayEndEvent was triggered by sleeping in a
+/// bedroll. Day-end handlers compare against the event so a planned rest in
+/// the mine is not treated as passing out.
+#[derive(Resource, Default, Debug)]
+pub struct BedrollRest(pub Option<(u8, Season, u32)>);
+
+impl BedrollRest {
+    pub fn covers(&self, ev: &DayEndEvent) -> bool {
+        self.0 == Some((ev.day, ev.season, ev.year))
+    }
+}
+
 /// Identifies the kind of interaction an entity supports.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum InteractionKind {
@@ -1545,6 +2362,8 @@ pub enum InteractionKind {
     BuildingUpgrade,
     Bed,
     KitchenStove,
+    Mailbox,
+    QuestBoard,
 }
 
 /// Marker component for entities the player can interact with via F key.
@@ -1600,6 +2419,13 @@ pub struct PlayerInput {
     pub interact: bool,       // F — talk, pick up, open chest, shipping bin
     pub tool_use: bool,       // Space / LMB — swing tool
     pub tool_secondary: bool, // R / RMB — eat food, place item
+    pub quick_water: bool,    // V — water the can's whole area in one sweep
+
+    // Placement drag (held — pressed, not just_pressed)
+    pub tool_secondary_held: bool, // R / RMB held — line-place sprinklers/paths
+    pub tool_use_held: bool,       // Space / LMB held — drag-plant seeds
+    pub run_held: bool,            // Left Shift held — run while moving
+    pub wrap_gift_held: bool,      // Left Ctrl held — wrap the gift being given
 
     // Menu toggles (just_pressed)
     pub open_inventory: bool,     // E
@@ -1621,8 +2447,10 @@ pub struct PlayerInput {
     pub attack: bool,
 
     // UI navigation (menus, dialogue)
-    pub ui_confirm: bool, // Enter / E
-    pub ui_cancel: bool,  // Escape
+    pub ui_confirm: bool,   // Enter / E
+    pub ui_cancel: bool,    // Escape
+    pub ui_secondary: bool, // U — use/eat the selected item
+    pub ui_discard: bool,   // X — trash the selected item
     pub ui_up: bool,
     pub ui_down: bool,
     pub ui_left: bool,
@@ -1634,6 +2462,7 @@ pub struct PlayerInput {
     pub skip_cutscene: bool, // Space during cutscene
     pub quicksave: bool,     // F5
     pub quickload: bool,     // F9
+    pub zoom_cycle: bool,    // Z
 }
 
 /// Which input context is active. Determines which PlayerInput fields get written.
@@ -1649,7 +2478,7 @@ pub enum InputContext {
 }
 
 /// Keyboard binding table. Hardcoded defaults now, remappable later.
-#[derive(Resource, Debug, Clone)]
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
 pub struct KeyBindings {
     pub move_up: KeyCode,
     pub move_down: KeyCode,
@@ -1658,6 +2487,9 @@ pub struct KeyBindings {
     pub interact: KeyCode,
     pub tool_use: KeyCode,
     pub tool_secondary: KeyCode,
+    pub quick_water: KeyCode,
+    pub run: KeyCode,
+    pub wrap_gift: KeyCode,
     pub open_inventory: KeyCode,
     pub open_crafting: KeyCode,
     pub open_map: KeyCode,
@@ -1668,6 +2500,8 @@ pub struct KeyBindings {
     pub tool_prev: KeyCode,
     pub ui_confirm: KeyCode,
     pub ui_cancel: KeyCode,
+    pub ui_secondary: KeyCode,
+    pub ui_discard: KeyCode,
     pub skip_cutscene: KeyCode,
 }
 
@@ -1681,6 +2515,9 @@ impl Default for KeyBindings {
             interact: KeyCode::KeyF,
             tool_use: KeyCode::Space,
             tool_secondary: KeyCode::KeyR,
+            quick_water: KeyCode::KeyV,
+            run: KeyCode::ShiftLeft,
+            wrap_gift: KeyCode::ControlLeft,
             open_inventory: KeyCode::KeyE,
             open_crafting: KeyCode::KeyC,
             open_map: KeyCode::KeyM,
@@ -1691,11 +2528,276 @@ impl Default for KeyBindings {
             tool_prev: KeyCode::BracketLeft,
             ui_confirm: KeyCode::Enter,
             ui_cancel: KeyCode::Escape,
+            ui_secondary: KeyCode::KeyU,
+            ui_discard: KeyCode::KeyX,
             skip_cutscene: KeyCode::Space,
         }
     }
 }
 
+impl KeyBindings {
+    /// The full binding table for a preset profile, or `None` for `Custom`.
+    pub fn for_profile(profile: KeybindingProfile) -> Option<Self> {
+        match profile {
+            KeybindingProfile::Wasd => Some(Self::default()),
+            // Left hand shifted one column right; actions that sat on the
+            // movement keys move along with it.
+            KeybindingProfile::Esdf => Some(Self {
+                move_up: KeyCode::KeyE,
+                move_down: KeyCode::KeyD,
+                move_left: KeyCode::KeyS,
+                move_right: KeyCode::KeyF,
+                interact: KeyCode::KeyG,
+                tool_secondary: KeyCode::KeyT,
+                quick_water: KeyCode::KeyB,
+                open_inventory: KeyCode::KeyR,
+                open_crafting: KeyCode::KeyV,
+                ..Self::default()
+            }),
+            KeybindingProfile::Arrows => Some(Self {
+                move_up: KeyCode::ArrowUp,
+                move_down: KeyCode::ArrowDown,
+                move_left: KeyCode::ArrowLeft,
+                move_right: KeyCode::ArrowRight,
+                ..Self::default()
+            }),
+            KeybindingProfile::Custom => None,
+        }
+    }
+}
+
+/// Keybinding preset chosen in the settings screen. `Custom` leaves the
+/// current `KeyBindings` alone so individually rebound keys survive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum KeybindingProfile {
+    #[default]
+    Wasd,
+    Esdf,
+    Arrows,
+    Custom,
+}
+
+impl KeybindingProfile {
+    /// Rewrite `bindings` wholesale to match this profile.
+    pub fn apply(self, bindings: &mut KeyBindings) {
+        if let Some(preset) = KeyBindings::for_profile(self) {
+            *bindings = preset;
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            KeybindingProfile::Wasd => KeybindingProfile::Esdf,
+            KeybindingProfile::Esdf => KeybindingProfile::Arrows,
+            KeybindingProfile::Arrows => KeybindingProfile::Custom,
+            KeybindingProfile::Custom => KeybindingProfile::Wasd,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            KeybindingProfile::Wasd => "WASD",
+            KeybindingProfile::Esdf => "ESDF",
+            KeybindingProfile::Arrows => "Arrows",
+            KeybindingProfile::Custom => "Custom",
+        }
+    }
+}
+
+/// Accessibility level for the fishing minigame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FishingAssist {
+    /// Standard minigame.
+    #[default]
+    Off,
+    /// Larger catch bar and a slower-falling bar.
+    Easy,
+    /// Holding the reel button briefly lands the fish.
+    Auto,
+}
+
+impl FishingAssist {
+    /// Multiplier applied to the catch bar's half-height.
+    pub fn catch_bar_multiplier(self) -> f32 {
+        match self {
+            FishingAssist::Off | FishingAssist::Auto => 1.0,
+            FishingAssist::Easy => 1.4,
+        }
+    }
+
+    /// Multiplier applied to the catch bar's fall speed and progress drain.
+    pub fn drain_multiplier(self) -> f32 {
+        match self {
+            FishingAssist::Off | FishingAssist::Auto => 1.0,
+            FishingAssist::Easy => 0.6,
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            FishingAssist::Off => FishingAssist::Easy,
+            FishingAssist::Easy => FishingAssist::Auto,
+            FishingAssist::Auto => FishingAssist::Off,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FishingAssist::Off => "Off",
+            FishingAssist::Easy => "Easy",
+            FishingAssist::Auto => "Auto",
+        }
+    }
+}
+
+/// UI scale steps offered in the settings screen.
+pub const UI_SCALE_STEPS: [f32; 5] = [0.75, 1.0, 1.25, 1.5, 2.0];
+
+/// Weather particle density steps offered in the settings screen.
+pub const PARTICLE_DENSITY_STEPS: [f32; 4] = [0.25, 0.5, 0.75, 1.0];
+
+/// Window sizes offered in the settings screen. The first entry is the
+/// game's native size.
+pub const RESOLUTION_PRESETS: [(f32, f32); 4] = [
+    (SCREEN_WIDTH, SCREEN_HEIGHT),
+    (1280.0, 720.0),
+    (1920.0, 1080.0),
+    (2560.0, 1440.0),
+];
+
+/// Player-facing gameplay, display, and accessibility options.
+/// Stored outside the save slots so they apply to every farm.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct GameSettings {
+    #[serde(default)]
+    pub fishing_assist: FishingAssist,
+    /// Multiplier for menu font sizes and panel dimensions.
+    #[serde(default = "default_ui_scale")]
+    pub ui_scale: f32,
+    /// Window size in logical pixels.
+    #[serde(default = "default_resolution")]
+    pub resolution: (f32, f32),
+    /// Show remaining growth days above crops on the farm.
+    #[serde(default)]
+    pub show_harvest_countdown: bool,
+    /// Fraction of the normal weather particle count to keep alive.
+    #[serde(default = "default_particle_density")]
+    pub particle_density: f32,
+    /// How many gifts each villager accepts per day.
+    #[serde(default = "default_gifts_per_day")]
+    pub gifts_per_day: u8,
+    /// Halve the friendship from an item the villager already got this week.
+    #[serde(default)]
+    pub repeat_gift_fatigue: bool,
+    /// Creative mode: tools cost no stamina. Saves made with it on are flagged.
+    #[serde(default)]
+    pub creative_tools: bool,
+    /// Peaceful mode: no collapsing from exhaustion, no mine knockout
+    /// penalties, and thirsty crops wait instead of dying. Saves made with it
+    /// on are flagged.
+    #[serde(default)]
+    pub peaceful: bool,
+    /// Hardy crops (`CropDef::off_season_tolerant`) live through the first
+    /// day after their season ends instead of withering at the change.
+    #[serde(default)]
+    pub off_season_grace: bool,
+    /// Language code for UI strings, matching a file in `assets/lang/`.
+    #[serde(default = "default_language")]
+    pub language: String,
+    /// Camera zoom level, clamped to `MIN_CAMERA_ZOOM..=MAX_CAMERA_ZOOM`.
+    #[serde(default = "default_camera_zoom")]
+    pub camera_zoom: u8,
+    /// Active keybinding preset, applied to `KeyBindings` on load and change.
+    #[serde(default)]
+    pub keybinding_profile: KeybindingProfile,
+}
+
+fn default_ui_scale() -> f32 {
+    1.0
+}
+
+fn default_resolution() -> (f32, f32) {
+    RESOLUTION_PRESETS[0]
+}
+
+fn default_particle_density() -> f32 {
+    1.0
+}
+
+fn default_gifts_per_day() -> u8 {
+    1
+}
+
+fn default_language() -> String {
+    "en".to_string()
+}
+
+fn default_camera_zoom() -> u8 {
+    PIXEL_SCALE as u8
+}
+
+/// Camera transform scale for a zoom level (world units per screen pixel).
+pub fn camera_scale(zoom: u8) -> f32 {
+    1.0 / zoom.clamp(MIN_CAMERA_ZOOM, MAX_CAMERA_ZOOM) as f32
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            fishing_assist: FishingAssist::default(),
+            ui_scale: default_ui_scale(),
+            resolution: default_resolution(),
+            show_harvest_countdown: false,
+            particle_density: default_particle_density(),
+            gifts_per_day: default_gifts_per_day(),
+            repeat_gift_fatigue: false,
+            creative_tools: false,
+            peaceful: false,
+            off_season_grace: false,
+            language: default_language(),
+            camera_zoom: default_camera_zoom(),
+            keybinding_profile: KeybindingProfile::default(),
+        }
+    }
+}
+
+impl GameSettings {
+    /// Step the UI scale up or down through `UI_SCALE_STEPS`, clamping at
+    /// either end.
+    pub fn step_ui_scale(&mut self, up: bool) {
+        let current = UI_SCALE_STEPS
+            .iter()
+            .position(|s| (*s - self.ui_scale).abs() < 0.01)
+            .unwrap_or(1);
+        let next = if up {
+            (current + 1).min(UI_SCALE_STEPS.len() - 1)
+        } else {
+            current.saturating_sub(1)
+        };
+        self.ui_scale = UI_SCALE_STEPS[next];
+    }
+
+    /// Cycle to the next entry in `RESOLUTION_PRESETS`.
+    pub fn next_resolution(&mut self) {
+        let current = RESOLUTION_PRESETS
+            .iter()
+            .position(|r| *r == self.resolution)
+            .unwrap_or(0);
+        self.resolution = RESOLUTION_PRESETS[(current + 1) % RESOLUTION_PRESETS.len()];
+    }
+
+    /// Step to the next camera zoom level, wrapping from the closest back to
+    /// the widest view.
+    pub fn cycle_camera_zoom(&mut self) {
+        let current = self.camera_zoom.clamp(MIN_CAMERA_ZOOM, MAX_CAMERA_ZOOM);
+        self.camera_zoom = if current >= MAX_CAMERA_ZOOM {
+            MIN_CAMERA_ZOOM
+        } else {
+            current + 1
+        };
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // MENU THEME & BUILDER TYPES
 // ═══════════════════════════════════════════════════════════════════════
@@ -1759,6 +2861,25 @@ impl Default for MenuTheme {
     }
 }
 
+impl MenuTheme {
+    /// The default theme with font sizes and panel/button dimensions
+    /// multiplied by `scale`. Colors and border widths are unchanged.
+    pub fn scaled(scale: f32) -> Self {
+        let base = Self::default();
+        Self {
+            panel_padding: base.panel_padding * scale,
+            panel_gap: base.panel_gap * scale,
+            panel_width: base.panel_width * scale,
+            button_height: base.button_height * scale,
+            button_width: base.button_width * scale,
+            title_font_size: base.title_font_size * scale,
+            button_font_size: base.button_font_size * scale,
+            hint_font_size: base.hint_font_size * scale,
+            ..base
+        }
+    }
+}
+
 /// Marker for any menu item that can be selected via keyboard or pointer.
 #[derive(Component, Debug, Clone)]
 pub struct MenuItem {
@@ -1772,6 +2893,10 @@ pub struct MenuAction {
     pub set_cursor: Option<usize>,
     pub activate: bool,
     pub cancel: bool,
+    /// The selected entry's secondary action (use an item, move a building).
+    pub secondary: bool,
+    /// Throw away the selected entry.
+    pub discard: bool,
     pub move_up: bool,
     pub move_down: bool,
     pub move_left: bool,
@@ -1782,6 +2907,19 @@ pub struct MenuAction {
 // TOOL UTILITY FUNCTIONS
 // ═══════════════════════════════════════════════════════════════════════
 
+/// Maps a tool item ID to the `ToolKind` it equips.
+pub fn tool_kind_for_item(item_id: &str) -> Option<ToolKind> {
+    match item_id {
+        "hoe" => Some(ToolKind::Hoe),
+        "watering_can" => Some(ToolKind::WateringCan),
+        "axe" => Some(ToolKind::Axe),
+        "pickaxe" => Some(ToolKind::Pickaxe),
+        "fishing_rod" => Some(ToolKind::FishingRod),
+        "scythe" => Some(ToolKind::Scythe),
+        _ => None,
+    }
+}
+
 /// Returns all tile positions that a watering-can action covers, based on tier
 /// and the direction the player is facing.
 ///
@@ -1819,7 +2957,11 @@ pub fn watering_can_area(
 }
 
 /// Returns the stamina cost for a single tool-use action at the given tier.
-pub fn tool_stamina_cost(base_cost: f32, tier: ToolTier) -> f32 {
+/// Creative tools are free.
+pub fn tool_stamina_cost(base_cost: f32, tier: ToolTier, creative: bool) -> f32 {
+    if creative {
+        return 0.0;
+    }
     base_cost * tier.stamina_multiplier()
 }
 
@@ -1868,8 +3010,11 @@ pub fn grid_to_world_center(gx: i32, gy: i32) -> Vec2 {
 }
 
 /// Convert world-space position to grid coordinates.
-/// Uses floor() — a point at (15.9, 31.1) with TILE_SIZE=16 is tile (0, 1).
-/// This is the ONLY sanctioned world→grid conversion in the codebase.
+/// Uses floor() — a point at (15.9, 31.1) with TILE_SIZE=16 is tile (0, 1),
+/// and (-0.1, -16.0) is tile (-1, -1): negative positions round toward -∞,
+/// never toward zero, so tile -1 is as wide as every other tile.
+/// This is the ONLY sanctioned world→grid conversion in the codebase;
+/// never `round()` or `as i32` a world coordinate into a tile.
 pub fn world_to_grid(wx: f32, wy: f32) -> IVec2 {
     IVec2::new(
         (wx / TILE_SIZE).floor() as i32,
@@ -1877,6 +3022,37 @@ pub fn world_to_grid(wx: f32, wy: f32) -> IVec2 {
     )
 }
 
+/// The grid tile directly in front of a player standing at world position
+/// `pos` and facing `facing`.
+pub fn player_target_tile(pos: Vec2, facing: Facing) -> (i32, i32) {
+    let tile = world_to_grid(pos.x, pos.y);
+    let (dx, dy) = facing_delta(facing);
+    (tile.x + dx, tile.y + dy)
+}
+
+/// Whether an object at world position `target` can be interacted with by a
+/// player at `pos` facing `facing`: it either sits on the faced tile, or is
+/// within `range` and roughly ahead (within 60° of the facing direction).
+/// Objects behind the player never qualify.
+pub fn can_interact_facing(pos: Vec2, facing: Facing, target: Vec2, range: f32) -> bool {
+    let target_tile = world_to_grid(target.x, target.y);
+    if (target_tile.x, target_tile.y) == player_target_tile(pos, facing) {
+        return true;
+    }
+
+    let offset = target - pos;
+    let dist = offset.length();
+    if dist > range {
+        return false;
+    }
+    // Standing on top of the object: direction is meaningless.
+    if dist <= TILE_SIZE * 0.25 {
+        return true;
+    }
+    let (dx, dy) = facing_delta(facing);
+    offset.dot(Vec2::new(dx as f32, dy as f32)) >= dist * 0.5
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // DEBUG OVERLAY
 // ═══════════════════════════════════════════════════════════════════════
@@ -1919,6 +3095,54 @@ mod tests {
     struct BlockerA;
     struct BlockerB;
 
+    #[test]
+    fn test_transition_cooldown_drops_transitions_right_after_a_load() {
+        let mut cooldown = TransitionCooldown::default();
+        assert!(cooldown.try_claim(100));
+        assert!(!cooldown.try_claim(101));
+        assert!(!cooldown.try_claim(100 + MAP_TRANSITION_COOLDOWN_FRAMES - 1));
+        assert!(cooldown.try_claim(100 + MAP_TRANSITION_COOLDOWN_FRAMES));
+    }
+
+    #[test]
+    fn test_day_period_wraps_past_midnight() {
+        assert_eq!(DayPeriod::from_hour(3), DayPeriod::Night);
+        assert_eq!(DayPeriod::from_hour(6), DayPeriod::Morning);
+        assert_eq!(DayPeriod::from_hour(12), DayPeriod::Afternoon);
+        assert_eq!(DayPeriod::from_hour(20), DayPeriod::Evening);
+        assert_eq!(DayPeriod::from_hour(21), DayPeriod::Night);
+        assert_eq!(DayPeriod::from_hour(25), DayPeriod::Night);
+    }
+
+    #[test]
+    fn test_player_target_tile_each_facing() {
+        let pos = grid_to_world_center(5, 7);
+        assert_eq!(player_target_tile(pos, Facing::Up), (5, 8));
+        assert_eq!(player_target_tile(pos, Facing::Down), (5, 6));
+        assert_eq!(player_target_tile(pos, Facing::Left), (4, 7));
+        assert_eq!(player_target_tile(pos, Facing::Right), (6, 7));
+    }
+
+    #[test]
+    fn test_can_interact_facing_rejects_objects_behind() {
+        let pos = grid_to_world_center(5, 7);
+        let range = TILE_SIZE * 1.5;
+        let above = grid_to_world_center(5, 8);
+        let right = grid_to_world_center(6, 7);
+        assert!(can_interact_facing(pos, Facing::Up, above, range));
+        assert!(!can_interact_facing(pos, Facing::Down, above, range));
+        assert!(can_interact_facing(pos, Facing::Right, right, range));
+        assert!(!can_interact_facing(pos, Facing::Left, right, range));
+        // A diagonal neighbour is roughly ahead when facing either of its axes.
+        let diagonal = grid_to_world_center(6, 8);
+        assert!(can_interact_facing(pos, Facing::Up, diagonal, range));
+        assert!(can_interact_facing(pos, Facing::Right, diagonal, range));
+        assert!(!can_interact_facing(pos, Facing::Down, diagonal, range));
+        // Out of range even when faced squarely.
+        let far = grid_to_world_center(5, 10);
+        assert!(!can_interact_facing(pos, Facing::Up, far, range));
+    }
+
     #[test]
     fn test_input_blocks_default_not_blocked() {
         let blocks = InputBlocks::default();
@@ -2047,6 +3271,58 @@ mod tests {
         assert!(ToolTier::Gold.stamina_multiplier() > ToolTier::Iridium.stamina_multiplier());
     }
 
+    #[test]
+    fn test_tool_stamina_cost_is_free_in_creative_mode() {
+        for tier in [
+            ToolTier::Basic,
+            ToolTier::Copper,
+            ToolTier::Iron,
+            ToolTier::Gold,
+            ToolTier::Iridium,
+        ] {
+            assert_eq!(tool_stamina_cost(2.0, tier, true), 0.0);
+            assert!(tool_stamina_cost(2.0, tier, false) > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_camera_scale_for_each_zoom_level() {
+        assert_eq!(camera_scale(2), 0.5);
+        assert_eq!(camera_scale(3), 1.0 / 3.0);
+        assert_eq!(camera_scale(4), 0.25);
+        assert_eq!(camera_scale(0), camera_scale(MIN_CAMERA_ZOOM));
+        assert_eq!(camera_scale(9), camera_scale(MAX_CAMERA_ZOOM));
+        assert_eq!(
+            camera_scale(GameSettings::default().camera_zoom),
+            1.0 / PIXEL_SCALE
+        );
+
+        let mut settings = GameSettings::default();
+        let levels: Vec<u8> = (0..3)
+            .map(|_| {
+                settings.cycle_camera_zoom();
+                settings.camera_zoom
+            })
+            .collect();
+        assert_eq!(levels, vec![4, 2, 3]);
+    }
+
+    #[test]
+    fn test_validate_name_trims_and_limits_length() {
+        assert_eq!(validate_name("  Robin "), Ok("Robin".to_string()));
+        assert!(validate_name("   ").is_err());
+        assert!(validate_name(&"a".repeat(MAX_NAME_LEN)).is_ok());
+        assert!(validate_name(&"a".repeat(MAX_NAME_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn test_typed_names_stop_at_the_length_limit() {
+        let mut field = String::from("Sunny");
+        push_name_text(&mut field, &"x".repeat(MAX_NAME_LEN));
+        assert_eq!(field.chars().count(), MAX_NAME_LEN);
+        assert!(field.starts_with("Sunny"));
+    }
+
     // ── Inventory ───────────────────────────────────────────────────
 
     #[test]
@@ -2063,6 +3339,110 @@ mod tests {
         assert_eq!(overflow, 5);
     }
 
+    #[test]
+    fn test_grant_item_beyond_capacity_stores_remainder_in_overflow() {
+        let mut inv = Inventory::default();
+        let mut overflow = OverflowStore::default();
+        let unlocked = inv.unlocked_slots;
+        for i in 0..unlocked - 1 {
+            inv.try_add(&format!("item_{}", i), 1, 1);
+        }
+
+        // One free slot holds 99; the other 21 go to the lost and found.
+        let left = grant_item(
+            &mut inv,
+            &mut overflow,
+            "stone",
+            120,
+            99,
+            ItemQuality::Normal,
+        );
+        assert_eq!(left, 21);
+        assert_eq!(inv.count("stone"), 99);
+        assert_eq!(overflow.items.len(), 1);
+        assert_eq!(overflow.items[0].item_id, "stone");
+        assert_eq!(overflow.items[0].quantity, 21);
+
+        // A second overflow of the same item merges into that entry.
+        grant_item(&mut inv, &mut overflow, "stone", 4, 99, ItemQuality::Normal);
+        assert_eq!(overflow.items.len(), 1);
+        assert_eq!(overflow.items[0].quantity, 25);
+
+        // Once there's room again the items come back.
+        inv.slots[0] = None;
+        assert_eq!(overflow.return_to(&mut inv, |_| 99), 25);
+        assert!(overflow.items.is_empty());
+        assert_eq!(inv.count("stone"), 124);
+    }
+
+    #[test]
+    fn test_inventory_move_stack_places_merges_swaps_and_splits() {
+        let mut inv = Inventory::default();
+        inv.slots[0] = Some(InventorySlot {
+            item_id: "turnip".into(),
+            quantity: 10,
+            quality: ItemQuality::Normal,
+        });
+        inv.slots[1] = Some(InventorySlot {
+            item_id: "turnip".into(),
+            quantity: 95,
+            quality: ItemQuality::Normal,
+        });
+        inv.slots[2] = Some(InventorySlot {
+            item_id: "stone".into(),
+            quantity: 5,
+            quality: ItemQuality::Normal,
+        });
+
+        // Merge stops at max_stack; the rest stays behind.
+        assert_eq!(inv.move_stack(0, 1, 10, 99), 4);
+        assert_eq!(inv.slots[0].as_ref().unwrap().quantity, 6);
+        assert_eq!(inv.slots[1].as_ref().unwrap().quantity, 99);
+
+        // Split half into an empty slot.
+        assert_eq!(inv.move_stack(0, 5, 3, 99), 3);
+        assert_eq!(inv.count("turnip"), 105);
+        assert_eq!(inv.slots[5].as_ref().unwrap().quantity, 3);
+
+        // A partial stack can't displace a different item...
+        assert_eq!(inv.move_stack(0, 2, 1, 99), 0);
+        // ...but a whole stack swaps with it.
+        assert_eq!(inv.move_stack(0, 2, 3, 99), 3);
+        assert_eq!(inv.slots[0].as_ref().unwrap().item_id, "stone");
+        assert_eq!(inv.slots[2].as_ref().unwrap().item_id, "turnip");
+
+        // Moving the last items empties the source slot.
+        assert_eq!(inv.move_stack(5, 2, 3, 99), 3);
+        assert!(inv.slots[5].is_none());
+        assert_eq!(inv.slots[2].as_ref().unwrap().quantity, 6);
+
+        // Locked slots are off limits.
+        inv.unlocked_slots = STARTING_UNLOCKED_SLOTS;
+        assert_eq!(inv.move_stack(0, STARTING_UNLOCKED_SLOTS, 5, 99), 0);
+    }
+
+    #[test]
+    fn test_inventory_locked_slots_cause_overflow() {
+        let mut inv = Inventory {
+            unlocked_slots: STARTING_UNLOCKED_SLOTS,
+            ..Default::default()
+        };
+        for i in 0..STARTING_UNLOCKED_SLOTS {
+            assert_eq!(inv.try_add(&format!("item_{}", i), 1, 1), 0);
+        }
+        // Backpack slots are still empty but locked, so nothing else fits.
+        assert_eq!(inv.try_add("extra", 3, 99), 3);
+        assert!(inv.slots[STARTING_UNLOCKED_SLOTS..]
+            .iter()
+            .all(|s| s.is_none()));
+
+        // The first backpack upgrade opens the next row.
+        assert!(!inv.apply_backpack_upgrade("deluxe_backpack"));
+        assert!(inv.apply_backpack_upgrade("backpack"));
+        assert_eq!(inv.try_add("extra", 3, 99), 0);
+        assert_eq!(inv.next_backpack_upgrade(), Some("deluxe_backpack"));
+    }
+
     #[test]
     fn test_inventory_try_add_respects_max_stack_size() {
         let mut inv = Inventory::default();
@@ -2100,6 +3480,45 @@ mod tests {
         assert!(!inv.has("turnip", 6));
     }
 
+    #[test]
+    fn test_inventory_try_add_quality_keeps_qualities_apart() {
+        let mut inv = Inventory::default();
+        inv.try_add("milk", 2, 99);
+        inv.try_add_quality("milk", 3, 99, ItemQuality::Gold);
+        inv.try_add_quality("milk", 1, 99, ItemQuality::Gold);
+        let stacks: Vec<_> = inv.slots.iter().flatten().collect();
+        assert_eq!(stacks.len(), 2);
+        assert_eq!(stacks[0].quality, ItemQuality::Normal);
+        assert_eq!(stacks[0].quantity, 2);
+        assert_eq!(stacks[1].quality, ItemQuality::Gold);
+        assert_eq!(stacks[1].quantity, 4);
+        assert_eq!(inv.count("milk"), 6);
+    }
+
+    #[test]
+    fn test_recipe_missing_ingredients_with_partial_stock() {
+        let recipe = Recipe {
+            id: "fried_egg".into(),
+            name: "Fried Egg".into(),
+            ingredients: vec![("egg".into(), 2), ("oil".into(), 1), ("any_fish".into(), 1)],
+            result: "fried_egg".into(),
+            result_quantity: 1,
+            is_cooking: true,
+            unlocked_by_default: true,
+        };
+        let mut inv = Inventory::default();
+        inv.try_add("egg", 1, 99);
+
+        assert_eq!(
+            recipe.missing_ingredients(&inv),
+            vec![("egg".to_string(), 1), ("oil".to_string(), 1)]
+        );
+
+        inv.try_add("egg", 1, 99);
+        inv.try_add("oil", 1, 99);
+        assert!(recipe.missing_ingredients(&inv).is_empty());
+    }
+
     #[test]
     fn test_inventory_try_remove() {
         let mut inv = Inventory::default();
@@ -2341,6 +3760,38 @@ mod tests {
         );
     }
 
+    // ── Grid ↔ world conversions ────────────────────────────────────
+
+    #[test]
+    fn test_world_to_grid_round_trips_tile_centres() {
+        for x in -64..=64 {
+            for y in -64..=64 {
+                let wc = grid_to_world_center(x, y);
+                assert_eq!(
+                    world_to_grid(wc.x, wc.y),
+                    IVec2::new(x, y),
+                    "tile ({x}, {y}) should survive a round trip"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_world_to_grid_floors_tile_edges_including_negatives() {
+        for t in -64..=64 {
+            let edge = t as f32 * TILE_SIZE;
+            // The lower edge belongs to the tile; just below it is the previous tile.
+            assert_eq!(world_to_grid(edge, edge), IVec2::new(t, t));
+            assert_eq!(
+                world_to_grid(edge - 0.01, edge - 0.01),
+                IVec2::new(t - 1, t - 1)
+            );
+            // Anywhere inside the tile maps back to it.
+            let inner = edge + TILE_SIZE - 0.01;
+            assert_eq!(world_to_grid(inner, inner), IVec2::new(t, t));
+        }
+    }
+
     #[test]
     fn test_farmstate_watering_already_watered_soil_is_idempotent() {
         let mut farm = FarmState::default();
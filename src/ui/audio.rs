@@ -1,6 +1,11 @@
+use crate::calendar::festivals::{FestivalKind, FestivalState};
 use crate::shared::*;
+use bevy::audio::Volume;
 use bevy::prelude::*;
 
+/// Seconds over which music fades out when replaced, and in when requested.
+pub const MUSIC_FADE_SECS: f32 = 1.0;
+
 // ═══════════════════════════════════════════════════════════════════════
 // MUSIC STATE — tracks the currently playing music entity
 // ═══════════════════════════════════════════════════════════════════════
@@ -9,6 +14,31 @@ use bevy::prelude::*;
 pub struct MusicState {
     pub current_track: Option<Entity>,
     pub current_track_id: String,
+    /// Fade-in progress of the current track, if it is still ramping up.
+    pub fade_in: Option<Timer>,
+    /// Replaced tracks that are still fading out, despawned when silent.
+    pub fading_out: Vec<(Entity, Timer)>,
+}
+
+impl MusicState {
+    /// Make `entity` the current track. The previous track, if any, starts
+    /// fading out rather than stopping abruptly.
+    pub fn begin_track(&mut self, entity: Option<Entity>, track_id: &str, fade_in: bool) {
+        if let Some(previous) = self.current_track.take() {
+            self.fading_out.push((
+                previous,
+                Timer::from_seconds(MUSIC_FADE_SECS, TimerMode::Once),
+            ));
+        }
+        self.current_track = entity;
+        self.fade_in = (entity.is_some() && fade_in)
+            .then(|| Timer::from_seconds(MUSIC_FADE_SECS, TimerMode::Once));
+        if entity.is_some() {
+            self.current_track_id = track_id.to_string();
+        } else {
+            self.current_track_id.clear();
+        }
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════
@@ -83,7 +113,7 @@ fn music_path(track_id: &str) -> Option<&'static str> {
         "indoor" => Some("audio/music/pixel_1.ogg"),
         "beach" => Some("audio/music/pixel_8.ogg"),
         "menu" => Some("audio/music/pixel_9.ogg"),
-        "night" => Some("audio/music/pixel_10.ogg"),
+        "night" | "rain" => Some("audio/music/pixel_10.ogg"),
         "festival" => Some("audio/music/pixel_11.ogg"),
         "credits" => Some("audio/music/pixel_12.ogg"),
         _ => None,
@@ -110,7 +140,8 @@ pub fn handle_play_sfx(
     }
 }
 
-/// Listen for PlayMusicEvent, stop the current music track, and start a new one.
+/// Listen for PlayMusicEvent and start the new track. The previous track
+/// fades out instead of cutting off; the new one fades in if requested.
 pub fn handle_play_music(
     mut events: EventReader<PlayMusicEvent>,
     mut commands: Commands,
@@ -118,45 +149,128 @@ pub fn handle_play_music(
     mut music_state: ResMut<MusicState>,
 ) {
     for event in events.read() {
-        // Stop current track if playing
-        if let Some(entity) = music_state.current_track {
-            commands.entity(entity).despawn_recursive();
+        // Re-requesting the playing track (e.g. farm → house) keeps it going.
+        if music_state.current_track.is_some() && music_state.current_track_id == event.track_id {
+            continue;
         }
 
-        if let Some(path) = music_path(&event.track_id) {
-            let entity = commands
-                .spawn((
-                    AudioPlayer::new(asset_server.load(path)),
-                    PlaybackSettings::LOOP,
-                ))
-                .id();
-            music_state.current_track = Some(entity);
-            music_state.current_track_id = event.track_id.clone();
-        } else {
-            music_state.current_track = None;
-            music_state.current_track_id.clear();
+        let entity = music_path(&event.track_id).map(|path| {
+            let settings = if event.fade_in {
+                PlaybackSettings::LOOP.with_volume(Volume::new(0.0))
+            } else {
+                PlaybackSettings::LOOP
+            };
+            commands
+                .spawn((AudioPlayer::new(asset_server.load(path)), settings))
+                .id()
+        });
+        music_state.begin_track(entity, &event.track_id, event.fade_in);
+    }
+}
+
+/// Advance music fades: ramp the current track up and outgoing tracks down,
+/// despawning each outgoing track once it is silent.
+pub fn update_music_fades(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut music_state: ResMut<MusicState>,
+    sinks: Query<&AudioSink>,
+) {
+    let delta = time.delta();
+
+    if let (Some(entity), Some(timer)) = (music_state.current_track, music_state.fade_in.as_mut()) {
+        timer.tick(delta);
+        if let Ok(sink) = sinks.get(entity) {
+            sink.set_volume(timer.fraction());
+        }
+        if timer.finished() {
+            music_state.fade_in = None;
+        }
+    }
+
+    music_state.fading_out.retain_mut(|(entity, timer)| {
+        timer.tick(delta);
+        if let Ok(sink) = sinks.get(*entity) {
+            sink.set_volume(timer.fraction_remaining());
+        }
+        if timer.finished() {
+            commands.entity(*entity).despawn_recursive();
+            return false;
         }
+        true
+    });
+}
+
+fn season_track(season: Season) -> &'static str {
+    match season {
+        Season::Spring => "spring",
+        Season::Summer => "summer",
+        Season::Fall => "fall",
+        Season::Winter => "winter",
     }
 }
 
-/// Start background music when entering the Playing state, using the current season.
+/// The track for a map. Festivals and the mines override the map's own music,
+/// and rain or storms swap in the rain theme anywhere outdoors.
+pub fn music_track_for(
+    map: MapId,
+    season: Season,
+    weather: Weather,
+    festival: Option<FestivalKind>,
+) -> &'static str {
+    if festival.is_some() {
+        return "festival";
+    }
+    let track = map_track(map, season);
+    let wet = matches!(weather, Weather::Rainy | Weather::Stormy);
+    if wet && !matches!(track, "indoor" | "mine") {
+        return "rain";
+    }
+    track
+}
+
+fn map_track(map: MapId, season: Season) -> &'static str {
+    match map {
+        MapId::Farm => season_track(season),
+        MapId::Town => "town",
+        MapId::TownWest => "town",
+        MapId::Mine | MapId::MineEntrance => "mine",
+        MapId::Forest => "forest",
+        MapId::DeepForest => "forest",
+        MapId::Beach => "beach",
+        MapId::PlayerHouse
+        | MapId::TownHouseWest
+        | MapId::TownHouseEast
+        | MapId::GeneralStore
+        | MapId::AnimalShop
+        | MapId::Blacksmith
+        | MapId::Library
+        | MapId::Tavern => "indoor",
+        MapId::CoralIsland => "beach",
+        MapId::SnowMountain => "forest",
+    }
+}
+
+/// Start background music when entering the Playing state.
 pub fn start_game_music(
     mut music_events: EventWriter<PlayMusicEvent>,
     music_state: Res<MusicState>,
     calendar: Res<Calendar>,
+    player_state: Res<PlayerState>,
+    festival: Res<FestivalState>,
 ) {
     // Skip if music is already playing (avoids restart on Cutscene→Playing).
     if music_state.current_track.is_some() {
         return;
     }
-    let track = match calendar.season {
-        Season::Spring => "spring",
-        Season::Summer => "summer",
-        Season::Fall => "fall",
-        Season::Winter => "winter",
-    };
     music_events.send(PlayMusicEvent {
-        track_id: track.to_string(),
+        track_id: music_track_for(
+            player_state.current_map,
+            calendar.season,
+            calendar.weather,
+            festival.active,
+        )
+        .to_string(),
         fade_in: true,
     });
 }
@@ -166,18 +280,20 @@ pub fn switch_music_on_season_change(
     mut season_events: EventReader<SeasonChangeEvent>,
     mut music_events: EventWriter<PlayMusicEvent>,
     player_state: Res<PlayerState>,
+    festival: Res<FestivalState>,
+    calendar: Res<Calendar>,
 ) {
     for event in season_events.read() {
-        let track = match event.new_season {
-            Season::Spring => "spring",
-            Season::Summer => "summer",
-            Season::Fall => "fall",
-            Season::Winter => "winter",
-        };
         // Only switch if player is on the farm (other maps have their own music)
         if player_state.current_map == MapId::Farm {
             music_events.send(PlayMusicEvent {
-                track_id: track.to_string(),
+                track_id: music_track_for(
+                    MapId::Farm,
+                    event.new_season,
+                    calendar.weather,
+                    festival.active,
+                )
+                .to_string(),
                 fade_in: true,
             });
         }
@@ -186,43 +302,75 @@ pub fn switch_music_on_season_change(
 
 /// Switch music when the player transitions to a new map.
 pub fn switch_music_on_map_change(
-    mut map_events: EventReader<MapTransitionEvent>,
+    mut map_events: EventReader<HonoredMapTransitionEvent>,
     mut music_events: EventWriter<PlayMusicEvent>,
     calendar: Res<Calendar>,
+    festival: Res<FestivalState>,
 ) {
     for event in map_events.read() {
-        let track = match event.to_map {
-            MapId::Farm => match calendar.season {
-                Season::Spring => "spring",
-                Season::Summer => "summer",
-                Season::Fall => "fall",
-                Season::Winter => "winter",
-            },
-            MapId::Town => "town",
-            MapId::TownWest => "town",
-            MapId::Mine => "mine",
-            MapId::Forest => "forest",
-            MapId::DeepForest => "forest",
-            MapId::Beach => "beach",
-            MapId::MineEntrance => "forest",
-            MapId::PlayerHouse
-            | MapId::TownHouseWest
-            | MapId::TownHouseEast
-            | MapId::GeneralStore
-            | MapId::AnimalShop
-            | MapId::Blacksmith
-            | MapId::Library
-            | MapId::Tavern => "indoor",
-            MapId::CoralIsland => "beach",
-            MapId::SnowMountain => "forest",
-        };
         music_events.send(PlayMusicEvent {
-            track_id: track.to_string(),
+            track_id: music_track_for(
+                event.to_map,
+                calendar.season,
+                calendar.weather,
+                festival.active,
+            )
+            .to_string(),
             fade_in: true,
         });
     }
 }
 
+/// Crossfade into (or back out of) festival music when a festival starts or
+/// ends while the player is already on the map.
+pub fn switch_music_on_festival_change(
+    festival: Res<FestivalState>,
+    mut last_festival: Local<Option<FestivalKind>>,
+    mut music_events: EventWriter<PlayMusicEvent>,
+    calendar: Res<Calendar>,
+    player_state: Res<PlayerState>,
+) {
+    if festival.active == *last_festival {
+        return;
+    }
+    *last_festival = festival.active;
+    music_events.send(PlayMusicEvent {
+        track_id: music_track_for(
+            player_state.current_map,
+            calendar.season,
+            calendar.weather,
+            festival.active,
+        )
+        .to_string(),
+        fade_in: true,
+    });
+}
+
+/// Crossfade into the rain theme when rain or a storm starts, and back to
+/// the map's own music when it clears.
+pub fn switch_music_on_weather_change(
+    calendar: Res<Calendar>,
+    mut last_weather: Local<Option<Weather>>,
+    mut music_events: EventWriter<PlayMusicEvent>,
+    player_state: Res<PlayerState>,
+    festival: Res<FestivalState>,
+) {
+    if *last_weather == Some(calendar.weather) {
+        return;
+    }
+    *last_weather = Some(calendar.weather);
+    music_events.send(PlayMusicEvent {
+        track_id: music_track_for(
+            player_state.current_map,
+            calendar.season,
+            calendar.weather,
+            festival.active,
+        )
+        .to_string(),
+        fade_in: true,
+    });
+}
+
 /// Start menu music when entering the MainMenu state.
 pub fn start_menu_music(mut music_events: EventWriter<PlayMusicEvent>) {
     music_events.send(PlayMusicEvent {
@@ -257,7 +405,7 @@ pub fn toast_sfx(
 
 /// Play a door SFX when the player transitions to or from an interior map.
 pub fn door_sfx_on_map_change(
-    mut map_events: EventReader<MapTransitionEvent>,
+    mut map_events: EventReader<HonoredMapTransitionEvent>,
     mut sfx_writer: EventWriter<PlaySfxEvent>,
     player_state: Res<PlayerState>,
 ) {
@@ -281,3 +429,75 @@ pub fn door_sfx_on_map_change(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_track_fades_out_the_previous_one() {
+        let old = Entity::from_raw(1);
+        let new = Entity::from_raw(2);
+        let mut state = MusicState::default();
+        state.begin_track(Some(old), "spring", false);
+        assert!(state.fading_out.is_empty());
+        assert!(state.fade_in.is_none());
+
+        state.begin_track(Some(new), "town", true);
+        assert_eq!(state.current_track, Some(new));
+        assert_eq!(state.current_track_id, "town");
+        assert_eq!(state.fading_out.len(), 1);
+        let (fading, timer) = &state.fading_out[0];
+        assert_eq!(*fading, old);
+        assert_eq!(timer.duration().as_secs_f32(), MUSIC_FADE_SECS);
+        assert!(!timer.finished());
+        assert!(state.fade_in.is_some());
+    }
+
+    #[test]
+    fn festivals_and_mines_override_map_music() {
+        let sunny = Weather::Sunny;
+        assert_eq!(
+            music_track_for(MapId::Town, Season::Summer, sunny, None),
+            "town"
+        );
+        assert_eq!(
+            music_track_for(MapId::Town, Season::Summer, sunny, Some(FestivalKind::Luau)),
+            "festival"
+        );
+        assert_eq!(
+            music_track_for(MapId::MineEntrance, Season::Fall, sunny, None),
+            "mine"
+        );
+        assert_eq!(
+            music_track_for(MapId::Farm, Season::Fall, sunny, None),
+            "fall"
+        );
+    }
+
+    #[test]
+    fn rain_overrides_music_only_outdoors() {
+        for weather in [Weather::Rainy, Weather::Stormy] {
+            assert_eq!(
+                music_track_for(MapId::Farm, Season::Spring, weather, None),
+                "rain"
+            );
+            assert_eq!(
+                music_track_for(MapId::Beach, Season::Summer, weather, None),
+                "rain"
+            );
+            assert_eq!(
+                music_track_for(MapId::PlayerHouse, Season::Spring, weather, None),
+                "indoor"
+            );
+            assert_eq!(
+                music_track_for(MapId::Mine, Season::Spring, weather, None),
+                "mine"
+            );
+        }
+        assert_eq!(
+            music_track_for(MapId::Farm, Season::Winter, Weather::Snowy, None),
+            "winter"
+        );
+    }
+}
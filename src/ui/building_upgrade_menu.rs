@@ -1,4 +1,5 @@
-//! Building Upgrade menu UI — lets the player upgrade Coop, Barn, House, and Silo.
+//! Building Upgrade menu UI — lets the player upgrade Coop, Barn, House, and Silo,
+//! and move the Coop or Barn to a new spot on the farm.
 //!
 //! Activated by entering `GameState::BuildingUpgrade`. Uses the same
 //! `MenuAction` resource that all other overlay menus consume.
@@ -61,6 +62,8 @@ pub struct BuildingUpgradeMenuState {
     entries: Vec<UpgradeEntry>,
     status_message: String,
     status_timer: f32,
+    /// Building being moved and the farm tile its footprint would start at.
+    moving: Option<(BuildingKind, (i32, i32))>,
 }
 
 use crate::economy::buildings::upgrade_cost;
@@ -194,6 +197,7 @@ pub fn spawn_building_upgrade_menu(
         entries: entries.clone(),
         status_message: String::new(),
         status_timer: 0.0,
+        moving: None,
     });
 
     commands
@@ -320,7 +324,7 @@ pub fn spawn_building_upgrade_menu(
 
                     // Hint
                     panel.spawn((
-                        Text::new("Up/Down: Select | Enter: Upgrade | Esc: Close"),
+                        Text::new("Up/Down: Select | Enter: Upgrade | U: Move | Esc: Close"),
                         TextFont {
                             font: font_handle.0.clone(),
                             font_size: 11.0,
@@ -346,12 +350,15 @@ pub fn despawn_building_upgrade_menu(
 // NAVIGATION + INPUT
 // ═══════════════════════════════════════════════════════════════════════
 
+#[allow(clippy::too_many_arguments)]
 pub fn building_upgrade_navigation(
     action: Res<MenuAction>,
     mut ui_state: Option<ResMut<BuildingUpgradeMenuState>>,
     player_state: Res<PlayerState>,
     inventory: Res<Inventory>,
+    placements: Res<BuildingPlacements>,
     mut upgrade_writer: EventWriter<BuildingUpgradeEvent>,
+    mut move_writer: EventWriter<BuildingMoveEvent>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     let Some(ref mut ui_state) = ui_state else {
@@ -362,6 +369,49 @@ pub fn building_upgrade_navigation(
         return;
     }
 
+    // Move mode: arrows nudge the new spot, Enter places, Esc backs out.
+    if let Some((building, (mut x, mut y))) = ui_state.moving {
+        if action.cancel {
+            ui_state.moving = None;
+            return;
+        }
+        if action.move_left {
+            x -= 1;
+        }
+        if action.move_right {
+            x += 1;
+        }
+        if action.move_up {
+            y += 1;
+        }
+        if action.move_down {
+            y -= 1;
+        }
+        if action.activate {
+            // The world handler checks the spot is clear and reports back.
+            move_writer.send(BuildingMoveEvent {
+                building,
+                to: (x, y),
+            });
+            next_state.set(GameState::Playing);
+            return;
+        }
+        ui_state.moving = Some((building, (x, y)));
+        return;
+    }
+
+    // Secondary action → start moving the selected coop or barn
+    if action.secondary {
+        let entry = &ui_state.entries[ui_state.cursor];
+        if let Some(origin) = placements.origin(entry.building) {
+            ui_state.moving = Some((entry.building, origin));
+        } else {
+            ui_state.status_message = format!("The {} can't be moved.", entry.label);
+            ui_state.status_timer = 2.0;
+        }
+        return;
+    }
+
     // Cancel → back to Playing
     if action.cancel {
         next_state.set(GameState::Playing);
@@ -464,8 +514,17 @@ pub fn update_building_upgrade_display(
     }
 
     // Status text
+    let status = match ui_state.moving {
+        Some((building, (x, y))) => format!(
+            "Move {} to ({}, {}) | Arrows: Nudge | Enter: Place | Esc: Back",
+            building_label(building),
+            x,
+            y
+        ),
+        None => ui_state.status_message.clone(),
+    };
     for mut text in &mut status_query {
-        **text = ui_state.status_message.clone();
+        **text = status.clone();
     }
 }
 
@@ -93,7 +93,7 @@ fn spawn_chest_ui(commands: &mut Commands, font: Handle<Font>) {
 
             // Hint text
             root.spawn((
-                Text::new("Tab: Switch panel | Up/Down: Select | Enter: Transfer | Esc: Close"),
+                Text::new("Tab: Switch panel | Up/Down: Select | Enter: Transfer | Q: Deposit matching | T: Take all | Esc: Close"),
                 TextFont {
                     font,
                     font_size: 11.0,
@@ -502,6 +502,55 @@ pub fn handle_chest_input(
     }
 }
 
+/// Q deposits every inventory stack the chest already holds; T takes
+/// everything out of the chest that fits in the inventory.
+pub fn handle_chest_bulk_transfer(
+    keys: Res<ButtonInput<KeyCode>>,
+    interaction: Res<ChestInteraction>,
+    mut inventory: ResMut<Inventory>,
+    mut chest_query: Query<&mut StorageChest>,
+    item_registry: Res<ItemRegistry>,
+    mut sfx_events: EventWriter<PlaySfxEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
+) {
+    if !interaction.is_open() {
+        return;
+    }
+    let deposit = keys.just_pressed(KeyCode::KeyQ);
+    let take = keys.just_pressed(KeyCode::KeyT);
+    if !deposit && !take {
+        return;
+    }
+    let Some(entity) = interaction.entity else {
+        return;
+    };
+    let Ok(mut chest) = chest_query.get_mut(entity) else {
+        return;
+    };
+
+    let moved = if deposit {
+        chest.deposit_matching(&mut inventory)
+    } else {
+        chest.take_all(&mut inventory, &item_registry)
+    };
+
+    if moved == 0 {
+        toast_events.send(ToastEvent {
+            message: if deposit {
+                "Nothing to deposit.".to_string()
+            } else {
+                "Nothing fits in your inventory.".to_string()
+            },
+            duration_secs: 2.0,
+        });
+        return;
+    }
+
+    sfx_events.send(PlaySfxEvent {
+        sfx_id: "item_pickup".to_string(),
+    });
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // TRANSFER HELPERS
 // ═══════════════════════════════════════════════════════════════════════
@@ -524,7 +573,8 @@ fn transfer_from_chest_to_inventory(
         .map(|def| def.stack_size)
         .unwrap_or(99);
 
-    let remaining = inventory.try_add(&stack.item_id, stack.quantity, max_stack);
+    let remaining =
+        inventory.try_add_quality(&stack.item_id, stack.quantity, max_stack, stack.quality);
     if remaining > 0 {
         chest.slots[idx] = Some(QualityStack {
             item_id: stack.item_id,
@@ -546,42 +596,81 @@ fn transfer_from_inventory_to_chest(
         return;
     };
 
-    // Try to stack onto existing chest slots with same item
-    let mut remaining = inv_slot.quantity;
-    for chest_slot in chest.slots.iter_mut() {
-        if remaining == 0 {
-            break;
-        }
-        if let Some(ref mut stack) = chest_slot {
-            if stack.item_id == inv_slot.item_id {
-                let space = 99u8.saturating_sub(stack.quantity);
-                let add = remaining.min(space);
-                stack.quantity += add;
-                remaining -= add;
-            }
-        }
-    }
-
-    // Place remainder in first empty chest slot
-    if remaining > 0 {
-        for chest_slot in chest.slots.iter_mut() {
-            if chest_slot.is_none() {
-                *chest_slot = Some(QualityStack {
-                    item_id: inv_slot.item_id.clone(),
-                    quantity: remaining,
-                    quality: ItemQuality::Normal,
-                });
-                remaining = 0;
-                break;
-            }
-        }
-    }
+    let remaining = chest.add_quality(&inv_slot.item_id, inv_slot.quantity, inv_slot.quality);
 
     // If chest was full, put items back into inventory
     if remaining > 0 {
         inventory.slots[idx] = Some(InventorySlot {
             item_id: inv_slot.item_id,
             quantity: remaining,
+            quality: inv_slot.quality,
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stack(item_id: &str, quantity: u8, quality: ItemQuality) -> Option<QualityStack> {
+        Some(QualityStack {
+            item_id: item_id.to_string(),
+            quantity,
+            quality,
+        })
+    }
+
+    fn slot(chest: &StorageChest, idx: usize) -> Option<(&str, u8, ItemQuality)> {
+        chest.slots[idx]
+            .as_ref()
+            .map(|s| (s.item_id.as_str(), s.quantity, s.quality))
+    }
+
+    #[test]
+    fn deposit_matching_moves_only_items_the_chest_already_holds() {
+        let mut chest = StorageChest::new(4, 0, 0);
+        chest.slots[0] = stack("turnip", 5, ItemQuality::Normal);
+
+        let mut inventory = Inventory::default();
+        inventory.try_add_quality("turnip", 3, 99, ItemQuality::Normal);
+        inventory.try_add_quality("turnip", 2, 99, ItemQuality::Gold);
+        inventory.try_add("stone", 10, 99);
+
+        let moved = chest.deposit_matching(&mut inventory);
+
+        assert_eq!(moved, 5);
+        assert_eq!(slot(&chest, 0), Some(("turnip", 8, ItemQuality::Normal)));
+        // Gold turnips keep their own stack instead of merging.
+        assert_eq!(slot(&chest, 1), Some(("turnip", 2, ItemQuality::Gold)));
+        assert_eq!(inventory.count("turnip"), 0);
+        assert_eq!(inventory.count("stone"), 10);
+    }
+
+    #[test]
+    fn deposit_matching_respects_chest_capacity() {
+        let mut chest = StorageChest::new(1, 0, 0);
+        chest.slots[0] = stack("turnip", 98, ItemQuality::Normal);
+
+        let mut inventory = Inventory::default();
+        inventory.try_add("turnip", 5, 99);
+
+        assert_eq!(chest.deposit_matching(&mut inventory), 1);
+        assert_eq!(slot(&chest, 0), Some(("turnip", 99, ItemQuality::Normal)));
+        assert_eq!(inventory.count("turnip"), 4);
+    }
+
+    #[test]
+    fn take_all_empties_the_chest_into_inventory() {
+        let mut chest = StorageChest::new(4, 0, 0);
+        chest.slots[0] = stack("stone", 20, ItemQuality::Normal);
+        chest.slots[2] = stack("turnip", 4, ItemQuality::Silver);
+
+        let mut inventory = Inventory::default();
+        let moved = chest.take_all(&mut inventory, &ItemRegistry::default());
+
+        assert_eq!(moved, 24);
+        assert!(chest.slots.iter().all(Option::is_none));
+        assert_eq!(inventory.count("stone"), 20);
+        assert_eq!(inventory.count("turnip"), 4);
+    }
+}
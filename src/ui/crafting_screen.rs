@@ -66,6 +66,7 @@ pub fn spawn_crafting_screen(
     mut commands: Commands,
     recipe_registry: Res<RecipeRegistry>,
     unlocked_recipes: Res<UnlockedRecipes>,
+    pinned_recipes: Res<PinnedRecipes>,
     item_registry: Res<ItemRegistry>,
     atlas_data: Res<ItemAtlasData>,
     font_handle: Res<UiFontHandle>,
@@ -81,6 +82,7 @@ pub fn spawn_crafting_screen(
             .cloned()
             .collect()
     };
+    let visible = order_recipes(visible, &pinned_recipes);
 
     commands.insert_resource(CraftingUiState {
         cursor: 0,
@@ -292,7 +294,7 @@ pub fn spawn_crafting_screen(
 
                     // Hint
                     panel.spawn((
-                        Text::new("Up/Down: Select | Enter: Craft | Esc: Close"),
+                        Text::new("Up/Down: Select | Enter: Craft | P: Pin | Esc: Close"),
                         TextFont {
                             font: font_handle.0.clone(),
                             font_size: 11.0,
@@ -323,6 +325,7 @@ pub fn update_crafting_display(
     mut commands: Commands,
     ui_state: Option<Res<CraftingUiState>>,
     recipe_registry: Res<RecipeRegistry>,
+    pinned_recipes: Res<PinnedRecipes>,
     inventory: Res<Inventory>,
     item_registry: Res<ItemRegistry>,
     atlas_data: Res<ItemAtlasData>,
@@ -409,10 +412,26 @@ pub fn update_crafting_display(
                 let has = inventory.count(item_id) as u8;
                 parts.push(format!("{} ({}/{})", name, has, qty));
             }
-            if parts.is_empty() {
+            let materials = if parts.is_empty() {
                 "Materials: None".to_string()
             } else {
                 format!("Materials: {}", parts.join(", "))
+            };
+            let missing: Vec<String> = recipe
+                .missing_ingredients(&inventory)
+                .iter()
+                .map(|(item_id, short)| {
+                    let name = item_registry
+                        .get(item_id)
+                        .map(|d| d.name.as_str())
+                        .unwrap_or(item_id.as_str());
+                    format!("{} x{}", name, short)
+                })
+                .collect();
+            if missing.is_empty() {
+                materials
+            } else {
+                format!("{}\nMissing: {}", materials, missing.join(", "))
             }
         })
         .unwrap_or_default();
@@ -456,7 +475,11 @@ pub fn update_crafting_display(
             let recipe_id = &ui_state.visible_recipes[idx];
             if let Some(recipe) = recipe_registry.recipes.get(recipe_id) {
                 let can_craft = can_craft_recipe(recipe, &inventory);
-                **text = recipe.name.clone();
+                **text = if pinned_recipes.is_pinned(recipe_id) {
+                    format!("* {}", recipe.name)
+                } else {
+                    recipe.name.clone()
+                };
                 if can_craft {
                     *color = TextColor(Color::srgb(0.5, 1.0, 0.5));
                 } else {
@@ -528,6 +551,16 @@ pub fn update_crafting_display(
     }
 }
 
+/// Pinned recipes first (in the order they were pinned), then the rest in
+/// their original order.
+pub fn order_recipes(recipe_ids: Vec<String>, pinned: &PinnedRecipes) -> Vec<String> {
+    let (mut first, rest): (Vec<String>, Vec<String>) =
+        recipe_ids.into_iter().partition(|id| pinned.is_pinned(id));
+    first.sort_by_key(|id| pinned.ids.iter().position(|p| p == id));
+    first.extend(rest);
+    first
+}
+
 fn can_craft_recipe(recipe: &Recipe, inventory: &Inventory) -> bool {
     recipe
         .ingredients
@@ -537,7 +570,9 @@ fn can_craft_recipe(recipe: &Recipe, inventory: &Inventory) -> bool {
 
 pub fn crafting_navigation(
     action: Res<MenuAction>,
+    keys: Res<ButtonInput<KeyCode>>,
     mut ui_state: Option<ResMut<CraftingUiState>>,
+    mut pinned_recipes: ResMut<PinnedRecipes>,
     recipe_registry: Res<RecipeRegistry>,
     inventory: Res<Inventory>,
     mut craft_events: EventWriter<crate::crafting::CraftItemEvent>,
@@ -546,6 +581,26 @@ pub fn crafting_navigation(
         return;
     };
 
+    // Pin / unpin the selected recipe and re-sort, keeping it selected.
+    if keys.just_pressed(KeyCode::KeyP) {
+        if let Some(recipe_id) = ui_state.visible_recipes.get(ui_state.cursor).cloned() {
+            let pinned = pinned_recipes.toggle(&recipe_id);
+            let visible = std::mem::take(&mut ui_state.visible_recipes);
+            ui_state.visible_recipes = order_recipes(visible, &pinned_recipes);
+            ui_state.cursor = ui_state
+                .visible_recipes
+                .iter()
+                .position(|id| *id == recipe_id)
+                .unwrap_or(0);
+            ui_state.status_message = if pinned {
+                "Pinned to the top.".to_string()
+            } else {
+                "Unpinned.".to_string()
+            };
+            ui_state.status_timer = 2.0;
+        }
+    }
+
     let max = ui_state.visible_recipes.len();
 
     if action.move_down && max > 0 && ui_state.cursor < max - 1 {
@@ -591,3 +646,30 @@ pub fn crafting_status_timer(time: Res<Time>, mut ui_state: Option<ResMut<Crafti
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_pinned_recipes_come_first() {
+        let mut pinned = PinnedRecipes::default();
+        pinned.toggle("sprinkler");
+        pinned.toggle("chest");
+
+        let ordered = order_recipes(ids(&["chest", "fence", "sprinkler", "torch"]), &pinned);
+        assert_eq!(ordered, ids(&["sprinkler", "chest", "fence", "torch"]));
+    }
+
+    #[test]
+    fn test_toggle_unpins() {
+        let mut pinned = PinnedRecipes::default();
+        assert!(pinned.toggle("recipe_chest"));
+        assert!(!pinned.toggle("recipe_chest"));
+        assert!(!pinned.is_pinned("recipe_chest"));
+    }
+}
@@ -0,0 +1,422 @@
+//! Developer command console, compiled only with the `debug_commands` feature.
+//!
+//! Backquote opens a one-line prompt at the bottom of the screen. Commands go
+//! through the same events regular gameplay uses, so other domains react to
+//! them exactly as they would to the real thing:
+//!
+//! ```text
+//! time <hour> [minute]     set the clock
+//! give <item_id> [qty]     ItemPickupEvent (validated against ItemRegistry)
+//! gold <amount>            GoldChangeEvent to reach the amount
+//! warp <map>               MapTransitionEvent to the map's default spawn
+//! friendship <npc> <hearts>
+//! reloaddata               re-read the JSON overrides in assets/data/
+//! ```
+
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+use super::UiFontHandle;
+use crate::data::overrides::{apply_data_overrides, OverrideTargets, DATA_OVERRIDE_DIR};
+use crate::shared::*;
+use crate::world::maps::default_spawn_position;
+
+/// Every map the `warp` command accepts.
+const WARP_TARGETS: [MapId; 18] = [
+    MapId::Farm,
+    MapId::Town,
+    MapId::TownWest,
+    MapId::Beach,
+    MapId::Forest,
+    MapId::DeepForest,
+    MapId::CoralIsland,
+    MapId::MineEntrance,
+    MapId::Mine,
+    MapId::PlayerHouse,
+    MapId::TownHouseWest,
+    MapId::TownHouseEast,
+    MapId::GeneralStore,
+    MapId::AnimalShop,
+    MapId::Blacksmith,
+    MapId::Library,
+    MapId::Tavern,
+    MapId::SnowMountain,
+];
+
+// ═══════════════════════════════════════════════════════════════════════
+// COMMANDS
+// ═══════════════════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DebugCommand {
+    SetTime { hour: u8, minute: u8 },
+    Give { item_id: ItemId, quantity: u8 },
+    SetGold(u32),
+    Warp(MapId),
+    SetFriendship { npc_id: NpcId, hearts: u8 },
+    ReloadData,
+}
+
+/// A parsed command waiting to be executed.
+#[derive(Event, Debug, Clone)]
+pub struct DebugCommandEvent(pub DebugCommand);
+
+/// Parse one console line. Item and NPC ids are checked at execution time,
+/// against the registries.
+pub fn parse_command(input: &str) -> Result<DebugCommand, String> {
+    let mut words = input.split_whitespace();
+    let Some(verb) = words.next() else {
+        return Err("Empty command".into());
+    };
+    let args: Vec<&str> = words.collect();
+
+    fn number<T: std::str::FromStr>(arg: Option<&&str>, what: &str) -> Result<T, String> {
+        let arg = arg.ok_or_else(|| format!("Missing {}", what))?;
+        arg.parse()
+            .map_err(|_| format!("'{}' is not a valid {}", arg, what))
+    }
+
+    match verb.to_lowercase().as_str() {
+        "time" => {
+            let hour: u8 = number(args.first(), "hour")?;
+            let minute: u8 = match args.get(1) {
+                Some(_) => number(args.get(1), "minute")?,
+                None => 0,
+            };
+            if !(6..=25).contains(&hour) || minute > 59 {
+                return Err("Time must be between 6:00 and 25:59".into());
+            }
+            Ok(DebugCommand::SetTime { hour, minute })
+        }
+        "give" => {
+            let item_id = args.first().ok_or("Missing item id")?.to_string();
+            let quantity: u8 = match args.get(1) {
+                Some(_) => number(args.get(1), "quantity")?,
+                None => 1,
+            };
+            if quantity == 0 {
+                return Err("Quantity must be at least 1".into());
+            }
+            Ok(DebugCommand::Give { item_id, quantity })
+        }
+        "gold" => Ok(DebugCommand::SetGold(number(args.first(), "amount")?)),
+        "warp" => {
+            let name = args.first().ok_or("Missing map name")?;
+            let wanted = name.replace('_', "").to_lowercase();
+            WARP_TARGETS
+                .iter()
+                .find(|m| format!("{:?}", m).to_lowercase() == wanted)
+                .map(|m| DebugCommand::Warp(*m))
+                .ok_or_else(|| format!("Unknown map '{}'", name))
+        }
+        "friendship" => {
+            let npc_id = args.first().ok_or("Missing npc id")?.to_string();
+            let hearts: u8 = number(args.get(1), "heart count")?;
+            if hearts > 10 {
+                return Err("Hearts must be 0-10".into());
+            }
+            Ok(DebugCommand::SetFriendship { npc_id, hearts })
+        }
+        "reloaddata" => Ok(DebugCommand::ReloadData),
+        other => Err(format!("Unknown command '{}'", other)),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// STATE & UI
+// ═══════════════════════════════════════════════════════════════════════
+
+#[derive(Resource, Debug, Default)]
+pub struct DebugConsoleState {
+    pub open: bool,
+    pub input: String,
+    /// Result of the last command, shown under the prompt.
+    pub feedback: String,
+}
+
+#[derive(Component)]
+pub struct DebugConsoleRoot;
+
+#[derive(Component)]
+pub struct DebugConsoleText;
+
+/// Spawn the (hidden) console UI at startup.
+pub fn spawn_debug_console(mut commands: Commands, font_handle: Res<UiFontHandle>) {
+    commands
+        .spawn((
+            DebugConsoleRoot,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(8.0),
+                right: Val::Px(8.0),
+                bottom: Val::Px(8.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+            GlobalZIndex(90),
+            Visibility::Hidden,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                DebugConsoleText,
+                Text::new("> "),
+                TextFont {
+                    font: font_handle.0.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.0, 1.0, 0.0)),
+            ));
+        });
+}
+
+/// Backquote toggles the console. While open, typed characters edit the
+/// prompt and Enter parses the line into a `DebugCommandEvent`.
+pub fn debug_console_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut console: ResMut<DebugConsoleState>,
+    mut input_blocks: ResMut<InputBlocks>,
+    mut command_events: EventWriter<DebugCommandEvent>,
+) {
+    if keys.just_pressed(KeyCode::Backquote) {
+        console.open = !console.open;
+        console.input.clear();
+        if console.open {
+            input_blocks.block::<DebugConsoleState>();
+        } else {
+            input_blocks.unblock::<DebugConsoleState>();
+        }
+        key_events.clear();
+        return;
+    }
+    if !console.open {
+        key_events.clear();
+        return;
+    }
+
+    for event in key_events.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter => {
+                let line = std::mem::take(&mut console.input);
+                match parse_command(&line) {
+                    Ok(command) => {
+                        console.feedback = format!("ok: {}", line.trim());
+                        command_events.send(DebugCommandEvent(command));
+                    }
+                    Err(e) => console.feedback = e,
+                }
+            }
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::Space => console.input.push(' '),
+            Key::Character(text) => {
+                if text.as_str() != "`" {
+                    console.input.push_str(text);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn update_debug_console(
+    console: Res<DebugConsoleState>,
+    mut root_query: Query<&mut Visibility, With<DebugConsoleRoot>>,
+    mut text_query: Query<&mut Text, With<DebugConsoleText>>,
+) {
+    if !console.is_changed() {
+        return;
+    }
+    for mut vis in &mut root_query {
+        *vis = if console.open {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+    for mut text in &mut text_query {
+        **text = if console.feedback.is_empty() {
+            format!("> {}", console.input)
+        } else {
+            format!("> {}\n{}", console.input, console.feedback)
+        };
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// EXECUTION
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Carry out parsed commands by sending the regular gameplay events.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_debug_commands(
+    mut command_events: EventReader<DebugCommandEvent>,
+    mut item_registry: ResMut<ItemRegistry>,
+    mut crop_registry: ResMut<CropRegistry>,
+    mut fish_registry: ResMut<FishRegistry>,
+    mut recipe_registry: ResMut<RecipeRegistry>,
+    npc_registry: Res<NpcRegistry>,
+    player_state: Res<PlayerState>,
+    mut calendar: ResMut<Calendar>,
+    mut relationships: ResMut<Relationships>,
+    mut pickup_events: EventWriter<ItemPickupEvent>,
+    mut gold_events: EventWriter<GoldChangeEvent>,
+    mut map_events: EventWriter<MapTransitionEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
+) {
+    for DebugCommandEvent(command) in command_events.read() {
+        let message = match command {
+            DebugCommand::SetTime { hour, minute } => {
+                calendar.hour = *hour;
+                calendar.minute = *minute;
+                format!("Time set to {}:{:02}", hour, minute)
+            }
+            DebugCommand::Give { item_id, quantity } => {
+                if item_registry.get(item_id).is_none() {
+                    format!("No item with id '{}'", item_id)
+                } else {
+                    pickup_events.send(ItemPickupEvent {
+                        item_id: item_id.clone(),
+                        quantity: *quantity,
+                        quality: ItemQuality::Normal,
+                    });
+                    format!("Gave {} x{}", item_id, quantity)
+                }
+            }
+            DebugCommand::SetGold(amount) => {
+                gold_events.send(GoldChangeEvent {
+                    amount: *amount as i32 - player_state.gold as i32,
+                    reason: "Debug console".into(),
+                });
+                format!("Gold set to {}", amount)
+            }
+            DebugCommand::Warp(map) => {
+                let (to_x, to_y) = default_spawn_position(*map);
+                map_events.send(MapTransitionEvent {
+                    to_map: *map,
+                    to_x,
+                    to_y,
+                });
+                format!("Warped to {:?}", map)
+            }
+            DebugCommand::SetFriendship { npc_id, hearts } => {
+                if !npc_registry.npcs.contains_key(npc_id) {
+                    format!("No npc with id '{}'", npc_id)
+                } else {
+                    relationships
+                        .friendship
+                        .insert(npc_id.clone(), *hearts as u32 * 100);
+                    format!("{} is now at {} hearts", npc_id, hearts)
+                }
+            }
+            DebugCommand::ReloadData => {
+                let report = apply_data_overrides(
+                    std::path::Path::new(DATA_OVERRIDE_DIR),
+                    OverrideTargets {
+                        items: &mut item_registry,
+                        crops: &mut crop_registry,
+                        fish: &mut fish_registry,
+                        recipes: &mut recipe_registry,
+                    },
+                );
+                format!(
+                    "Reloaded {} definitions ({} rejected)",
+                    report.applied,
+                    report.errors.len()
+                )
+            }
+        };
+        info!("[Debug] {}", message);
+        toast_events.send(ToastEvent {
+            message,
+            duration_secs: 2.0,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rejects_unknown_and_malformed_commands() {
+        assert_eq!(
+            parse_command("warp blacksmith"),
+            Ok(DebugCommand::Warp(MapId::Blacksmith))
+        );
+        assert_eq!(
+            parse_command("warp mine_entrance"),
+            Ok(DebugCommand::Warp(MapId::MineEntrance))
+        );
+        assert!(parse_command("warp moon").is_err());
+        assert!(parse_command("give parsnip lots").is_err());
+        assert!(parse_command("time 3").is_err());
+        assert!(parse_command("fly").is_err());
+    }
+
+    #[test]
+    fn give_command_sends_pickup_event() {
+        let command = parse_command("give parsnip 5").expect("valid command");
+        assert_eq!(
+            command,
+            DebugCommand::Give {
+                item_id: "parsnip".into(),
+                quantity: 5
+            }
+        );
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<ItemRegistry>()
+            .init_resource::<CropRegistry>()
+            .init_resource::<FishRegistry>()
+            .init_resource::<RecipeRegistry>()
+            .init_resource::<NpcRegistry>()
+            .init_resource::<PlayerState>()
+            .init_resource::<Calendar>()
+            .init_resource::<Relationships>()
+            .add_event::<DebugCommandEvent>()
+            .add_event::<ItemPickupEvent>()
+            .add_event::<GoldChangeEvent>()
+            .add_event::<MapTransitionEvent>()
+            .add_event::<ToastEvent>()
+            .add_systems(Update, execute_debug_commands);
+        app.world_mut().resource_mut::<ItemRegistry>().items.insert(
+            "parsnip".into(),
+            ItemDef {
+                id: "parsnip".into(),
+                name: "Parsnip".into(),
+                description: String::new(),
+                category: ItemCategory::Crop,
+                sell_price: 35,
+                buy_price: None,
+                stack_size: 99,
+                edible: true,
+                energy_restore: 10.0,
+                sprite_index: 0,
+            },
+        );
+
+        app.world_mut().send_event(DebugCommandEvent(command));
+        // Unknown ids are reported, not sent.
+        app.world_mut()
+            .send_event(DebugCommandEvent(DebugCommand::Give {
+                item_id: "moon_rock".into(),
+                quantity: 1,
+            }));
+        app.update();
+
+        let events = app.world().resource::<Events<ItemPickupEvent>>();
+        let mut reader = events.get_cursor();
+        let pickups: Vec<_> = reader.read(events).collect();
+        assert_eq!(pickups.len(), 1);
+        assert_eq!(pickups[0].item_id, "parsnip");
+        assert_eq!(pickups[0].quantity, 5);
+    }
+}
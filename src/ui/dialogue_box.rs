@@ -1,6 +1,7 @@
 use super::UiFontHandle;
 use crate::npcs::definitions::npc_sprite_file;
 use crate::npcs::spawning::NpcSpriteData;
+use crate::save::GameStatistics;
 use crate::shared::*;
 use bevy::prelude::*;
 
@@ -48,6 +49,8 @@ pub fn listen_for_dialogue_start(
     mut commands: Commands,
     mut events: EventReader<DialogueStartEvent>,
     mut next_state: ResMut<NextState<GameState>>,
+    profile: Res<PlayerProfile>,
+    statistics: Res<GameStatistics>,
     existing: Query<Entity, With<DialogueBoxRoot>>,
 ) {
     for event in events.read() {
@@ -58,7 +61,11 @@ pub fn listen_for_dialogue_start(
 
         commands.insert_resource(DialogueUiState {
             npc_id: event.npc_id.clone(),
-            lines: event.lines.clone(),
+            lines: event
+                .lines
+                .iter()
+                .map(|line| profile.fill_tokens(line, &statistics.farm_name))
+                .collect(),
             current_line: 0,
             portrait_index: event.portrait_index,
             chars_revealed: 0,
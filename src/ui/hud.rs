@@ -1,3 +1,4 @@
+use super::localization::{Localization, LocalizedText};
 use super::UiFontHandle;
 use super::{item_icon_index, ITEM_ATLAS_COLUMNS, ITEM_ATLAS_ROWS};
 use crate::economy::shipping::ShippingBinPreview;
@@ -57,6 +58,10 @@ pub struct HudHealthFill;
 #[derive(Component)]
 pub struct HudToolText;
 
+/// Projected stamina per swing of the equipped tool, shown under its name.
+#[derive(Component)]
+pub struct HudToolCostText;
+
 #[derive(Component)]
 pub struct HotbarRoot;
 
@@ -192,7 +197,7 @@ pub fn preload_weather_icon_atlas(
 // SPAWN HUD
 // ═══════════════════════════════════════════════════════════════════════
 
-pub fn spawn_hud(mut commands: Commands, font_handle: Res<UiFontHandle>) {
+pub fn spawn_hud(mut commands: Commands, font_handle: Res<UiFontHandle>, loc: Res<Localization>) {
     let font = font_handle.0.clone();
 
     // Root container — full screen overlay, no interaction blocking
@@ -239,7 +244,8 @@ pub fn spawn_hud(mut commands: Commands, font_handle: Res<UiFontHandle>) {
                         ))
                         .with_children(|left| {
                             left.spawn((
-                                Text::new("Time"),
+                                Text::new(loc.t("hud.time")),
+                                LocalizedText("hud.time"),
                                 TextFont {
                                     font: font.clone(),
                                     font_size: 10.0,
@@ -272,7 +278,8 @@ pub fn spawn_hud(mut commands: Commands, font_handle: Res<UiFontHandle>) {
                             ))
                             .with_children(|weather| {
                                 weather.spawn((
-                                    Text::new("Weather"),
+                                    Text::new(loc.t("hud.weather")),
+                                    LocalizedText("hud.weather"),
                                     TextFont {
                                         font: font.clone(),
                                         font_size: 10.0,
@@ -322,7 +329,8 @@ pub fn spawn_hud(mut commands: Commands, font_handle: Res<UiFontHandle>) {
                         ))
                         .with_children(|tool| {
                             tool.spawn((
-                                Text::new("Current Tool"),
+                                Text::new(loc.t("hud.current_tool")),
+                                LocalizedText("hud.current_tool"),
                                 TextFont {
                                     font: font.clone(),
                                     font_size: 10.0,
@@ -343,6 +351,18 @@ pub fn spawn_hud(mut commands: Commands, font_handle: Res<UiFontHandle>) {
                                 TextColor(Color::srgb(0.9, 0.95, 1.0)),
                                 PickingBehavior::IGNORE,
                             ));
+
+                            tool.spawn((
+                                HudToolCostText,
+                                Text::new(""),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 10.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.55, 0.85, 0.45)),
+                                PickingBehavior::IGNORE,
+                            ));
                         });
 
                     // Right group: gold + health + stamina
@@ -369,7 +389,8 @@ pub fn spawn_hud(mut commands: Commands, font_handle: Res<UiFontHandle>) {
                                 ))
                                 .with_children(|gold| {
                                     gold.spawn((
-                                        Text::new("Gold"),
+                                        Text::new(loc.t("hud.gold")),
+                                        LocalizedText("hud.gold"),
                                         TextFont {
                                             font: font.clone(),
                                             font_size: 10.0,
@@ -416,7 +437,8 @@ pub fn spawn_hud(mut commands: Commands, font_handle: Res<UiFontHandle>) {
                                     ))
                                     .with_children(|row| {
                                         row.spawn((
-                                            Text::new("Health"),
+                                            Text::new(loc.t("hud.health")),
+                                            LocalizedText("hud.health"),
                                             TextFont {
                                                 font: font.clone(),
                                                 font_size: 11.0,
@@ -470,7 +492,8 @@ pub fn spawn_hud(mut commands: Commands, font_handle: Res<UiFontHandle>) {
                                     ))
                                     .with_children(|row| {
                                         row.spawn((
-                                            Text::new("Stamina"),
+                                            Text::new(loc.t("hud.stamina")),
+                                            LocalizedText("hud.stamina"),
                                             TextFont {
                                                 font: font.clone(),
                                                 font_size: 11.0,
@@ -534,7 +557,7 @@ pub fn spawn_hud(mut commands: Commands, font_handle: Res<UiFontHandle>) {
             ));
 
             // ─── BOTTOM: HOTBAR ───
-            spawn_hotbar(parent, &font);
+            spawn_hotbar(parent, &font, &loc);
         });
 
     // ─── MAP NAME — absolute position, bottom-left ───
@@ -644,7 +667,7 @@ pub fn spawn_hud(mut commands: Commands, font_handle: Res<UiFontHandle>) {
     commands.insert_resource(InteractionPromptCache::default());
 }
 
-fn spawn_hotbar(parent: &mut ChildBuilder, font: &Handle<Font>) {
+fn spawn_hotbar(parent: &mut ChildBuilder, font: &Handle<Font>, loc: &Localization) {
     parent
         .spawn((
             HotbarRoot,
@@ -662,7 +685,8 @@ fn spawn_hotbar(parent: &mut ChildBuilder, font: &Handle<Font>) {
         ))
         .with_children(|hotbar| {
             hotbar.spawn((
-                Text::new("Inventory"),
+                Text::new(loc.t("hud.inventory")),
+                LocalizedText("hud.inventory"),
                 TextFont {
                     font: font.clone(),
                     font_size: 11.0,
@@ -1037,6 +1061,20 @@ pub fn update_tool_display(
     }
 }
 
+pub fn update_tool_cost_display(
+    player: Res<PlayerState>,
+    settings: Res<GameSettings>,
+    mut query: Query<&mut Text, With<HudToolCostText>>,
+) {
+    if !player.is_changed() && !settings.is_changed() {
+        return;
+    }
+    let cost = crate::player::equipped_tool_stamina_cost(&player, settings.creative_tools);
+    for mut text in &mut query {
+        **text = format!("{:.1} stamina / use", cost);
+    }
+}
+
 pub fn update_hotbar(
     inventory: Res<Inventory>,
     item_registry: Res<ItemRegistry>,
@@ -1408,8 +1446,45 @@ pub fn update_objective_display(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::player::equipped_tool_stamina_cost;
     use std::collections::HashMap;
 
+    #[test]
+    fn projected_tool_cost_matches_the_actual_drain() {
+        let mut player = PlayerState {
+            equipped_tool: ToolKind::Hoe,
+            current_map: MapId::Farm,
+            ..default()
+        };
+        player.tools.insert(ToolKind::Hoe, ToolTier::Basic);
+        // Swing (4) plus tilling (2).
+        assert!((equipped_tool_stamina_cost(&player, false) - 6.0).abs() < 1e-4);
+
+        // Upgrades only ease the tilling part; the swing stays flat.
+        player.tools.insert(ToolKind::Hoe, ToolTier::Iron);
+        assert!((equipped_tool_stamina_cost(&player, false) - 5.5).abs() < 1e-4);
+
+        // Watering adds the tier-scaled pour cost.
+        player.equipped_tool = ToolKind::WateringCan;
+        player.tools.insert(ToolKind::WateringCan, ToolTier::Iron);
+        assert!((equipped_tool_stamina_cost(&player, false) - 4.4).abs() < 1e-4);
+
+        // Off the farm only the swing is charged.
+        player.current_map = MapId::Town;
+        assert!((equipped_tool_stamina_cost(&player, false) - 3.0).abs() < 1e-4);
+
+        // In the mine a pickaxe swing also pays for breaking rock.
+        player.current_map = MapId::Mine;
+        player.equipped_tool = ToolKind::Pickaxe;
+        assert!((equipped_tool_stamina_cost(&player, false) - 9.5).abs() < 1e-4);
+
+        // Chopping adds nothing on top of the axe's swing.
+        player.equipped_tool = ToolKind::Axe;
+        assert!((equipped_tool_stamina_cost(&player, false) - 6.0).abs() < 1e-4);
+
+        assert_eq!(equipped_tool_stamina_cost(&player, true), 0.0);
+    }
+
     fn completed_tutorial_state() -> TutorialState {
         TutorialState {
             hints_shown: vec![OBJECTIVES_DONE_FLAG.to_string()],
@@ -1445,6 +1520,7 @@ mod tests {
             gift_preferences: HashMap::default(),
             default_dialogue: Vec::new(),
             heart_dialogue: HashMap::default(),
+            context_dialogue: ContextDialogue::default(),
             is_marriageable: false,
             sprite_index: 0,
             portrait_index: 0,
@@ -1485,6 +1561,7 @@ mod tests {
         let quest_log = QuestLog {
             active: vec![make_test_quest("Board Cleanup", Some(1))],
             completed: Vec::new(),
+            ..Default::default()
         };
         let mut npc_registry = NpcRegistry::default();
         npc_registry.npcs.insert(
@@ -1520,6 +1597,7 @@ mod tests {
                 make_test_quest("Apple Delivery", Some(0)),
             ],
             completed: Vec::new(),
+            ..Default::default()
         };
         let mut npc_registry = NpcRegistry::default();
         npc_registry.npcs.insert(
@@ -46,10 +46,23 @@ pub struct InventorySlotBg {
 #[derive(Component)]
 pub struct InventoryDescText;
 
+/// Items picked up with Enter, waiting to be placed. They stay in their
+/// original slot until placed, so closing the screen never loses anything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeldStack {
+    pub from: usize,
+    pub quantity: u8,
+}
+
 /// Tracks which slot is currently selected/hovered in the inventory UI
 #[derive(Resource, Default)]
 pub struct InventoryUiState {
     pub cursor_slot: usize,
+    /// Slot armed for deletion by a first trash press; a second press on the
+    /// same slot confirms.
+    pub pending_trash: Option<usize>,
+    /// Stack being moved, if any.
+    pub held: Option<HeldStack>,
 }
 
 // ═══════════════════════════════════════════════════════════════════════
@@ -63,7 +76,7 @@ pub fn spawn_inventory_screen(
     inventory: Res<Inventory>,
     item_registry: Res<ItemRegistry>,
 ) {
-    commands.insert_resource(InventoryUiState { cursor_slot: 0 });
+    commands.insert_resource(InventoryUiState::default());
 
     let font = font_handle.0.clone();
 
@@ -110,7 +123,9 @@ pub fn spawn_inventory_screen(
 
                     // Hint text
                     panel.spawn((
-                        Text::new("WASD/Arrows: Move | Enter: Use | Esc: Close"),
+                        Text::new(
+                            "WASD/Arrows: Move | Enter: Pick up/Place | Shift+Enter: Split | U: Use | X: Trash | Esc: Close",
+                        ),
                         TextFont {
                             font: font.clone(),
                             font_size: 12.0,
@@ -376,7 +391,7 @@ pub fn update_inventory_slots(
             if let Some(ref slot_data) = inventory.slots[idx] {
                 let tool_tier = item_registry
                     .get(&slot_data.item_id)
-                    .and_then(|def| tool_kind_from_item_id(&def.id))
+                    .and_then(|def| tool_kind_for_item(&def.id))
                     .and_then(|tool| player_state.tools.get(&tool).copied());
                 if let Some(tier) = tool_tier {
                     **text = tool_tier_label(tier).to_string();
@@ -391,9 +406,22 @@ pub fn update_inventory_slots(
         }
     }
 
-    // Update description text for hovered slot
+    // Update description text for hovered slot, or the stack being moved
     let cursor = ui_state.as_ref().map(|s| s.cursor_slot).unwrap_or(0);
-    let desc = hovered_item_description(cursor, &inventory, &item_registry, &player_state);
+    let held = ui_state.as_ref().and_then(|s| s.held);
+    let desc = match held.and_then(|h| Some((h, inventory.slots.get(h.from)?.as_ref()?))) {
+        Some((held, slot)) => {
+            let name = item_registry
+                .get(&slot.item_id)
+                .map(|def| def.name.clone())
+                .unwrap_or_else(|| slot.item_id.clone());
+            format!(
+                "Holding {} x{}.\nEnter: place here | Shift+Enter: place one",
+                name, held.quantity
+            )
+        }
+        None => hovered_item_description(cursor, &inventory, &item_registry, &player_state),
+    };
     for mut text in &mut desc_query {
         **text = desc.clone();
     }
@@ -407,6 +435,7 @@ pub fn update_inventory_cursor(
     mut slot_query: Query<(&InventorySlotBg, &mut BackgroundColor, &mut BorderColor)>,
 ) {
     let Some(ui_state) = ui_state else { return };
+    let held_from = ui_state.held.map(|h| h.from);
     for (slot, mut bg, mut border) in &mut slot_query {
         let is_selected = slot.index == ui_state.cursor_slot;
         let is_equipped = inventory
@@ -414,10 +443,17 @@ pub fn update_inventory_cursor(
             .get(slot.index)
             .and_then(Option::as_ref)
             .and_then(|slot_data| item_registry.get(&slot_data.item_id))
-            .and_then(|def| tool_kind_from_item_id(&def.id))
+            .and_then(|def| tool_kind_for_item(&def.id))
             .is_some_and(|tool| tool == player_state.equipped_tool);
 
-        if is_selected && is_equipped {
+        if is_selected && held_from.is_some() {
+            // Carrying a stack: the cursor shows where it will land.
+            *bg = BackgroundColor(Color::srgba(0.18, 0.3, 0.42, 0.98));
+            *border = BorderColor(Color::srgb(0.55, 0.85, 1.0));
+        } else if held_from == Some(slot.index) {
+            *bg = BackgroundColor(Color::srgba(0.14, 0.16, 0.22, 0.9));
+            *border = BorderColor(Color::srgba(0.55, 0.85, 1.0, 0.6));
+        } else if is_selected && is_equipped {
             *bg = BackgroundColor(Color::srgba(0.34, 0.36, 0.19, 0.98));
             *border = BorderColor(Color::srgb(1.0, 0.93, 0.45));
         } else if is_selected {
@@ -426,6 +462,10 @@ pub fn update_inventory_cursor(
         } else if is_equipped {
             *bg = BackgroundColor(Color::srgba(0.2, 0.24, 0.14, 0.94));
             *border = BorderColor(Color::srgb(0.44, 0.9, 0.42));
+        } else if slot.index >= inventory.unlocked_slots {
+            // Locked until a backpack upgrade is bought.
+            *bg = BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.9));
+            *border = BorderColor(Color::srgba(0.25, 0.25, 0.25, 0.6));
         } else {
             *bg = BackgroundColor(Color::srgba(0.2, 0.17, 0.14, 0.9));
             *border = BorderColor(Color::srgba(0.4, 0.35, 0.3, 0.7));
@@ -433,10 +473,12 @@ pub fn update_inventory_cursor(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn inventory_navigation(
     action: Res<MenuAction>,
+    keys: Res<ButtonInput<KeyCode>>,
     mut ui_state: Option<ResMut<InventoryUiState>>,
-    inventory: Res<Inventory>,
+    mut inventory: ResMut<Inventory>,
     item_registry: Res<ItemRegistry>,
     mut player_state: ResMut<PlayerState>,
     mut eat_food_events: EventWriter<EatFoodEvent>,
@@ -462,12 +504,68 @@ pub fn inventory_navigation(
         ui_state.cursor_slot = (row - 1) * 12 + col;
     }
 
-    if action.activate && cur < inventory.slots.len() {
+    // The held stack may have shrunk or vanished (e.g. trashed) since pickup.
+    let source_qty = ui_state
+        .held
+        .and_then(|h| inventory.slots.get(h.from)?.as_ref().map(|s| s.quantity));
+    ui_state.held = ui_state.held.zip(source_qty).map(|(held, qty)| HeldStack {
+        quantity: held.quantity.min(qty),
+        ..held
+    });
+
+    if action.activate {
+        let split = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+        match ui_state.held {
+            None => {
+                if let Some(slot) = inventory
+                    .slots
+                    .get(cur)
+                    .and_then(Option::as_ref)
+                    .filter(|_| cur < inventory.unlocked_slots)
+                {
+                    let quantity = if split {
+                        slot.quantity.div_ceil(2)
+                    } else {
+                        slot.quantity
+                    };
+                    ui_state.held = Some(HeldStack {
+                        from: cur,
+                        quantity,
+                    });
+                }
+            }
+            Some(held) if held.from == cur => ui_state.held = None,
+            Some(held) => {
+                let max_stack = inventory.slots[held.from]
+                    .as_ref()
+                    .and_then(|s| item_registry.get(&s.item_id))
+                    .map(|def| def.stack_size)
+                    .unwrap_or(99);
+                let quantity = if split { 1 } else { held.quantity };
+                let moved = inventory.move_stack(held.from, cur, quantity, max_stack);
+                if moved == 0 {
+                    toast_events.send(ToastEvent {
+                        message: "Can't place that here.".to_string(),
+                        duration_secs: 2.0,
+                    });
+                } else if moved < held.quantity {
+                    ui_state.held = Some(HeldStack {
+                        quantity: held.quantity - moved,
+                        ..held
+                    });
+                } else {
+                    ui_state.held = None;
+                }
+            }
+        }
+    }
+
+    if action.secondary && cur < inventory.slots.len() {
         if let Some(ref slot) = inventory.slots[cur] {
             if let Some(def) = item_registry.get(&slot.item_id) {
                 match def.category {
                     ItemCategory::Tool => {
-                        if let Some(tool) = tool_kind_from_item_id(&def.id) {
+                        if let Some(tool) = tool_kind_for_item(&def.id) {
                             player_state.equipped_tool = tool;
                         } else {
                             toast_events.send(ToastEvent {
@@ -495,19 +593,78 @@ pub fn inventory_navigation(
     }
 }
 
-/// Maps a tool item ID to its corresponding ToolKind.
-fn tool_kind_from_item_id(item_id: &str) -> Option<ToolKind> {
-    match item_id {
-        "hoe" => Some(ToolKind::Hoe),
-        "watering_can" => Some(ToolKind::WateringCan),
-        "axe" => Some(ToolKind::Axe),
-        "pickaxe" => Some(ToolKind::Pickaxe),
-        "fishing_rod" => Some(ToolKind::FishingRod),
-        "scythe" => Some(ToolKind::Scythe),
-        _ => None,
+/// Discard (X) on the hovered slot arms it for trashing; discarding again on
+/// the same slot deletes the whole stack. Tools and special items are locked.
+pub fn inventory_trash(
+    action: Res<MenuAction>,
+    mut ui_state: Option<ResMut<InventoryUiState>>,
+    mut inventory: ResMut<Inventory>,
+    item_registry: Res<ItemRegistry>,
+    mut removed_events: EventWriter<ItemRemovedEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
+) {
+    let Some(ref mut ui_state) = ui_state else {
+        return;
+    };
+    let cur = ui_state.cursor_slot;
+
+    // Moving off the armed slot cancels the pending confirm.
+    if ui_state.pending_trash.is_some_and(|slot| slot != cur) {
+        ui_state.pending_trash = None;
+    }
+
+    if !action.discard {
+        return;
+    }
+
+    let Some(slot) = inventory.slots.get(cur).and_then(Option::as_ref) else {
+        ui_state.pending_trash = None;
+        return;
+    };
+    let def = item_registry.get(&slot.item_id);
+    let name = def
+        .map(|d| d.name.clone())
+        .unwrap_or_else(|| slot.item_id.clone());
+
+    if def.is_some_and(is_trash_locked) {
+        ui_state.pending_trash = None;
+        toast_events.send(ToastEvent {
+            message: format!("{} can't be thrown away.", name),
+            duration_secs: 2.0,
+        });
+        return;
+    }
+
+    if ui_state.pending_trash != Some(cur) {
+        ui_state.pending_trash = Some(cur);
+        toast_events.send(ToastEvent {
+            message: format!(
+                "Trash {} x{}? Press X again to confirm.",
+                name, slot.quantity
+            ),
+            duration_secs: 2.5,
+        });
+        return;
+    }
+
+    ui_state.pending_trash = None;
+    if let Some(trashed) = inventory.slots[cur].take() {
+        removed_events.send(ItemRemovedEvent {
+            item_id: trashed.item_id,
+            quantity: trashed.quantity,
+        });
+        toast_events.send(ToastEvent {
+            message: format!("Threw away {} x{}.", name, trashed.quantity),
+            duration_secs: 2.0,
+        });
     }
 }
 
+/// Tools and special (quest/key) items can never be trashed.
+fn is_trash_locked(def: &ItemDef) -> bool {
+    matches!(def.category, ItemCategory::Tool | ItemCategory::Special)
+}
+
 fn compact_item_name(name: &str) -> String {
     if name.chars().count() <= 12 {
         name.to_string()
@@ -526,7 +683,7 @@ fn slot_status_text(
         return String::new();
     };
 
-    let Some(tool) = tool_kind_from_item_id(&def.id) else {
+    let Some(tool) = tool_kind_for_item(&def.id) else {
         return String::new();
     };
 
@@ -563,7 +720,7 @@ fn hovered_item_description(
 
     let mut lines = vec![def.name.clone()];
 
-    if let Some(tool) = tool_kind_from_item_id(&def.id) {
+    if let Some(tool) = tool_kind_for_item(&def.id) {
         let tier = player_state
             .tools
             .get(&tool)
@@ -604,4 +761,76 @@ mod tests {
     fn tool_tier_label_matches_expected_copy() {
         assert_eq!(tool_tier_label(ToolTier::Iridium), "Iridium");
     }
+
+    fn trash_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<MenuAction>()
+            .init_resource::<Inventory>()
+            .init_resource::<ItemRegistry>()
+            .init_resource::<InventoryUiState>()
+            .add_event::<ItemRemovedEvent>()
+            .add_event::<ToastEvent>()
+            .add_systems(Update, inventory_trash);
+        app
+    }
+
+    fn press_trash(app: &mut App) {
+        app.world_mut().resource_mut::<MenuAction>().discard = true;
+        app.update();
+        app.world_mut().resource_mut::<MenuAction>().discard = false;
+    }
+
+    #[test]
+    fn trashing_a_stack_clears_the_slot_and_fires_event() {
+        let mut app = trash_test_app();
+        app.world_mut()
+            .resource_mut::<Inventory>()
+            .try_add("stone", 12, 99);
+
+        // First press only arms the confirm.
+        press_trash(&mut app);
+        assert!(app.world().resource::<Inventory>().slots[0].is_some());
+        assert_eq!(
+            app.world().resource::<InventoryUiState>().pending_trash,
+            Some(0)
+        );
+
+        press_trash(&mut app);
+        assert!(app.world().resource::<Inventory>().slots[0].is_none());
+
+        let events = app.world().resource::<Events<ItemRemovedEvent>>();
+        let removed: Vec<_> = events.get_cursor().read(events).cloned().collect();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].item_id, "stone");
+        assert_eq!(removed[0].quantity, 12);
+    }
+
+    #[test]
+    fn tools_are_locked_from_trashing() {
+        let mut app = trash_test_app();
+        app.world_mut().resource_mut::<ItemRegistry>().items.insert(
+            "hoe".into(),
+            ItemDef {
+                id: "hoe".into(),
+                name: "Hoe".into(),
+                description: String::new(),
+                category: ItemCategory::Tool,
+                sell_price: 0,
+                buy_price: None,
+                stack_size: 1,
+                edible: false,
+                energy_restore: 0.0,
+                sprite_index: 0,
+            },
+        );
+        app.world_mut()
+            .resource_mut::<Inventory>()
+            .try_add("hoe", 1, 1);
+
+        press_trash(&mut app);
+        press_trash(&mut app);
+
+        assert!(app.world().resource::<Inventory>().slots[0].is_some());
+    }
 }
@@ -1,4 +1,5 @@
 use super::UiFontHandle;
+use crate::economy::achievements::ACHIEVEMENTS;
 use crate::shared::*;
 use bevy::prelude::*;
 
@@ -23,15 +24,62 @@ pub struct QuestListItemBg {
 #[derive(Component)]
 pub struct QuestDetailPanel;
 
+#[derive(Component)]
+pub struct JournalTitleText;
+
+/// Container for the quest list and details; hidden on the achievements page.
+#[derive(Component)]
+pub struct JournalQuestPage;
+
+/// Container for the achievement list; hidden on the quest page.
+#[derive(Component)]
+pub struct JournalAchievementsPage;
+
+#[derive(Component)]
+pub struct AchievementSummaryText;
+
+/// An achievement slot. `row` is the on-screen slot, not the achievement index.
+#[derive(Component)]
+pub struct AchievementRow {
+    pub row: usize,
+}
+
+#[derive(Component)]
+pub struct AchievementRowName {
+    pub row: usize,
+}
+
+#[derive(Component)]
+pub struct AchievementRowInfo {
+    pub row: usize,
+}
+
+#[derive(Component)]
+pub struct AchievementRowFill {
+    pub row: usize,
+}
+
+/// Number of achievements shown at once; the list scrolls with the cursor.
+const ACHIEVEMENT_ROWS: usize = 7;
+
 // ═══════════════════════════════════════════════════════════════════════
 // RESOURCE
 // ═══════════════════════════════════════════════════════════════════════
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JournalPage {
+    #[default]
+    Quests,
+    Achievements,
+}
+
 #[derive(Resource, Default)]
 pub struct JournalUiState {
     pub cursor: usize,
     #[allow(dead_code)]
     pub quest_ids: Vec<String>,
+    pub page: JournalPage,
+    pub achievement_cursor: usize,
 }
 
 // ═══════════════════════════════════════════════════════════════════════
@@ -42,13 +90,14 @@ pub fn spawn_journal_screen(
     mut commands: Commands,
     font_handle: Res<UiFontHandle>,
     quest_log: Res<QuestLog>,
+    theme: Res<MenuTheme>,
 ) {
     let font = font_handle.0.clone();
 
     let quest_ids: Vec<String> = quest_log.active.iter().map(|q| q.id.clone()).collect();
     commands.insert_resource(JournalUiState {
-        cursor: 0,
         quest_ids,
+        ..default()
     });
 
     commands
@@ -61,7 +110,7 @@ pub fn spawn_journal_screen(
                 align_items: AlignItems::Center,
                 ..default()
             },
-            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            BackgroundColor(theme.bg_overlay),
         ))
         .with_children(|parent| {
             parent
@@ -73,19 +122,20 @@ pub fn spawn_journal_screen(
                         align_items: AlignItems::Center,
                         padding: UiRect::all(Val::Px(16.0)),
                         row_gap: Val::Px(10.0),
-                        border: UiRect::all(Val::Px(3.0)),
+                        border: UiRect::all(Val::Px(theme.panel_border_width)),
                         ..default()
                     },
-                    BackgroundColor(Color::srgba(0.12, 0.1, 0.08, 0.95)),
-                    BorderColor(Color::srgb(0.5, 0.4, 0.25)),
+                    BackgroundColor(theme.panel_bg),
+                    BorderColor(theme.panel_border),
                 ))
                 .with_children(|panel| {
                     // Title
                     panel.spawn((
+                        JournalTitleText,
                         Text::new("QUEST LOG"),
                         TextFont {
                             font: font.clone(),
-                            font_size: 22.0,
+                            font_size: theme.title_font_size,
                             ..default()
                         },
                         TextColor(Color::srgb(1.0, 0.9, 0.6)),
@@ -93,138 +143,242 @@ pub fn spawn_journal_screen(
 
                     // Hint text
                     panel.spawn((
-                        Text::new("W/S or Arrows: Navigate | J/Esc: Close"),
+                        Text::new("A/D: Page | W/S or Arrows: Navigate | J/Esc: Close"),
                         TextFont {
                             font: font.clone(),
-                            font_size: 12.0,
+                            font_size: theme.hint_font_size,
                             ..default()
                         },
-                        TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                        TextColor(theme.text_color_disabled),
                     ));
 
-                    // Quest list area
                     panel
-                        .spawn((Node {
-                            width: Val::Percent(100.0),
-                            flex_direction: FlexDirection::Column,
-                            flex_grow: 1.0,
-                            row_gap: Val::Px(4.0),
-                            overflow: Overflow::clip_y(),
-                            ..default()
-                        },))
-                        .with_children(|list| {
-                            if quest_log.active.is_empty() {
-                                list.spawn((
-                                    Text::new("No active quests."),
-                                    TextFont {
-                                        font: font.clone(),
-                                        font_size: 14.0,
-                                        ..default()
-                                    },
-                                    TextColor(Color::srgb(0.6, 0.6, 0.6)),
-                                ));
-                            } else {
-                                for (i, quest) in quest_log.active.iter().enumerate() {
-                                    let progress = format_objective(&quest.objective);
-                                    let from_text = format!("From: {}", quest.giver);
-                                    list.spawn((
-                                        QuestListItemBg { index: i },
-                                        QuestListItem { index: i },
-                                        Node {
-                                            width: Val::Percent(100.0),
-                                            flex_direction: FlexDirection::Column,
-                                            padding: UiRect::all(Val::Px(6.0)),
-                                            border: UiRect::all(Val::Px(2.0)),
-                                            ..default()
-                                        },
-                                        BackgroundColor(Color::srgba(0.2, 0.17, 0.14, 0.9)),
-                                        BorderColor(Color::srgba(0.4, 0.35, 0.3, 0.7)),
-                                    ))
-                                    .with_children(|row| {
-                                        // Quest title
-                                        row.spawn((
-                                            Text::new(quest.title.clone()),
+                        .spawn((
+                            JournalQuestPage,
+                            Node {
+                                width: Val::Percent(100.0),
+                                flex_direction: FlexDirection::Column,
+                                flex_grow: 1.0,
+                                row_gap: Val::Px(10.0),
+                                ..default()
+                            },
+                        ))
+                        .with_children(|panel| {
+                            // Quest list area
+                            panel
+                                .spawn((Node {
+                                    width: Val::Percent(100.0),
+                                    flex_direction: FlexDirection::Column,
+                                    flex_grow: 1.0,
+                                    row_gap: Val::Px(4.0),
+                                    overflow: Overflow::clip_y(),
+                                    ..default()
+                                },))
+                                .with_children(|list| {
+                                    if quest_log.active.is_empty() {
+                                        list.spawn((
+                                            Text::new("No active quests."),
                                             TextFont {
                                                 font: font.clone(),
                                                 font_size: 14.0,
                                                 ..default()
                                             },
-                                            TextColor(Color::srgb(1.0, 0.9, 0.7)),
-                                        ));
-                                        // From NPC
-                                        row.spawn((
-                                            Text::new(from_text),
-                                            TextFont {
-                                                font: font.clone(),
-                                                font_size: 11.0,
-                                                ..default()
-                                            },
-                                            TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                                            TextColor(Color::srgb(0.6, 0.6, 0.6)),
                                         ));
-                                        // Progress
-                                        row.spawn((
-                                            Text::new(progress),
+                                    } else {
+                                        for (i, quest) in quest_log.active.iter().enumerate() {
+                                            let progress = format_objective(&quest.objective);
+                                            let fraction =
+                                                quest_progress_fraction(&quest.objective);
+                                            let from_text = format!("From: {}", quest.giver);
+                                            list.spawn((
+                                                QuestListItemBg { index: i },
+                                                QuestListItem { index: i },
+                                                Node {
+                                                    width: Val::Percent(100.0),
+                                                    flex_direction: FlexDirection::Column,
+                                                    padding: UiRect::all(Val::Px(6.0)),
+                                                    border: UiRect::all(Val::Px(2.0)),
+                                                    ..default()
+                                                },
+                                                BackgroundColor(theme.button_bg_normal),
+                                                BorderColor(theme.button_border_normal),
+                                            ))
+                                            .with_children(|row| {
+                                                // Quest title
+                                                row.spawn((
+                                                    Text::new(quest.title.clone()),
+                                                    TextFont {
+                                                        font: font.clone(),
+                                                        font_size: 14.0,
+                                                        ..default()
+                                                    },
+                                                    TextColor(Color::srgb(1.0, 0.9, 0.7)),
+                                                ));
+                                                // From NPC
+                                                row.spawn((
+                                                    Text::new(from_text),
+                                                    TextFont {
+                                                        font: font.clone(),
+                                                        font_size: 11.0,
+                                                        ..default()
+                                                    },
+                                                    TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                                                ));
+                                                // Progress
+                                                row.spawn((
+                                                    Text::new(progress),
+                                                    TextFont {
+                                                        font: font.clone(),
+                                                        font_size: 11.0,
+                                                        ..default()
+                                                    },
+                                                    TextColor(Color::srgb(0.6, 0.85, 0.6)),
+                                                ));
+                                                spawn_progress_bar(row, fraction);
+                                            });
+                                        }
+                                    }
+                                });
+
+                            // Divider
+                            panel.spawn((
+                                Node {
+                                    width: Val::Percent(100.0),
+                                    height: Val::Px(2.0),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgb(0.5, 0.4, 0.25)),
+                            ));
+
+                            // Detail panel
+                            panel
+                                .spawn((
+                                    QuestDetailPanel,
+                                    Node {
+                                        width: Val::Percent(100.0),
+                                        flex_direction: FlexDirection::Column,
+                                        padding: UiRect::all(Val::Px(8.0)),
+                                        row_gap: Val::Px(4.0),
+                                        min_height: Val::Px(80.0),
+                                        ..default()
+                                    },
+                                    BackgroundColor(Color::srgba(0.08, 0.07, 0.06, 0.9)),
+                                ))
+                                .with_children(|detail| {
+                                    detail.spawn((
+                                        Text::new("DETAILS"),
+                                        TextFont {
+                                            font: font.clone(),
+                                            font_size: 13.0,
+                                            ..default()
+                                        },
+                                        TextColor(Color::srgb(1.0, 0.9, 0.6)),
+                                    ));
+                                    if let Some(quest) = quest_log.active.first() {
+                                        spawn_detail_children(detail, quest, &font);
+                                    } else {
+                                        detail.spawn((
+                                            Text::new("Select a quest to see details."),
                                             TextFont {
                                                 font: font.clone(),
-                                                font_size: 11.0,
+                                                font_size: 12.0,
                                                 ..default()
                                             },
-                                            TextColor(Color::srgb(0.6, 0.85, 0.6)),
+                                            TextColor(Color::srgb(0.6, 0.6, 0.6)),
                                         ));
-                                    });
-                                }
-                            }
+                                    }
+                                });
                         });
 
-                    // Divider
-                    panel.spawn((
+                    spawn_achievements_page(panel, &font, &theme);
+                });
+        });
+}
+
+/// Achievements page: unlock summary plus a scrolling list of achievement rows.
+/// Starts hidden; `update_achievements_page` fills and shows it.
+fn spawn_achievements_page(parent: &mut ChildBuilder, font: &Handle<Font>, theme: &MenuTheme) {
+    parent
+        .spawn((
+            JournalAchievementsPage,
+            Node {
+                display: Display::None,
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                flex_grow: 1.0,
+                row_gap: Val::Px(4.0),
+                overflow: Overflow::clip_y(),
+                ..default()
+            },
+        ))
+        .with_children(|page| {
+            page.spawn((
+                AchievementSummaryText,
+                Text::new(""),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.85, 0.4)),
+            ));
+            for row in 0..ACHIEVEMENT_ROWS {
+                page.spawn((
+                    AchievementRow { row },
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::axes(Val::Px(6.0), Val::Px(3.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(theme.button_bg_normal),
+                    BorderColor(theme.button_border_normal),
+                ))
+                .with_children(|slot| {
+                    slot.spawn((
+                        AchievementRowName { row },
+                        Text::new(""),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(1.0, 0.9, 0.7)),
+                    ));
+                    slot.spawn((
+                        AchievementRowInfo { row },
+                        Text::new(""),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 11.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                    ));
+                    slot.spawn((
                         Node {
                             width: Val::Percent(100.0),
-                            height: Val::Px(2.0),
+                            height: Val::Px(4.0),
+                            margin: UiRect::top(Val::Px(2.0)),
                             ..default()
                         },
-                        BackgroundColor(Color::srgb(0.5, 0.4, 0.25)),
-                    ));
-
-                    // Detail panel
-                    panel
-                        .spawn((
-                            QuestDetailPanel,
+                        BackgroundColor(Color::srgba(0.05, 0.04, 0.03, 0.9)),
+                    ))
+                    .with_children(|track| {
+                        track.spawn((
+                            AchievementRowFill { row },
                             Node {
-                                width: Val::Percent(100.0),
-                                flex_direction: FlexDirection::Column,
-                                padding: UiRect::all(Val::Px(8.0)),
-                                row_gap: Val::Px(4.0),
-                                min_height: Val::Px(80.0),
+                                width: Val::Percent(0.0),
+                                height: Val::Percent(100.0),
                                 ..default()
                             },
-                            BackgroundColor(Color::srgba(0.08, 0.07, 0.06, 0.9)),
-                        ))
-                        .with_children(|detail| {
-                            detail.spawn((
-                                Text::new("DETAILS"),
-                                TextFont {
-                                    font: font.clone(),
-                                    font_size: 13.0,
-                                    ..default()
-                                },
-                                TextColor(Color::srgb(1.0, 0.9, 0.6)),
-                            ));
-                            if let Some(quest) = quest_log.active.first() {
-                                spawn_detail_children(detail, quest, &font);
-                            } else {
-                                detail.spawn((
-                                    Text::new("Select a quest to see details."),
-                                    TextFont {
-                                        font: font.clone(),
-                                        font_size: 12.0,
-                                        ..default()
-                                    },
-                                    TextColor(Color::srgb(0.6, 0.6, 0.6)),
-                                ));
-                            }
-                        });
+                            BackgroundColor(Color::srgb(0.4, 0.75, 0.35)),
+                        ));
+                    });
                 });
+            }
         });
 }
 
@@ -238,6 +392,21 @@ fn spawn_detail_children(parent: &mut ChildBuilder, quest: &Quest, font: &Handle
         },
         TextColor(Color::srgb(0.85, 0.85, 0.85)),
     ));
+    let fraction = quest_progress_fraction(&quest.objective);
+    parent.spawn((
+        Text::new(format!(
+            "{} ({:.0}%)",
+            format_objective(&quest.objective),
+            fraction * 100.0
+        )),
+        TextFont {
+            font: font.clone(),
+            font_size: 11.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.6, 0.85, 0.6)),
+    ));
+    spawn_progress_bar(parent, fraction);
     parent.spawn((
         Text::new(format!("Reward: {}g", quest.reward_gold)),
         TextFont {
@@ -319,16 +488,17 @@ pub fn update_quest_display(
 
 pub fn update_cursor_highlight(
     ui_state: Option<Res<JournalUiState>>,
+    theme: Res<MenuTheme>,
     mut item_query: Query<(&QuestListItemBg, &mut BackgroundColor, &mut BorderColor)>,
 ) {
     let Some(ui_state) = ui_state else { return };
     for (item, mut bg, mut border) in &mut item_query {
         if item.index == ui_state.cursor {
-            *bg = BackgroundColor(Color::srgba(0.35, 0.3, 0.2, 0.95));
-            *border = BorderColor(Color::srgb(1.0, 0.84, 0.0));
+            *bg = BackgroundColor(theme.button_bg_selected);
+            *border = BorderColor(theme.button_border_selected);
         } else {
-            *bg = BackgroundColor(Color::srgba(0.2, 0.17, 0.14, 0.9));
-            *border = BorderColor(Color::srgba(0.4, 0.35, 0.3, 0.7));
+            *bg = BackgroundColor(theme.button_bg_normal);
+            *border = BorderColor(theme.button_border_normal);
         }
     }
 }
@@ -344,6 +514,24 @@ pub fn journal_navigation(
     let Some(ref mut ui_state) = ui_state else {
         return;
     };
+
+    if action.move_left || action.move_right {
+        ui_state.page = match ui_state.page {
+            JournalPage::Quests => JournalPage::Achievements,
+            JournalPage::Achievements => JournalPage::Quests,
+        };
+        return;
+    }
+    if ui_state.page == JournalPage::Achievements {
+        if action.move_up && ui_state.achievement_cursor > 0 {
+            ui_state.achievement_cursor -= 1;
+        }
+        if action.move_down && ui_state.achievement_cursor + 1 < ACHIEVEMENTS.len() {
+            ui_state.achievement_cursor += 1;
+        }
+        return;
+    }
+
     let count = quest_log.active.len();
     if count == 0 {
         return;
@@ -393,10 +581,222 @@ pub fn journal_navigation(
     }
 }
 
+/// Shows the selected journal page and refreshes the achievement list.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn update_achievements_page(
+    ui_state: Option<Res<JournalUiState>>,
+    achievements: Res<Achievements>,
+    theme: Res<MenuTheme>,
+    mut title_query: Query<&mut Text, With<JournalTitleText>>,
+    mut quest_page: Query<&mut Node, (With<JournalQuestPage>, Without<JournalAchievementsPage>)>,
+    mut achievements_page: Query<
+        &mut Node,
+        (With<JournalAchievementsPage>, Without<JournalQuestPage>),
+    >,
+    mut summary_query: Query<&mut Text, (With<AchievementSummaryText>, Without<JournalTitleText>)>,
+    mut row_query: Query<(&AchievementRow, &mut BackgroundColor, &mut BorderColor)>,
+    mut name_query: Query<
+        (&AchievementRowName, &mut Text, &mut TextColor),
+        (Without<JournalTitleText>, Without<AchievementSummaryText>),
+    >,
+    mut info_query: Query<
+        (&AchievementRowInfo, &mut Text),
+        (
+            Without<AchievementRowName>,
+            Without<JournalTitleText>,
+            Without<AchievementSummaryText>,
+        ),
+    >,
+    mut fill_query: Query<
+        (&AchievementRowFill, &mut Node, &mut BackgroundColor),
+        (
+            Without<AchievementRow>,
+            Without<JournalQuestPage>,
+            Without<JournalAchievementsPage>,
+        ),
+    >,
+) {
+    let Some(ui_state) = ui_state else { return };
+    if !ui_state.is_changed() && !achievements.is_changed() {
+        return;
+    }
+
+    let on_achievements = ui_state.page == JournalPage::Achievements;
+    for mut text in &mut title_query {
+        **text = if on_achievements {
+            "ACHIEVEMENTS".to_string()
+        } else {
+            "QUEST LOG".to_string()
+        };
+    }
+    for mut node in &mut quest_page {
+        node.display = if on_achievements {
+            Display::None
+        } else {
+            Display::Flex
+        };
+    }
+    for mut node in &mut achievements_page {
+        node.display = if on_achievements {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+    if !on_achievements {
+        return;
+    }
+
+    let unlocked_count = ACHIEVEMENTS
+        .iter()
+        .filter(|def| is_unlocked(def.id, &achievements))
+        .count();
+    for mut text in &mut summary_query {
+        **text = format!("Unlocked {}/{}", unlocked_count, ACHIEVEMENTS.len());
+    }
+
+    let cursor = ui_state.achievement_cursor;
+    let offset = (cursor + 1).saturating_sub(ACHIEVEMENT_ROWS);
+    for (slot, mut bg, mut border) in &mut row_query {
+        if offset + slot.row == cursor {
+            *bg = BackgroundColor(theme.button_bg_selected);
+            *border = BorderColor(theme.button_border_selected);
+        } else {
+            *bg = BackgroundColor(theme.button_bg_normal);
+            *border = BorderColor(theme.button_border_normal);
+        }
+    }
+    for (slot, mut text, mut color) in &mut name_query {
+        let Some(def) = ACHIEVEMENTS.get(offset + slot.row) else {
+            continue;
+        };
+        if is_unlocked(def.id, &achievements) {
+            **text = format!("{} ✓", def.name);
+            color.0 = Color::srgb(1.0, 0.84, 0.0);
+        } else {
+            **text = def.name.to_string();
+            color.0 = Color::srgb(1.0, 0.9, 0.7);
+        }
+    }
+    for (slot, mut text) in &mut info_query {
+        let Some(def) = ACHIEVEMENTS.get(offset + slot.row) else {
+            continue;
+        };
+        **text = match def.counter {
+            Some((counter, target)) => {
+                let current = achievements.progress.get(counter).copied().unwrap_or(0);
+                format!("{} ({}/{})", def.description, current.min(target), target)
+            }
+            None => def.description.to_string(),
+        };
+    }
+    for (slot, mut node, mut color) in &mut fill_query {
+        let Some(def) = ACHIEVEMENTS.get(offset + slot.row) else {
+            continue;
+        };
+        let fraction = achievement_fraction(def.id, &achievements);
+        node.width = Val::Percent(fraction * 100.0);
+        color.0 = if fraction >= 1.0 {
+            Color::srgb(1.0, 0.84, 0.0)
+        } else {
+            Color::srgb(0.4, 0.75, 0.35)
+        };
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // HELPERS
 // ═══════════════════════════════════════════════════════════════════════
 
+fn is_unlocked(id: &str, achievements: &Achievements) -> bool {
+    achievements.unlocked.iter().any(|unlocked| unlocked == id)
+}
+
+/// How close an achievement is to unlocking, from 0.0 to 1.0. Unlocked
+/// achievements are always complete; locked ones without a progress counter
+/// stay at 0.0 until they unlock.
+pub fn achievement_fraction(id: &str, achievements: &Achievements) -> f32 {
+    if is_unlocked(id, achievements) {
+        return 1.0;
+    }
+    let Some((counter, target)) = ACHIEVEMENTS
+        .iter()
+        .find(|def| def.id == id)
+        .and_then(|def| def.counter)
+    else {
+        return 0.0;
+    };
+    if target == 0 {
+        return 1.0;
+    }
+    let current = achievements.progress.get(counter).copied().unwrap_or(0);
+    (current as f32 / target as f32).min(1.0)
+}
+
+/// How far along a quest objective is, from 0.0 (not started) to 1.0 (done).
+pub fn quest_progress_fraction(objective: &QuestObjective) -> f32 {
+    let counted = |done: u8, total: u8| {
+        if total == 0 {
+            1.0
+        } else {
+            (done as f32 / total as f32).min(1.0)
+        }
+    };
+    let flag = |done: bool| if done { 1.0 } else { 0.0 };
+
+    match objective {
+        QuestObjective::Deliver {
+            quantity,
+            delivered,
+            ..
+        } => counted(*delivered, *quantity),
+        QuestObjective::Catch { delivered, .. } => flag(*delivered),
+        QuestObjective::Harvest {
+            quantity,
+            harvested,
+            ..
+        } => counted(*harvested, *quantity),
+        QuestObjective::Mine {
+            quantity,
+            collected,
+            ..
+        } => counted(*collected, *quantity),
+        QuestObjective::Talk { talked, .. } => flag(*talked),
+        QuestObjective::Slay {
+            quantity, slain, ..
+        } => counted(*slain, *quantity),
+    }
+}
+
+/// Thin horizontal bar filled to `fraction`; turns gold once complete.
+fn spawn_progress_bar(parent: &mut ChildBuilder, fraction: f32) {
+    let fill_color = if fraction >= 1.0 {
+        Color::srgb(1.0, 0.84, 0.0)
+    } else {
+        Color::srgb(0.4, 0.75, 0.35)
+    };
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(6.0),
+                margin: UiRect::top(Val::Px(3.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.04, 0.03, 0.9)),
+        ))
+        .with_children(|track| {
+            track.spawn((
+                Node {
+                    width: Val::Percent(fraction.clamp(0.0, 1.0) * 100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(fill_color),
+            ));
+        });
+}
+
 fn format_objective(objective: &QuestObjective) -> String {
     match objective {
         QuestObjective::Deliver {
@@ -443,3 +843,95 @@ fn format_objective(objective: &QuestObjective) -> String {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counted_objectives_report_partial_progress() {
+        let deliver = QuestObjective::Deliver {
+            item_id: "egg".into(),
+            quantity: 4,
+            delivered: 1,
+        };
+        let harvest = QuestObjective::Harvest {
+            crop_id: "turnip".into(),
+            quantity: 5,
+            harvested: 5,
+        };
+        let mine = QuestObjective::Mine {
+            item_id: "copper_ore".into(),
+            quantity: 10,
+            collected: 0,
+        };
+        let slay = QuestObjective::Slay {
+            monster_kind: "slime".into(),
+            quantity: 2,
+            slain: 1,
+        };
+        assert_eq!(quest_progress_fraction(&deliver), 0.25);
+        assert_eq!(quest_progress_fraction(&harvest), 1.0);
+        assert_eq!(quest_progress_fraction(&mine), 0.0);
+        assert_eq!(quest_progress_fraction(&slay), 0.5);
+    }
+
+    #[test]
+    fn flag_objectives_are_all_or_nothing() {
+        let catch = |delivered| QuestObjective::Catch {
+            fish_id: "carp".into(),
+            delivered,
+        };
+        let talk = |talked| QuestObjective::Talk {
+            npc_name: "Mira".into(),
+            talked,
+        };
+        assert_eq!(quest_progress_fraction(&catch(false)), 0.0);
+        assert_eq!(quest_progress_fraction(&catch(true)), 1.0);
+        assert_eq!(quest_progress_fraction(&talk(false)), 0.0);
+        assert_eq!(quest_progress_fraction(&talk(true)), 1.0);
+    }
+
+    #[test]
+    fn achievement_fraction_tracks_counter_progress() {
+        let mut achievements = Achievements::default();
+        assert_eq!(achievement_fraction("rock_breaker", &achievements), 0.0);
+
+        achievements.progress.insert("rocks_broken".into(), 25);
+        assert_eq!(achievement_fraction("rock_breaker", &achievements), 0.25);
+
+        // Counters can run past the target before the unlock check fires.
+        achievements.progress.insert("rocks_broken".into(), 250);
+        assert_eq!(achievement_fraction("rock_breaker", &achievements), 1.0);
+    }
+
+    #[test]
+    fn achievement_fraction_is_full_once_unlocked() {
+        let mut achievements = Achievements::default();
+        assert_eq!(achievement_fraction("newlywed", &achievements), 0.0);
+        achievements.unlocked.push("newlywed".into());
+        achievements.unlocked.push("green_acres".into());
+        assert_eq!(achievement_fraction("newlywed", &achievements), 1.0);
+        assert_eq!(achievement_fraction("green_acres", &achievements), 1.0);
+        assert_eq!(
+            achievement_fraction("no_such_achievement", &achievements),
+            0.0
+        );
+    }
+
+    #[test]
+    fn progress_is_clamped_and_handles_zero_quantity() {
+        let over = QuestObjective::Harvest {
+            crop_id: "turnip".into(),
+            quantity: 2,
+            harvested: 9,
+        };
+        let empty = QuestObjective::Deliver {
+            item_id: "egg".into(),
+            quantity: 0,
+            delivered: 0,
+        };
+        assert_eq!(quest_progress_fraction(&over), 1.0);
+        assert_eq!(quest_progress_fraction(&empty), 1.0);
+    }
+}
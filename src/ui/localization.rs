@@ -0,0 +1,196 @@
+//! Keyed UI string tables.
+//!
+//! Each language lives in `assets/lang/<code>.json` as a flat JSON object of
+//! `"key": "text"` pairs. Lookups fall back to the English table, then to the
+//! key itself, so a partial translation never leaves a label blank.
+//!
+//! The files are embedded at compile time so labels resolve on the wasm build
+//! and regardless of the directory the game is launched from.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::shared::*;
+
+/// Embedded language files, as (code, JSON text).
+const LANG_FILES: &[(&str, &str)] = &[
+    ("en", include_str!("../../assets/lang/en.json")),
+    ("es", include_str!("../../assets/lang/es.json")),
+];
+
+/// Language every lookup falls back to.
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// Languages offered by the settings screen, as (code, display name).
+pub const LANGUAGES: &[(&str, &str)] = &[("en", "English"), ("es", "Español")];
+
+/// The active language's strings plus the English fallback table.
+#[derive(Resource, Debug, Clone)]
+pub struct Localization {
+    pub code: String,
+    strings: HashMap<String, String>,
+    fallback: HashMap<String, String>,
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Self::load(DEFAULT_LANGUAGE)
+    }
+}
+
+impl Localization {
+    /// Build from in-memory tables.
+    pub fn from_tables(
+        code: impl Into<String>,
+        strings: HashMap<String, String>,
+        fallback: HashMap<String, String>,
+    ) -> Self {
+        Self {
+            code: code.into(),
+            strings,
+            fallback,
+        }
+    }
+
+    /// Parse the `code` table and the English fallback. Unknown codes and
+    /// malformed files are logged and treated as empty tables.
+    pub fn load(code: &str) -> Self {
+        let fallback = read_table(DEFAULT_LANGUAGE);
+        let strings = if code == DEFAULT_LANGUAGE {
+            fallback.clone()
+        } else {
+            read_table(code)
+        };
+        Self::from_tables(code, strings, fallback)
+    }
+
+    /// Look up `key` in the active language, then English, then return the
+    /// key unchanged.
+    pub fn t<'a>(&'a self, key: &'a str) -> &'a str {
+        self.strings
+            .get(key)
+            .or_else(|| self.fallback.get(key))
+            .map(String::as_str)
+            .unwrap_or(key)
+    }
+}
+
+fn read_table(code: &str) -> HashMap<String, String> {
+    let Some((_, text)) = LANG_FILES.iter().find(|(c, _)| *c == code) else {
+        warn!("Missing language file {}.json", code);
+        return HashMap::new();
+    };
+    serde_json::from_str(text).unwrap_or_else(|e| {
+        warn!("Failed to parse {}.json: {}", code, e);
+        HashMap::new()
+    })
+}
+
+/// Display name for a language code, or the code itself if unknown.
+pub fn language_name(code: &str) -> &str {
+    LANGUAGES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, name)| *name)
+        .unwrap_or(code)
+}
+
+/// The language after `code` in `LANGUAGES`, wrapping around.
+pub fn next_language(code: &str) -> &'static str {
+    let index = LANGUAGES.iter().position(|(c, _)| *c == code);
+    let next = index.map_or(0, |i| (i + 1) % LANGUAGES.len());
+    LANGUAGES[next].0
+}
+
+/// A label showing the string for `key`, kept in the active language by
+/// `refresh_localized_text`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct LocalizedText(pub &'static str);
+
+/// Reload the string tables whenever the chosen language changes.
+pub fn sync_localization(settings: Res<GameSettings>, mut localization: ResMut<Localization>) {
+    if !settings.is_changed() || localization.code == settings.language {
+        return;
+    }
+    *localization = Localization::load(&settings.language);
+}
+
+/// Rewrite every `LocalizedText` label after the string tables change, so
+/// UI that stays on screen (like the HUD) switches language immediately.
+pub fn refresh_localized_text(
+    localization: Res<Localization>,
+    mut labels: Query<(&LocalizedText, &mut Text)>,
+) {
+    if !localization.is_changed() {
+        return;
+    }
+    for (label, mut text) in &mut labels {
+        **text = localization.t(label.0).to_string();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn present_keys_resolve_and_missing_keys_fall_back() {
+        let loc = Localization::from_tables(
+            "es",
+            table(&[("hud.gold", "Oro")]),
+            table(&[("hud.gold", "Gold"), ("hud.health", "Health")]),
+        );
+
+        assert_eq!(loc.t("hud.gold"), "Oro");
+        assert_eq!(loc.t("hud.health"), "Health", "falls back to English");
+        assert_eq!(loc.t("hud.unknown"), "hud.unknown", "falls back to the key");
+    }
+
+    #[test]
+    fn shipped_translations_only_use_english_keys() {
+        let english = read_table(DEFAULT_LANGUAGE);
+        assert!(!english.is_empty());
+        for (code, _) in LANGUAGES {
+            let table = read_table(code);
+            assert!(!table.is_empty(), "{} is not embedded", code);
+            for key in table.keys() {
+                assert!(english.contains_key(key), "{}: unknown key {}", code, key);
+            }
+        }
+    }
+
+    #[test]
+    fn localized_labels_follow_the_language_setting() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<GameSettings>()
+            .init_resource::<Localization>()
+            .add_systems(Update, (sync_localization, refresh_localized_text).chain());
+        let label = app
+            .world_mut()
+            .spawn((Text::new("Gold"), LocalizedText("hud.gold")))
+            .id();
+        app.update();
+
+        app.world_mut().resource_mut::<GameSettings>().language = "es".to_string();
+        app.update();
+
+        let text = app.world().get::<Text>(label).unwrap();
+        assert_eq!(text.0, read_table("es")["hud.gold"]);
+    }
+
+    #[test]
+    fn next_language_cycles_through_the_list() {
+        assert_eq!(next_language("en"), "es");
+        assert_eq!(next_language("es"), "en");
+        assert_eq!(next_language("xx"), "en");
+    }
+}
@@ -0,0 +1,330 @@
+//! Mailbox screen — read letters and collect their attachments.
+//!
+//! Activated by entering `GameState::Mailbox` from the farm mailbox. Uses the
+//! same `MenuAction` resource that all other overlay menus consume.
+
+use super::UiFontHandle;
+use crate::economy::mail::collect_letter;
+use crate::shared::*;
+use bevy::prelude::*;
+
+/// Number of subject lines shown at once; the list scrolls with the cursor.
+const VISIBLE_ROWS: usize = 6;
+
+// ═══════════════════════════════════════════════════════════════════════
+// LOCAL TYPES
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Marker component for the root UI node so we can despawn everything on exit.
+#[derive(Component)]
+pub struct MailboxScreenRoot;
+
+/// A subject line in the letter list. `row` is the on-screen slot, not the
+/// letter index.
+#[derive(Component)]
+pub struct MailRow {
+    pub row: usize,
+}
+
+/// Marker for the body text of the selected letter.
+#[derive(Component)]
+pub struct MailBodyText;
+
+/// Marker for the status feedback text at the bottom.
+#[derive(Component)]
+pub struct MailStatusText;
+
+/// UI-local resource tracking the cursor and feedback message.
+#[derive(Resource, Default)]
+pub struct MailboxScreenState {
+    cursor: usize,
+    status_message: String,
+    status_timer: f32,
+}
+
+fn row_colors(selected: bool) -> (Color, Color) {
+    if selected {
+        (
+            Color::srgba(0.35, 0.3, 0.2, 0.9),
+            Color::srgb(1.0, 0.95, 0.8),
+        )
+    } else {
+        (
+            Color::srgba(0.2, 0.17, 0.14, 0.6),
+            Color::srgb(0.75, 0.7, 0.6),
+        )
+    }
+}
+
+fn attachment_line(attachment: &Option<(ItemId, u8)>, item_registry: &ItemRegistry) -> String {
+    match attachment {
+        Some((item_id, qty)) => {
+            let name = item_registry
+                .get(item_id)
+                .map(|def| def.name.as_str())
+                .unwrap_or(item_id.as_str());
+            format!("\n\nAttached: {} x{}", name, qty)
+        }
+        None => String::new(),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// SPAWN / DESPAWN
+// ═══════════════════════════════════════════════════════════════════════
+
+pub fn spawn_mailbox_screen(mut commands: Commands, font_handle: Res<UiFontHandle>) {
+    commands.insert_resource(MailboxScreenState::default());
+
+    commands
+        .spawn((
+            MailboxScreenRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(520.0),
+                        min_height: Val::Px(360.0),
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(16.0)),
+                        row_gap: Val::Px(6.0),
+                        border: UiRect::all(Val::Px(3.0)),
+                        overflow: Overflow::clip(),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.12, 0.1, 0.08, 0.95)),
+                    BorderColor(Color::srgb(0.5, 0.4, 0.25)),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new("MAILBOX"),
+                        TextFont {
+                            font: font_handle.0.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(1.0, 0.9, 0.6)),
+                    ));
+
+                    for row in 0..VISIBLE_ROWS {
+                        let (bg, fg) = row_colors(row == 0);
+                        panel
+                            .spawn((
+                                MailRow { row },
+                                Node {
+                                    width: Val::Percent(100.0),
+                                    height: Val::Px(26.0),
+                                    align_items: AlignItems::Center,
+                                    padding: UiRect::horizontal(Val::Px(8.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(bg),
+                            ))
+                            .with_children(|r| {
+                                r.spawn((
+                                    Text::new(""),
+                                    TextFont {
+                                        font: font_handle.0.clone(),
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(fg),
+                                ));
+                            });
+                    }
+
+                    panel.spawn((
+                        MailBodyText,
+                        Text::new(""),
+                        TextFont {
+                            font: font_handle.0.clone(),
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.88, 0.8)),
+                        Node {
+                            min_height: Val::Px(90.0),
+                            margin: UiRect::top(Val::Px(8.0)),
+                            ..default()
+                        },
+                    ));
+
+                    panel.spawn((
+                        MailStatusText,
+                        Text::new(""),
+                        TextFont {
+                            font: font_handle.0.clone(),
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.5, 0.9, 0.5)),
+                    ));
+
+                    panel.spawn((
+                        Text::new("Up/Down: Select | Enter: Collect | Esc: Close"),
+                        TextFont {
+                            font: font_handle.0.clone(),
+                            font_size: 11.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.5, 0.5, 0.5)),
+                    ));
+                });
+        });
+}
+
+pub fn despawn_mailbox_screen(
+    mut commands: Commands,
+    query: Query<Entity, With<MailboxScreenRoot>>,
+) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<MailboxScreenState>();
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// NAVIGATION + INPUT
+// ═══════════════════════════════════════════════════════════════════════
+
+pub fn mailbox_navigation(
+    action: Res<MenuAction>,
+    mut ui_state: Option<ResMut<MailboxScreenState>>,
+    mut mailbox: ResMut<Mailbox>,
+    mut inventory: ResMut<Inventory>,
+    item_registry: Res<ItemRegistry>,
+    mut sfx_writer: EventWriter<PlaySfxEvent>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Some(ref mut ui_state) = ui_state else {
+        return;
+    };
+
+    if action.cancel {
+        next_state.set(GameState::Playing);
+        return;
+    }
+
+    let count = mailbox.letters.len();
+    if action.move_down && ui_state.cursor + 1 < count {
+        ui_state.cursor += 1;
+    }
+    if action.move_up && ui_state.cursor > 0 {
+        ui_state.cursor -= 1;
+    }
+
+    if action.activate && ui_state.cursor < count {
+        match collect_letter(
+            &mut mailbox,
+            ui_state.cursor,
+            &mut inventory,
+            &item_registry,
+        ) {
+            Some(letter) => {
+                ui_state.status_message = match letter.attachment {
+                    Some(_) => format!("Collected \"{}\".", letter.subject),
+                    None => format!("Filed away \"{}\".", letter.subject),
+                };
+                sfx_writer.send(PlaySfxEvent {
+                    sfx_id: "pickup".to_string(),
+                });
+                if mailbox.letters.is_empty() {
+                    next_state.set(GameState::Playing);
+                    return;
+                }
+                ui_state.cursor = ui_state.cursor.min(mailbox.letters.len() - 1);
+            }
+            None => {
+                ui_state.status_message = "Not enough room in your inventory!".to_string();
+            }
+        }
+        ui_state.status_timer = 2.0;
+    }
+}
+
+/// Refreshes the letter list, cursor highlight, and selected letter body.
+#[allow(clippy::type_complexity)]
+pub fn update_mailbox_display(
+    ui_state: Option<Res<MailboxScreenState>>,
+    mailbox: Res<Mailbox>,
+    item_registry: Res<ItemRegistry>,
+    mut row_query: Query<(&MailRow, &mut BackgroundColor, &Children)>,
+    mut text_query: Query<
+        (&mut Text, &mut TextColor),
+        (Without<MailBodyText>, Without<MailStatusText>),
+    >,
+    mut body_query: Query<&mut Text, (With<MailBodyText>, Without<MailStatusText>)>,
+    mut status_query: Query<&mut Text, (With<MailStatusText>, Without<MailBodyText>)>,
+) {
+    let Some(ui_state) = ui_state else { return };
+
+    let offset = (ui_state.cursor + 1).saturating_sub(VISIBLE_ROWS);
+    for (mail_row, mut bg, children) in &mut row_query {
+        let index = offset + mail_row.row;
+        let (row_bg, row_fg) = row_colors(index == ui_state.cursor);
+        let label = mailbox
+            .letters
+            .get(index)
+            .map(|letter| {
+                let clip = if letter.attachment.is_some() {
+                    " [+]"
+                } else {
+                    ""
+                };
+                format!("{}{}", letter.subject, clip)
+            })
+            .unwrap_or_default();
+
+        *bg = if index < mailbox.letters.len() {
+            BackgroundColor(row_bg)
+        } else {
+            BackgroundColor(Color::NONE)
+        };
+        for &child in children.iter() {
+            if let Ok((mut text, mut color)) = text_query.get_mut(child) {
+                **text = label.clone();
+                color.0 = row_fg;
+            }
+        }
+    }
+
+    for mut text in &mut body_query {
+        **text = mailbox
+            .letters
+            .get(ui_state.cursor)
+            .map(|letter| {
+                format!(
+                    "{}{}",
+                    letter.body,
+                    attachment_line(&letter.attachment, &item_registry)
+                )
+            })
+            .unwrap_or_default();
+    }
+
+    for mut text in &mut status_query {
+        **text = ui_state.status_message.clone();
+    }
+}
+
+/// Tick down the status message timer.
+pub fn mailbox_status_timer(time: Res<Time>, mut ui_state: Option<ResMut<MailboxScreenState>>) {
+    let Some(ref mut ui_state) = ui_state else {
+        return;
+    };
+    if ui_state.status_timer > 0.0 {
+        ui_state.status_timer -= time.delta_secs();
+        if ui_state.status_timer <= 0.0 {
+            ui_state.status_message.clear();
+        }
+    }
+}
@@ -4,6 +4,8 @@ use crate::save::{
     LoadCompleteEvent, LoadRequestEvent, NewGameEvent, SaveSlotInfoCache, NUM_SAVE_SLOTS,
 };
 use crate::shared::*;
+use crate::world::maps::FarmLayout;
+use bevy::input::keyboard::{Key, KeyboardInput};
 use bevy::prelude::*;
 #[cfg(not(target_arch = "wasm32"))]
 use std::path::{Path, PathBuf};
@@ -25,12 +27,18 @@ pub struct MainMenuState {
     pub cursor: usize,
     pub status_message: String,
     pub pending_load_slot: Option<u8>,
+    /// Layout picked for the new farm while its names are being entered.
+    pub pending_layout: FarmLayout,
+    pub farm_name_input: String,
+    pub player_name_input: String,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MainMenuMode {
     Root,
     LoadSlots,
+    FarmLayout,
+    NameEntry,
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -47,6 +55,13 @@ const MAIN_MENU_OPTIONS: &[&str] = &[
 const MAIN_MENU_OPTIONS: &[&str] = &["New Game", "Load Game", "Fishing Encyclopedia"];
 const LOAD_MENU_BACK_INDEX: usize = NUM_SAVE_SLOTS;
 const LOAD_MENU_OPTION_COUNT: usize = NUM_SAVE_SLOTS + 1;
+const LAYOUT_MENU_BACK_INDEX: usize = FarmLayout::ALL.len();
+const LAYOUT_MENU_OPTION_COUNT: usize = FarmLayout::ALL.len() + 1;
+const NAME_ENTRY_FARM_INDEX: usize = 0;
+const NAME_ENTRY_PLAYER_INDEX: usize = 1;
+const NAME_ENTRY_OPTION_COUNT: usize = 2;
+const NAME_ENTRY_HINT: &str = "Type to edit - Tab switches field - Enter to start - Esc to go back";
+const DEFAULT_FARM_NAME: &str = "Hearthfield Farm";
 const ROOT_MENU_OPTION_COUNT: usize = MAIN_MENU_OPTIONS.len();
 const MENU_MODE_FADE_DURATION: f32 = 0.22;
 const TITLE_BOB_SPEED: f32 = 1.35;
@@ -140,6 +155,9 @@ pub fn spawn_main_menu(
         cursor: 0,
         status_message: String::new(),
         pending_load_slot: None,
+        pending_layout: FarmLayout::default(),
+        farm_name_input: DEFAULT_FARM_NAME.to_string(),
+        player_name_input: PlayerProfile::default().name,
     });
     commands.insert_resource(MainMenuVisualState {
         previous_mode: MainMenuMode::Root,
@@ -246,6 +264,8 @@ fn current_option_count(mode: MainMenuMode) -> usize {
     match mode {
         MainMenuMode::Root => MAIN_MENU_OPTIONS.len(),
         MainMenuMode::LoadSlots => MAIN_MENU_MAX_ITEMS,
+        MainMenuMode::FarmLayout => LAYOUT_MENU_OPTION_COUNT,
+        MainMenuMode::NameEntry => NAME_ENTRY_OPTION_COUNT,
     }
 }
 
@@ -304,12 +324,17 @@ fn load_slot_label(slot_info: Option<&crate::save::SaveSlotInfo>) -> String {
     }
 }
 
+fn name_entry_label(title: &str, value: &str, editing: bool) -> String {
+    let caret = if editing { "_" } else { "" };
+    format!("{}\n{}{}", title, value, caret)
+}
+
 fn menu_option_label(
-    mode: MainMenuMode,
+    state: &MainMenuState,
     index: usize,
     cache: Option<&SaveSlotInfoCache>,
 ) -> Option<(String, bool)> {
-    match mode {
+    match state.mode {
         MainMenuMode::Root => MAIN_MENU_OPTIONS
             .get(index)
             .map(|label| ((*label).to_string(), true)),
@@ -322,6 +347,32 @@ fn menu_option_label(
                 Some((load_slot_label(slot_info), slot_exists))
             }
         }
+        MainMenuMode::FarmLayout => {
+            if index == LAYOUT_MENU_BACK_INDEX {
+                Some(("Back".to_string(), true))
+            } else {
+                FarmLayout::ALL.get(index).map(|layout| {
+                    (
+                        format!("{}\n{}", layout.display_name(), layout.description()),
+                        true,
+                    )
+                })
+            }
+        }
+        MainMenuMode::NameEntry => {
+            let editing = index == state.cursor;
+            match index {
+                NAME_ENTRY_FARM_INDEX => Some((
+                    name_entry_label("Farm name", &state.farm_name_input, editing),
+                    true,
+                )),
+                NAME_ENTRY_PLAYER_INDEX => Some((
+                    name_entry_label("Your name", &state.player_name_input, editing),
+                    true,
+                )),
+                _ => None,
+            }
+        }
     }
 }
 
@@ -406,8 +457,7 @@ pub fn update_main_menu_visuals(
             continue;
         }
 
-        let Some((label, enabled)) =
-            menu_option_label(state.mode, btn_text.index, cache.as_deref())
+        let Some((label, enabled)) = menu_option_label(&state, btn_text.index, cache.as_deref())
         else {
             text.0.clear();
             continue;
@@ -432,19 +482,29 @@ pub fn update_main_menu_visuals(
     status.0 = state.status_message.clone();
 }
 
-#[allow(clippy::too_many_arguments)]
 pub fn main_menu_navigation(
     action: Res<MenuAction>,
     mut state: Option<ResMut<MainMenuState>>,
     cache: Option<Res<SaveSlotInfoCache>>,
     mut next_state: ResMut<NextState<GameState>>,
-    mut new_game_events: EventWriter<NewGameEvent>,
     mut load_events: EventWriter<LoadRequestEvent>,
     mut app_exit: EventWriter<AppExit>,
-    mut cutscene_queue: ResMut<CutsceneQueue>,
-    mut fade: ResMut<super::transitions::ScreenFade>,
 ) {
     let Some(ref mut state) = state else { return };
+
+    // Typing is handled by main_menu_name_entry; only Esc applies here.
+    if state.mode == MainMenuMode::NameEntry {
+        if action.cancel {
+            state.mode = MainMenuMode::FarmLayout;
+            state.cursor = FarmLayout::ALL
+                .iter()
+                .position(|layout| *layout == state.pending_layout)
+                .unwrap_or(0);
+            state.status_message.clear();
+        }
+        return;
+    }
+
     let option_count = current_option_count(state.mode);
 
     // Pointer hover → set cursor
@@ -469,22 +529,9 @@ pub fn main_menu_navigation(
         match state.mode {
             MainMenuMode::Root => match state.cursor {
                 0 => {
-                    new_game_events.send(NewGameEvent {
-                        farm_name: "Hearthfield Farm".to_string(),
-                        active_slot: 0,
-                    });
-                    // Set screen to black before entering Playing so the
-                    // farm spawns invisibly behind the fade overlay.
-                    fade.alpha = 1.0;
-                    fade.target_alpha = 1.0;
-                    fade.active = false;
-                    // Pre-populate the cutscene queue with the intro sequence.
-                    // start_pending_cutscene (OnEnter Playing) will detect this
-                    // and redirect to Cutscene state.
-                    cutscene_queue.steps = super::intro_sequence::build_intro_sequence();
-                    cutscene_queue.active = true;
-                    cutscene_queue.step_timer = 0.0;
-                    next_state.set(GameState::Playing);
+                    state.mode = MainMenuMode::FarmLayout;
+                    state.cursor = 0;
+                    state.status_message.clear();
                 }
                 1 => {
                     state.mode = MainMenuMode::LoadSlots;
@@ -520,6 +567,18 @@ pub fn main_menu_navigation(
                 }
                 _ => {}
             },
+            MainMenuMode::FarmLayout => {
+                if state.cursor == LAYOUT_MENU_BACK_INDEX {
+                    state.mode = MainMenuMode::Root;
+                    state.cursor = 0;
+                } else if let Some(&farm_layout) = FarmLayout::ALL.get(state.cursor) {
+                    state.pending_layout = farm_layout;
+                    state.mode = MainMenuMode::NameEntry;
+                    state.cursor = NAME_ENTRY_FARM_INDEX;
+                    state.status_message = NAME_ENTRY_HINT.to_string();
+                }
+            }
+            MainMenuMode::NameEntry => {}
             MainMenuMode::LoadSlots => {
                 if state.cursor == LOAD_MENU_BACK_INDEX {
                     state.mode = MainMenuMode::Root;
@@ -546,7 +605,7 @@ pub fn main_menu_navigation(
         }
     }
 
-    if action.cancel && state.mode == MainMenuMode::LoadSlots {
+    if action.cancel && state.mode != MainMenuMode::Root {
         state.mode = MainMenuMode::Root;
         state.cursor = 0;
         state.status_message.clear();
@@ -554,6 +613,92 @@ pub fn main_menu_navigation(
     }
 }
 
+fn name_entry_field(state: &mut MainMenuState) -> &mut String {
+    if state.cursor == NAME_ENTRY_PLAYER_INDEX {
+        &mut state.player_name_input
+    } else {
+        &mut state.farm_name_input
+    }
+}
+
+/// New-game name entry: typed characters edit the highlighted field, Tab or
+/// the arrow keys switch fields and Enter starts the farm once both names
+/// validate. Esc is handled by `main_menu_navigation`.
+pub fn main_menu_name_entry(
+    mut key_events: EventReader<KeyboardInput>,
+    mut state: Option<ResMut<MainMenuState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut new_game_events: EventWriter<NewGameEvent>,
+    mut cutscene_queue: ResMut<CutsceneQueue>,
+    mut fade: ResMut<super::transitions::ScreenFade>,
+) {
+    let Some(ref mut state) = state else {
+        key_events.clear();
+        return;
+    };
+    if state.mode != MainMenuMode::NameEntry {
+        key_events.clear();
+        return;
+    }
+
+    for event in key_events.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter => {
+                let farm_name = match validate_name(&state.farm_name_input) {
+                    Ok(name) => name,
+                    Err(e) => {
+                        state.cursor = NAME_ENTRY_FARM_INDEX;
+                        state.status_message = format!("Farm name: {}", e);
+                        continue;
+                    }
+                };
+                let player_name = match validate_name(&state.player_name_input) {
+                    Ok(name) => name,
+                    Err(e) => {
+                        state.cursor = NAME_ENTRY_PLAYER_INDEX;
+                        state.status_message = format!("Your name: {}", e);
+                        continue;
+                    }
+                };
+
+                new_game_events.send(NewGameEvent {
+                    farm_name,
+                    player_name,
+                    active_slot: 0,
+                    farm_layout: state.pending_layout,
+                    start_season: None,
+                    start_year: None,
+                });
+                // Set screen to black before entering Playing so the
+                // farm spawns invisibly behind the fade overlay.
+                fade.alpha = 1.0;
+                fade.target_alpha = 1.0;
+                fade.active = false;
+                // Pre-populate the cutscene queue with the intro sequence.
+                // start_pending_cutscene (OnEnter Playing) will detect this
+                // and redirect to Cutscene state.
+                cutscene_queue.steps = super::intro_sequence::build_intro_sequence();
+                cutscene_queue.active = true;
+                cutscene_queue.step_timer = 0.0;
+                next_state.set(GameState::Playing);
+                return;
+            }
+            Key::Tab | Key::ArrowUp | Key::ArrowDown => {
+                state.cursor = (state.cursor + 1) % NAME_ENTRY_OPTION_COUNT;
+            }
+            Key::Backspace => {
+                name_entry_field(state).pop();
+            }
+            Key::Space => push_name_text(name_entry_field(state), " "),
+            Key::Character(text) => push_name_text(name_entry_field(state), text),
+            _ => {}
+        }
+    }
+}
+
 pub fn handle_load_complete_in_main_menu(
     mut load_complete_events: EventReader<LoadCompleteEvent>,
     mut state: Option<ResMut<MainMenuState>>,
@@ -597,6 +742,8 @@ mod tests {
     fn menu_button_pool_covers_root_and_load_menus() {
         assert!(MAIN_MENU_MAX_ITEMS >= MAIN_MENU_OPTIONS.len());
         assert!(MAIN_MENU_MAX_ITEMS >= LOAD_MENU_OPTION_COUNT);
+        assert!(MAIN_MENU_MAX_ITEMS >= LAYOUT_MENU_OPTION_COUNT);
+        assert!(MAIN_MENU_MAX_ITEMS >= NAME_ENTRY_OPTION_COUNT);
     }
 
     #[test]
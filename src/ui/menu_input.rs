@@ -19,6 +19,8 @@ pub fn merge_keyboard_to_menu_action(input: Res<PlayerInput>, mut action: ResMut
     action.move_right = action.move_right || input.ui_right;
     action.activate = action.activate || input.ui_confirm;
     action.cancel = action.cancel || input.ui_cancel || input.pause;
+    action.secondary = action.secondary || input.ui_secondary;
+    action.discard = action.discard || input.ui_discard;
 }
 
 /// State transitions driven by PlayerInput (gameplay context).
@@ -30,6 +32,7 @@ pub fn gameplay_state_transitions(
     calendar_overlay: Res<CalendarOverlayState>,
     stats_overlay: Res<StatsOverlayState>,
     settings_overlay: Res<SettingsOverlayState>,
+    input_blocks: Res<InputBlocks>,
 ) {
     if *state.get() != GameState::Playing {
         return;
@@ -37,6 +40,11 @@ pub fn gameplay_state_transitions(
     if calendar_overlay.visible || stats_overlay.visible || settings_overlay.visible {
         return;
     }
+    // Text prompts (e.g. renaming an animal) type letters that double as
+    // menu hotkeys.
+    if input_blocks.is_blocked() {
+        return;
+    }
     if input.pause {
         next.set(GameState::Paused);
     }
@@ -110,12 +118,93 @@ pub fn menu_cancel_transitions(
 
 /// Hotbar slot selection driven by PlayerInput.
 /// Replaces hotbar_input_handler in ui/input.rs.
-pub fn hotbar_input_handler(input: Res<PlayerInput>, mut inventory: ResMut<Inventory>) {
+/// Selecting a slot that holds a tool also equips that tool.
+pub fn hotbar_input_handler(
+    input: Res<PlayerInput>,
+    mut inventory: ResMut<Inventory>,
+    item_registry: Res<ItemRegistry>,
+    mut player_state: ResMut<PlayerState>,
+) {
     // tool_slot maps 1-9 keys → Some(0..8)
     if let Some(slot) = input.tool_slot {
         inventory.selected_slot = slot as usize;
+
+        let tool = inventory
+            .slots
+            .get(inventory.selected_slot)
+            .and_then(Option::as_ref)
+            .and_then(|s| item_registry.get(&s.item_id))
+            .filter(|def| def.category == ItemCategory::Tool)
+            .and_then(|def| tool_kind_for_item(&def.id));
+        if let Some(tool) = tool {
+            player_state.equipped_tool = tool;
+        }
     }
 
     // Also support 0, -, = keys for slots 9-11 via tool_next/tool_prev as fallback
     // These are handled in the main input reader via digit keys already
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hotbar_test_app() -> App {
+        let mut registry = ItemRegistry::default();
+        for (id, category) in [("axe", ItemCategory::Tool), ("turnip", ItemCategory::Crop)] {
+            registry.items.insert(
+                id.to_string(),
+                ItemDef {
+                    id: id.to_string(),
+                    name: id.to_string(),
+                    description: String::new(),
+                    category,
+                    sell_price: 0,
+                    buy_price: None,
+                    stack_size: 1,
+                    edible: false,
+                    energy_restore: 0.0,
+                    sprite_index: 0,
+                },
+            );
+        }
+        let mut inventory = Inventory::default();
+        inventory.try_add("turnip", 1, 1);
+        inventory.try_add("axe", 1, 1);
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PlayerInput>()
+            .init_resource::<PlayerState>()
+            .insert_resource(registry)
+            .insert_resource(inventory)
+            .add_systems(Update, hotbar_input_handler);
+        app
+    }
+
+    fn select_slot(app: &mut App, slot: u8) {
+        app.world_mut().resource_mut::<PlayerInput>().tool_slot = Some(slot);
+        app.update();
+    }
+
+    #[test]
+    fn selecting_a_hotbar_tool_equips_it() {
+        let mut app = hotbar_test_app();
+        app.world_mut().resource_mut::<PlayerState>().equipped_tool = ToolKind::Hoe;
+
+        select_slot(&mut app, 1);
+        assert_eq!(app.world().resource::<Inventory>().selected_slot, 1);
+        assert_eq!(
+            app.world().resource::<PlayerState>().equipped_tool,
+            ToolKind::Axe
+        );
+
+        // A non-tool slot changes the selection but keeps the tool.
+        select_slot(&mut app, 0);
+        assert_eq!(app.world().resource::<Inventory>().selected_slot, 0);
+        assert_eq!(
+            app.world().resource::<PlayerState>().equipped_tool,
+            ToolKind::Axe
+        );
+    }
+}
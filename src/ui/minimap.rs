@@ -3,7 +3,8 @@
 //! positions, and map transition zones.
 
 use crate::shared::*;
-use crate::world::maps::generate_map;
+use crate::world::maps::{generate_map_with_layout, FarmLayout};
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy::render::render_asset::RenderAssetUsages;
 use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
@@ -128,6 +129,13 @@ pub fn despawn_minimap(mut commands: Commands, query: Query<Entity, With<Minimap
     commands.remove_resource::<MinimapState>();
 }
 
+/// The farm as the minimap draws it: its layout and what is planted on it.
+#[derive(SystemParam)]
+pub struct MinimapFarm<'w> {
+    farm_state: Res<'w, FarmState>,
+    farm_layout: Res<'w, FarmLayout>,
+}
+
 /// Update minimap pixels each frame: regenerate base on map change,
 /// overlay player and NPC positions.
 pub fn update_minimap(
@@ -135,10 +143,14 @@ pub fn update_minimap(
     player_state: Res<PlayerState>,
     player_query: Query<&GridPosition, With<Player>>,
     npc_query: Query<(&Npc, &Transform)>,
-    farm_state: Res<FarmState>,
+    farm: MinimapFarm,
     mut minimap: ResMut<MinimapState>,
     mut images: ResMut<Assets<Image>>,
 ) {
+    let MinimapFarm {
+        farm_state,
+        farm_layout,
+    } = farm;
     let current_map = player_state.current_map;
     let mut base_dirty = false;
 
@@ -146,7 +158,7 @@ pub fn update_minimap(
     if minimap.cached_map != Some(current_map)
         || (current_map == MapId::Farm && farm_state.is_changed())
     {
-        let map_def = generate_map(current_map);
+        let map_def = generate_map_with_layout(current_map, *farm_layout);
         let w = map_def.width.min(MAX_MAP);
         let h = map_def.height.min(MAX_MAP);
 
@@ -204,15 +216,18 @@ pub fn update_minimap(
     let w = minimap.map_width;
     let h = minimap.map_height;
     let player_tile = player_query.get_single().ok().and_then(|grid| {
-        let px = grid.x as usize;
-        let py = grid.y as usize;
+        let px = usize::try_from(grid.x).ok()?;
+        let py = usize::try_from(grid.y).ok()?;
         (px < w && py < h).then_some((px, py))
     });
     let player_blink_on = player_tile.map(|_| (time.elapsed_secs() * 4.0).sin() > 0.0);
     let mut npc_tiles = Vec::new();
     for (_npc, tf) in &npc_query {
-        let gx = (tf.translation.x / TILE_SIZE).round() as usize;
-        let gy = (tf.translation.y / TILE_SIZE).round() as usize;
+        // NPCs stand on tile centres; rounding would push them a tile over.
+        let grid = world_to_grid(tf.translation.x, tf.translation.y);
+        let (Ok(gx), Ok(gy)) = (usize::try_from(grid.x), usize::try_from(grid.y)) else {
+            continue;
+        };
         if gx < w && gy < h {
             npc_tiles.push((gx, gy));
         }
@@ -4,6 +4,8 @@ pub mod calendar_screen;
 mod chest_screen;
 mod crafting_screen;
 pub mod cutscene_runner;
+#[cfg(feature = "debug_commands")]
+pub mod debug_console;
 mod debug_overlay;
 pub mod dialogue_box;
 mod fish_encyclopedia;
@@ -12,14 +14,18 @@ mod hud;
 pub mod intro_sequence;
 mod inventory_screen;
 pub mod journal_screen;
+pub mod localization;
+pub mod mailbox_screen;
 mod main_menu;
 pub mod map_screen;
 pub mod menu_input;
 pub mod menu_kit;
 mod minimap;
 mod pause_menu;
+pub mod quest_board_screen;
 pub mod relationships_screen;
 pub mod settings_screen;
+pub mod shipping_log_screen;
 mod shop_screen;
 pub mod stats_screen;
 mod toast;
@@ -71,6 +77,7 @@ impl Plugin for UiPlugin {
             (
                 audio::handle_play_sfx,
                 audio::handle_play_music,
+                audio::update_music_fades,
                 audio::toast_sfx,
             )
                 .in_set(UpdatePhase::Reactions),
@@ -82,6 +89,8 @@ impl Plugin for UiPlugin {
             (
                 audio::switch_music_on_season_change,
                 audio::switch_music_on_map_change,
+                audio::switch_music_on_festival_change,
+                audio::switch_music_on_weather_change,
                 audio::door_sfx_on_map_change,
             )
                 .in_set(UpdatePhase::Reactions)
@@ -148,6 +157,7 @@ impl Plugin for UiPlugin {
             Update,
             (
                 main_menu::update_main_menu_visuals,
+                main_menu::main_menu_name_entry.before(main_menu::main_menu_navigation),
                 main_menu::main_menu_navigation,
                 main_menu::handle_load_complete_in_main_menu,
             )
@@ -206,7 +216,7 @@ impl Plugin for UiPlugin {
                 hud::update_gold_display,
                 hud::update_stamina_bar,
                 hud::update_health_bar,
-                hud::update_tool_display,
+                (hud::update_tool_display, hud::update_tool_cost_display),
                 hud::update_hotbar,
                 hud::hydrate_hotbar_icons,
                 hud::update_hotbar_icons,
@@ -255,6 +265,11 @@ impl Plugin for UiPlugin {
                 .run_if(in_state(GameState::Playing)),
         );
 
+        app.add_systems(
+            OnEnter(GameState::Inventory),
+            tutorial::record_inventory_opened,
+        );
+
         // ─── MENU ACTION RESET (PreUpdate, after input reader) ───
         app.add_systems(PreUpdate, menu_input::reset_menu_action);
 
@@ -294,6 +309,7 @@ impl Plugin for UiPlugin {
                 inventory_screen::update_inventory_slots,
                 inventory_screen::update_inventory_cursor,
                 inventory_screen::inventory_navigation,
+                inventory_screen::inventory_trash,
             )
                 .run_if(in_state(GameState::Inventory)),
         );
@@ -313,6 +329,7 @@ impl Plugin for UiPlugin {
                 journal_screen::update_quest_display,
                 journal_screen::update_cursor_highlight,
                 journal_screen::journal_navigation,
+                journal_screen::update_achievements_page,
             )
                 .run_if(in_state(GameState::Journal)),
         );
@@ -378,6 +395,7 @@ impl Plugin for UiPlugin {
                 shop_screen::update_shop_display,
                 shop_screen::shop_navigation,
             )
+                .in_set(UpdatePhase::Intent)
                 .run_if(in_state(GameState::Shop)),
         );
 
@@ -419,6 +437,44 @@ impl Plugin for UiPlugin {
                 .run_if(in_state(GameState::BuildingUpgrade)),
         );
 
+        // ─── MAILBOX ───
+        app.add_systems(
+            OnEnter(GameState::Mailbox),
+            mailbox_screen::spawn_mailbox_screen,
+        );
+        app.add_systems(
+            OnExit(GameState::Mailbox),
+            mailbox_screen::despawn_mailbox_screen,
+        );
+        app.add_systems(
+            Update,
+            (
+                mailbox_screen::update_mailbox_display,
+                mailbox_screen::mailbox_navigation,
+                mailbox_screen::mailbox_status_timer,
+            )
+                .run_if(in_state(GameState::Mailbox)),
+        );
+
+        // ─── QUEST BOARD ───
+        app.add_systems(
+            OnEnter(GameState::QuestBoard),
+            quest_board_screen::spawn_quest_board_screen,
+        );
+        app.add_systems(
+            OnExit(GameState::QuestBoard),
+            quest_board_screen::despawn_quest_board_screen,
+        );
+        app.add_systems(
+            Update,
+            (
+                quest_board_screen::update_quest_board_display,
+                quest_board_screen::quest_board_navigation,
+                quest_board_screen::quest_board_status_timer,
+            )
+                .run_if(in_state(GameState::QuestBoard)),
+        );
+
         // ─── PAUSE MENU ───
         app.add_systems(OnEnter(GameState::Paused), pause_menu::spawn_pause_menu);
         app.add_systems(OnExit(GameState::Paused), pause_menu::despawn_pause_menu);
@@ -428,9 +484,12 @@ impl Plugin for UiPlugin {
                 pause_menu::update_pause_menu_visuals,
                 pause_menu::pause_menu_navigation,
                 pause_menu::handle_save_complete_in_pause_menu,
+                pause_menu::open_pending_quit_confirm,
             )
                 .run_if(in_state(GameState::Paused)),
         );
+        app.init_resource::<pause_menu::PendingQuitConfirm>();
+        app.add_systems(Update, pause_menu::handle_window_close_request);
 
         // ─── DEBUG OVERLAY (always available, toggled by F3) ───
         app.init_resource::<DebugOverlayState>();
@@ -446,6 +505,27 @@ impl Plugin for UiPlugin {
             ),
         );
 
+        // ─── DEBUG CONSOLE (debug_commands builds only, toggled by `) ───
+        #[cfg(feature = "debug_commands")]
+        {
+            app.init_resource::<debug_console::DebugConsoleState>()
+                .add_event::<debug_console::DebugCommandEvent>();
+            app.add_systems(
+                Startup,
+                debug_console::spawn_debug_console.after(load_ui_font),
+            );
+            app.add_systems(
+                Update,
+                (
+                    debug_console::debug_console_input,
+                    debug_console::execute_debug_commands,
+                    debug_console::update_debug_console,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            );
+        }
+
         // ─── CHEST SCREEN (reactive overlay during Playing state) ───
         app.add_systems(
             Update,
@@ -456,6 +536,7 @@ impl Plugin for UiPlugin {
                 chest_screen::update_chest_cursor,
                 chest_screen::update_chest_quality_borders,
                 chest_screen::handle_chest_input,
+                chest_screen::handle_chest_bulk_transfer,
             )
                 .run_if(in_state(GameState::Playing)),
         );
@@ -487,19 +568,65 @@ impl Plugin for UiPlugin {
                 .run_if(in_state(GameState::Playing)),
         );
 
+        // ─── SHIPPING COLLECTION OVERLAY (F6 toggle during Playing) ───
+        app.init_resource::<shipping_log_screen::ShippingLogOverlayState>();
+        app.add_systems(
+            Update,
+            (
+                shipping_log_screen::toggle_shipping_log_overlay,
+                shipping_log_screen::shipping_log_close_on_escape,
+                shipping_log_screen::shipping_log_block_gameplay_input,
+                shipping_log_screen::shipping_log_page_input,
+                shipping_log_screen::update_shipping_log_lifecycle,
+            )
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        );
+
         // ─── SETTINGS OVERLAY (F4 toggle during Playing) ───
         app.init_resource::<settings_screen::SettingsOverlayState>();
         app.init_resource::<settings_screen::AudioVolume>();
+        app.init_resource::<localization::Localization>();
         app.add_systems(
             Update,
             (
                 settings_screen::toggle_settings_overlay,
                 settings_screen::settings_close_on_escape,
+                settings_screen::settings_block_gameplay_input,
                 settings_screen::update_settings_lifecycle,
                 settings_screen::settings_volume_input,
+                settings_screen::settings_fishing_assist_input,
+                settings_screen::settings_display_input,
+                settings_screen::settings_harvest_countdown_input,
+                settings_screen::settings_particle_density_input,
+                settings_screen::settings_gifts_per_day_input,
+                settings_screen::settings_repeat_gift_fatigue_input,
+                settings_screen::settings_creative_tools_input,
+                settings_screen::settings_peaceful_input,
+                settings_screen::settings_off_season_grace_input,
+                settings_screen::settings_language_input,
+                settings_screen::settings_keybinding_profile_input,
+                settings_screen::settings_camera_zoom_label,
             )
                 .chain()
                 .run_if(in_state(GameState::Playing)),
         );
+        // Display settings apply in every state so persisted values take
+        // effect on the main menu too.
+        app.add_systems(
+            Update,
+            (
+                settings_screen::apply_display_settings,
+                settings_screen::apply_keybinding_profile,
+            ),
+        );
+        app.add_systems(
+            Update,
+            (
+                localization::sync_localization,
+                localization::refresh_localized_text,
+            )
+                .chain(),
+        );
     }
 }
@@ -1,8 +1,13 @@
-use super::menu_kit::{self, set_button_visual, MenuAssets};
+use super::localization::Localization;
+use super::menu_kit::{self, set_button_visual, MenuAssets, MenuButtonText};
 use super::UiFontHandle;
-use crate::save::{ActiveSaveSlot, SaveCompleteEvent, SaveRequestEvent};
+use crate::save::{
+    ActiveSaveSlot, SaveCompleteEvent, SaveRequestEvent, SaveSlotInfo, SaveSlotInfoCache,
+    UnsavedChanges, NUM_SAVE_SLOTS,
+};
 use crate::shared::*;
 use bevy::prelude::*;
+use bevy::window::WindowCloseRequested;
 
 // ═══════════════════════════════════════════════════════════════════════
 // MARKER COMPONENTS
@@ -14,14 +19,120 @@ pub struct PauseMenuRoot;
 /// Tracks pause menu selection
 #[derive(Resource)]
 pub struct PauseMenuState {
+    pub mode: PauseMenuMode,
     pub cursor: usize,
     pub status_message: String,
+    /// Where to go once the pending save finishes (save-and-quit).
+    pub quit_after_save: Option<QuitTarget>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseMenuMode {
+    Root,
+    SaveSlots,
+    /// Waiting for the player to confirm overwriting an occupied slot.
+    ConfirmOverwrite(u8),
+    /// Quitting with unsaved progress: save first, quit anyway, or cancel.
+    ConfirmQuit(QuitTarget),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuitTarget {
+    MainMenu,
+    Desktop,
+}
+
+/// Set when the window close button is pressed with unsaved progress; the
+/// pause menu picks it up and shows the quit confirmation.
+#[derive(Resource, Debug, Default)]
+pub struct PendingQuitConfirm(pub bool);
+
 #[derive(Component)]
 pub struct PauseMenuStatusText;
 
-const PAUSE_OPTIONS: &[&str] = &["Resume", "Save Game", "Quit to Menu"];
+/// Localization keys for the root pause menu options.
+const PAUSE_OPTIONS: &[&str] = &["pause.resume", "pause.save_game", "pause.quit_to_menu"];
+const SAVE_MENU_BACK_INDEX: usize = NUM_SAVE_SLOTS;
+const SAVE_MENU_OPTION_COUNT: usize = NUM_SAVE_SLOTS + 1;
+const CONFIRM_OPTION_COUNT: usize = 2;
+const CONFIRM_QUIT_OPTION_COUNT: usize = 3;
+const QUIT_TO_MENU_INDEX: usize = 2;
+const PAUSE_MENU_MAX_ITEMS: usize = if PAUSE_OPTIONS.len() > SAVE_MENU_OPTION_COUNT {
+    PAUSE_OPTIONS.len()
+} else {
+    SAVE_MENU_OPTION_COUNT
+};
+
+fn current_option_count(mode: PauseMenuMode) -> usize {
+    match mode {
+        PauseMenuMode::Root => PAUSE_OPTIONS.len(),
+        PauseMenuMode::SaveSlots => SAVE_MENU_OPTION_COUNT,
+        PauseMenuMode::ConfirmOverwrite(_) => CONFIRM_OPTION_COUNT,
+        PauseMenuMode::ConfirmQuit(_) => CONFIRM_QUIT_OPTION_COUNT,
+    }
+}
+
+fn save_slot_label(slot: usize, info: Option<&SaveSlotInfo>, active_slot: u8) -> String {
+    let marker = if slot == active_slot as usize {
+        "  (current)"
+    } else {
+        ""
+    };
+    match info {
+        Some(info) if info.exists => format!(
+            "Slot {}  {}{}\nDay {} {:?} Y{}  {}g",
+            slot + 1,
+            info.farm_name,
+            marker,
+            info.day,
+            info.season,
+            info.year,
+            info.gold
+        ),
+        _ => format!("Slot {}  (Empty){}", slot + 1, marker),
+    }
+}
+
+fn pause_option_label(
+    mode: PauseMenuMode,
+    index: usize,
+    cache: Option<&SaveSlotInfoCache>,
+    active_slot: u8,
+    loc: &Localization,
+) -> Option<String> {
+    match mode {
+        PauseMenuMode::Root => PAUSE_OPTIONS.get(index).map(|key| loc.t(key).to_string()),
+        PauseMenuMode::SaveSlots => {
+            if index == SAVE_MENU_BACK_INDEX {
+                Some(loc.t("menu.back").to_string())
+            } else {
+                let info = cache.and_then(|c| c.slots.get(index));
+                Some(save_slot_label(index, info, active_slot))
+            }
+        }
+        PauseMenuMode::ConfirmOverwrite(slot) => match index {
+            0 => Some(format!("Overwrite Slot {}", slot + 1)),
+            1 => Some(loc.t("menu.cancel").to_string()),
+            _ => None,
+        },
+        PauseMenuMode::ConfirmQuit(_) => match index {
+            0 => Some(loc.t("pause.save_and_quit").to_string()),
+            1 => Some(loc.t("pause.quit_without_saving").to_string()),
+            2 => Some(loc.t("menu.cancel").to_string()),
+            _ => None,
+        },
+    }
+}
+
+/// Whether saving into `slot` would replace a different farm's save and so
+/// needs confirming first. Re-saving the slot you are playing from doesn't.
+fn needs_overwrite_confirm(slot: u8, active_slot: u8, cache: Option<&SaveSlotInfoCache>) -> bool {
+    slot != active_slot
+        && cache
+            .and_then(|c| c.slots.get(slot as usize))
+            .map(|info| info.exists)
+            .unwrap_or(false)
+}
 
 // ═══════════════════════════════════════════════════════════════════════
 // SPAWN / DESPAWN
@@ -32,10 +143,13 @@ pub fn spawn_pause_menu(
     font_handle: Res<UiFontHandle>,
     assets: Res<MenuAssets>,
     theme: Res<MenuTheme>,
+    loc: Res<Localization>,
 ) {
     commands.insert_resource(PauseMenuState {
+        mode: PauseMenuMode::Root,
         cursor: 0,
         status_message: String::new(),
+        quit_after_save: None,
     });
 
     let font = font_handle.0.clone();
@@ -71,10 +185,12 @@ pub fn spawn_pause_menu(
                 ))
                 .with_children(|panel| {
                     // Title
-                    menu_kit::spawn_menu_title(panel, "PAUSED", &theme, &font);
+                    menu_kit::spawn_menu_title(panel, loc.t("pause.title"), &theme, &font);
 
-                    // Menu items — atlas-backed buttons matching main menu
-                    for (i, label) in PAUSE_OPTIONS.iter().enumerate() {
+                    // Menu items — atlas-backed buttons matching main menu.
+                    // Labels are rewritten per mode by update_pause_menu_visuals.
+                    for i in 0..PAUSE_MENU_MAX_ITEMS {
+                        let label = PAUSE_OPTIONS.get(i).map_or("", |key| loc.t(key));
                         menu_kit::spawn_menu_button(panel, i, label, &assets, &theme, &font);
                     }
 
@@ -92,9 +208,7 @@ pub fn spawn_pause_menu(
 
                     // Controls reminder
                     panel.spawn((
-                        Text::new(
-                            "WASD: Move | F: Interact | Space: Use Tool | I: Inventory | C: Craft | Esc: Pause",
-                        ),
+                        Text::new(loc.t("pause.controls")),
                         TextFont {
                             font: font.clone(),
                             font_size: 11.0,
@@ -120,37 +234,64 @@ pub fn despawn_pause_menu(mut commands: Commands, query: Query<Entity, With<Paus
 
 pub fn update_pause_menu_visuals(
     state: Option<Res<PauseMenuState>>,
-    mut query: Query<(&MenuItem, &mut ImageNode)>,
-    mut status_query: Query<&mut Text, With<PauseMenuStatusText>>,
+    cache: Option<Res<SaveSlotInfoCache>>,
+    active_slot: Res<ActiveSaveSlot>,
+    loc: Res<Localization>,
+    mut query: Query<(&MenuItem, &mut ImageNode, &mut Node)>,
+    mut text_query: Query<(&MenuButtonText, &mut Text), Without<PauseMenuStatusText>>,
+    mut status_query: Query<&mut Text, (With<PauseMenuStatusText>, Without<MenuButtonText>)>,
 ) {
     let Some(state) = state else { return };
-    for (item, mut image_node) in &mut query {
+    let option_count = current_option_count(state.mode);
+    for (item, mut image_node, mut node) in &mut query {
+        node.display = if item.index < option_count {
+            Display::Flex
+        } else {
+            Display::None
+        };
         set_button_visual(&mut image_node, item.index == state.cursor);
     }
 
+    for (btn_text, mut text) in &mut text_query {
+        text.0 = pause_option_label(
+            state.mode,
+            btn_text.index,
+            cache.as_deref(),
+            active_slot.slot,
+            &loc,
+        )
+        .unwrap_or_default();
+    }
+
     let Ok(mut text) = status_query.get_single_mut() else {
         return;
     };
     text.0 = state.status_message.clone();
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn pause_menu_navigation(
     action: Res<MenuAction>,
     mut state: Option<ResMut<PauseMenuState>>,
+    cache: Option<Res<SaveSlotInfoCache>>,
     mut next_state: ResMut<NextState<GameState>>,
     active_slot: Res<ActiveSaveSlot>,
+    unsaved: Res<UnsavedChanges>,
     mut save_writer: EventWriter<SaveRequestEvent>,
+    mut title_writer: EventWriter<ReturnToTitleEvent>,
+    mut app_exit: EventWriter<AppExit>,
 ) {
     let Some(ref mut state) = state else { return };
+    let option_count = current_option_count(state.mode);
 
     // Pointer hover → set cursor
     if let Some(idx) = action.set_cursor {
-        if idx < PAUSE_OPTIONS.len() {
+        if idx < option_count {
             state.cursor = idx;
         }
     }
 
-    if action.move_down && state.cursor < PAUSE_OPTIONS.len() - 1 {
+    if action.move_down && state.cursor < option_count - 1 {
         state.cursor += 1;
     }
     if action.move_up && state.cursor > 0 {
@@ -158,40 +299,132 @@ pub fn pause_menu_navigation(
     }
 
     if action.activate {
-        match state.cursor {
-            0 => {
+        match (state.mode, state.cursor) {
+            (PauseMenuMode::Root, 0) => {
                 // Resume
                 next_state.set(GameState::Playing);
             }
-            1 => {
-                let slot = active_slot.slot;
-                state.status_message = format!("Securing Slot {}...", slot + 1);
-                save_writer.send(SaveRequestEvent { slot });
+            (PauseMenuMode::Root, 1) => {
+                state.mode = PauseMenuMode::SaveSlots;
+                state.cursor = active_slot.slot as usize;
+                state.status_message = "Choose a slot to save into.".to_string();
             }
-            2 => {
-                // Quit to menu
-                next_state.set(GameState::MainMenu);
+            (PauseMenuMode::Root, QUIT_TO_MENU_INDEX) => {
+                if unsaved.dirty {
+                    open_quit_confirm(state, QuitTarget::MainMenu);
+                } else {
+                    title_writer.send(ReturnToTitleEvent);
+                }
+            }
+            (PauseMenuMode::SaveSlots, SAVE_MENU_BACK_INDEX) => {
+                state.mode = PauseMenuMode::Root;
+                state.cursor = 1;
+                state.status_message.clear();
+            }
+            (PauseMenuMode::SaveSlots, index) => {
+                let slot = index as u8;
+                if needs_overwrite_confirm(slot, active_slot.slot, cache.as_deref()) {
+                    state.mode = PauseMenuMode::ConfirmOverwrite(slot);
+                    state.cursor = 1;
+                    state.status_message = format!("Slot {} already holds another farm.", slot + 1);
+                } else {
+                    send_save(state, &mut save_writer, slot);
+                }
+            }
+            (PauseMenuMode::ConfirmOverwrite(slot), 0) => {
+                send_save(state, &mut save_writer, slot);
+            }
+            (PauseMenuMode::ConfirmOverwrite(slot), _) => {
+                state.mode = PauseMenuMode::SaveSlots;
+                state.cursor = slot as usize;
+                state.status_message = "Choose a slot to save into.".to_string();
+            }
+            (PauseMenuMode::ConfirmQuit(target), 0) => {
+                send_save(state, &mut save_writer, active_slot.slot);
+                state.quit_after_save = Some(target);
+            }
+            (PauseMenuMode::ConfirmQuit(target), 1) => {
+                finish_quit(target, &mut title_writer, &mut app_exit);
+            }
+            (PauseMenuMode::ConfirmQuit(_), _) => {
+                close_quit_confirm(state);
             }
             _ => {}
         }
     }
 
-    // Escape also resumes
     if action.cancel {
-        next_state.set(GameState::Playing);
+        match state.mode {
+            // Escape also resumes
+            PauseMenuMode::Root => next_state.set(GameState::Playing),
+            PauseMenuMode::SaveSlots => {
+                state.mode = PauseMenuMode::Root;
+                state.cursor = 1;
+                state.status_message.clear();
+            }
+            PauseMenuMode::ConfirmOverwrite(slot) => {
+                state.mode = PauseMenuMode::SaveSlots;
+                state.cursor = slot as usize;
+            }
+            PauseMenuMode::ConfirmQuit(_) => close_quit_confirm(state),
+        }
     }
 }
 
+fn open_quit_confirm(state: &mut PauseMenuState, target: QuitTarget) {
+    state.mode = PauseMenuMode::ConfirmQuit(target);
+    state.cursor = 0;
+    state.status_message = "You have unsaved progress.".to_string();
+}
+
+fn close_quit_confirm(state: &mut PauseMenuState) {
+    state.mode = PauseMenuMode::Root;
+    state.cursor = QUIT_TO_MENU_INDEX;
+    state.status_message.clear();
+}
+
+fn finish_quit(
+    target: QuitTarget,
+    title_writer: &mut EventWriter<ReturnToTitleEvent>,
+    app_exit: &mut EventWriter<AppExit>,
+) {
+    match target {
+        QuitTarget::MainMenu => {
+            title_writer.send(ReturnToTitleEvent);
+        }
+        QuitTarget::Desktop => {
+            app_exit.send(AppExit::Success);
+        }
+    }
+}
+
+fn send_save(
+    state: &mut PauseMenuState,
+    save_writer: &mut EventWriter<SaveRequestEvent>,
+    slot: u8,
+) {
+    state.mode = PauseMenuMode::Root;
+    state.cursor = 1;
+    state.status_message = format!("Securing Slot {}...", slot + 1);
+    save_writer.send(SaveRequestEvent { slot });
+}
+
 pub fn handle_save_complete_in_pause_menu(
     mut complete_events: EventReader<SaveCompleteEvent>,
     mut state: Option<ResMut<PauseMenuState>>,
+    mut title_writer: EventWriter<ReturnToTitleEvent>,
+    mut app_exit: EventWriter<AppExit>,
 ) {
     let Some(ref mut state) = state else { return };
 
     for ev in complete_events.read() {
         if ev.success {
             state.status_message = format!("Progress secured in Slot {}.", ev.slot + 1);
+            if let Some(target) = state.quit_after_save.take() {
+                finish_quit(target, &mut title_writer, &mut app_exit);
+            }
         } else {
+            state.quit_after_save = None;
             state.status_message = ev
                 .error_message
                 .clone()
@@ -199,3 +432,171 @@ pub fn handle_save_complete_in_pause_menu(
         }
     }
 }
+
+/// Open the quit confirmation requested by a window close.
+pub fn open_pending_quit_confirm(
+    mut pending: ResMut<PendingQuitConfirm>,
+    mut state: Option<ResMut<PauseMenuState>>,
+) {
+    if !pending.0 {
+        return;
+    }
+    let Some(ref mut state) = state else { return };
+    pending.0 = false;
+    open_quit_confirm(state, QuitTarget::Desktop);
+}
+
+/// The window close button exits straight away unless there is unsaved
+/// progress, in which case the game pauses and asks first.
+pub fn handle_window_close_request(
+    mut close_events: EventReader<WindowCloseRequested>,
+    unsaved: Res<UnsavedChanges>,
+    game_state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut pending: ResMut<PendingQuitConfirm>,
+    menu: Option<Res<PauseMenuState>>,
+    mut app_exit: EventWriter<AppExit>,
+) {
+    if close_events.read().count() == 0 {
+        return;
+    }
+    let in_game = !matches!(game_state.get(), GameState::Loading | GameState::MainMenu);
+    // A second close while the prompt is already up quits for real.
+    let prompt_open = pending.0
+        || menu.is_some_and(|m| m.mode == PauseMenuMode::ConfirmQuit(QuitTarget::Desktop));
+    if in_game && unsaved.dirty && !prompt_open {
+        pending.0 = true;
+        if *game_state.get() != GameState::Paused {
+            next_state.set(GameState::Paused);
+        }
+    } else {
+        app_exit.send(AppExit::Success);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot_info(slot: u8, exists: bool) -> SaveSlotInfo {
+        SaveSlotInfo {
+            slot,
+            exists,
+            ..Default::default()
+        }
+    }
+
+    fn test_app(cache: SaveSlotInfoCache) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(bevy::state::app::StatesPlugin)
+            .init_state::<GameState>()
+            .add_event::<SaveRequestEvent>()
+            .add_event::<ReturnToTitleEvent>()
+            .init_resource::<MenuAction>()
+            .init_resource::<UnsavedChanges>()
+            .insert_resource(ActiveSaveSlot { slot: 0 })
+            .insert_resource(cache)
+            .insert_resource(PauseMenuState {
+                mode: PauseMenuMode::Root,
+                cursor: 0,
+                status_message: String::new(),
+                quit_after_save: None,
+            })
+            .add_systems(Update, pause_menu_navigation);
+        app
+    }
+
+    fn press(app: &mut App, action: MenuAction) {
+        app.insert_resource(action);
+        app.update();
+    }
+
+    fn activate_at(app: &mut App, index: usize) {
+        press(
+            app,
+            MenuAction {
+                set_cursor: Some(index),
+                activate: true,
+                ..Default::default()
+            },
+        );
+    }
+
+    fn sent_slots(app: &App) -> Vec<u8> {
+        let events = app.world().resource::<Events<SaveRequestEvent>>();
+        events
+            .iter_current_update_events()
+            .map(|e| e.slot)
+            .collect()
+    }
+
+    #[test]
+    fn test_selecting_slot_two_saves_to_slot_two() {
+        let cache = SaveSlotInfoCache {
+            slots: (0..NUM_SAVE_SLOTS as u8)
+                .map(|s| slot_info(s, s == 0))
+                .collect(),
+        };
+        let mut app = test_app(cache);
+
+        activate_at(&mut app, 1); // Save Game → slot picker
+        assert_eq!(
+            app.world().resource::<PauseMenuState>().mode,
+            PauseMenuMode::SaveSlots
+        );
+        assert!(sent_slots(&app).is_empty());
+
+        activate_at(&mut app, 2); // "Slot 3" row is index 2, i.e. save slot 2
+        assert_eq!(sent_slots(&app), vec![2]);
+    }
+
+    #[test]
+    fn test_occupied_slot_asks_before_overwriting() {
+        let cache = SaveSlotInfoCache {
+            slots: (0..NUM_SAVE_SLOTS as u8)
+                .map(|s| slot_info(s, true))
+                .collect(),
+        };
+        let mut app = test_app(cache);
+
+        activate_at(&mut app, 1);
+        activate_at(&mut app, 2);
+        assert_eq!(
+            app.world().resource::<PauseMenuState>().mode,
+            PauseMenuMode::ConfirmOverwrite(2)
+        );
+        assert!(sent_slots(&app).is_empty());
+
+        activate_at(&mut app, 0); // Overwrite
+        assert_eq!(sent_slots(&app), vec![2]);
+    }
+
+    #[test]
+    fn test_quitting_saved_game_returns_to_title() {
+        let mut app = test_app(SaveSlotInfoCache::default());
+
+        activate_at(&mut app, QUIT_TO_MENU_INDEX);
+        let events = app.world().resource::<Events<ReturnToTitleEvent>>();
+        assert_eq!(events.iter_current_update_events().count(), 1);
+    }
+
+    #[test]
+    fn test_quitting_with_unsaved_progress_offers_to_save() {
+        let mut app = test_app(SaveSlotInfoCache::default());
+        app.world_mut().resource_mut::<UnsavedChanges>().dirty = true;
+
+        activate_at(&mut app, QUIT_TO_MENU_INDEX);
+        assert_eq!(
+            app.world().resource::<PauseMenuState>().mode,
+            PauseMenuMode::ConfirmQuit(QuitTarget::MainMenu)
+        );
+
+        activate_at(&mut app, 0); // Save and Quit
+        assert_eq!(sent_slots(&app), vec![0]);
+        assert_eq!(
+            app.world().resource::<PauseMenuState>().quit_after_save,
+            Some(QuitTarget::MainMenu)
+        );
+    }
+}
@@ -0,0 +1,347 @@
+//! Quest board screen — browse today's posted quests and accept them.
+//!
+//! Activated by entering `GameState::QuestBoard` from the town bulletin board.
+//! Accepting a quest sends `QuestAcceptedEvent`; `handle_quest_accepted` moves
+//! it from `QuestLog.posted` into `QuestLog.active`.
+
+use super::UiFontHandle;
+use crate::shared::*;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// Number of quests shown at once; the list scrolls with the cursor.
+const VISIBLE_ROWS: usize = 6;
+
+// ═══════════════════════════════════════════════════════════════════════
+// LOCAL TYPES
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Marker component for the root UI node so we can despawn everything on exit.
+#[derive(Component)]
+pub struct QuestBoardScreenRoot;
+
+/// A quest line in the posted list. `row` is the on-screen slot, not the
+/// quest index.
+#[derive(Component)]
+pub struct QuestBoardRow {
+    pub row: usize,
+}
+
+/// Marker for the description of the selected quest.
+#[derive(Component)]
+pub struct QuestBoardBodyText;
+
+/// Marker for the status feedback text at the bottom.
+#[derive(Component)]
+pub struct QuestBoardStatusText;
+
+/// UI-local resource tracking the cursor and feedback message.
+#[derive(Resource, Default)]
+pub struct QuestBoardScreenState {
+    cursor: usize,
+    status_message: String,
+    status_timer: f32,
+}
+
+fn reward_line(quest: &Quest, item_registry: &ItemRegistry) -> String {
+    let mut parts = vec![format!("{}g", quest.reward_gold)];
+    for (item_id, qty) in &quest.reward_items {
+        let name = item_registry
+            .get(item_id)
+            .map(|def| def.name.as_str())
+            .unwrap_or(item_id.as_str());
+        parts.push(format!("{} x{}", name, qty));
+    }
+    parts.join(", ")
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// SPAWN / DESPAWN
+// ═══════════════════════════════════════════════════════════════════════
+
+pub fn spawn_quest_board_screen(
+    mut commands: Commands,
+    font_handle: Res<UiFontHandle>,
+    theme: Res<MenuTheme>,
+) {
+    commands.insert_resource(QuestBoardScreenState::default());
+
+    commands
+        .spawn((
+            QuestBoardScreenRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(theme.bg_overlay),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(560.0),
+                        min_height: Val::Px(380.0),
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(16.0)),
+                        row_gap: Val::Px(6.0),
+                        border: UiRect::all(Val::Px(theme.panel_border_width)),
+                        overflow: Overflow::clip(),
+                        ..default()
+                    },
+                    BackgroundColor(theme.panel_bg),
+                    BorderColor(theme.panel_border),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new("QUEST BOARD"),
+                        TextFont {
+                            font: font_handle.0.clone(),
+                            font_size: theme.title_font_size,
+                            ..default()
+                        },
+                        TextColor(theme.text_color_selected),
+                    ));
+
+                    for row in 0..VISIBLE_ROWS {
+                        panel
+                            .spawn((
+                                QuestBoardRow { row },
+                                Node {
+                                    width: Val::Percent(100.0),
+                                    height: Val::Px(26.0),
+                                    align_items: AlignItems::Center,
+                                    padding: UiRect::horizontal(Val::Px(8.0)),
+                                    border: UiRect::all(Val::Px(1.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(theme.button_bg_normal),
+                                BorderColor(theme.button_border_normal),
+                            ))
+                            .with_children(|r| {
+                                r.spawn((
+                                    Text::new(""),
+                                    TextFont {
+                                        font: font_handle.0.clone(),
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(theme.text_color),
+                                ));
+                            });
+                    }
+
+                    panel.spawn((
+                        QuestBoardBodyText,
+                        Text::new(""),
+                        TextFont {
+                            font: font_handle.0.clone(),
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(theme.text_color),
+                        Node {
+                            min_height: Val::Px(90.0),
+                            margin: UiRect::top(Val::Px(8.0)),
+                            ..default()
+                        },
+                    ));
+
+                    panel.spawn((
+                        QuestBoardStatusText,
+                        Text::new(""),
+                        TextFont {
+                            font: font_handle.0.clone(),
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.6, 0.4)),
+                    ));
+
+                    panel.spawn((
+                        Text::new("Up/Down: Select | Enter: Accept | Esc: Close"),
+                        TextFont {
+                            font: font_handle.0.clone(),
+                            font_size: theme.hint_font_size,
+                            ..default()
+                        },
+                        TextColor(theme.text_color_disabled),
+                    ));
+                });
+        });
+}
+
+pub fn despawn_quest_board_screen(
+    mut commands: Commands,
+    query: Query<Entity, With<QuestBoardScreenRoot>>,
+) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<QuestBoardScreenState>();
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// NAVIGATION + INPUT
+// ═══════════════════════════════════════════════════════════════════════
+
+pub fn quest_board_navigation(
+    action: Res<MenuAction>,
+    mut ui_state: Option<ResMut<QuestBoardScreenState>>,
+    quest_log: Res<QuestLog>,
+    mut accepted_events: EventWriter<QuestAcceptedEvent>,
+    mut sfx_writer: EventWriter<PlaySfxEvent>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Some(ref mut ui_state) = ui_state else {
+        return;
+    };
+
+    if action.cancel {
+        next_state.set(GameState::Playing);
+        return;
+    }
+
+    // The last accepted quest leaves the board empty.
+    let count = quest_log.posted.len();
+    if count == 0 {
+        next_state.set(GameState::Playing);
+        return;
+    }
+    ui_state.cursor = ui_state.cursor.min(count - 1);
+
+    if action.move_down && ui_state.cursor + 1 < count {
+        ui_state.cursor += 1;
+    }
+    if action.move_up && ui_state.cursor > 0 {
+        ui_state.cursor -= 1;
+    }
+
+    if action.activate {
+        if quest_log.active.len() >= MAX_ACTIVE_QUESTS {
+            ui_state.status_message =
+                format!("You already have {} quests in progress.", MAX_ACTIVE_QUESTS);
+            ui_state.status_timer = 2.0;
+            return;
+        }
+        accepted_events.send(QuestAcceptedEvent {
+            quest_id: quest_log.posted[ui_state.cursor].id.clone(),
+        });
+        sfx_writer.send(PlaySfxEvent {
+            sfx_id: "pickup".to_string(),
+        });
+    }
+}
+
+/// The posted quests and the item names their rewards are described with.
+#[derive(SystemParam)]
+pub struct QuestBoardListings<'w> {
+    quest_log: Res<'w, QuestLog>,
+    item_registry: Res<'w, ItemRegistry>,
+}
+
+/// Refreshes the quest list, cursor highlight, and selected quest details.
+#[allow(clippy::type_complexity)]
+pub fn update_quest_board_display(
+    ui_state: Option<Res<QuestBoardScreenState>>,
+    listings: QuestBoardListings,
+    theme: Res<MenuTheme>,
+    mut row_query: Query<(
+        &QuestBoardRow,
+        &mut BackgroundColor,
+        &mut BorderColor,
+        &Children,
+    )>,
+    mut text_query: Query<
+        (&mut Text, &mut TextColor),
+        (Without<QuestBoardBodyText>, Without<QuestBoardStatusText>),
+    >,
+    mut body_query: Query<&mut Text, (With<QuestBoardBodyText>, Without<QuestBoardStatusText>)>,
+    mut status_query: Query<&mut Text, (With<QuestBoardStatusText>, Without<QuestBoardBodyText>)>,
+) {
+    let Some(ui_state) = ui_state else { return };
+    let quest_log = &listings.quest_log;
+    let item_registry = &listings.item_registry;
+
+    let offset = (ui_state.cursor + 1).saturating_sub(VISIBLE_ROWS);
+    for (board_row, mut bg, mut border, children) in &mut row_query {
+        let index = offset + board_row.row;
+        let selected = index == ui_state.cursor;
+        let label = quest_log
+            .posted
+            .get(index)
+            .map(|quest| {
+                format!(
+                    "{} ({}) - {}",
+                    quest.title,
+                    quest.giver,
+                    reward_line(quest, item_registry)
+                )
+            })
+            .unwrap_or_default();
+
+        if index < quest_log.posted.len() {
+            if selected {
+                *bg = BackgroundColor(theme.button_bg_selected);
+                *border = BorderColor(theme.button_border_selected);
+            } else {
+                *bg = BackgroundColor(theme.button_bg_normal);
+                *border = BorderColor(theme.button_border_normal);
+            }
+        } else {
+            *bg = BackgroundColor(Color::NONE);
+            *border = BorderColor(Color::NONE);
+        }
+        for &child in children.iter() {
+            if let Ok((mut text, mut color)) = text_query.get_mut(child) {
+                **text = label.clone();
+                color.0 = if selected {
+                    theme.text_color_selected
+                } else {
+                    theme.text_color
+                };
+            }
+        }
+    }
+
+    for mut text in &mut body_query {
+        **text = quest_log
+            .posted
+            .get(ui_state.cursor)
+            .map(|quest| {
+                let deadline = match quest.days_remaining {
+                    Some(days) => format!("\nDue in {} days.", days),
+                    None => String::new(),
+                };
+                format!(
+                    "{}\n\nReward: {}{}",
+                    quest.description,
+                    reward_line(quest, item_registry),
+                    deadline
+                )
+            })
+            .unwrap_or_default();
+    }
+
+    for mut text in &mut status_query {
+        **text = ui_state.status_message.clone();
+    }
+}
+
+/// Tick down the status message timer.
+pub fn quest_board_status_timer(
+    time: Res<Time>,
+    mut ui_state: Option<ResMut<QuestBoardScreenState>>,
+) {
+    let Some(ref mut ui_state) = ui_state else {
+        return;
+    };
+    if ui_state.status_timer > 0.0 {
+        ui_state.status_timer -= time.delta_secs();
+        if ui_state.status_timer <= 0.0 {
+            ui_state.status_message.clear();
+        }
+    }
+}
@@ -42,6 +42,8 @@ pub fn spawn_relationships_screen(
     font_handle: Res<UiFontHandle>,
     npc_registry: Res<NpcRegistry>,
     relationships: Res<Relationships>,
+    gift_history: Res<GiftHistory>,
+    item_registry: Res<ItemRegistry>,
 ) {
     let font = font_handle.0.clone();
 
@@ -196,6 +198,8 @@ pub fn spawn_relationships_screen(
                                 first_id,
                                 &npc_registry,
                                 &relationships,
+                                &gift_history,
+                                &item_registry,
                                 &font,
                             );
                         });
@@ -217,12 +221,15 @@ pub fn despawn_relationships_screen(
 // UPDATE SYSTEMS
 // ═══════════════════════════════════════════════════════════════════════
 
+#[allow(clippy::too_many_arguments)]
 pub fn relationships_navigation(
     action: Res<MenuAction>,
     mut commands: Commands,
     mut ui_state: Option<ResMut<RelationshipsUiState>>,
     npc_registry: Res<NpcRegistry>,
     relationships: Res<Relationships>,
+    gift_history: Res<GiftHistory>,
+    item_registry: Res<ItemRegistry>,
     font_handle: Res<UiFontHandle>,
     detail_query: Query<Entity, With<RelDetailPanel>>,
 ) {
@@ -250,7 +257,15 @@ pub fn relationships_navigation(
         for entity in &detail_query {
             commands.entity(entity).despawn_descendants();
             commands.entity(entity).with_children(|detail| {
-                spawn_detail_children(detail, selected_id, &npc_registry, &relationships, &font);
+                spawn_detail_children(
+                    detail,
+                    selected_id,
+                    &npc_registry,
+                    &relationships,
+                    &gift_history,
+                    &item_registry,
+                    &font,
+                );
             });
         }
     }
@@ -287,6 +302,8 @@ fn spawn_detail_children(
     npc_id: Option<&NpcId>,
     npc_registry: &NpcRegistry,
     relationships: &Relationships,
+    gift_history: &GiftHistory,
+    item_registry: &ItemRegistry,
     font: &Handle<Font>,
 ) {
     let Some(id) = npc_id else {
@@ -366,6 +383,30 @@ fn spawn_detail_children(
         ));
     }
 
+    // Gifts already given this week
+    let mut given: Vec<String> = gift_history
+        .items_for(id)
+        .into_iter()
+        .map(|item_id| {
+            item_registry
+                .get(&item_id)
+                .map(|d| d.name.clone())
+                .unwrap_or(item_id)
+        })
+        .collect();
+    given.sort();
+    if !given.is_empty() {
+        parent.spawn((
+            Text::new(format!("Gifted this week: {}", given.join(", "))),
+            TextFont {
+                font: font.clone(),
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.8, 1.0)),
+        ));
+    }
+
     // Marriageable
     if def.is_marriageable {
         parent.spawn((
@@ -1,6 +1,9 @@
+use super::localization::{language_name, next_language, Localization};
+use super::shipping_log_screen::ShippingLogOverlayState;
 use super::UiFontHandle;
 use crate::shared::*;
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
 
 // ═══════════════════════════════════════════════════════════════════════
 // MARKER COMPONENTS
@@ -12,6 +15,48 @@ pub struct SettingsScreenRoot;
 #[derive(Component)]
 pub struct VolumeValueText;
 
+#[derive(Component)]
+pub struct FishingAssistValueText;
+
+#[derive(Component)]
+pub struct UiScaleValueText;
+
+#[derive(Component)]
+pub struct ResolutionValueText;
+
+#[derive(Component)]
+pub struct HarvestCountdownValueText;
+
+#[derive(Component)]
+pub struct ParticleDensityValueText;
+
+#[derive(Component)]
+pub struct GiftsPerDayValueText;
+
+#[derive(Component)]
+pub struct RepeatGiftFatigueValueText;
+
+#[derive(Component)]
+pub struct CreativeToolsValueText;
+
+#[derive(Component)]
+pub struct PeacefulValueText;
+
+#[derive(Component)]
+pub struct OffSeasonGraceValueText;
+
+#[derive(Component)]
+pub struct LanguageValueText;
+
+#[derive(Component)]
+pub struct CameraZoomValueText;
+
+#[derive(Component)]
+pub struct KeybindingProfileValueText;
+
+/// Largest daily gift limit the settings screen offers.
+const MAX_GIFTS_PER_DAY: u8 = 3;
+
 /// Tracks overlay visibility (toggled by F4 during Playing).
 #[derive(Resource, Default)]
 pub struct SettingsOverlayState {
@@ -34,12 +79,33 @@ impl Default for AudioVolume {
 // TOGGLE
 // ═══════════════════════════════════════════════════════════════════════
 
+/// F4 toggles the overlay; opening it closes the shipping collection log.
 pub fn toggle_settings_overlay(
     keys: Res<ButtonInput<KeyCode>>,
     mut overlay: ResMut<SettingsOverlayState>,
+    mut shipping_log: ResMut<ShippingLogOverlayState>,
 ) {
     if keys.just_pressed(KeyCode::F4) {
         overlay.visible = !overlay.visible;
+        if overlay.visible && shipping_log.visible {
+            shipping_log.visible = false;
+        }
+    }
+}
+
+/// Hold an `InputBlocks` entry while the overlay is open, so its letter
+/// hotkeys (R, G, T, ...) don't also fire the gameplay actions bound to them.
+pub fn settings_block_gameplay_input(
+    overlay: Res<SettingsOverlayState>,
+    mut input_blocks: ResMut<InputBlocks>,
+) {
+    if !overlay.is_changed() {
+        return;
+    }
+    if overlay.visible {
+        input_blocks.block::<SettingsOverlayState>();
+    } else {
+        input_blocks.unblock::<SettingsOverlayState>();
     }
 }
 
@@ -47,18 +113,28 @@ pub fn toggle_settings_overlay(
 // LIFECYCLE — reactive spawn/despawn based on SettingsOverlayState
 // ═══════════════════════════════════════════════════════════════════════
 
+#[allow(clippy::too_many_arguments)]
 pub fn update_settings_lifecycle(
     mut commands: Commands,
     overlay: Res<SettingsOverlayState>,
     font_handle: Res<UiFontHandle>,
     volume: Res<AudioVolume>,
+    settings: Res<GameSettings>,
     bindings: Res<KeyBindings>,
+    loc: Res<Localization>,
     existing: Query<Entity, With<SettingsScreenRoot>>,
 ) {
     let ui_exists = !existing.is_empty();
 
     if overlay.visible && !ui_exists {
-        spawn_settings_screen(&mut commands, &font_handle, &volume, &bindings);
+        spawn_settings_screen(
+            &mut commands,
+            &font_handle,
+            &volume,
+            &settings,
+            &bindings,
+            &loc,
+        );
     } else if !overlay.visible && ui_exists {
         for entity in &existing {
             commands.entity(entity).despawn_recursive();
@@ -74,7 +150,9 @@ fn spawn_settings_screen(
     commands: &mut Commands,
     font_handle: &UiFontHandle,
     volume: &AudioVolume,
+    settings: &GameSettings,
     bindings: &KeyBindings,
+    loc: &Localization,
 ) {
     let font = font_handle.0.clone();
 
@@ -85,6 +163,9 @@ fn spawn_settings_screen(
         ("Move Right", format!("{:?}", bindings.move_right)),
         ("Interact", format!("{:?}", bindings.interact)),
         ("Tool Use", format!("{:?}", bindings.tool_use)),
+        ("Quick Water", format!("{:?}", bindings.quick_water)),
+        ("Run", format!("{:?}", bindings.run)),
+        ("Wrap Gift", format!("{:?}", bindings.wrap_gift)),
         ("Inventory", format!("{:?}", bindings.open_inventory)),
         ("Crafting", format!("{:?}", bindings.open_crafting)),
         ("Map", format!("{:?}", bindings.open_map)),
@@ -129,7 +210,7 @@ fn spawn_settings_screen(
                 .with_children(|panel| {
                     // Title
                     panel.spawn((
-                        Text::new("SETTINGS"),
+                        Text::new(loc.t("settings.title")),
                         TextFont {
                             font: font.clone(),
                             font_size: 22.0,
@@ -140,7 +221,9 @@ fn spawn_settings_screen(
 
                     // Hint
                     panel.spawn((
-                        Text::new("F4 / Esc: Close | Left/Right: Volume"),
+                        Text::new(
+                            "F4 / Esc: Close | Left/Right: Volume | Up/Down: Fishing Assist | -/=: UI Scale | PgUp/PgDn: Resolution",
+                        ),
                         TextFont {
                             font: font.clone(),
                             font_size: 11.0,
@@ -161,7 +244,7 @@ fn spawn_settings_screen(
 
                     // ─── Audio volume section ───
                     panel.spawn((
-                        Text::new("AUDIO"),
+                        Text::new(loc.t("settings.audio")),
                         TextFont {
                             font: font.clone(),
                             font_size: 16.0,
@@ -181,7 +264,7 @@ fn spawn_settings_screen(
                         })
                         .with_children(|row| {
                             row.spawn((
-                                Text::new("Volume"),
+                                Text::new(loc.t("settings.volume")),
                                 TextFont {
                                     font: font.clone(),
                                     font_size: 14.0,
@@ -213,9 +296,9 @@ fn spawn_settings_screen(
                         BackgroundColor(Color::srgb(0.5, 0.4, 0.4)),
                     ));
 
-                    // ─── Keybinds section ───
+                    // ─── Accessibility section ───
                     panel.spawn((
-                        Text::new("KEYBINDS"),
+                        Text::new(loc.t("settings.accessibility")),
                         TextFont {
                             font: font.clone(),
                             font_size: 16.0,
@@ -224,88 +307,1005 @@ fn spawn_settings_screen(
                         TextColor(Color::srgb(1.0, 0.85, 0.7)),
                     ));
 
-                    for (action_name, key_name) in &keybind_rows {
+                    panel
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            row.spawn((
+                                Text::new(loc.t("settings.fishing_assist")),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                            ));
+                            row.spawn((
+                                FishingAssistValueText,
+                                Text::new(settings.fishing_assist.label()),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.9, 0.5)),
+                            ));
+                        });
+
+                    // Divider
+                    panel.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Px(2.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.5, 0.4, 0.4)),
+                    ));
+
+                    // ─── Display section ───
+                    panel.spawn((
+                        Text::new(loc.t("settings.display")),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(1.0, 0.85, 0.7)),
+                    ));
+
+                    let display_rows = [
+                        ("UI Scale", ui_scale_label(settings.ui_scale), true),
+                        ("Resolution", resolution_label(settings.resolution), false),
+                    ];
+                    for (label, value, is_scale) in display_rows {
                         panel
                             .spawn(Node {
                                 width: Val::Percent(100.0),
                                 flex_direction: FlexDirection::Row,
                                 justify_content: JustifyContent::SpaceBetween,
-                                padding: UiRect::axes(Val::Px(12.0), Val::Px(3.0)),
+                                align_items: AlignItems::Center,
+                                padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
                                 ..default()
                             })
                             .with_children(|row| {
                                 row.spawn((
-                                    Text::new(*action_name),
+                                    Text::new(label),
                                     TextFont {
                                         font: font.clone(),
-                                        font_size: 12.0,
+                                        font_size: 14.0,
                                         ..default()
                                     },
-                                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                                    TextColor(Color::srgb(0.85, 0.85, 0.85)),
                                 ));
-                                row.spawn((
-                                    Text::new(key_name.clone()),
+                                let mut value_text = row.spawn((
+                                    Text::new(value),
                                     TextFont {
                                         font: font.clone(),
-                                        font_size: 12.0,
+                                        font_size: 14.0,
                                         ..default()
                                     },
-                                    TextColor(Color::srgb(0.6, 0.8, 1.0)),
+                                    TextColor(Color::srgb(1.0, 0.9, 0.5)),
                                 ));
+                                if is_scale {
+                                    value_text.insert(UiScaleValueText);
+                                } else {
+                                    value_text.insert(ResolutionValueText);
+                                }
                             });
                     }
-                });
-        });
-}
-
-// ═══════════════════════════════════════════════════════════════════════
-// VOLUME ADJUSTMENT
-// ═══════════════════════════════════════════════════════════════════════
-
-pub fn settings_volume_input(
-    keys: Res<ButtonInput<KeyCode>>,
-    overlay: Res<SettingsOverlayState>,
-    mut volume: ResMut<AudioVolume>,
-    mut text_query: Query<&mut Text, With<VolumeValueText>>,
-) {
-    if !overlay.visible {
-        return;
-    }
 
-    let mut changed = false;
-    if keys.just_pressed(KeyCode::ArrowRight) && volume.level < 100 {
-        volume.level = (volume.level + 10).min(100);
-        changed = true;
-    }
-    if keys.just_pressed(KeyCode::ArrowLeft) && volume.level > 0 {
-        volume.level = volume.level.saturating_sub(10);
-        changed = true;
-    }
+                    panel
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            row.spawn((
+                                Text::new(loc.t("settings.harvest_countdown")),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                            ));
+                            row.spawn((
+                                HarvestCountdownValueText,
+                                Text::new(on_off_label(settings.show_harvest_countdown)),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.9, 0.5)),
+                            ));
+                        });
 
-    if changed {
-        let bar = build_volume_bar(volume.level);
-        for mut text in &mut text_query {
-            **text = bar.clone();
-        }
-    }
-}
+                    panel
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            row.spawn((
+                                Text::new(loc.t("settings.weather_particles")),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                            ));
+                            row.spawn((
+                                ParticleDensityValueText,
+                                Text::new(particle_density_label(settings.particle_density)),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.9, 0.5)),
+                            ));
+                        });
 
-/// Close overlay on Escape as well.
-pub fn settings_close_on_escape(
-    keys: Res<ButtonInput<KeyCode>>,
-    mut overlay: ResMut<SettingsOverlayState>,
-) {
-    if overlay.visible && keys.just_pressed(KeyCode::Escape) {
-        overlay.visible = false;
-    }
-}
+                    panel
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            row.spawn((
+                                Text::new(loc.t("settings.gifts_per_day")),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                            ));
+                            row.spawn((
+                                GiftsPerDayValueText,
+                                Text::new(settings.gifts_per_day.to_string()),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.9, 0.5)),
+                            ));
+                        });
 
-// ═══════════════════════════════════════════════════════════════════════
-// HELPERS
-// ═══════════════════════════════════════════════════════════════════════
+                    panel
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            row.spawn((
+                                Text::new(loc.t("settings.repeat_gift_fatigue")),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                            ));
+                            row.spawn((
+                                RepeatGiftFatigueValueText,
+                                Text::new(on_off_label(settings.repeat_gift_fatigue)),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.9, 0.5)),
+                            ));
+                        });
 
-fn build_volume_bar(level: u8) -> String {
-    let filled = (level / 10) as usize;
-    let empty = 10usize.saturating_sub(filled);
-    format!("[{}{}] {}%", "|".repeat(filled), "-".repeat(empty), level)
+                    panel
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            row.spawn((
+                                Text::new(loc.t("settings.creative_tools")),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                            ));
+                            row.spawn((
+                                CreativeToolsValueText,
+                                Text::new(on_off_label(settings.creative_tools)),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.9, 0.5)),
+                            ));
+                        });
+
+                    panel
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            row.spawn((
+                                Text::new(loc.t("settings.peaceful")),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                            ));
+                            row.spawn((
+                                PeacefulValueText,
+                                Text::new(on_off_label(settings.peaceful)),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.9, 0.5)),
+                            ));
+                        });
+
+                    panel
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            row.spawn((
+                                Text::new(loc.t("settings.off_season_grace")),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                            ));
+                            row.spawn((
+                                OffSeasonGraceValueText,
+                                Text::new(on_off_label(settings.off_season_grace)),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.9, 0.5)),
+                            ));
+                        });
+
+                    panel
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            row.spawn((
+                                Text::new(loc.t("settings.language")),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                            ));
+                            row.spawn((
+                                LanguageValueText,
+                                Text::new(language_name(&settings.language)),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.9, 0.5)),
+                            ));
+                        });
+
+                    panel
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            row.spawn((
+                                Text::new(loc.t("settings.camera_zoom")),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                            ));
+                            row.spawn((
+                                CameraZoomValueText,
+                                Text::new(camera_zoom_label(settings.camera_zoom)),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.9, 0.5)),
+                            ));
+                        });
+
+                    // Divider
+                    panel.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Px(2.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.5, 0.4, 0.4)),
+                    ));
+
+                    // ─── Keybinds section ───
+                    panel.spawn((
+                        Text::new(loc.t("settings.keybinds")),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(1.0, 0.85, 0.7)),
+                    ));
+
+                    panel
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            row.spawn((
+                                Text::new(loc.t("settings.keybinding_profile")),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                            ));
+                            row.spawn((
+                                KeybindingProfileValueText,
+                                Text::new(settings.keybinding_profile.label()),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.9, 0.5)),
+                            ));
+                        });
+
+                    for (action_name, key_name) in &keybind_rows {
+                        panel
+                            .spawn(Node {
+                                width: Val::Percent(100.0),
+                                flex_direction: FlexDirection::Row,
+                                justify_content: JustifyContent::SpaceBetween,
+                                padding: UiRect::axes(Val::Px(12.0), Val::Px(3.0)),
+                                ..default()
+                            })
+                            .with_children(|row| {
+                                row.spawn((
+                                    Text::new(*action_name),
+                                    TextFont {
+                                        font: font.clone(),
+                                        font_size: 12.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                                ));
+                                row.spawn((
+                                    Text::new(key_name.clone()),
+                                    TextFont {
+                                        font: font.clone(),
+                                        font_size: 12.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.6, 0.8, 1.0)),
+                                ));
+                            });
+                    }
+                });
+        });
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// VOLUME ADJUSTMENT
+// ═══════════════════════════════════════════════════════════════════════
+
+pub fn settings_volume_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    overlay: Res<SettingsOverlayState>,
+    mut volume: ResMut<AudioVolume>,
+    mut text_query: Query<&mut Text, With<VolumeValueText>>,
+) {
+    if !overlay.visible {
+        return;
+    }
+
+    let mut changed = false;
+    if keys.just_pressed(KeyCode::ArrowRight) && volume.level < 100 {
+        volume.level = (volume.level + 10).min(100);
+        changed = true;
+    }
+    if keys.just_pressed(KeyCode::ArrowLeft) && volume.level > 0 {
+        volume.level = volume.level.saturating_sub(10);
+        changed = true;
+    }
+
+    if changed {
+        let bar = build_volume_bar(volume.level);
+        for mut text in &mut text_query {
+            **text = bar.clone();
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// FISHING ASSIST
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Cycle the fishing assist level with Up/Down while the overlay is open.
+pub fn settings_fishing_assist_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    overlay: Res<SettingsOverlayState>,
+    mut settings: ResMut<GameSettings>,
+    mut text_query: Query<&mut Text, With<FishingAssistValueText>>,
+) {
+    if !overlay.visible {
+        return;
+    }
+
+    let steps = match (
+        keys.just_pressed(KeyCode::ArrowDown),
+        keys.just_pressed(KeyCode::ArrowUp),
+    ) {
+        (true, false) => 1,
+        // Stepping forward twice walks the three-level cycle backwards.
+        (false, true) => 2,
+        _ => return,
+    };
+    for _ in 0..steps {
+        settings.fishing_assist = settings.fishing_assist.next();
+    }
+
+    for mut text in &mut text_query {
+        **text = settings.fishing_assist.label().to_string();
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// DISPLAY
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Adjust UI scale with -/= and cycle the window resolution with
+/// PageUp/PageDown while the overlay is open.
+pub fn settings_display_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    overlay: Res<SettingsOverlayState>,
+    mut settings: ResMut<GameSettings>,
+    mut scale_text: Query<&mut Text, (With<UiScaleValueText>, Without<ResolutionValueText>)>,
+    mut resolution_text: Query<&mut Text, (With<ResolutionValueText>, Without<UiScaleValueText>)>,
+) {
+    if !overlay.visible {
+        return;
+    }
+
+    if keys.just_pressed(KeyCode::Equal) {
+        settings.step_ui_scale(true);
+    } else if keys.just_pressed(KeyCode::Minus) {
+        settings.step_ui_scale(false);
+    } else if keys.just_pressed(KeyCode::PageUp) || keys.just_pressed(KeyCode::PageDown) {
+        settings.next_resolution();
+    } else {
+        return;
+    }
+
+    for mut text in &mut scale_text {
+        **text = ui_scale_label(settings.ui_scale);
+    }
+    for mut text in &mut resolution_text {
+        **text = resolution_label(settings.resolution);
+    }
+}
+
+/// Toggle the crop harvest countdown overlay with H while the overlay is open.
+pub fn settings_harvest_countdown_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    overlay: Res<SettingsOverlayState>,
+    mut settings: ResMut<GameSettings>,
+    mut text_query: Query<&mut Text, With<HarvestCountdownValueText>>,
+) {
+    if !overlay.visible || !keys.just_pressed(KeyCode::KeyH) {
+        return;
+    }
+
+    settings.show_harvest_countdown = !settings.show_harvest_countdown;
+    for mut text in &mut text_query {
+        **text = on_off_label(settings.show_harvest_countdown).to_string();
+    }
+}
+
+/// Cycle weather particle density with P while the overlay is open.
+pub fn settings_particle_density_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    overlay: Res<SettingsOverlayState>,
+    mut settings: ResMut<GameSettings>,
+    mut text_query: Query<&mut Text, With<ParticleDensityValueText>>,
+) {
+    if !overlay.visible || !keys.just_pressed(KeyCode::KeyP) {
+        return;
+    }
+
+    settings.particle_density = next_particle_density(settings.particle_density);
+    for mut text in &mut text_query {
+        **text = particle_density_label(settings.particle_density);
+    }
+}
+
+/// Cycle the per-villager daily gift limit (1 to 3) with G while the overlay
+/// is open.
+pub fn settings_gifts_per_day_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    overlay: Res<SettingsOverlayState>,
+    mut settings: ResMut<GameSettings>,
+    mut text_query: Query<&mut Text, With<GiftsPerDayValueText>>,
+) {
+    if !overlay.visible || !keys.just_pressed(KeyCode::KeyG) {
+        return;
+    }
+
+    settings.gifts_per_day = settings.gifts_per_day % MAX_GIFTS_PER_DAY + 1;
+    for mut text in &mut text_query {
+        **text = settings.gifts_per_day.to_string();
+    }
+}
+
+/// Toggle the repeat-gift penalty with R while the overlay is open.
+pub fn settings_repeat_gift_fatigue_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    overlay: Res<SettingsOverlayState>,
+    mut settings: ResMut<GameSettings>,
+    mut text_query: Query<&mut Text, With<RepeatGiftFatigueValueText>>,
+) {
+    if !overlay.visible || !keys.just_pressed(KeyCode::KeyR) {
+        return;
+    }
+
+    settings.repeat_gift_fatigue = !settings.repeat_gift_fatigue;
+    for mut text in &mut text_query {
+        **text = on_off_label(settings.repeat_gift_fatigue).to_string();
+    }
+}
+
+/// Toggle creative tools (free tool use) with T while the overlay is open.
+pub fn settings_creative_tools_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    overlay: Res<SettingsOverlayState>,
+    mut settings: ResMut<GameSettings>,
+    mut text_query: Query<&mut Text, With<CreativeToolsValueText>>,
+) {
+    if !overlay.visible || !keys.just_pressed(KeyCode::KeyT) {
+        return;
+    }
+
+    settings.creative_tools = !settings.creative_tools;
+    for mut text in &mut text_query {
+        **text = on_off_label(settings.creative_tools).to_string();
+    }
+}
+
+/// Toggle peaceful mode (no collapse, knockout penalties or crop death)
+/// with N while the overlay is open.
+pub fn settings_peaceful_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    overlay: Res<SettingsOverlayState>,
+    mut settings: ResMut<GameSettings>,
+    mut text_query: Query<&mut Text, With<PeacefulValueText>>,
+) {
+    if !overlay.visible || !keys.just_pressed(KeyCode::KeyN) {
+        return;
+    }
+
+    settings.peaceful = !settings.peaceful;
+    for mut text in &mut text_query {
+        **text = on_off_label(settings.peaceful).to_string();
+    }
+}
+
+/// Toggle the one-day off-season grace for hardy crops with O while the
+/// overlay is open.
+pub fn settings_off_season_grace_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    overlay: Res<SettingsOverlayState>,
+    mut settings: ResMut<GameSettings>,
+    mut text_query: Query<&mut Text, With<OffSeasonGraceValueText>>,
+) {
+    if !overlay.visible || !keys.just_pressed(KeyCode::KeyO) {
+        return;
+    }
+
+    settings.off_season_grace = !settings.off_season_grace;
+    for mut text in &mut text_query {
+        **text = on_off_label(settings.off_season_grace).to_string();
+    }
+}
+
+/// Cycle the UI language with L while the overlay is open. The overlay is
+/// rebuilt so its own labels switch language straight away.
+pub fn settings_language_input(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    overlay: Res<SettingsOverlayState>,
+    mut settings: ResMut<GameSettings>,
+    mut loc: ResMut<Localization>,
+    existing: Query<Entity, With<SettingsScreenRoot>>,
+) {
+    if !overlay.visible || !keys.just_pressed(KeyCode::KeyL) {
+        return;
+    }
+
+    settings.language = next_language(&settings.language).to_string();
+    *loc = Localization::load(&settings.language);
+    for entity in &existing {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Cycle the keybinding profile with K while the overlay is open. The
+/// overlay is rebuilt so the keybind list shows the new bindings, which
+/// `apply_keybinding_profile` writes this same frame.
+pub fn settings_keybinding_profile_input(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    overlay: Res<SettingsOverlayState>,
+    mut settings: ResMut<GameSettings>,
+    existing: Query<Entity, With<SettingsScreenRoot>>,
+) {
+    if !overlay.visible || !keys.just_pressed(KeyCode::KeyK) {
+        return;
+    }
+
+    settings.keybinding_profile = settings.keybinding_profile.next();
+    for entity in &existing {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Keep the camera zoom row current. Z is read by the camera itself, so it
+/// works with or without the overlay open; this only refreshes the label.
+pub fn settings_camera_zoom_label(
+    settings: Res<GameSettings>,
+    mut text_query: Query<&mut Text, With<CameraZoomValueText>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut text in &mut text_query {
+        **text = camera_zoom_label(settings.camera_zoom);
+    }
+}
+
+/// Rebuild `MenuTheme` when the UI scale changes and resize the primary
+/// window when the resolution changes (including when loaded at startup).
+/// Other settings changes leave a window the player resized by hand alone.
+pub fn apply_display_settings(
+    settings: Res<GameSettings>,
+    mut theme: ResMut<MenuTheme>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut applied: Local<Option<(f32, (f32, f32))>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    let previous = applied.replace((settings.ui_scale, settings.resolution));
+
+    if previous.map(|(scale, _)| scale) != Some(settings.ui_scale) {
+        *theme = MenuTheme::scaled(settings.ui_scale);
+    }
+
+    if previous.map(|(_, resolution)| resolution) != Some(settings.resolution) {
+        let (width, height) = settings.resolution;
+        for mut window in &mut windows {
+            window.resolution.set(width, height);
+        }
+    }
+}
+
+/// Apply the keybinding profile when it changes (including when loaded at
+/// startup). A rebind that no longer matches the active preset switches the
+/// profile to `Custom`, so unrelated settings changes never undo it.
+pub fn apply_keybinding_profile(
+    mut settings: ResMut<GameSettings>,
+    mut bindings: ResMut<KeyBindings>,
+    mut applied: Local<Option<KeybindingProfile>>,
+) {
+    let profile = settings.keybinding_profile;
+    if *applied != Some(profile) {
+        *applied = Some(profile);
+        profile.apply(&mut bindings);
+        return;
+    }
+
+    if bindings.is_changed()
+        && KeyBindings::for_profile(profile).is_some_and(|preset| preset != *bindings)
+    {
+        settings.keybinding_profile = KeybindingProfile::Custom;
+        *applied = Some(KeybindingProfile::Custom);
+    }
+}
+
+/// Close overlay on Escape as well.
+pub fn settings_close_on_escape(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<SettingsOverlayState>,
+) {
+    if overlay.visible && keys.just_pressed(KeyCode::Escape) {
+        overlay.visible = false;
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// HELPERS
+// ═══════════════════════════════════════════════════════════════════════
+
+fn on_off_label(on: bool) -> &'static str {
+    if on {
+        "On"
+    } else {
+        "Off"
+    }
+}
+
+/// Next step after `current`, wrapping back to the lowest density.
+fn next_particle_density(current: f32) -> f32 {
+    PARTICLE_DENSITY_STEPS
+        .iter()
+        .copied()
+        .find(|step| *step > current + 0.01)
+        .unwrap_or(PARTICLE_DENSITY_STEPS[0])
+}
+
+fn particle_density_label(density: f32) -> String {
+    format!("{}%", (density * 100.0).round() as u32)
+}
+
+fn ui_scale_label(scale: f32) -> String {
+    format!("{}%", (scale * 100.0).round() as u32)
+}
+
+fn camera_zoom_label(zoom: u8) -> String {
+    format!("{}x", zoom.clamp(MIN_CAMERA_ZOOM, MAX_CAMERA_ZOOM))
+}
+
+fn resolution_label((width, height): (f32, f32)) -> String {
+    format!("{} x {}", width as u32, height as u32)
+}
+
+fn build_volume_bar(level: u8) -> String {
+    let filled = (level / 10) as usize;
+    let empty = 10usize.saturating_sub(filled);
+    format!("[{}{}] {}%", "|".repeat(filled), "-".repeat(empty), level)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ui_scale_rebuilds_theme_font_sizes() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(GameSettings {
+                ui_scale: 1.5,
+                ..default()
+            })
+            .init_resource::<MenuTheme>()
+            .add_systems(Update, apply_display_settings);
+
+        app.update();
+
+        let base = MenuTheme::default();
+        let theme = app.world().resource::<MenuTheme>();
+        assert_eq!(theme.title_font_size, base.title_font_size * 1.5);
+        assert_eq!(theme.button_font_size, base.button_font_size * 1.5);
+        assert_eq!(theme.panel_width, base.panel_width * 1.5);
+        assert_eq!(theme.panel_border_width, base.panel_border_width);
+    }
+
+    #[test]
+    fn window_is_resized_only_when_the_resolution_changes() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(GameSettings {
+                resolution: (1280.0, 720.0),
+                ..default()
+            })
+            .init_resource::<MenuTheme>()
+            .add_systems(Update, apply_display_settings);
+        let window = app
+            .world_mut()
+            .spawn((Window::default(), PrimaryWindow))
+            .id();
+        let size = |app: &App| {
+            let window = app.world().get::<Window>(window).unwrap();
+            (window.resolution.width(), window.resolution.height())
+        };
+
+        app.update();
+        assert_eq!(size(&app), (1280.0, 720.0));
+
+        // The player drags the window bigger, then changes the camera zoom.
+        app.world_mut()
+            .get_mut::<Window>(window)
+            .unwrap()
+            .resolution
+            .set(1600.0, 900.0);
+        app.world_mut().resource_mut::<GameSettings>().camera_zoom += 1;
+        app.update();
+        assert_eq!(size(&app), (1600.0, 900.0));
+
+        app.world_mut().resource_mut::<GameSettings>().resolution = (1920.0, 1080.0);
+        app.update();
+        assert_eq!(size(&app), (1920.0, 1080.0));
+    }
+
+    #[test]
+    fn rebinds_survive_unrelated_settings_changes() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(GameSettings {
+                keybinding_profile: KeybindingProfile::Esdf,
+                ..default()
+            })
+            .init_resource::<KeyBindings>()
+            .add_systems(Update, apply_keybinding_profile);
+
+        app.update();
+        assert_eq!(app.world().resource::<KeyBindings>().move_up, KeyCode::KeyE);
+
+        app.world_mut().resource_mut::<KeyBindings>().interact = KeyCode::KeyQ;
+        app.update();
+        assert_eq!(
+            app.world().resource::<GameSettings>().keybinding_profile,
+            KeybindingProfile::Custom
+        );
+
+        app.world_mut().resource_mut::<GameSettings>().camera_zoom += 1;
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().resource::<KeyBindings>().interact,
+            KeyCode::KeyQ
+        );
+    }
+
+    #[test]
+    fn esdf_overlay_hotkeys_do_not_reach_gameplay() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(bevy::state::app::StatesPlugin)
+            .add_plugins(crate::input::InputPlugin)
+            .init_state::<GameState>()
+            .insert_resource(GameSettings {
+                keybinding_profile: KeybindingProfile::Esdf,
+                ..default()
+            })
+            .init_resource::<KeyBindings>()
+            .init_resource::<PlayerInput>()
+            .init_resource::<InputContext>()
+            .init_resource::<InteractionClaimed>()
+            .init_resource::<InputBlocks>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<bevy::input::touch::Touches>()
+            .insert_resource(SettingsOverlayState { visible: true })
+            .add_systems(
+                Update,
+                (
+                    apply_keybinding_profile,
+                    settings_block_gameplay_input,
+                    settings_gifts_per_day_input,
+                    settings_creative_tools_input,
+                )
+                    .chain(),
+            );
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app.update();
+
+        let bindings = app.world().resource::<KeyBindings>();
+        assert_eq!(bindings.interact, KeyCode::KeyG);
+        assert_eq!(bindings.tool_secondary, KeyCode::KeyT);
+
+        let gifts_before = app.world().resource::<GameSettings>().gifts_per_day;
+        {
+            let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keys.press(KeyCode::KeyG);
+            keys.press(KeyCode::KeyT);
+        }
+        app.update();
+
+        let settings = app.world().resource::<GameSettings>();
+        assert_ne!(
+            settings.gifts_per_day, gifts_before,
+            "G cycles gifts per day"
+        );
+        assert!(settings.creative_tools, "T toggles creative tools");
+        let input = app.world().resource::<PlayerInput>();
+        assert!(!input.interact, "G must not also interact");
+        assert!(
+            !input.tool_secondary,
+            "T must not also use the secondary tool"
+        );
+
+        // Once the overlay has closed the same keys reach gameplay again.
+        app.world_mut()
+            .resource_mut::<SettingsOverlayState>()
+            .visible = false;
+        app.update();
+        app.update();
+        let input = app.world().resource::<PlayerInput>();
+        assert!(input.interact);
+        assert!(input.tool_secondary);
+    }
 }
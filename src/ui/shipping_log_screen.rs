@@ -0,0 +1,353 @@
+use super::settings_screen::SettingsOverlayState;
+use super::UiFontHandle;
+use crate::economy::shipping::{
+    category_completion, collection_category_label, collection_items, COLLECTION_CATEGORIES,
+};
+use crate::shared::*;
+use bevy::prelude::*;
+
+// ═══════════════════════════════════════════════════════════════════════
+// MARKER COMPONENTS
+// ═══════════════════════════════════════════════════════════════════════
+
+#[derive(Component)]
+pub struct ShippingLogScreenRoot;
+
+/// How many items one page of the grid shows (three columns of twenty).
+const ENTRIES_PER_PAGE: usize = 60;
+
+/// Tracks overlay visibility (toggled by F6 during Playing), which
+/// collection category is on screen and which page of it.
+#[derive(Resource, Default)]
+pub struct ShippingLogOverlayState {
+    pub visible: bool,
+    pub category_index: usize,
+    pub page: usize,
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// INPUT
+// ═══════════════════════════════════════════════════════════════════════
+
+/// F6 toggles the overlay; opening it closes the settings overlay.
+pub fn toggle_shipping_log_overlay(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<ShippingLogOverlayState>,
+    mut settings_overlay: ResMut<SettingsOverlayState>,
+) {
+    if keys.just_pressed(KeyCode::F6) {
+        overlay.visible = !overlay.visible;
+        if overlay.visible && settings_overlay.visible {
+            settings_overlay.visible = false;
+        }
+    }
+}
+
+/// Hold an `InputBlocks` entry while the overlay is open, so its keys don't
+/// also drive the player.
+pub fn shipping_log_block_gameplay_input(
+    overlay: Res<ShippingLogOverlayState>,
+    mut input_blocks: ResMut<InputBlocks>,
+) {
+    if !overlay.is_changed() {
+        return;
+    }
+    if overlay.visible {
+        input_blocks.block::<ShippingLogOverlayState>();
+    } else {
+        input_blocks.unblock::<ShippingLogOverlayState>();
+    }
+}
+
+/// Close overlay on Escape as well.
+pub fn shipping_log_close_on_escape(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<ShippingLogOverlayState>,
+) {
+    if overlay.visible && keys.just_pressed(KeyCode::Escape) {
+        overlay.visible = false;
+    }
+}
+
+/// PageUp / PageDown flip between collection categories; Up / Down page
+/// through a category too long for one screen.
+pub fn shipping_log_page_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    item_registry: Res<ItemRegistry>,
+    mut overlay: ResMut<ShippingLogOverlayState>,
+) {
+    if !overlay.visible {
+        return;
+    }
+    let count = COLLECTION_CATEGORIES.len();
+    if keys.just_pressed(KeyCode::PageDown) {
+        overlay.category_index = (overlay.category_index + 1) % count;
+        overlay.page = 0;
+    } else if keys.just_pressed(KeyCode::PageUp) {
+        overlay.category_index = (overlay.category_index + count - 1) % count;
+        overlay.page = 0;
+    } else if keys.just_pressed(KeyCode::ArrowDown) {
+        let category = COLLECTION_CATEGORIES[overlay.category_index % count];
+        let pages = page_count(collection_items(category, &item_registry).len());
+        if overlay.page + 1 < pages {
+            overlay.page += 1;
+        }
+    } else if keys.just_pressed(KeyCode::ArrowUp) && overlay.page > 0 {
+        overlay.page -= 1;
+    }
+}
+
+/// Pages needed to show `entries` items; an empty category still has one.
+fn page_count(entries: usize) -> usize {
+    entries.div_ceil(ENTRIES_PER_PAGE).max(1)
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// LIFECYCLE — reactive spawn/despawn based on ShippingLogOverlayState
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Spawns the screen when opened, despawns it when closed, and rebuilds it
+/// when the page changes or something new is shipped while it is open.
+pub fn update_shipping_log_lifecycle(
+    mut commands: Commands,
+    overlay: Res<ShippingLogOverlayState>,
+    font_handle: Res<UiFontHandle>,
+    shipping_log: Res<ShippingLog>,
+    item_registry: Res<ItemRegistry>,
+    existing: Query<Entity, With<ShippingLogScreenRoot>>,
+) {
+    let ui_exists = !existing.is_empty();
+    let stale = overlay.is_changed() || shipping_log.is_changed();
+
+    if ui_exists && (!overlay.visible || stale) {
+        for entity in &existing {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+    if overlay.visible && (!ui_exists || stale) {
+        spawn_shipping_log_screen(
+            &mut commands,
+            &font_handle,
+            &overlay,
+            &shipping_log,
+            &item_registry,
+        );
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// SPAWN
+// ═══════════════════════════════════════════════════════════════════════
+
+fn spawn_shipping_log_screen(
+    commands: &mut Commands,
+    font_handle: &UiFontHandle,
+    overlay: &ShippingLogOverlayState,
+    shipping_log: &ShippingLog,
+    item_registry: &ItemRegistry,
+) {
+    let font = font_handle.0.clone();
+
+    let category = COLLECTION_CATEGORIES[overlay.category_index % COLLECTION_CATEGORIES.len()];
+    let (done, total) = category_completion(category, shipping_log, item_registry);
+    let items = collection_items(category, item_registry);
+    let pages = page_count(items.len());
+    let page = overlay.page.min(pages - 1);
+    let mut header = format!(
+        "{} — {}/{} shipped",
+        collection_category_label(category),
+        done,
+        total
+    );
+    if pages > 1 {
+        header.push_str(&format!("  (page {}/{})", page + 1, pages));
+    }
+    let header_color = if total > 0 && done == total {
+        Color::srgb(1.0, 0.85, 0.3)
+    } else {
+        Color::srgb(0.85, 0.85, 0.9)
+    };
+    let entries: Vec<(String, Option<u32>)> = items
+        .into_iter()
+        .skip(page * ENTRIES_PER_PAGE)
+        .take(ENTRIES_PER_PAGE)
+        .map(|def| {
+            let shipped = shipping_log
+                .shipped_items
+                .get(&def.id)
+                .copied()
+                .filter(|&n| n > 0);
+            (def.name.clone(), shipped)
+        })
+        .collect();
+
+    commands
+        .spawn((
+            ShippingLogScreenRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            GlobalZIndex(60),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(580.0),
+                        height: Val::Px(480.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(16.0)),
+                        row_gap: Val::Px(8.0),
+                        border: UiRect::all(Val::Px(3.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.08, 0.14, 0.97)),
+                    BorderColor(Color::srgb(0.3, 0.35, 0.6)),
+                ))
+                .with_children(|panel| {
+                    // Title
+                    panel.spawn((
+                        Text::new("SHIPPING COLLECTION"),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.8, 0.85, 1.0)),
+                    ));
+
+                    // Hint
+                    panel.spawn((
+                        Text::new("PgUp / PgDn: Category    Up / Down: Page    F6 / Esc: Close"),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 11.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.55, 0.55, 0.55)),
+                    ));
+
+                    // Category header with completion count
+                    panel.spawn((
+                        Text::new(header),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(header_color),
+                    ));
+
+                    // Divider
+                    panel.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Px(2.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.3, 0.35, 0.6)),
+                    ));
+
+                    // Item grid — three columns, unshipped items dimmed
+                    panel
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            flex_wrap: FlexWrap::Wrap,
+                            align_content: AlignContent::FlexStart,
+                            row_gap: Val::Px(2.0),
+                            overflow: Overflow::clip_y(),
+                            ..default()
+                        })
+                        .with_children(|grid| {
+                            for (name, shipped) in &entries {
+                                let (label, color) = match shipped {
+                                    Some(count) => (
+                                        format!("\u{2713} {} x{}", name, count),
+                                        Color::srgb(0.85, 0.95, 0.75),
+                                    ),
+                                    None => (format!("- {}", name), Color::srgb(0.45, 0.45, 0.5)),
+                                };
+                                grid.spawn((
+                                    Node {
+                                        width: Val::Percent(33.3),
+                                        ..default()
+                                    },
+                                    Text::new(label),
+                                    TextFont {
+                                        font: font.clone(),
+                                        font_size: 11.0,
+                                        ..default()
+                                    },
+                                    TextColor(color),
+                                ));
+                            }
+                        });
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::settings_screen::{
+        settings_block_gameplay_input, toggle_settings_overlay, SettingsOverlayState,
+    };
+
+    #[test]
+    fn long_categories_split_into_pages() {
+        assert_eq!(page_count(0), 1);
+        assert_eq!(page_count(ENTRIES_PER_PAGE), 1);
+        assert_eq!(page_count(ENTRIES_PER_PAGE + 1), 2);
+    }
+
+    #[test]
+    fn shipping_log_and_settings_overlays_are_exclusive_and_block_input() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<InputBlocks>()
+            .init_resource::<ShippingLogOverlayState>()
+            .init_resource::<SettingsOverlayState>()
+            .add_systems(
+                Update,
+                (
+                    toggle_shipping_log_overlay,
+                    toggle_settings_overlay,
+                    shipping_log_block_gameplay_input,
+                    settings_block_gameplay_input,
+                )
+                    .chain(),
+            );
+
+        let press = |app: &mut App, key: KeyCode| {
+            let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keys.clear();
+            keys.press(key);
+            app.update();
+            app.world_mut()
+                .resource_mut::<ButtonInput<KeyCode>>()
+                .release(key);
+        };
+
+        press(&mut app, KeyCode::F6);
+        assert!(app.world().resource::<ShippingLogOverlayState>().visible);
+        assert!(app.world().resource::<InputBlocks>().is_blocked());
+
+        press(&mut app, KeyCode::F4);
+        assert!(app.world().resource::<SettingsOverlayState>().visible);
+        assert!(!app.world().resource::<ShippingLogOverlayState>().visible);
+        assert!(app.world().resource::<InputBlocks>().is_blocked());
+
+        press(&mut app, KeyCode::F6);
+        assert!(app.world().resource::<ShippingLogOverlayState>().visible);
+        assert!(!app.world().resource::<SettingsOverlayState>().visible);
+
+        press(&mut app, KeyCode::F6);
+        assert!(!app.world().resource::<InputBlocks>().is_blocked());
+    }
+}
@@ -2,6 +2,10 @@ use super::hud::ItemAtlasData;
 use super::item_icon_index;
 use super::UiFontHandle;
 use crate::economy::blacksmith::ToolUpgradeRequestEvent;
+use crate::economy::shop::{
+    is_sell_locked, listings_for_season, shop_gold_change, shop_sell_price, try_buy,
+    try_buy_starter_bundle, try_buyback, try_sell, ShopBuyback, TransactionResult,
+};
 use crate::shared::*;
 use bevy::prelude::*;
 
@@ -66,12 +70,14 @@ pub struct ShopUiState {
     pub shop_id: ShopId,
     pub cursor: usize,
     pub is_buy_mode: bool,
+    /// True when the buyback tab (items sold today) is active.
+    pub buyback_mode: bool,
     /// True when the Blacksmith upgrade tab is active.
     pub upgrade_mode: bool,
     /// Cached list of available items (filtered by season for buy mode)
     pub buy_items: Vec<ShopListing>,
-    /// Player items available to sell
-    pub sell_items: Vec<(ItemId, String, u32, u8)>, // id, name, sell_price, quantity
+    /// Player items available to sell, one entry per item and quality
+    pub sell_items: Vec<(ItemId, String, u32, u8, ItemQuality)>, // id, name, sell_price, quantity, quality
     /// Upgrade entries (only populated for ShopId::Blacksmith)
     pub upgrade_entries: Vec<ToolUpgradeDisplayEntry>,
 }
@@ -99,14 +105,17 @@ pub fn spawn_shop_screen(
     let buy_items: Vec<ShopListing> = shop_data
         .listings
         .get(&shop_id)
-        .cloned()
+        .map(|listings| listings_for_season(listings, calendar.season))
         .unwrap_or_default()
         .into_iter()
         .filter(|listing| {
-            listing
-                .season_available
-                .is_none_or(|s| s == calendar.season)
+            !BACKPACK_UPGRADES
+                .iter()
+                .any(|(id, _)| *id == listing.item_id)
+                || inventory.next_backpack_upgrade() == Some(listing.item_id.as_str())
         })
+        .filter(|listing| listing.item_id != STARTER_BUNDLE || !player.starter_bundle_bought)
+        .cloned()
         .collect();
 
     let sell_items = build_sell_list(&inventory, &item_registry);
@@ -122,6 +131,7 @@ pub fn spawn_shop_screen(
         shop_id,
         cursor: 0,
         is_buy_mode: true,
+        buyback_mode: false,
         upgrade_mode: false,
         buy_items: buy_items.clone(),
         sell_items: sell_items.clone(),
@@ -295,29 +305,42 @@ pub fn spawn_shop_screen(
         });
 }
 
+fn item_display_name(item_id: &str, item_registry: &ItemRegistry) -> String {
+    item_registry
+        .get(item_id)
+        .map(|d| d.name.clone())
+        .unwrap_or_else(|| item_id.to_string())
+}
+
 fn build_sell_list(
     inventory: &Inventory,
     item_registry: &ItemRegistry,
-) -> Vec<(ItemId, String, u32, u8)> {
-    let mut result = Vec::new();
+) -> Vec<(ItemId, String, u32, u8, ItemQuality)> {
+    let mut result: Vec<(ItemId, String, u32, u8, ItemQuality)> = Vec::new();
     for slot in inventory.slots.iter().flatten() {
-        // Check if we already have this item in the sell list
+        // Each quality of an item gets its own entry and price.
         if result
             .iter()
-            .any(|(id, _, _, _): &(ItemId, String, u32, u8)| id == &slot.item_id)
+            .any(|(id, _, _, _, quality)| id == &slot.item_id && *quality == slot.quality)
         {
             continue;
         }
-        let name = item_registry
-            .get(&slot.item_id)
+        let def = item_registry.get(&slot.item_id);
+        if def.is_some_and(is_sell_locked) {
+            continue;
+        }
+        let base_name = def
             .map(|d| d.name.clone())
             .unwrap_or_else(|| slot.item_id.clone());
-        let price = item_registry
-            .get(&slot.item_id)
-            .map(|d| d.sell_price)
-            .unwrap_or(1);
-        let total_qty = inventory.count(&slot.item_id) as u8;
-        result.push((slot.item_id.clone(), name, price, total_qty));
+        let name = match slot.quality {
+            ItemQuality::Normal => base_name,
+            quality => format!("{} ({:?})", base_name, quality),
+        };
+        let price = def.map(|d| shop_sell_price(d, slot.quality)).unwrap_or(1);
+        let total_qty = inventory
+            .count_quality(&slot.item_id, slot.quality)
+            .min(u8::MAX as u32) as u8;
+        result.push((slot.item_id.clone(), name, price, total_qty, slot.quality));
     }
     result
 }
@@ -375,6 +398,7 @@ pub fn despawn_shop_screen(mut commands: Commands, query: Query<Entity, With<Sho
 pub fn update_shop_display(
     ui_state: Option<Res<ShopUiState>>,
     item_registry: Res<ItemRegistry>,
+    buyback: Res<ShopBuyback>,
     player: Res<PlayerState>,
     mut gold_query: Query<&mut Text, With<ShopGoldDisplay>>,
     mut mode_query: Query<
@@ -406,6 +430,8 @@ pub fn update_shop_display(
     mut icon_query: Query<(&ShopItemIcon, &mut ImageNode, &mut Visibility)>,
 ) {
     let Some(ui_state) = ui_state else { return };
+    let buyback_items = buyback.for_shop(ui_state.shop_id);
+    let buyback_entry = |idx: usize| buyback_items.and_then(|q| q.get(idx));
 
     // Gold
     for mut text in &mut gold_query {
@@ -416,6 +442,8 @@ pub fn update_shop_display(
     for mut text in &mut mode_query {
         if ui_state.upgrade_mode {
             **text = "[Tab] Mode: UPGRADE".to_string();
+        } else if ui_state.buyback_mode {
+            **text = "[Tab] Mode: BUYBACK".to_string();
         } else if ui_state.is_buy_mode {
             **text = "[Tab] Mode: BUY".to_string();
         } else {
@@ -461,6 +489,32 @@ pub fn update_shop_display(
                 **text = String::new();
             }
         }
+    } else if ui_state.buyback_mode {
+        for (name_comp, mut text) in &mut name_query {
+            **text = match buyback_entry(name_comp.index) {
+                Some((item_id, qty, _, _)) => {
+                    let name = item_registry
+                        .get(item_id)
+                        .map(|d| d.name.clone())
+                        .unwrap_or_else(|| item_id.clone());
+                    format!("{} (x{})", name, qty)
+                }
+                None => String::new(),
+            };
+        }
+        for (price_comp, mut text, mut color) in &mut price_query {
+            match buyback_entry(price_comp.index) {
+                Some((_, _, price, _)) => {
+                    **text = format!("{} G", price);
+                    if *price > player.gold {
+                        *color = TextColor(Color::srgb(0.8, 0.3, 0.3));
+                    } else {
+                        *color = TextColor(Color::srgb(1.0, 0.84, 0.0));
+                    }
+                }
+                None => **text = String::new(),
+            }
+        }
     } else if ui_state.is_buy_mode {
         for (name_comp, mut text) in &mut name_query {
             let idx = name_comp.index;
@@ -494,7 +548,7 @@ pub fn update_shop_display(
         for (name_comp, mut text) in &mut name_query {
             let idx = name_comp.index;
             if idx < ui_state.sell_items.len() {
-                let (_, ref name, _, qty) = ui_state.sell_items[idx];
+                let (_, ref name, _, qty, _) = ui_state.sell_items[idx];
                 **text = format!("{} (x{})", name, qty);
             } else {
                 **text = String::new();
@@ -503,7 +557,7 @@ pub fn update_shop_display(
         for (price_comp, mut text, mut color) in &mut price_query {
             let idx = price_comp.index;
             if idx < ui_state.sell_items.len() {
-                let (_, _, price, _) = ui_state.sell_items[idx];
+                let (_, _, price, _, _) = ui_state.sell_items[idx];
                 **text = format!("{} G", price);
                 *color = TextColor(Color::srgb(0.5, 0.9, 0.5));
             } else {
@@ -517,13 +571,15 @@ pub fn update_shop_display(
         let idx = icon.index;
         let item_id: Option<&str> = if ui_state.upgrade_mode {
             None // No item icon for tool upgrades
+        } else if ui_state.buyback_mode {
+            buyback_entry(idx).map(|(id, _, _, _)| id.as_str())
         } else if ui_state.is_buy_mode {
             ui_state.buy_items.get(idx).map(|l| l.item_id.as_str())
         } else {
             ui_state
                 .sell_items
                 .get(idx)
-                .map(|(id, _, _, _)| id.as_str())
+                .map(|(id, _, _, _, _)| id.as_str())
         };
         if let Some(id) = item_id {
             if let Some(def) = item_registry.get(id) {
@@ -547,6 +603,11 @@ pub fn update_shop_display(
     }
 }
 
+/// Cursor movement, tab cycling and buy/sell/buyback for the shop screen.
+/// Transactions are validated against `PlayerState.gold` and then charged or
+/// paid with one `GoldChangeEvent`; gold itself is left to `apply_gold_changes`,
+/// which runs later in the frame. The starter bundle grants several stacks
+/// for one charge and then leaves the listing.
 #[allow(clippy::too_many_arguments)]
 pub fn shop_navigation(
     action: Res<MenuAction>,
@@ -556,7 +617,9 @@ pub fn shop_navigation(
     mut inventory: ResMut<Inventory>,
     item_registry: Res<ItemRegistry>,
     upgrade_queue: Res<crate::economy::blacksmith::ToolUpgradeQueue>,
+    mut buyback: ResMut<ShopBuyback>,
     mut tx_events: EventWriter<ShopTransactionEvent>,
+    mut gold_events: EventWriter<GoldChangeEvent>,
     mut upgrade_events: EventWriter<ToolUpgradeRequestEvent>,
     mut toast_events: EventWriter<ToastEvent>,
     mut sfx_events: EventWriter<PlaySfxEvent>,
@@ -567,6 +630,8 @@ pub fn shop_navigation(
 
     let max_items = if ui_state.upgrade_mode {
         ui_state.upgrade_entries.len()
+    } else if ui_state.buyback_mode {
+        buyback.for_shop(ui_state.shop_id).map_or(0, |q| q.len())
     } else if ui_state.is_buy_mode {
         ui_state.buy_items.len()
     } else {
@@ -582,30 +647,26 @@ pub fn shop_navigation(
     }
 
     // Cycle modes: Tab
-    // Blacksmith: buy → sell → upgrade → buy
-    // Other shops: buy ↔ sell
+    // Blacksmith: buy → sell → buyback → upgrade → buy
+    // Other shops: buy → sell → buyback → buy
     if player_input.tab_pressed {
-        if ui_state.shop_id == ShopId::Blacksmith {
-            if ui_state.is_buy_mode && !ui_state.upgrade_mode {
-                // buy → sell
-                ui_state.is_buy_mode = false;
-                ui_state.upgrade_mode = false;
-                ui_state.sell_items = build_sell_list(&inventory, &item_registry);
-            } else if !ui_state.is_buy_mode && !ui_state.upgrade_mode {
-                // sell → upgrade
-                ui_state.upgrade_mode = true;
-                ui_state.upgrade_entries =
-                    build_upgrade_entries(&player, &inventory, &upgrade_queue);
-            } else {
-                // upgrade → buy
-                ui_state.upgrade_mode = false;
-                ui_state.is_buy_mode = true;
-            }
+        if ui_state.is_buy_mode && !ui_state.upgrade_mode {
+            // buy → sell
+            ui_state.is_buy_mode = false;
+            ui_state.sell_items = build_sell_list(&inventory, &item_registry);
+        } else if !ui_state.buyback_mode && !ui_state.upgrade_mode {
+            // sell → buyback
+            ui_state.buyback_mode = true;
+        } else if ui_state.buyback_mode && ui_state.shop_id == ShopId::Blacksmith {
+            // buyback → upgrade
+            ui_state.buyback_mode = false;
+            ui_state.upgrade_mode = true;
+            ui_state.upgrade_entries = build_upgrade_entries(&player, &inventory, &upgrade_queue);
         } else {
-            ui_state.is_buy_mode = !ui_state.is_buy_mode;
-            if !ui_state.is_buy_mode {
-                ui_state.sell_items = build_sell_list(&inventory, &item_registry);
-            }
+            // buyback / upgrade → buy
+            ui_state.buyback_mode = false;
+            ui_state.upgrade_mode = false;
+            ui_state.is_buy_mode = true;
         }
         ui_state.cursor = 0;
     }
@@ -621,63 +682,220 @@ pub fn shop_navigation(
                 ui_state.upgrade_entries =
                     build_upgrade_entries(&player, &inventory, &upgrade_queue);
             }
-        } else if ui_state.is_buy_mode {
-            // Buy
-            if ui_state.cursor < ui_state.buy_items.len() {
-                let listing = ui_state.buy_items[ui_state.cursor].clone();
-                if player.gold >= listing.price {
-                    let max_stack = item_registry
-                        .get(&listing.item_id)
-                        .map(|d| d.stack_size)
-                        .unwrap_or(99);
-                    let overflow = inventory.try_add(&listing.item_id, 1, max_stack);
-                    if overflow == 0 {
-                        player.gold -= listing.price;
+        } else if ui_state.buyback_mode {
+            // Buy back something sold earlier today, at the price it sold for
+            let shop_id = ui_state.shop_id;
+            let entry = buyback
+                .for_shop(shop_id)
+                .and_then(|q| q.get(ui_state.cursor))
+                .cloned();
+            if let Some((item_id, _, _, quality)) = entry {
+                match try_buyback(
+                    &mut buyback,
+                    shop_id,
+                    ui_state.cursor,
+                    &player,
+                    &mut inventory,
+                    &item_registry,
+                ) {
+                    TransactionResult::Success { total } => {
+                        gold_events.send(shop_gold_change(
+                            true,
+                            &item_display_name(&item_id, &item_registry),
+                            total,
+                        ));
                         tx_events.send(ShopTransactionEvent {
-                            shop_id: ui_state.shop_id,
-                            item_id: listing.item_id,
+                            shop_id,
+                            item_id,
                             quantity: 1,
-                            total_cost: listing.price,
+                            total_cost: total,
                             is_purchase: true,
+                            quality,
                         });
                         sfx_events.send(PlaySfxEvent {
                             sfx_id: "sfx_coin_single1".into(),
                         });
-                        // Refresh sell list so it reflects the newly added item
                         ui_state.sell_items = build_sell_list(&inventory, &item_registry);
-                    } else {
-                        inventory.try_remove(&listing.item_id, 1);
+                        let remaining = buyback.for_shop(shop_id).map_or(0, |q| q.len());
+                        if ui_state.cursor >= remaining && ui_state.cursor > 0 {
+                            ui_state.cursor -= 1;
+                        }
+                    }
+                    failure => {
+                        let message = match failure {
+                            TransactionResult::InventoryFull => "Inventory is full!",
+                            _ => "Not enough gold!",
+                        };
                         toast_events.send(ToastEvent {
-                            message: "Inventory is full!".into(),
+                            message: message.into(),
                             duration_secs: 2.0,
                         });
                         sfx_events.send(PlaySfxEvent {
                             sfx_id: "error".into(),
                         });
                     }
-                } else {
+                }
+            }
+        } else if ui_state.is_buy_mode {
+            // Buy
+            if ui_state.cursor < ui_state.buy_items.len() {
+                let listing = ui_state.buy_items[ui_state.cursor].clone();
+                let is_backpack = BACKPACK_UPGRADES
+                    .iter()
+                    .any(|(id, _)| *id == listing.item_id);
+                if is_backpack
+                    && inventory.next_backpack_upgrade() != Some(listing.item_id.as_str())
+                {
                     toast_events.send(ToastEvent {
-                        message: "Not enough gold!".into(),
+                        message: "You already have that backpack.".into(),
                         duration_secs: 2.0,
                     });
                     sfx_events.send(PlaySfxEvent {
                         sfx_id: "error".into(),
                     });
+                } else if is_backpack && player.gold >= listing.price {
+                    // Backpacks unlock slots directly rather than taking one.
+                    inventory.apply_backpack_upgrade(&listing.item_id);
+                    gold_events.send(shop_gold_change(
+                        true,
+                        &item_display_name(&listing.item_id, &item_registry),
+                        listing.price,
+                    ));
+                    tx_events.send(ShopTransactionEvent {
+                        shop_id: ui_state.shop_id,
+                        item_id: listing.item_id,
+                        quantity: 1,
+                        total_cost: listing.price,
+                        is_purchase: true,
+                        quality: ItemQuality::Normal,
+                    });
+                    sfx_events.send(PlaySfxEvent {
+                        sfx_id: "sfx_coin_single1".into(),
+                    });
+                    toast_events.send(ToastEvent {
+                        message: format!(
+                            "Backpack upgraded! {} inventory slots.",
+                            inventory.unlocked_slots
+                        ),
+                        duration_secs: 3.0,
+                    });
+                } else if listing.item_id == STARTER_BUNDLE {
+                    match try_buy_starter_bundle(
+                        listing.price,
+                        &player,
+                        &mut inventory,
+                        &item_registry,
+                    ) {
+                        TransactionResult::Success { total } => {
+                            player.starter_bundle_bought = true;
+                            gold_events.send(shop_gold_change(
+                                true,
+                                &item_display_name(STARTER_BUNDLE, &item_registry),
+                                total,
+                            ));
+                            tx_events.send(ShopTransactionEvent {
+                                shop_id: ui_state.shop_id,
+                                item_id: listing.item_id,
+                                quantity: 1,
+                                total_cost: total,
+                                is_purchase: true,
+                                quality: ItemQuality::Normal,
+                            });
+                            sfx_events.send(PlaySfxEvent {
+                                sfx_id: "sfx_coin_single1".into(),
+                            });
+                            toast_events.send(ToastEvent {
+                                message: "Starter bundle unpacked into your bag!".into(),
+                                duration_secs: 3.0,
+                            });
+                            let cursor = ui_state.cursor;
+                            ui_state.buy_items.remove(cursor);
+                            if ui_state.cursor >= ui_state.buy_items.len() && ui_state.cursor > 0 {
+                                ui_state.cursor -= 1;
+                            }
+                            ui_state.sell_items = build_sell_list(&inventory, &item_registry);
+                        }
+                        failure => {
+                            let message = match failure {
+                                TransactionResult::InventoryFull => {
+                                    "Not enough room in your bag for the bundle!"
+                                }
+                                TransactionResult::AlreadyOwned => "You already bought that.",
+                                _ => "Not enough gold!",
+                            };
+                            toast_events.send(ToastEvent {
+                                message: message.into(),
+                                duration_secs: 2.0,
+                            });
+                            sfx_events.send(PlaySfxEvent {
+                                sfx_id: "error".into(),
+                            });
+                        }
+                    }
+                } else {
+                    match try_buy(
+                        &listing.item_id,
+                        1,
+                        listing.price,
+                        &player,
+                        &mut inventory,
+                        &item_registry,
+                    ) {
+                        TransactionResult::Success { total } => {
+                            gold_events.send(shop_gold_change(
+                                true,
+                                &item_display_name(&listing.item_id, &item_registry),
+                                total,
+                            ));
+                            tx_events.send(ShopTransactionEvent {
+                                shop_id: ui_state.shop_id,
+                                item_id: listing.item_id,
+                                quantity: 1,
+                                total_cost: total,
+                                is_purchase: true,
+                                quality: ItemQuality::Normal,
+                            });
+                            sfx_events.send(PlaySfxEvent {
+                                sfx_id: "sfx_coin_single1".into(),
+                            });
+                            // Refresh sell list so it reflects the newly added item
+                            ui_state.sell_items = build_sell_list(&inventory, &item_registry);
+                        }
+                        failure => {
+                            let message = match failure {
+                                TransactionResult::InventoryFull => "Inventory is full!",
+                                _ => "Not enough gold!",
+                            };
+                            toast_events.send(ToastEvent {
+                                message: message.into(),
+                                duration_secs: 2.0,
+                            });
+                            sfx_events.send(PlaySfxEvent {
+                                sfx_id: "error".into(),
+                            });
+                        }
+                    }
                 }
             }
         } else {
             // Sell
             if ui_state.cursor < ui_state.sell_items.len() {
-                let (ref item_id, _, price, _) = ui_state.sell_items[ui_state.cursor];
-                let removed = inventory.try_remove(item_id, 1);
-                if removed > 0 {
-                    player.gold += price;
+                let (ref item_id, _, _, _, quality) = ui_state.sell_items[ui_state.cursor];
+                if let TransactionResult::Success { total } =
+                    try_sell(item_id, 1, quality, &mut inventory, &item_registry)
+                {
+                    gold_events.send(shop_gold_change(
+                        false,
+                        &item_display_name(item_id, &item_registry),
+                        total,
+                    ));
                     tx_events.send(ShopTransactionEvent {
                         shop_id: ui_state.shop_id,
                         item_id: item_id.clone(),
                         quantity: 1,
-                        total_cost: price,
+                        total_cost: total,
                         is_purchase: false,
+                        quality,
                     });
                     sfx_events.send(PlaySfxEvent {
                         sfx_id: "sfx_coin_single1".into(),
@@ -692,3 +910,135 @@ pub fn shop_navigation(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::economy::blacksmith::ToolUpgradeQueue;
+    use crate::economy::gold::{apply_gold_changes, DailyLedger, EconomyStats};
+
+    fn shop_test_app(gold: u32, item_id: &str, price: u32) -> App {
+        let mut registry = ItemRegistry::default();
+        let bundle_ids = STARTER_BUNDLE_ITEMS.iter().map(|(id, _)| *id);
+        for id in ["parsnip_seeds", STARTER_BUNDLE]
+            .into_iter()
+            .chain(bundle_ids)
+        {
+            registry.items.insert(
+                id.to_string(),
+                ItemDef {
+                    id: id.to_string(),
+                    name: id.to_string(),
+                    description: String::new(),
+                    category: ItemCategory::Seed,
+                    sell_price: 10,
+                    buy_price: None,
+                    stack_size: 99,
+                    edible: false,
+                    energy_restore: 0.0,
+                    sprite_index: 0,
+                },
+            );
+        }
+        let player = PlayerState {
+            gold,
+            ..Default::default()
+        };
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(registry)
+            .insert_resource(player)
+            .insert_resource(ShopUiState {
+                shop_id: ShopId::GeneralStore,
+                cursor: 0,
+                is_buy_mode: true,
+                buyback_mode: false,
+                upgrade_mode: false,
+                buy_items: vec![ShopListing {
+                    item_id: item_id.to_string(),
+                    price,
+                    season_available: None,
+                }],
+                sell_items: Vec::new(),
+                upgrade_entries: Vec::new(),
+            })
+            .init_resource::<MenuAction>()
+            .init_resource::<PlayerInput>()
+            .init_resource::<Inventory>()
+            .init_resource::<ToolUpgradeQueue>()
+            .init_resource::<ShopBuyback>()
+            .init_resource::<EconomyStats>()
+            .init_resource::<DailyLedger>()
+            .add_event::<ShopTransactionEvent>()
+            .add_event::<GoldChangeEvent>()
+            .add_event::<ToolUpgradeRequestEvent>()
+            .add_event::<ToastEvent>()
+            .add_event::<PlaySfxEvent>()
+            .add_systems(Update, (shop_navigation, apply_gold_changes).chain());
+        app.world_mut().resource_mut::<MenuAction>().activate = true;
+        app
+    }
+
+    #[test]
+    fn buying_an_unaffordable_item_changes_nothing() {
+        let mut app = shop_test_app(50, "parsnip_seeds", 100);
+        app.update();
+
+        assert_eq!(app.world().resource::<PlayerState>().gold, 50);
+        assert!(app.world().resource::<Events<GoldChangeEvent>>().is_empty());
+        assert!(app
+            .world()
+            .resource::<Events<ShopTransactionEvent>>()
+            .is_empty());
+        assert_eq!(
+            app.world().resource::<Inventory>().count("parsnip_seeds"),
+            0
+        );
+    }
+
+    #[test]
+    fn buying_charges_once_through_a_single_gold_event() {
+        let mut app = shop_test_app(500, "parsnip_seeds", 100);
+        app.update();
+
+        let gold_events = app.world().resource::<Events<GoldChangeEvent>>();
+        assert_eq!(gold_events.len(), 1);
+        assert_eq!(app.world().resource::<PlayerState>().gold, 400);
+        assert_eq!(
+            app.world().resource::<Inventory>().count("parsnip_seeds"),
+            1
+        );
+        assert_eq!(app.world().resource::<EconomyStats>().total_gold_spent, 100);
+    }
+
+    #[test]
+    fn starter_bundle_grants_every_item_once() {
+        let mut app = shop_test_app(1_000, STARTER_BUNDLE, 450);
+        app.update();
+
+        assert_eq!(app.world().resource::<PlayerState>().gold, 550);
+        assert!(app.world().resource::<PlayerState>().starter_bundle_bought);
+        assert_eq!(app.world().resource::<Events<GoldChangeEvent>>().len(), 1);
+        let inventory = app.world().resource::<Inventory>();
+        for (item_id, quantity) in STARTER_BUNDLE_ITEMS {
+            assert_eq!(inventory.count(item_id), quantity as u32, "{item_id}");
+        }
+        assert!(app.world().resource::<ShopUiState>().buy_items.is_empty());
+
+        // Even with the listing still on screen, a second purchase is refused.
+        app.world_mut().resource_mut::<ShopUiState>().buy_items = vec![ShopListing {
+            item_id: STARTER_BUNDLE.to_string(),
+            price: 450,
+            season_available: None,
+        }];
+        app.world_mut().resource_mut::<MenuAction>().activate = true;
+        app.update();
+
+        assert_eq!(app.world().resource::<PlayerState>().gold, 550);
+        let inventory = app.world().resource::<Inventory>();
+        for (item_id, quantity) in STARTER_BUNDLE_ITEMS {
+            assert_eq!(inventory.count(item_id), quantity as u32, "{item_id}");
+        }
+    }
+}
@@ -74,7 +74,7 @@ pub fn spawn_fade_overlay(mut commands: Commands) {
 pub fn trigger_fade_on_transition(
     mut load_requests: EventReader<LoadRequestEvent>,
     mut load_completions: EventReader<LoadCompleteEvent>,
-    mut events: EventReader<MapTransitionEvent>,
+    mut events: EventReader<HonoredMapTransitionEvent>,
     mut fade: ResMut<ScreenFade>,
 ) {
     if load_requests.read().next().is_some() {
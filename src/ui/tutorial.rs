@@ -6,44 +6,105 @@ use bevy::prelude::*;
 // (Fire-and-forget hints for situations outside the objective sequence.)
 // ═══════════════════════════════════════════════════════════════════════
 
+/// What has to be true in the world for a contextual hint to fire.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HintTrigger {
+    /// The player is on any of these maps.
+    OnMap(&'static [MapId]),
+    /// It is raining or storming, on any map.
+    RainyWeather,
+    /// First day of a season other than the very first spring.
+    SeasonStart,
+    /// Stamina has dropped below this value.
+    StaminaBelow(f32),
+    /// Any crop on the farm has reached at least this growth stage.
+    CropStageAtLeast(u8),
+    /// The opening morning of a new game.
+    FirstMorning,
+    /// The inventory screen has been opened at least once.
+    InventoryOpened,
+}
+
 struct HintDef {
     id: &'static str,
+    trigger: HintTrigger,
     message: &'static str,
 }
 
 const HINTS: &[HintDef] = &[
     HintDef {
         id: "mine_entrance",
+        trigger: HintTrigger::OnMap(&[MapId::Mine, MapId::MineEntrance]),
         message: "Break rocks with your pickaxe. Watch your health!",
     },
     HintDef {
         id: "npc_nearby",
+        trigger: HintTrigger::OnMap(&[MapId::Town]),
         message: "Press F to talk to villagers. Give gifts to build friendship!",
     },
     HintDef {
         id: "rainy_day",
+        trigger: HintTrigger::RainyWeather,
         message: "Rain waters your crops automatically. Lucky!",
     },
     HintDef {
         id: "season_change",
+        trigger: HintTrigger::SeasonStart,
         message: "New season! Check which crops grow in this season.",
     },
     HintDef {
         id: "low_stamina",
+        trigger: HintTrigger::StaminaBelow(20.0),
         message: "Low energy! Eat food or go to bed early to recover.",
     },
     // Fix 4: Shipping bin hint
     HintDef {
         id: "shipping_bin",
+        trigger: HintTrigger::CropStageAtLeast(3),
         message: "Put items in the Shipping Bin near your house to sell them overnight for gold!",
     },
     // Fix 5: Inventory hint
     HintDef {
         id: "open_inventory",
+        trigger: HintTrigger::FirstMorning,
         message: "Press E to open your inventory and see your items and tools!",
     },
+    HintDef {
+        id: "inventory_tips",
+        trigger: HintTrigger::InventoryOpened,
+        message: "In your inventory, U uses the selected item and X twice throws it away.",
+    },
 ];
 
+/// Set the first time the inventory screen opens.
+const INVENTORY_OPENED_FLAG: &str = "inventory_opened";
+
+fn hint_triggered(
+    trigger: HintTrigger,
+    tutorial: &TutorialState,
+    player_state: &PlayerState,
+    calendar: &Calendar,
+    farm_state: &FarmState,
+) -> bool {
+    match trigger {
+        HintTrigger::OnMap(maps) => maps.contains(&player_state.current_map),
+        HintTrigger::RainyWeather => {
+            matches!(calendar.weather, Weather::Rainy | Weather::Stormy)
+        }
+        HintTrigger::SeasonStart => {
+            calendar.day == 1
+                && calendar.season != Season::Spring
+                && calendar.total_days_elapsed() > 0
+        }
+        HintTrigger::StaminaBelow(threshold) => player_state.stamina < threshold,
+        HintTrigger::CropStageAtLeast(stage) => {
+            farm_state.crops.values().any(|c| c.current_stage >= stage)
+        }
+        HintTrigger::FirstMorning => calendar.day == 1 && calendar.year == 1 && calendar.hour >= 7,
+        HintTrigger::InventoryOpened => tutorial_flag_is_set(tutorial, INVENTORY_OPENED_FLAG),
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // HELPER — check if a crop is fully grown
 // ═══════════════════════════════════════════════════════════════════════
@@ -65,17 +126,12 @@ fn is_crop_ready(crop_tile: &CropTile, crop_registry: &CropRegistry) -> bool {
 // SYSTEM — check contextual hints each frame
 // ═══════════════════════════════════════════════════════════════════════
 
-#[allow(clippy::too_many_arguments)]
 pub fn check_tutorial_hints(
     mut tutorial: ResMut<TutorialState>,
     mut hint_writer: EventWriter<HintEvent>,
     player_state: Res<PlayerState>,
     calendar: Res<Calendar>,
-    #[allow(unused)] inventory: Res<Inventory>,
-    #[allow(unused)] farm_state: Res<FarmState>,
-    #[allow(unused)] crop_registry: Res<CropRegistry>,
-    #[allow(unused)] item_registry: Res<ItemRegistry>,
-    #[allow(unused)] play_stats: Res<PlayStats>,
+    farm_state: Res<FarmState>,
 ) {
     if tutorial.tutorial_complete {
         return;
@@ -88,26 +144,13 @@ pub fn check_tutorial_hints(
             continue;
         }
 
-        let triggered = match hint.id {
-            "mine_entrance" => {
-                matches!(player_state.current_map, MapId::Mine | MapId::MineEntrance)
-            }
-            "npc_nearby" => player_state.current_map == MapId::Town,
-            "rainy_day" => {
-                calendar.weather == Weather::Rainy && player_state.current_map == MapId::Farm
-            }
-            "season_change" => {
-                calendar.day == 1
-                    && calendar.season != Season::Spring
-                    && calendar.total_days_elapsed() > 0
-            }
-            "low_stamina" => player_state.stamina < 20.0,
-            // Fix 4: Shipping bin hint — triggers when any crop is nearing maturity
-            "shipping_bin" => farm_state.crops.values().any(|c| c.current_stage >= 3),
-            // Fix 5: Inventory hint — triggers on Day 1 morning
-            "open_inventory" => calendar.day == 1 && calendar.year == 1 && calendar.hour >= 7,
-            _ => false,
-        };
+        let triggered = hint_triggered(
+            hint.trigger,
+            &tutorial,
+            &player_state,
+            &calendar,
+            &farm_state,
+        );
 
         if triggered {
             newly_shown.push(hint.id.to_string());
@@ -131,6 +174,12 @@ pub fn check_tutorial_hints(
     }
 }
 
+/// Runs on entering the inventory screen so the follow-up hint can fire once
+/// the player is back in the world.
+pub fn record_inventory_opened(mut tutorial: ResMut<TutorialState>) {
+    set_tutorial_flag(&mut tutorial, INVENTORY_OPENED_FLAG);
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // SYSTEM — forward HintEvent to ToastEvent
 // ═══════════════════════════════════════════════════════════════════════
@@ -342,8 +391,8 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_hints_table_has_7_entries() {
-        assert_eq!(HINTS.len(), 7);
+    fn test_hints_table_has_8_entries() {
+        assert_eq!(HINTS.len(), 8);
     }
 
     #[test]
@@ -425,6 +474,67 @@ mod tests {
         assert!(!is_crop_ready(&crop_tile, &registry));
     }
 
+    fn hint_test_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<TutorialState>()
+            .init_resource::<PlayerState>()
+            .init_resource::<Calendar>()
+            .init_resource::<FarmState>()
+            .add_event::<HintEvent>()
+            .add_systems(Update, check_tutorial_hints);
+        app
+    }
+
+    fn fired_hints(app: &App) -> Vec<String> {
+        app.world()
+            .resource::<Events<HintEvent>>()
+            .iter_current_update_events()
+            .map(|e| e.hint_id.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_low_stamina_hint_fires_once_and_is_recorded() {
+        let mut app = hint_test_app();
+        app.world_mut().resource_mut::<PlayerState>().stamina = 10.0;
+
+        app.update();
+        assert_eq!(fired_hints(&app), vec!["low_stamina".to_string()]);
+
+        app.update();
+        assert!(fired_hints(&app).is_empty());
+
+        let tutorial = app.world().resource::<TutorialState>();
+        let recorded = tutorial
+            .hints_shown
+            .iter()
+            .filter(|id| *id == "low_stamina")
+            .count();
+        assert_eq!(recorded, 1);
+    }
+
+    #[test]
+    fn test_inventory_tips_wait_for_first_inventory_open() {
+        let mut app = hint_test_app();
+        app.update();
+        assert!(!fired_hints(&app).contains(&"inventory_tips".to_string()));
+
+        app.add_systems(PostUpdate, record_inventory_opened);
+        app.update();
+        app.update();
+        assert_eq!(fired_hints(&app), vec!["inventory_tips".to_string()]);
+    }
+
+    #[test]
+    fn test_rain_hint_fires_away_from_the_farm() {
+        let mut app = hint_test_app();
+        app.world_mut().resource_mut::<PlayerState>().current_map = MapId::Beach;
+        app.world_mut().resource_mut::<Calendar>().weather = Weather::Stormy;
+
+        app.update();
+        assert_eq!(fired_hints(&app), vec!["rainy_day".to_string()]);
+    }
+
     #[test]
     fn test_tutorial_state_default_not_complete() {
         let state = TutorialState::default();
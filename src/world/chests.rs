@@ -168,8 +168,9 @@ pub fn place_chest(
     }
 
     // Spawn the chest entity with a brown placeholder sprite.
-    let world_x = target_x as f32 * TILE_SIZE + TILE_SIZE * 0.5;
-    let world_y = target_y as f32 * TILE_SIZE + TILE_SIZE * 0.5;
+    let wc = grid_to_world_center(target_x, target_y);
+    let world_x = wc.x;
+    let world_y = wc.y;
 
     let chest_sprite = if chest_sprites.loaded {
         let mut s = Sprite::from_atlas_image(
@@ -214,16 +215,15 @@ pub fn place_chest(
 // CHEST INTERACTION
 // ═══════════════════════════════════════════════════════════════════════
 
-/// When the player presses F near a chest entity (within 2 tiles),
-/// open the chest by setting ChestInteraction.entity.
+/// When an interact press resolves to a chest, open it by setting
+/// ChestInteraction.entity.
 pub fn interact_with_chest(
     player_input: Res<PlayerInput>,
     mut input_blocks: ResMut<InputBlocks>,
     mut chest_interaction: ResMut<ChestInteraction>,
-    player_query: Query<&Transform, With<Player>>,
-    chest_query: Query<(Entity, &Transform), With<ChestMarker>>,
     player_state: Res<PlayerState>,
     interaction_claimed: Res<InteractionClaimed>,
+    target: Res<InteractionTarget>,
 ) {
     // Don't open another chest if one is already open.
     if chest_interaction.is_open() {
@@ -248,24 +248,7 @@ pub fn interact_with_chest(
         return;
     }
 
-    let Ok(player_transform) = player_query.get_single() else {
-        return;
-    };
-
-    let player_pos = player_transform.translation.truncate();
-    let interact_range = TILE_SIZE * 2.0;
-
-    // Find the closest chest within interaction range.
-    let mut closest: Option<(Entity, f32)> = None;
-    for (entity, chest_transform) in chest_query.iter() {
-        let chest_pos = chest_transform.translation.truncate();
-        let dist = player_pos.distance(chest_pos);
-        if dist <= interact_range && closest.is_none_or(|(_, d)| dist < d) {
-            closest = Some((entity, dist));
-        }
-    }
-
-    if let Some((entity, _)) = closest {
+    if let Some(entity) = target.entity_of(InteractTargetKind::Chest) {
         chest_interaction.entity = Some(entity);
         input_blocks.block::<ChestOverlayInputBlock>();
         info!("[Chest] Opened chest {:?}", entity);
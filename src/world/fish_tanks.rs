@@ -0,0 +1,315 @@
+//! Fish tank furniture for the world domain.
+//!
+//! A fish tank is a placeable trophy case: the player sets one down on the
+//! farm, then faces it with a caught fish selected and presses F to drop the
+//! fish inside. Stored fish are drawn as small item icons within the tank.
+
+use crate::shared::*;
+use bevy::prelude::*;
+
+use super::objects::ObjectAtlases;
+
+/// How many fish a single tank can display.
+pub const FISH_TANK_CAPACITY: usize = 6;
+
+/// Item id of the placeable tank.
+pub const FISH_TANK_ITEM: &str = "fish_tank";
+
+// ═══════════════════════════════════════════════════════════════════════
+// COMPONENTS
+// ═══════════════════════════════════════════════════════════════════════
+
+/// A placed fish tank and the fish item ids on display inside it.
+#[derive(Component, Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct FishTank {
+    pub fish: Vec<ItemId>,
+    pub grid_pos: (i32, i32),
+}
+
+impl FishTank {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self {
+            fish: Vec::new(),
+            grid_pos: (x, y),
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.fish.len() >= FISH_TANK_CAPACITY
+    }
+
+    /// Put a fish on display. Returns false (and stores nothing) when full.
+    pub fn try_add(&mut self, fish_id: &str) -> bool {
+        if self.is_full() {
+            return false;
+        }
+        self.fish.push(fish_id.to_string());
+        true
+    }
+}
+
+/// Child sprite showing one stored fish inside a tank.
+#[derive(Component, Debug)]
+pub struct FishTankFishSprite;
+
+/// Spawn a fish tank entity. Fish icons are added by `sync_fish_tank_sprites`
+/// once the entity exists, so this is shared by placement and save loading.
+pub fn spawn_fish_tank(commands: &mut Commands, tank: FishTank) -> Entity {
+    let wc = grid_to_world_center(tank.grid_pos.0, tank.grid_pos.1);
+    commands
+        .spawn((
+            tank,
+            Sprite {
+                color: Color::srgba(0.35, 0.62, 0.85, 0.85),
+                custom_size: Some(Vec2::new(TILE_SIZE, TILE_SIZE)),
+                ..default()
+            },
+            Transform::from_translation(Vec3::new(wc.x, wc.y, Z_ENTITY_BASE)),
+            LogicalPosition(wc),
+            YSorted,
+        ))
+        .id()
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// PLACEMENT
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Places a fish tank on the faced farm tile when R is pressed with a
+/// "fish_tank" item selected.
+#[allow(clippy::too_many_arguments)]
+pub fn place_fish_tank(
+    player_input: Res<PlayerInput>,
+    input_blocks: Res<InputBlocks>,
+    mut commands: Commands,
+    mut inventory: ResMut<Inventory>,
+    player_state: Res<PlayerState>,
+    farm_state: Res<FarmState>,
+    tank_query: Query<&FishTank>,
+    player_query: Query<(&LogicalPosition, &PlayerMovement), With<Player>>,
+    mut sfx_events: EventWriter<PlaySfxEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
+) {
+    if input_blocks.is_blocked() || !player_input.tool_secondary {
+        return;
+    }
+
+    let selected = inventory.selected_slot;
+    let has_tank_item = inventory
+        .slots
+        .get(selected)
+        .and_then(|s| s.as_ref())
+        .is_some_and(|slot| slot.item_id == FISH_TANK_ITEM);
+    if !has_tank_item {
+        return;
+    }
+
+    if player_state.current_map != MapId::Farm {
+        toast_events.send(ToastEvent {
+            message: "Fish tanks can only be placed on the farm.".into(),
+            duration_secs: 2.0,
+        });
+        return;
+    }
+
+    let Ok((logical_pos, movement)) = player_query.get_single() else {
+        return;
+    };
+    let (target_x, target_y) = player_target_tile(logical_pos.0, movement.facing);
+
+    if farm_state.crops.contains_key(&(target_x, target_y))
+        || farm_state.objects.contains_key(&(target_x, target_y))
+        || tank_query
+            .iter()
+            .any(|t| t.grid_pos == (target_x, target_y))
+    {
+        info!(
+            "[FishTank] Cannot place fish tank — tile ({}, {}) is occupied",
+            target_x, target_y
+        );
+        return;
+    }
+
+    if inventory.try_remove(FISH_TANK_ITEM, 1) == 0 {
+        return;
+    }
+
+    spawn_fish_tank(&mut commands, FishTank::new(target_x, target_y));
+
+    sfx_events.send(PlaySfxEvent {
+        sfx_id: "place".to_string(),
+    });
+    toast_events.send(ToastEvent {
+        message: "Fish tank placed!".into(),
+        duration_secs: 2.0,
+    });
+    info!(
+        "[FishTank] Placed fish tank at ({}, {})",
+        target_x, target_y
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// STOCKING
+// ═══════════════════════════════════════════════════════════════════════
+
+/// When an interact press resolves to a fish tank, move the selected fish
+/// from the inventory into the tank. With no fish selected, report what
+/// the tank holds.
+#[allow(clippy::too_many_arguments)]
+pub fn stock_fish_tank(
+    player_input: Res<PlayerInput>,
+    input_blocks: Res<InputBlocks>,
+    mut interaction_claimed: ResMut<InteractionClaimed>,
+    mut inventory: ResMut<Inventory>,
+    item_registry: Res<ItemRegistry>,
+    target: Res<InteractionTarget>,
+    mut tank_query: Query<&mut FishTank>,
+    mut removed_events: EventWriter<ItemRemovedEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
+) {
+    if input_blocks.is_blocked() || !player_input.interact || interaction_claimed.0 {
+        return;
+    }
+
+    let Some(mut tank) = target
+        .entity_of(InteractTargetKind::FishTank)
+        .and_then(|entity| tank_query.get_mut(entity).ok())
+    else {
+        return;
+    };
+    interaction_claimed.0 = true;
+
+    let selected_fish = inventory
+        .slots
+        .get(inventory.selected_slot)
+        .and_then(|s| s.as_ref())
+        .and_then(|slot| item_registry.get(&slot.item_id))
+        .filter(|def| def.category == ItemCategory::Fish)
+        .map(|def| (def.id.clone(), def.name.clone()));
+
+    let Some((fish_id, fish_name)) = selected_fish else {
+        toast_events.send(ToastEvent {
+            message: format!(
+                "Fish tank: {}/{} fish. Select a fish to add it.",
+                tank.fish.len(),
+                FISH_TANK_CAPACITY
+            ),
+            duration_secs: 2.5,
+        });
+        return;
+    };
+
+    if tank.is_full() {
+        toast_events.send(ToastEvent {
+            message: "This fish tank is full.".into(),
+            duration_secs: 2.0,
+        });
+        return;
+    }
+
+    if inventory.try_remove(&fish_id, 1) == 0 {
+        return;
+    }
+    tank.try_add(&fish_id);
+    removed_events.send(ItemRemovedEvent {
+        item_id: fish_id,
+        quantity: 1,
+    });
+    toast_events.send(ToastEvent {
+        message: format!(
+            "Added {} to the fish tank ({}/{}).",
+            fish_name,
+            tank.fish.len(),
+            FISH_TANK_CAPACITY
+        ),
+        duration_secs: 2.0,
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// RENDERING
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Rebuild the fish icons of any tank whose contents changed (including
+/// freshly placed or loaded tanks). Icons sit in a 3×2 grid inside the tank.
+pub fn sync_fish_tank_sprites(
+    mut commands: Commands,
+    tank_query: Query<(Entity, &FishTank, Option<&Children>), Changed<FishTank>>,
+    icon_query: Query<(), With<FishTankFishSprite>>,
+    item_registry: Res<ItemRegistry>,
+    object_atlases: Res<ObjectAtlases>,
+) {
+    const COLUMNS: usize = 3;
+    let icon_size = TILE_SIZE * 0.3;
+
+    for (entity, tank, children) in tank_query.iter() {
+        if let Some(children) = children {
+            for &child in children.iter() {
+                if icon_query.contains(child) {
+                    commands.entity(child).despawn_recursive();
+                }
+            }
+        }
+
+        for (i, fish_id) in tank.fish.iter().enumerate() {
+            let col = (i % COLUMNS) as f32;
+            let row = (i / COLUMNS) as f32;
+            let x = (col - 1.0) * icon_size;
+            let y = (0.5 - row) * icon_size;
+
+            let sprite_index = item_registry.get(fish_id).map(|def| def.sprite_index);
+            let sprite = match sprite_index {
+                Some(index) if object_atlases.loaded => {
+                    let mut s = Sprite::from_atlas_image(
+                        object_atlases.item_icon_image.clone(),
+                        TextureAtlas {
+                            layout: object_atlases.item_icon_layout.clone(),
+                            index: index as usize,
+                        },
+                    );
+                    s.custom_size = Some(Vec2::splat(icon_size));
+                    s
+                }
+                _ => Sprite {
+                    color: Color::srgb(0.95, 0.6, 0.25),
+                    custom_size: Some(Vec2::splat(icon_size * 0.8)),
+                    ..default()
+                },
+            };
+
+            let icon = commands
+                .spawn((
+                    FishTankFishSprite,
+                    sprite,
+                    Transform::from_translation(Vec3::new(x, y, 0.1)),
+                ))
+                .id();
+            commands.entity(entity).add_child(icon);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fish_tank_fills_to_capacity_and_rejects_overflow() {
+        let mut tank = FishTank::new(4, 5);
+        for i in 0..FISH_TANK_CAPACITY {
+            assert!(
+                tank.try_add(&format!("fish_{}", i)),
+                "slot {} should fit",
+                i
+            );
+        }
+        assert!(tank.is_full());
+        assert!(
+            !tank.try_add("pufferfish"),
+            "a full tank must reject more fish"
+        );
+        assert_eq!(tank.fish.len(), FISH_TANK_CAPACITY);
+        assert!(!tank.fish.iter().any(|f| f == "pufferfish"));
+    }
+}
@@ -205,8 +205,9 @@ pub fn spawn_grass_decorations(
                 let offset_x = ((vh % 7) as f32 - 3.0) * 1.5; // -4.5 to +4.5 px
                 let offset_y = (((vh / 7) % 7) as f32 - 3.0) * 1.5;
 
-                let wx = x as f32 * TILE_SIZE + TILE_SIZE * 0.5 + offset_x;
-                let wy = y as f32 * TILE_SIZE + TILE_SIZE * 0.5 + offset_y;
+                let wc = grid_to_world_center(x as i32, y as i32);
+                let wx = wc.x + offset_x;
+                let wy = wc.y + offset_y;
 
                 let mut sprite = Sprite::from_atlas_image(
                     object_atlases.grass_biome_image.clone(),
@@ -4,6 +4,7 @@
 //! Maps also include transition zones and object spawn points.
 
 use crate::shared::*;
+use bevy::prelude::Resource;
 use serde::{Deserialize, Serialize};
 
 /// Complete definition of a game map.
@@ -59,6 +60,47 @@ pub enum WorldObjectKind {
     Driftwood,
 }
 
+// ═══════════════════════════════════════════════════════════════════════
+// FARM LAYOUTS
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Farm arrangement chosen at new-game time. Persisted in the save file and
+/// consulted whenever the Farm map is generated.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum FarmLayout {
+    /// The classic open field.
+    #[default]
+    Standard,
+    /// A stream and pond cut through the field — less soil, more fishing.
+    Riverland,
+    /// A smaller field ringed by trees with extra forage spots.
+    Forest,
+}
+
+impl FarmLayout {
+    pub const ALL: [FarmLayout; 3] = [
+        FarmLayout::Standard,
+        FarmLayout::Riverland,
+        FarmLayout::Forest,
+    ];
+
+    pub fn display_name(self) -> &'static str {
+        match self {
+            FarmLayout::Standard => "Standard Farm",
+            FarmLayout::Riverland => "Riverland Farm",
+            FarmLayout::Forest => "Forest Farm",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            FarmLayout::Standard => "Wide open soil for big harvests.",
+            FarmLayout::Riverland => "Streams and a pond. Great fishing, less soil.",
+            FarmLayout::Forest => "Woodland edges full of forageables.",
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // DEFAULT SPAWN POSITIONS
 // ═══════════════════════════════════════════════════════════════════════
@@ -93,8 +135,14 @@ pub fn default_spawn_position(map_id: MapId) -> (i32, i32) {
 // ═══════════════════════════════════════════════════════════════════════
 
 pub fn generate_map(map_id: MapId) -> MapDef {
+    generate_map_with_layout(map_id, FarmLayout::Standard)
+}
+
+/// Like [`generate_map`], but builds the Farm using the given layout variant.
+/// Every other map ignores `farm_layout`.
+pub fn generate_map_with_layout(map_id: MapId, farm_layout: FarmLayout) -> MapDef {
     match map_id {
-        MapId::Farm => generate_farm(),
+        MapId::Farm => generate_farm_layout(farm_layout),
         MapId::Town => generate_town(),
         MapId::TownWest => generate_town_west(),
         MapId::Beach => generate_beach(),
@@ -327,6 +375,92 @@ fn generate_farm() -> MapDef {
     }
 }
 
+/// Build the Farm map for a layout. Variants are overlays on the standard
+/// farm so exits, doors, and building footprints never move.
+fn generate_farm_layout(layout: FarmLayout) -> MapDef {
+    let mut farm = generate_farm();
+    match layout {
+        FarmLayout::Standard => {}
+        FarmLayout::Riverland => apply_riverland_layout(&mut farm),
+        FarmLayout::Forest => apply_forest_layout(&mut farm),
+    }
+    farm
+}
+
+/// Set every tile in the rectangle to `kind` (clipped to map bounds).
+fn set_tiles(map: &mut MapDef, x0: i32, y0: i32, rw: i32, rh: i32, kind: TileKind) {
+    for y in y0..y0 + rh {
+        for x in x0..x0 + rw {
+            if x >= 0 && y >= 0 && (x as usize) < map.width && (y as usize) < map.height {
+                map.tiles[y as usize * map.width + x as usize] = kind;
+            }
+        }
+    }
+}
+
+/// Drop object placements and forage points that ended up on water.
+fn clear_objects_on_water(map: &mut MapDef) {
+    let tiles = map.tiles.clone();
+    let width = map.width;
+    let on_water = |x: i32, y: i32| tiles[y as usize * width + x as usize] == TileKind::Water;
+    map.objects.retain(|o| !on_water(o.x, o.y));
+    map.forage_points.retain(|&(x, y)| !on_water(x, y));
+}
+
+fn push_objects(map: &mut MapDef, positions: &[(i32, i32)], kind: WorldObjectKind) {
+    for &(x, y) in positions {
+        map.objects.push(ObjectPlacement { x, y, kind });
+    }
+}
+
+/// Riverland: a stream down the east side of the field and a fishing pond
+/// in its south half.
+fn apply_riverland_layout(farm: &mut MapDef) {
+    // Stream (east side, below the forest path so it never blocks an exit)
+    set_tiles(farm, 26, 12, 2, 8, TileKind::Water);
+    // Pond in the lower half of the field
+    set_tiles(farm, 9, 12, 6, 4, TileKind::Water);
+    clear_objects_on_water(farm);
+
+    // Reeds and driftwood along the banks
+    push_objects(farm, &[(8, 12), (15, 15), (25, 18)], WorldObjectKind::Bush);
+    push_objects(farm, &[(28, 13), (12, 16)], WorldObjectKind::Driftwood);
+    farm.forage_points.extend([(8, 15), (16, 12), (28, 19)]);
+}
+
+/// Forest: the eastern strip of the field reverts to grass and the farm edges
+/// fill in with trees, a small spring, and extra forage spots.
+fn apply_forest_layout(farm: &mut MapDef) {
+    // Shrink the tillable field
+    set_tiles(farm, 24, 5, 4, 5, TileKind::Grass);
+    set_tiles(farm, 24, 12, 4, 5, TileKind::Grass);
+    // Woodland spring
+    set_tiles(farm, 28, 4, 3, 2, TileKind::Water);
+    clear_objects_on_water(farm);
+
+    push_objects(
+        farm,
+        &[(1, 13), (1, 15), (30, 13), (30, 16), (30, 19), (30, 22)],
+        WorldObjectKind::Tree,
+    );
+    push_objects(
+        farm,
+        &[
+            (4, 22),
+            (8, 22),
+            (20, 22),
+            (24, 22),
+            (26, 6),
+            (25, 12),
+            (26, 15),
+        ],
+        WorldObjectKind::Pine,
+    );
+    push_objects(farm, &[(27, 7), (24, 15)], WorldObjectKind::Log);
+    farm.forage_points
+        .extend([(25, 7), (27, 12), (26, 16), (2, 14), (29, 21), (21, 21)]);
+}
+
 // ---------------------------------------------------------------------------
 // Town map: 28x22  (Harvest Moon scale — ~2.8 screens)
 // Layout: shops top, plaza center, houses + blacksmith mid, park bottom
@@ -1975,6 +2109,55 @@ mod map_object_generation_tests {
         ));
         assert!(has_object(&island.objects, 18, 19, WorldObjectKind::Log));
     }
+
+    fn count_tiles(map: &MapDef, pred: impl Fn(TileKind) -> bool) -> usize {
+        map.tiles.iter().filter(|&&tile| pred(tile)).count()
+    }
+
+    #[test]
+    fn farm_layouts_have_distinct_water_and_solid_counts() {
+        let counts: Vec<(usize, usize)> = FarmLayout::ALL
+            .iter()
+            .map(|&layout| {
+                let farm = generate_map_with_layout(MapId::Farm, layout);
+                let water = count_tiles(&farm, |t| t == TileKind::Water);
+                let solid = count_tiles(&farm, |t| {
+                    matches!(t, TileKind::Water | TileKind::Void | TileKind::Stone)
+                });
+                (water, solid)
+            })
+            .collect();
+
+        for (i, a) in counts.iter().enumerate() {
+            for b in &counts[i + 1..] {
+                assert_ne!(a.0, b.0, "two layouts share a water count: {:?}", counts);
+                assert_ne!(a.1, b.1, "two layouts share a solid count: {:?}", counts);
+            }
+        }
+        // Riverland is the fishing layout.
+        assert!(counts[1].0 > counts[0].0 && counts[1].0 > counts[2].0);
+    }
+
+    #[test]
+    fn farm_layouts_keep_objects_off_water() {
+        for layout in FarmLayout::ALL {
+            let farm = generate_map_with_layout(MapId::Farm, layout);
+            for o in &farm.objects {
+                assert_ne!(farm.get_tile(o.x, o.y), TileKind::Water, "{:?}", layout);
+            }
+            for &(x, y) in &farm.forage_points {
+                assert_ne!(farm.get_tile(x, y), TileKind::Water, "{:?}", layout);
+            }
+        }
+    }
+
+    #[test]
+    fn standard_layout_matches_default_farm() {
+        let standard = generate_map_with_layout(MapId::Farm, FarmLayout::Standard);
+        let default = generate_map(MapId::Farm);
+        assert_eq!(standard.tiles, default.tiles);
+        assert_eq!(standard.objects.len(), default.objects.len());
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -8,18 +8,23 @@
 //! - Forageable spawning per season/day
 //! - Seasonal visual changes
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use std::collections::HashSet;
 
+use crate::farming::{respawn_farm_entities, FarmEntities};
 use crate::shared::*;
 
 pub mod chests;
+pub mod fish_tanks;
 pub mod grass_decor;
 pub mod lighting;
 pub mod map_data;
 pub mod maps;
 pub mod objects;
+pub mod pathfind;
 pub mod seasonal;
+pub mod teardown;
 pub mod tree_fx;
 pub mod weather_fx;
 pub mod ysort;
@@ -30,13 +35,14 @@ use lighting::{
     update_lightning_flash, LightningFlash,
 };
 use map_data::MapRegistry;
-use maps::{generate_map, MapDef};
+use maps::{generate_map, generate_map_with_layout, FarmLayout, MapDef};
 use objects::{
-    animate_bush_rustle, animate_doors, animate_wind_sway, handle_forageable_pickup,
-    handle_tool_use_on_objects, handle_weed_scythe, regrow_trees_on_season_change,
-    spawn_building_signs, spawn_building_sprites, spawn_carpenter_board, spawn_chimney_smoke,
-    spawn_crafting_bench, spawn_daily_weeds, spawn_farm_decorations, spawn_forageables,
-    spawn_interior_decorations, spawn_shipping_bin, spawn_world_objects, update_candle_flicker,
+    animate_bush_rustle, animate_doors, animate_wind_sway, handle_building_move,
+    handle_forageable_pickup, handle_tool_use_on_objects, handle_weed_scythe,
+    regrow_trees_on_season_change, spawn_building_signs, spawn_building_sprites,
+    spawn_carpenter_board, spawn_chimney_smoke, spawn_crafting_bench, spawn_daily_weeds,
+    spawn_farm_decorations, spawn_forageables, spawn_interior_decorations, spawn_mailbox,
+    spawn_quest_board, spawn_shipping_bin, spawn_world_objects, update_candle_flicker,
     update_chimney_smoke, update_forage_sparkles, update_tree_sprites_on_season_change,
     ChimneySmokeTimer, WorldObject,
 };
@@ -50,8 +56,8 @@ use tree_fx::{
 };
 use weather_fx::{
     cleanup_all_weather_particles, cleanup_weather_on_change, spawn_weather_particles,
-    update_weather_particles, weather_change_notification, PreviousWeather, WeatherParticleCounts,
-    WeatherSprites,
+    update_weather_particles, weather_change_notification, PreviousWeather, WeatherParticleConfig,
+    WeatherParticleCounts, WeatherSprites,
 };
 
 // ═══════════════════════════════════════════════════════════════════════
@@ -65,6 +71,7 @@ impl Plugin for WorldPlugin {
         app.insert_resource(map_data::build_map_registry())
             .init_resource::<WorldMap>()
             .init_resource::<CurrentMapId>()
+            .init_resource::<FarmLayout>()
             .init_resource::<TerrainAtlases>()
             .init_resource::<objects::ObjectAtlases>()
             .init_resource::<objects::FurnitureAtlases>()
@@ -79,6 +86,7 @@ impl Plugin for WorldPlugin {
             .init_resource::<LightningFlash>()
             .init_resource::<PreviousWeather>()
             .init_resource::<WeatherParticleCounts>()
+            .init_resource::<WeatherParticleConfig>()
             .init_resource::<WeatherSprites>()
             .init_resource::<GrassDecorState>()
             .init_resource::<ChimneySmokeTimer>()
@@ -97,16 +105,25 @@ impl Plugin for WorldPlugin {
                 OnExit(GameState::Playing),
                 (despawn_day_night_overlay, cleanup_all_weather_particles),
             )
+            // Quit to title from any in-game state (usually Paused)
+            .add_systems(Update, teardown::handle_return_to_title)
+            // Debounce map transition requests before anything reacts to them
+            .add_systems(PreUpdate, honor_map_transitions)
             // Gameplay systems: tool interactions, transitions, forageables
             .add_systems(
                 Update,
                 (
                     handle_map_transition,
+                    relocate_blocked_spawn
+                        .after(handle_map_transition)
+                        .after(crate::player::interaction::handle_map_transition),
                     handle_tool_use_on_objects,
                     handle_forageable_pickup,
                     chests::place_chest,
                     chests::interact_with_chest,
                     chests::close_chest_on_escape,
+                    fish_tanks::place_fish_tank,
+                    fish_tanks::stock_fish_tank.before(chests::interact_with_chest),
                     // Weed scythe clearing
                     handle_weed_scythe,
                     // Tree axe-hit feedback triggers (read events, spawn VFX)
@@ -167,8 +184,11 @@ impl Plugin for WorldPlugin {
                     // Interactable object spawning (shipping bin, crafting bench, etc.)
                     spawn_shipping_bin,
                     spawn_crafting_bench,
+                    spawn_mailbox,
                     spawn_carpenter_board,
+                    spawn_quest_board,
                     spawn_building_signs,
+                    handle_building_move.before(spawn_building_sprites),
                     spawn_building_sprites,
                     spawn_farm_decorations,
                     spawn_interior_decorations,
@@ -183,6 +203,8 @@ impl Plugin for WorldPlugin {
                 (
                     // Subtle pulse on nearby interactable objects
                     highlight_nearby_interactables,
+                    // Fish icons inside placed fish tanks
+                    fish_tanks::sync_fish_tank_sprites,
                 )
                     .in_set(UpdatePhase::Presentation)
                     .run_if(in_state(GameState::Playing)),
@@ -685,6 +707,38 @@ impl WorldMap {
         }
     }
 
+    /// Find the walkable tile closest to `(x, y)`, searching outward in
+    /// square rings. Returns `(x, y)` itself when it is already walkable,
+    /// or `None` if the map has no walkable tile at all.
+    pub fn nearest_walkable(&self, x: i32, y: i32) -> Option<(i32, i32)> {
+        if self.is_walkable(x, y) {
+            return Some((x, y));
+        }
+        let max_radius = self.width.max(self.height) as i32;
+        for radius in 1..=max_radius {
+            let mut best: Option<((i32, i32), i32)> = None;
+            for dy in -radius..=radius {
+                for dx in -radius..=radius {
+                    if dx.abs() != radius && dy.abs() != radius {
+                        continue;
+                    }
+                    let (tx, ty) = (x + dx, y + dy);
+                    if !self.is_walkable(tx, ty) {
+                        continue;
+                    }
+                    let dist_sq = dx * dx + dy * dy;
+                    if best.is_none_or(|(_, d)| dist_sq < d) {
+                        best = Some(((tx, ty), dist_sq));
+                    }
+                }
+            }
+            if let Some((tile, _)) = best {
+                return Some(tile);
+            }
+        }
+        None
+    }
+
     /// Check if a tile is solid (object or terrain).
     pub fn is_solid(&self, x: i32, y: i32) -> bool {
         !self.is_walkable(x, y)
@@ -802,6 +856,28 @@ fn tile_color(kind: TileKind, season: Season) -> Color {
 // MAP LOADING AND RENDERING
 // ═══════════════════════════════════════════════════════════════════════
 
+/// Farming-domain state needed to tear down and rebuild soil/crop/object
+/// entities, which live outside `MapTile`/`WorldObject`.
+#[derive(SystemParam)]
+struct FarmVisuals<'w> {
+    farm_entities: ResMut<'w, FarmEntities>,
+    farm_state: ResMut<'w, FarmState>,
+    crop_registry: Res<'w, CropRegistry>,
+}
+
+/// The definition a map loads from. Non-standard farm layouts are generated;
+/// otherwise prefer the data-driven map from registry and fall back to the
+/// hardcoded generator.
+pub fn resolve_map_def(map_id: MapId, registry: &MapRegistry, farm_layout: FarmLayout) -> MapDef {
+    if map_id == MapId::Farm && farm_layout != FarmLayout::Standard {
+        generate_map_with_layout(map_id, farm_layout)
+    } else if let Some(data) = registry.maps.get(&map_id) {
+        map_data::map_data_to_map_def(data)
+    } else {
+        generate_map(map_id)
+    }
+}
+
 /// Load a map by ID: populate WorldMap resource and spawn tile entities.
 #[allow(clippy::too_many_arguments)]
 fn load_map(
@@ -815,13 +891,10 @@ fn load_map(
     item_registry: &ItemRegistry,
     object_atlases: &objects::ObjectAtlases,
     registry: &MapRegistry,
+    farm_layout: FarmLayout,
+    farm_visuals: &mut FarmVisuals,
 ) {
-    // Prefer data-driven map from registry; fall back to hardcoded generator.
-    let map_def = if let Some(data) = registry.maps.get(&map_id) {
-        map_data::map_data_to_map_def(data)
-    } else {
-        generate_map(map_id)
-    };
+    let map_def = resolve_map_def(map_id, registry, farm_layout);
 
     // Update tracking
     current_map_id.map_id = map_id;
@@ -907,10 +980,9 @@ fn load_map(
     );
 
     // Spawn forageables for today
-    let forage_points = map_def.forage_points.clone();
     spawn_forageables(
         commands,
-        &forage_points,
+        &map_def,
         season,
         day,
         world_map,
@@ -918,6 +990,17 @@ fn load_map(
         object_atlases,
     );
 
+    // Crops and soil persist in FarmState while the player is away; rebuild
+    // their entities instead of relying on the static map definition.
+    if map_id == MapId::Farm {
+        respawn_farm_entities(
+            commands,
+            &mut farm_visuals.farm_entities,
+            &mut farm_visuals.farm_state,
+            &farm_visuals.crop_registry,
+        );
+    }
+
     // Store the map definition
     world_map.map_def = Some(map_def);
 }
@@ -1122,11 +1205,12 @@ fn spawn_water_edge_overlays(
     }
 }
 
-/// Despawn all map tiles and world objects.
+/// Despawn all map tiles, world objects, and farm visuals.
 fn despawn_map(
     commands: &mut Commands,
     tile_query: &Query<Entity, With<MapTile>>,
     object_query: &Query<Entity, With<WorldObject>>,
+    farm_entities: &mut FarmEntities,
 ) {
     for entity in tile_query.iter() {
         commands.entity(entity).despawn_recursive();
@@ -1134,6 +1218,7 @@ fn despawn_map(
     for entity in object_query.iter() {
         commands.entity(entity).despawn_recursive();
     }
+    farm_entities.despawn_all(commands);
 }
 
 /// Animate water tiles by cycling through 4 atlas frames, and pulse edge overlay alpha.
@@ -1191,6 +1276,8 @@ fn spawn_initial_map(
     mut furniture_atlases: ResMut<objects::FurnitureAtlases>,
     existing_tiles: Query<Entity, With<MapTile>>,
     registry: Res<MapRegistry>,
+    farm_layout: Res<FarmLayout>,
+    mut farm_visuals: FarmVisuals,
 ) {
     // Guard against re-entry (e.g. Playing → Cutscene → Playing).
     if !existing_tiles.is_empty() {
@@ -1219,14 +1306,37 @@ fn spawn_initial_map(
         &item_registry,
         &object_atlases,
         &registry,
+        *farm_layout,
+        &mut farm_visuals,
     );
 }
 
-/// Handle MapTransitionEvent: despawn current map, load new one.
+/// Forwards at most one `MapTransitionEvent` per frame as a
+/// `HonoredMapTransitionEvent`. Overlapping transition zones can fire several
+/// in one frame, and a zone the player is still touching fires again right
+/// after a load; both are dropped here so every domain agrees on what loads.
+pub fn honor_map_transitions(
+    mut requests: EventReader<MapTransitionEvent>,
+    frame: Res<bevy::core::FrameCount>,
+    mut cooldown: Local<TransitionCooldown>,
+    mut honored: EventWriter<HonoredMapTransitionEvent>,
+) {
+    let first = requests.read().next().cloned();
+    requests.clear();
+    if let Some(ev) = first.filter(|_| cooldown.try_claim(frame.0)) {
+        honored.send(HonoredMapTransitionEvent {
+            to_map: ev.to_map,
+            to_x: ev.to_x,
+            to_y: ev.to_y,
+        });
+    }
+}
+
+/// Handle HonoredMapTransitionEvent: despawn current map, load new one.
 #[allow(clippy::too_many_arguments)]
 fn handle_map_transition(
     mut commands: Commands,
-    mut events: EventReader<MapTransitionEvent>,
+    mut events: EventReader<HonoredMapTransitionEvent>,
     tile_query: Query<Entity, With<MapTile>>,
     object_query: Query<Entity, With<WorldObject>>,
     mut world_map: ResMut<WorldMap>,
@@ -1239,15 +1349,23 @@ fn handle_map_transition(
     mut object_atlases: ResMut<objects::ObjectAtlases>,
     mut furniture_atlases: ResMut<objects::FurnitureAtlases>,
     registry: Res<MapRegistry>,
+    farm_layout: Res<FarmLayout>,
+    mut farm_visuals: FarmVisuals,
 ) {
-    for event in events.read() {
+    // At most one transition is honored per frame.
+    if let Some(event) = events.read().last() {
         // Don't transition to the same map
         if event.to_map == current_map_id.map_id {
-            continue;
+            return;
         }
 
         // Despawn current map
-        despawn_map(&mut commands, &tile_query, &object_query);
+        despawn_map(
+            &mut commands,
+            &tile_query,
+            &object_query,
+            &mut farm_visuals.farm_entities,
+        );
 
         // Ensure atlases are loaded (in case they weren't yet)
         ensure_atlases_loaded(&asset_server, &mut atlas_layouts, &mut terrain_atlases);
@@ -1274,10 +1392,45 @@ fn handle_map_transition(
             &item_registry,
             &object_atlases,
             &registry,
+            *farm_layout,
+            &mut farm_visuals,
         );
     }
 }
 
+/// After a transition has loaded the destination map and moved the player,
+/// make sure they did not land inside something solid (e.g. an object that
+/// now sits on a doorway). If so, nudge them to the nearest walkable tile.
+pub fn relocate_blocked_spawn(
+    mut events: EventReader<HonoredMapTransitionEvent>,
+    world_map: Res<WorldMap>,
+    mut query: Query<(&mut LogicalPosition, &mut GridPosition), With<Player>>,
+) {
+    if events.read().last().is_none() {
+        return;
+    }
+    let Ok((mut logical_pos, mut grid_pos)) = query.get_single_mut() else {
+        return;
+    };
+    if world_map.is_walkable(grid_pos.x, grid_pos.y) {
+        return;
+    }
+    let Some((x, y)) = world_map.nearest_walkable(grid_pos.x, grid_pos.y) else {
+        warn!(
+            "[World] No walkable tile near spawn ({}, {}); leaving player in place",
+            grid_pos.x, grid_pos.y
+        );
+        return;
+    };
+    info!(
+        "[World] Spawn ({}, {}) is blocked; relocating player to ({}, {})",
+        grid_pos.x, grid_pos.y, x, y
+    );
+    grid_pos.x = x;
+    grid_pos.y = y;
+    logical_pos.0 = grid_to_world_center(x, y);
+}
+
 /// Handle DayEndEvent: despawn old forageables and spawn new ones.
 fn handle_day_end_forageables(
     mut commands: Commands,
@@ -1295,10 +1448,9 @@ fn handle_day_end_forageables(
 
         // Spawn new forageables for the new day
         if let Some(ref map_def) = world_map.map_def {
-            let forage_points = map_def.forage_points.clone();
             spawn_forageables(
                 &mut commands,
-                &forage_points,
+                map_def,
                 event.season,
                 event.day,
                 &world_map,
@@ -1397,21 +1549,22 @@ fn handle_season_change(
 // INTERACTABLE HIGHLIGHT — subtle pulse on nearby interactable objects
 // ═══════════════════════════════════════════════════════════════════════
 
-/// Gently brighten interactable sprites when the player is within interaction range.
+/// Gently brighten interactable sprites when the player is within interaction
+/// range and facing them, so the highlight matches what F would trigger.
 pub fn highlight_nearby_interactables(
     time: Res<Time>,
-    player_query: Query<&LogicalPosition, With<Player>>,
+    player_query: Query<(&LogicalPosition, &PlayerMovement), With<Player>>,
     mut interactable_query: Query<(&Transform, &mut Sprite), With<Interactable>>,
 ) {
-    let Ok(player_pos) = player_query.get_single() else {
+    let Ok((player_pos, movement)) = player_query.get_single() else {
         return;
     };
     let range = TILE_SIZE * 1.8;
     let pulse = 1.0 + 0.15 * (time.elapsed_secs() * 3.0).sin().abs();
 
     for (tf, mut sprite) in &mut interactable_query {
-        let d = player_pos.0.distance(tf.translation.truncate());
-        if d <= range {
+        let target = tf.translation.truncate();
+        if can_interact_facing(player_pos.0, movement.facing, target, range) {
             sprite.color = Color::srgb(pulse, pulse, pulse);
         } else {
             sprite.color = Color::WHITE;
@@ -1460,4 +1613,56 @@ mod tests {
 
         assert_eq!(index, 544);
     }
+
+    #[test]
+    fn transition_onto_solid_spawn_relocates_to_walkable_tile() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<HonoredMapTransitionEvent>()
+            .add_systems(Update, relocate_blocked_spawn);
+
+        // 5x5 grass map whose nominal spawn (2, 2) is now covered by an
+        // object, with the tile directly below it also blocked.
+        let mut world_map = WorldMap {
+            map_def: Some(MapDef {
+                id: MapId::Town,
+                width: 5,
+                height: 5,
+                tiles: vec![TileKind::Grass; 25],
+                transitions: Vec::new(),
+                objects: Vec::new(),
+                forage_points: Vec::new(),
+            }),
+            width: 5,
+            height: 5,
+            ..default()
+        };
+        world_map.set_solid(2, 2, true);
+        world_map.set_solid(2, 1, true);
+        app.insert_resource(world_map);
+
+        // The player domain has already moved the player onto the spawn tile.
+        app.world_mut().spawn((
+            Player,
+            GridPosition::new(2, 2),
+            LogicalPosition(grid_to_world_center(2, 2)),
+        ));
+        app.world_mut().send_event(HonoredMapTransitionEvent {
+            to_map: MapId::Town,
+            to_x: 2,
+            to_y: 2,
+        });
+        app.update();
+
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<(&LogicalPosition, &GridPosition), With<Player>>();
+        let (logical_pos, grid_pos) = query.single(world);
+        let landed = (grid_pos.x, grid_pos.y);
+        assert_ne!(landed, (2, 2), "player must not stay inside the object");
+        let world_map = world.resource::<WorldMap>();
+        assert!(world_map.is_walkable(landed.0, landed.1));
+        let (dx, dy) = (landed.0 - 2, landed.1 - 2);
+        assert_eq!(dx.abs() + dy.abs(), 1, "should pick an adjacent open tile");
+        assert_eq!(logical_pos.0, grid_to_world_center(landed.0, landed.1));
+    }
 }
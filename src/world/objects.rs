@@ -7,7 +7,7 @@ use crate::shared::*;
 use bevy::prelude::*;
 use rand::Rng;
 
-use super::maps::{ObjectPlacement, WorldObjectKind};
+use super::maps::{MapDef, ObjectPlacement, WorldObjectKind};
 use super::WorldMap;
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -345,6 +345,14 @@ pub struct CarpenterBoardMarker;
 #[derive(Component, Debug)]
 pub struct CraftingBenchMarker;
 
+/// Marker for the farm mailbox interactable entity.
+#[derive(Component, Debug)]
+pub struct MailboxMarker;
+
+/// Marker for the town quest board interactable entity.
+#[derive(Component, Debug)]
+pub struct QuestBoardMarker;
+
 /// Tracks the kind, health, and grid position of a world object.
 #[derive(Component, Debug, Clone)]
 pub struct WorldObjectData {
@@ -815,7 +823,14 @@ fn tool_display_name(tool: ToolKind) -> &'static str {
     }
 }
 
+/// Whether `tool` can damage a world object of `kind`: axes chop trees, stumps
+/// and wood, pickaxes break rock and coral, and scythes clear bushes.
+pub fn tool_can_affect(tool: ToolKind, kind: WorldObjectKind) -> bool {
+    tool == kind.effective_tool()
+}
+
 /// System that handles tool use events on world objects.
+/// An axe swung at any tile of a giant crop on the farm breaks it.
 #[allow(clippy::too_many_arguments)]
 pub fn handle_tool_use_on_objects(
     mut commands: Commands,
@@ -826,12 +841,56 @@ pub fn handle_tool_use_on_objects(
     mut toast_writer: EventWriter<ToastEvent>,
     mut world_map: ResMut<WorldMap>,
     object_atlases: Res<ObjectAtlases>,
+    player_state: Res<PlayerState>,
+    mut farm_state: ResMut<FarmState>,
+    crop_registry: Res<CropRegistry>,
 ) {
     for event in tool_events.read() {
+        // Giant crops live in FarmState rather than as WorldObjects.
+        if event.tool == ToolKind::Axe && player_state.current_map == MapId::Farm {
+            if let Some(idx) = farm_state
+                .giant_crops
+                .iter()
+                .position(|g| g.covers(event.target_x, event.target_y))
+            {
+                let giant = farm_state.giant_crops.remove(idx);
+                let harvest_id = crop_registry
+                    .crops
+                    .get(&giant.crop_id)
+                    .map(|def| def.harvest_id.clone())
+                    .unwrap_or(giant.crop_id);
+                pickup_writer.send(ItemPickupEvent {
+                    item_id: harvest_id,
+                    quantity: crate::farming::giant_crops::GIANT_CROP_YIELD,
+                    quality: ItemQuality::Normal,
+                });
+                sfx_writer.send(PlaySfxEvent {
+                    sfx_id: "object_break".to_string(),
+                });
+                continue;
+            }
+        }
+
         for (entity, mut obj_data, mut sprite) in objects.iter_mut() {
             if obj_data.grid_x == event.target_x && obj_data.grid_y == event.target_y {
                 let effective = obj_data.kind.effective_tool();
-                if event.tool == effective {
+                if !tool_can_affect(event.tool, obj_data.kind) {
+                    // Only hint for the breaking tools; hoeing or watering next
+                    // to a tree isn't an attempt to clear it.
+                    if matches!(
+                        event.tool,
+                        ToolKind::Axe | ToolKind::Pickaxe | ToolKind::Scythe
+                    ) {
+                        toast_writer.send(ToastEvent {
+                            message: format!(
+                                "The {} won't work on that. Try the {}.",
+                                tool_display_name(event.tool),
+                                tool_display_name(effective)
+                            ),
+                            duration_secs: 2.0,
+                        });
+                    }
+                } else {
                     // Check tool tier requirement
                     let required = obj_data.kind.required_tier();
                     if tier_level(event.tier) < tier_level(required) {
@@ -868,6 +927,7 @@ pub fn handle_tool_use_on_objects(
                             pickup_writer.send(ItemPickupEvent {
                                 item_id: item_id.to_string(),
                                 quantity,
+                                quality: ItemQuality::Normal,
                             });
                         }
 
@@ -984,6 +1044,35 @@ pub fn seasonal_forageables(season: Season) -> Vec<(&'static str, Color)> {
     }
 }
 
+/// Wild relatives of farm crops that only grow in the Forest. They can't be
+/// planted and sell for less than their farmed counterparts.
+pub fn forest_wild_crops(season: Season) -> Vec<(&'static str, Color)> {
+    match season {
+        Season::Spring => vec![
+            ("wild_strawberry", Color::srgb(0.85, 0.2, 0.25)),
+            ("wild_potato", Color::srgb(0.7, 0.6, 0.4)),
+        ],
+        Season::Summer => vec![
+            ("wild_blueberry", Color::srgb(0.3, 0.35, 0.8)),
+            ("wild_tomato", Color::srgb(0.9, 0.3, 0.2)),
+        ],
+        Season::Fall => vec![
+            ("wild_cranberry", Color::srgb(0.7, 0.1, 0.2)),
+            ("wild_yam", Color::srgb(0.75, 0.45, 0.3)),
+        ],
+        Season::Winter => vec![("wild_turnip", Color::srgb(0.9, 0.85, 0.9))],
+    }
+}
+
+/// Everything that can be foraged on `map_id` this season.
+pub fn forageables_for_map(map_id: MapId, season: Season) -> Vec<(&'static str, Color)> {
+    let mut forageables = seasonal_forageables(season);
+    if map_id == MapId::Forest {
+        forageables.extend(forest_wild_crops(season));
+    }
+    forageables
+}
+
 fn forageable_icon_index(item_id: &str, item_registry: &ItemRegistry) -> Option<usize> {
     item_registry
         .get(item_id)
@@ -993,21 +1082,21 @@ fn forageable_icon_index(item_id: &str, item_registry: &ItemRegistry) -> Option<
 /// Spawn forageables for the current day on the active map.
 pub fn spawn_forageables(
     commands: &mut Commands,
-    forage_points: &[(i32, i32)],
+    map_def: &MapDef,
     season: Season,
     day: u8,
     world_map: &WorldMap,
     item_registry: &ItemRegistry,
     object_atlases: &ObjectAtlases,
 ) {
-    let forageables = seasonal_forageables(season);
+    let forageables = forageables_for_map(map_def.id, season);
     if forageables.is_empty() {
         return;
     }
 
     // Use day as a seed for pseudo-random selection (deterministic per day)
     // Spawn on roughly 40-60% of available points, varying by day
-    for (i, &(gx, gy)) in forage_points.iter().enumerate() {
+    for (i, &(gx, gy)) in map_def.forage_points.iter().enumerate() {
         // Simple hash to determine if this point spawns today
         let hash = ((day as usize)
             .wrapping_mul(31)
@@ -1086,6 +1175,7 @@ pub fn handle_forageable_pickup(
                 pickup_writer.send(ItemPickupEvent {
                     item_id: forageable.item_id.clone(),
                     quantity: 1,
+                    quality: ItemQuality::Normal,
                 });
                 sfx_writer.send(PlaySfxEvent {
                     sfx_id: "pickup".to_string(),
@@ -1114,8 +1204,97 @@ pub struct Weed {
     pub grid_y: i32,
 }
 
-/// System: on DayEndEvent, spawn 2-4 weeds on random empty farm tiles.
-/// Only spawns weeds when the current map is Farm.
+/// Chance that a weed seeds one neighbouring tile overnight.
+pub const WEED_SPREAD_CHANCE: f64 = 0.15;
+
+/// Weeds stop spreading (and stop sprouting overnight) once the farm holds
+/// this many, so a neglected farm gets wild without being buried.
+pub const MAX_FARM_WEEDS: usize = 60;
+
+/// Tiles tonight's weeds spread to. Each existing weed has a
+/// `WEED_SPREAD_CHANCE` of seeding one random orthogonal neighbour, which
+/// must be open grass or dirt with no crop, tilled soil or weed on it.
+/// Never returns more tiles than fit under `MAX_FARM_WEEDS`.
+pub fn spread_candidates(
+    farm: &FarmState,
+    world_map: &WorldMap,
+    weeds: &std::collections::HashSet<(i32, i32)>,
+    rng: &mut impl Rng,
+) -> Vec<(i32, i32)> {
+    let mut room = MAX_FARM_WEEDS.saturating_sub(weeds.len());
+    let mut parents: Vec<(i32, i32)> = weeds.iter().copied().collect();
+    parents.sort_unstable();
+
+    let mut spread = Vec::new();
+    for (x, y) in parents {
+        if room == 0 {
+            break;
+        }
+        if !rng.gen_bool(WEED_SPREAD_CHANCE) {
+            continue;
+        }
+        let (dx, dy) = [(1, 0), (-1, 0), (0, 1), (0, -1)][rng.gen_range(0..4)];
+        let tile = (x + dx, y + dy);
+        let open_ground = world_map.map_def.as_ref().is_some_and(|map| {
+            matches!(
+                map.get_tile(tile.0, tile.1),
+                TileKind::Grass | TileKind::Dirt
+            )
+        });
+        if !open_ground
+            || !world_map.is_walkable(tile.0, tile.1)
+            || farm.crops.contains_key(&tile)
+            || farm.soil.contains_key(&tile)
+            || weeds.contains(&tile)
+            || spread.contains(&tile)
+        {
+            continue;
+        }
+        spread.push(tile);
+        room -= 1;
+    }
+    spread
+}
+
+fn spawn_weed(commands: &mut Commands, object_atlases: &ObjectAtlases, x: i32, y: i32) {
+    let wwc = grid_to_world_center(x, y);
+    let sprite = if object_atlases.loaded {
+        let mut s = Sprite::from_atlas_image(
+            object_atlases.grass_biome_image.clone(),
+            TextureAtlas {
+                layout: object_atlases.grass_biome_layout.clone(),
+                index: 2, // weed/grass frame from row 0
+            },
+        );
+        s.custom_size = Some(Vec2::new(TILE_SIZE * 0.5, TILE_SIZE * 0.5));
+        s
+    } else {
+        Sprite {
+            color: Color::srgb(0.25, 0.55, 0.2),
+            custom_size: Some(Vec2::new(TILE_SIZE * 0.5, TILE_SIZE * 0.5)),
+            ..default()
+        }
+    };
+    commands.spawn((
+        sprite,
+        Transform::from_translation(Vec3::new(wwc.x, wwc.y, Z_ENTITY_BASE)),
+        LogicalPosition(Vec2::new(wwc.x, wwc.y)),
+        YSorted,
+        Weed {
+            grid_x: x,
+            grid_y: y,
+        },
+        WindSway {
+            offset: (x * y) as f32,
+            speed: 1.2,
+            amount: 0.03,
+        },
+    ));
+}
+
+/// System: on DayEndEvent, existing weeds spread to neighbouring tiles (see
+/// `spread_candidates`), then 2-4 new weeds sprout on random empty farm tiles,
+/// all capped at `MAX_FARM_WEEDS`. Only runs when the current map is Farm.
 pub fn spawn_daily_weeds(
     mut commands: Commands,
     mut day_events: EventReader<DayEndEvent>,
@@ -1137,6 +1316,13 @@ pub fn spawn_daily_weeds(
             occupied.insert((weed.grid_x, weed.grid_y));
         }
 
+        // Weeds left standing seed their neighbours
+        let spread = spread_candidates(&farm_state, &world_map, &occupied, &mut rand::thread_rng());
+        for &(x, y) in &spread {
+            spawn_weed(&mut commands, &object_atlases, x, y);
+            occupied.insert((x, y));
+        }
+
         // Determine how many weeds to spawn (2-4), using day as pseudo-random seed
         let weed_count = 2
             + ((event.day as usize)
@@ -1150,7 +1336,7 @@ pub fn spawn_daily_weeds(
 
         // Attempt up to weed_count * 10 random positions to find valid spots
         for attempt in 0..(weed_count * 10) {
-            if spawned >= weed_count {
+            if spawned >= weed_count || occupied.len() >= MAX_FARM_WEEDS {
                 break;
             }
 
@@ -1186,52 +1372,20 @@ pub fn spawn_daily_weeds(
                 continue;
             }
 
-            // Spawn the weed entity
-            let wwc = grid_to_world_center(x, y);
-            let sprite = if object_atlases.loaded {
-                let mut s = Sprite::from_atlas_image(
-                    object_atlases.grass_biome_image.clone(),
-                    TextureAtlas {
-                        layout: object_atlases.grass_biome_layout.clone(),
-                        index: 2, // weed/grass frame from row 0
-                    },
-                );
-                s.custom_size = Some(Vec2::new(TILE_SIZE * 0.5, TILE_SIZE * 0.5));
-                s
-            } else {
-                Sprite {
-                    color: Color::srgb(0.25, 0.55, 0.2),
-                    custom_size: Some(Vec2::new(TILE_SIZE * 0.5, TILE_SIZE * 0.5)),
-                    ..default()
-                }
-            };
-            commands.spawn((
-                sprite,
-                Transform::from_translation(Vec3::new(wwc.x, wwc.y, Z_ENTITY_BASE)),
-                LogicalPosition(Vec2::new(wwc.x, wwc.y)),
-                YSorted,
-                Weed {
-                    grid_x: x,
-                    grid_y: y,
-                },
-                WindSway {
-                    offset: (x * y) as f32,
-                    speed: 1.2,
-                    amount: 0.03,
-                },
-            ));
-
+            spawn_weed(&mut commands, &object_atlases, x, y);
             occupied.insert((x, y));
             spawned += 1;
         }
     }
 }
 
-/// System: handle scythe use on weeds. Despawn the weed and drop fiber.
+/// System: handle scythe use on weeds. Despawn the weed, drop fiber, and
+/// store the cut grass in the silo as hay.
 pub fn handle_weed_scythe(
     mut commands: Commands,
     mut tool_events: EventReader<ToolUseEvent>,
     weeds: Query<(Entity, &Weed)>,
+    mut silo: ResMut<SiloState>,
     mut pickup_writer: EventWriter<ItemPickupEvent>,
     mut sfx_writer: EventWriter<PlaySfxEvent>,
 ) {
@@ -1247,8 +1401,13 @@ pub fn handle_weed_scythe(
                 pickup_writer.send(ItemPickupEvent {
                     item_id: "fiber".to_string(),
                     quantity: 1,
+                    quality: ItemQuality::Normal,
                 });
 
+                // Cut grass goes to the silo as hay (no-op until one is built
+                // or once it is full).
+                silo.store(1);
+
                 sfx_writer.send(PlaySfxEvent {
                     sfx_id: "swish".to_string(),
                 });
@@ -1433,6 +1592,13 @@ pub fn update_tree_sprites_on_season_change(
 // INTERACTABLE OBJECT SPAWNING
 // ═══════════════════════════════════════════════════════════════════════
 
+/// Farm tile the shipping bin stands on.
+const SHIPPING_BIN_TILE: (i32, i32) = (24, 20);
+/// Farm tile the crafting bench stands on.
+const CRAFTING_BENCH_TILE: (i32, i32) = (4, 20);
+/// Farm tile the mailbox stands on.
+const MAILBOX_TILE: (i32, i32) = (9, 17);
+
 /// Spawns the shipping bin on the Farm map at grid (24, 20).
 /// Only spawns if the player is on the Farm map and the bin hasn't been spawned yet.
 pub fn spawn_shipping_bin(
@@ -1444,7 +1610,7 @@ pub fn spawn_shipping_bin(
     if player_state.current_map != MapId::Farm || !query.is_empty() {
         return;
     }
-    let wc = grid_to_world_center(24, 20);
+    let wc = grid_to_world_center(SHIPPING_BIN_TILE.0, SHIPPING_BIN_TILE.1);
     let sprite = if furniture.loaded {
         let mut s = Sprite::from_image(furniture.shipping_bin_image.clone());
         s.custom_size = Some(Vec2::splat(TILE_SIZE));
@@ -1481,7 +1647,7 @@ pub fn spawn_crafting_bench(
     if player_state.current_map != MapId::Farm || !query.is_empty() {
         return;
     }
-    let wc = grid_to_world_center(4, 20);
+    let wc = grid_to_world_center(CRAFTING_BENCH_TILE.0, CRAFTING_BENCH_TILE.1);
     let sprite = if furniture.loaded {
         let mut s = Sprite::from_image(furniture.crafting_bench_image.clone());
         s.custom_size = Some(Vec2::splat(TILE_SIZE));
@@ -1507,6 +1673,35 @@ pub fn spawn_crafting_bench(
     ));
 }
 
+/// Spawns the mailbox on the Farm map at grid (9, 17), beside the house door.
+/// Only spawns if the player is on the Farm map and the mailbox hasn't been spawned yet.
+pub fn spawn_mailbox(
+    mut commands: Commands,
+    player_state: Res<PlayerState>,
+    query: Query<Entity, With<MailboxMarker>>,
+) {
+    if player_state.current_map != MapId::Farm || !query.is_empty() {
+        return;
+    }
+    let wc = grid_to_world_center(MAILBOX_TILE.0, MAILBOX_TILE.1);
+    commands.spawn((
+        MailboxMarker,
+        WorldObject,
+        Interactable {
+            kind: InteractionKind::Mailbox,
+            label: "Mailbox".into(),
+        },
+        Sprite {
+            color: Color::srgb(0.7, 0.25, 0.2),
+            custom_size: Some(Vec2::new(TILE_SIZE * 0.6, TILE_SIZE)),
+            ..default()
+        },
+        Transform::from_translation(Vec3::new(wc.x, wc.y, Z_ENTITY_BASE)),
+        YSorted,
+        Visibility::default(),
+    ));
+}
+
 /// Spawns the carpenter board on the Town map at grid (10, 8).
 /// Only spawns if the player is on the Town map and the board hasn't been spawned yet.
 pub fn spawn_carpenter_board(
@@ -1544,6 +1739,36 @@ pub fn spawn_carpenter_board(
     ));
 }
 
+/// Spawns the quest bulletin board on the Town map at grid (17, 8), across the
+/// plaza from the carpenter board.
+/// Only spawns if the player is on the Town map and the board hasn't been spawned yet.
+pub fn spawn_quest_board(
+    mut commands: Commands,
+    player_state: Res<PlayerState>,
+    query: Query<Entity, With<QuestBoardMarker>>,
+) {
+    if player_state.current_map != MapId::Town || !query.is_empty() {
+        return;
+    }
+    let wc = grid_to_world_center(17, 8);
+    commands.spawn((
+        QuestBoardMarker,
+        WorldObject,
+        Interactable {
+            kind: InteractionKind::QuestBoard,
+            label: "Quest Board".into(),
+        },
+        Sprite {
+            color: Color::srgb(0.55, 0.4, 0.25),
+            custom_size: Some(Vec2::splat(TILE_SIZE)),
+            ..default()
+        },
+        Transform::from_translation(Vec3::new(wc.x, wc.y, Z_ENTITY_BASE)),
+        YSorted,
+        Visibility::default(),
+    ));
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // BUILDING ENTRANCE SIGNS — floating labels above shop/building doors
 // ═══════════════════════════════════════════════════════════════════════
@@ -1620,6 +1845,38 @@ struct BuildingDef {
     composite: Option<BuildingImage>,
 }
 
+impl BuildingDef {
+    /// Every grid tile covered by the building, row by row.
+    fn footprint(&self) -> Vec<(i32, i32)> {
+        (self.y..self.y + self.h)
+            .flat_map(|gy| (self.x..self.x + self.w).map(move |gx| (gx, gy)))
+            .collect()
+    }
+
+    /// Whether a tile is part of the doorway. Doors are two tiles wide
+    /// (`door_x` and the tile to its right), matching the map door zones.
+    fn is_door_tile(&self, gx: i32, gy: i32) -> bool {
+        gy == self.door_y && (gx == self.door_x || gx == self.door_x + 1)
+    }
+
+    /// Mark the building's footprint solid, leaving the doorway walkable so
+    /// the door transition can still be reached.
+    fn register_collision(&self, world_map: &mut super::WorldMap) {
+        for (gx, gy) in self.footprint() {
+            if !self.is_door_tile(gx, gy) {
+                world_map.set_solid(gx, gy, true);
+            }
+        }
+    }
+
+    /// Make the whole footprint walkable again after the building moves away.
+    fn clear_collision(&self, world_map: &mut super::WorldMap) {
+        for (gx, gy) in self.footprint() {
+            world_map.set_solid(gx, gy, false);
+        }
+    }
+}
+
 /// Town building definitions.
 /// All use composite sprites (farmhouse/barn) with distinct tints.
 fn town_buildings() -> Vec<BuildingDef> {
@@ -1718,8 +1975,8 @@ fn town_decorations() -> Vec<(Vec2, WorldObjectKind)> {
     ]
 }
 
-fn farm_buildings() -> Vec<BuildingDef> {
-    vec![
+fn farm_buildings(placements: &BuildingPlacements) -> Vec<BuildingDef> {
+    let mut buildings = vec![
         // Player house (lower-left area) — composite farmhouse sprite (128x160)
         BuildingDef {
             x: 2,
@@ -1731,29 +1988,190 @@ fn farm_buildings() -> Vec<BuildingDef> {
             roof_tint: Color::srgb(0.75, 0.5, 0.4),
             composite: Some(BuildingImage::Farmhouse),
         },
+    ];
+    buildings.extend(
+        [BuildingKind::Coop, BuildingKind::Barn]
+            .into_iter()
+            .filter_map(|building| farm_building(building, placements)),
+    );
+    buildings
+}
+
+/// The coop or barn at its current placement. Doors keep their offset from
+/// the footprint origin wherever the building stands.
+fn farm_building(building: BuildingKind, placements: &BuildingPlacements) -> Option<BuildingDef> {
+    let (x, y) = placements.origin(building)?;
+    match building {
         // Chicken coop (upper-right area) — composite chicken house sprite (48x48)
-        BuildingDef {
-            x: 24,
-            y: 1,
+        BuildingKind::Coop => Some(BuildingDef {
+            x,
+            y,
             w: 3,
             h: 2,
-            door_x: 25,
-            door_y: 2,
+            door_x: x + 1,
+            door_y: y + 1,
             roof_tint: Color::srgb(0.9, 0.8, 0.5),
             composite: Some(BuildingImage::ChickenHouse),
-        },
+        }),
         // Barn (upper-left area) — composite barn sprite (128x160)
-        BuildingDef {
-            x: 2,
-            y: 1,
+        BuildingKind::Barn => Some(BuildingDef {
+            x,
+            y,
             w: 5,
             h: 3,
-            door_x: 4,
-            door_y: 3,
+            door_x: x + 2,
+            door_y: y + 2,
             roof_tint: Color::srgb(0.7, 0.3, 0.3),
             composite: Some(BuildingImage::Barn),
-        },
-    ]
+        }),
+        BuildingKind::House | BuildingKind::Silo => None,
+    }
+}
+
+/// Tiles in front of the farmhouse door, `(min, max)` inclusive. Buildings
+/// may not be moved onto them or the player could be shut in.
+const FARMHOUSE_DOOR_APPROACH: ((i32, i32), (i32, i32)) = ((6, 18), (9, 20));
+
+/// Why `building` can't be moved so its footprint starts at `to`, or `None`
+/// when the spot is clear. The new footprint must sit on open ground, away
+/// from the other farm buildings, map objects, and tilled soil or crops. It
+/// may not touch the map edge (every farm edge is an exit), the farmhouse
+/// door, or the shipping bin, crafting bench, mailbox and feed trough. The
+/// building's own starting foundation is always clear for it. `world_map` is
+/// the loaded farm, if the player is on it, for anything placed since load.
+pub fn building_move_blocker(
+    building: BuildingKind,
+    to: (i32, i32),
+    placements: &BuildingPlacements,
+    farm_map: &MapDef,
+    farm_state: &FarmState,
+    world_map: Option<&WorldMap>,
+) -> Option<&'static str> {
+    let mut moved = *placements;
+    moved.set_origin(building, to);
+    let Some(target) = farm_building(building, &moved) else {
+        return Some("only the coop and barn can be moved");
+    };
+    let foundation = farm_building(building, &BuildingPlacements::default())
+        .map(|bld| bld.footprint())
+        .unwrap_or_default();
+    let current = farm_building(building, placements)
+        .map(|bld| bld.footprint())
+        .unwrap_or_default();
+    let others: Vec<BuildingDef> = farm_buildings(placements)
+        .into_iter()
+        .filter(|bld| bld.composite != target.composite)
+        .collect();
+    let (width, height) = (farm_map.width as i32, farm_map.height as i32);
+    let on_edge = |(gx, gy): (i32, i32)| gx <= 0 || gy <= 0 || gx >= width - 1 || gy >= height - 1;
+    let ((door_x0, door_y0), (door_x1, door_y1)) = FARMHOUSE_DOOR_APPROACH;
+    let fixtures = [SHIPPING_BIN_TILE, CRAFTING_BENCH_TILE, MAILBOX_TILE];
+    // The trough travels with the barn, so only the coop can land on it.
+    let trough = (building == BuildingKind::Coop).then(|| placements.feed_trough());
+
+    if building == BuildingKind::Barn {
+        let trough = moved.feed_trough();
+        let coop = farm_building(BuildingKind::Coop, placements)
+            .map(|bld| bld.footprint())
+            .unwrap_or_default();
+        if on_edge(trough) || coop.contains(&trough) || fixtures.contains(&trough) {
+            return Some("the feed trough wouldn't fit beside it");
+        }
+    }
+
+    for (gx, gy) in target.footprint() {
+        if others.iter().any(|bld| bld.footprint().contains(&(gx, gy))) {
+            return Some("another building is in the way");
+        }
+        if on_edge((gx, gy)) {
+            return Some("the paths off the farm must stay open");
+        }
+        if (door_x0..=door_x1).contains(&gx) && (door_y0..=door_y1).contains(&gy) {
+            return Some("the farmhouse door must stay clear");
+        }
+        if fixtures.contains(&(gx, gy)) || trough == Some((gx, gy)) {
+            return Some("it would bury the bin, bench, mailbox or trough");
+        }
+        if world_map.is_some_and(|map| map.solid_tiles.contains(&(gx, gy)))
+            && !current.contains(&(gx, gy))
+        {
+            return Some("something is in the way");
+        }
+        if foundation.contains(&(gx, gy)) {
+            continue;
+        }
+        if !matches!(
+            farm_map.get_tile(gx, gy),
+            TileKind::Grass | TileKind::Dirt | TileKind::Path
+        ) {
+            return Some("the ground there isn't clear");
+        }
+        if farm_map.objects.iter().any(|o| o.x == gx && o.y == gy)
+            || farm_state.objects.contains_key(&(gx, gy))
+        {
+            return Some("something is in the way");
+        }
+        if farm_state.soil.contains_key(&(gx, gy)) || farm_state.crops.contains_key(&(gx, gy)) {
+            return Some("there are crops or tilled soil there");
+        }
+    }
+    None
+}
+
+/// Moves the coop or barn when the carpenter is asked to. The old footprint
+/// is opened up, the new one blocked off, and the building sprites are
+/// respawned by `spawn_building_sprites`. Animal pens follow via
+/// [`BuildingPlacements`] change detection in the animals domain.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_building_move(
+    mut commands: Commands,
+    mut events: EventReader<BuildingMoveEvent>,
+    mut placements: ResMut<BuildingPlacements>,
+    mut world_map: ResMut<WorldMap>,
+    player_state: Res<PlayerState>,
+    farm_state: Res<FarmState>,
+    registry: Res<super::map_data::MapRegistry>,
+    farm_layout: Res<super::maps::FarmLayout>,
+    overlays: Query<Entity, With<BuildingOverlay>>,
+    mut toast_writer: EventWriter<ToastEvent>,
+) {
+    for ev in events.read() {
+        let farm_map = super::resolve_map_def(MapId::Farm, &registry, *farm_layout);
+        let loaded_farm = (player_state.current_map == MapId::Farm).then_some(&*world_map);
+        if let Some(reason) = building_move_blocker(
+            ev.building,
+            ev.to,
+            &placements,
+            &farm_map,
+            &farm_state,
+            loaded_farm,
+        ) {
+            toast_writer.send(ToastEvent {
+                message: format!("Can't move the {:?} there: {}.", ev.building, reason),
+                duration_secs: 3.0,
+            });
+            continue;
+        }
+        let Some(old) = farm_building(ev.building, &placements) else {
+            continue;
+        };
+        placements.set_origin(ev.building, ev.to);
+
+        if player_state.current_map == MapId::Farm {
+            if let Some(new) = farm_building(ev.building, &placements) {
+                old.clear_collision(&mut world_map);
+                new.register_collision(&mut world_map);
+            }
+            for entity in &overlays {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+
+        toast_writer.send(ToastEvent {
+            message: format!("The {:?} has been moved.", ev.building),
+            duration_secs: 3.0,
+        });
+    }
 }
 
 /// Resolve a `BuildingImage` variant to the corresponding loaded image handle.
@@ -1779,11 +2197,14 @@ fn building_image_source_size(img: BuildingImage) -> Vec2 {
 /// Spawn building overlays. Buildings with a `composite` image are rendered as
 /// a single sprite scaled to match the footprint width; others fall back to
 /// the legacy tile-by-tile wall+roof+door construction from Sprout Lands.
+/// Every footprint tile except the doorway is registered as solid.
 pub fn spawn_building_sprites(
     mut commands: Commands,
     player_state: Res<PlayerState>,
     existing: Query<Entity, With<BuildingOverlay>>,
     object_atlases: Res<ObjectAtlases>,
+    placements: Res<BuildingPlacements>,
+    mut world_map: ResMut<super::WorldMap>,
 ) {
     if !existing.is_empty() || !object_atlases.loaded {
         return;
@@ -1791,11 +2212,23 @@ pub fn spawn_building_sprites(
 
     let buildings = match player_state.current_map {
         MapId::Town => town_buildings(),
-        MapId::Farm => farm_buildings(),
+        MapId::Farm => {
+            // A moved coop or barn leaves its starting foundation walkable.
+            for building in [BuildingKind::Coop, BuildingKind::Barn] {
+                if placements.offset(building) != (0, 0) {
+                    if let Some(bld) = farm_building(building, &BuildingPlacements::default()) {
+                        bld.clear_collision(&mut world_map);
+                    }
+                }
+            }
+            farm_buildings(&placements)
+        }
         _ => return,
     };
 
     for bld in &buildings {
+        bld.register_collision(&mut world_map);
+
         if let Some(img) = bld.composite {
             // ── Composite sprite path ──────────────────────────────────
             // Render the entire building (walls + roof) as a single image.
@@ -3488,6 +3921,54 @@ mod farm_decoration_tests {
     }
 }
 
+#[cfg(test)]
+mod forest_forageable_tests {
+    use super::*;
+
+    const SEASONS: [Season; 4] = [Season::Spring, Season::Summer, Season::Fall, Season::Winter];
+
+    #[test]
+    fn forest_tables_differ_by_season_and_stay_in_season() {
+        for season in SEASONS {
+            let forest: Vec<&str> = forageables_for_map(MapId::Forest, season)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect();
+            for (id, _) in forest_wild_crops(season) {
+                assert!(forest.contains(&id));
+            }
+            for other in SEASONS.into_iter().filter(|s| *s != season) {
+                assert_ne!(
+                    forest_wild_crops(season),
+                    forest_wild_crops(other),
+                    "{:?} and {:?} share a wild crop table",
+                    season,
+                    other
+                );
+                for (id, _) in forest_wild_crops(other) {
+                    assert!(
+                        !forest.contains(&id),
+                        "{} from {:?} appears in the {:?} forest",
+                        id,
+                        other,
+                        season
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wild_crops_only_grow_in_the_forest() {
+        for season in SEASONS {
+            let town = forageables_for_map(MapId::Town, season);
+            for (id, _) in forest_wild_crops(season) {
+                assert!(!town.iter().any(|(t, _)| *t == id));
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod forageable_icon_tests {
     use super::*;
@@ -3518,3 +3999,171 @@ mod forageable_icon_tests {
         assert_eq!(forageable_icon_index("crocus", &registry), Some(240));
     }
 }
+
+#[cfg(test)]
+mod building_footprint_tests {
+    use super::*;
+
+    fn building(x: i32, y: i32, w: i32, h: i32, door_x: i32, door_y: i32) -> BuildingDef {
+        BuildingDef {
+            x,
+            y,
+            w,
+            h,
+            door_x,
+            door_y,
+            roof_tint: Color::WHITE,
+            composite: None,
+        }
+    }
+
+    #[test]
+    fn three_by_two_building_marks_six_solid_tiles() {
+        // Door sits just outside the footprint, so every tile is solid.
+        let bld = building(10, 4, 3, 2, 11, 3);
+        assert_eq!(bld.footprint().len(), 6);
+
+        let mut world_map = super::super::WorldMap::default();
+        bld.register_collision(&mut world_map);
+        assert_eq!(world_map.solid_tiles.len(), 6);
+        for gy in 4..6 {
+            for gx in 10..13 {
+                assert!(world_map.solid_tiles.contains(&(gx, gy)));
+            }
+        }
+    }
+
+    #[test]
+    fn doorway_stays_walkable() {
+        let bld = building(24, 1, 3, 2, 25, 2);
+        let mut world_map = super::super::WorldMap::default();
+        bld.register_collision(&mut world_map);
+        assert_eq!(world_map.solid_tiles.len(), 4);
+        assert!(!world_map.solid_tiles.contains(&(25, 2)));
+        assert!(!world_map.solid_tiles.contains(&(26, 2)));
+    }
+}
+
+#[cfg(test)]
+mod tool_target_tests {
+    use super::*;
+
+    const TOOLS: [ToolKind; 6] = [
+        ToolKind::Hoe,
+        ToolKind::WateringCan,
+        ToolKind::Axe,
+        ToolKind::Pickaxe,
+        ToolKind::FishingRod,
+        ToolKind::Scythe,
+    ];
+
+    #[test]
+    fn each_object_is_affected_by_exactly_its_tool() {
+        let matrix = [
+            (WorldObjectKind::Tree, ToolKind::Axe),
+            (WorldObjectKind::Pine, ToolKind::Axe),
+            (WorldObjectKind::PalmTree, ToolKind::Axe),
+            (WorldObjectKind::Stump, ToolKind::Axe),
+            (WorldObjectKind::Log, ToolKind::Axe),
+            (WorldObjectKind::Driftwood, ToolKind::Axe),
+            (WorldObjectKind::Rock, ToolKind::Pickaxe),
+            (WorldObjectKind::LargeRock, ToolKind::Pickaxe),
+            (WorldObjectKind::Coral, ToolKind::Pickaxe),
+            (WorldObjectKind::Bush, ToolKind::Scythe),
+        ];
+        for (kind, expected) in matrix {
+            for tool in TOOLS {
+                assert_eq!(
+                    tool_can_affect(tool, kind),
+                    tool == expected,
+                    "{tool:?} on {kind:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn axe_cannot_chop_rocks_and_pickaxe_cannot_fell_trees() {
+        assert!(!tool_can_affect(ToolKind::Axe, WorldObjectKind::Rock));
+        assert!(!tool_can_affect(ToolKind::Axe, WorldObjectKind::LargeRock));
+        assert!(!tool_can_affect(ToolKind::Pickaxe, WorldObjectKind::Tree));
+        assert!(!tool_can_affect(ToolKind::Pickaxe, WorldObjectKind::Stump));
+        assert!(!tool_can_affect(ToolKind::Scythe, WorldObjectKind::Tree));
+    }
+}
+
+#[cfg(test)]
+mod weed_spread_tests {
+    use super::*;
+    use crate::world::maps::MapDef;
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::collections::HashSet;
+
+    fn grass_map(size: usize) -> WorldMap {
+        WorldMap {
+            map_def: Some(MapDef {
+                id: MapId::Farm,
+                width: size,
+                height: size,
+                tiles: vec![TileKind::Grass; size * size],
+                transitions: Vec::new(),
+                objects: Vec::new(),
+                forage_points: Vec::new(),
+            }),
+            width: size,
+            height: size,
+            ..default()
+        }
+    }
+
+    /// `count` weeds on every other tile, so each has free neighbours.
+    fn scattered_weeds(count: usize) -> HashSet<(i32, i32)> {
+        (0..12)
+            .flat_map(|y| (0..12).map(move |x| (x * 2 + 1, y * 2 + 1)))
+            .take(count)
+            .collect()
+    }
+
+    #[test]
+    fn spreading_never_pushes_the_farm_past_the_weed_cap() {
+        let world_map = grass_map(26);
+        let farm = FarmState::default();
+        let weeds = scattered_weeds(MAX_FARM_WEEDS - 3);
+
+        let mut hit_cap = false;
+        for seed in 0..50 {
+            let spread =
+                spread_candidates(&farm, &world_map, &weeds, &mut StdRng::seed_from_u64(seed));
+            assert!(spread.len() <= 3, "seed {seed} spread {}", spread.len());
+            for tile in &spread {
+                assert!(!weeds.contains(tile));
+                assert!(world_map.is_walkable(tile.0, tile.1));
+            }
+            hit_cap |= spread.len() == 3;
+        }
+        assert!(hit_cap, "57 weeds should fill the last three slots");
+
+        let full = scattered_weeds(MAX_FARM_WEEDS);
+        for seed in 0..10 {
+            let spread =
+                spread_candidates(&farm, &world_map, &full, &mut StdRng::seed_from_u64(seed));
+            assert!(spread.is_empty());
+        }
+    }
+
+    #[test]
+    fn weeds_do_not_spread_onto_tilled_soil_or_crops() {
+        let mut world_map = grass_map(3);
+        world_map.set_solid(1, 2, true);
+        let mut farm = FarmState::default();
+        farm.soil.insert((0, 1), SoilState::Tilled);
+        farm.soil.insert((2, 1), SoilState::Tilled);
+        let weeds: HashSet<(i32, i32)> = [(1, 1)].into_iter().collect();
+
+        for seed in 0..100 {
+            let spread =
+                spread_candidates(&farm, &world_map, &weeds, &mut StdRng::seed_from_u64(seed));
+            assert!(spread.iter().all(|&tile| tile == (1, 0)), "{spread:?}");
+        }
+    }
+}
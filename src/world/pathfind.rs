@@ -0,0 +1,224 @@
+//! Grid pathfinding shared by NPCs and animals.
+//!
+//! Both searches work on 4-connected tile grids and take the walkability test
+//! as a closure, so callers can combine `WorldMap::is_walkable` with their own
+//! limits (e.g. an animal's pen). Search buffers live in a thread-local and are
+//! reused between calls, so steady-state pathing does not allocate beyond the
+//! returned path.
+
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
+/// Upper bound on tiles expanded per search. Keeps a search for an
+/// unreachable goal on a large open map from stalling a frame.
+pub const MAX_SEARCH_NODES: usize = 4096;
+
+const NEIGHBORS: [(i32, i32); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+/// Open-set entry ordered by (estimated total cost, heuristic, tile).
+type OpenEntry = Reverse<(u32, u32, (i32, i32))>;
+
+#[derive(Default)]
+struct SearchBuffers {
+    open: BinaryHeap<OpenEntry>,
+    came_from: HashMap<(i32, i32), (i32, i32)>,
+    cost: HashMap<(i32, i32), u32>,
+    frontier: VecDeque<(i32, i32)>,
+    visited: HashSet<(i32, i32)>,
+}
+
+impl SearchBuffers {
+    fn clear(&mut self) {
+        self.open.clear();
+        self.came_from.clear();
+        self.cost.clear();
+        self.frontier.clear();
+        self.visited.clear();
+    }
+}
+
+thread_local! {
+    static BUFFERS: RefCell<SearchBuffers> = RefCell::new(SearchBuffers::default());
+}
+
+fn manhattan(a: (i32, i32), b: (i32, i32)) -> u32 {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+/// A* over a 4-connected grid. Returns the tiles to step through, excluding
+/// `start` and ending at `goal`, or `None` when the goal cannot be reached.
+///
+/// The goal itself does not need to pass `is_walkable`, so callers can path
+/// up to an NPC's counter spot or a door tile that is marked solid.
+pub fn astar(
+    start: (i32, i32),
+    goal: (i32, i32),
+    is_walkable: impl Fn(i32, i32) -> bool,
+) -> Option<Vec<(i32, i32)>> {
+    if start == goal {
+        return Some(Vec::new());
+    }
+
+    BUFFERS.with(|buffers| {
+        let mut buf = buffers.borrow_mut();
+        buf.clear();
+        let SearchBuffers {
+            open,
+            came_from,
+            cost,
+            ..
+        } = &mut *buf;
+
+        cost.insert(start, 0);
+        // Ties on f-score prefer the node closer to the goal (lower h).
+        open.push(Reverse((
+            manhattan(start, goal),
+            manhattan(start, goal),
+            start,
+        )));
+
+        let mut expanded = 0;
+        while let Some(Reverse((_, _, current))) = open.pop() {
+            if current == goal {
+                let mut path = vec![goal];
+                let mut node = goal;
+                while let Some(&prev) = came_from.get(&node) {
+                    if prev == start {
+                        break;
+                    }
+                    path.push(prev);
+                    node = prev;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            expanded += 1;
+            if expanded > MAX_SEARCH_NODES {
+                return None;
+            }
+
+            let current_cost = cost[&current];
+            for (dx, dy) in NEIGHBORS {
+                let next = (current.0 + dx, current.1 + dy);
+                if next != goal && !is_walkable(next.0, next.1) {
+                    continue;
+                }
+                let next_cost = current_cost + 1;
+                if cost.get(&next).is_some_and(|&c| c <= next_cost) {
+                    continue;
+                }
+                cost.insert(next, next_cost);
+                came_from.insert(next, current);
+                let h = manhattan(next, goal);
+                open.push(Reverse((next_cost + h, h, next)));
+            }
+        }
+        None
+    })
+}
+
+/// Breadth-first search outward from `from` for the closest walkable tile
+/// (by step count). Returns `from` itself when it is walkable.
+pub fn bfs_nearest_walkable(
+    from: (i32, i32),
+    is_walkable: impl Fn(i32, i32) -> bool,
+) -> Option<(i32, i32)> {
+    if is_walkable(from.0, from.1) {
+        return Some(from);
+    }
+
+    BUFFERS.with(|buffers| {
+        let mut buf = buffers.borrow_mut();
+        buf.clear();
+        let SearchBuffers {
+            frontier, visited, ..
+        } = &mut *buf;
+
+        frontier.push_back(from);
+        visited.insert(from);
+        while let Some(current) = frontier.pop_front() {
+            if visited.len() > MAX_SEARCH_NODES {
+                return None;
+            }
+            for (dx, dy) in NEIGHBORS {
+                let next = (current.0 + dx, current.1 + dy);
+                if !visited.insert(next) {
+                    continue;
+                }
+                if is_walkable(next.0, next.1) {
+                    return Some(next);
+                }
+                frontier.push_back(next);
+            }
+        }
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parse a grid where `#` is solid; row 0 is the first line.
+    fn grid<'a>(rows: &'a [&'a str]) -> impl Fn(i32, i32) -> bool + 'a {
+        move |x, y| {
+            if x < 0 || y < 0 {
+                return false;
+            }
+            rows.get(y as usize)
+                .and_then(|row| row.as_bytes().get(x as usize))
+                .is_some_and(|&c| c != b'#')
+        }
+    }
+
+    #[test]
+    fn astar_routes_around_a_wall() {
+        let map = [
+            ".....", //
+            ".###.", //
+            ".#...", //
+            ".#.#.", //
+            "...#.",
+        ];
+        let path = astar((2, 2), (0, 2), grid(&map)).expect("a path exists");
+
+        assert_eq!(path.last(), Some(&(0, 2)));
+        // Each step moves exactly one tile and never enters a wall.
+        let mut prev = (2, 2);
+        for &step in &path {
+            assert_eq!(manhattan(prev, step), 1);
+            assert!(grid(&map)(step.0, step.1), "stepped into {:?}", step);
+            prev = step;
+        }
+        // Shortest route is down and around the bottom of the wall.
+        assert_eq!(path.len(), 6);
+    }
+
+    #[test]
+    fn astar_returns_none_for_enclosed_goal() {
+        let map = [
+            ".......", //
+            "..###..", //
+            "..#.#..", //
+            "..###..", //
+            ".......",
+        ];
+        // (3, 2) is walkable but completely walled in.
+        assert!(astar((0, 0), (3, 2), grid(&map)).is_none());
+        assert_eq!(astar((1, 1), (1, 1), grid(&map)), Some(Vec::new()));
+    }
+
+    #[test]
+    fn bfs_finds_closest_open_tile() {
+        let map = [
+            "#####", //
+            "###..", //
+            "#####",
+        ];
+        assert_eq!(bfs_nearest_walkable((1, 1), grid(&map)), Some((3, 1)));
+        assert_eq!(bfs_nearest_walkable((4, 1), grid(&map)), Some((4, 1)));
+        assert_eq!(bfs_nearest_walkable((0, 0), |_, _| false), None);
+    }
+}
@@ -0,0 +1,69 @@
+//! Returning to the title screen.
+//!
+//! Pausing and quitting used to just flip the state to `MainMenu`, leaving the
+//! player, NPCs and the current map alive. The spawn guards then saw those
+//! leftovers and skipped spawning the next game's world. `ReturnToTitleEvent`
+//! despawns everything gameplay owns, drops resources that cache entity ids,
+//! and only then changes state.
+
+use bevy::prelude::*;
+
+use super::chests::{ChestInteraction, ChestMarker};
+use super::fish_tanks::FishTank;
+use super::objects::WorldObject;
+use super::MapTile;
+use crate::crafting::machines::{ProcessingMachine, ProcessingMachineRegistry};
+use crate::farming::FarmEntities;
+use crate::mining::components::MineFloorEntity;
+use crate::npcs::spawning::SpawnedNpcs;
+use crate::player::tool_anim::PlayerShadow;
+use crate::shared::*;
+
+/// Every entity that belongs to a running game rather than to the app.
+type GameplayEntity = Or<(
+    With<Player>,
+    With<PlayerShadow>,
+    With<Npc>,
+    With<Animal>,
+    With<MapTile>,
+    With<WorldObject>,
+    With<ChestMarker>,
+    With<FishTank>,
+    With<ProcessingMachine>,
+    With<MineFloorEntity>,
+)>;
+
+/// Tear the world down and head to the title screen.
+///
+/// Entity-id caches are cleared in the same system that queues the despawns,
+/// so nothing can look up a stale entity in between. The state change lands
+/// on the next frame, after the despawn commands have been applied.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_return_to_title(
+    mut events: EventReader<ReturnToTitleEvent>,
+    mut commands: Commands,
+    gameplay_entities: Query<Entity, GameplayEntity>,
+    mut farm_entities: ResMut<FarmEntities>,
+    mut spawned_npcs: ResMut<SpawnedNpcs>,
+    mut chest_interaction: ResMut<ChestInteraction>,
+    mut machine_registry: ResMut<ProcessingMachineRegistry>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+
+    chest_interaction.entity = None;
+    spawned_npcs.entities.clear();
+    machine_registry.machines.clear();
+    farm_entities.despawn_all(&mut commands);
+
+    for entity in gameplay_entities.iter() {
+        if let Some(e) = commands.get_entity(entity) {
+            e.despawn_recursive();
+        }
+    }
+
+    next_state.set(GameState::MainMenu);
+    info!("[World] Returned to title; gameplay entities despawned.");
+}
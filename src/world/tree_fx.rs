@@ -321,8 +321,9 @@ pub fn on_tree_destruction(
         }
 
         // Convert grid to world for poof spawn position.
-        let wx = event.target_x as f32 * TILE_SIZE + TILE_SIZE * 0.5;
-        let wy = event.target_y as f32 * TILE_SIZE + TILE_SIZE * 0.5;
+        let wc = grid_to_world_center(event.target_x, event.target_y);
+        let wx = wc.x;
+        let wy = wc.y;
 
         // Spawn poof: 12x12 pixels, brown-green tint, scale 0.5→2.0 over 0.3s.
         commands.spawn((
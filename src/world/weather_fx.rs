@@ -2,7 +2,9 @@
 //!
 //! Spawns world-space particle entities (Sprite + Transform) that simulate
 //! rain drops, snowflakes, and storm effects. Particles are spawned above the
-//! camera viewport and despawned when they fall below it.
+//! camera viewport until the weather's target count is reached; after that a
+//! particle that falls out of view is moved back to the top instead of being
+//! despawned, so steady rain or snow costs no entity churn per frame.
 //!
 //! Weather particles use procedurally generated sprite images (cached in a
 //! Resource) rather than plain colored rectangles, giving rain a tapered
@@ -65,6 +67,27 @@ pub struct WeatherParticleCounts {
     pub snow: usize,
 }
 
+impl WeatherParticleCounts {
+    pub fn total(&self) -> usize {
+        self.rain + self.snow
+    }
+}
+
+/// Hard limit on live weather particle entities. Lower it for slow machines;
+/// `GameSettings::particle_density` scales the per-weather targets on top.
+#[derive(Resource, Debug, Clone)]
+pub struct WeatherParticleConfig {
+    pub max_particles: usize,
+}
+
+impl Default for WeatherParticleConfig {
+    fn default() -> Self {
+        Self {
+            max_particles: MAX_WEATHER_PARTICLES,
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // PROCEDURAL WEATHER SPRITES — cached resource
 // ═══════════════════════════════════════════════════════════════════════
@@ -177,9 +200,39 @@ pub fn ensure_weather_sprites_loaded(images: &mut Assets<Image>, sprites: &mut W
 // CONSTANTS
 // ═══════════════════════════════════════════════════════════════════════
 
-/// Maximum number of weather particles alive at once to prevent performance issues.
+/// Default maximum number of weather particles alive at once.
 const MAX_WEATHER_PARTICLES: usize = 600;
 
+/// Pool size each weather fills up to at full density.
+const RAIN_PARTICLE_TARGET: usize = 300;
+const STORM_PARTICLE_TARGET: usize = 480;
+const SNOW_PARTICLE_TARGET: usize = 200;
+
+/// Extra margin around the view that particles spawn into / are recycled from.
+const SPAWN_MARGIN_X: f32 = 20.0;
+const RECYCLE_MARGIN: f32 = 20.0;
+
+/// How many particles should be alive for `weather`, after applying the
+/// configured cap and the player's density setting.
+pub fn weather_particle_target(weather: Weather, max_particles: usize, density: f32) -> usize {
+    let base = match weather {
+        Weather::Rainy => RAIN_PARTICLE_TARGET,
+        Weather::Stormy => STORM_PARTICLE_TARGET,
+        Weather::Snowy => SNOW_PARTICLE_TARGET,
+        Weather::Sunny => 0,
+    };
+    let scaled = (base as f32 * density.clamp(0.0, 1.0)).round() as usize;
+    scaled.min(max_particles)
+}
+
+fn rain_speed(weather: Weather, rng: &mut impl Rng) -> f32 {
+    if weather == Weather::Stormy {
+        rng.gen_range(280.0..480.0)
+    } else {
+        rng.gen_range(170.0..310.0)
+    }
+}
+
 /// Returns true if the given map is indoors (no weather particles).
 fn is_indoor_map(map_id: MapId) -> bool {
     matches!(
@@ -204,10 +257,13 @@ fn is_indoor_map(map_id: MapId) -> bool {
 /// Rain drops use a procedural tapered raindrop sprite.
 /// Snowflakes use a procedural cross/star pattern sprite.
 /// Particles are spawned at random X positions above the camera's visible area.
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_weather_particles(
     mut commands: Commands,
     calendar: Res<Calendar>,
     player_state: Res<PlayerState>,
+    settings: Res<GameSettings>,
+    config: Res<WeatherParticleConfig>,
     camera_query: Query<&Transform, With<Camera2d>>,
     mut counts: ResMut<WeatherParticleCounts>,
     mut images: ResMut<Assets<Image>>,
@@ -222,9 +278,15 @@ pub fn spawn_weather_particles(
         return;
     };
 
-    // Enforce hard cap using tracked totals.
-    let existing = counts.rain + counts.snow;
-    if existing >= MAX_WEATHER_PARTICLES {
+    // Stop spawning once the pool is full; update_weather_particles recycles
+    // from then on.
+    let target = weather_particle_target(
+        calendar.weather,
+        config.max_particles,
+        settings.particle_density,
+    );
+    let existing = counts.total();
+    if existing >= target {
         return;
     }
 
@@ -241,11 +303,11 @@ pub fn spawn_weather_particles(
     let cam_y = cam_tf.translation.y;
 
     // Spawn area: slightly wider than the visible area to avoid visible pop-in.
-    let spawn_left = cam_x - half_w - 20.0;
-    let spawn_right = cam_x + half_w + 20.0;
+    let spawn_left = cam_x - half_w - SPAWN_MARGIN_X;
+    let spawn_right = cam_x + half_w + SPAWN_MARGIN_X;
     let spawn_top = cam_y + half_h + 10.0;
 
-    let budget = MAX_WEATHER_PARTICLES - existing;
+    let budget = target - existing;
 
     match calendar.weather {
         Weather::Rainy => {
@@ -253,7 +315,7 @@ pub fn spawn_weather_particles(
             for _ in 0..count {
                 let x = rng.gen_range(spawn_left..spawn_right);
                 let y = spawn_top + rng.gen_range(0.0..20.0);
-                let speed = rng.gen_range(170.0..310.0);
+                let speed = rain_speed(Weather::Rainy, &mut rng);
 
                 let mut sprite = Sprite::from_image(weather_sprites.rain_image.clone());
                 sprite.custom_size = Some(Vec2::new(2.5, 10.0));
@@ -272,7 +334,7 @@ pub fn spawn_weather_particles(
             for _ in 0..count {
                 let x = rng.gen_range(spawn_left..spawn_right);
                 let y = spawn_top + rng.gen_range(0.0..20.0);
-                let speed = rng.gen_range(280.0..480.0);
+                let speed = rain_speed(Weather::Stormy, &mut rng);
 
                 let mut sprite = Sprite::from_image(weather_sprites.storm_image.clone());
                 sprite.custom_size = Some(Vec2::new(3.5, 10.0));
@@ -324,14 +386,19 @@ pub fn spawn_weather_particles(
     }
 }
 
-/// Move weather particles each frame and despawn those that fall below the camera.
-#[allow(clippy::type_complexity)]
+/// Move weather particles each frame. Particles that leave the view are sent
+/// back above the camera with a fresh speed, or despawned if the pool is over
+/// its target (density lowered, or the cap shrank).
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub fn update_weather_particles(
     mut commands: Commands,
     time: Res<Time>,
+    calendar: Res<Calendar>,
+    settings: Res<GameSettings>,
+    config: Res<WeatherParticleConfig>,
     camera_query: Query<&Transform, With<Camera2d>>,
     mut counts: ResMut<WeatherParticleCounts>,
-    mut rain_query: Query<(Entity, &RainDrop, &mut Transform), Without<Camera2d>>,
+    mut rain_query: Query<(Entity, &mut RainDrop, &mut Transform), Without<Camera2d>>,
     mut snow_query: Query<
         (Entity, &mut SnowFlake, &mut Transform),
         (Without<Camera2d>, Without<RainDrop>),
@@ -343,15 +410,40 @@ pub fn update_weather_particles(
 
     let dt = time.delta_secs();
     let cam_scale = cam_tf.scale.x;
+    let half_w = (SCREEN_WIDTH / 2.0) * cam_scale;
     let half_h = (SCREEN_HEIGHT / 2.0) * cam_scale;
-    let despawn_y = cam_tf.translation.y - half_h - 20.0;
+    let cam = cam_tf.translation.truncate();
+    let despawn_y = cam.y - half_h - RECYCLE_MARGIN;
+    let spawn_top = cam.y + half_h + 10.0;
+    let spawn_left = cam.x - half_w - SPAWN_MARGIN_X;
+    let spawn_right = cam.x + half_w + SPAWN_MARGIN_X;
+    // Particles left behind by a camera jump (map transition) also recycle.
+    let out_of_view = |pos: Vec3| {
+        pos.y < despawn_y
+            || pos.y > spawn_top + half_h
+            || (pos.x - cam.x).abs() > half_w + SPAWN_MARGIN_X + RECYCLE_MARGIN
+    };
+
+    let target = weather_particle_target(
+        calendar.weather,
+        config.max_particles,
+        settings.particle_density,
+    );
+    let mut rng = rand::thread_rng();
 
     // Update rain drops
-    for (entity, drop, mut transform) in rain_query.iter_mut() {
+    for (entity, mut drop, mut transform) in rain_query.iter_mut() {
         transform.translation.y -= drop.speed * dt;
-        if transform.translation.y < despawn_y {
+        if !out_of_view(transform.translation) {
+            continue;
+        }
+        if counts.total() > target {
             commands.entity(entity).despawn();
             counts.rain = counts.rain.saturating_sub(1);
+        } else {
+            transform.translation.x = rng.gen_range(spawn_left..spawn_right);
+            transform.translation.y = spawn_top + rng.gen_range(0.0..20.0);
+            drop.speed = rain_speed(calendar.weather, &mut rng);
         }
     }
 
@@ -362,9 +454,17 @@ pub fn update_weather_particles(
         // Lateral sine-wave drift
         transform.translation.x = flake.origin_x
             + (flake.elapsed * flake.drift_freq + flake.drift_phase).sin() * flake.drift_amp;
-        if transform.translation.y < despawn_y {
+        if !out_of_view(transform.translation) {
+            continue;
+        }
+        if counts.total() > target {
             commands.entity(entity).despawn();
             counts.snow = counts.snow.saturating_sub(1);
+        } else {
+            flake.origin_x = rng.gen_range(spawn_left..spawn_right);
+            flake.elapsed = 0.0;
+            transform.translation.x = flake.origin_x;
+            transform.translation.y = spawn_top + rng.gen_range(0.0..20.0);
         }
     }
 }
@@ -435,3 +535,84 @@ pub fn cleanup_all_weather_particles(
     counts.rain = 0;
     counts.snow = 0;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::asset::AssetPlugin;
+
+    fn weather_app(weather: Weather, max_particles: usize) -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Image>()
+            .insert_resource(Calendar {
+                weather,
+                ..default()
+            })
+            .insert_resource(PlayerState {
+                current_map: MapId::Farm,
+                ..default()
+            })
+            .init_resource::<GameSettings>()
+            .insert_resource(WeatherParticleConfig { max_particles })
+            .init_resource::<WeatherParticleCounts>()
+            .init_resource::<WeatherSprites>()
+            .add_systems(
+                Update,
+                (spawn_weather_particles, update_weather_particles).chain(),
+            );
+        app.world_mut().spawn((Camera2d, Transform::default()));
+        app
+    }
+
+    fn particle_count(app: &mut App) -> usize {
+        let world = app.world_mut();
+        let rain = world.query::<&RainDrop>().iter(world).count();
+        let snow = world.query::<&SnowFlake>().iter(world).count();
+        rain + snow
+    }
+
+    #[test]
+    fn test_particle_count_never_exceeds_cap() {
+        let cap = 40;
+        let mut app = weather_app(Weather::Stormy, cap);
+        for _ in 0..300 {
+            app.update();
+            assert!(particle_count(&mut app) <= cap);
+        }
+        assert_eq!(particle_count(&mut app), cap);
+        assert_eq!(app.world().resource::<WeatherParticleCounts>().total(), cap);
+    }
+
+    #[test]
+    fn test_particles_leaving_view_are_recycled() {
+        let mut app = weather_app(Weather::Rainy, 10);
+        for _ in 0..10 {
+            app.update();
+        }
+        let world = app.world_mut();
+        let (entity, mut transform) = world
+            .query_filtered::<(Entity, &mut Transform), With<RainDrop>>()
+            .iter_mut(world)
+            .next()
+            .unwrap();
+        transform.translation.y = -SCREEN_HEIGHT * 2.0;
+
+        app.update();
+
+        let transform = app
+            .world()
+            .get::<Transform>(entity)
+            .expect("recycled, not despawned");
+        assert!(transform.translation.y > SCREEN_HEIGHT / 2.0);
+        assert_eq!(particle_count(&mut app), 10);
+    }
+
+    #[test]
+    fn test_density_scales_target() {
+        assert_eq!(weather_particle_target(Weather::Rainy, 600, 1.0), 300);
+        assert_eq!(weather_particle_target(Weather::Rainy, 600, 0.5), 150);
+        assert_eq!(weather_particle_target(Weather::Stormy, 100, 1.0), 100);
+        assert_eq!(weather_particle_target(Weather::Sunny, 600, 1.0), 0);
+    }
+}
@@ -1,11 +1,9 @@
 //! Regression test: no unintended duplicate keybindings.
 use bevy::prelude::KeyCode;
-use hearthfield::shared::KeyBindings;
+use hearthfield::shared::{KeyBindings, KeybindingProfile};
 use std::collections::HashMap;
 
-#[test]
-fn default_keybindings_have_no_unintended_duplicates() {
-    let b = KeyBindings::default();
+fn assert_no_unintended_duplicates(b: &KeyBindings) {
     let mut map: HashMap<KeyCode, Vec<&'static str>> = HashMap::new();
 
     macro_rules! add {
@@ -21,6 +19,9 @@ fn default_keybindings_have_no_unintended_duplicates() {
     add!(b.interact, "interact");
     add!(b.tool_use, "tool_use");
     add!(b.tool_secondary, "tool_secondary");
+    add!(b.quick_water, "quick_water");
+    add!(b.run, "run");
+    add!(b.wrap_gift, "wrap_gift");
     add!(b.open_inventory, "open_inventory");
     add!(b.open_crafting, "open_crafting");
     add!(b.open_map, "open_map");
@@ -31,6 +32,8 @@ fn default_keybindings_have_no_unintended_duplicates() {
     add!(b.tool_prev, "tool_prev");
     add!(b.ui_confirm, "ui_confirm");
     add!(b.ui_cancel, "ui_cancel");
+    add!(b.ui_secondary, "ui_secondary");
+    add!(b.ui_discard, "ui_discard");
     add!(b.skip_cutscene, "skip_cutscene");
 
     // These are intentionally shared across input contexts:
@@ -42,3 +45,44 @@ fn default_keybindings_have_no_unintended_duplicates() {
         }
     }
 }
+
+#[test]
+fn default_keybindings_have_no_unintended_duplicates() {
+    assert_no_unintended_duplicates(&KeyBindings::default());
+}
+
+#[test]
+fn preset_profiles_have_no_unintended_duplicates() {
+    for profile in [
+        KeybindingProfile::Wasd,
+        KeybindingProfile::Esdf,
+        KeybindingProfile::Arrows,
+    ] {
+        let bindings = KeyBindings::for_profile(profile).expect("preset profile");
+        assert_no_unintended_duplicates(&bindings);
+    }
+}
+
+#[test]
+fn arrows_profile_moves_with_the_arrow_keys() {
+    let mut bindings = KeyBindings::default();
+    KeybindingProfile::Arrows.apply(&mut bindings);
+    assert_eq!(bindings.move_up, KeyCode::ArrowUp);
+    assert_eq!(bindings.move_down, KeyCode::ArrowDown);
+    assert_eq!(bindings.move_left, KeyCode::ArrowLeft);
+    assert_eq!(bindings.move_right, KeyCode::ArrowRight);
+    assert_eq!(bindings.interact, KeyBindings::default().interact);
+}
+
+#[test]
+fn custom_profile_keeps_the_current_bindings() {
+    let mut bindings = KeyBindings {
+        interact: KeyCode::KeyQ,
+        ..KeyBindings::default()
+    };
+    KeybindingProfile::Custom.apply(&mut bindings);
+    assert_eq!(bindings.interact, KeyCode::KeyQ);
+
+    KeybindingProfile::Wasd.apply(&mut bindings);
+    assert_eq!(bindings.interact, KeyCode::KeyF);
+}